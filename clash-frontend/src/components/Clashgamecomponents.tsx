@@ -16,6 +16,8 @@ export const ATTACK_META: Record<number, {
   [Attack.Slash]:     { label: 'Slash',     emoji: '⚔️',  dmg: 30, beats: 'Block',   loses: 'Dodge',   color: 'from-amber-500  to-yellow-400', border: 'border-amber-400'  },
   [Attack.Fireball]:  { label: 'Fireball',  emoji: '🔥',  dmg: 40, beats: 'Dodge',   loses: 'Counter', color: 'from-red-600    to-orange-400', border: 'border-red-400'    },
   [Attack.Lightning]: { label: 'Lightning', emoji: '⚡',  dmg: 35, beats: 'Counter', loses: 'Block',   color: 'from-violet-600 to-blue-400',   border: 'border-violet-400' },
+  [Attack.Broadside]: { label: 'Broadside', emoji: '💣',  dmg: 50, beats: 'Block',   loses: 'Dodge',   color: 'from-stone-600  to-zinc-400',   border: 'border-stone-400'  },
+  [Attack.Hook]:      { label: 'Hook',      emoji: '🪝',  dmg: 20, beats: 'Block',   loses: 'Riposte', color: 'from-teal-600   to-cyan-400',   border: 'border-teal-400'   },
 };
 
 export const DEFENSE_META: Record<number, {
@@ -24,6 +26,7 @@ export const DEFENSE_META: Record<number, {
   [Defense.Block]:   { label: 'Block',   emoji: '🛡️', stops: 'Lightning & Slash', color: 'from-sky-600   to-cyan-400',    border: 'border-sky-400'   },
   [Defense.Dodge]:   { label: 'Dodge',   emoji: '🏃', stops: 'Fireball & Slash',  color: 'from-emerald-600 to-green-400', border: 'border-emerald-400' },
   [Defense.Counter]: { label: 'Counter', emoji: '🔄', stops: 'Lightning & Fire',  color: 'from-pink-600  to-rose-400',    border: 'border-pink-400'  },
+  [Defense.Riposte]: { label: 'Riposte', emoji: '🤺', stops: 'Hook, hits back',   color: 'from-indigo-600 to-purple-400', border: 'border-indigo-400' },
 };
 
 export function shortAddress(addr: string): string {
@@ -202,6 +205,7 @@ export function MoveSelector({ moves, onChange, disabled }: MoveSelectorProps) {
   };
 
   const allFilled = moves.every(m => m.attack !== null && m.defense !== null);
+  // Only what the deployed commit circuit can prove; the newer moves are display-only
   const attacks = [Attack.Slash, Attack.Fireball, Attack.Lightning];
   const defenses = [Defense.Block, Defense.Dodge, Defense.Counter];
 
@@ -18,20 +18,27 @@ type ZkPhase = 'create' | 'commit' | 'waiting_reveal' | 'reveal' | 'resolve' | '
 const STEP_KEYS = ['moves', 'proof', 'commit', 'reveal', 'resolve'] as const;
 const POINTS_DECIMALS = 7;
 const DEFAULT_POINTS = '0.1';
+// `provable`: the deployed commit circuit only accepts the original three attacks and
+// defenses, so the rest are shown in playback but can't be picked for a ZK commit yet
 const ATTACK_MOVES = [
-  { index: 0, icon: '⚔️', name: 'Cutlass Slash', damage: 30, counters: 'Counter', blockedBy: 'Dodge' },
-  { index: 1, icon: '🔥', name: 'Cannon Blast', damage: 40, counters: 'Dodge', blockedBy: 'Counter' },
-  { index: 2, icon: '⚡', name: 'Lightning Strike', damage: 35, counters: 'Block', blockedBy: 'Block' },
+  { index: 0, icon: '⚔️', name: 'Cutlass Slash', damage: 30, counters: 'Counter', blockedBy: 'Dodge', provable: true },
+  { index: 1, icon: '🔥', name: 'Cannon Blast', damage: 40, counters: 'Dodge', blockedBy: 'Counter', provable: true },
+  { index: 2, icon: '⚡', name: 'Lightning Strike', damage: 35, counters: 'Block', blockedBy: 'Block', provable: true },
+  { index: 3, icon: '💣', name: 'Broadside', damage: 50, counters: 'Block', blockedBy: 'Dodge', provable: false },
+  { index: 4, icon: '🪝', name: 'Grappling Hook', damage: 20, counters: 'Block', blockedBy: 'Riposte', provable: false },
 ] as const;
 const DEFENSE_MOVES = [
-  { index: 0, icon: '🛡️', name: 'Raised Shield', label: 'Block', stops: 'Lightning Strike', stopsIcon: '⚡' },
-  { index: 1, icon: '🏃', name: 'Quick Sidestep', label: 'Dodge', stops: 'Cutlass Slash', stopsIcon: '⚔️' },
-  { index: 2, icon: '🔄', name: 'Riposte', label: 'Counter', stops: 'Cannon Blast', stopsIcon: '🔥' },
+  { index: 0, icon: '🛡️', name: 'Raised Shield', label: 'Block', stops: 'Lightning Strike', stopsIcon: '⚡', provable: true },
+  { index: 1, icon: '🏃', name: 'Quick Sidestep', label: 'Dodge', stops: 'Cutlass Slash', stopsIcon: '⚔️', provable: true },
+  { index: 2, icon: '🔄', name: 'Parry', label: 'Counter', stops: 'Cannon Blast', stopsIcon: '🔥', provable: true },
+  { index: 3, icon: '🤺', name: 'Riposte', label: 'Riposte', stops: 'Grappling Hook', stopsIcon: '🪝', provable: false },
 ] as const;
 const COUNTER_DEFENSE_BY_ATTACK: Record<number, number> = {
   [Attack.Slash]: Defense.Dodge,
   [Attack.Fireball]: Defense.Counter,
   [Attack.Lightning]: Defense.Block,
+  [Attack.Broadside]: Defense.Dodge,
+  [Attack.Hook]: Defense.Riposte,
 };
 
 const MATRIX_CHARS = 'アイウエオカキクケコ0123456789ABCDEF<>{}[]|/\\';
@@ -884,6 +891,7 @@ export function ClashZkArena({
   const [challengesLoading, setChallengesLoading] = useState(false);
   const [pointsStr, setPointsStr] = useState(DEFAULT_POINTS);
   const [loadSessionId, setLoadSessionId] = useState('');
+  const [activeGames, setActiveGames] = useState<Array<{ sessionId: number; game: Game }>>([]);
   const [selectedMoves, setSelectedMoves] = useState<SelectedMove[]>(() => createEmptyMoves());
   const [storedPublicInputs, setStoredPublicInputs] = useState<Uint8Array | null>(null);
   const [proofBundle, setProofBundle] = useState<ClashProofResult | null>(null);
@@ -1087,6 +1095,8 @@ export function ClashZkArena({
   const loadChallenges = useCallback(async () => {
     setChallengesLoading(true);
     try {
+      const activeIds = await clashService.getPlayerActiveGames(userAddress);
+      setActiveGames(await clashService.getGames(activeIds));
       const res = await clashService.getPlayerChallenges(userAddress);
       setAllChallenges({
         active: res.active,
@@ -1141,18 +1151,24 @@ export function ClashZkArena({
     }
   }, [challengeOutcomes, clashService, userAddress]);
 
+  // Only re-fetch the lists when the player's activity seq has moved
+  const lastActivitySeqRef = useRef<bigint | null>(null);
   useEffect(() => {
     if (phase !== 'create') return;
     void loadChallenges();
-    const id = window.setInterval(() => void loadChallenges(), 8000);
+    const id = window.setInterval(async () => {
+      const seq = await clashService.getLastActivitySeq(userAddress);
+      if (seq !== null && seq === lastActivitySeqRef.current) return;
+      lastActivitySeqRef.current = seq;
+      void loadChallenges();
+    }, 8000);
     return () => window.clearInterval(id);
-  }, [phase, loadChallenges]);
+  }, [phase, loadChallenges, clashService, userAddress]);
 
-  const handleAcceptChallenge = async (challengeId: number) => {
+  const handleAcceptChallenge = async (challengeId: number, challengeNonce: number) => {
     setBusy(true);
     try {
-      const sid = createRandomSessionId();
-      await clashService.acceptChallengeWithSmartAccount(challengeId, userAddress, sid, smartAccountService);
+      const sid = await clashService.acceptChallengeWithSmartAccount(challengeId, userAddress, challengeNonce, smartAccountService);
       setSessionId(sid);
       const acceptedGame = await clashService.getGame(sid);
       if (acceptedGame) {
@@ -1709,7 +1725,7 @@ export function ClashZkArena({
                   className="btn-arena-secondary"
                   style={{ marginLeft: 8 }}
                   disabled={busy}
-                  onClick={() => void handleAcceptChallenge(Number(challenge.challenge_id))}
+                  onClick={() => void handleAcceptChallenge(Number(challenge.challenge_id), Number(challenge.nonce))}
                 >
                   Accept
                 </button>
@@ -1784,6 +1800,23 @@ export function ClashZkArena({
               );
             })}
           </section>
+          {activeGames.length > 0 && (
+            <section className="arena-card">
+              <h3>Your Games</h3>
+              {activeGames.map(({ sessionId: sid, game }) => {
+                const isPlayer1 = game.player1 === userAddress;
+                const committed = isPlayer1 ? game.has_player1_commitment : game.has_player2_commitment;
+                const revealed = isPlayer1 ? game.player1_commitment?.has_revealed : game.player2_commitment?.has_revealed;
+                const status = !committed ? 'Commit due' : !(game.has_player1_commitment && game.has_player2_commitment)
+                  ? 'Waiting for opponent' : !revealed ? 'Reveal due' : 'Waiting for resolution';
+                return (
+                  <button key={sid} className="btn-arena-secondary" disabled={busy} onClick={() => void openSessionFromHistory(sid)}>
+                    Session {sid} · {status}
+                  </button>
+                );
+              })}
+            </section>
+          )}
           <section className="arena-card">
             <h3>Rejoin Arena</h3>
             <label>Session ID</label>
@@ -1826,7 +1859,7 @@ export function ClashZkArena({
                           ⚔ ATTACK {m.attack === null ? '(Pick attack)' : ''}
                         </div>
                         <div className="move-card-row">
-                          {ATTACK_MOVES.map((atk) => (
+                          {ATTACK_MOVES.filter((atk) => atk.provable).map((atk) => (
                             <motion.button
                               key={`atk-${i}-${atk.index}`}
                               type="button"
@@ -1853,7 +1886,7 @@ export function ClashZkArena({
                           🛡 DEFENSE {m.defense === null ? '(Pick defense)' : ''}
                         </div>
                         <div className="move-card-row">
-                          {DEFENSE_MOVES.map((def) => (
+                          {DEFENSE_MOVES.filter((def) => def.provable).map((def) => (
                             <motion.button
                               key={`def-${i}-${def.index}`}
                               type="button"
@@ -5,7 +5,7 @@
 import { useCallback, useEffect, useMemo, useRef, useState } from 'react';
 import { Buffer } from 'buffer';
 import { NoirService, type ClashProofResult } from '@/utils/NoirService';
-import type { ClashGameService } from './clashService';
+import { CAPTAIN_CLASS_COPY, OUTCOME_REASON_COPY, type ClashGameService } from './clashService';
 import type { SmartAccountService } from './smartAccountService';
 import type { Game, GamePlayback, Move } from './bindings';
 import { Attack, Defense } from './bindings';
@@ -94,7 +94,7 @@ function describeScriptStep(args: {
   hasLocalProof: boolean;
 }): { ref: string; hint: string } {
   const { hasGame, phase, myCommitted, p1c, p2c, p1r, p2r, hasBattle, hasLocalProof } = args;
-  if (!hasGame) return { ref: 'Step 15: start_game', hint: 'Create or load a session (locks stake via GameHub).' };
+  if (!hasGame) return { ref: 'Step 15: propose_game + confirm_game', hint: 'Propose a session, or load one to confirm it (locks stakes via GameHub).' };
   if (phase === 'commit' && !myCommitted && !hasLocalProof)
     return { ref: 'Prover.toml', hint: 'Pick 3×(attack, defense) — same as attacks/defenses arrays in the script.' };
   if (phase === 'commit' && !myCommitted && hasLocalProof)
@@ -102,7 +102,7 @@ function describeScriptStep(args: {
   if (phase === 'waiting_reveal' && myCommitted && !(p1c && p2c))
     return { ref: 'Steps 16–17', hint: 'Waiting for the other player’s commit_moves.' };
   if (phase === 'reveal' || (phase === 'waiting_reveal' && !myCommitted))
-    return { ref: 'Steps 18–19: reveal_moves', hint: 'Submit same public_inputs + plaintext moves as JSON (attest hash).' };
+    return { ref: 'Steps 18–19: reveal_and_resolve', hint: 'Submit same public_inputs + plaintext moves as JSON (attest hash); the second reveal also resolves.' };
   if (phase === 'resolve')
     return { ref: 'Step 20: resolve_battle', hint: 'Anyone can invoke once both reveals are on-chain.' };
   if (phase === 'complete' || hasBattle) return { ref: 'Step 21: get_game_playback', hint: 'Resolved — fetch turn-by-turn playback.' };
@@ -270,7 +270,7 @@ export function ClashZkDuelSmartAccount({ userAddress, clashService, smartAccoun
       setGamePlayback(null);
       setGameState(null);
 
-      await clashService.startGameWithSmartAccount(
+      await clashService.proposeGameWithSmartAccount(
         sid,
         userAddress,
         opponentAddress.trim(),
@@ -279,10 +279,7 @@ export function ClashZkDuelSmartAccount({ userAddress, clashService, smartAccoun
         smartAccountService
       );
 
-      setSuccess('Game started! Choose your 3-turn strategy.');
-      setPhase('commit');
-      await loadGameState();
-      setTimeout(() => setSuccess(null), 4000);
+      setSuccess(`Game proposed! Share session ${sid}; it starts once your opponent loads and confirms it.`);
     } catch (e) {
       setError(e instanceof Error ? e.message : 'Failed to start game');
     } finally {
@@ -299,7 +296,18 @@ export function ClashZkDuelSmartAccount({ userAddress, clashService, smartAccoun
     }
     setBusy(true);
     try {
-      const game = await clashService.getGame(sid);
+      let game = await clashService.getGame(sid);
+      if (!game) {
+        // A proposed game starts once player 2 confirms it
+        const proposal = await clashService.getGameProposal(sid);
+        if (proposal && proposal.player2 === userAddress) {
+          await clashService.confirmGameWithSmartAccount(sid, userAddress, smartAccountService);
+          game = await clashService.getGame(sid);
+        } else if (proposal && proposal.player1 === userAddress) {
+          setError('Waiting for your opponent to confirm this game');
+          return;
+        }
+      }
       if (!game) {
         setError('Game not found');
         return;
@@ -419,7 +427,8 @@ export function ClashZkDuelSmartAccount({ userAddress, clashService, smartAccoun
     setBusy(true);
     setError(null);
     try {
-      await clashService.revealMovesWithSmartAccount(
+      // The second reveal resolves the battle in the same transaction
+      const resolved = await clashService.revealAndResolveWithSmartAccount(
         sessionId,
         userAddress,
         toBuffer(storedPublicInputs),
@@ -427,7 +436,12 @@ export function ClashZkDuelSmartAccount({ userAddress, clashService, smartAccoun
         smartAccountService
       );
       clearPublicInputs(sessionId, userAddress);
-      setSuccess('Moves revealed');
+      if (resolved) {
+        const pb = await clashService.getGamePlayback(sessionId);
+        if (pb) setGamePlayback(pb);
+        setPhase('complete');
+      }
+      setSuccess(resolved ? 'Moves revealed, battle resolved' : 'Moves revealed');
       await loadGameState();
       setTimeout(() => setSuccess(null), 4000);
     } catch (e) {
@@ -539,12 +553,12 @@ export function ClashZkDuelSmartAccount({ userAddress, clashService, smartAccoun
           <p className="text-sm font-bold text-gray-900">{scriptCue.ref}</p>
           <p className="mt-1 text-xs leading-relaxed text-gray-600">{scriptCue.hint}</p>
           <ol className="mt-3 list-decimal space-y-1 pl-5 text-[11px] text-gray-500">
-            <li>start_game — session, both players, stake (script step 15)</li>
+            <li>propose_game, then confirm_game by the opponent — session, both players, stake (script step 15)</li>
             <li>Prover inputs: 3× attack + 3× defense; player_address + session_id fields for the circuit</li>
             <li>nargo execute + prove_ultra_keccak_honk → split public_inputs (96 B) + proof_bytes (browser uses Noir + bb.js)</li>
             <li>commit_moves with --public_inputs and --proof_bytes (steps 16–17)</li>
-            <li>reveal_moves with same public_inputs + moves JSON (steps 18–19)</li>
-            <li>resolve_battle (step 20), then get_game_playback (step 21)</li>
+            <li>reveal_and_resolve with same public_inputs + moves JSON (steps 18–19); the second reveal resolves</li>
+            <li>resolve_battle (step 20) only if that didn't happen, then get_game_playback (step 21)</li>
           </ol>
         </div>
       )}
@@ -728,6 +742,17 @@ export function ClashZkDuelSmartAccount({ userAddress, clashService, smartAccoun
               return addr === userAddress ? '🏆 You won!' : '😢 You lost';
             })()}
           </p>
+          {OUTCOME_REASON_COPY[Number(gameState.battle_result.reason)] && (
+            <p className="text-center text-sm text-gray-600 mt-1">
+              {OUTCOME_REASON_COPY[Number(gameState.battle_result.reason)]}
+            </p>
+          )}
+          {gamePlayback && (CAPTAIN_CLASS_COPY[Number(gamePlayback.player1_class)] || CAPTAIN_CLASS_COPY[Number(gamePlayback.player2_class)]) && (
+            <p className="text-center text-sm text-gray-600 mt-1">
+              P1 {CAPTAIN_CLASS_COPY[Number(gamePlayback.player1_class)]?.name ?? 'No class'} vs P2{' '}
+              {CAPTAIN_CLASS_COPY[Number(gamePlayback.player2_class)]?.name ?? 'No class'}
+            </p>
+          )}
           {gamePlayback && gamePlayback.turn_results?.length > 0 && (
             <div className="mt-4 text-sm text-gray-700 space-y-2">
               <p className="font-bold">Turn-by-turn</p>
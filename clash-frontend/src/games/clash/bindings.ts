@@ -38,661 +38,6634 @@ export const networks = {
   }
 } as const
 
-
-export interface Game {
-  battle_result: BattleResult;
-  has_battle_result: boolean;
-  has_player1_commitment: boolean;
-  has_player2_commitment: boolean;
-  player1: string;
-  player1_commitment: PlayerCommitment;
-  player1_points: i128;
-  player2: string;
-  player2_commitment: PlayerCommitment;
-  player2_points: i128;
-}
-
-
-export interface Move {
-  attack: Attack;
-  defense: Defense;
-}
-
+/**
+ * Every entrypoint returns this single error type. Codes are stable so the
+ * frontend can map them to messages; new variants are only ever appended.
+ */
 export const Errors = {
   1: {message:"GameNotFound"},
   2: {message:"NotPlayer"},
   3: {message:"AlreadyCommitted"},
   4: {message:"BothPlayersNotCommitted"},
   5: {message:"GameAlreadyEnded"},
+  /**
+   * The verifier ran and rejected the proof
+   */
   6: {message:"InvalidProof"},
+  /**
+   * Catch-all verifier failure from before the causes were split; no longer
+   * returned, see `InvalidProof` and `VerifierTrapped`
+   */
   7: {message:"ProofVerificationFailed"},
   8: {message:"InvalidMoveSequence"},
   9: {message:"UsernameAlreadyTaken"},
   10: {message:"UsernameTooLong"},
   11: {message:"ChallengeNotFound"},
   12: {message:"ChallengeExpired"},
+  /**
+   * A player tried to challenge, invite or start a game against themselves, or
+   * against an address linked to them
+   */
   13: {message:"CannotChallengeSelf"},
   14: {message:"UsernameTooShort"},
   15: {message:"InvalidUsernameFormat"},
   16: {message:"UsernameReserved"},
   17: {message:"AlreadyRevealed"},
   18: {message:"CommitmentMismatch"},
-  19: {message:"InvalidPublicInputs"}
+  /**
+   * Public inputs are not the expected `[player | session_id | commitment]` layout
+   */
+  19: {message:"InvalidPublicInputs"},
+  /**
+   * The current duel phase closed before this action arrived
+   */
+  20: {message:"DeadlinePassed"},
+  /**
+   * A timeout was claimed while the opponent still has time to act
+   */
+  21: {message:"DeadlineNotReached"},
+  /**
+   * Wager is negative or outside the configured limits
+   */
+  22: {message:"InvalidWager"},
+  23: {message:"ChallengeAlreadyAccepted"},
+  /**
+   * The Game Hub refused or failed to start the session
+   */
+  24: {message:"GameHubCallFailed"},
+  /**
+   * Both players revealed in time, so there is no timeout to claim
+   */
+  25: {message:"NothingToClaim"},
+  /**
+   * Ranked play requires both players to be registered with the Game Hub
+   */
+  26: {message:"PlayerNotRegistered"},
+  /**
+   * A co-signed result is waiting out its challenge window for this game
+   */
+  27: {message:"SettlementPending"},
+  /**
+   * There is no co-signed result to dispute or finalize
+   */
+  28: {message:"NoPendingSettlement"},
+  /**
+   * The signed result names a non-player as winner, is inconsistent, or a player
+   * has no settlement key registered
+   */
+  29: {message:"InvalidSignedResult"},
+  /**
+   * A `GameConfig` value or the nullifier retention is out of range
+   */
+  30: {message:"InvalidGameConfig"},
+  /**
+   * Raise amount is out of bounds, or raising is off for this game
+   */
+  31: {message:"InvalidRaise"},
+  /**
+   * Raises are only allowed once per game, after both commits and before any reveal
+   */
+  32: {message:"RaiseNotAllowed"},
+  /**
+   * There is no raise from the opponent to call or fold
+   */
+  33: {message:"NoRaisePending"},
+  /**
+   * The pending raise must be called or folded before revealing
+   */
+  34: {message:"RaisePending"},
+  /**
+   * Invite code lifetime is zero or longer than a challenge may stay open
+   */
+  35: {message:"InvalidTtl"},
+  /**
+   * A challenge can only be forwarded once
+   */
+  36: {message:"ChallengeAlreadyForwarded"},
+  /**
+   * No lobby tier with that number
+   */
+  37: {message:"InvalidTier"},
+  /**
+   * The player is already waiting in a lobby
+   */
+  38: {message:"AlreadyQueued"},
+  /**
+   * The player is not waiting in any lobby
+   */
+  39: {message:"NotQueued"},
+  /**
+   * Fee tiers are out of order or charge more than the maximum rate
+   */
+  40: {message:"InvalidFeeSchedule"},
+  /**
+   * Promo code is unknown, used up, or created with a zero amount or cap
+   */
+  41: {message:"InvalidPromoCode"},
+  /**
+   * Each player can redeem a promo code once
+   */
+  42: {message:"PromoAlreadyRedeemed"},
+  /**
+   * The challenge was declined or cancelled
+   */
+  43: {message:"ChallengeClosed"},
+  /**
+   * The open challenge board is full, or the player already has 3 challenges on
+   * it; wait for some to be taken or expire, or cancel one
+   */
+  44: {message:"TooManyOpenChallenges"},
+  /**
+   * The player's friends list is full
+   */
+  45: {message:"TooManyFriends"},
+  /**
+   * The session hasn't finished yet
+   */
+  46: {message:"GameNotEnded"},
+  /**
+   * A series must be best of 3 or best of 5
+   */
+  47: {message:"InvalidSeriesLength"},
+  48: {message:"SeriesAlreadyExists"},
+  49: {message:"SeriesNotFound"},
+  50: {message:"TournamentNotFound"},
+  /**
+   * Bad field size (a power of two up to 32), deadline or entry
+   */
+  51: {message:"InvalidTournament"},
+  52: {message:"TournamentFull"},
+  53: {message:"RegistrationClosed"},
+  54: {message:"AlreadyRegistered"},
+  /**
+   * These public inputs were already used for a commit
+   */
+  55: {message:"NullifierUsed"},
+  /**
+   * A `RuleSet` value is out of range
+   */
+  56: {message:"InvalidRules"},
+  /**
+   * Reserved: reveals once carried a wager field checked against the stake
+   */
+  57: {message:"WagerMismatch"},
+  /**
+   * Teams must be two players each, with nobody on both sides
+   */
+  58: {message:"InvalidTeam"},
+  59: {message:"RematchNotFound"},
+  /**
+   * A rematch of this session has already been offered
+   */
+  60: {message:"RematchAlreadyRequested"},
+  /**
+   * The game commits the other way: ZK calls on a hash-mode game or vice versa
+   */
+  61: {message:"WrongCommitMode"},
+  /**
+   * No verifier is registered for this circuit version
+   */
+  62: {message:"UnknownCircuitVersion"},
+  /**
+   * The game's circuit version has been deprecated, so its proofs are refused
+   */
+  63: {message:"CircuitDeprecated"},
+  /**
+   * New games are pinned to this version; register a newer one first
+   */
+  64: {message:"CircuitInUse"},
+  /**
+   * Predictions on this game closed when the first commit landed
+   */
+  65: {message:"PredictionsClosed"},
+  /**
+   * Bad amount, backed player or side, a player predicting on their own game, or
+   * no prediction token configured
+   */
+  66: {message:"InvalidPrediction"},
+  /**
+   * The running season has to end before the next one starts
+   */
+  67: {message:"SeasonInProgress"},
+  /**
+   * Season ID not above the last one, end time already passed, or no season to end
+   */
+  68: {message:"InvalidSeason"},
+  /**
+   * The claim window for this reward has closed
+   */
+  69: {message:"ClaimExpired"},
+  /**
+   * A player has already committed, so the game can only end through play or
+   * its deadlines
+   */
+  70: {message:"GameInProgress"},
+  /**
+   * A session signer must be a different address from the player, can't be set for
+   * a game that takes a commit deposit, and can only be revoked once set
+   */
+  71: {message:"InvalidSessionSigner"},
+  /**
+   * `sweep_fees` needs a treasury set with `set_treasury`
+   */
+  72: {message:"NoTreasury"},
+  /**
+   * The challenge changed (e.g. was forwarded) since the nonce the acceptance was
+   * built against
+   */
+  73: {message:"StaleChallenge"},
+  /**
+   * The admin has paused this entrypoint (or the whole contract); see `get_pause`
+   */
+  74: {message:"ContractPaused"},
+  /**
+   * Not the player's coach for the game, or a player naming themselves
+   */
+  75: {message:"InvalidCoach"},
+  /**
+   * Coaches see the moves only once the battle is resolved
+   */
+  76: {message:"CoachingLocked"},
+  /**
+   * The player (or name) has no username to release or transfer
+   */
+  77: {message:"NoUsername"},
+  /**
+   * A game or game proposal already uses this session ID
+   */
+  78: {message:"SessionTaken"},
+  /**
+   * No sponsor tier is set, or a player has played too many rated games for it
+   */
+  79: {message:"NotSponsored"},
+  /**
+   * A player has used up today's sponsored games
+   */
+  80: {message:"SponsorLimitReached"},
+  /**
+   * The sponsor pool can't cover both stakes, or the withdrawal asked for
+   */
+  81: {message:"SponsorPoolEmpty"},
+  /**
+   * Stake or daily limit not positive, or a token change while the pool holds funds
+   */
+  82: {message:"InvalidSponsorTier"},
+  /**
+   * Not a live correspondence game, no pause to accept or resume, one already
+   * running, or more paused time than the game's allowance
+   */
+  83: {message:"InvalidPause"},
+  /**
+   * The player already named a referrer
+   */
+  84: {message:"AlreadyReferred"},
+  /**
+   * Self-referral, a player who has already played, or a referrer who hasn't
+   */
+  85: {message:"InvalidReferral"},
+  /**
+   * Self link, no matching request, a secondary with a record, live games or
+   * challenges of its own, a primary that is itself linked, or too many linked
+   * addresses
+   */
+  86: {message:"InvalidLink"},
+  /**
+   * Settling more bonus points than the player has, or none
+   */
+  87: {message:"InsufficientPoints"},
+  /**
+   * The game already holds the most spectator picks it takes
+   */
+  88: {message:"PicksFull"},
+  /**
+   * A ledger range that is reversed or spans more than a day
+   */
+  89: {message:"InvalidRange"},
+  /**
+   * A signed result for this session was disputed; it must be played out on-chain
+   */
+  90: {message:"SettlementDisputed"},
+  /**
+   * A player tried to add themselves as a friend
+   */
+  91: {message:"CannotFriendSelf"},
+  /**
+   * A player is already in as many unsettled games and duels as they can be
+   */
+  92: {message:"TooManyLiveSessions"},
+  /**
+   * Prize shares that are empty, pay more places than the field or don't sum to
+   * 100%, a pool that is empty or already set, or vesting that pays everything at
+   * once, locks for no time or too long, or is already set
+   */
+  93: {message:"InvalidPrizeSchedule"},
+  /**
+   * Check-in is only open in the half hour before a tournament starts
+   */
+  94: {message:"CheckInClosed"},
+  /**
+   * The locked part of a tournament prize hasn't vested yet
+   */
+  95: {message:"PrizeStillVesting"},
+  /**
+   * The tournament has already completed or been cancelled
+   */
+  96: {message:"TournamentFinished"},
+  /**
+   * The verifier trapped or returned something other than `()`, so the proof
+   * could not be checked at all (misconfigured or broken verifier contract)
+   */
+  97: {message:"VerifierTrapped"}
 }
 
+export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void} | {tag: "Verifier", values: readonly [u32]} | {tag: "CircuitVersion", values: void} | {tag: "PredictionToken", values: void} | {tag: "PredictionPool", values: readonly [u32]} | {tag: "Prediction", values: readonly [u32, string]} | {tag: "Picks", values: readonly [u32]} | {tag: "PickStats", values: readonly [string]} | {tag: "Season", values: void} | {tag: "SeasonStats", values: readonly [u32, string]} | {tag: "SeasonLeaderboard", values: readonly [u32]} | {tag: "SeasonResults", values: readonly [u32]} | {tag: "SeasonRewards", values: void} | {tag: "SeasonPlacement", values: readonly [u32, string]} | {tag: "Achievements", values: readonly [string]} | {tag: "DailyBonusDay", values: readonly [string]} | {tag: "BonusPoints", values: readonly [string]} | {tag: "SponsorTier", values: void} | {tag: "SponsorPool", values: void} | {tag: "Sponsorship", values: readonly [u32]} | {tag: "GamePause", values: readonly [u32]} | {tag: "MaxGamePause", values: void} | {tag: "Referrer", values: readonly [string]} | {tag: "ReferralStats", values: readonly [string]} | {tag: "ReferralBonus", values: void} | {tag: "SponsoredGames", values: readonly [string]} | {tag: "HubLastOk", values: void} | {tag: "Username", values: readonly [string]} | {tag: "AddressByUsername", values: readonly [string]} | {tag: "LastActiveAt", values: readonly [string]} | {tag: "UsernameExpiry", values: void} | {tag: "CaptainClass", values: readonly [string]} | {tag: "LinkedTo", values: readonly [string]} | {tag: "LinkedAddresses", values: readonly [string]} | {tag: "LinkRequest", values: readonly [string]} | {tag: "Challenge", values: readonly [u32]} | {tag: "ChallengeCounter", values: void} | {tag: "PlayerChallenges", values: readonly [string]} | {tag: "Match", values: readonly [u32]} | {tag: "MatchCounter", values: void} | {tag: "PlayerMatches", values: readonly [string]} | {tag: "TokenContractAddress", values: void} | {tag: "BuildInfo", values: void} | {tag: "StorageVersion", values: void} | {tag: "MigrationCursor", values: void} | {tag: "MigrationStartedAt", values: void} | {tag: "Duel", values: readonly [u32]} | {tag: "SiegeStats", values: readonly [string]} | {tag: "PracticeCounter", values: void} | {tag: "PracticeGame", values: readonly [u32]} | {tag: "PracticeStats", values: readonly [string, PracticeDifficulty]} | {tag: "WagerLimits", values: void} | {tag: "CommitDeposit", values: void} | {tag: "RequireRegistered", values: void} | {tag: "PendingSettlement", values: readonly [u32]} | {tag: "SettlementDisputed", values: readonly [u32]} | {tag: "SettlementKey", values: readonly [string]} | {tag: "GameConfig", values: void} | {tag: "RaiseConfig", values: void} | {tag: "InviteCode", values: readonly [u64]} | {tag: "GameProposal", values: readonly [u32]} | {tag: "Queue", values: readonly [u32]} | {tag: "QueuedTier", values: readonly [string]} | {tag: "Escrow", values: readonly [u32]} | {tag: "FeeSchedule", values: void} | {tag: "OpenChallenge", values: readonly [u32]} | {tag: "OpenChallenges", values: void} | {tag: "Friends", values: readonly [string]} | {tag: "LookingForGame", values: readonly [string]} | {tag: "GlobalStats", values: void} | {tag: "PlayerStats", values: readonly [string]} | {tag: "Leaderboard", values: void} | {tag: "RatingHistogram", values: void} | {tag: "RatingDecay", values: void} | {tag: "DecaySince", values: readonly [string]} | {tag: "MatchHistory", values: readonly [string]} | {tag: "MatchNote", values: readonly [string, u32]} | {tag: "Rival", values: readonly [string]} | {tag: "HeadToHead", values: readonly [string, string]} | {tag: "CollectedFees", values: readonly [string]} | {tag: "Treasury", values: void} | {tag: "Moderator", values: void} | {tag: "Shadowed", values: readonly [string]} | {tag: "Pause", values: void} | {tag: "Winnings", values: readonly [u32, string]} | {tag: "PromoCode", values: readonly [string]} | {tag: "PromoRedeemed", values: readonly [string, string]} | {tag: "FeeWaivers", values: readonly [string]} | {tag: "DepositBonus", values: readonly [string]} | {tag: "PendingResolutions", values: void} | {tag: "ChallengeBySession", values: readonly [u32]} | {tag: "SessionSigner", values: readonly [u32, string]} | {tag: "PlaybackTurns", values: readonly [u32]} | {tag: "Series", values: readonly [u32]} | {tag: "SeriesBySession", values: readonly [u32]} | {tag: "PlayerSessions", values: readonly [string]} | {tag: "Badges", values: readonly [string]} | {tag: "BadgeOwed", values: readonly [u32]} | {tag: "ActivityLog", values: readonly [string]} | {tag: "SessionBucket", values: readonly [u32]} | {tag: "SessionPage", values: readonly [u32, u32]} | {tag: "TournamentCounter", values: void} | {tag: "Tournament", values: readonly [u32]} | {tag: "Bracket", values: readonly [u32]} | {tag: "TournamentPrizes", values: readonly [u32]} | {tag: "TournamentPrize", values: readonly [u32, string]} | {tag: "PrizeVesting", values: readonly [u32]} | {tag: "VestedPrize", values: readonly [u32, string]} | {tag: "NullifierRetention", values: void} | {tag: "Nullifier", values: readonly [Buffer]} | {tag: "RuleSet", values: void} | {tag: "TeamGame", values: readonly [u32]} | {tag: "CommitReceipt", values: readonly [u32, string]} | {tag: "Rematch", values: readonly [u32]} | {tag: "RematchOf", values: readonly [u32]} | {tag: "RivalrySeries", values: readonly [string, string]} | {tag: "LastOpponent", values: readonly [string]} | {tag: "MovePauses", values: void};
+
 export enum Attack {
   Slash = 0,
   Fireball = 1,
   Lightning = 2,
+  Broadside = 3,
+  Hook = 4,
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void} | {tag: "Ultrahonkverifier", values: void} | {tag: "Username", values: readonly [string]} | {tag: "AddressByUsername", values: readonly [string]} | {tag: "Challenge", values: readonly [u32]} | {tag: "ChallengeCounter", values: void} | {tag: "PlayerChallenges", values: readonly [string]} | {tag: "Match", values: readonly [u32]} | {tag: "MatchCounter", values: void} | {tag: "PlayerMatches", values: readonly [string]} | {tag: "TokenContractAddress", values: void};
-
 export enum Defense {
   Block = 0,
   Dodge = 1,
   Counter = 2,
+  Riposte = 3,
 }
 
 
-export interface PvPMatch {
-  current_turn: u32;
-  last_action: Option<Move>;
-  match_id: u32;
-  player1: string;
-  player1_hp: i32;
-  player2: string;
-  player2_hp: i32;
-  state: MatchState;
-  winner: Option<string>;
-}
-
-
-export interface Challenge {
-  challenge_id: u32;
-  challenged: string;
-  challenger: string;
-  created_at: u64;
-  expires_at: u64;
-  is_accepted: boolean;
-  is_completed: boolean;
-  points_wagered: i128;
-  session_id: Option<u32>;
+export interface Move {
+  attack: Attack;
+  defense: Defense;
 }
 
-export const ClashError = {
-  1: {message:"CommitmentExists"},
-  2: {message:"NullifierUsed"},
-  3: {message:"VerificationFailed"}
-}
 
-export enum MatchState {
-  Created = 0,
-  Accepted = 1,
-  Active = 2,
-  Finished = 3,
+export interface MoveSequence {
+  moves: Array<Move>;
 }
 
 
-export interface TurnResult {
-  player1_damage_dealt: i32;
-  player1_defense_successful: boolean;
-  player1_hp_remaining: i32;
-  player2_damage_dealt: i32;
-  player2_defense_successful: boolean;
-  player2_hp_remaining: i32;
-  turn: u32;
+export interface PlayerCommitment {
+  has_revealed: boolean;
+  moves: MoveSequence;
+  proof_id: Buffer;
 }
 
 
 export interface BattleResult {
+  entropy: RandomnessAudit;
   is_draw: boolean;
+  overtime_turns: Array<TurnResult>;
   player1_hp: i32;
   player2_hp: i32;
+  reason: OutcomeReason;
   turn_results: Array<TurnResult>;
+  went_to_overtime: boolean;
   winner: Option<string>;
 }
 
-
-export interface GamePlayback {
-  final_player1_hp: i32;
-  final_player2_hp: i32;
-  is_draw: boolean;
-  player1: string;
-  player1_username: Option<string>;
-  player2: string;
-  player2_username: Option<string>;
-  session_id: u32;
-  turn_results: Array<DetailedTurnResult>;
-  winner: Option<string>;
+/**
+ * Why a game, duel or team game ended
+ */
+export enum OutcomeReason {
+  Pending = 0,
+  BattleDecision = 1,
+  Draw = 2,
+  Forfeit = 3,
+  CommitTimeout = 4,
+  RevealTimeout = 5,
+  Voided = 6,
 }
 
+/**
+ * Pirate captain a player battles as, with a passive modifier (see `combat::Captains`)
+ */
+export enum CaptainClass {
+  None = 0,
+  Brawler = 1,
+  Trickster = 2,
+  Gunner = 3,
+}
 
-export interface MoveSequence {
-  moves: Array<Move>;
+/**
+ * How a battle still level after its regular turns is decided (see `combat::simulate`)
+ */
+export enum TieBreak {
+  SuddenDeath = 0,
+  Player1Wins = 1,
+  DamageDealt = 2,
+  TrueDraw = 3,
 }
 
 
-export interface PlayerCommitment {
-  has_revealed: boolean;
-  moves: MoveSequence;
-  proof_id: Buffer;
+/**
+ * How a random seed was derived, so anyone can recompute it:
+ * `seed = sha256(domain | inputs)`
+ */
+export interface RandomnessAudit {
+  domain: string;
+  inputs: Buffer;
+  seed: Buffer;
 }
 
 
-export interface DetailedTurnResult {
+export interface TurnResult {
   player1_damage_dealt: i32;
-  player1_damage_taken: i32;
   player1_defense_successful: boolean;
   player1_hp_remaining: i32;
-  player1_move: Move;
+  player1_momentum: boolean;
+  player1_reflected: i32;
+  player1_was_critical: boolean;
   player2_damage_dealt: i32;
-  player2_damage_taken: i32;
   player2_defense_successful: boolean;
   player2_hp_remaining: i32;
-  player2_move: Move;
+  player2_momentum: boolean;
+  player2_reflected: i32;
+  player2_was_critical: boolean;
   turn: u32;
 }
 
+export enum GameMode {
+  Classic = 0,
+  Siege = 1,
+  Correspondence = 2,
+}
 
+/**
+ * How a game's moves are committed and revealed
+ */
+export enum CommitMode {
+  Zk = 0,
+  Hash = 1,
+}
 
 
-
-
-
+/**
+ * A circuit version's verifier contract in the registry
+ */
+export interface VerifierEntry {
+  address: string;
+  deprecated: boolean;
+}
 
 /**
- * Error codes for smart account operations.
+ * Which deadline a [`WindowOpened`] event announces
  */
-export const SmartAccountError = {
-  /**
-   * The specified context rule does not exist.
-   */
-  3000: {message:"ContextRuleNotFound"},
-  /**
-   * The provided context cannot be validated against any rule.
-   */
-  3002: {message:"UnvalidatedContext"},
-  /**
-   * External signature verification failed.
-   */
-  3003: {message:"ExternalVerificationFailed"},
-  /**
-   * Context rule must have at least one signer or policy.
-   */
-  3004: {message:"NoSignersAndPolicies"},
-  /**
-   * The valid_until timestamp is in the past.
-   */
-  3005: {message:"PastValidUntil"},
-  /**
-   * The specified signer was not found.
-   */
-  3006: {message:"SignerNotFound"},
-  /**
-   * The signer already exists in the context rule.
-   */
-  3007: {message:"DuplicateSigner"},
-  /**
-   * The specified policy was not found.
-   */
-  3008: {message:"PolicyNotFound"},
-  /**
-   * The policy already exists in the context rule.
-   */
-  3009: {message:"DuplicatePolicy"},
-  /**
-   * Too many signers in the context rule.
-   */
-  3010: {message:"TooManySigners"},
-  /**
-   * Too many policies in the context rule.
-   */
-  3011: {message:"TooManyPolicies"},
-  /**
-   * An internal ID counter (context rule, signer, or policy) has reached
-   * its maximum value (`u32::MAX`) and cannot be incremented further.
-   */
-  3012: {message:"MathOverflow"},
-  /**
-   * External signer key data exceeds the maximum allowed size.
-   */
-  3013: {message:"KeyDataTooLarge"},
-  /**
-   * context_rule_ids length does not match auth_contexts length.
-   */
-  3014: {message:"ContextRuleIdsLengthMismatch"},
-  /**
-   * Context rule name exceeds the maximum allowed length.
-   */
-  3015: {message:"NameTooLong"},
-  /**
-   * A signer in `AuthPayload` is not part of any selected context rule.
-   */
-  3016: {message:"UnauthorizedSigner"}
+export enum GameWindow {
+  Commit = 0,
+  Reveal = 1,
 }
 
 
 
 
 
-/**
- * Represents different types of signers in the smart account system.
- */
-export type Signer = {tag: "Delegated", values: readonly [string]} | {tag: "External", values: readonly [string, Buffer]};
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+export interface Game {
+  battle_result: BattleResult;
+  circuit_version: u32;
+  commit_deadline: u64;
+  commit_mode: CommitMode;
+  crit_chance_bps: u32;
+  crit_multiplier_pct: i32;
+  deposit_amount: i128;
+  deposit_token: Option<string>;
+  has_battle_result: boolean;
+  has_player1_commitment: boolean;
+  has_player2_commitment: boolean;
+  initiative_bonus: i32;
+  is_cancelled: boolean;
+  is_settled: boolean;
+  mode: GameMode;
+  player1: string;
+  player1_class: CaptainClass;
+  player1_coach: Option<string>;
+  player1_commitment: PlayerCommitment;
+  player1_points: i128;
+  player1_username: Option<string>;
+  player2: string;
+  player2_class: CaptainClass;
+  player2_coach: Option<string>;
+  player2_commitment: PlayerCommitment;
+  player2_points: i128;
+  player2_username: Option<string>;
+  raise: Raise;
+  resolved_at: u64;
+  reveal_deadline: u64;
+  rules: RuleSet;
+  started_at: u64;
+}
+
+export enum RaiseStatus {
+  None = 0,
+  Pending = 1,
+  Called = 2,
+  Folded = 3,
+}
 
 
 /**
- * The authorization payload passed to `__check_auth`, bundling cryptographic
- * proofs with context rule selection.
- * 
- * This struct carries two distinct pieces of information that are both
- * required for authorization but cannot be derived from each other:
- * 
- * - `signers` maps each [`Signer`] to its raw signature bytes, providing
- * cryptographic proof that the signer actually signed the transaction
- * payload. A context rule stores which signer *identities* are authorized
- * (via `signer_ids`), but the rule does not contain the signatures
- * themselves — those must be supplied here.
- * 
- * - `context_rule_ids` tells the system which rule to validate for each auth
- * context. Because multiple rules can exist for the same context type, the
- * caller must explicitly select one per context rather than relying on
- * auto-discovery. Each entry is aligned by index with the `auth_contexts`
- * passed to `__check_auth`.
- * 
- * The length of `context_rule_ids` must equal the number of auth contexts;
- * a mismatch is rejected with
- * [`SmartAccountError::ContextRuleIdsLen
- */
-export interface AuthPayload {
-  /**
- * Per-context rule IDs, aligned by index with `auth_contexts`.
+ * Blind raise state of a game, with the raise settings snapshotted at start
  */
-context_rule_ids: Array<u32>;
-  /**
- * Signature data mapped to each signer.
+export interface Raise {
+  amount: i128;
+  fee_schedule: Array<FeeTier>;
+  max_amount: i128;
+  raiser: Option<string>;
+  status: RaiseStatus;
+  token: Option<string>;
+}
+
+/**
+ * Lifecycle of a challenge. Expiry isn't stored: a pending challenge past its
+ * `expires_at` is expired.
  */
-signers: Map<Signer, Buffer>;
+export enum ChallengeStatus {
+  Pending = 0,
+  Accepted = 1,
+  Completed = 2,
+  Declined = 3,
+  Cancelled = 4,
+}
+
+
+export interface Challenge {
+  challenge_id: u32;
+  challenged: string;
+  challenger: string;
+  created_at: u64;
+  created_ledger: u32;
+  expires_at: u64;
+  forwarded_from: Option<string>;
+  is_accepted: boolean;
+  is_completed: boolean;
+  is_rival_match: boolean;
+  nonce: u32;
+  points_wagered: i128;
+  session_id: Option<u32>;
+  status: ChallengeStatus;
+  tie_break: TieBreak;
 }
 
 
 /**
- * A complete context rule defining authorization requirements.
- */
-export interface ContextRule {
-  /**
- * The type of context this rule applies to.
- */
-context_type: ContextRuleType;
-  /**
- * Unique identifier for the context rule.
- */
-id: u32;
-  /**
- * Human-readable name for the context rule.
- */
-name: string;
-  /**
- * List of policy contracts that must be satisfied.
- */
-policies: Array<string>;
-  /**
- * Global registry IDs for each policy, positionally aligned with
- * `policies`.
- */
-policy_ids: Array<u32>;
-  /**
- * Global registry IDs for each signer, positionally aligned with
- * `signers`.
- */
-signer_ids: Array<u32>;
-  /**
- * List of signers authorized by this rule.
- */
-signers: Array<Signer>;
-  /**
- * Optional expiration ledger sequence for the rule.
+ * Offer to replay a finished game with the same players, stakes and mode
  */
-valid_until: Option<u32>;
+export interface Rematch {
+  expires_at: u64;
+  mode: GameMode;
+  player1: string;
+  player1_points: i128;
+  player2: string;
+  player2_points: i128;
+  rematch_session_id: Option<u32>;
+  requester: string;
+  session_id: u32;
 }
 
 
 /**
- * Combines policy data and its reference count into a single storage entry.
- */
-export interface PolicyEntry {
-  /**
- * Number of context rules referencing this policy.
- */
-count: u32;
-  /**
- * The policy address stored in the global registry.
+ * Spectator stakes on one game, escrowed in the prediction token
  */
-policy: string;
+export interface PredictionPool {
+  is_settled: boolean;
+  player1: string;
+  player1_total: i128;
+  player2: string;
+  player2_total: i128;
+  session_id: u32;
+  token: string;
+  winner: Option<string>;
 }
 
 
 /**
- * Combines signer data and its reference count into a single storage entry.
- */
-export interface SignerEntry {
-  /**
- * Number of context rules referencing this signer.
- */
-count: u32;
-  /**
- * The signer stored in the global registry.
+ * One spectator's stake in a [`PredictionPool`]
  */
-signer: Signer;
+export interface Prediction {
+  amount: i128;
+  backed_player: string;
 }
 
+
 /**
- * Types of contexts that can be authorized by smart account rules.
+ * What a challenge sent now would cost and pay, under the current configuration
  */
-export type ContextRuleType = {tag: "Default", values: void} | {tag: "CallContract", values: readonly [string]} | {tag: "CreateContract", values: readonly [Buffer]};
+export interface ChallengeQuote {
+  commit_window_secs: u64;
+  deposit_amount: i128;
+  deposit_token: Option<string>;
+  expires_at: u64;
+  max_raise: i128;
+  max_raise_fee: i128;
+  payout: i128;
+  points_wagered: i128;
+  raise_token: Option<string>;
+  reveal_window_secs: u64;
+  win_reward: i128;
+}
 
 
 /**
- * Combines context rule metadata, signer IDs, and policy addresses into a
- * single storage entry, reducing persistent reads per auth check from 3 to 1.
+ * A challenge posted to the public board for anyone to accept
  */
-export interface ContextRuleEntry {
-  /**
- * The type of context this rule applies to.
- */
-context_type: ContextRuleType;
-  /**
- * Human-readable name for the context rule.
- */
-name: string;
-  /**
- * Policy IDs referenced by this rule.
- */
-policy_ids: Array<u32>;
-  /**
- * Global signer IDs referenced by this rule.
- */
-signer_ids: Array<u32>;
-  /**
- * Optional expiration ledger sequence.
- */
-valid_until: Option<u32>;
+export interface OpenChallenge {
+  challenge_id: u32;
+  challenger: string;
+  created_at: u64;
+  expires_at: u64;
+  points_wagered: i128;
 }
 
+
 /**
- * Storage keys for smart account data.
+ * A listed friend and whether they are looking for a game
  */
-export type SmartAccountStorageKey = {tag: "ContextRuleData", values: readonly [u32]} | {tag: "NextId", values: void} | {tag: "Count", values: void} | {tag: "SignerData", values: readonly [u32]} | {tag: "SignerLookup", values: readonly [Buffer]} | {tag: "NextSignerId", values: void} | {tag: "PolicyData", values: readonly [u32]} | {tag: "PolicyLookup", values: readonly [string]} | {tag: "NextPolicyId", values: void};
+export interface FriendStatus {
+  friend: string;
+  looking_for_game: boolean;
+}
 
 
 /**
- * Individual spending entry for tracking purposes.
- */
-export interface SpendingEntry {
-  /**
- * The amount spent in this transaction.
- */
-amount: i128;
-  /**
- * The ledger sequence when this transaction occurred.
+ * Waiting count for one lobby tier
  */
-ledger_sequence: u32;
+export interface QueueTier {
+  tier: u32;
+  wager: i128;
+  waiting: u32;
 }
 
 
 /**
- * Internal storage structure for spending limit tracking.
- */
-export interface SpendingLimitData {
-  /**
- * Cached total of all amounts in spending_history.
- */
-cached_total_spent: i128;
-  /**
- * The period in ledgers over which the spending limit applies.
- */
-period_ledgers: u32;
-  /**
- * History of spending transactions with their ledger sequences.
- */
-spending_history: Array<SpendingEntry>;
-  /**
- * The spending limit for the period.
+ * A game `player1` has offered with `propose_game`, waiting on `confirm_game`
  */
-spending_limit: i128;
+export interface GameProposal {
+  expires_at: u64;
+  player1: string;
+  player1_points: i128;
+  player2: string;
+  player2_points: i128;
 }
 
+
 /**
- * Error codes for spending limit policy operations.
+ * Open challenge anyone holding its code can accept
  */
-export const SpendingLimitError = {
-  /**
-   * The smart account does not have a spending limit policy installed.
-   */
-  3220: {message:"SmartAccountNotInstalled"},
-  /**
-   * The spending limit has been exceeded.
-   */
-  3221: {message:"SpendingLimitExceeded"},
-  /**
-   * The spending limit or period is invalid.
-   */
-  3222: {message:"InvalidLimitOrPeriod"},
-  /**
-   * The transaction is not allowed by this policy.
-   */
-  3223: {message:"NotAllowed"},
-  /**
-   * The spending history has reached maximum capacity.
-   */
-  3224: {message:"HistoryCapacityExceeded"},
-  /**
-   * The context rule for the smart account has been already installed.
-   */
-  3225: {message:"AlreadyInstalled"},
-  /**
-   * The transfer amount is negative.
-   */
-  3226: {message:"LessThanZero"},
-  /**
-   * Only the `CallContract` context rule type is allowed.
-   */
-  3227: {message:"OnlyCallContractAllowed"}
+export interface InviteCode {
+  creator: string;
+  expires_at: u64;
+  points_wagered: i128;
 }
 
 
+export interface DetailedTurnResult {
+  player1_damage_dealt: i32;
+  player1_damage_taken: i32;
+  player1_defense_successful: boolean;
+  player1_hp_remaining: i32;
+  player1_momentum: boolean;
+  player1_move: Move;
+  player1_reflected: i32;
+  player1_was_critical: boolean;
+  player2_damage_dealt: i32;
+  player2_damage_taken: i32;
+  player2_defense_successful: boolean;
+  player2_hp_remaining: i32;
+  player2_momentum: boolean;
+  player2_move: Move;
+  player2_reflected: i32;
+  player2_was_critical: boolean;
+  turn: u32;
+}
 
 
-/**
- * Storage keys for spending limit policy data.
- */
-export type SpendingLimitStorageKey = {tag: "AccountContext", values: readonly [string, u32]};
+export interface GamePlayback {
+  final_player1_hp: i32;
+  final_player2_hp: i32;
+  is_draw: boolean;
+  player1: string;
+  player1_class: CaptainClass;
+  player1_username: Option<string>;
+  player2: string;
+  player2_class: CaptainClass;
+  player2_initiative_bonus: i32;
+  player2_username: Option<string>;
+  reason: OutcomeReason;
+  rematch_of: Option<u32>;
+  rematched_as: Option<u32>;
+  session_id: u32;
+  turn_results: Array<DetailedTurnResult>;
+  went_to_overtime: boolean;
+  winner: Option<string>;
+}
 
+export enum MatchState {
+  Created = 0,
+  Accepted = 1,
+  Active = 2,
+  Finished = 3,
+}
 
 
-/**
- * Installation parameters for the spending limit policy.
- */
-export interface SpendingLimitAccountParams {
-  /**
- * The period in ledgers over which the spending limit applies.
- */
-period_ledgers: u32;
-  /**
- * The maximum amount that can be spent within the specified period (in
- * stroops).
- */
-spending_limit: i128;
+export interface PvPMatch {
+  current_turn: u32;
+  has_last_action: boolean;
+  last_action: Move;
+  match_id: u32;
+  player1: string;
+  player1_hp: i32;
+  player2: string;
+  player2_hp: i32;
+  state: MatchState;
+  winner: Option<string>;
 }
 
-
+export enum DuelPhase {
+  Commit = 0,
+  Reveal = 1,
+  Finished = 2,
+}
 
 
 /**
- * Error codes for simple threshold policy operations.
+ * One player's side of the current duel round
  */
-export const SimpleThresholdError = {
-  /**
-   * The smart account does not have a simple threshold policy installed.
-   */
-  3200: {message:"SmartAccountNotInstalled"},
-  /**
-   * When threshold is 0 or exceeds the number of available signers.
-   */
-  3201: {message:"InvalidThreshold"},
-  /**
-   * The transaction is not allowed by this policy.
-   */
-  3202: {message:"NotAllowed"},
-  /**
-   * The context rule for the smart account has been already installed.
-   */
-  3203: {message:"AlreadyInstalled"}
+export interface DuelTurn {
+  action: Move;
+  commitment: Buffer;
+  has_committed: boolean;
+  has_revealed: boolean;
 }
 
 
 /**
- * Storage keys for simple threshold policy data.
+ * Turn-by-turn duel: one move per round is committed, revealed and resolved,
+ * so players can adapt to what the opponent did last round.
  */
-export type SimpleThresholdStorageKey = {tag: "AccountContext", values: readonly [string, u32]};
+export interface Duel {
+  battle_result: BattleResult;
+  deadline: u64;
+  phase: DuelPhase;
+  player1: string;
+  player1_moves: Array<Move>;
+  player1_points: i128;
+  player1_turn: DuelTurn;
+  player2: string;
+  player2_moves: Array<Move>;
+  player2_points: i128;
+  player2_turn: DuelTurn;
+  round: u32;
+  rules: RuleSet;
+}
 
 
 /**
- * Installation parameters for the simple threshold policy.
+ * A 2v2 battle. Every player commits and reveals their own ZK-proven move
+ * sequence; each team fights from one shared HP pool. The Game Hub sees the two
+ * captains (the first member of each team) as the session's players.
  */
-export interface SimpleThresholdAccountParams {
-  /**
- * The minimum number of signers required for authorization.
+export interface TeamGame {
+  battle_result: BattleResult;
+  circuit_version: u32;
+  commitments: Array<PlayerCommitment>;
+  has_battle_result: boolean;
+  has_committed: Array<boolean>;
+  rules: RuleSet;
+  team1: Array<string>;
+  team1_points: i128;
+  team2: Array<string>;
+  team2_points: i128;
+}
+
+
+/**
+ * Best-of-N series played as linked games under one Game Hub session
  */
-threshold: u32;
+export interface MatchSeries {
+  best_of: u32;
+  draws: u32;
+  is_cancelled: boolean;
+  is_finished: boolean;
+  player1: string;
+  player1_points: i128;
+  player1_wins: u32;
+  player2: string;
+  player2_points: i128;
+  player2_wins: u32;
+  sessions: Array<u32>;
+  winner: Option<string>;
+}
+
+export enum TournamentStatus {
+  Registration = 0,
+  InProgress = 1,
+  Completed = 2,
+  Cancelled = 3,
 }
 
+export enum BracketFormat {
+  SingleElimination = 0,
+  DoubleElimination = 1,
+}
 
+export enum BracketSide {
+  Winners = 0,
+  Losers = 1,
+  GrandFinal = 2,
+}
 
 
 /**
- * Error codes for weighted threshold policy operations.
+ * A tournament's prize pool and how it is split by final standing, kept beside the
+ * tournament
  */
-export const WeightedThresholdError = {
-  /**
-   * The smart account does not have a weighted threshold policy installed.
-   */
-  3210: {message:"SmartAccountNotInstalled"},
-  /**
-   * The threshold value is invalid.
-   */
-  3211: {message:"InvalidThreshold"},
-  /**
-   * A mathematical operation would overflow.
-   */
-  3212: {message:"MathOverflow"},
-  /**
-   * The transaction is not allowed by this policy.
-   */
-  3213: {message:"NotAllowed"},
-  /**
-   * The context rule for the smart account has been already installed.
-   */
-  3214: {message:"AlreadyInstalled"}
+export interface TournamentPrizes {
+  payouts_bps: Array<u32>;
+  pool: i128;
+  token: string;
 }
 
 
 /**
- * Storage keys for weighted threshold policy data.
+ * How a tournament's large prizes vest: a prize above `threshold` pays
+ * `immediate_bps` of itself straight away and the rest `lock_secs` after the
+ * tournament completes
  */
-export type WeightedThresholdStorageKey = {tag: "AccountContext", values: readonly [string, u32]};
+export interface PrizeVesting {
+  immediate_bps: u32;
+  lock_secs: u64;
+  threshold: i128;
+}
+
 
+/**
+ * The locked part of a player's tournament prize
+ */
+export interface VestedPrize {
+  amount: i128;
+  unlocks_at: u64;
+}
 
 
 /**
- * Installation parameters for the weighted threshold policy.
+ * Elimination tournament; its matches are kept separately as the bracket
  */
-export interface WeightedThresholdAccountParams {
-  /**
- * Mapping of signers to their respective weights.
+export interface Tournament {
+  champion: Option<string>;
+  checked_in: Array<string>;
+  entry_points: i128;
+  format: BracketFormat;
+  max_players: u32;
+  organizer: string;
+  players: Array<string>;
+  registration_deadline: u64;
+  round: u32;
+  status: TournamentStatus;
+  tournament_id: u32;
+}
+
+
+/**
+ * One match of a bracket. A match with a single player is a bye, and one left with
+ * none is skipped.
  */
-signer_weights: Map<Signer, u32>;
-  /**
- * The minimum total weight required for authorization.
+export interface BracketMatch {
+  index: u32;
+  is_walkover: boolean;
+  loser_to: Option<u32>;
+  pending: u32;
+  player1: Option<string>;
+  player2: Option<string>;
+  replayed: boolean;
+  round: u32;
+  session_id: Option<u32>;
+  side: BracketSide;
+  winner: Option<string>;
+  winner_to: Option<u32>;
+}
+
+
+/**
+ * A player's record on the rated ladder
  */
-threshold: u32;
+export interface PlayerStats {
+  draws: u32;
+  losses: u32;
+  rating: i32;
+  streak: i32;
+  wins: u32;
 }
 
+
 /**
- * Error types for WebAuthn verification operations.
+ * Compact, permanent record of a resolved battle, kept in both players' history
  */
-export const WebAuthnError = {
+export interface ArchivedGame {
+  is_draw: boolean;
+  mode: GameMode;
+  player1: string;
+  player1_moves: Array<Move>;
+  player2: string;
+  player2_moves: Array<Move>;
+  resolved_at: u64;
+  session_id: u32;
+  turn_results: Array<TurnResult>;
+  winner: Option<string>;
+}
+
+
+/**
+ * One player's results against a rival
+ */
+export interface HeadToHead {
+  draws: u32;
+  losses: u32;
+  wins: u32;
+}
+
+
+/**
+ * Unbroken run of games between two players, with its running score. Stored from
+ * the lower address's side; `get_rivalry_series` returns it from the caller's.
+ */
+export interface RivalrySeries {
+  a_wins: u32;
+  b_wins: u32;
+  draws: u32;
+  last_played_at: u64;
+  player_a: string;
+  player_b: string;
+  sessions: Array<u32>;
+  started_at: u64;
+}
+
+
+/**
+ * Where a player's rating stands among every rated player
+ */
+export interface RatingPercentile {
+  is_provisional: boolean;
+  rated_players: u32;
+  rating: i32;
+  top_pct: u32;
+}
+
+
+/**
+ * Rating decay for inactive players: a rating above the starting 1500 loses
+ * `points` for every `inactive_secs` without a rated game, down to 1500. Off while
+ * either is 0.
+ */
+export interface RatingDecay {
+  inactive_secs: u64;
+  points: u32;
+}
+
+
+/**
+ * Leaderboard entry
+ */
+export interface RankedPlayer {
+  player: string;
+  rating: i32;
+}
+
+
+/**
+ * A competitive period with its own ratings ladder
+ */
+export interface Season {
+  ends_at: u64;
+  has_ended: boolean;
+  season_id: u32;
+  started_at: u64;
+}
+
+
+/**
+ * Ranked snapshot of a season, frozen when it ends
+ */
+export interface SeasonResults {
+  ended_at: u64;
+  season_id: u32;
+  standings: Array<RankedPlayer>;
+  started_at: u64;
+}
+
+
+/**
+ * Token paid to a season's top finishers when it ends: `amounts[0]` to first place,
+ * and so on down the standings
+ */
+export interface SeasonRewards {
+  amounts: Array<i128>;
+  token: string;
+}
+
+
+/**
+ * A player's finish in an ended season, and the reward it pays
+ */
+export interface SeasonPlacement {
+  amount: i128;
+  claimed: boolean;
+  expires_at: u64;
+  player: string;
+  rank: u32;
+  rating: i32;
+  season_id: u32;
+  token: Option<string>;
+}
+
+/**
+ * Milestone badges awarded when a battle is resolved
+ */
+export enum Achievement {
+  FirstWin = 0,
+  WinStreak3 = 1,
+  Flawless = 2,
+  TripleComboFinisher = 3,
+  TenBattles = 4,
+  SharpEye = 5,
+  Oracle = 6,
+}
+
+
+/**
+ * A badge as held by a player
+ */
+export interface EarnedAchievement {
+  achievement: Achievement;
+  earned_at: u64;
+  session_id: u32;
+}
+
+
+/**
+ * Totals over every battle resolved on-chain
+ */
+export interface GlobalStats {
+  average_turns_x100: u64;
+  broadside_count: u64;
+  fireball_count: u64;
+  hook_count: u64;
+  lightning_count: u64;
+  slash_count: u64;
+  total_draws: u64;
+  total_games: u64;
+  total_turns: u64;
+}
+
+
+/**
+ * Siege results for one player, tracked apart from classic games
+ */
+export interface SiegeStats {
+  attacker_losses: u32;
+  attacker_wins: u32;
+  defender_losses: u32;
+  defender_wins: u32;
+}
+
+/**
+ * How hard the practice bot plays
+ */
+export enum PracticeDifficulty {
+  Easy = 0,
+  Normal = 1,
+  Hard = 2,
+}
+
+
+/**
+ * A game against the on-chain bot: no Game Hub, no wager, no reward
+ */
+export interface PracticeGame {
+  battle_result: BattleResult;
+  bot_moves: Array<Move>;
+  difficulty: PracticeDifficulty;
+  has_battle_result: boolean;
+  player: string;
+  player_moves: Array<Move>;
+  rules: RuleSet;
+  started_at: u64;
+}
+
+
+/**
+ * Practice results for one player at one difficulty, tracked apart from rated play
+ */
+export interface PracticeStats {
+  draws: u32;
+  losses: u32;
+  wins: u32;
+}
+
+
+/**
+ * Inclusive bounds on points wagered per player, set by the admin
+ */
+export interface WagerLimits {
+  max: i128;
+  min: i128;
+}
+
+
+/**
+ * Players a referrer brought in, and the bonus credited for them
+ */
+export interface ReferralStats {
+  completed: u32;
+  earned: i128;
+  referred: u32;
+  unclaimed: i128;
+}
+
+
+/**
+ * A spectator's free call on who wins a game
+ */
+export interface SpectatorPick {
+  backed_player: string;
+  spectator: string;
+}
+
+
+/**
+ * A spectator's record over their scored picks; draws are not counted
+ */
+export interface PickStats {
+  best_streak: u32;
+  correct: u32;
+  picks: u32;
+  streak: u32;
+}
+
+
+/**
+ * Navbar counts for one player, kept current by the entrypoints that change them
+ */
+export interface Badges {
+  awaiting_action: u32;
+  pending_challenges: u32;
+  unclaimed_rewards: u32;
+}
+
+
+/**
+ * Free-to-play tier: an admin-funded pool stakes new players in micro-wager games
+ */
+export interface SponsorTier {
+  daily_games: u32;
+  max_rated_games: u32;
+  stake: i128;
+  token: string;
+}
+
+
+/**
+ * Agreed pauses of a correspondence game
+ */
+export interface GamePause {
+  paused_until: u64;
+  proposed_by: Option<string>;
+  proposed_secs: u64;
+  used_secs: u64;
+}
+
+
+/**
+ * Stakes the pool put up for a sponsored game, held until it settles
+ */
+export interface Sponsorship {
+  stake: i128;
+  token: string;
+}
+
+
+/**
+ * Circuit breakers set by the admin. `all` pauses every mutating entrypoint the
+ * others cover; views keep working either way.
+ */
+export interface PauseFlags {
+  all: boolean;
+  commits: boolean;
+  new_games: boolean;
+  resolution: boolean;
+}
+
+
+/**
+ * Damage of one attack and the defense that stops it
+ */
+export interface AttackRule {
+  attack: Attack;
+  blocked_by: Defense;
+  damage: i32;
+}
+
+
+/**
+ * Combat numbers set by the admin. Each game and duel keeps the rule set it started
+ * under, so a rebalance never changes a battle already in play.
+ */
+export interface RuleSet {
+  broadside_damage: i32;
+  combo_2_bonus: i32;
+  combo_3_bonus: i32;
+  fireball_damage: i32;
+  hook_damage: i32;
+  lightning_damage: i32;
+  momentum_bonus: i32;
+  riposte_reflect_pct: i32;
+  slash_damage: i32;
+  starting_hp: i32;
+  tie_break: TieBreak;
+  turns_per_battle: u32;
+  version: u32;
+}
+
+
+/**
+ * The combat rules new games are played under, for help screens and move planners
+ */
+export interface Rules {
+  attacks: Array<AttackRule>;
+  combo_2_bonus: i32;
+  combo_3_bonus: i32;
+  crit_chance_bps: u32;
+  crit_multiplier_pct: i32;
+  initiative_bonus: i32;
+  momentum_bonus: i32;
+  riposte_reflect_pct: i32;
+  starting_hp: i32;
+  tie_break: TieBreak;
+  turns_per_battle: u32;
+  version: u32;
+}
+
+
+/**
+ * Gameplay tuning set by the admin; values are snapshotted onto each game at start
+ */
+export interface GameConfig {
+  commit_mode: CommitMode;
+  crit_chance_bps: u32;
+  crit_multiplier_pct: i32;
+  initiative_bonus: i32;
+  reveal_window_secs: u64;
+}
+
+
+/**
+ * Token and upper bound for blind raises
+ */
+export interface RaiseConfig {
+  max_amount: i128;
+  token: string;
+}
+
+
+/**
+ * Breakpoint of the fee schedule: wagers of at least `min_wager` (up to the next
+ * tier) pay `fee_bps` of the pot
+ */
+export interface FeeTier {
+  fee_bps: u32;
+  min_wager: i128;
+}
+
+export enum PromoReward {
+  FeeWaiver = 0,
+  DepositBonus = 1,
+}
+
+
+export interface PromoCode {
+  amount: i128;
+  max_uses: u32;
+  reward: PromoReward;
+  uses: u32;
+}
+
+
+/**
+ * Refundable deposit each player pays at commit time
+ */
+export interface CommitDeposit {
+  amount: i128;
+  token: string;
+}
+
+
+/**
+ * Stakes the Game Hub holds for one session
+ */
+export interface EscrowReceipt {
+  asset: string;
+  locked_at: u64;
+  player1: string;
+  player1_amount: i128;
+  player2: string;
+  player2_amount: i128;
+  released_at: u64;
+  session_id: u32;
+}
+
+
+/**
+ * Proof that a player committed, anchored to the ledger the commit landed in
+ */
+export interface CommitReceipt {
+  commitment: Buffer;
+  ledger: u32;
+  player: string;
+  session_id: u32;
+  timestamp: u64;
+}
+
+
+/**
+ * Outcome of a match played off-chain, signed by both players
+ */
+export interface SignedResult {
+  is_draw: boolean;
+  player1_hp: i32;
+  player2_hp: i32;
+  winner: Option<string>;
+}
+
+
+/**
+ * A co-signed result that either player can still dispute
+ */
+export interface PendingSettlement {
+  challenge_deadline: u64;
+  result: SignedResult;
+}
+
+
+/**
+ * A game with the session it's stored under, for bulk views
+ */
+export interface SessionGame {
+  game: Game;
+  session_id: u32;
+}
+
+
+/**
+ * Everything stored for one game, for backup and restore across migrations
+ */
+export interface GameExport {
+  exported_at: u32;
+  game: Game;
+  pending_settlement: ExportedSettlement;
+  session_id: u32;
+}
+
+/**
+ * The signed result a game was waiting on when exported, if any. An
+ * `Option<PendingSettlement>` in effect: an optional struct field builds for the
+ * contract, but not with `testutils`, whose XDR conversions need an `Into<ScVal>`
+ * that contract types don't implement.
+ */
+export type ExportedSettlement = {tag: "None", values: void} | {tag: "Pending", values: readonly [PendingSettlement]};
+
+/**
+ * What kind of entity an activity log entry points at
+ */
+export enum ActivityKind {
+  Game = 0,
+  Challenge = 1,
+  Duel = 2,
+  TeamGame = 3,
+}
+
+
+/**
+ * A session as listed in the registry, with the ledger it was created in
+ */
+export interface SessionRecord {
+  kind: ActivityKind;
+  ledger: u32;
+  session_id: u32;
+}
+
+
+/**
+ * Answer to `get_sessions_in_range`
+ */
+export interface SessionRange {
+  next_page: Option<u32>;
+  sessions: Array<SessionRecord>;
+}
+
+
+/**
+ * An entity that changed for a player, and the player's sequence it changed at
+ */
+export interface EntityChange {
+  id: u32;
+  kind: ActivityKind;
+  seq: u64;
+}
+
+
+/**
+ * A player's recent changes, latest per entity, oldest first
+ */
+export interface ActivityLog {
+  changes: Array<EntityChange>;
+  dropped_seq: u64;
+  last_activity_seq: u64;
+}
+
+
+/**
+ * Answer to `get_updates_since`
+ */
+export interface PlayerUpdates {
+  changes: Array<EntityChange>;
+  is_complete: boolean;
+  last_activity_seq: u64;
+}
+
+
+/**
+ * Build metadata recorded when the contract is deployed or upgraded
+ */
+export interface BuildInfo {
+  installed_at: u64;
+  installed_ledger: u32;
+  wasm_hash: Option<Buffer>;
+}
+
+
+/**
+ * Progress of `migrate` towards the storage layout this WASM writes
+ */
+export interface MigrationStatus {
+  cursor: u32;
+  latest_version: u32;
+  version: u32;
+}
+
+
+/**
+ * Readiness of the contract's dependencies and settings, for maintenance banners
+ */
+export interface ContractStatus {
+  circuit_version: u32;
+  commit_mode: CommitMode;
+  hub: string;
+  hub_last_ok_at: u64;
+  pause: PauseFlags;
+  rules_version: u32;
+  season_id: u32;
+  season_running: boolean;
+  verifier_ready: boolean;
+}
+
+
+export interface ContractVersion {
+  build: BuildInfo;
+  version: string;
+}
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+/**
+ * Error codes for smart account operations.
+ */
+export const SmartAccountError = {
+  /**
+   * The specified context rule does not exist.
+   */
+  3000: {message:"ContextRuleNotFound"},
+  /**
+   * The provided context cannot be validated against any rule.
+   */
+  3002: {message:"UnvalidatedContext"},
+  /**
+   * External signature verification failed.
+   */
+  3003: {message:"ExternalVerificationFailed"},
+  /**
+   * Context rule must have at least one signer or policy.
+   */
+  3004: {message:"NoSignersAndPolicies"},
+  /**
+   * The valid_until timestamp is in the past.
+   */
+  3005: {message:"PastValidUntil"},
+  /**
+   * The specified signer was not found.
+   */
+  3006: {message:"SignerNotFound"},
+  /**
+   * The signer already exists in the context rule.
+   */
+  3007: {message:"DuplicateSigner"},
+  /**
+   * The specified policy was not found.
+   */
+  3008: {message:"PolicyNotFound"},
+  /**
+   * The policy already exists in the context rule.
+   */
+  3009: {message:"DuplicatePolicy"},
+  /**
+   * Too many signers in the context rule.
+   */
+  3010: {message:"TooManySigners"},
+  /**
+   * Too many policies in the context rule.
+   */
+  3011: {message:"TooManyPolicies"},
+  /**
+   * An internal ID counter (context rule, signer, or policy) has reached
+   * its maximum value (`u32::MAX`) and cannot be incremented further.
+   */
+  3012: {message:"MathOverflow"},
+  /**
+   * External signer key data exceeds the maximum allowed size.
+   */
+  3013: {message:"KeyDataTooLarge"},
+  /**
+   * context_rule_ids length does not match auth_contexts length.
+   */
+  3014: {message:"ContextRuleIdsLengthMismatch"},
+  /**
+   * Context rule name exceeds the maximum allowed length.
+   */
+  3015: {message:"NameTooLong"},
+  /**
+   * A signer in `AuthPayload` is not part of any selected context rule.
+   */
+  3016: {message:"UnauthorizedSigner"}
+}
+
+
+
+
+
+
+
+
+
+/**
+ * Represents different types of signers in the smart account system.
+ */
+export type Signer = {tag: "Delegated", values: readonly [string]} | {tag: "External", values: readonly [string, Buffer]};
+
+
+/**
+ * The authorization payload passed to `__check_auth`, bundling cryptographic
+ * proofs with context rule selection.
+ * 
+ * This struct carries two distinct pieces of information that are both
+ * required for authorization but cannot be derived from each other:
+ * 
+ * - `signers` maps each [`Signer`] to its raw signature bytes, providing
+ * cryptographic proof that the signer actually signed the transaction
+ * payload. A context rule stores which signer *identities* are authorized
+ * (via `signer_ids`), but the rule does not contain the signatures
+ * themselves — those must be supplied here.
+ * 
+ * - `context_rule_ids` tells the system which rule to validate for each auth
+ * context. Because multiple rules can exist for the same context type, the
+ * caller must explicitly select one per context rather than relying on
+ * auto-discovery. Each entry is aligned by index with the `auth_contexts`
+ * passed to `__check_auth`.
+ * 
+ * The length of `context_rule_ids` must equal the number of auth contexts;
+ * a mismatch is rejected with
+ * [`SmartAccountError::ContextRuleIdsLen
+ */
+export interface AuthPayload {
+  /**
+ * Per-context rule IDs, aligned by index with `auth_contexts`.
+ */
+context_rule_ids: Array<u32>;
+  /**
+ * Signature data mapped to each signer.
+ */
+signers: Map<Signer, Buffer>;
+}
+
+
+/**
+ * A complete context rule defining authorization requirements.
+ */
+export interface ContextRule {
+  /**
+ * The type of context this rule applies to.
+ */
+context_type: ContextRuleType;
+  /**
+ * Unique identifier for the context rule.
+ */
+id: u32;
+  /**
+ * Human-readable name for the context rule.
+ */
+name: string;
+  /**
+ * List of policy contracts that must be satisfied.
+ */
+policies: Array<string>;
+  /**
+ * Global registry IDs for each policy, positionally aligned with
+ * `policies`.
+ */
+policy_ids: Array<u32>;
+  /**
+ * Global registry IDs for each signer, positionally aligned with
+ * `signers`.
+ */
+signer_ids: Array<u32>;
+  /**
+ * List of signers authorized by this rule.
+ */
+signers: Array<Signer>;
+  /**
+ * Optional expiration ledger sequence for the rule.
+ */
+valid_until: Option<u32>;
+}
+
+
+/**
+ * Combines policy data and its reference count into a single storage entry.
+ */
+export interface PolicyEntry {
+  /**
+ * Number of context rules referencing this policy.
+ */
+count: u32;
+  /**
+ * The policy address stored in the global registry.
+ */
+policy: string;
+}
+
+
+/**
+ * Combines signer data and its reference count into a single storage entry.
+ */
+export interface SignerEntry {
+  /**
+ * Number of context rules referencing this signer.
+ */
+count: u32;
+  /**
+ * The signer stored in the global registry.
+ */
+signer: Signer;
+}
+
+/**
+ * Types of contexts that can be authorized by smart account rules.
+ */
+export type ContextRuleType = {tag: "Default", values: void} | {tag: "CallContract", values: readonly [string]} | {tag: "CreateContract", values: readonly [Buffer]};
+
+
+/**
+ * Combines context rule metadata, signer IDs, and policy addresses into a
+ * single storage entry, reducing persistent reads per auth check from 3 to 1.
+ */
+export interface ContextRuleEntry {
+  /**
+ * The type of context this rule applies to.
+ */
+context_type: ContextRuleType;
+  /**
+ * Human-readable name for the context rule.
+ */
+name: string;
+  /**
+ * Policy IDs referenced by this rule.
+ */
+policy_ids: Array<u32>;
+  /**
+ * Global signer IDs referenced by this rule.
+ */
+signer_ids: Array<u32>;
+  /**
+ * Optional expiration ledger sequence.
+ */
+valid_until: Option<u32>;
+}
+
+/**
+ * Storage keys for smart account data.
+ */
+export type SmartAccountStorageKey = {tag: "ContextRuleData", values: readonly [u32]} | {tag: "NextId", values: void} | {tag: "Count", values: void} | {tag: "SignerData", values: readonly [u32]} | {tag: "SignerLookup", values: readonly [Buffer]} | {tag: "NextSignerId", values: void} | {tag: "PolicyData", values: readonly [u32]} | {tag: "PolicyLookup", values: readonly [string]} | {tag: "NextPolicyId", values: void};
+
+
+/**
+ * Individual spending entry for tracking purposes.
+ */
+export interface SpendingEntry {
+  /**
+ * The amount spent in this transaction.
+ */
+amount: i128;
+  /**
+ * The ledger sequence when this transaction occurred.
+ */
+ledger_sequence: u32;
+}
+
+
+/**
+ * Internal storage structure for spending limit tracking.
+ */
+export interface SpendingLimitData {
+  /**
+ * Cached total of all amounts in spending_history.
+ */
+cached_total_spent: i128;
+  /**
+ * The period in ledgers over which the spending limit applies.
+ */
+period_ledgers: u32;
+  /**
+ * History of spending transactions with their ledger sequences.
+ */
+spending_history: Array<SpendingEntry>;
+  /**
+ * The spending limit for the period.
+ */
+spending_limit: i128;
+}
+
+/**
+ * Error codes for spending limit policy operations.
+ */
+export const SpendingLimitError = {
+  /**
+   * The smart account does not have a spending limit policy installed.
+   */
+  3220: {message:"SmartAccountNotInstalled"},
+  /**
+   * The spending limit has been exceeded.
+   */
+  3221: {message:"SpendingLimitExceeded"},
+  /**
+   * The spending limit or period is invalid.
+   */
+  3222: {message:"InvalidLimitOrPeriod"},
+  /**
+   * The transaction is not allowed by this policy.
+   */
+  3223: {message:"NotAllowed"},
+  /**
+   * The spending history has reached maximum capacity.
+   */
+  3224: {message:"HistoryCapacityExceeded"},
+  /**
+   * The context rule for the smart account has been already installed.
+   */
+  3225: {message:"AlreadyInstalled"},
+  /**
+   * The transfer amount is negative.
+   */
+  3226: {message:"LessThanZero"},
+  /**
+   * Only the `CallContract` context rule type is allowed.
+   */
+  3227: {message:"OnlyCallContractAllowed"}
+}
+
+
+
+
+
+
+
+/**
+ * Storage keys for spending limit policy data.
+ */
+export type SpendingLimitStorageKey = {tag: "AccountContext", values: readonly [string, u32]};
+
+
+
+
+/**
+ * Installation parameters for the spending limit policy.
+ */
+export interface SpendingLimitAccountParams {
+  /**
+ * The period in ledgers over which the spending limit applies.
+ */
+period_ledgers: u32;
+  /**
+ * The maximum amount that can be spent within the specified period (in
+ * stroops).
+ */
+spending_limit: i128;
+}
+
+
+
+
+
+
+
+/**
+ * Error codes for simple threshold policy operations.
+ */
+export const SimpleThresholdError = {
+  /**
+   * The smart account does not have a simple threshold policy installed.
+   */
+  3200: {message:"SmartAccountNotInstalled"},
+  /**
+   * When threshold is 0 or exceeds the number of available signers.
+   */
+  3201: {message:"InvalidThreshold"},
+  /**
+   * The transaction is not allowed by this policy.
+   */
+  3202: {message:"NotAllowed"},
+  /**
+   * The context rule for the smart account has been already installed.
+   */
+  3203: {message:"AlreadyInstalled"}
+}
+
+
+
+/**
+ * Storage keys for simple threshold policy data.
+ */
+export type SimpleThresholdStorageKey = {tag: "AccountContext", values: readonly [string, u32]};
+
+
+/**
+ * Installation parameters for the simple threshold policy.
+ */
+export interface SimpleThresholdAccountParams {
+  /**
+ * The minimum number of signers required for authorization.
+ */
+threshold: u32;
+}
+
+
+
+
+
+
+
+/**
+ * Error codes for weighted threshold policy operations.
+ */
+export const WeightedThresholdError = {
+  /**
+   * The smart account does not have a weighted threshold policy installed.
+   */
+  3210: {message:"SmartAccountNotInstalled"},
+  /**
+   * The threshold value is invalid.
+   */
+  3211: {message:"InvalidThreshold"},
+  /**
+   * A mathematical operation would overflow.
+   */
+  3212: {message:"MathOverflow"},
+  /**
+   * The transaction is not allowed by this policy.
+   */
+  3213: {message:"NotAllowed"},
+  /**
+   * The context rule for the smart account has been already installed.
+   */
+  3214: {message:"AlreadyInstalled"}
+}
+
+
+
+/**
+ * Storage keys for weighted threshold policy data.
+ */
+export type WeightedThresholdStorageKey = {tag: "AccountContext", values: readonly [string, u32]};
+
+
+
+
+/**
+ * Installation parameters for the weighted threshold policy.
+ */
+export interface WeightedThresholdAccountParams {
+  /**
+ * Mapping of signers to their respective weights.
+ */
+signer_weights: Map<Signer, u32>;
+  /**
+ * The minimum total weight required for authorization.
+ */
+threshold: u32;
+}
+
+/**
+ * Error types for WebAuthn verification operations.
+ */
+export const WebAuthnError = {
+  /**
+   * The signature payload is invalid or has incorrect format.
+   */
+  3110: {message:"SignaturePayloadInvalid"},
+  /**
+   * The client data exceeds the maximum allowed length.
+   */
+  3111: {message:"ClientDataTooLong"},
+  /**
+   * Failed to parse JSON from client data.
+   */
+  3112: {message:"JsonParseError"},
+  /**
+   * The type field in client data is not "webauthn.get".
+   */
+  3113: {message:"TypeFieldInvalid"},
+  /**
+   * The challenge in client data does not match expected value.
+   */
+  3114: {message:"ChallengeInvalid"},
+  /**
+   * The authenticator data format is invalid or too short.
+   */
+  3115: {message:"AuthDataFormatInvalid"},
+  /**
+   * The User Present (UP) bit is not set in authenticator flags.
+   */
+  3116: {message:"PresentBitNotSet"},
+  /**
+   * The User Verified (UV) bit is not set in authenticator flags.
+   */
+  3117: {message:"VerifiedBitNotSet"},
+  /**
+   * Invalid relationship between Backup Eligibility and State bits.
+   */
+  3118: {message:"BackupEligibilityAndStateNotSet"},
+  /**
+   * The provided key data does not contain a valid 65-byte public key.
+   */
+  3119: {message:"KeyDataInvalid"}
+}
+
+
+/**
+ * WebAuthn signature data structure containing all components needed for
+ * verification.
+ * 
+ * This structure encapsulates the signature and associated data generated
+ * during a WebAuthn authentication ceremony.
+ */
+export interface WebAuthnSigData {
+  /**
+ * Raw authenticator data from the WebAuthn response.
+ */
+authenticator_data: Buffer;
+  /**
+ * Raw client data JSON from the WebAuthn response.
+ */
+client_data: Buffer;
+  /**
+ * The cryptographic signature (64 bytes for secp256r1).
+ */
+signature: Buffer;
+}
+export interface Client {
+  /**
+   * Construct and simulate a status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Everything a frontend needs to decide whether to show a maintenance banner,
+   * in one call
+   */
+  status: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<ContractStatus>>
+
+  /**
+   * Construct and simulate a forfeit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Concede an unfinished game; the opponent wins and the Game Hub is told
+   */
+  forfeit: ({session_id, player}: {session_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<BattleResult>>>
+
+  /**
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_hub: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * After an upgrade that changes the storage layout, rewrite up to `batch_size`
+   * old entries. Call until `version` reaches `latest_version`.
+   */
+  migrate: ({batch_size}: {batch_size: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<MigrationStatus>>
+
+  /**
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_hub: ({new_hub}: {new_hub: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * API semver of the running contract plus the build metadata recorded at deploy/upgrade time,
+   * so clients can feature-detect before calling newer entrypoints.
+   */
+  version: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<ContractVersion>>
+
+  /**
+   * Construct and simulate a check_in transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Confirm a registered player will play, in the 30 minutes before the
+   * tournament starts. Only checked-in players are seeded.
+   */
+  check_in: ({tournament_id, player}: {tournament_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_duel transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_duel: ({session_id}: {session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Duel>>>
+
+  /**
+   * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get game information
+   */
+  get_game: ({session_id}: {session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Game>>>
+
+  /**
+   * Construct and simulate a end_match transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  end_match: ({player, match_id}: {player: string, match_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_admin: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a get_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Several games at once, each with its session ID; sessions with no game are
+   * left out, and at most 20 are read
+   */
+  get_games: ({session_ids}: {session_ids: Array<u32>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<SessionGame>>>
+
+  /**
+   * Construct and simulate a get_match transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_match: ({match_id}: {match_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<PvPMatch>>>
+
+  /**
+   * Construct and simulate a get_pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_pause: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<PauseFlags>>
+
+  /**
+   * Construct and simulate a get_picks transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Picks made on a game that has not been scored yet
+   */
+  get_picks: ({session_id}: {session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<SpectatorPick>>>
+
+  /**
+   * Construct and simulate a get_rival transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_rival: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_rules transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Damage table, blocks, combo, momentum and crit settings new games are played with
+   */
+  get_rules: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Rules>>
+
+  /**
+   * Construct and simulate a make_pick transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Call the winner of a game for free. Open to anyone but the two players, until
+   * the second reveal lands; scored into `get_pick_stats` when the game resolves.
+   */
+  make_pick: ({session_id, spectator, backed_player}: {session_id: u32, spectator: string, backed_player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a play_turn transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  play_turn: ({player, match_id, action}: {player: string, match_id: u32, action: Move}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a quote_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cut the current schedule takes from the pot when each player stakes `wager`
+   */
+  quote_fee: ({wager}: {wager: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_admin: ({new_admin}: {new_admin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_coach transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Name (or with `None`, remove) `player`'s coach for `session_id`
+   */
+  set_coach: ({session_id, player, coach}: {session_id: u32, player: string, coach: Option<string>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Trip or reset the circuit breakers. Replaces all flags at once; pass the
+   * default to unpause.
+   */
+  set_pause: ({flags}: {flags: PauseFlags}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_rules transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replace the combat rules. Applies to games and duels started afterwards; the
+   * `version` passed in is ignored and the new rules get the next version, which
+   * is returned.
+   */
+  set_rules: ({rules}: {rules: RuleSet}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a add_friend transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Add `friend` to your friends list (up to 50)
+   */
+  add_friend: ({player, friend}: {player: string, friend: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a add_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  add_policy: ({context_rule_id, policy, install_param}: {context_rule_id: u32, policy: string, install_param: any}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a add_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  add_signer: ({context_rule_id, signer}: {context_rule_id: u32, signer: Signer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a call_raise transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Match the opponent's raise
+   */
+  call_raise: ({session_id, player}: {session_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a end_season transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Close the running season, freezing its `top_n` best season ratings. The
+   * season rewards, if set, are taken from the admin and become claimable.
+   */
+  end_season: ({top_n}: {top_n: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<SeasonResults>>>
+
+  /**
+   * Construct and simulate a fold_raise transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Decline the opponent's raise, conceding the game at the original stake
+   */
+  fold_raise: ({session_id, player}: {session_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<BattleResult>>>
+
+  /**
+   * Construct and simulate a get_badges transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pending challenges, games awaiting `player` and rewards to claim, as counts
+   * for the navbar. They can lag the clock; `get_player_challenges` and
+   * `get_awaiting_action` are exact.
+   */
+  get_badges: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Badges>>
+
+  /**
+   * Construct and simulate a get_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Escrow receipt for a game or duel's locked stakes
+   */
+  get_escrow: ({session_id}: {session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<EscrowReceipt>>>
+
+  /**
+   * Construct and simulate a get_season transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The running season, or the last one if it has ended
+   */
+  get_season: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<Season>>>
+
+  /**
+   * Construct and simulate a get_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_series: ({series_id}: {series_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<MatchSeries>>>
+
+  /**
+   * Construct and simulate a join_queue transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Queue at a wager tier (0: 10, 1: 100, 2: 1000 points). Returns the session id if
+   * someone was already waiting and a game started, `None` if now waiting.
+   */
+  join_queue: ({player, tier}: {player: string, tier: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Option<u32>>>>
+
+  /**
+   * Construct and simulate a start_duel transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a turn-by-turn duel. Same Game Hub session and points as `start_game`,
+   * but moves are committed and revealed one round at a time.
+   */
+  start_duel: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game between two players with points. Both players sign their own
+   * stake in this one transaction; see `propose_game` otherwise.
+   */
+  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a sweep_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Anyone can send all fees collected in `token` to the treasury; returns the
+   * amount sent
+   */
+  sweep_fees: ({token}: {token: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a cancel_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Call off a game nobody has committed to once it has sat for a day (or, for
+   * correspondence, past its commit deadline). Either player or the admin can
+   * cancel; the Game Hub refunds both stakes and no result is recorded.
+   */
+  cancel_game: ({session_id, caller}: {session_id: u32, caller: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claim_prize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Collect the prize credited to `player` when the tournament completed, or the
+   * organizer's pool back if it was cancelled
+   */
+  claim_prize: ({tournament_id, player}: {tournament_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a clear_rival transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  clear_rival: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a expire_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Anyone: void a game still unresolved 14 days after it started (plus any agreed
+   * pauses), whichever phase it stalled in. Every stake is refunded and no result
+   * is recorded.
+   */
+  expire_game: ({session_id}: {session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a export_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Full stored state of a game in one struct, for disaster recovery
+   */
+  export_game: ({session_id}: {session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<GameExport>>>
+
+  /**
+   * Construct and simulate a get_bracket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The whole bracket, laid out when it is seeded: winners' bracket round by
+   * round, then the losers' bracket and the grand final and its reset
+   */
+  get_bracket: ({tournament_id}: {tournament_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<BracketMatch>>>
+
+  /**
+   * Construct and simulate a get_rematch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_rematch: ({session_id}: {session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<Rematch>>>
+
+  /**
+   * Construct and simulate a import_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Restore a game from `export_game`, overwriting whatever is stored for that
+   * session. For recovering from migrations or expired temporary storage; the
+   * Game Hub is not notified.
+   */
+  import_game: ({export}: {export: GameExport}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a is_shadowed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Moderator only, so the flag stays hidden from the player it is set on
+   */
+  is_shadowed: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a leave_queue transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  leave_queue: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a raise_wager transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * After both commits and before any reveal, raise the stake by `amount` of the
+   * raise token. The opponent must call or fold before anyone can reveal.
+   */
+  raise_wager: ({session_id, player, amount}: {session_id: u32, player: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a redeem_code transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  redeem_code: ({player, code}: {player: string, code: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a resume_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * End a running pause early, taking the unused time back off the deadlines
+   */
+  resume_game: ({session_id, player}: {session_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_siege transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a siege: `attacker` only attacks and `defender` only defends, winning by
+   * surviving every turn. Commit, reveal and resolve work as for `start_game`.
+   */
+  start_siege: ({session_id, attacker, defender, attacker_points, defender_points}: {session_id: u32, attacker: string, defender: string, attacker_points: i128, defender_points: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a accept_pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept the opponent's pause: every open deadline moves out by its length.
+   * Returns when the pause ends.
+   */
+  accept_pause: ({session_id, player}: {session_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a claim_vested transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Collect the locked part of `player`'s prize once it has vested
+   */
+  claim_vested: ({tournament_id, player}: {tournament_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a commit_moves transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Commit move sequence with ZK proof
+   * Player proves they know valid moves WITHOUT revealing them.
+   * The proof's public output (commitment hash) is stored.
+   */
+  commit_moves: ({session_id, player, public_inputs, proof_bytes}: {session_id: u32, player: string, public_inputs: Buffer, proof_bytes: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<CommitReceipt>>>
+
+  /**
+   * Construct and simulate a confirm_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept a proposed game, starting it as `start_game` would
+   */
+  confirm_game: ({session_id, player2}: {session_id: u32, player2: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a confirm_link transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  confirm_link: ({secondary, primary}: {secondary: string, primary: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a decay_rating transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Write the decay `player` owes for inactivity into their stored record, the
+   * histogram and the leaderboard. Reads already show it; anyone can call this
+   * to bring the leaderboard up to date.
+   */
+  decay_rating: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<PlayerStats>>
+
+  /**
+   * Construct and simulate a get_identity transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The primary address `player` plays as; `player` itself if not linked
+   */
+  get_identity: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a get_referrer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_referrer: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_treasury: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_username transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get username for an address (a linked address shares its primary's)
+   */
+  get_username: ({address}: {address: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_verifier: ({version}: {version: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<VerifierEntry>>>
+
+  /**
+   * Construct and simulate a get_winnings transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Token amounts `player` can still claim from a game
+   */
+  get_winnings: ({session_id, player}: {session_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Map<string, i128>>>
+
+  /**
+   * Construct and simulate a link_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Ask to link `secondary` to the caller's identity; `secondary` confirms with
+   * `confirm_link`. It must have no games, rating or name of its own, and no live
+   * sessions or challenges.
+   */
+  link_address: ({primary, secondary}: {primary: string, secondary: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a propose_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Offer `player2` a classic game on `session_id`; nothing is locked until they
+   * call `confirm_game`
+   */
+  propose_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_moves transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal moves — player re-proves with moves now PUBLIC.
+   * The contract verifies the new proof's commitment output
+   * matches what was stored at commit time.
+   */
+  reveal_moves: ({session_id, player, public_inputs, moves}: {session_id: u32, player: string, public_inputs: Buffer, moves: Array<Move>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_shadowed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Moderator: quietly keep `player` out of rated play, the ladders and
+   * matchmaking while they are investigated, or lift that
+   */
+  set_shadowed: ({player, shadowed}: {player: string, shadowed: boolean}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Where `sweep_fees` pays collected fees
+   */
+  set_treasury: ({treasury}: {treasury: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_username transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_username: ({caller, username}: {caller: string, username: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_season transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Open a season ending at `end_timestamp`. Rated results are also tracked per
+   * season from everyone's starting rating; lifetime stats carry on as before.
+   */
+  start_season: ({season_id, end_timestamp}: {season_id: u32, end_timestamp: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Season>>>
+
+  /**
+   * Construct and simulate a start_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a best-of-3 or best-of-5 series on Game Hub session `series_id`. Each
+   * round is a classic game on its own session; the next one starts when a round
+   * resolves. Returns the first round's session id.
+   */
+  start_series: ({series_id, player1, player2, player1_points, player2_points, best_of}: {series_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, best_of: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a accept_invite transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  accept_invite: ({accepter, match_id}: {accepter: string, match_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a create_invite transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  create_invite: ({inviter, opponent}: {inviter: string, opponent: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a declare_rival transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Name `rival` as your rival. Once they name you back, games between you pay a
+   * rivalry bonus and count towards your head-to-head record.
+   */
+  declare_rival: ({player, rival}: {player: string, rival: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a force_release transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Take a name away from whoever holds it, e.g. for abuse
+   */
+  force_release: ({username}: {username: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_addresses transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Addresses holding up to 50 usernames in one call, in the order given; `None`
+   * for a name nobody holds
+   */
+  get_addresses: ({usernames}: {usernames: Array<string>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Option<string>>>>
+
+  /**
+   * Construct and simulate a get_challenge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_challenge: ({challenge_id}: {challenge_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Challenge>>>
+
+  /**
+   * Construct and simulate a get_moderator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_moderator: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_team_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_team_game: ({session_id}: {session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<TeamGame>>>
+
+  /**
+   * Construct and simulate a get_usernames transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Usernames of up to 50 addresses in one call, in the order given; `None` for
+   * an address without one
+   */
+  get_usernames: ({addresses}: {addresses: Array<string>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Option<string>>>>
+
+  /**
+   * Construct and simulate a propose_pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose pausing a correspondence game for `duration_secs`; the opponent
+   * accepts with `accept_pause`
+   */
+  propose_pause: ({session_id, player, duration_secs}: {session_id: u32, player: string, duration_secs: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reject_invite transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  reject_invite: ({rejecter, match_id}: {rejecter: string, match_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a remove_friend transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Take `friend` off your friends list
+   */
+  remove_friend: ({player, friend}: {player: string, friend: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a remove_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  remove_policy: ({context_rule_id, policy_id}: {context_rule_id: u32, policy_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a remove_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  remove_signer: ({context_rule_id, signer_id}: {context_rule_id: u32, signer_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_moderator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Who sets shadow flags; `None` hands it back to the admin
+   */
+  set_moderator: ({moderator}: {moderator: Option<string>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a withdraw_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Send all fees collected in `token` to `to`; returns the amount sent
+   */
+  withdraw_fees: ({token, to}: {token: string, to: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a accept_rematch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept a rematch offer and start the new game. Returns its session id.
+   */
+  accept_rematch: ({session_id, player}: {session_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a claim_winnings transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Collect the token winnings, refunds and forfeited deposits owed to `player`
+   * from a settled game; returns the amount paid per token
+   */
+  claim_winnings: ({session_id, player}: {session_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Map<string, i128>>>>
+
+  /**
+   * Construct and simulate a get_game_pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_game_pause: ({session_id}: {session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<GamePause>>
+
+  /**
+   * Construct and simulate a get_match_note transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_match_note: ({player, session_id}: {player: string, session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<Buffer>>>
+
+  /**
+   * Construct and simulate a get_pick_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_pick_stats: ({spectator}: {spectator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<PickStats>>
+
+  /**
+   * Construct and simulate a get_prediction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_prediction: ({session_id, predictor}: {session_id: u32, predictor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<Prediction>>>
+
+  /**
+   * Construct and simulate a get_promo_code transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_promo_code: ({code}: {code: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<PromoCode>>>
+
+  /**
+   * Construct and simulate a get_tournament transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_tournament: ({tournament_id}: {tournament_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Tournament>>>
+
+  /**
+   * Construct and simulate a is_provisional transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether `player`'s rating is still provisional: under 10 rated games, each
+   * moving it twice as far as an established rating
+   */
+  is_provisional: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a resolve_battle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resolve the battle after both players have revealed their moves
+   */
+  resolve_battle: ({session_id}: {session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<BattleResult>>>
+
+  /**
+   * Construct and simulate a send_challenge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Send a challenge to another player
+   */
+  send_challenge: ({challenger, challenged, points_wagered}: {challenger: string, challenged: string, points_wagered: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a set_match_note transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pin a hash of your (off-chain, encrypted) notes to a session you finished
+   */
+  set_match_note: ({player, session_id, note_hash}: {player: string, session_id: u32, note_hash: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a unlink_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Undo a link from either side; ratings and the name stay with the primary
+   */
+  unlink_address: ({caller, secondary}: {caller: string, secondary: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a advance_bracket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Seed the bracket from the checked-in players once the tournament starts, then
+   * record results and move players on to their next matches as games resolve.
+   * Anyone can call it.
+   */
+  advance_bracket: ({tournament_id}: {tournament_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Tournament>>>
+
+  /**
+   * Construct and simulate a get_csh_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_csh_balance: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_fee_waivers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_fee_waivers: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_game_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_game_config: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<GameConfig>>
+
+  /**
+   * Construct and simulate a get_game_expiry transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * When `expire_game` can first be called on this game
+   */
+  get_game_expiry: ({session_id}: {session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a get_invite_code transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_invite_code: ({code}: {code: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<InviteCode>>>
+
+  /**
+   * Construct and simulate a get_queue_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * How many players are waiting at each tier
+   */
+  get_queue_stats: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<QueueTier>>>
+
+  /**
+   * Construct and simulate a get_siege_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Siege wins and losses for a player, by role
+   */
+  get_siege_stats: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<SiegeStats>>
+
+  /**
+   * Construct and simulate a get_sponsorship transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stakes the pool put up for `session_id`, until the game settles
+   */
+  get_sponsorship: ({session_id}: {session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<Sponsorship>>>
+
+  /**
+   * Construct and simulate a get_top_players transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Highest-rated players, best first (at most 100), after any rating decay owed
+   */
+  get_top_players: ({limit}: {limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<RankedPlayer>>>
+
+  /**
+   * Construct and simulate a preverify_proof transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Run the current circuit's verifier on a proof without committing it, returning
+   * the commitment hash. Meant to be simulated by frontends before sending `commit_moves`; fails
+   * with the same `InvalidPublicInputs`, `NullifierUsed`, `InvalidProof` and
+   * `VerifierTrapped` errors a commit would.
+   */
+  preverify_proof: ({public_inputs, proof_bytes}: {public_inputs: Buffer, proof_bytes: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Buffer>>>
+
+  /**
+   * Construct and simulate a quote_challenge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Preview the stakes, deposits, rake and deadlines of sending a challenge for
+   * `points_wagered` in `mode`; fails like `send_challenge` on an invalid wager
+   */
+  quote_challenge: ({points_wagered, mode}: {points_wagered: i128, mode: GameMode}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<ChallengeQuote>>>
+
+  /**
+   * Construct and simulate a request_rematch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Offer the opponent a rematch of finished game `session_id` with the same
+   * pairing, stakes and mode
+   */
+  request_rematch: ({session_id, requester}: {session_id: u32, requester: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a rollover_season transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `end_season`, then `start_season` for the next one
+   */
+  rollover_season: ({top_n, next_season_id, end_timestamp}: {top_n: u32, next_season_id: u32, end_timestamp: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<SeasonResults>>>
+
+  /**
+   * Construct and simulate a set_game_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update gameplay tuning. Applies to games started afterwards.
+   */
+  set_game_config: ({config}: {config: GameConfig}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_team_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a 2v2 game. Each team stakes its points through its captain, the first
+   * player listed, who is also who the Game Hub sees for that side.
+   */
+  start_team_game: ({session_id, team1, team2, team1_points, team2_points}: {session_id: u32, team1: Array<string>, team2: Array<string>, team1_points: i128, team2_points: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a accept_challenge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept a challenge and start a game. Returns the session id allocated for it.
+   * `challenge_nonce` is the nonce of the challenge the player agreed to; if the
+   * challenge has changed since, acceptance fails with `StaleChallenge`.
+   */
+  accept_challenge: ({challenge_id, challenged, challenge_nonce}: {challenge_id: u32, challenged: string, challenge_nonce: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a add_context_rule transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  add_context_rule: ({context_type, name, valid_until, signers, policies}: {context_type: ContextRuleType, name: string, valid_until: Option<u32>, signers: Array<Signer>, policies: Map<string, any>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<ContextRule>>
+
+  /**
+   * Construct and simulate a cancel_challenge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw a sent challenge that hasn't been accepted yet. It leaves both
+   * players' challenge lists.
+   */
+  cancel_challenge: ({challenge_id, challenger}: {challenge_id: u32, challenger: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claim_prediction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Collect a prediction once the game settles: a pro-rata share of the losing
+   * side on a correct call, the stake back on a draw; returns the amount paid
+   */
+  claim_prediction: ({session_id, predictor}: {session_id: u32, predictor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a commit_duel_move transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Commit this round's move as `sha256(attack | defense | salt)`
+   */
+  commit_duel_move: ({session_id, player, commitment}: {session_id: u32, player: string, commitment: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_achievements transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Badges `player` has earned, oldest first
+   */
+  get_achievements: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<EarnedAchievement>>>
+
+  /**
+   * Construct and simulate a get_bonus_points transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Bonus points `player` has earned here and not yet settled to the Game Hub
+   */
+  get_bonus_points: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_context_rule transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_context_rule: ({context_rule_id}: {context_rule_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<ContextRule>>
+
+  /**
+   * Construct and simulate a get_fee_schedule transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_fee_schedule: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<FeeTier>>>
+
+  /**
+   * Construct and simulate a get_global_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Game counts, attack usage and average battle length across all players
+   */
+  get_global_stats: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<GlobalStats>>
+
+  /**
+   * Construct and simulate a get_head_to_head transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `player`'s record in rival matches against `opponent`
+   */
+  get_head_to_head: ({player, opponent}: {player: string, opponent: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<HeadToHead>>
+
+  /**
+   * Construct and simulate a get_player_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Rated record of `player`; players who haven't played a rated game start on 1500
+   */
+  get_player_stats: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<PlayerStats>>
+
+  /**
+   * Construct and simulate a get_raise_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_raise_config: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<RaiseConfig>>>
+
+  /**
+   * Construct and simulate a get_rating_decay transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_rating_decay: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<RatingDecay>>
+
+  /**
+   * Construct and simulate a get_season_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `player`'s record in a season; the starting record if they didn't play in it
+   */
+  get_season_stats: ({season_id, player}: {season_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<PlayerStats>>
+
+  /**
+   * Construct and simulate a get_sponsor_pool transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sponsor pool balance not set aside for games in play
+   */
+  get_sponsor_pool: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_sponsor_tier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_sponsor_tier: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<SponsorTier>>>
+
+  /**
+   * Construct and simulate a get_vested_prize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_vested_prize: ({tournament_id, player}: {tournament_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<VestedPrize>>>
+
+  /**
+   * Construct and simulate a get_wager_limits transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_wager_limits: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<WagerLimits>>
+
+  /**
+   * Construct and simulate a place_prediction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stake `amount` of the prediction token on `backed_player` winning. Open to
+   * anyone but the two players, until the first commit lands.
+   */
+  place_prediction: ({session_id, predictor, backed_player, amount}: {session_id: u32, predictor: string, backed_player: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a release_username transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Free the caller's username for anyone to claim
+   */
+  release_username: ({caller}: {caller: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_duel_move transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal this round's move; the second reveal resolves the round
+   */
+  reveal_duel_move: ({session_id, player, action, salt}: {session_id: u32, player: string, action: Move, salt: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_fee_schedule transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the rake breakpoints, ordered by `min_wager`. An empty schedule charges
+   * nothing. Applies to games started afterwards.
+   */
+  set_fee_schedule: ({schedule}: {schedule: Array<FeeTier>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_raise_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Allow blind raises of up to `max_amount` of `token`. A max of 0 turns raising
+   * off. Applies to games started afterwards.
+   */
+  set_raise_config: ({token, max_amount}: {token: string, max_amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_rating_decay transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Have inactive players' ratings decay (see `RatingDecay`); 0 for either turns
+   * it off. Admin only.
+   */
+  set_rating_decay: ({inactive_secs, points}: {inactive_secs: u64, points: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_sponsor_tier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the sponsored free-to-play tier; `None` turns it off for new games
+   */
+  set_sponsor_tier: ({tier}: {tier: Option<SponsorTier>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_wager_limits transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the inclusive wager bounds checked when games and challenges are created
+   */
+  set_wager_limits: ({min, max}: {min: i128, max: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a cancel_tournament transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Call off a tournament before it completes. Organizer or admin; the prize pool
+   * is credited back to the organizer, to collect with `claim_prize`.
+   */
+  cancel_tournament: ({tournament_id, caller}: {tournament_id: u32, caller: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Tournament>>>
+
+  /**
+   * Construct and simulate a commit_team_moves transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Commit one team member's move sequence with a ZK proof, as in `commit_moves`
+   */
+  commit_team_moves: ({session_id, player, public_inputs, proof_bytes}: {session_id: u32, player: string, public_inputs: Buffer, proof_bytes: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<CommitReceipt>>>
+
+  /**
+   * Construct and simulate a create_promo_code transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Create (or replace) a promo code redeemable up to `max_uses` times
+   */
+  create_promo_code: ({code, reward, amount, max_uses}: {code: string, reward: PromoReward, amount: i128, max_uses: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a create_tournament transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Open a single- or double-elimination tournament for `max_players` (a power of
+   * two, up to 32, and at least 4 for double elimination); each match stakes
+   * `entry_points` from both players
+   */
+  create_tournament: ({organizer, max_players, entry_points, registration_deadline, format}: {organizer: string, max_players: u32, entry_points: i128, registration_deadline: u64, format: BracketFormat}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a decline_challenge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Turn down a received challenge. It leaves both players' challenge lists.
+   */
+  decline_challenge: ({challenge_id, challenged}: {challenge_id: u32, challenged: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a enforce_deadlines transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Settle a game or duel whose deadline has passed, with the same outcome the
+   * players' timeout claims would give. Needs no signature, so keepers and other
+   * observers can end abandoned sessions.
+   */
+  enforce_deadlines: ({session_id}: {session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<BattleResult>>>
+
+  /**
+   * Construct and simulate a forward_challenge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hand a received challenge to someone else (e.g. a clanmate) on the same terms.
+   * Needs the challenged player's auth and works once per challenge.
+   */
+  forward_challenge: ({challenge_id, to}: {challenge_id: u32, to: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a fund_sponsor_pool transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pay `amount` of the tier's token into the sponsor pool; returns the new balance
+   */
+  fund_sponsor_pool: ({amount}: {amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_captain_class transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_captain_class: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<CaptainClass>>
+
+  /**
+   * Construct and simulate a get_coached_moves transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `player`'s revealed moves, for their coach, once the battle is resolved. The
+   * same moves are public through `get_game` as soon as they're revealed.
+   */
+  get_coached_moves: ({session_id, coach, player}: {session_id: u32, coach: string, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<Move>>>>
+
+  /**
+   * Construct and simulate a get_game_playback transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get detailed game playback with all moves and results
+   */
+  get_game_playback: ({session_id}: {session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<GamePlayback>>>
+
+  /**
+   * Construct and simulate a get_game_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_game_proposal: ({session_id}: {session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<GameProposal>>>
+
+  /**
+   * Construct and simulate a get_match_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `player`'s most recent resolved battles (up to 20), newest first. Unlike
+   * games, these never expire.
+   */
+  get_match_history: ({player, offset, limit}: {player: string, offset: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<ArchivedGame>>>
+
+  /**
+   * Construct and simulate a get_practice_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_practice_game: ({practice_id}: {practice_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<PracticeGame>>>
+
+  /**
+   * Construct and simulate a get_prize_vesting transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_prize_vesting: ({tournament_id}: {tournament_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<PrizeVesting>>>
+
+  /**
+   * Construct and simulate a get_updates_since transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Changes to `player`'s games, challenges, duels and team games since `seq`
+   */
+  get_updates_since: ({player, seq}: {player: string, seq: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<PlayerUpdates>>
+
+  /**
+   * Construct and simulate a register_referral transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Name the player who referred `new_player`; once per player, before their
+   * first game
+   */
+  register_referral: ({new_player, referrer}: {new_player: string, referrer: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a register_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Register the verifier contract for circuit `version`, or replace its address.
+   * A version above the current one becomes current: games created from then on
+   * are pinned to it, while games already running keep their own version.
+   */
+  register_verifier: ({version, verifier}: {version: u32, verifier: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_team_moves transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal one team member's moves, as in `reveal_moves`
+   */
+  reveal_team_moves: ({session_id, player, public_inputs, moves}: {session_id: u32, player: string, public_inputs: Buffer, moves: Array<Move>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_captain_class transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pick the captain class the caller battles as in games started from now on
+   */
+  set_captain_class: ({player, class}: {player: string, class: CaptainClass}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_prize_vesting transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Vest prizes above `threshold`: they pay `immediate_bps` of themselves when the
+   * tournament completes and the rest `lock_secs` later (up to 30 days).
+   * Organizer only, once, before the bracket is seeded.
+   */
+  set_prize_vesting: ({tournament_id, threshold, immediate_bps, lock_secs}: {tournament_id: u32, threshold: i128, immediate_bps: u32, lock_secs: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a transfer_username transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Move `from`'s username to `to`; both must sign
+   */
+  transfer_username: ({from, to}: {from: string, to: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a verify_randomness transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Recompute a stored seed from its domain and inputs
+   */
+  verify_randomness: ({audit}: {audit: RandomnessAudit}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a accept_invite_code transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Redeem an invite code and start the game. Returns the session id.
+   */
+  accept_invite_code: ({code, acceptor}: {code: u64, acceptor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a claim_duel_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * End a duel whose current phase deadline passed without the opponent acting
+   */
+  claim_duel_timeout: ({session_id, player}: {session_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a create_invite_code transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Create a one-time code that lets anyone start a game against `creator`, without
+   * knowing their address or username. Valid for `ttl_secs` (up to 7 days).
+   */
+  create_invite_code: ({creator, points_wagered, ttl_secs}: {creator: string, points_wagered: i128, ttl_secs: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a deprecate_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stop accepting proofs for circuit `version`, e.g. after a circuit bug. Games
+   * pinned to it can no longer commit and have to be settled by timeout. The
+   * current version can't be deprecated.
+   */
+  deprecate_verifier: ({version}: {version: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_collected_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_collected_fees: ({token}: {token: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_commit_deposit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_commit_deposit: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<CommitDeposit>>>
+
+  /**
+   * Construct and simulate a get_commit_receipt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Receipt for `player`'s commit in a game or team game, showing which ledger
+   * it landed in
+   */
+  get_commit_receipt: ({session_id, player}: {session_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<CommitReceipt>>>
+
+  /**
+   * Construct and simulate a get_friends_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `player`'s friends with their looking-for-game flags, for a "ready to duel" panel
+   */
+  get_friends_status: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<FriendStatus>>>
+
+  /**
+   * Construct and simulate a get_last_active_at transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Last battle or username claim, as used for the name expiry; 0 if none recorded
+   */
+  get_last_active_at: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a get_max_game_pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_max_game_pause: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a get_player_matches transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_player_matches: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<u32>>>
+
+  /**
+   * Construct and simulate a get_practice_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_practice_stats: ({player, difficulty}: {player: string, difficulty: PracticeDifficulty}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<PracticeStats>>
+
+  /**
+   * Construct and simulate a get_referral_bonus transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_referral_bonus: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_referral_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_referral_stats: ({referrer}: {referrer: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<ReferralStats>>
+
+  /**
+   * Construct and simulate a get_rivalry_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The grudge series `a` and `b` are in: their games against each other since
+   * either last played someone else, scored from `a`'s side
+   */
+  get_rivalry_series: ({a, b}: {a: string, b: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<RivalrySeries>>>
+
+  /**
+   * Construct and simulate a get_season_results transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_season_results: ({season_id}: {season_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<SeasonResults>>>
+
+  /**
+   * Construct and simulate a get_season_rewards transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_season_rewards: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<SeasonRewards>>>
+
+  /**
+   * Construct and simulate a get_session_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_session_signer: ({session_id, player}: {session_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_settlement_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_settlement_key: ({player}: {player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<Buffer>>>
+
+  /**
+   * Construct and simulate a get_token_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_token_contract: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a reveal_and_resolve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `reveal_moves`, then, if both players have now revealed, `resolve_battle` in the
+   * same invocation, so the last player finishes the game in one transaction.
+   * Returns the result, or the still-pending one (reason `Pending`) when the
+   * opponent hasn't revealed, resolution is paused or a signed result is pending.
+   */
+  reveal_and_resolve: ({session_id, player, public_inputs, moves}: {session_id: u32, player: string, public_inputs: Buffer, moves: Array<Move>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<BattleResult>>>
+
+  /**
+   * Construct and simulate a set_commit_deposit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Require a refundable `amount` of `token` from each player at commit time.
+   * An amount of 0 turns the deposit off. Applies to games started afterwards.
+   */
+  set_commit_deposit: ({token, amount}: {token: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_max_game_pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Most time a correspondence game can spend paused, over all its pauses
+   */
+  set_max_game_pause: ({max_secs}: {max_secs: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_referral_bonus transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * CSH credited to a referrer when a player they referred finishes a first battle
+   */
+  set_referral_bonus: ({amount}: {amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_season_rewards transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Rewards for the top finishers of seasons ended from now on, best rank first.
+   * An empty list turns them off.
+   */
+  set_season_rewards: ({token, amounts}: {token: string, amounts: Array<i128>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_settlement_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Register the ed25519 key `player` co-signs off-chain results with
+   */
+  set_settlement_key: ({player, public_key}: {player: string, public_key: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_token_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_token_contract: ({token_contract}: {token_contract: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a commit_moves_hashed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Commit `sha256(moves || salt)` on a hash-mode game, where each move is its
+   * attack and defense byte and the salt is 32 bytes. Takes the place of
+   * `commit_moves` when the game has no ZK verifier to call.
+   */
+  commit_moves_hashed: ({session_id, player, commitment}: {session_id: u32, player: string, commitment: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<CommitReceipt>>>
+
+  /**
+   * Construct and simulate a get_awaiting_action transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Games and duels waiting on `player` to commit, reveal or answer a raise, among
+   * up to `limit` of their live sessions from `offset` (a player has at most 30)
+   */
+  get_awaiting_action: ({player, offset, limit}: {player: string, offset: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<u32>>>
+
+  /**
+   * Construct and simulate a get_circuit_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The circuit version new games are pinned to
+   */
+  get_circuit_version: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_effective_wager transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * What `player` has riding on a game: their Game Hub stake, plus the raise once
+   * called. Reveals wait for a pending raise, so this is final once anyone reveals.
+   */
+  get_effective_wager: ({session_id, player}: {session_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_open_challenges transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Page through open challenges with a wager in `min_wager..=max_wager`, oldest
+   * first
+   */
+  get_open_challenges: ({offset, limit, min_wager, max_wager}: {offset: u32, limit: u32, min_wager: i128, max_wager: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<OpenChallenge>>>
+
+  /**
+   * Construct and simulate a get_prediction_pool transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_prediction_pool: ({session_id}: {session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<PredictionPool>>>
+
   /**
-   * The signature payload is invalid or has incorrect format.
+   * Construct and simulate a get_username_expiry transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  3110: {message:"SignaturePayloadInvalid"},
+  get_username_expiry: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
   /**
-   * The client data exceeds the maximum allowed length.
+   * Construct and simulate a post_open_challenge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Post a challenge anyone can accept to the open board (up to 3 live per
+   * player). Returns its challenge id.
    */
-  3111: {message:"ClientDataTooLong"},
+  post_open_challenge: ({challenger, points_wagered}: {challenger: string, points_wagered: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u32>>>
+
   /**
-   * Failed to parse JSON from client data.
+   * Construct and simulate a remove_context_rule transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  3112: {message:"JsonParseError"},
+  remove_context_rule: ({context_rule_id}: {context_rule_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
   /**
-   * The type field in client data is not "webauthn.get".
+   * Construct and simulate a resolve_team_battle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Play out a team game once all four players have revealed, and report the
+   * winning team to the Game Hub
    */
-  3113: {message:"TypeFieldInvalid"},
+  resolve_team_battle: ({session_id}: {session_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<BattleResult>>>
+
   /**
-   * The challenge in client data does not match expected value.
+   * Construct and simulate a reveal_moves_hashed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the moves and salt behind a `commit_moves_hashed` commitment. The
+   * digest is recomputed on-chain; everything after that is as for `reveal_moves`.
    */
-  3114: {message:"ChallengeInvalid"},
+  reveal_moves_hashed: ({session_id, player, moves, salt}: {session_id: u32, player: string, moves: Array<Move>, salt: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
   /**
-   * The authenticator data format is invalid or too short.
+   * Construct and simulate a set_username_expiry transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Seconds without a battle after which a player's name can be claimed by
+   * others; 0 (the default) keeps names forever
    */
-  3115: {message:"AuthDataFormatInvalid"},
+  set_username_expiry: ({expiry_secs}: {expiry_secs: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
   /**
-   * The User Present (UP) bit is not set in authenticator flags.
+   * Construct and simulate a start_practice_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Open an unranked game against the on-chain bot. Returns the practice id.
    */
-  3116: {message:"PresentBitNotSet"},
+  start_practice_game: ({player, difficulty}: {player: string, difficulty: PracticeDifficulty}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
   /**
-   * The User Verified (UV) bit is not set in authenticator flags.
+   * Construct and simulate a withdraw_prediction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Take a prediction back before the first commit (or after the game expired
+   * unsettled); returns the refund
    */
-  3117: {message:"VerifiedBitNotSet"},
+  withdraw_prediction: ({session_id, predictor}: {session_id: u32, predictor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
   /**
-   * Invalid relationship between Backup Eligibility and State bits.
+   * Construct and simulate a claim_reveal_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * After the reveal deadline, end a game in which a committed player never revealed.
+   * The player who revealed wins by forfeit and receives the other's deposit.
+   * Correspondence games can also be claimed when the opponent missed the commit
+   * deadline.
    */
-  3118: {message:"BackupEligibilityAndStateNotSet"},
+  claim_reveal_timeout: ({session_id, player}: {session_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<BattleResult>>>
+
   /**
-   * The provided key data does not contain a valid 65-byte public key.
+   * Construct and simulate a get_linked_addresses transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  3119: {message:"KeyDataInvalid"}
-}
+  get_linked_addresses: ({primary}: {primary: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<string>>>
+
+  /**
+   * Construct and simulate a get_migration_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_migration_status: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<MigrationStatus>>
+
+  /**
+   * Construct and simulate a get_prediction_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_prediction_token: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_season_placement transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_season_placement: ({season_id, player}: {season_id: u32, player: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<SeasonPlacement>>>
+
+  /**
+   * Construct and simulate a set_looking_for_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Tell friends you are up for a game, or no longer
+   */
+  set_looking_for_game: ({player, looking}: {player: string, looking: boolean}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_prediction_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Token spectators stake with `place_prediction`; `None` turns new pools off.
+   * Pools already open keep the token they started with.
+   */
+  set_prediction_token: ({token}: {token: Option<string>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
 
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
 
-/**
- * WebAuthn signature data structure containing all components needed for
- * verification.
- * 
- * This structure encapsulates the signature and associated data generated
- * during a WebAuthn authentication ceremony.
- */
-export interface WebAuthnSigData {
-  /**
- * Raw authenticator data from the WebAuthn response.
- */
-authenticator_data: Buffer;
-  /**
- * Raw client data JSON from the WebAuthn response.
- */
-client_data: Buffer;
-  /**
- * The cryptographic signature (64 bytes for secp256r1).
- */
-signature: Buffer;
-}
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
 
-export interface Client {
   /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a settle_points_to_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Move `amount` of the player's bonus points onto the Game Hub's ledger; returns
+   * what is left here
    */
-  get_hub: (options?: {
+  settle_points_to_hub: ({player, amount}: {player: string, amount: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -707,12 +6680,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<Result<i128>>>
 
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a settle_signed_result transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Submit the result of a match played off-chain, signed by both players over
+   * `signed_result_digest`. It applies after a challenge window unless disputed.
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: {
+  settle_signed_result: ({session_id, result, sig_p1, sig_p2}: {session_id: u32, result: SignedResult, sig_p1: Buffer, sig_p2: Buffer}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -727,12 +6702,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Result<PendingSettlement>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a signed_result_digest transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The message each player signs for `settle_signed_result`
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: {
+  signed_result_digest: ({session_id, result}: {session_id: u32, result: SignedResult}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -747,13 +6723,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Buffer>>
 
   /**
-   * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get game information
+   * Construct and simulate a start_correspondence transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a correspondence game: classic rules, but players get days rather than
+   * hours to commit and to reveal, and a `WindowOpened` event marks each deadline.
    */
-  get_game: ({session_id}: {session_id: u32}, options?: {
+  start_correspondence: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -768,12 +6745,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<Game>>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a end_match transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a start_sponsored_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a classic game whose stakes the sponsor pool puts up. Both players sign,
+   * and both must qualify for the sponsor tier.
    */
-  end_match: ({player, match_id}: {player: string, match_id: u32}, options?: {
+  start_sponsored_game: ({session_id, player1, player2}: {session_id: u32, player1: string, player2: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -791,9 +6770,11 @@ export interface Client {
   }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a sweep_season_rewards transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Anyone: once a season's claim window has closed, move its unclaimed rewards
+   * to the collected fees. Returns the amount moved.
    */
-  get_admin: (options?: {
+  sweep_season_rewards: ({season_id}: {season_id: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -808,12 +6789,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<Result<i128>>>
 
   /**
-   * Construct and simulate a get_match transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a accept_open_challenge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Take an open challenge and start a game, like `accept_challenge`. Returns the
+   * session id allocated for it.
    */
-  get_match: ({match_id}: {match_id: u32}, options?: {
+  accept_open_challenge: ({challenge_id, acceptor}: {challenge_id: u32, acceptor: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -828,12 +6811,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<PvPMatch>>>
+  }) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
-   * Construct and simulate a play_turn transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a cancel_open_challenge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Take your open challenge off the board before anyone accepts it
    */
-  play_turn: ({player, match_id, action}: {player: string, match_id: u32, action: Move}, options?: {
+  cancel_open_challenge: ({challenge_id, challenger}: {challenge_id: u32, challenger: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -851,9 +6835,11 @@ export interface Client {
   }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a dispute_signed_result transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reject a pending signed result and fall back to full ZK resolution; the session
+   * takes no further signed results
    */
-  set_admin: ({new_admin}: {new_admin: string}, options?: {
+  dispute_signed_result: ({session_id, player}: {session_id: u32, player: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -868,12 +6854,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a add_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_last_activity_seq transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cheap marker to poll: bumped by every change affecting `player`
    */
-  add_policy: ({context_rule_id, policy, install_param}: {context_rule_id: u32, policy: string, install_param: any}, options?: {
+  get_last_activity_seq: ({player}: {player: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -888,12 +6875,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u32>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a add_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_player_challenges transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get all challenges for a player (sorted by status)
    */
-  add_signer: ({context_rule_id, signer}: {context_rule_id: u32, signer: Signer}, options?: {
+  get_player_challenges: ({player}: {player: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -908,13 +6896,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u32>>
+  }) => Promise<AssembledTransaction<readonly [Array<Challenge>, Array<Challenge>, Array<Challenge>]>>
 
   /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new game between two players with points
+   * Construct and simulate a get_rating_percentile transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * How `player` ranks among all rated players ("top 5%"), read from the rating
+   * histogram instead of the whole ladder
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: {
+  get_rating_percentile: ({player}: {player: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -929,15 +6918,15 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  }) => Promise<AssembledTransaction<RatingPercentile>>
 
   /**
-   * Construct and simulate a commit_moves transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Commit move sequence with ZK proof
-   * Player proves they know valid moves WITHOUT revealing them.
-   * The proof's public output (commitment hash) is stored.
+   * Construct and simulate a get_sessions_in_range transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Games, duels and team games created in ledgers `from_seq..=to_seq`, oldest
+   * first. The range may span at most a day of ledgers; a busy one is listed over
+   * several calls, starting with `page` 0 and following `next_page`.
    */
-  commit_moves: ({session_id, player, public_inputs, proof_bytes}: {session_id: u32, player: string, public_inputs: Buffer, proof_bytes: Buffer}, options?: {
+  get_sessions_in_range: ({from_seq, to_seq, page}: {from_seq: u32, to_seq: u32, page: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -952,13 +6941,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<Buffer>>>
+  }) => Promise<AssembledTransaction<Result<SessionRange>>>
 
   /**
-   * Construct and simulate a get_username transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get username for an address
+   * Construct and simulate a get_tournament_prizes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_username: ({address}: {address: string}, options?: {
+  get_tournament_prizes: ({tournament_id}: {tournament_id: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -973,15 +6961,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Option<string>>>
+  }) => Promise<AssembledTransaction<Option<TournamentPrizes>>>
 
   /**
-   * Construct and simulate a reveal_moves transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Reveal moves — player re-proves with moves now PUBLIC.
-   * The contract verifies the new proof's commitment output
-   * matches what was stored at commit time.
+   * Construct and simulate a revoke_session_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sign `session_id`'s commits and reveals with the player's own account again
    */
-  reveal_moves: ({session_id, player, public_inputs, moves}: {session_id: u32, player: string, public_inputs: Buffer, moves: Array<Move>}, options?: {
+  revoke_session_signer: ({player, session_id}: {player: string, session_id: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -999,9 +6985,12 @@ export interface Client {
   }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a set_username transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_tournament_prizes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Put up a prize pool of `pool` in `token`, split by `payouts_bps` (first place
+   * first, summing to 10_000) when the bracket completes. Organizer only, once,
+   * before the bracket is seeded.
    */
-  set_username: ({caller, username}: {caller: string, username: string}, options?: {
+  set_tournament_prizes: ({tournament_id, token, pool, payouts_bps}: {tournament_id: u32, token: string, pool: i128, payouts_bps: Array<u32>}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1019,9 +7008,10 @@ export interface Client {
   }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a accept_invite transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a withdraw_sponsor_pool transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Take `amount` back out of the sponsor pool; returns the new balance
    */
-  accept_invite: ({accepter, match_id}: {accepter: string, match_id: u32}, options?: {
+  withdraw_sponsor_pool: ({amount}: {amount: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1036,12 +7026,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  }) => Promise<AssembledTransaction<Result<i128>>>
 
   /**
-   * Construct and simulate a create_invite transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a claim_referral_rewards transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Mint the referral bonuses credited to `referrer`; returns the amount paid
    */
-  create_invite: ({inviter, opponent}: {inviter: string, opponent: string}, options?: {
+  claim_referral_rewards: ({referrer}: {referrer: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1056,12 +7047,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<u32>>>
+  }) => Promise<AssembledTransaction<Result<i128>>>
 
   /**
-   * Construct and simulate a reject_invite transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a claim_season_placement transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Collect the reward for `player`'s finish in `season_id`; returns the amount paid
    */
-  reject_invite: ({rejecter, match_id}: {rejecter: string, match_id: u32}, options?: {
+  claim_season_placement: ({player, season_id}: {player: string, season_id: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1076,12 +7068,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  }) => Promise<AssembledTransaction<Result<i128>>>
 
   /**
-   * Construct and simulate a remove_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a finalize_signed_result transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Apply an undisputed signed result once its challenge window has closed
    */
-  remove_policy: ({context_rule_id, policy_id}: {context_rule_id: u32, policy_id: u32}, options?: {
+  finalize_signed_result: ({session_id}: {session_id: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1096,12 +7089,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Result<BattleResult>>>
 
   /**
-   * Construct and simulate a remove_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_pending_settlement transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  remove_signer: ({context_rule_id, signer_id}: {context_rule_id: u32, signer_id: u32}, options?: {
+  get_pending_settlement: ({session_id}: {session_id: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1116,13 +7109,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Option<PendingSettlement>>>
 
   /**
-   * Construct and simulate a resolve_battle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Resolve the battle after both players have revealed their moves
+   * Construct and simulate a get_require_registered transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  resolve_battle: ({session_id}: {session_id: u32}, options?: {
+  get_require_registered: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1137,13 +7129,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<BattleResult>>>
+  }) => Promise<AssembledTransaction<boolean>>
 
   /**
-   * Construct and simulate a send_challenge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Send a challenge to another player
+   * Construct and simulate a set_require_registered transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * When on, games only start if the Game Hub reports both players as registered,
+   * so throwaway accounts can't farm leaderboard points
    */
-  send_challenge: ({challenger, challenged, points_wagered}: {challenger: string, challenged: string, points_wagered: i128}, options?: {
+  set_require_registered: ({required}: {required: boolean}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1158,12 +7151,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<u32>>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a get_csh_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_address_by_username transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get address for a username
    */
-  get_csh_balance: ({player}: {player: string}, options?: {
+  get_address_by_username: ({username}: {username: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1178,13 +7172,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<Option<string>>>
 
   /**
-   * Construct and simulate a accept_challenge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Accept a challenge and start a game
+   * Construct and simulate a get_context_rules_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  accept_challenge: ({challenge_id, challenged, session_id}: {challenge_id: u32, challenged: string, session_id: u32}, options?: {
+  get_context_rules_count: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1199,12 +7192,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  }) => Promise<AssembledTransaction<u32>>
 
   /**
-   * Construct and simulate a add_context_rule transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_nullifier_retention transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  add_context_rule: ({context_type, name, valid_until, signers, policies}: {context_type: ContextRuleType, name: string, valid_until: Option<u32>, signers: Array<Signer>, policies: Map<string, any>}, options?: {
+  get_nullifier_retention: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1219,12 +7212,15 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<ContextRule>>
+  }) => Promise<AssembledTransaction<u32>>
 
   /**
-   * Construct and simulate a get_context_rule transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_pending_resolutions transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Up to `limit` sessions from `offset` with both moves revealed that haven't been
+   * resolved yet, for bots and the frontend to retry `resolve_battle` on. The list
+   * holds the latest 100.
    */
-  get_context_rule: ({context_rule_id}: {context_rule_id: u32}, options?: {
+  get_pending_resolutions: ({offset, limit}: {offset: u32, limit: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1239,13 +7235,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<ContextRule>>
+  }) => Promise<AssembledTransaction<Array<u32>>>
 
   /**
-   * Construct and simulate a get_game_playback transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get detailed game playback with all moves and results
+   * Construct and simulate a get_player_active_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sessions of `player`'s games that haven't settled yet, oldest first, among up to
+   * `limit` of their live sessions from `offset` (a player has at most 30)
    */
-  get_game_playback: ({session_id}: {session_id: u32}, options?: {
+  get_player_active_games: ({player, offset, limit}: {player: string, offset: u32, limit: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1260,12 +7257,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<GamePlayback>>>
+  }) => Promise<AssembledTransaction<Array<u32>>>
 
   /**
-   * Construct and simulate a get_player_matches transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a register_for_tournament transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_player_matches: ({player}: {player: string}, options?: {
+  register_for_tournament: ({tournament_id, player}: {tournament_id: u32, player: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1280,12 +7277,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<u32>>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_token_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a resolve_practice_battle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Play a practice game with the player's moves against the bot's, drawn now
    */
-  get_token_contract: (options?: {
+  resolve_practice_battle: ({practice_id, player, moves}: {practice_id: u32, player: string, moves: Array<Move>}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1300,12 +7298,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Option<string>>>
+  }) => Promise<AssembledTransaction<Result<BattleResult>>>
 
   /**
-   * Construct and simulate a set_token_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_challenge_tie_break transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pick how the challenge's game breaks a tie. Only while it is pending; bumps
+   * the nonce the challenged player accepts.
    */
-  set_token_contract: ({token_contract}: {token_contract: string}, options?: {
+  set_challenge_tie_break: ({challenge_id, tie_break}: {challenge_id: u32, tie_break: TieBreak}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1320,12 +7320,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a remove_context_rule transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_nullifier_retention transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Keep spent commit nullifiers for `ledgers` ledgers. Applies to commits made
+   * afterwards; a proof can be replayed once its nullifier has expired.
    */
-  remove_context_rule: ({context_rule_id}: {context_rule_id: u32}, options?: {
+  set_nullifier_retention: ({ledgers}: {ledgers: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1340,13 +7342,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_player_challenges transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get all challenges for a player (sorted by status)
+   * Construct and simulate a authorize_session_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Let `signer` (e.g. a hot key of a passkey smart account) sign `player`'s commits
+   * and reveals in `session_id`, and nothing else
    */
-  get_player_challenges: ({player}: {player: string}, options?: {
+  authorize_session_signer: ({player, signer, session_id}: {player: string, signer: string, session_id: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1361,13 +7364,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<readonly [Array<Challenge>, Array<Challenge>, Array<Challenge>]>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_address_by_username transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get address for a username
+   * Construct and simulate a get_sponsored_games_left transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sponsored games `player` can still start today; 0 if they don't qualify
    */
-  get_address_by_username: ({username}: {username: string}, options?: {
+  get_sponsored_games_left: ({player}: {player: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1382,12 +7385,15 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Option<string>>>
+  }) => Promise<AssembledTransaction<u32>>
 
   /**
-   * Construct and simulate a get_context_rules_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a index_challenge_sessions transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * One-off migration after upgrading: index the sessions of challenges accepted
+   * before `resolve_battle` looked them up by session. Paged to stay within the
+   * instruction budget; returns how many were indexed.
    */
-  get_context_rules_count: (options?: {
+  index_challenge_sessions: ({start_id, count}: {start_id: u32, count: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1424,6 +7430,28 @@ export interface Client {
     simulate?: boolean;
   }) => Promise<AssembledTransaction<ContextRule>>
 
+  /**
+   * Construct and simulate a get_player_challenges_page transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Up to `limit` of a player's challenges from `offset`, oldest first; ids whose
+   * challenge has expired are pruned as they are reached
+   */
+  get_player_challenges_page: ({player, offset, limit}: {player: string, offset: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Challenge>>>
+
   /**
    * Construct and simulate a update_context_rule_valid_until transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
@@ -1464,61 +7492,128 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAKAAAAAAAAAA1iYXR0bGVfcmVzdWx0AAAAAAAH0AAAAAxCYXR0bGVSZXN1bHQAAAAAAAAAEWhhc19iYXR0bGVfcmVzdWx0AAAAAAAAAQAAAAAAAAAWaGFzX3BsYXllcjFfY29tbWl0bWVudAAAAAAAAQAAAAAAAAAWaGFzX3BsYXllcjJfY29tbWl0bWVudAAAAAAAAQAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAABJwbGF5ZXIxX2NvbW1pdG1lbnQAAAAAB9AAAAAQUGxheWVyQ29tbWl0bWVudAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAScGxheWVyMl9jb21taXRtZW50AAAAAAfQAAAAEFBsYXllckNvbW1pdG1lbnQAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAAL",
+      new ContractSpec([ "AAAABAAAAJBFdmVyeSBlbnRyeXBvaW50IHJldHVybnMgdGhpcyBzaW5nbGUgZXJyb3IgdHlwZS4gQ29kZXMgYXJlIHN0YWJsZSBzbyB0aGUKZnJvbnRlbmQgY2FuIG1hcCB0aGVtIHRvIG1lc3NhZ2VzOyBuZXcgdmFyaWFudHMgYXJlIG9ubHkgZXZlciBhcHBlbmRlZC4AAAAAAAAABUVycm9yAAAAAAAAYQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAQQWxyZWFkeUNvbW1pdHRlZAAAAAMAAAAAAAAAF0JvdGhQbGF5ZXJzTm90Q29tbWl0dGVkAAAAAAQAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAFAAAAJ1RoZSB2ZXJpZmllciByYW4gYW5kIHJlamVjdGVkIHRoZSBwcm9vZgAAAAAMSW52YWxpZFByb29mAAAABgAAAHpDYXRjaC1hbGwgdmVyaWZpZXIgZmFpbHVyZSBmcm9tIGJlZm9yZSB0aGUgY2F1c2VzIHdlcmUgc3BsaXQ7IG5vIGxvbmdlcgpyZXR1cm5lZCwgc2VlIGBJbnZhbGlkUHJvb2ZgIGFuZCBgVmVyaWZpZXJUcmFwcGVkYAAAAAAAF1Byb29mVmVyaWZpY2F0aW9uRmFpbGVkAAAAAAcAAAAAAAAAE0ludmFsaWRNb3ZlU2VxdWVuY2UAAAAACAAAAAAAAAAUVXNlcm5hbWVBbHJlYWR5VGFrZW4AAAAJAAAAAAAAAA9Vc2VybmFtZVRvb0xvbmcAAAAACgAAAAAAAAARQ2hhbGxlbmdlTm90Rm91bmQAAAAAAAALAAAAAAAAABBDaGFsbGVuZ2VFeHBpcmVkAAAADAAAAGxBIHBsYXllciB0cmllZCB0byBjaGFsbGVuZ2UsIGludml0ZSBvciBzdGFydCBhIGdhbWUgYWdhaW5zdCB0aGVtc2VsdmVzLCBvcgphZ2FpbnN0IGFuIGFkZHJlc3MgbGlua2VkIHRvIHRoZW0AAAATQ2Fubm90Q2hhbGxlbmdlU2VsZgAAAAANAAAAAAAAABBVc2VybmFtZVRvb1Nob3J0AAAADgAAAAAAAAAVSW52YWxpZFVzZXJuYW1lRm9ybWF0AAAAAAAADwAAAAAAAAAQVXNlcm5hbWVSZXNlcnZlZAAAABAAAAAAAAAAD0FscmVhZHlSZXZlYWxlZAAAAAARAAAAAAAAABJDb21taXRtZW50TWlzbWF0Y2gAAAAAABIAAABOUHVibGljIGlucHV0cyBhcmUgbm90IHRoZSBleHBlY3RlZCBgW3BsYXllciB8IHNlc3Npb25faWQgfCBjb21taXRtZW50XWAgbGF5b3V0AAAAAAATSW52YWxpZFB1YmxpY0lucHV0cwAAAAATAAAAOFRoZSBjdXJyZW50IGR1ZWwgcGhhc2UgY2xvc2VkIGJlZm9yZSB0aGlzIGFjdGlvbiBhcnJpdmVkAAAADkRlYWRsaW5lUGFzc2VkAAAAAAAUAAAAPkEgdGltZW91dCB3YXMgY2xhaW1lZCB3aGlsZSB0aGUgb3Bwb25lbnQgc3RpbGwgaGFzIHRpbWUgdG8gYWN0AAAAAAASRGVhZGxpbmVOb3RSZWFjaGVkAAAAAAAVAAAAMldhZ2VyIGlzIG5lZ2F0aXZlIG9yIG91dHNpZGUgdGhlIGNvbmZpZ3VyZWQgbGltaXRzAAAAAAAMSW52YWxpZFdhZ2VyAAAAFgAAAAAAAAAYQ2hhbGxlbmdlQWxyZWFkeUFjY2VwdGVkAAAAFwAAADNUaGUgR2FtZSBIdWIgcmVmdXNlZCBvciBmYWlsZWQgdG8gc3RhcnQgdGhlIHNlc3Npb24AAAAAEUdhbWVIdWJDYWxsRmFpbGVkAAAAAAAAGAAAAD5Cb3RoIHBsYXllcnMgcmV2ZWFsZWQgaW4gdGltZSwgc28gdGhlcmUgaXMgbm8gdGltZW91dCB0byBjbGFpbQAAAAAADk5vdGhpbmdUb0NsYWltAAAAAAAZAAAARFJhbmtlZCBwbGF5IHJlcXVpcmVzIGJvdGggcGxheWVycyB0byBiZSByZWdpc3RlcmVkIHdpdGggdGhlIEdhbWUgSHViAAAAE1BsYXllck5vdFJlZ2lzdGVyZWQAAAAAGgAAAERBIGNvLXNpZ25lZCByZXN1bHQgaXMgd2FpdGluZyBvdXQgaXRzIGNoYWxsZW5nZSB3aW5kb3cgZm9yIHRoaXMgZ2FtZQAAABFTZXR0bGVtZW50UGVuZGluZwAAAAAAABsAAAAzVGhlcmUgaXMgbm8gY28tc2lnbmVkIHJlc3VsdCB0byBkaXNwdXRlIG9yIGZpbmFsaXplAAAAABNOb1BlbmRpbmdTZXR0bGVtZW50AAAAABwAAABtVGhlIHNpZ25lZCByZXN1bHQgbmFtZXMgYSBub24tcGxheWVyIGFzIHdpbm5lciwgaXMgaW5jb25zaXN0ZW50LCBvciBhIHBsYXllcgpoYXMgbm8gc2V0dGxlbWVudCBrZXkgcmVnaXN0ZXJlZAAAAAAAABNJbnZhbGlkU2lnbmVkUmVzdWx0AAAAAB0AAAA/QSBgR2FtZUNvbmZpZ2AgdmFsdWUgb3IgdGhlIG51bGxpZmllciByZXRlbnRpb24gaXMgb3V0IG9mIHJhbmdlAAAAABFJbnZhbGlkR2FtZUNvbmZpZwAAAAAAAB4AAAA+UmFpc2UgYW1vdW50IGlzIG91dCBvZiBib3VuZHMsIG9yIHJhaXNpbmcgaXMgb2ZmIGZvciB0aGlzIGdhbWUAAAAAAAxJbnZhbGlkUmFpc2UAAAAfAAAAT1JhaXNlcyBhcmUgb25seSBhbGxvd2VkIG9uY2UgcGVyIGdhbWUsIGFmdGVyIGJvdGggY29tbWl0cyBhbmQgYmVmb3JlIGFueSByZXZlYWwAAAAAD1JhaXNlTm90QWxsb3dlZAAAAAAgAAAAM1RoZXJlIGlzIG5vIHJhaXNlIGZyb20gdGhlIG9wcG9uZW50IHRvIGNhbGwgb3IgZm9sZAAAAAAOTm9SYWlzZVBlbmRpbmcAAAAAACEAAAA7VGhlIHBlbmRpbmcgcmFpc2UgbXVzdCBiZSBjYWxsZWQgb3IgZm9sZGVkIGJlZm9yZSByZXZlYWxpbmcAAAAADFJhaXNlUGVuZGluZwAAACIAAABFSW52aXRlIGNvZGUgbGlmZXRpbWUgaXMgemVybyBvciBsb25nZXIgdGhhbiBhIGNoYWxsZW5nZSBtYXkgc3RheSBvcGVuAAAAAAAACkludmFsaWRUdGwAAAAAACMAAAAmQSBjaGFsbGVuZ2UgY2FuIG9ubHkgYmUgZm9yd2FyZGVkIG9uY2UAAAAAABlDaGFsbGVuZ2VBbHJlYWR5Rm9yd2FyZGVkAAAAAAAAJAAAAB5ObyBsb2JieSB0aWVyIHdpdGggdGhhdCBudW1iZXIAAAAAAAtJbnZhbGlkVGllcgAAAAAlAAAAKFRoZSBwbGF5ZXIgaXMgYWxyZWFkeSB3YWl0aW5nIGluIGEgbG9iYnkAAAANQWxyZWFkeVF1ZXVlZAAAAAAAACYAAAAmVGhlIHBsYXllciBpcyBub3Qgd2FpdGluZyBpbiBhbnkgbG9iYnkAAAAAAAlOb3RRdWV1ZWQAAAAAAAAnAAAAP0ZlZSB0aWVycyBhcmUgb3V0IG9mIG9yZGVyIG9yIGNoYXJnZSBtb3JlIHRoYW4gdGhlIG1heGltdW0gcmF0ZQAAAAASSW52YWxpZEZlZVNjaGVkdWxlAAAAAAAoAAAARFByb21vIGNvZGUgaXMgdW5rbm93biwgdXNlZCB1cCwgb3IgY3JlYXRlZCB3aXRoIGEgemVybyBhbW91bnQgb3IgY2FwAAAAEEludmFsaWRQcm9tb0NvZGUAAAApAAAAKEVhY2ggcGxheWVyIGNhbiByZWRlZW0gYSBwcm9tbyBjb2RlIG9uY2UAAAAUUHJvbW9BbHJlYWR5UmVkZWVtZWQAAAAqAAAAJ1RoZSBjaGFsbGVuZ2Ugd2FzIGRlY2xpbmVkIG9yIGNhbmNlbGxlZAAAAAAPQ2hhbGxlbmdlQ2xvc2VkAAAAACsAAACCVGhlIG9wZW4gY2hhbGxlbmdlIGJvYXJkIGlzIGZ1bGwsIG9yIHRoZSBwbGF5ZXIgYWxyZWFkeSBoYXMgMyBjaGFsbGVuZ2VzIG9uCml0OyB3YWl0IGZvciBzb21lIHRvIGJlIHRha2VuIG9yIGV4cGlyZSwgb3IgY2FuY2VsIG9uZQAAAAAAFVRvb01hbnlPcGVuQ2hhbGxlbmdlcwAAAAAAACwAAAAhVGhlIHBsYXllcidzIGZyaWVuZHMgbGlzdCBpcyBmdWxsAAAAAAAADlRvb01hbnlGcmllbmRzAAAAAAAtAAAAH1RoZSBzZXNzaW9uIGhhc24ndCBmaW5pc2hlZCB5ZXQAAAAADEdhbWVOb3RFbmRlZAAAAC4AAAAnQSBzZXJpZXMgbXVzdCBiZSBiZXN0IG9mIDMgb3IgYmVzdCBvZiA1AAAAABNJbnZhbGlkU2VyaWVzTGVuZ3RoAAAAAC8AAAAAAAAAE1Nlcmllc0FscmVhZHlFeGlzdHMAAAAAMAAAAAAAAAAOU2VyaWVzTm90Rm91bmQAAAAAADEAAAAAAAAAElRvdXJuYW1lbnROb3RGb3VuZAAAAAAAMgAAADtCYWQgZmllbGQgc2l6ZSAoYSBwb3dlciBvZiB0d28gdXAgdG8gMzIpLCBkZWFkbGluZSBvciBlbnRyeQAAAAARSW52YWxpZFRvdXJuYW1lbnQAAAAAAAAzAAAAAAAAAA5Ub3VybmFtZW50RnVsbAAAAAAANAAAAAAAAAASUmVnaXN0cmF0aW9uQ2xvc2VkAAAAAAA1AAAAAAAAABFBbHJlYWR5UmVnaXN0ZXJlZAAAAAAAADYAAAAyVGhlc2UgcHVibGljIGlucHV0cyB3ZXJlIGFscmVhZHkgdXNlZCBmb3IgYSBjb21taXQAAAAAAA1OdWxsaWZpZXJVc2VkAAAAAAAANwAAACFBIGBSdWxlU2V0YCB2YWx1ZSBpcyBvdXQgb2YgcmFuZ2UAAAAAAAAMSW52YWxpZFJ1bGVzAAAAOAAAAEZSZXNlcnZlZDogcmV2ZWFscyBvbmNlIGNhcnJpZWQgYSB3YWdlciBmaWVsZCBjaGVja2VkIGFnYWluc3QgdGhlIHN0YWtlAAAAAAANV2FnZXJNaXNtYXRjaAAAAAAAADkAAAA5VGVhbXMgbXVzdCBiZSB0d28gcGxheWVycyBlYWNoLCB3aXRoIG5vYm9keSBvbiBib3RoIHNpZGVzAAAAAAAAC0ludmFsaWRUZWFtAAAAADoAAAAAAAAAD1JlbWF0Y2hOb3RGb3VuZAAAAAA7AAAAMkEgcmVtYXRjaCBvZiB0aGlzIHNlc3Npb24gaGFzIGFscmVhZHkgYmVlbiBvZmZlcmVkAAAAAAAXUmVtYXRjaEFscmVhZHlSZXF1ZXN0ZWQAAAAAPAAAAEpUaGUgZ2FtZSBjb21taXRzIHRoZSBvdGhlciB3YXk6IFpLIGNhbGxzIG9uIGEgaGFzaC1tb2RlIGdhbWUgb3IgdmljZSB2ZXJzYQAAAAAAD1dyb25nQ29tbWl0TW9kZQAAAAA9AAAAMk5vIHZlcmlmaWVyIGlzIHJlZ2lzdGVyZWQgZm9yIHRoaXMgY2lyY3VpdCB2ZXJzaW9uAAAAAAAVVW5rbm93bkNpcmN1aXRWZXJzaW9uAAAAAAAAPgAAAElUaGUgZ2FtZSdzIGNpcmN1aXQgdmVyc2lvbiBoYXMgYmVlbiBkZXByZWNhdGVkLCBzbyBpdHMgcHJvb2ZzIGFyZSByZWZ1c2VkAAAAAAAAEUNpcmN1aXREZXByZWNhdGVkAAAAAAAAPwAAAEBOZXcgZ2FtZXMgYXJlIHBpbm5lZCB0byB0aGlzIHZlcnNpb247IHJlZ2lzdGVyIGEgbmV3ZXIgb25lIGZpcnN0AAAADENpcmN1aXRJblVzZQAAAEAAAAA8UHJlZGljdGlvbnMgb24gdGhpcyBnYW1lIGNsb3NlZCB3aGVuIHRoZSBmaXJzdCBjb21taXQgbGFuZGVkAAAAEVByZWRpY3Rpb25zQ2xvc2VkAAAAAAAAQQAAAGtCYWQgYW1vdW50LCBiYWNrZWQgcGxheWVyIG9yIHNpZGUsIGEgcGxheWVyIHByZWRpY3Rpbmcgb24gdGhlaXIgb3duIGdhbWUsIG9yCm5vIHByZWRpY3Rpb24gdG9rZW4gY29uZmlndXJlZAAAAAARSW52YWxpZFByZWRpY3Rpb24AAAAAAABCAAAAOFRoZSBydW5uaW5nIHNlYXNvbiBoYXMgdG8gZW5kIGJlZm9yZSB0aGUgbmV4dCBvbmUgc3RhcnRzAAAAEFNlYXNvbkluUHJvZ3Jlc3MAAABDAAAATlNlYXNvbiBJRCBub3QgYWJvdmUgdGhlIGxhc3Qgb25lLCBlbmQgdGltZSBhbHJlYWR5IHBhc3NlZCwgb3Igbm8gc2Vhc29uIHRvIGVuZAAAAAAADUludmFsaWRTZWFzb24AAAAAAABEAAAAK1RoZSBjbGFpbSB3aW5kb3cgZm9yIHRoaXMgcmV3YXJkIGhhcyBjbG9zZWQAAAAADENsYWltRXhwaXJlZAAAAEUAAABWQSBwbGF5ZXIgaGFzIGFscmVhZHkgY29tbWl0dGVkLCBzbyB0aGUgZ2FtZSBjYW4gb25seSBlbmQgdGhyb3VnaCBwbGF5IG9yCml0cyBkZWFkbGluZXMAAAAAAA5HYW1lSW5Qcm9ncmVzcwAAAAAARgAAAJNBIHNlc3Npb24gc2lnbmVyIG11c3QgYmUgYSBkaWZmZXJlbnQgYWRkcmVzcyBmcm9tIHRoZSBwbGF5ZXIsIGNhbid0IGJlIHNldCBmb3IKYSBnYW1lIHRoYXQgdGFrZXMgYSBjb21taXQgZGVwb3NpdCwgYW5kIGNhbiBvbmx5IGJlIHJldm9rZWQgb25jZSBzZXQAAAAAFEludmFsaWRTZXNzaW9uU2lnbmVyAAAARwAAADVgc3dlZXBfZmVlc2AgbmVlZHMgYSB0cmVhc3VyeSBzZXQgd2l0aCBgc2V0X3RyZWFzdXJ5YAAAAAAAAApOb1RyZWFzdXJ5AAAAAABIAAAAW1RoZSBjaGFsbGVuZ2UgY2hhbmdlZCAoZS5nLiB3YXMgZm9yd2FyZGVkKSBzaW5jZSB0aGUgbm9uY2UgdGhlIGFjY2VwdGFuY2Ugd2FzCmJ1aWx0IGFnYWluc3QAAAAADlN0YWxlQ2hhbGxlbmdlAAAAAABJAAAATVRoZSBhZG1pbiBoYXMgcGF1c2VkIHRoaXMgZW50cnlwb2ludCAob3IgdGhlIHdob2xlIGNvbnRyYWN0KTsgc2VlIGBnZXRfcGF1c2VgAAAAAAAADkNvbnRyYWN0UGF1c2VkAAAAAABKAAAAQk5vdCB0aGUgcGxheWVyJ3MgY29hY2ggZm9yIHRoZSBnYW1lLCBvciBhIHBsYXllciBuYW1pbmcgdGhlbXNlbHZlcwAAAAAADEludmFsaWRDb2FjaAAAAEsAAAA2Q29hY2hlcyBzZWUgdGhlIG1vdmVzIG9ubHkgb25jZSB0aGUgYmF0dGxlIGlzIHJlc29sdmVkAAAAAAAOQ29hY2hpbmdMb2NrZWQAAAAAAEwAAAA7VGhlIHBsYXllciAob3IgbmFtZSkgaGFzIG5vIHVzZXJuYW1lIHRvIHJlbGVhc2Ugb3IgdHJhbnNmZXIAAAAACk5vVXNlcm5hbWUAAAAAAE0AAAA0QSBnYW1lIG9yIGdhbWUgcHJvcG9zYWwgYWxyZWFkeSB1c2VzIHRoaXMgc2Vzc2lvbiBJRAAAAAxTZXNzaW9uVGFrZW4AAABOAAAASk5vIHNwb25zb3IgdGllciBpcyBzZXQsIG9yIGEgcGxheWVyIGhhcyBwbGF5ZWQgdG9vIG1hbnkgcmF0ZWQgZ2FtZXMgZm9yIGl0AAAAAAAMTm90U3BvbnNvcmVkAAAATwAAACxBIHBsYXllciBoYXMgdXNlZCB1cCB0b2RheSdzIHNwb25zb3JlZCBnYW1lcwAAABNTcG9uc29yTGltaXRSZWFjaGVkAAAAAFAAAABFVGhlIHNwb25zb3IgcG9vbCBjYW4ndCBjb3ZlciBib3RoIHN0YWtlcywgb3IgdGhlIHdpdGhkcmF3YWwgYXNrZWQgZm9yAAAAAAAAEFNwb25zb3JQb29sRW1wdHkAAABRAAAAT1N0YWtlIG9yIGRhaWx5IGxpbWl0IG5vdCBwb3NpdGl2ZSwgb3IgYSB0b2tlbiBjaGFuZ2Ugd2hpbGUgdGhlIHBvb2wgaG9sZHMgZnVuZHMAAAAAEkludmFsaWRTcG9uc29yVGllcgAAAAAAUgAAAIBOb3QgYSBsaXZlIGNvcnJlc3BvbmRlbmNlIGdhbWUsIG5vIHBhdXNlIHRvIGFjY2VwdCBvciByZXN1bWUsIG9uZSBhbHJlYWR5CnJ1bm5pbmcsIG9yIG1vcmUgcGF1c2VkIHRpbWUgdGhhbiB0aGUgZ2FtZSdzIGFsbG93YW5jZQAAAAxJbnZhbGlkUGF1c2UAAABTAAAAI1RoZSBwbGF5ZXIgYWxyZWFkeSBuYW1lZCBhIHJlZmVycmVyAAAAAA9BbHJlYWR5UmVmZXJyZWQAAAAAVAAAAEhTZWxmLXJlZmVycmFsLCBhIHBsYXllciB3aG8gaGFzIGFscmVhZHkgcGxheWVkLCBvciBhIHJlZmVycmVyIHdobyBoYXNuJ3QAAAAPSW52YWxpZFJlZmVycmFsAAAAAFUAAACdU2VsZiBsaW5rLCBubyBtYXRjaGluZyByZXF1ZXN0LCBhIHNlY29uZGFyeSB3aXRoIGEgcmVjb3JkLCBsaXZlIGdhbWVzIG9yCmNoYWxsZW5nZXMgb2YgaXRzIG93biwgYSBwcmltYXJ5IHRoYXQgaXMgaXRzZWxmIGxpbmtlZCwgb3IgdG9vIG1hbnkgbGlua2VkCmFkZHJlc3NlcwAAAAAAAAtJbnZhbGlkTGluawAAAABWAAAAN1NldHRsaW5nIG1vcmUgYm9udXMgcG9pbnRzIHRoYW4gdGhlIHBsYXllciBoYXMsIG9yIG5vbmUAAAAAEkluc3VmZmljaWVudFBvaW50cwAAAAAAVwAAADhUaGUgZ2FtZSBhbHJlYWR5IGhvbGRzIHRoZSBtb3N0IHNwZWN0YXRvciBwaWNrcyBpdCB0YWtlcwAAAAlQaWNrc0Z1bGwAAAAAAABYAAAAOEEgbGVkZ2VyIHJhbmdlIHRoYXQgaXMgcmV2ZXJzZWQgb3Igc3BhbnMgbW9yZSB0aGFuIGEgZGF5AAAADEludmFsaWRSYW5nZQAAAFkAAABNQSBzaWduZWQgcmVzdWx0IGZvciB0aGlzIHNlc3Npb24gd2FzIGRpc3B1dGVkOyBpdCBtdXN0IGJlIHBsYXllZCBvdXQgb24tY2hhaW4AAAAAAAASU2V0dGxlbWVudERpc3B1dGVkAAAAAABaAAAALEEgcGxheWVyIHRyaWVkIHRvIGFkZCB0aGVtc2VsdmVzIGFzIGEgZnJpZW5kAAAAEENhbm5vdEZyaWVuZFNlbGYAAABbAAAAR0EgcGxheWVyIGlzIGFscmVhZHkgaW4gYXMgbWFueSB1bnNldHRsZWQgZ2FtZXMgYW5kIGR1ZWxzIGFzIHRoZXkgY2FuIGJlAAAAABNUb29NYW55TGl2ZVNlc3Npb25zAAAAAFwAAADQUHJpemUgc2hhcmVzIHRoYXQgYXJlIGVtcHR5LCBwYXkgbW9yZSBwbGFjZXMgdGhhbiB0aGUgZmllbGQgb3IgZG9uJ3Qgc3VtIHRvCjEwMCUsIGEgcG9vbCB0aGF0IGlzIGVtcHR5IG9yIGFscmVhZHkgc2V0LCBvciB2ZXN0aW5nIHRoYXQgcGF5cyBldmVyeXRoaW5nIGF0Cm9uY2UsIGxvY2tzIGZvciBubyB0aW1lIG9yIHRvbyBsb25nLCBvciBpcyBhbHJlYWR5IHNldAAAABRJbnZhbGlkUHJpemVTY2hlZHVsZQAAAF0AAABBQ2hlY2staW4gaXMgb25seSBvcGVuIGluIHRoZSBoYWxmIGhvdXIgYmVmb3JlIGEgdG91cm5hbWVudCBzdGFydHMAAAAAAAANQ2hlY2tJbkNsb3NlZAAAAAAAAF4AAAA3VGhlIGxvY2tlZCBwYXJ0IG9mIGEgdG91cm5hbWVudCBwcml6ZSBoYXNuJ3QgdmVzdGVkIHlldAAAAAARUHJpemVTdGlsbFZlc3RpbmcAAAAAAABfAAAANlRoZSB0b3VybmFtZW50IGhhcyBhbHJlYWR5IGNvbXBsZXRlZCBvciBiZWVuIGNhbmNlbGxlZAAAAAAAElRvdXJuYW1lbnRGaW5pc2hlZAAAAAAAYAAAAJBUaGUgdmVyaWZpZXIgdHJhcHBlZCBvciByZXR1cm5lZCBzb21ldGhpbmcgb3RoZXIgdGhhbiBgKClgLCBzbyB0aGUgcHJvb2YKY291bGQgbm90IGJlIGNoZWNrZWQgYXQgYWxsIChtaXNjb25maWd1cmVkIG9yIGJyb2tlbiB2ZXJpZmllciBjb250cmFjdCkAAAAPVmVyaWZpZXJUcmFwcGVkAAAAAGE=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAeAAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAAAAAAEAAAAAAAAACFZlcmlmaWVyAAAAAQAAAAQAAAAAAAAAAAAAAA5DaXJjdWl0VmVyc2lvbgAAAAAAAAAAAAAAAAAPUHJlZGljdGlvblRva2VuAAAAAAEAAAAAAAAADlByZWRpY3Rpb25Qb29sAAAAAAABAAAABAAAAAEAAAAAAAAAClByZWRpY3Rpb24AAAAAAAIAAAAEAAAAEwAAAAEAAAAAAAAABVBpY2tzAAAAAAAAAQAAAAQAAAABAAAAAAAAAAlQaWNrU3RhdHMAAAAAAAABAAAAEwAAAAAAAAAAAAAABlNlYXNvbgAAAAAAAQAAAAAAAAALU2Vhc29uU3RhdHMAAAAAAgAAAAQAAAATAAAAAQAAAAAAAAARU2Vhc29uTGVhZGVyYm9hcmQAAAAAAAABAAAABAAAAAEAAAAAAAAADVNlYXNvblJlc3VsdHMAAAAAAAABAAAABAAAAAAAAAAAAAAADVNlYXNvblJld2FyZHMAAAAAAAABAAAAAAAAAA9TZWFzb25QbGFjZW1lbnQAAAAAAgAAAAQAAAATAAAAAQAAAAAAAAAMQWNoaWV2ZW1lbnRzAAAAAQAAABMAAAABAAAAAAAAAA1EYWlseUJvbnVzRGF5AAAAAAAAAQAAABMAAAABAAAAAAAAAAtCb251c1BvaW50cwAAAAABAAAAEwAAAAAAAAAAAAAAC1Nwb25zb3JUaWVyAAAAAAAAAAAAAAAAC1Nwb25zb3JQb29sAAAAAAEAAAAAAAAAC1Nwb25zb3JzaGlwAAAAAAEAAAAEAAAAAQAAAAAAAAAJR2FtZVBhdXNlAAAAAAAAAQAAAAQAAAAAAAAAAAAAAAxNYXhHYW1lUGF1c2UAAAABAAAAAAAAAAhSZWZlcnJlcgAAAAEAAAATAAAAAQAAAAAAAAANUmVmZXJyYWxTdGF0cwAAAAAAAAEAAAATAAAAAAAAAAAAAAANUmVmZXJyYWxCb251cwAAAAAAAAEAAAAAAAAADlNwb25zb3JlZEdhbWVzAAAAAAABAAAAEwAAAAAAAAAAAAAACUh1Ykxhc3RPawAAAAAAAAEAAAAAAAAACFVzZXJuYW1lAAAAAQAAABMAAAABAAAAAAAAABFBZGRyZXNzQnlVc2VybmFtZQAAAAAAAAEAAAAQAAAAAQAAAAAAAAAMTGFzdEFjdGl2ZUF0AAAAAQAAABMAAAAAAAAAAAAAAA5Vc2VybmFtZUV4cGlyeQAAAAAAAQAAAAAAAAAMQ2FwdGFpbkNsYXNzAAAAAQAAABMAAAABAAAAAAAAAAhMaW5rZWRUbwAAAAEAAAATAAAAAQAAAAAAAAAPTGlua2VkQWRkcmVzc2VzAAAAAAEAAAATAAAAAQAAAAAAAAALTGlua1JlcXVlc3QAAAAAAQAAABMAAAABAAAAAAAAAAlDaGFsbGVuZ2UAAAAAAAABAAAABAAAAAAAAAAAAAAAEENoYWxsZW5nZUNvdW50ZXIAAAABAAAAAAAAABBQbGF5ZXJDaGFsbGVuZ2VzAAAAAQAAABMAAAABAAAAAAAAAAVNYXRjaAAAAAAAAAEAAAAEAAAAAAAAAAAAAAAMTWF0Y2hDb3VudGVyAAAAAQAAAAAAAAANUGxheWVyTWF0Y2hlcwAAAAAAAAEAAAATAAAAAAAAAAAAAAAUVG9rZW5Db250cmFjdEFkZHJlc3MAAAAAAAAAAAAAAAlCdWlsZEluZm8AAAAAAAAAAAAAAAAAAA5TdG9yYWdlVmVyc2lvbgAAAAAAAAAAAAAAAAAPTWlncmF0aW9uQ3Vyc29yAAAAAAAAAAAAAAAAEk1pZ3JhdGlvblN0YXJ0ZWRBdAAAAAAAAQAAAAAAAAAERHVlbAAAAAEAAAAEAAAAAQAAAAAAAAAKU2llZ2VTdGF0cwAAAAAAAQAAABMAAAAAAAAAAAAAAA9QcmFjdGljZUNvdW50ZXIAAAAAAQAAAAAAAAAMUHJhY3RpY2VHYW1lAAAAAQAAAAQAAAABAAAAAAAAAA1QcmFjdGljZVN0YXRzAAAAAAAAAgAAABMAAAfQAAAAElByYWN0aWNlRGlmZmljdWx0eQAAAAAAAAAAAAAAAAALV2FnZXJMaW1pdHMAAAAAAAAAAAAAAAANQ29tbWl0RGVwb3NpdAAAAAAAAAAAAAAAAAAAEVJlcXVpcmVSZWdpc3RlcmVkAAAAAAAAAQAAAAAAAAARUGVuZGluZ1NldHRsZW1lbnQAAAAAAAABAAAABAAAAAEAAAAAAAAAElNldHRsZW1lbnREaXNwdXRlZAAAAAAAAQAAAAQAAAABAAAAAAAAAA1TZXR0bGVtZW50S2V5AAAAAAAAAQAAABMAAAAAAAAAAAAAAApHYW1lQ29uZmlnAAAAAAAAAAAAAAAAAAtSYWlzZUNvbmZpZwAAAAABAAAAAAAAAApJbnZpdGVDb2RlAAAAAAABAAAABgAAAAEAAAAAAAAADEdhbWVQcm9wb3NhbAAAAAEAAAAEAAAAAQAAAAAAAAAFUXVldWUAAAAAAAABAAAABAAAAAEAAAAAAAAAClF1ZXVlZFRpZXIAAAAAAAEAAAATAAAAAQAAAAAAAAAGRXNjcm93AAAAAAABAAAABAAAAAAAAAAAAAAAC0ZlZVNjaGVkdWxlAAAAAAEAAAAAAAAADU9wZW5DaGFsbGVuZ2UAAAAAAAABAAAABAAAAAAAAAAAAAAADk9wZW5DaGFsbGVuZ2VzAAAAAAABAAAAAAAAAAdGcmllbmRzAAAAAAEAAAATAAAAAQAAAAAAAAAOTG9va2luZ0ZvckdhbWUAAAAAAAEAAAATAAAAAAAAAAAAAAALR2xvYmFsU3RhdHMAAAAAAQAAAAAAAAALUGxheWVyU3RhdHMAAAAAAQAAABMAAAAAAAAAAAAAAAtMZWFkZXJib2FyZAAAAAAAAAAAAAAAAA9SYXRpbmdIaXN0b2dyYW0AAAAAAAAAAAAAAAALUmF0aW5nRGVjYXkAAAAAAQAAAAAAAAAKRGVjYXlTaW5jZQAAAAAAAQAAABMAAAABAAAAAAAAAAxNYXRjaEhpc3RvcnkAAAABAAAAEwAAAAEAAAAAAAAACU1hdGNoTm90ZQAAAAAAAAIAAAATAAAABAAAAAEAAAAAAAAABVJpdmFsAAAAAAAAAQAAABMAAAABAAAAAAAAAApIZWFkVG9IZWFkAAAAAAACAAAAEwAAABMAAAABAAAAAAAAAA1Db2xsZWN0ZWRGZWVzAAAAAAAAAQAAABMAAAAAAAAAAAAAAAhUcmVhc3VyeQAAAAAAAAAAAAAACU1vZGVyYXRvcgAAAAAAAAEAAAAAAAAACFNoYWRvd2VkAAAAAQAAABMAAAAAAAAAAAAAAAVQYXVzZQAAAAAAAAEAAAAAAAAACFdpbm5pbmdzAAAAAgAAAAQAAAATAAAAAQAAAAAAAAAJUHJvbW9Db2RlAAAAAAAAAQAAABEAAAABAAAAAAAAAA1Qcm9tb1JlZGVlbWVkAAAAAAAAAgAAABEAAAATAAAAAQAAAAAAAAAKRmVlV2FpdmVycwAAAAAAAQAAABMAAAABAAAAAAAAAAxEZXBvc2l0Qm9udXMAAAABAAAAEwAAAAAAAAAAAAAAElBlbmRpbmdSZXNvbHV0aW9ucwAAAAAAAQAAAAAAAAASQ2hhbGxlbmdlQnlTZXNzaW9uAAAAAAABAAAABAAAAAEAAAAAAAAADVNlc3Npb25TaWduZXIAAAAAAAACAAAABAAAABMAAAABAAAAAAAAAA1QbGF5YmFja1R1cm5zAAAAAAAAAQAAAAQAAAABAAAAAAAAAAZTZXJpZXMAAAAAAAEAAAAEAAAAAQAAAAAAAAAPU2VyaWVzQnlTZXNzaW9uAAAAAAEAAAAEAAAAAQAAAAAAAAAOUGxheWVyU2Vzc2lvbnMAAAAAAAEAAAATAAAAAQAAAAAAAAAGQmFkZ2VzAAAAAAABAAAAEwAAAAEAAAAAAAAACUJhZGdlT3dlZAAAAAAAAAEAAAAEAAAAAQAAAAAAAAALQWN0aXZpdHlMb2cAAAAAAQAAABMAAAABAAAAAAAAAA1TZXNzaW9uQnVja2V0AAAAAAAAAQAAAAQAAAABAAAAAAAAAAtTZXNzaW9uUGFnZQAAAAACAAAABAAAAAQAAAAAAAAAAAAAABFUb3VybmFtZW50Q291bnRlcgAAAAAAAAEAAAAAAAAAClRvdXJuYW1lbnQAAAAAAAEAAAAEAAAAAQAAAAAAAAAHQnJhY2tldAAAAAABAAAABAAAAAEAAAAAAAAAEFRvdXJuYW1lbnRQcml6ZXMAAAABAAAABAAAAAEAAAAAAAAAD1RvdXJuYW1lbnRQcml6ZQAAAAACAAAABAAAABMAAAABAAAAAAAAAAxQcml6ZVZlc3RpbmcAAAABAAAABAAAAAEAAAAAAAAAC1Zlc3RlZFByaXplAAAAAAIAAAAEAAAAEwAAAAAAAAAAAAAAEk51bGxpZmllclJldGVudGlvbgAAAAAAAQAAAAAAAAAJTnVsbGlmaWVyAAAAAAAAAQAAA+4AAAAgAAAAAAAAAAAAAAAHUnVsZVNldAAAAAABAAAAAAAAAAhUZWFtR2FtZQAAAAEAAAAEAAAAAQAAAAAAAAANQ29tbWl0UmVjZWlwdAAAAAAAAAIAAAAEAAAAEwAAAAEAAAAAAAAAB1JlbWF0Y2gAAAAAAQAAAAQAAAABAAAAAAAAAAlSZW1hdGNoT2YAAAAAAAABAAAABAAAAAEAAAAAAAAADVJpdmFscnlTZXJpZXMAAAAAAAACAAAAEwAAABMAAAABAAAAAAAAAAxMYXN0T3Bwb25lbnQAAAABAAAAEwAAAAAAAAAAAAAACk1vdmVQYXVzZXMAAA==",
+        "AAAAAwAAAAAAAAAAAAAABkF0dGFjawAAAAAABQAAAAAAAAAFU2xhc2gAAAAAAAAAAAAAAAAAAAhGaXJlYmFsbAAAAAEAAAAAAAAACUxpZ2h0bmluZwAAAAAAAAIAAAAAAAAACUJyb2Fkc2lkZQAAAAAAAAMAAAAAAAAABEhvb2sAAAAE",
+        "AAAAAwAAAAAAAAAAAAAAB0RlZmVuc2UAAAAABAAAAAAAAAAFQmxvY2sAAAAAAAAAAAAAAAAAAAVEb2RnZQAAAAAAAAEAAAAAAAAAB0NvdW50ZXIAAAAAAgAAAAAAAAAHUmlwb3N0ZQAAAAAD",
         "AAAAAQAAAAAAAAAAAAAABE1vdmUAAAACAAAAAAAAAAZhdHRhY2sAAAAAB9AAAAAGQXR0YWNrAAAAAAAAAAAAB2RlZmVuc2UAAAAH0AAAAAdEZWZlbnNlAA==",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAEwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAQQWxyZWFkeUNvbW1pdHRlZAAAAAMAAAAAAAAAF0JvdGhQbGF5ZXJzTm90Q29tbWl0dGVkAAAAAAQAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAFAAAAAAAAAAxJbnZhbGlkUHJvb2YAAAAGAAAAAAAAABdQcm9vZlZlcmlmaWNhdGlvbkZhaWxlZAAAAAAHAAAAAAAAABNJbnZhbGlkTW92ZVNlcXVlbmNlAAAAAAgAAAAAAAAAFFVzZXJuYW1lQWxyZWFkeVRha2VuAAAACQAAAAAAAAAPVXNlcm5hbWVUb29Mb25nAAAAAAoAAAAAAAAAEUNoYWxsZW5nZU5vdEZvdW5kAAAAAAAACwAAAAAAAAAQQ2hhbGxlbmdlRXhwaXJlZAAAAAwAAAAAAAAAE0Nhbm5vdENoYWxsZW5nZVNlbGYAAAAADQAAAAAAAAAQVXNlcm5hbWVUb29TaG9ydAAAAA4AAAAAAAAAFUludmFsaWRVc2VybmFtZUZvcm1hdAAAAAAAAA8AAAAAAAAAEFVzZXJuYW1lUmVzZXJ2ZWQAAAAQAAAAAAAAAA9BbHJlYWR5UmV2ZWFsZWQAAAAAEQAAAAAAAAASQ29tbWl0bWVudE1pc21hdGNoAAAAAAASAAAAAAAAABNJbnZhbGlkUHVibGljSW5wdXRzAAAAABM=",
-        "AAAAAwAAAAAAAAAAAAAABkF0dGFjawAAAAAAAwAAAAAAAAAFU2xhc2gAAAAAAAAAAAAAAAAAAAhGaXJlYmFsbAAAAAEAAAAAAAAACUxpZ2h0bmluZwAAAAAAAAI=",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAADQAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAAAAAAAAAAAAAAAAEVVsdHJhaG9ua3ZlcmlmaWVyAAAAAAAAAQAAAAAAAAAIVXNlcm5hbWUAAAABAAAAEwAAAAEAAAAAAAAAEUFkZHJlc3NCeVVzZXJuYW1lAAAAAAAAAQAAABAAAAABAAAAAAAAAAlDaGFsbGVuZ2UAAAAAAAABAAAABAAAAAAAAAAAAAAAEENoYWxsZW5nZUNvdW50ZXIAAAABAAAAAAAAABBQbGF5ZXJDaGFsbGVuZ2VzAAAAAQAAABMAAAABAAAAAAAAAAVNYXRjaAAAAAAAAAEAAAAEAAAAAAAAAAAAAAAMTWF0Y2hDb3VudGVyAAAAAQAAAAAAAAANUGxheWVyTWF0Y2hlcwAAAAAAAAEAAAATAAAAAAAAAAAAAAAUVG9rZW5Db250cmFjdEFkZHJlc3M=",
-        "AAAAAwAAAAAAAAAAAAAAB0RlZmVuc2UAAAAAAwAAAAAAAAAFQmxvY2sAAAAAAAAAAAAAAAAAAAVEb2RnZQAAAAAAAAEAAAAAAAAAB0NvdW50ZXIAAAAAAg==",
-        "AAAAAQAAAAAAAAAAAAAACFB2UE1hdGNoAAAACQAAAAAAAAAMY3VycmVudF90dXJuAAAABAAAAAAAAAALbGFzdF9hY3Rpb24AAAAD6AAAB9AAAAAETW92ZQAAAAAAAAAIbWF0Y2hfaWQAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAACnBsYXllcjFfaHAAAAAAAAUAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAKcGxheWVyMl9ocAAAAAAABQAAAAAAAAAFc3RhdGUAAAAAAAfQAAAACk1hdGNoU3RhdGUAAAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
-        "AAAAAQAAAAAAAAAAAAAACUNoYWxsZW5nZQAAAAAAAAkAAAAAAAAADGNoYWxsZW5nZV9pZAAAAAQAAAAAAAAACmNoYWxsZW5nZWQAAAAAABMAAAAAAAAACmNoYWxsZW5nZXIAAAAAABMAAAAAAAAACmNyZWF0ZWRfYXQAAAAAAAYAAAAAAAAACmV4cGlyZXNfYXQAAAAAAAYAAAAAAAAAC2lzX2FjY2VwdGVkAAAAAAEAAAAAAAAADGlzX2NvbXBsZXRlZAAAAAEAAAAAAAAADnBvaW50c193YWdlcmVkAAAAAAALAAAAAAAAAApzZXNzaW9uX2lkAAAAAAPoAAAABA==",
-        "AAAABAAAAAAAAAAAAAAACkNsYXNoRXJyb3IAAAAAAAMAAAAAAAAAEENvbW1pdG1lbnRFeGlzdHMAAAABAAAAAAAAAA1OdWxsaWZpZXJVc2VkAAAAAAAAAgAAAAAAAAASVmVyaWZpY2F0aW9uRmFpbGVkAAAAAAAD",
-        "AAAAAwAAAAAAAAAAAAAACk1hdGNoU3RhdGUAAAAAAAQAAAAAAAAAB0NyZWF0ZWQAAAAAAAAAAAAAAAAIQWNjZXB0ZWQAAAABAAAAAAAAAAZBY3RpdmUAAAAAAAIAAAAAAAAACEZpbmlzaGVkAAAAAw==",
-        "AAAAAQAAAAAAAAAAAAAAClR1cm5SZXN1bHQAAAAAAAcAAAAAAAAAFHBsYXllcjFfZGFtYWdlX2RlYWx0AAAABQAAAAAAAAAacGxheWVyMV9kZWZlbnNlX3N1Y2Nlc3NmdWwAAAAAAAEAAAAAAAAAFHBsYXllcjFfaHBfcmVtYWluaW5nAAAABQAAAAAAAAAUcGxheWVyMl9kYW1hZ2VfZGVhbHQAAAAFAAAAAAAAABpwbGF5ZXIyX2RlZmVuc2Vfc3VjY2Vzc2Z1bAAAAAAAAQAAAAAAAAAUcGxheWVyMl9ocF9yZW1haW5pbmcAAAAFAAAAAAAAAAR0dXJuAAAABA==",
-        "AAAAAQAAAAAAAAAAAAAADEJhdHRsZVJlc3VsdAAAAAUAAAAAAAAAB2lzX2RyYXcAAAAAAQAAAAAAAAAKcGxheWVyMV9ocAAAAAAABQAAAAAAAAAKcGxheWVyMl9ocAAAAAAABQAAAAAAAAAMdHVybl9yZXN1bHRzAAAD6gAAB9AAAAAKVHVyblJlc3VsdAAAAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAAT",
-        "AAAAAQAAAAAAAAAAAAAADEdhbWVQbGF5YmFjawAAAAoAAAAAAAAAEGZpbmFsX3BsYXllcjFfaHAAAAAFAAAAAAAAABBmaW5hbF9wbGF5ZXIyX2hwAAAABQAAAAAAAAAHaXNfZHJhdwAAAAABAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAEHBsYXllcjFfdXNlcm5hbWUAAAPoAAAAEAAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAABBwbGF5ZXIyX3VzZXJuYW1lAAAD6AAAABAAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAADHR1cm5fcmVzdWx0cwAAA+oAAAfQAAAAEkRldGFpbGVkVHVyblJlc3VsdAAAAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAAT",
         "AAAAAQAAAAAAAAAAAAAADE1vdmVTZXF1ZW5jZQAAAAEAAAAAAAAABW1vdmVzAAAAAAAD6gAAB9AAAAAETW92ZQ==",
-        "AAAAAAAAAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
-        "AAAAAAAAABRHZXQgZ2FtZSBpbmZvcm1hdGlvbgAAAAhnZXRfZ2FtZQAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAER2FtZQAAAAM=",
-        "AAAAAAAAAAAAAAAJZW5kX21hdGNoAAAAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAhtYXRjaF9pZAAAAAQAAAABAAAD6QAAAAIAAAAD",
-        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAAAAAAAJZ2V0X21hdGNoAAAAAAAAAQAAAAAAAAAIbWF0Y2hfaWQAAAAEAAAAAQAAA+kAAAfQAAAACFB2UE1hdGNoAAAAAw==",
-        "AAAAAAAAAAAAAAAJcGxheV90dXJuAAAAAAAAAwAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAhtYXRjaF9pZAAAAAQAAAAAAAAABmFjdGlvbgAAAAAH0AAAAARNb3ZlAAAAAQAAA+kAAAACAAAAAw==",
-        "AAAAAAAAAAAAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
         "AAAAAQAAAAAAAAAAAAAAEFBsYXllckNvbW1pdG1lbnQAAAADAAAAAAAAAAxoYXNfcmV2ZWFsZWQAAAABAAAAAAAAAAVtb3ZlcwAAAAAAB9AAAAAMTW92ZVNlcXVlbmNlAAAAAAAAAAhwcm9vZl9pZAAAA+4AAAAg",
-        "AAAAAAAAAAAAAAAKYWRkX3BvbGljeQAAAAAAAwAAAAAAAAAPY29udGV4dF9ydWxlX2lkAAAAAAQAAAAAAAAABnBvbGljeQAAAAAAEwAAAAAAAAANaW5zdGFsbF9wYXJhbQAAAAAAAAAAAAABAAAABA==",
-        "AAAAAAAAAAAAAAAKYWRkX3NpZ25lcgAAAAAAAgAAAAAAAAAPY29udGV4dF9ydWxlX2lkAAAAAAQAAAAAAAAABnNpZ25lcgAAAAAH0AAAAAZTaWduZXIAAAAAAAEAAAAE",
-        "AAAAAAAAADBTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMAAAAKc3RhcnRfZ2FtZQAAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
-        "AAAAAQAAAAAAAAAAAAAAEkRldGFpbGVkVHVyblJlc3VsdAAAAAAACwAAAAAAAAAUcGxheWVyMV9kYW1hZ2VfZGVhbHQAAAAFAAAAAAAAABRwbGF5ZXIxX2RhbWFnZV90YWtlbgAAAAUAAAAAAAAAGnBsYXllcjFfZGVmZW5zZV9zdWNjZXNzZnVsAAAAAAABAAAAAAAAABRwbGF5ZXIxX2hwX3JlbWFpbmluZwAAAAUAAAAAAAAADHBsYXllcjFfbW92ZQAAB9AAAAAETW92ZQAAAAAAAAAUcGxheWVyMl9kYW1hZ2VfZGVhbHQAAAAFAAAAAAAAABRwbGF5ZXIyX2RhbWFnZV90YWtlbgAAAAUAAAAAAAAAGnBsYXllcjJfZGVmZW5zZV9zdWNjZXNzZnVsAAAAAAABAAAAAAAAABRwbGF5ZXIyX2hwX3JlbWFpbmluZwAAAAUAAAAAAAAADHBsYXllcjJfbW92ZQAAB9AAAAAETW92ZQAAAAAAAAAEdHVybgAAAAQ=",
-        "AAAAAAAAAAAAAAAMX19jaGVja19hdXRoAAAAAwAAAAAAAAARc2lnbmF0dXJlX3BheWxvYWQAAAAAAAPuAAAAIAAAAAAAAAAKc2lnbmF0dXJlcwAAAAAH0AAAAAtBdXRoUGF5bG9hZAAAAAAAAAAADWF1dGhfY29udGV4dHMAAAAAAAPqAAAH0AAAAAdDb250ZXh0AAAAAAEAAAPpAAAAAgAAB9AAAAARU21hcnRBY2NvdW50RXJyb3IAAAA=",
-        "AAAAAAAAAJVDb21taXQgbW92ZSBzZXF1ZW5jZSB3aXRoIFpLIHByb29mClBsYXllciBwcm92ZXMgdGhleSBrbm93IHZhbGlkIG1vdmVzIFdJVEhPVVQgcmV2ZWFsaW5nIHRoZW0uClRoZSBwcm9vZidzIHB1YmxpYyBvdXRwdXQgKGNvbW1pdG1lbnQgaGFzaCkgaXMgc3RvcmVkLgAAAAAAAAxjb21taXRfbW92ZXMAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAADXB1YmxpY19pbnB1dHMAAAAAAAAOAAAAAAAAAAtwcm9vZl9ieXRlcwAAAAAOAAAAAQAAA+kAAAPuAAAAIAAAAAM=",
-        "AAAAAAAAABtHZXQgdXNlcm5hbWUgZm9yIGFuIGFkZHJlc3MAAAAADGdldF91c2VybmFtZQAAAAEAAAAAAAAAB2FkZHJlc3MAAAAAEwAAAAEAAAPoAAAAEA==",
-        "AAAAAAAAAJhSZXZlYWwgbW92ZXMg4oCUIHBsYXllciByZS1wcm92ZXMgd2l0aCBtb3ZlcyBub3cgUFVCTElDLgpUaGUgY29udHJhY3QgdmVyaWZpZXMgdGhlIG5ldyBwcm9vZidzIGNvbW1pdG1lbnQgb3V0cHV0Cm1hdGNoZXMgd2hhdCB3YXMgc3RvcmVkIGF0IGNvbW1pdCB0aW1lLgAAAAxyZXZlYWxfbW92ZXMAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAADXB1YmxpY19pbnB1dHMAAAAAAAAOAAAAAAAAAAVtb3ZlcwAAAAAAA+oAAAfQAAAABE1vdmUAAAABAAAD6QAAAAIAAAAD",
-        "AAAAAAAAAAAAAAAMc2V0X3VzZXJuYW1lAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAh1c2VybmFtZQAAABAAAAABAAAD6QAAAAIAAAAD",
-        "AAAAAAAAADZJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4AAAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAwAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAAAAAAEXZlcmlmaWVyX2NvbnRyYWN0AAAAAAAAEwAAAAA=",
-        "AAAAAAAAAAAAAAANYWNjZXB0X2ludml0ZQAAAAAAAAIAAAAAAAAACGFjY2VwdGVyAAAAEwAAAAAAAAAIbWF0Y2hfaWQAAAAEAAAAAQAAA+kAAAACAAAAAw==",
-        "AAAAAAAAAAAAAAANY3JlYXRlX2ludml0ZQAAAAAAAAIAAAAAAAAAB2ludml0ZXIAAAAAEwAAAAAAAAAIb3Bwb25lbnQAAAATAAAAAQAAA+kAAAAEAAAAAw==",
-        "AAAAAAAAAAAAAAANcmVqZWN0X2ludml0ZQAAAAAAAAIAAAAAAAAACHJlamVjdGVyAAAAEwAAAAAAAAAIbWF0Y2hfaWQAAAAEAAAAAQAAA+kAAAACAAAAAw==",
-        "AAAAAAAAAAAAAAANcmVtb3ZlX3BvbGljeQAAAAAAAAIAAAAAAAAAD2NvbnRleHRfcnVsZV9pZAAAAAAEAAAAAAAAAAlwb2xpY3lfaWQAAAAAAAAEAAAAAA==",
-        "AAAAAAAAAAAAAAANcmVtb3ZlX3NpZ25lcgAAAAAAAAIAAAAAAAAAD2NvbnRleHRfcnVsZV9pZAAAAAAEAAAAAAAAAAlzaWduZXJfaWQAAAAAAAAEAAAAAA==",
-        "AAAAAAAAAD9SZXNvbHZlIHRoZSBiYXR0bGUgYWZ0ZXIgYm90aCBwbGF5ZXJzIGhhdmUgcmV2ZWFsZWQgdGhlaXIgbW92ZXMAAAAADnJlc29sdmVfYmF0dGxlAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAADEJhdHRsZVJlc3VsdAAAAAM=",
-        "AAAAAAAAACJTZW5kIGEgY2hhbGxlbmdlIHRvIGFub3RoZXIgcGxheWVyAAAAAAAOc2VuZF9jaGFsbGVuZ2UAAAAAAAMAAAAAAAAACmNoYWxsZW5nZXIAAAAAABMAAAAAAAAACmNoYWxsZW5nZWQAAAAAABMAAAAAAAAADnBvaW50c193YWdlcmVkAAAAAAALAAAAAQAAA+kAAAAEAAAAAw==",
-        "AAAAAAAAAAAAAAAPZ2V0X2NzaF9iYWxhbmNlAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAL",
-        "AAAAAAAAACNBY2NlcHQgYSBjaGFsbGVuZ2UgYW5kIHN0YXJ0IGEgZ2FtZQAAAAAQYWNjZXB0X2NoYWxsZW5nZQAAAAMAAAAAAAAADGNoYWxsZW5nZV9pZAAAAAQAAAAAAAAACmNoYWxsZW5nZWQAAAAAABMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
-        "AAAAAAAAAAAAAAAQYWRkX2NvbnRleHRfcnVsZQAAAAUAAAAAAAAADGNvbnRleHRfdHlwZQAAB9AAAAAPQ29udGV4dFJ1bGVUeXBlAAAAAAAAAAAEbmFtZQAAABAAAAAAAAAAC3ZhbGlkX3VudGlsAAAAA+gAAAAEAAAAAAAAAAdzaWduZXJzAAAAA+oAAAfQAAAABlNpZ25lcgAAAAAAAAAAAAhwb2xpY2llcwAAA+wAAAATAAAAAAAAAAEAAAfQAAAAC0NvbnRleHRSdWxlAA==",
-        "AAAAAAAAAAAAAAAQZ2V0X2NvbnRleHRfcnVsZQAAAAEAAAAAAAAAD2NvbnRleHRfcnVsZV9pZAAAAAAEAAAAAQAAB9AAAAALQ29udGV4dFJ1bGUA",
-        "AAAAAAAAADVHZXQgZGV0YWlsZWQgZ2FtZSBwbGF5YmFjayB3aXRoIGFsbCBtb3ZlcyBhbmQgcmVzdWx0cwAAAAAAABFnZXRfZ2FtZV9wbGF5YmFjawAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAMR2FtZVBsYXliYWNrAAAAAw==",
-        "AAAAAAAAAAAAAAASZ2V0X3BsYXllcl9tYXRjaGVzAAAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6gAAAAQ=",
-        "AAAAAAAAAAAAAAASZ2V0X3Rva2VuX2NvbnRyYWN0AAAAAAAAAAAAAQAAA+gAAAAT",
-        "AAAAAAAAAAAAAAASc2V0X3Rva2VuX2NvbnRyYWN0AAAAAAABAAAAAAAAAA50b2tlbl9jb250cmFjdAAAAAAAEwAAAAA=",
-        "AAAAAAAAAAAAAAATcmVtb3ZlX2NvbnRleHRfcnVsZQAAAAABAAAAAAAAAA9jb250ZXh0X3J1bGVfaWQAAAAABAAAAAA=",
-        "AAAAAAAAADJHZXQgYWxsIGNoYWxsZW5nZXMgZm9yIGEgcGxheWVyIChzb3J0ZWQgYnkgc3RhdHVzKQAAAAAAFWdldF9wbGF5ZXJfY2hhbGxlbmdlcwAAAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPtAAAAAwAAA+oAAAfQAAAACUNoYWxsZW5nZQAAAAAAA+oAAAfQAAAACUNoYWxsZW5nZQAAAAAAA+oAAAfQAAAACUNoYWxsZW5nZQAAAA==",
-        "AAAAAAAAABpHZXQgYWRkcmVzcyBmb3IgYSB1c2VybmFtZQAAAAAAF2dldF9hZGRyZXNzX2J5X3VzZXJuYW1lAAAAAAEAAAAAAAAACHVzZXJuYW1lAAAAEAAAAAEAAAPoAAAAEw==",
-        "AAAAAAAAAAAAAAAXZ2V0X2NvbnRleHRfcnVsZXNfY291bnQAAAAAAAAAAAEAAAAE",
-        "AAAAAAAAAAAAAAAYdXBkYXRlX2NvbnRleHRfcnVsZV9uYW1lAAAAAgAAAAAAAAAPY29udGV4dF9ydWxlX2lkAAAAAAQAAAAAAAAABG5hbWUAAAAQAAAAAQAAB9AAAAALQ29udGV4dFJ1bGUA",
-        "AAAAAAAAAAAAAAAfdXBkYXRlX2NvbnRleHRfcnVsZV92YWxpZF91bnRpbAAAAAACAAAAAAAAAA9jb250ZXh0X3J1bGVfaWQAAAAABAAAAAAAAAALdmFsaWRfdW50aWwAAAAD6AAAAAQAAAABAAAH0AAAAAtDb250ZXh0UnVsZQA=",
+        "AAAAAQAAAAAAAAAAAAAADEJhdHRsZVJlc3VsdAAAAAkAAAAAAAAAB2VudHJvcHkAAAAH0AAAAA9SYW5kb21uZXNzQXVkaXQAAAAAAAAAAAdpc19kcmF3AAAAAAEAAAAAAAAADm92ZXJ0aW1lX3R1cm5zAAAAAAPqAAAH0AAAAApUdXJuUmVzdWx0AAAAAAAAAAAACnBsYXllcjFfaHAAAAAAAAUAAAAAAAAACnBsYXllcjJfaHAAAAAAAAUAAAAAAAAABnJlYXNvbgAAAAAH0AAAAA1PdXRjb21lUmVhc29uAAAAAAAAAAAAAAx0dXJuX3Jlc3VsdHMAAAPqAAAH0AAAAApUdXJuUmVzdWx0AAAAAAAAAAAAEHdlbnRfdG9fb3ZlcnRpbWUAAAABAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAAT",
+        "AAAAAwAAACNXaHkgYSBnYW1lLCBkdWVsIG9yIHRlYW0gZ2FtZSBlbmRlZAAAAAAAAAAADU91dGNvbWVSZWFzb24AAAAAAAAHAAAAAAAAAAdQZW5kaW5nAAAAAAAAAAAAAAAADkJhdHRsZURlY2lzaW9uAAAAAAABAAAAAAAAAAREcmF3AAAAAgAAAAAAAAAHRm9yZmVpdAAAAAADAAAAAAAAAA1Db21taXRUaW1lb3V0AAAAAAAABAAAAAAAAAANUmV2ZWFsVGltZW91dAAAAAAAAAUAAAAAAAAABlZvaWRlZAAAAAAABg==",
+        "AAAAAwAAAFRQaXJhdGUgY2FwdGFpbiBhIHBsYXllciBiYXR0bGVzIGFzLCB3aXRoIGEgcGFzc2l2ZSBtb2RpZmllciAoc2VlIGBjb21iYXQ6OkNhcHRhaW5zYCkAAAAAAAAADENhcHRhaW5DbGFzcwAAAAQAAAAAAAAABE5vbmUAAAAAAAAAAAAAAAdCcmF3bGVyAAAAAAEAAAAAAAAACVRyaWNrc3RlcgAAAAAAAAIAAAAAAAAABkd1bm5lcgAAAAAAAw==",
+        "AAAAAwAAAFRIb3cgYSBiYXR0bGUgc3RpbGwgbGV2ZWwgYWZ0ZXIgaXRzIHJlZ3VsYXIgdHVybnMgaXMgZGVjaWRlZCAoc2VlIGBjb21iYXQ6OnNpbXVsYXRlYCkAAAAAAAAACFRpZUJyZWFrAAAABAAAAAAAAAALU3VkZGVuRGVhdGgAAAAAAAAAAAAAAAALUGxheWVyMVdpbnMAAAAAAQAAAAAAAAALRGFtYWdlRGVhbHQAAAAAAgAAAAAAAAAIVHJ1ZURyYXcAAAAD",
+        "AAAAAQAAAFtIb3cgYSByYW5kb20gc2VlZCB3YXMgZGVyaXZlZCwgc28gYW55b25lIGNhbiByZWNvbXB1dGUgaXQ6CmBzZWVkID0gc2hhMjU2KGRvbWFpbiB8IGlucHV0cylgAAAAAAAAAAAPUmFuZG9tbmVzc0F1ZGl0AAAAAAMAAAAAAAAABmRvbWFpbgAAAAAAEAAAAAAAAAAGaW5wdXRzAAAAAAAOAAAAAAAAAARzZWVkAAAD7gAAACA=",
+        "AAAAAQAAAAAAAAAAAAAAClR1cm5SZXN1bHQAAAAAAA0AAAAAAAAAFHBsYXllcjFfZGFtYWdlX2RlYWx0AAAABQAAAAAAAAAacGxheWVyMV9kZWZlbnNlX3N1Y2Nlc3NmdWwAAAAAAAEAAAAAAAAAFHBsYXllcjFfaHBfcmVtYWluaW5nAAAABQAAAAAAAAAQcGxheWVyMV9tb21lbnR1bQAAAAEAAAAAAAAAEXBsYXllcjFfcmVmbGVjdGVkAAAAAAAABQAAAAAAAAAUcGxheWVyMV93YXNfY3JpdGljYWwAAAABAAAAAAAAABRwbGF5ZXIyX2RhbWFnZV9kZWFsdAAAAAUAAAAAAAAAGnBsYXllcjJfZGVmZW5zZV9zdWNjZXNzZnVsAAAAAAABAAAAAAAAABRwbGF5ZXIyX2hwX3JlbWFpbmluZwAAAAUAAAAAAAAAEHBsYXllcjJfbW9tZW50dW0AAAABAAAAAAAAABFwbGF5ZXIyX3JlZmxlY3RlZAAAAAAAAAUAAAAAAAAAFHBsYXllcjJfd2FzX2NyaXRpY2FsAAAAAQAAAAAAAAAEdHVybgAAAAQ=",
+        "AAAAAwAAAAAAAAAAAAAACEdhbWVNb2RlAAAAAwAAAAAAAAAHQ2xhc3NpYwAAAAAAAAAAAAAAAAVTaWVnZQAAAAAAAAEAAAAAAAAADkNvcnJlc3BvbmRlbmNlAAAAAAAC",
+        "AAAAAwAAAC1Ib3cgYSBnYW1lJ3MgbW92ZXMgYXJlIGNvbW1pdHRlZCBhbmQgcmV2ZWFsZWQAAAAAAAAAAAAACkNvbW1pdE1vZGUAAAAAAAIAAAAAAAAAAlprAAAAAAAAAAAAAAAAAARIYXNoAAAAAQ==",
+        "AAAAAQAAADVBIGNpcmN1aXQgdmVyc2lvbidzIHZlcmlmaWVyIGNvbnRyYWN0IGluIHRoZSByZWdpc3RyeQAAAAAAAAAAAAANVmVyaWZpZXJFbnRyeQAAAAAAAAIAAAAAAAAAB2FkZHJlc3MAAAAAEwAAAAAAAAAKZGVwcmVjYXRlZAAAAAAAAQ==",
+        "AAAAAwAAADFXaGljaCBkZWFkbGluZSBhIFtgV2luZG93T3BlbmVkYF0gZXZlbnQgYW5ub3VuY2VzAAAAAAAAAAAAAApHYW1lV2luZG93AAAAAAACAAAAAAAAAAZDb21taXQAAAAAAAAAAAAAAAAABlJldmVhbAAAAAAAAQ==",
+        "AAAABQAAAIhQdWJsaXNoZWQgd2hlbiBhIGNvcnJlc3BvbmRlbmNlIGdhbWUgb3BlbnMgaXRzIGNvbW1pdCBvciByZXZlYWwgd2luZG93LCBzbwpvZmYtY2hhaW4gc2VydmljZXMgY2FuIHNjaGVkdWxlIHJlbWluZGVycyBhaGVhZCBvZiBgZGVhZGxpbmVgAAAAAAAAAAxXaW5kb3dPcGVuZWQAAAABAAAADXdpbmRvd19vcGVuZWQAAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAGd2luZG93AAAAAAfQAAAACkdhbWVXaW5kb3cAAAAAAAAAAAAAAAAACGRlYWRsaW5lAAAABgAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADUNoYWxsZW5nZVNlbnQAAAAAAAABAAAADmNoYWxsZW5nZV9zZW50AAAAAAAEAAAAAAAAAAxjaGFsbGVuZ2VfaWQAAAAEAAAAAQAAAAAAAAAKY2hhbGxlbmdlcgAAAAAAEwAAAAEAAAAAAAAACmNoYWxsZW5nZWQAAAAAABMAAAABAAAAAAAAAA5wb2ludHNfd2FnZXJlZAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAEUNoYWxsZW5nZUFjY2VwdGVkAAAAAAAAAQAAABJjaGFsbGVuZ2VfYWNjZXB0ZWQAAAAAAAQAAAAAAAAADGNoYWxsZW5nZV9pZAAAAAQAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAKY2hhbGxlbmdlZAAAAAAAEwAAAAEAAAAAAAAACmNoYWxsZW5nZXIAAAAAABMAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAEFJlbWF0Y2hSZXF1ZXN0ZWQAAAABAAAAEXJlbWF0Y2hfcmVxdWVzdGVkAAAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAAAAAACG9wcG9uZW50AAAAEwAAAAEAAAAAAAAACXJlcXVlc3RlcgAAAAAAABMAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAD1JlbWF0Y2hBY2NlcHRlZAAAAAABAAAAEHJlbWF0Y2hfYWNjZXB0ZWQAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAScmVtYXRjaF9zZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAALYWNjZXB0ZWRfYnkAAAAAEwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAE0FjaGlldmVtZW50VW5sb2NrZWQAAAAAAQAAABRhY2hpZXZlbWVudF91bmxvY2tlZAAAAAMAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAAAAAAC2FjaGlldmVtZW50AAAAB9AAAAALQWNoaWV2ZW1lbnQAAAAAAAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAEURhaWx5Qm9udXNBd2FyZGVkAAAAAAAAAQAAABNkYWlseV9ib251c19hd2FyZGVkAAAAAAMAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAAAAAAA2RheQAAAAAGAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADVBvaW50c1NldHRsZWQAAAAAAAABAAAADnBvaW50c19zZXR0bGVkAAAAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAADUdhbWVDYW5jZWxsZWQAAAAAAAABAAAADmdhbWVfY2FuY2VsbGVkAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAMY2FuY2VsbGVkX2J5AAAAEwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAC0dhbWVFeHBpcmVkAAAAAAEAAAAMZ2FtZV9leHBpcmVkAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAC",
+        "AAAABQAAAAAAAAAAAAAAEFNlc3Npb25TaWduZXJTZXQAAAABAAAAEnNlc3Npb25fc2lnbmVyX3NldAAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAAAAAABnNpZ25lcgAAAAAD6AAAABMAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAACENvYWNoU2V0AAAAAQAAAAljb2FjaF9zZXQAAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAAAAAAAAFY29hY2gAAAAAAAPoAAAAEwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADk1vdmVzQ29tbWl0dGVkAAAAAAABAAAAD21vdmVzX2NvbW1pdHRlZAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAADU1vdmVzUmV2ZWFsZWQAAAAAAAABAAAADm1vdmVzX3JldmVhbGVkAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAAAAAAAAFbW92ZXMAAAAAAAPqAAAH0AAAAARNb3ZlAAAAAAAAAAI=",
+        "AAAABQAAAFNQdWJsaXNoZWQgb25jZSwgd2hlbiB0aGUgYmF0dGxlIGlzIHNpbXVsYXRlZDsgYSByZXBlYXRlZCBgcmVzb2x2ZV9iYXR0bGVgIGlzIHNpbGVudAAAAAAAAAAADkJhdHRsZVJlc29sdmVkAAAAAAABAAAAD2JhdHRsZV9yZXNvbHZlZAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEwAAAAAAAAAAAAAAB2lzX2RyYXcAAAAAAQAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAC1VzZXJuYW1lU2V0AAAAAAEAAAAMdXNlcm5hbWVfc2V0AAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAAAAAAAAIdXNlcm5hbWUAAAAQAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAEFJlZmVycmFsQ3JlZGl0ZWQAAAABAAAAEXJlZmVycmFsX2NyZWRpdGVkAAAAAAAAAwAAAAAAAAAIcmVmZXJyZXIAAAATAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAACkdhbWVQYXVzZWQAAAAAAAEAAAALZ2FtZV9wYXVzZWQAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAAAAAADHBhdXNlZF91bnRpbAAAAAYAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAC0dhbWVSZXN1bWVkAAAAAAEAAAAMZ2FtZV9yZXN1bWVkAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAAAAAACnJlc3VtZWRfYnkAAAAAABMAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAD0NhcHRhaW5DbGFzc1NldAAAAAABAAAAEWNhcHRhaW5fY2xhc3Nfc2V0AAAAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAAAAAAAAFY2xhc3MAAAAAAAfQAAAADENhcHRhaW5DbGFzcwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAD1N0b3JhZ2VNaWdyYXRlZAAAAAABAAAAEHN0b3JhZ2VfbWlncmF0ZWQAAAABAAAAAAAAAAd2ZXJzaW9uAAAAAAQAAAABAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAADUFkZHJlc3NMaW5rZWQAAAAAAAABAAAADmFkZHJlc3NfbGlua2VkAAAAAAACAAAAAAAAAAdwcmltYXJ5AAAAABMAAAABAAAAAAAAAAlzZWNvbmRhcnkAAAAAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAD0FkZHJlc3NVbmxpbmtlZAAAAAABAAAAEGFkZHJlc3NfdW5saW5rZWQAAAACAAAAAAAAAAdwcmltYXJ5AAAAABMAAAABAAAAAAAAAAlzZWNvbmRhcnkAAAAAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAEFVzZXJuYW1lUmVsZWFzZWQAAAABAAAAEXVzZXJuYW1lX3JlbGVhc2VkAAAAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAAAAAAAAIdXNlcm5hbWUAAAAQAAAAAAAAAAI=",
+        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAgAAAAAAAAAA1iYXR0bGVfcmVzdWx0AAAAAAAH0AAAAAxCYXR0bGVSZXN1bHQAAAAAAAAAD2NpcmN1aXRfdmVyc2lvbgAAAAAEAAAAAAAAAA9jb21taXRfZGVhZGxpbmUAAAAABgAAAAAAAAALY29tbWl0X21vZGUAAAAH0AAAAApDb21taXRNb2RlAAAAAAAAAAAAD2NyaXRfY2hhbmNlX2JwcwAAAAAEAAAAAAAAABNjcml0X211bHRpcGxpZXJfcGN0AAAAAAUAAAAAAAAADmRlcG9zaXRfYW1vdW50AAAAAAALAAAAAAAAAA1kZXBvc2l0X3Rva2VuAAAAAAAD6AAAABMAAAAAAAAAEWhhc19iYXR0bGVfcmVzdWx0AAAAAAAAAQAAAAAAAAAWaGFzX3BsYXllcjFfY29tbWl0bWVudAAAAAAAAQAAAAAAAAAWaGFzX3BsYXllcjJfY29tbWl0bWVudAAAAAAAAQAAAAAAAAAQaW5pdGlhdGl2ZV9ib251cwAAAAUAAAAAAAAADGlzX2NhbmNlbGxlZAAAAAEAAAAAAAAACmlzX3NldHRsZWQAAAAAAAEAAAAAAAAABG1vZGUAAAfQAAAACEdhbWVNb2RlAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADXBsYXllcjFfY2xhc3MAAAAAAAfQAAAADENhcHRhaW5DbGFzcwAAAAAAAAANcGxheWVyMV9jb2FjaAAAAAAAA+gAAAATAAAAAAAAABJwbGF5ZXIxX2NvbW1pdG1lbnQAAAAAB9AAAAAQUGxheWVyQ29tbWl0bWVudAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAAEHBsYXllcjFfdXNlcm5hbWUAAAPoAAAAEAAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA1wbGF5ZXIyX2NsYXNzAAAAAAAH0AAAAAxDYXB0YWluQ2xhc3MAAAAAAAAADXBsYXllcjJfY29hY2gAAAAAAAPoAAAAEwAAAAAAAAAScGxheWVyMl9jb21taXRtZW50AAAAAAfQAAAAEFBsYXllckNvbW1pdG1lbnQAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAAAAABBwbGF5ZXIyX3VzZXJuYW1lAAAD6AAAABAAAAAAAAAABXJhaXNlAAAAAAAH0AAAAAVSYWlzZQAAAAAAAAAAAAALcmVzb2x2ZWRfYXQAAAAABgAAAAAAAAAPcmV2ZWFsX2RlYWRsaW5lAAAAAAYAAAAAAAAABXJ1bGVzAAAAAAAH0AAAAAdSdWxlU2V0AAAAAAAAAAAKc3RhcnRlZF9hdAAAAAAABg==",
+        "AAAAAwAAAAAAAAAAAAAAC1JhaXNlU3RhdHVzAAAAAAQAAAAAAAAABE5vbmUAAAAAAAAAAAAAAAdQZW5kaW5nAAAAAAEAAAAAAAAABkNhbGxlZAAAAAAAAgAAAAAAAAAGRm9sZGVkAAAAAAAD",
+        "AAAAAQAAAElCbGluZCByYWlzZSBzdGF0ZSBvZiBhIGdhbWUsIHdpdGggdGhlIHJhaXNlIHNldHRpbmdzIHNuYXBzaG90dGVkIGF0IHN0YXJ0AAAAAAAAAAAAAAVSYWlzZQAAAAAAAAYAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAMZmVlX3NjaGVkdWxlAAAD6gAAB9AAAAAHRmVlVGllcgAAAAAAAAAACm1heF9hbW91bnQAAAAAAAsAAAAAAAAABnJhaXNlcgAAAAAD6AAAABMAAAAAAAAABnN0YXR1cwAAAAAH0AAAAAtSYWlzZVN0YXR1cwAAAAAAAAAABXRva2VuAAAAAAAD6AAAABM=",
+        "AAAAAwAAAGRMaWZlY3ljbGUgb2YgYSBjaGFsbGVuZ2UuIEV4cGlyeSBpc24ndCBzdG9yZWQ6IGEgcGVuZGluZyBjaGFsbGVuZ2UgcGFzdCBpdHMKYGV4cGlyZXNfYXRgIGlzIGV4cGlyZWQuAAAAAAAAAA9DaGFsbGVuZ2VTdGF0dXMAAAAABQAAAAAAAAAHUGVuZGluZwAAAAAAAAAAAAAAAAhBY2NlcHRlZAAAAAEAAAAAAAAACUNvbXBsZXRlZAAAAAAAAAIAAAAAAAAACERlY2xpbmVkAAAAAwAAAAAAAAAJQ2FuY2VsbGVkAAAAAAAABA==",
+        "AAAAAQAAAAAAAAAAAAAACUNoYWxsZW5nZQAAAAAAAA8AAAAAAAAADGNoYWxsZW5nZV9pZAAAAAQAAAAAAAAACmNoYWxsZW5nZWQAAAAAABMAAAAAAAAACmNoYWxsZW5nZXIAAAAAABMAAAAAAAAACmNyZWF0ZWRfYXQAAAAAAAYAAAAAAAAADmNyZWF0ZWRfbGVkZ2VyAAAAAAAEAAAAAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAAAAAAA5mb3J3YXJkZWRfZnJvbQAAAAAD6AAAABMAAAAAAAAAC2lzX2FjY2VwdGVkAAAAAAEAAAAAAAAADGlzX2NvbXBsZXRlZAAAAAEAAAAAAAAADmlzX3JpdmFsX21hdGNoAAAAAAABAAAAAAAAAAVub25jZQAAAAAAAAQAAAAAAAAADnBvaW50c193YWdlcmVkAAAAAAALAAAAAAAAAApzZXNzaW9uX2lkAAAAAAPoAAAABAAAAAAAAAAGc3RhdHVzAAAAAAfQAAAAD0NoYWxsZW5nZVN0YXR1cwAAAAAAAAAACXRpZV9icmVhawAAAAAAB9AAAAAIVGllQnJlYWs=",
+        "AAAAAQAAAEZPZmZlciB0byByZXBsYXkgYSBmaW5pc2hlZCBnYW1lIHdpdGggdGhlIHNhbWUgcGxheWVycywgc3Rha2VzIGFuZCBtb2RlAAAAAAAAAAAAB1JlbWF0Y2gAAAAACQAAAAAAAAAKZXhwaXJlc19hdAAAAAAABgAAAAAAAAAEbW9kZQAAB9AAAAAIR2FtZU1vZGUAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAAEnJlbWF0Y2hfc2Vzc2lvbl9pZAAAAAAD6AAAAAQAAAAAAAAACXJlcXVlc3RlcgAAAAAAABMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQ=",
+        "AAAAAQAAAD5TcGVjdGF0b3Igc3Rha2VzIG9uIG9uZSBnYW1lLCBlc2Nyb3dlZCBpbiB0aGUgcHJlZGljdGlvbiB0b2tlbgAAAAAAAAAAAA5QcmVkaWN0aW9uUG9vbAAAAAAACAAAAAAAAAAKaXNfc2V0dGxlZAAAAAAAAQAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAA1wbGF5ZXIxX3RvdGFsAAAAAAAACwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA1wbGF5ZXIyX3RvdGFsAAAAAAAACwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAAT",
+        "AAAAAQAAAC1PbmUgc3BlY3RhdG9yJ3Mgc3Rha2UgaW4gYSBbYFByZWRpY3Rpb25Qb29sYF0AAAAAAAAAAAAAClByZWRpY3Rpb24AAAAAAAIAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAANYmFja2VkX3BsYXllcgAAAAAAABM=",
+        "AAAAAQAAAE1XaGF0IGEgY2hhbGxlbmdlIHNlbnQgbm93IHdvdWxkIGNvc3QgYW5kIHBheSwgdW5kZXIgdGhlIGN1cnJlbnQgY29uZmlndXJhdGlvbgAAAAAAAAAAAAAOQ2hhbGxlbmdlUXVvdGUAAAAAAAsAAAAAAAAAEmNvbW1pdF93aW5kb3dfc2VjcwAAAAAABgAAAAAAAAAOZGVwb3NpdF9hbW91bnQAAAAAAAsAAAAAAAAADWRlcG9zaXRfdG9rZW4AAAAAAAPoAAAAEwAAAAAAAAAKZXhwaXJlc19hdAAAAAAABgAAAAAAAAAJbWF4X3JhaXNlAAAAAAAACwAAAAAAAAANbWF4X3JhaXNlX2ZlZQAAAAAAAAsAAAAAAAAABnBheW91dAAAAAAACwAAAAAAAAAOcG9pbnRzX3dhZ2VyZWQAAAAAAAsAAAAAAAAAC3JhaXNlX3Rva2VuAAAAA+gAAAATAAAAAAAAABJyZXZlYWxfd2luZG93X3NlY3MAAAAAAAYAAAAAAAAACndpbl9yZXdhcmQAAAAAAAs=",
+        "AAAAAQAAADtBIGNoYWxsZW5nZSBwb3N0ZWQgdG8gdGhlIHB1YmxpYyBib2FyZCBmb3IgYW55b25lIHRvIGFjY2VwdAAAAAAAAAAADU9wZW5DaGFsbGVuZ2UAAAAAAAAFAAAAAAAAAAxjaGFsbGVuZ2VfaWQAAAAEAAAAAAAAAApjaGFsbGVuZ2VyAAAAAAATAAAAAAAAAApjcmVhdGVkX2F0AAAAAAAGAAAAAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAAAAAAA5wb2ludHNfd2FnZXJlZAAAAAAACw==",
+        "AAAAAQAAADdBIGxpc3RlZCBmcmllbmQgYW5kIHdoZXRoZXIgdGhleSBhcmUgbG9va2luZyBmb3IgYSBnYW1lAAAAAAAAAAAMRnJpZW5kU3RhdHVzAAAAAgAAAAAAAAAGZnJpZW5kAAAAAAATAAAAAAAAABBsb29raW5nX2Zvcl9nYW1lAAAAAQ==",
+        "AAAAAQAAACBXYWl0aW5nIGNvdW50IGZvciBvbmUgbG9iYnkgdGllcgAAAAAAAAAJUXVldWVUaWVyAAAAAAAAAwAAAAAAAAAEdGllcgAAAAQAAAAAAAAABXdhZ2VyAAAAAAAACwAAAAAAAAAHd2FpdGluZwAAAAAE",
+        "AAAAAQAAAEtBIGdhbWUgYHBsYXllcjFgIGhhcyBvZmZlcmVkIHdpdGggYHByb3Bvc2VfZ2FtZWAsIHdhaXRpbmcgb24gYGNvbmZpcm1fZ2FtZWAAAAAAAAAAAAxHYW1lUHJvcG9zYWwAAAAFAAAAAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAAL",
+        "AAAAAQAAADFPcGVuIGNoYWxsZW5nZSBhbnlvbmUgaG9sZGluZyBpdHMgY29kZSBjYW4gYWNjZXB0AAAAAAAAAAAAAApJbnZpdGVDb2RlAAAAAAADAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAACmV4cGlyZXNfYXQAAAAAAAYAAAAAAAAADnBvaW50c193YWdlcmVkAAAAAAAL",
+        "AAAAAQAAAAAAAAAAAAAAEkRldGFpbGVkVHVyblJlc3VsdAAAAAAAEQAAAAAAAAAUcGxheWVyMV9kYW1hZ2VfZGVhbHQAAAAFAAAAAAAAABRwbGF5ZXIxX2RhbWFnZV90YWtlbgAAAAUAAAAAAAAAGnBsYXllcjFfZGVmZW5zZV9zdWNjZXNzZnVsAAAAAAABAAAAAAAAABRwbGF5ZXIxX2hwX3JlbWFpbmluZwAAAAUAAAAAAAAAEHBsYXllcjFfbW9tZW50dW0AAAABAAAAAAAAAAxwbGF5ZXIxX21vdmUAAAfQAAAABE1vdmUAAAAAAAAAEXBsYXllcjFfcmVmbGVjdGVkAAAAAAAABQAAAAAAAAAUcGxheWVyMV93YXNfY3JpdGljYWwAAAABAAAAAAAAABRwbGF5ZXIyX2RhbWFnZV9kZWFsdAAAAAUAAAAAAAAAFHBsYXllcjJfZGFtYWdlX3Rha2VuAAAABQAAAAAAAAAacGxheWVyMl9kZWZlbnNlX3N1Y2Nlc3NmdWwAAAAAAAEAAAAAAAAAFHBsYXllcjJfaHBfcmVtYWluaW5nAAAABQAAAAAAAAAQcGxheWVyMl9tb21lbnR1bQAAAAEAAAAAAAAADHBsYXllcjJfbW92ZQAAB9AAAAAETW92ZQAAAAAAAAARcGxheWVyMl9yZWZsZWN0ZWQAAAAAAAAFAAAAAAAAABRwbGF5ZXIyX3dhc19jcml0aWNhbAAAAAEAAAAAAAAABHR1cm4AAAAE",
+        "AAAAAQAAAAAAAAAAAAAADEdhbWVQbGF5YmFjawAAABEAAAAAAAAAEGZpbmFsX3BsYXllcjFfaHAAAAAFAAAAAAAAABBmaW5hbF9wbGF5ZXIyX2hwAAAABQAAAAAAAAAHaXNfZHJhdwAAAAABAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADXBsYXllcjFfY2xhc3MAAAAAAAfQAAAADENhcHRhaW5DbGFzcwAAAAAAAAAQcGxheWVyMV91c2VybmFtZQAAA+gAAAAQAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADXBsYXllcjJfY2xhc3MAAAAAAAfQAAAADENhcHRhaW5DbGFzcwAAAAAAAAAYcGxheWVyMl9pbml0aWF0aXZlX2JvbnVzAAAABQAAAAAAAAAQcGxheWVyMl91c2VybmFtZQAAA+gAAAAQAAAAAAAAAAZyZWFzb24AAAAAB9AAAAANT3V0Y29tZVJlYXNvbgAAAAAAAAAAAAAKcmVtYXRjaF9vZgAAAAAD6AAAAAQAAAAAAAAADHJlbWF0Y2hlZF9hcwAAA+gAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAx0dXJuX3Jlc3VsdHMAAAPqAAAH0AAAABJEZXRhaWxlZFR1cm5SZXN1bHQAAAAAAAAAAAAQd2VudF90b19vdmVydGltZQAAAAEAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
+        "AAAAAwAAAAAAAAAAAAAACk1hdGNoU3RhdGUAAAAAAAQAAAAAAAAAB0NyZWF0ZWQAAAAAAAAAAAAAAAAIQWNjZXB0ZWQAAAABAAAAAAAAAAZBY3RpdmUAAAAAAAIAAAAAAAAACEZpbmlzaGVkAAAAAw==",
+        "AAAAAQAAAAAAAAAAAAAACFB2UE1hdGNoAAAACgAAAAAAAAAMY3VycmVudF90dXJuAAAABAAAAAAAAAAPaGFzX2xhc3RfYWN0aW9uAAAAAAEAAAAAAAAAC2xhc3RfYWN0aW9uAAAAB9AAAAAETW92ZQAAAAAAAAAIbWF0Y2hfaWQAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAACnBsYXllcjFfaHAAAAAAAAUAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAKcGxheWVyMl9ocAAAAAAABQAAAAAAAAAFc3RhdGUAAAAAAAfQAAAACk1hdGNoU3RhdGUAAAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
+        "AAAAAwAAAAAAAAAAAAAACUR1ZWxQaGFzZQAAAAAAAAMAAAAAAAAABkNvbW1pdAAAAAAAAAAAAAAAAAAGUmV2ZWFsAAAAAAABAAAAAAAAAAhGaW5pc2hlZAAAAAI=",
+        "AAAAAQAAACtPbmUgcGxheWVyJ3Mgc2lkZSBvZiB0aGUgY3VycmVudCBkdWVsIHJvdW5kAAAAAAAAAAAIRHVlbFR1cm4AAAAEAAAAAAAAAAZhY3Rpb24AAAAAB9AAAAAETW92ZQAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAAAAAAADWhhc19jb21taXR0ZWQAAAAAAAABAAAAAAAAAAxoYXNfcmV2ZWFsZWQAAAAB",
+        "AAAAAQAAAIRUdXJuLWJ5LXR1cm4gZHVlbDogb25lIG1vdmUgcGVyIHJvdW5kIGlzIGNvbW1pdHRlZCwgcmV2ZWFsZWQgYW5kIHJlc29sdmVkLApzbyBwbGF5ZXJzIGNhbiBhZGFwdCB0byB3aGF0IHRoZSBvcHBvbmVudCBkaWQgbGFzdCByb3VuZC4AAAAAAAAABER1ZWwAAAANAAAAAAAAAA1iYXR0bGVfcmVzdWx0AAAAAAAH0AAAAAxCYXR0bGVSZXN1bHQAAAAAAAAACGRlYWRsaW5lAAAABgAAAAAAAAAFcGhhc2UAAAAAAAfQAAAACUR1ZWxQaGFzZQAAAAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAA1wbGF5ZXIxX21vdmVzAAAAAAAD6gAAB9AAAAAETW92ZQAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADHBsYXllcjFfdHVybgAAB9AAAAAIRHVlbFR1cm4AAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAANcGxheWVyMl9tb3ZlcwAAAAAAA+oAAAfQAAAABE1vdmUAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAAAAAAxwbGF5ZXIyX3R1cm4AAAfQAAAACER1ZWxUdXJuAAAAAAAAAAVyb3VuZAAAAAAAAAQAAAAAAAAABXJ1bGVzAAAAAAAH0AAAAAdSdWxlU2V0AA==",
+        "AAAAAQAAANhBIDJ2MiBiYXR0bGUuIEV2ZXJ5IHBsYXllciBjb21taXRzIGFuZCByZXZlYWxzIHRoZWlyIG93biBaSy1wcm92ZW4gbW92ZQpzZXF1ZW5jZTsgZWFjaCB0ZWFtIGZpZ2h0cyBmcm9tIG9uZSBzaGFyZWQgSFAgcG9vbC4gVGhlIEdhbWUgSHViIHNlZXMgdGhlIHR3bwpjYXB0YWlucyAodGhlIGZpcnN0IG1lbWJlciBvZiBlYWNoIHRlYW0pIGFzIHRoZSBzZXNzaW9uJ3MgcGxheWVycy4AAAAAAAAACFRlYW1HYW1lAAAACgAAAAAAAAANYmF0dGxlX3Jlc3VsdAAAAAAAB9AAAAAMQmF0dGxlUmVzdWx0AAAAAAAAAA9jaXJjdWl0X3ZlcnNpb24AAAAABAAAAAAAAAALY29tbWl0bWVudHMAAAAD6gAAB9AAAAAQUGxheWVyQ29tbWl0bWVudAAAAAAAAAARaGFzX2JhdHRsZV9yZXN1bHQAAAAAAAABAAAAAAAAAA1oYXNfY29tbWl0dGVkAAAAAAAD6gAAAAEAAAAAAAAABXJ1bGVzAAAAAAAH0AAAAAdSdWxlU2V0AAAAAAAAAAAFdGVhbTEAAAAAAAPqAAAAEwAAAAAAAAAMdGVhbTFfcG9pbnRzAAAACwAAAAAAAAAFdGVhbTIAAAAAAAPqAAAAEwAAAAAAAAAMdGVhbTJfcG9pbnRzAAAACw==",
+        "AAAAAQAAAEJCZXN0LW9mLU4gc2VyaWVzIHBsYXllZCBhcyBsaW5rZWQgZ2FtZXMgdW5kZXIgb25lIEdhbWUgSHViIHNlc3Npb24AAAAAAAAAAAALTWF0Y2hTZXJpZXMAAAAADAAAAAAAAAAHYmVzdF9vZgAAAAAEAAAAAAAAAAVkcmF3cwAAAAAAAAQAAAAAAAAADGlzX2NhbmNlbGxlZAAAAAEAAAAAAAAAC2lzX2ZpbmlzaGVkAAAAAAEAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADHBsYXllcjFfd2lucwAAAAQAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADHBsYXllcjJfd2lucwAAAAQAAAAAAAAACHNlc3Npb25zAAAD6gAAAAQAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
+        "AAAAAwAAAAAAAAAAAAAAEFRvdXJuYW1lbnRTdGF0dXMAAAAEAAAAAAAAAAxSZWdpc3RyYXRpb24AAAAAAAAAAAAAAApJblByb2dyZXNzAAAAAAABAAAAAAAAAAlDb21wbGV0ZWQAAAAAAAACAAAAAAAAAAlDYW5jZWxsZWQAAAAAAAAD",
+        "AAAAAwAAAAAAAAAAAAAADUJyYWNrZXRGb3JtYXQAAAAAAAACAAAAAAAAABFTaW5nbGVFbGltaW5hdGlvbgAAAAAAAAAAAAAAAAAAEURvdWJsZUVsaW1pbmF0aW9uAAAAAAAAAQ==",
+        "AAAAAwAAAAAAAAAAAAAAC0JyYWNrZXRTaWRlAAAAAAMAAAAAAAAAB1dpbm5lcnMAAAAAAAAAAAAAAAAGTG9zZXJzAAAAAAABAAAAAAAAAApHcmFuZEZpbmFsAAAAAAAC",
+        "AAAAAQAAAFtBIHRvdXJuYW1lbnQncyBwcml6ZSBwb29sIGFuZCBob3cgaXQgaXMgc3BsaXQgYnkgZmluYWwgc3RhbmRpbmcsIGtlcHQgYmVzaWRlIHRoZQp0b3VybmFtZW50AAAAAAAAAAAQVG91cm5hbWVudFByaXplcwAAAAMAAAAAAAAAC3BheW91dHNfYnBzAAAAA+oAAAAEAAAAAAAAAARwb29sAAAACwAAAAAAAAAFdG9rZW4AAAAAAAAT",
+        "AAAAAQAAAEZFbGltaW5hdGlvbiB0b3VybmFtZW50OyBpdHMgbWF0Y2hlcyBhcmUga2VwdCBzZXBhcmF0ZWx5IGFzIHRoZSBicmFja2V0AAAAAAAAAAAAClRvdXJuYW1lbnQAAAAAAAsAAAAAAAAACGNoYW1waW9uAAAD6AAAABMAAAAAAAAACmNoZWNrZWRfaW4AAAAAA+oAAAATAAAAAAAAAAxlbnRyeV9wb2ludHMAAAALAAAAAAAAAAZmb3JtYXQAAAAAB9AAAAANQnJhY2tldEZvcm1hdAAAAAAAAAAAAAALbWF4X3BsYXllcnMAAAAABAAAAAAAAAAJb3JnYW5pemVyAAAAAAAAEwAAAAAAAAAHcGxheWVycwAAAAPqAAAAEwAAAAAAAAAVcmVnaXN0cmF0aW9uX2RlYWRsaW5lAAAAAAAABgAAAAAAAAAFcm91bmQAAAAAAAAEAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAQVG91cm5hbWVudFN0YXR1cwAAAAAAAAANdG91cm5hbWVudF9pZAAAAAAAAAQ=",
+        "AAAAAQAAAGFPbmUgbWF0Y2ggb2YgYSBicmFja2V0LiBBIG1hdGNoIHdpdGggYSBzaW5nbGUgcGxheWVyIGlzIGEgYnllLCBhbmQgb25lIGxlZnQgd2l0aApub25lIGlzIHNraXBwZWQuAAAAAAAAAAAAAAxCcmFja2V0TWF0Y2gAAAAMAAAAAAAAAAVpbmRleAAAAAAAAAQAAAAAAAAAC2lzX3dhbGtvdmVyAAAAAAEAAAAAAAAACGxvc2VyX3RvAAAD6AAAAAQAAAAAAAAAB3BlbmRpbmcAAAAABAAAAAAAAAAHcGxheWVyMQAAAAPoAAAAEwAAAAAAAAAHcGxheWVyMgAAAAPoAAAAEwAAAAAAAAAIcmVwbGF5ZWQAAAABAAAAAAAAAAVyb3VuZAAAAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAA+gAAAAEAAAAAAAAAARzaWRlAAAH0AAAAAtCcmFja2V0U2lkZQAAAAAAAAAABndpbm5lcgAAAAAD6AAAABMAAAAAAAAACXdpbm5lcl90bwAAAAAAA+gAAAAE",
+        "AAAAAQAAACVBIHBsYXllcidzIHJlY29yZCBvbiB0aGUgcmF0ZWQgbGFkZGVyAAAAAAAAAAAAAAtQbGF5ZXJTdGF0cwAAAAAFAAAAAAAAAAVkcmF3cwAAAAAAAAQAAAAAAAAABmxvc3NlcwAAAAAABAAAAAAAAAAGcmF0aW5nAAAAAAAFAAAAAAAAAAZzdHJlYWsAAAAAAAUAAAAAAAAABHdpbnMAAAAE",
+        "AAAAAQAAAE1Db21wYWN0LCBwZXJtYW5lbnQgcmVjb3JkIG9mIGEgcmVzb2x2ZWQgYmF0dGxlLCBrZXB0IGluIGJvdGggcGxheWVycycgaGlzdG9yeQAAAAAAAAAAAAAMQXJjaGl2ZWRHYW1lAAAACgAAAAAAAAAHaXNfZHJhdwAAAAABAAAAAAAAAARtb2RlAAAH0AAAAAhHYW1lTW9kZQAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAA1wbGF5ZXIxX21vdmVzAAAAAAAD6gAAB9AAAAAETW92ZQAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA1wbGF5ZXIyX21vdmVzAAAAAAAD6gAAB9AAAAAETW92ZQAAAAAAAAALcmVzb2x2ZWRfYXQAAAAABgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAMdHVybl9yZXN1bHRzAAAD6gAAB9AAAAAKVHVyblJlc3VsdAAAAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAAT",
+        "AAAAAQAAACRPbmUgcGxheWVyJ3MgcmVzdWx0cyBhZ2FpbnN0IGEgcml2YWwAAAAAAAAACkhlYWRUb0hlYWQAAAAAAAMAAAAAAAAABWRyYXdzAAAAAAAABAAAAAAAAAAGbG9zc2VzAAAAAAAEAAAAAAAAAAR3aW5zAAAABA==",
+        "AAAAAQAAAJtVbmJyb2tlbiBydW4gb2YgZ2FtZXMgYmV0d2VlbiB0d28gcGxheWVycywgd2l0aCBpdHMgcnVubmluZyBzY29yZS4gU3RvcmVkIGZyb20KdGhlIGxvd2VyIGFkZHJlc3MncyBzaWRlOyBgZ2V0X3JpdmFscnlfc2VyaWVzYCByZXR1cm5zIGl0IGZyb20gdGhlIGNhbGxlcidzLgAAAAAAAAAADVJpdmFscnlTZXJpZXMAAAAAAAAIAAAAAAAAAAZhX3dpbnMAAAAAAAQAAAAAAAAABmJfd2lucwAAAAAABAAAAAAAAAAFZHJhd3MAAAAAAAAEAAAAAAAAAA5sYXN0X3BsYXllZF9hdAAAAAAABgAAAAAAAAAIcGxheWVyX2EAAAATAAAAAAAAAAhwbGF5ZXJfYgAAABMAAAAAAAAACHNlc3Npb25zAAAD6gAAAAQAAAAAAAAACnN0YXJ0ZWRfYXQAAAAAAAY=",
+        "AAAAAQAAADdXaGVyZSBhIHBsYXllcidzIHJhdGluZyBzdGFuZHMgYW1vbmcgZXZlcnkgcmF0ZWQgcGxheWVyAAAAAAAAAAAQUmF0aW5nUGVyY2VudGlsZQAAAAQAAAAAAAAADmlzX3Byb3Zpc2lvbmFsAAAAAAABAAAAAAAAAA1yYXRlZF9wbGF5ZXJzAAAAAAAABAAAAAAAAAAGcmF0aW5nAAAAAAAFAAAAAAAAAAd0b3BfcGN0AAAAAAQ=",
+        "AAAAAQAAABFMZWFkZXJib2FyZCBlbnRyeQAAAAAAAAAAAAAMUmFua2VkUGxheWVyAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZyYXRpbmcAAAAAAAU=",
+        "AAAAAQAAADBBIGNvbXBldGl0aXZlIHBlcmlvZCB3aXRoIGl0cyBvd24gcmF0aW5ncyBsYWRkZXIAAAAAAAAABlNlYXNvbgAAAAAABAAAAAAAAAAHZW5kc19hdAAAAAAGAAAAAAAAAAloYXNfZW5kZWQAAAAAAAABAAAAAAAAAAlzZWFzb25faWQAAAAAAAAEAAAAAAAAAApzdGFydGVkX2F0AAAAAAAG",
+        "AAAAAQAAADBSYW5rZWQgc25hcHNob3Qgb2YgYSBzZWFzb24sIGZyb3plbiB3aGVuIGl0IGVuZHMAAAAAAAAADVNlYXNvblJlc3VsdHMAAAAAAAAEAAAAAAAAAAhlbmRlZF9hdAAAAAYAAAAAAAAACXNlYXNvbl9pZAAAAAAAAAQAAAAAAAAACXN0YW5kaW5ncwAAAAAAA+oAAAfQAAAADFJhbmtlZFBsYXllcgAAAAAAAAAKc3RhcnRlZF9hdAAAAAAABg==",
+        "AAAAAQAAAG5Ub2tlbiBwYWlkIHRvIGEgc2Vhc29uJ3MgdG9wIGZpbmlzaGVycyB3aGVuIGl0IGVuZHM6IGBhbW91bnRzWzBdYCB0byBmaXJzdCBwbGFjZSwKYW5kIHNvIG9uIGRvd24gdGhlIHN0YW5kaW5ncwAAAAAAAAAAAA1TZWFzb25SZXdhcmRzAAAAAAAAAgAAAAAAAAAHYW1vdW50cwAAAAPqAAAACwAAAAAAAAAFdG9rZW4AAAAAAAAT",
+        "AAAAAQAAADxBIHBsYXllcidzIGZpbmlzaCBpbiBhbiBlbmRlZCBzZWFzb24sIGFuZCB0aGUgcmV3YXJkIGl0IHBheXMAAAAAAAAAD1NlYXNvblBsYWNlbWVudAAAAAAIAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAB2NsYWltZWQAAAAAAQAAAAAAAAAKZXhwaXJlc19hdAAAAAAABgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAARyYW5rAAAABAAAAAAAAAAGcmF0aW5nAAAAAAAFAAAAAAAAAAlzZWFzb25faWQAAAAAAAAEAAAAAAAAAAV0b2tlbgAAAAAAA+gAAAAT",
+        "AAAAAwAAADJNaWxlc3RvbmUgYmFkZ2VzIGF3YXJkZWQgd2hlbiBhIGJhdHRsZSBpcyByZXNvbHZlZAAAAAAAAAAAAAtBY2hpZXZlbWVudAAAAAAHAAAAAAAAAAhGaXJzdFdpbgAAAAAAAAAAAAAACldpblN0cmVhazMAAAAAAAEAAAAAAAAACEZsYXdsZXNzAAAAAgAAAAAAAAATVHJpcGxlQ29tYm9GaW5pc2hlcgAAAAADAAAAAAAAAApUZW5CYXR0bGVzAAAAAAAEAAAAAAAAAAhTaGFycEV5ZQAAAAUAAAAAAAAABk9yYWNsZQAAAAAABg==",
+        "AAAAAQAAABtBIGJhZGdlIGFzIGhlbGQgYnkgYSBwbGF5ZXIAAAAAAAAAABFFYXJuZWRBY2hpZXZlbWVudAAAAAAAAAMAAAAAAAAAC2FjaGlldmVtZW50AAAAB9AAAAALQWNoaWV2ZW1lbnQAAAAAAAAAAAllYXJuZWRfYXQAAAAAAAAGAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAE",
+        "AAAAAQAAACpUb3RhbHMgb3ZlciBldmVyeSBiYXR0bGUgcmVzb2x2ZWQgb24tY2hhaW4AAAAAAAAAAAALR2xvYmFsU3RhdHMAAAAACQAAAAAAAAASYXZlcmFnZV90dXJuc194MTAwAAAAAAAGAAAAAAAAAA9icm9hZHNpZGVfY291bnQAAAAABgAAAAAAAAAOZmlyZWJhbGxfY291bnQAAAAAAAYAAAAAAAAACmhvb2tfY291bnQAAAAAAAYAAAAAAAAAD2xpZ2h0bmluZ19jb3VudAAAAAAGAAAAAAAAAAtzbGFzaF9jb3VudAAAAAAGAAAAAAAAAAt0b3RhbF9kcmF3cwAAAAAGAAAAAAAAAAt0b3RhbF9nYW1lcwAAAAAGAAAAAAAAAAt0b3RhbF90dXJucwAAAAAG",
+        "AAAAAQAAAD5TaWVnZSByZXN1bHRzIGZvciBvbmUgcGxheWVyLCB0cmFja2VkIGFwYXJ0IGZyb20gY2xhc3NpYyBnYW1lcwAAAAAAAAAAAApTaWVnZVN0YXRzAAAAAAAEAAAAAAAAAA9hdHRhY2tlcl9sb3NzZXMAAAAABAAAAAAAAAANYXR0YWNrZXJfd2lucwAAAAAAAAQAAAAAAAAAD2RlZmVuZGVyX2xvc3NlcwAAAAAEAAAAAAAAAA1kZWZlbmRlcl93aW5zAAAAAAAABA==",
+        "AAAAAwAAAB9Ib3cgaGFyZCB0aGUgcHJhY3RpY2UgYm90IHBsYXlzAAAAAAAAAAASUHJhY3RpY2VEaWZmaWN1bHR5AAAAAAADAAAAAAAAAARFYXN5AAAAAAAAAAAAAAAGTm9ybWFsAAAAAAABAAAAAAAAAARIYXJkAAAAAg==",
+        "AAAAAQAAAEFBIGdhbWUgYWdhaW5zdCB0aGUgb24tY2hhaW4gYm90OiBubyBHYW1lIEh1Yiwgbm8gd2FnZXIsIG5vIHJld2FyZAAAAAAAAAAAAAAMUHJhY3RpY2VHYW1lAAAACAAAAAAAAAANYmF0dGxlX3Jlc3VsdAAAAAAAB9AAAAAMQmF0dGxlUmVzdWx0AAAAAAAAAAlib3RfbW92ZXMAAAAAAAPqAAAH0AAAAARNb3ZlAAAAAAAAAApkaWZmaWN1bHR5AAAAAAfQAAAAElByYWN0aWNlRGlmZmljdWx0eQAAAAAAAAAAABFoYXNfYmF0dGxlX3Jlc3VsdAAAAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAMcGxheWVyX21vdmVzAAAD6gAAB9AAAAAETW92ZQAAAAAAAAAFcnVsZXMAAAAAAAfQAAAAB1J1bGVTZXQAAAAAAAAAAApzdGFydGVkX2F0AAAAAAAG",
+        "AAAAAQAAAFBQcmFjdGljZSByZXN1bHRzIGZvciBvbmUgcGxheWVyIGF0IG9uZSBkaWZmaWN1bHR5LCB0cmFja2VkIGFwYXJ0IGZyb20gcmF0ZWQgcGxheQAAAAAAAAANUHJhY3RpY2VTdGF0cwAAAAAAAAMAAAAAAAAABWRyYXdzAAAAAAAABAAAAAAAAAAGbG9zc2VzAAAAAAAEAAAAAAAAAAR3aW5zAAAABA==",
+        "AAAAAQAAAD9JbmNsdXNpdmUgYm91bmRzIG9uIHBvaW50cyB3YWdlcmVkIHBlciBwbGF5ZXIsIHNldCBieSB0aGUgYWRtaW4AAAAAAAAAAAtXYWdlckxpbWl0cwAAAAACAAAAAAAAAANtYXgAAAAACwAAAAAAAAADbWluAAAAAAs=",
+        "AAAAAQAAAD5QbGF5ZXJzIGEgcmVmZXJyZXIgYnJvdWdodCBpbiwgYW5kIHRoZSBib251cyBjcmVkaXRlZCBmb3IgdGhlbQAAAAAAAAAAAA1SZWZlcnJhbFN0YXRzAAAAAAAABAAAAAAAAAAJY29tcGxldGVkAAAAAAAABAAAAAAAAAAGZWFybmVkAAAAAAALAAAAAAAAAAhyZWZlcnJlZAAAAAQAAAAAAAAACXVuY2xhaW1lZAAAAAAAAAs=",
+        "AAAAAQAAACpBIHNwZWN0YXRvcidzIGZyZWUgY2FsbCBvbiB3aG8gd2lucyBhIGdhbWUAAAAAAAAAAAANU3BlY3RhdG9yUGljawAAAAAAAAIAAAAAAAAADWJhY2tlZF9wbGF5ZXIAAAAAAAATAAAAAAAAAAlzcGVjdGF0b3IAAAAAAAAT",
+        "AAAAAQAAAENBIHNwZWN0YXRvcidzIHJlY29yZCBvdmVyIHRoZWlyIHNjb3JlZCBwaWNrczsgZHJhd3MgYXJlIG5vdCBjb3VudGVkAAAAAAAAAAAJUGlja1N0YXRzAAAAAAAABAAAAAAAAAALYmVzdF9zdHJlYWsAAAAABAAAAAAAAAAHY29ycmVjdAAAAAAEAAAAAAAAAAVwaWNrcwAAAAAAAAQAAAAAAAAABnN0cmVhawAAAAAABA==",
+        "AAAAAQAAAE5OYXZiYXIgY291bnRzIGZvciBvbmUgcGxheWVyLCBrZXB0IGN1cnJlbnQgYnkgdGhlIGVudHJ5cG9pbnRzIHRoYXQgY2hhbmdlIHRoZW0AAAAAAAAAAAAGQmFkZ2VzAAAAAAADAAAAAAAAAA9hd2FpdGluZ19hY3Rpb24AAAAABAAAAAAAAAAScGVuZGluZ19jaGFsbGVuZ2VzAAAAAAAEAAAAAAAAABF1bmNsYWltZWRfcmV3YXJkcwAAAAAAAAQ=",
+        "AAAAAQAAAE9GcmVlLXRvLXBsYXkgdGllcjogYW4gYWRtaW4tZnVuZGVkIHBvb2wgc3Rha2VzIG5ldyBwbGF5ZXJzIGluIG1pY3JvLXdhZ2VyIGdhbWVzAAAAAAAAAAALU3BvbnNvclRpZXIAAAAABAAAAAAAAAALZGFpbHlfZ2FtZXMAAAAABAAAAAAAAAAPbWF4X3JhdGVkX2dhbWVzAAAAAAQAAAAAAAAABXN0YWtlAAAAAAAACwAAAAAAAAAFdG9rZW4AAAAAAAAT",
+        "AAAAAQAAACZBZ3JlZWQgcGF1c2VzIG9mIGEgY29ycmVzcG9uZGVuY2UgZ2FtZQAAAAAAAAAAAAlHYW1lUGF1c2UAAAAAAAAEAAAAAAAAAAxwYXVzZWRfdW50aWwAAAAGAAAAAAAAAAtwcm9wb3NlZF9ieQAAAAPoAAAAEwAAAAAAAAANcHJvcG9zZWRfc2VjcwAAAAAAAAYAAAAAAAAACXVzZWRfc2VjcwAAAAAAAAY=",
+        "AAAAAQAAAEJTdGFrZXMgdGhlIHBvb2wgcHV0IHVwIGZvciBhIHNwb25zb3JlZCBnYW1lLCBoZWxkIHVudGlsIGl0IHNldHRsZXMAAAAAAAAAAAALU3BvbnNvcnNoaXAAAAAAAgAAAAAAAAAFc3Rha2UAAAAAAAALAAAAAAAAAAV0b2tlbgAAAAAAABM=",
+        "AAAAAQAAAHpDaXJjdWl0IGJyZWFrZXJzIHNldCBieSB0aGUgYWRtaW4uIGBhbGxgIHBhdXNlcyBldmVyeSBtdXRhdGluZyBlbnRyeXBvaW50IHRoZQpvdGhlcnMgY292ZXI7IHZpZXdzIGtlZXAgd29ya2luZyBlaXRoZXIgd2F5LgAAAAAAAAAAAApQYXVzZUZsYWdzAAAAAAAEAAAAAAAAAANhbGwAAAAAAQAAAAAAAAAHY29tbWl0cwAAAAABAAAAAAAAAAluZXdfZ2FtZXMAAAAAAAABAAAAAAAAAApyZXNvbHV0aW9uAAAAAAAB",
+        "AAAAAQAAADJEYW1hZ2Ugb2Ygb25lIGF0dGFjayBhbmQgdGhlIGRlZmVuc2UgdGhhdCBzdG9wcyBpdAAAAAAAAAAAAApBdHRhY2tSdWxlAAAAAAADAAAAAAAAAAZhdHRhY2sAAAAAB9AAAAAGQXR0YWNrAAAAAAAAAAAACmJsb2NrZWRfYnkAAAAAB9AAAAAHRGVmZW5zZQAAAAAAAAAABmRhbWFnZQAAAAAABQ==",
+        "AAAAAQAAAI9Db21iYXQgbnVtYmVycyBzZXQgYnkgdGhlIGFkbWluLiBFYWNoIGdhbWUgYW5kIGR1ZWwga2VlcHMgdGhlIHJ1bGUgc2V0IGl0IHN0YXJ0ZWQKdW5kZXIsIHNvIGEgcmViYWxhbmNlIG5ldmVyIGNoYW5nZXMgYSBiYXR0bGUgYWxyZWFkeSBpbiBwbGF5LgAAAAAAAAAAB1J1bGVTZXQAAAAADQAAAAAAAAAQYnJvYWRzaWRlX2RhbWFnZQAAAAUAAAAAAAAADWNvbWJvXzJfYm9udXMAAAAAAAAFAAAAAAAAAA1jb21ib18zX2JvbnVzAAAAAAAABQAAAAAAAAAPZmlyZWJhbGxfZGFtYWdlAAAAAAUAAAAAAAAAC2hvb2tfZGFtYWdlAAAAAAUAAAAAAAAAEGxpZ2h0bmluZ19kYW1hZ2UAAAAFAAAAAAAAAA5tb21lbnR1bV9ib251cwAAAAAABQAAAAAAAAATcmlwb3N0ZV9yZWZsZWN0X3BjdAAAAAAFAAAAAAAAAAxzbGFzaF9kYW1hZ2UAAAAFAAAAAAAAAAtzdGFydGluZ19ocAAAAAAFAAAAAAAAAAl0aWVfYnJlYWsAAAAAAAfQAAAACFRpZUJyZWFrAAAAAAAAABB0dXJuc19wZXJfYmF0dGxlAAAABAAAAAAAAAAHdmVyc2lvbgAAAAAE",
+        "AAAAAQAAAE9UaGUgY29tYmF0IHJ1bGVzIG5ldyBnYW1lcyBhcmUgcGxheWVkIHVuZGVyLCBmb3IgaGVscCBzY3JlZW5zIGFuZCBtb3ZlIHBsYW5uZXJzAAAAAAAAAAAFUnVsZXMAAAAAAAAMAAAAAAAAAAdhdHRhY2tzAAAAA+oAAAfQAAAACkF0dGFja1J1bGUAAAAAAAAAAAANY29tYm9fMl9ib251cwAAAAAAAAUAAAAAAAAADWNvbWJvXzNfYm9udXMAAAAAAAAFAAAAAAAAAA9jcml0X2NoYW5jZV9icHMAAAAABAAAAAAAAAATY3JpdF9tdWx0aXBsaWVyX3BjdAAAAAAFAAAAAAAAABBpbml0aWF0aXZlX2JvbnVzAAAABQAAAAAAAAAObW9tZW50dW1fYm9udXMAAAAAAAUAAAAAAAAAE3JpcG9zdGVfcmVmbGVjdF9wY3QAAAAABQAAAAAAAAALc3RhcnRpbmdfaHAAAAAABQAAAAAAAAAJdGllX2JyZWFrAAAAAAAH0AAAAAhUaWVCcmVhawAAAAAAAAAQdHVybnNfcGVyX2JhdHRsZQAAAAQAAAAAAAAAB3ZlcnNpb24AAAAABA==",
+        "AAAAAQAAAFBHYW1lcGxheSB0dW5pbmcgc2V0IGJ5IHRoZSBhZG1pbjsgdmFsdWVzIGFyZSBzbmFwc2hvdHRlZCBvbnRvIGVhY2ggZ2FtZSBhdCBzdGFydAAAAAAAAAAKR2FtZUNvbmZpZwAAAAAABQAAAAAAAAALY29tbWl0X21vZGUAAAAH0AAAAApDb21taXRNb2RlAAAAAAAAAAAAD2NyaXRfY2hhbmNlX2JwcwAAAAAEAAAAAAAAABNjcml0X211bHRpcGxpZXJfcGN0AAAAAAUAAAAAAAAAEGluaXRpYXRpdmVfYm9udXMAAAAFAAAAAAAAABJyZXZlYWxfd2luZG93X3NlY3MAAAAAAAY=",
+        "AAAAAQAAACZUb2tlbiBhbmQgdXBwZXIgYm91bmQgZm9yIGJsaW5kIHJhaXNlcwAAAAAAAAAAAAtSYWlzZUNvbmZpZwAAAAACAAAAAAAAAAptYXhfYW1vdW50AAAAAAALAAAAAAAAAAV0b2tlbgAAAAAAABM=",
+        "AAAAAQAAAG1CcmVha3BvaW50IG9mIHRoZSBmZWUgc2NoZWR1bGU6IHdhZ2VycyBvZiBhdCBsZWFzdCBgbWluX3dhZ2VyYCAodXAgdG8gdGhlIG5leHQKdGllcikgcGF5IGBmZWVfYnBzYCBvZiB0aGUgcG90AAAAAAAAAAAAAAdGZWVUaWVyAAAAAAIAAAAAAAAAB2ZlZV9icHMAAAAABAAAAAAAAAAJbWluX3dhZ2VyAAAAAAAACw==",
+        "AAAAAwAAAAAAAAAAAAAAC1Byb21vUmV3YXJkAAAAAAIAAAAAAAAACUZlZVdhaXZlcgAAAAAAAAAAAAAAAAAADERlcG9zaXRCb251cwAAAAE=",
+        "AAAAAQAAAAAAAAAAAAAACVByb21vQ29kZQAAAAAAAAQAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAIbWF4X3VzZXMAAAAEAAAAAAAAAAZyZXdhcmQAAAAAB9AAAAALUHJvbW9SZXdhcmQAAAAAAAAAAAR1c2VzAAAABA==",
+        "AAAAAQAAADJSZWZ1bmRhYmxlIGRlcG9zaXQgZWFjaCBwbGF5ZXIgcGF5cyBhdCBjb21taXQgdGltZQAAAAAAAAAAAA1Db21taXREZXBvc2l0AAAAAAAAAgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAV0b2tlbgAAAAAAABM=",
+        "AAAAAQAAAClTdGFrZXMgdGhlIEdhbWUgSHViIGhvbGRzIGZvciBvbmUgc2Vzc2lvbgAAAAAAAAAAAAANRXNjcm93UmVjZWlwdAAAAAAAAAgAAAAAAAAABWFzc2V0AAAAAAAAEwAAAAAAAAAJbG9ja2VkX2F0AAAAAAAABgAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAA5wbGF5ZXIxX2Ftb3VudAAAAAAACwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIyX2Ftb3VudAAAAAAACwAAAAAAAAALcmVsZWFzZWRfYXQAAAAABgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABA==",
+        "AAAAAQAAAEpQcm9vZiB0aGF0IGEgcGxheWVyIGNvbW1pdHRlZCwgYW5jaG9yZWQgdG8gdGhlIGxlZGdlciB0aGUgY29tbWl0IGxhbmRlZCBpbgAAAAAAAAAAAA1Db21taXRSZWNlaXB0AAAAAAAABQAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAAAAAAABmxlZGdlcgAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAG",
+        "AAAAAQAAADtPdXRjb21lIG9mIGEgbWF0Y2ggcGxheWVkIG9mZi1jaGFpbiwgc2lnbmVkIGJ5IGJvdGggcGxheWVycwAAAAAAAAAADFNpZ25lZFJlc3VsdAAAAAQAAAAAAAAAB2lzX2RyYXcAAAAAAQAAAAAAAAAKcGxheWVyMV9ocAAAAAAABQAAAAAAAAAKcGxheWVyMl9ocAAAAAAABQAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
+        "AAAAAQAAADdBIGNvLXNpZ25lZCByZXN1bHQgdGhhdCBlaXRoZXIgcGxheWVyIGNhbiBzdGlsbCBkaXNwdXRlAAAAAAAAAAARUGVuZGluZ1NldHRsZW1lbnQAAAAAAAACAAAAAAAAABJjaGFsbGVuZ2VfZGVhZGxpbmUAAAAAAAYAAAAAAAAABnJlc3VsdAAAAAAH0AAAAAxTaWduZWRSZXN1bHQ=",
+        "AAAAAQAAADlBIGdhbWUgd2l0aCB0aGUgc2Vzc2lvbiBpdCdzIHN0b3JlZCB1bmRlciwgZm9yIGJ1bGsgdmlld3MAAAAAAAAAAAAAC1Nlc3Npb25HYW1lAAAAAAIAAAAAAAAABGdhbWUAAAfQAAAABEdhbWUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQ=",
+        "AAAAAQAAAEhFdmVyeXRoaW5nIHN0b3JlZCBmb3Igb25lIGdhbWUsIGZvciBiYWNrdXAgYW5kIHJlc3RvcmUgYWNyb3NzIG1pZ3JhdGlvbnMAAAAAAAAACkdhbWVFeHBvcnQAAAAAAAQAAAAAAAAAC2V4cG9ydGVkX2F0AAAAAAQAAAAAAAAABGdhbWUAAAfQAAAABEdhbWUAAAAAAAAAEnBlbmRpbmdfc2V0dGxlbWVudAAAAAAH0AAAABJFeHBvcnRlZFNldHRsZW1lbnQAAAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABA==",
+        "AAAAAgAAAQVUaGUgc2lnbmVkIHJlc3VsdCBhIGdhbWUgd2FzIHdhaXRpbmcgb24gd2hlbiBleHBvcnRlZCwgaWYgYW55LiBBbgpgT3B0aW9uPFBlbmRpbmdTZXR0bGVtZW50PmAgaW4gZWZmZWN0OiBhbiBvcHRpb25hbCBzdHJ1Y3QgZmllbGQgYnVpbGRzIGZvciB0aGUKY29udHJhY3QsIGJ1dCBub3Qgd2l0aCBgdGVzdHV0aWxzYCwgd2hvc2UgWERSIGNvbnZlcnNpb25zIG5lZWQgYW4gYEludG88U2NWYWw+YAp0aGF0IGNvbnRyYWN0IHR5cGVzIGRvbid0IGltcGxlbWVudC4AAAAAAAAAAAAAEkV4cG9ydGVkU2V0dGxlbWVudAAAAAAAAgAAAAAAAAAAAAAABE5vbmUAAAABAAAAAAAAAAdQZW5kaW5nAAAAAAEAAAfQAAAAEVBlbmRpbmdTZXR0bGVtZW50AAAA",
+        "AAAAAwAAADNXaGF0IGtpbmQgb2YgZW50aXR5IGFuIGFjdGl2aXR5IGxvZyBlbnRyeSBwb2ludHMgYXQAAAAAAAAAAAxBY3Rpdml0eUtpbmQAAAAEAAAAAAAAAARHYW1lAAAAAAAAAAAAAAAJQ2hhbGxlbmdlAAAAAAAAAQAAAAAAAAAERHVlbAAAAAIAAAAAAAAACFRlYW1HYW1lAAAAAw==",
+        "AAAAAQAAAEZBIHNlc3Npb24gYXMgbGlzdGVkIGluIHRoZSByZWdpc3RyeSwgd2l0aCB0aGUgbGVkZ2VyIGl0IHdhcyBjcmVhdGVkIGluAAAAAAAAAAAADVNlc3Npb25SZWNvcmQAAAAAAAADAAAAAAAAAARraW5kAAAH0AAAAAxBY3Rpdml0eUtpbmQAAAAAAAAABmxlZGdlcgAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABA==",
+        "AAAAAQAAACFBbnN3ZXIgdG8gYGdldF9zZXNzaW9uc19pbl9yYW5nZWAAAAAAAAAAAAAADFNlc3Npb25SYW5nZQAAAAIAAAAAAAAACW5leHRfcGFnZQAAAAAAA+gAAAAEAAAAAAAAAAhzZXNzaW9ucwAAA+oAAAfQAAAADVNlc3Npb25SZWNvcmQAAAA=",
+        "AAAAAQAAAExBbiBlbnRpdHkgdGhhdCBjaGFuZ2VkIGZvciBhIHBsYXllciwgYW5kIHRoZSBwbGF5ZXIncyBzZXF1ZW5jZSBpdCBjaGFuZ2VkIGF0AAAAAAAAAAxFbnRpdHlDaGFuZ2UAAAADAAAAAAAAAAJpZAAAAAAABAAAAAAAAAAEa2luZAAAB9AAAAAMQWN0aXZpdHlLaW5kAAAAAAAAAANzZXEAAAAABg==",
+        "AAAAAQAAADpBIHBsYXllcidzIHJlY2VudCBjaGFuZ2VzLCBsYXRlc3QgcGVyIGVudGl0eSwgb2xkZXN0IGZpcnN0AAAAAAAAAAAAC0FjdGl2aXR5TG9nAAAAAAMAAAAAAAAAB2NoYW5nZXMAAAAD6gAAB9AAAAAMRW50aXR5Q2hhbmdlAAAAAAAAAAtkcm9wcGVkX3NlcQAAAAAGAAAAAAAAABFsYXN0X2FjdGl2aXR5X3NlcQAAAAAAAAY=",
+        "AAAAAQAAAB1BbnN3ZXIgdG8gYGdldF91cGRhdGVzX3NpbmNlYAAAAAAAAAAAAAANUGxheWVyVXBkYXRlcwAAAAAAAAMAAAAAAAAAB2NoYW5nZXMAAAAD6gAAB9AAAAAMRW50aXR5Q2hhbmdlAAAAAAAAAAtpc19jb21wbGV0ZQAAAAABAAAAAAAAABFsYXN0X2FjdGl2aXR5X3NlcQAAAAAAAAY=",
+        "AAAAAQAAAEFCdWlsZCBtZXRhZGF0YSByZWNvcmRlZCB3aGVuIHRoZSBjb250cmFjdCBpcyBkZXBsb3llZCBvciB1cGdyYWRlZAAAAAAAAAAAAAAJQnVpbGRJbmZvAAAAAAAAAwAAAAAAAAAMaW5zdGFsbGVkX2F0AAAABgAAAAAAAAAQaW5zdGFsbGVkX2xlZGdlcgAAAAQAAAAAAAAACXdhc21faGFzaAAAAAAAA+gAAAPuAAAAIA==",
+        "AAAAAQAAAEFQcm9ncmVzcyBvZiBgbWlncmF0ZWAgdG93YXJkcyB0aGUgc3RvcmFnZSBsYXlvdXQgdGhpcyBXQVNNIHdyaXRlcwAAAAAAAAAAAAAPTWlncmF0aW9uU3RhdHVzAAAAAAMAAAAAAAAABmN1cnNvcgAAAAAABAAAAAAAAAAObGF0ZXN0X3ZlcnNpb24AAAAAAAQAAAAAAAAAB3ZlcnNpb24AAAAABA==",
+        "AAAAAQAAAE5SZWFkaW5lc3Mgb2YgdGhlIGNvbnRyYWN0J3MgZGVwZW5kZW5jaWVzIGFuZCBzZXR0aW5ncywgZm9yIG1haW50ZW5hbmNlIGJhbm5lcnMAAAAAAAAAAAAOQ29udHJhY3RTdGF0dXMAAAAAAAkAAAAAAAAAD2NpcmN1aXRfdmVyc2lvbgAAAAAEAAAAAAAAAAtjb21taXRfbW9kZQAAAAfQAAAACkNvbW1pdE1vZGUAAAAAAAAAAAADaHViAAAAABMAAAAAAAAADmh1Yl9sYXN0X29rX2F0AAAAAAAGAAAAAAAAAAVwYXVzZQAAAAAAB9AAAAAKUGF1c2VGbGFncwAAAAAAAAAAAA1ydWxlc192ZXJzaW9uAAAAAAAABAAAAAAAAAAJc2Vhc29uX2lkAAAAAAAABAAAAAAAAAAOc2Vhc29uX3J1bm5pbmcAAAAAAAEAAAAAAAAADnZlcmlmaWVyX3JlYWR5AAAAAAAB",
+        "AAAAAQAAAAAAAAAAAAAAD0NvbnRyYWN0VmVyc2lvbgAAAAACAAAAAAAAAAVidWlsZAAAAAAAB9AAAAAJQnVpbGRJbmZvAAAAAAAAAAAAAAd2ZXJzaW9uAAAAABA=",
         "AAAABQAAADdFdmVudCBlbWl0dGVkIHdoZW4gYSBwb2xpY3kgaXMgYWRkZWQgdG8gYSBjb250ZXh0IHJ1bGUuAAAAAAAAAAALUG9saWN5QWRkZWQAAAAAAQAAAAxwb2xpY3lfYWRkZWQAAAACAAAAAAAAAA9jb250ZXh0X3J1bGVfaWQAAAAABAAAAAEAAAAAAAAACXBvbGljeV9pZAAAAAAAAAQAAAAAAAAAAg==",
         "AAAABQAAADdFdmVudCBlbWl0dGVkIHdoZW4gYSBzaWduZXIgaXMgYWRkZWQgdG8gYSBjb250ZXh0IHJ1bGUuAAAAAAAAAAALU2lnbmVyQWRkZWQAAAAAAQAAAAxzaWduZXJfYWRkZWQAAAACAAAAAAAAAA9jb250ZXh0X3J1bGVfaWQAAAAABAAAAAEAAAAAAAAACXNpZ25lcl9pZAAAAAAAAAQAAAAAAAAAAg==",
         "AAAABQAAADtFdmVudCBlbWl0dGVkIHdoZW4gYSBwb2xpY3kgaXMgcmVtb3ZlZCBmcm9tIGEgY29udGV4dCBydWxlLgAAAAAAAAAADVBvbGljeVJlbW92ZWQAAAAAAAABAAAADnBvbGljeV9yZW1vdmVkAAAAAAACAAAAAAAAAA9jb250ZXh0X3J1bGVfaWQAAAAABAAAAAEAAAAAAAAACXBvbGljeV9pZAAAAAAAAAQAAAAAAAAAAg==",
@@ -1564,47 +7659,523 @@ export class Client extends ContractClient {
         "AAAABQAAAE1FdmVudCBlbWl0dGVkIHdoZW4gYSBzaWduZXIgd2VpZ2h0IGlzIGNoYW5nZWQgaW4gYSB3ZWlnaHRlZCB0aHJlc2hvbGQKcG9saWN5LgAAAAAAAAAAAAAbV2VpZ2h0ZWRTaWduZXJXZWlnaHRDaGFuZ2VkAAAAAAEAAAAed2VpZ2h0ZWRfc2lnbmVyX3dlaWdodF9jaGFuZ2VkAAAAAAAEAAAAAAAAAA1zbWFydF9hY2NvdW50AAAAAAAAEwAAAAEAAAAAAAAAD2NvbnRleHRfcnVsZV9pZAAAAAAEAAAAAAAAAAAAAAAGc2lnbmVyAAAAAAfQAAAABlNpZ25lcgAAAAAAAAAAAAAAAAAGd2VpZ2h0AAAAAAAEAAAAAAAAAAI=",
         "AAAAAQAAADpJbnN0YWxsYXRpb24gcGFyYW1ldGVycyBmb3IgdGhlIHdlaWdodGVkIHRocmVzaG9sZCBwb2xpY3kuAAAAAAAAAAAAHldlaWdodGVkVGhyZXNob2xkQWNjb3VudFBhcmFtcwAAAAAAAgAAAC9NYXBwaW5nIG9mIHNpZ25lcnMgdG8gdGhlaXIgcmVzcGVjdGl2ZSB3ZWlnaHRzLgAAAAAOc2lnbmVyX3dlaWdodHMAAAAAA+wAAAfQAAAABlNpZ25lcgAAAAAABAAAADRUaGUgbWluaW11bSB0b3RhbCB3ZWlnaHQgcmVxdWlyZWQgZm9yIGF1dGhvcml6YXRpb24uAAAACXRocmVzaG9sZAAAAAAAAAQ=",
         "AAAABAAAADFFcnJvciB0eXBlcyBmb3IgV2ViQXV0aG4gdmVyaWZpY2F0aW9uIG9wZXJhdGlvbnMuAAAAAAAAAAAAAA1XZWJBdXRobkVycm9yAAAAAAAACgAAADlUaGUgc2lnbmF0dXJlIHBheWxvYWQgaXMgaW52YWxpZCBvciBoYXMgaW5jb3JyZWN0IGZvcm1hdC4AAAAAAAAXU2lnbmF0dXJlUGF5bG9hZEludmFsaWQAAAAMJgAAADNUaGUgY2xpZW50IGRhdGEgZXhjZWVkcyB0aGUgbWF4aW11bSBhbGxvd2VkIGxlbmd0aC4AAAAAEUNsaWVudERhdGFUb29Mb25nAAAAAAAMJwAAACZGYWlsZWQgdG8gcGFyc2UgSlNPTiBmcm9tIGNsaWVudCBkYXRhLgAAAAAADkpzb25QYXJzZUVycm9yAAAAAAwoAAAANFRoZSB0eXBlIGZpZWxkIGluIGNsaWVudCBkYXRhIGlzIG5vdCAid2ViYXV0aG4uZ2V0Ii4AAAAQVHlwZUZpZWxkSW52YWxpZAAADCkAAAA7VGhlIGNoYWxsZW5nZSBpbiBjbGllbnQgZGF0YSBkb2VzIG5vdCBtYXRjaCBleHBlY3RlZCB2YWx1ZS4AAAAAEENoYWxsZW5nZUludmFsaWQAAAwqAAAANlRoZSBhdXRoZW50aWNhdG9yIGRhdGEgZm9ybWF0IGlzIGludmFsaWQgb3IgdG9vIHNob3J0LgAAAAAAFUF1dGhEYXRhRm9ybWF0SW52YWxpZAAAAAAADCsAAAA8VGhlIFVzZXIgUHJlc2VudCAoVVApIGJpdCBpcyBub3Qgc2V0IGluIGF1dGhlbnRpY2F0b3IgZmxhZ3MuAAAAEFByZXNlbnRCaXROb3RTZXQAAAwsAAAAPVRoZSBVc2VyIFZlcmlmaWVkIChVVikgYml0IGlzIG5vdCBzZXQgaW4gYXV0aGVudGljYXRvciBmbGFncy4AAAAAAAARVmVyaWZpZWRCaXROb3RTZXQAAAAAAAwtAAAAP0ludmFsaWQgcmVsYXRpb25zaGlwIGJldHdlZW4gQmFja3VwIEVsaWdpYmlsaXR5IGFuZCBTdGF0ZSBiaXRzLgAAAAAfQmFja3VwRWxpZ2liaWxpdHlBbmRTdGF0ZU5vdFNldAAAAAwuAAAAQlRoZSBwcm92aWRlZCBrZXkgZGF0YSBkb2VzIG5vdCBjb250YWluIGEgdmFsaWQgNjUtYnl0ZSBwdWJsaWMga2V5LgAAAAAADktleURhdGFJbnZhbGlkAAAAAAwv",
-        "AAAAAQAAAMhXZWJBdXRobiBzaWduYXR1cmUgZGF0YSBzdHJ1Y3R1cmUgY29udGFpbmluZyBhbGwgY29tcG9uZW50cyBuZWVkZWQgZm9yCnZlcmlmaWNhdGlvbi4KClRoaXMgc3RydWN0dXJlIGVuY2Fwc3VsYXRlcyB0aGUgc2lnbmF0dXJlIGFuZCBhc3NvY2lhdGVkIGRhdGEgZ2VuZXJhdGVkCmR1cmluZyBhIFdlYkF1dGhuIGF1dGhlbnRpY2F0aW9uIGNlcmVtb255LgAAAAAAAAAPV2ViQXV0aG5TaWdEYXRhAAAAAAMAAAAyUmF3IGF1dGhlbnRpY2F0b3IgZGF0YSBmcm9tIHRoZSBXZWJBdXRobiByZXNwb25zZS4AAAAAABJhdXRoZW50aWNhdG9yX2RhdGEAAAAAAA4AAAAwUmF3IGNsaWVudCBkYXRhIEpTT04gZnJvbSB0aGUgV2ViQXV0aG4gcmVzcG9uc2UuAAAAC2NsaWVudF9kYXRhAAAAAA4AAAA1VGhlIGNyeXB0b2dyYXBoaWMgc2lnbmF0dXJlICg2NCBieXRlcyBmb3Igc2VjcDI1NnIxKS4AAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEA=" ]),
+        "AAAAAQAAAMhXZWJBdXRobiBzaWduYXR1cmUgZGF0YSBzdHJ1Y3R1cmUgY29udGFpbmluZyBhbGwgY29tcG9uZW50cyBuZWVkZWQgZm9yCnZlcmlmaWNhdGlvbi4KClRoaXMgc3RydWN0dXJlIGVuY2Fwc3VsYXRlcyB0aGUgc2lnbmF0dXJlIGFuZCBhc3NvY2lhdGVkIGRhdGEgZ2VuZXJhdGVkCmR1cmluZyBhIFdlYkF1dGhuIGF1dGhlbnRpY2F0aW9uIGNlcmVtb255LgAAAAAAAAAPV2ViQXV0aG5TaWdEYXRhAAAAAAMAAAAyUmF3IGF1dGhlbnRpY2F0b3IgZGF0YSBmcm9tIHRoZSBXZWJBdXRobiByZXNwb25zZS4AAAAAABJhdXRoZW50aWNhdG9yX2RhdGEAAAAAAA4AAAAwUmF3IGNsaWVudCBkYXRhIEpTT04gZnJvbSB0aGUgV2ViQXV0aG4gcmVzcG9uc2UuAAAAC2NsaWVudF9kYXRhAAAAAA4AAAA1VGhlIGNyeXB0b2dyYXBoaWMgc2lnbmF0dXJlICg2NCBieXRlcyBmb3Igc2VjcDI1NnIxKS4AAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAEA=",
+        "AAAAAQAAAKRIb3cgYSB0b3VybmFtZW50J3MgbGFyZ2UgcHJpemVzIHZlc3Q6IGEgcHJpemUgYWJvdmUgYHRocmVzaG9sZGAgcGF5cwpgaW1tZWRpYXRlX2Jwc2Agb2YgaXRzZWxmIHN0cmFpZ2h0IGF3YXkgYW5kIHRoZSByZXN0IGBsb2NrX3NlY3NgIGFmdGVyIHRoZQp0b3VybmFtZW50IGNvbXBsZXRlcwAAAAAAAAAMUHJpemVWZXN0aW5nAAAAAwAAAAAAAAANaW1tZWRpYXRlX2JwcwAAAAAAAAQAAAAAAAAACWxvY2tfc2VjcwAAAAAAAAYAAAAAAAAACXRocmVzaG9sZAAAAAAAAAs=",
+        "AAAAAQAAAC5UaGUgbG9ja2VkIHBhcnQgb2YgYSBwbGF5ZXIncyB0b3VybmFtZW50IHByaXplAAAAAAAAAAAAC1Zlc3RlZFByaXplAAAAAAIAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAKdW5sb2Nrc19hdAAAAAAABg==",
+        "AAAAAQAAAKdSYXRpbmcgZGVjYXkgZm9yIGluYWN0aXZlIHBsYXllcnM6IGEgcmF0aW5nIGFib3ZlIHRoZSBzdGFydGluZyAxNTAwIGxvc2VzCmBwb2ludHNgIGZvciBldmVyeSBgaW5hY3RpdmVfc2Vjc2Agd2l0aG91dCBhIHJhdGVkIGdhbWUsIGRvd24gdG8gMTUwMC4gT2ZmIHdoaWxlCmVpdGhlciBpcyAwLgAAAAAAAAAAC1JhdGluZ0RlY2F5AAAAAAIAAAAAAAAADWluYWN0aXZlX3NlY3MAAAAAAAAGAAAAAAAAAAZwb2ludHMAAAAAAAQ=",
+        "AAAAAAAAAFdFdmVyeXRoaW5nIGEgZnJvbnRlbmQgbmVlZHMgdG8gZGVjaWRlIHdoZXRoZXIgdG8gc2hvdyBhIG1haW50ZW5hbmNlIGJhbm5lciwKaW4gb25lIGNhbGwAAAAABnN0YXR1cwAAAAAAAAAAAAEAAAfQAAAADkNvbnRyYWN0U3RhdHVzAAA=",
+        "AAAAAAAAAEZDb25jZWRlIGFuIHVuZmluaXNoZWQgZ2FtZTsgdGhlIG9wcG9uZW50IHdpbnMgYW5kIHRoZSBHYW1lIEh1YiBpcyB0b2xkAAAAAAAHZm9yZmVpdAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAB9AAAAAMQmF0dGxlUmVzdWx0AAAAAw==",
+        "AAAAAAAAAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAIhBZnRlciBhbiB1cGdyYWRlIHRoYXQgY2hhbmdlcyB0aGUgc3RvcmFnZSBsYXlvdXQsIHJld3JpdGUgdXAgdG8gYGJhdGNoX3NpemVgCm9sZCBlbnRyaWVzLiBDYWxsIHVudGlsIGB2ZXJzaW9uYCByZWFjaGVzIGBsYXRlc3RfdmVyc2lvbmAuAAAAB21pZ3JhdGUAAAAAAQAAAAAAAAAKYmF0Y2hfc2l6ZQAAAAAABAAAAAEAAAfQAAAAD01pZ3JhdGlvblN0YXR1cwA=",
+        "AAAAAAAAAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
+        "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
+        "AAAAAAAAAJtBUEkgc2VtdmVyIG9mIHRoZSBydW5uaW5nIGNvbnRyYWN0IHBsdXMgdGhlIGJ1aWxkIG1ldGFkYXRhIHJlY29yZGVkIGF0IGRlcGxveS91cGdyYWRlIHRpbWUsCnNvIGNsaWVudHMgY2FuIGZlYXR1cmUtZGV0ZWN0IGJlZm9yZSBjYWxsaW5nIG5ld2VyIGVudHJ5cG9pbnRzLgAAAAAHdmVyc2lvbgAAAAAAAAAAAQAAB9AAAAAPQ29udHJhY3RWZXJzaW9uAA==",
+        "AAAAAAAAAHpDb25maXJtIGEgcmVnaXN0ZXJlZCBwbGF5ZXIgd2lsbCBwbGF5LCBpbiB0aGUgMzAgbWludXRlcyBiZWZvcmUgdGhlCnRvdXJuYW1lbnQgc3RhcnRzLiBPbmx5IGNoZWNrZWQtaW4gcGxheWVycyBhcmUgc2VlZGVkLgAAAAAACGNoZWNrX2luAAAAAgAAAAAAAAANdG91cm5hbWVudF9pZAAAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAAAAAAAIZ2V0X2R1ZWwAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAABER1ZWwAAAAD",
+        "AAAAAAAAABRHZXQgZ2FtZSBpbmZvcm1hdGlvbgAAAAhnZXRfZ2FtZQAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAER2FtZQAAAAM=",
+        "AAAAAAAAAAAAAAAJZW5kX21hdGNoAAAAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAhtYXRjaF9pZAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAGxTZXZlcmFsIGdhbWVzIGF0IG9uY2UsIGVhY2ggd2l0aCBpdHMgc2Vzc2lvbiBJRDsgc2Vzc2lvbnMgd2l0aCBubyBnYW1lIGFyZQpsZWZ0IG91dCwgYW5kIGF0IG1vc3QgMjAgYXJlIHJlYWQAAAAJZ2V0X2dhbWVzAAAAAAAAAQAAAAAAAAALc2Vzc2lvbl9pZHMAAAAD6gAAAAQAAAABAAAD6gAAB9AAAAALU2Vzc2lvbkdhbWUA",
+        "AAAAAAAAAAAAAAAJZ2V0X21hdGNoAAAAAAAAAQAAAAAAAAAIbWF0Y2hfaWQAAAAEAAAAAQAAA+kAAAfQAAAACFB2UE1hdGNoAAAAAw==",
+        "AAAAAAAAAAAAAAAJZ2V0X3BhdXNlAAAAAAAAAAAAAAEAAAfQAAAAClBhdXNlRmxhZ3MAAA==",
+        "AAAAAAAAADFQaWNrcyBtYWRlIG9uIGEgZ2FtZSB0aGF0IGhhcyBub3QgYmVlbiBzY29yZWQgeWV0AAAAAAAACWdldF9waWNrcwAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6gAAB9AAAAANU3BlY3RhdG9yUGljawAAAA==",
+        "AAAAAAAAAAAAAAAJZ2V0X3JpdmFsAAAAAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAFFEYW1hZ2UgdGFibGUsIGJsb2NrcywgY29tYm8sIG1vbWVudHVtIGFuZCBjcml0IHNldHRpbmdzIG5ldyBnYW1lcyBhcmUgcGxheWVkIHdpdGgAAAAAAAAJZ2V0X3J1bGVzAAAAAAAAAAAAAAEAAAfQAAAABVJ1bGVzAAAA",
+        "AAAAAAAAAJtDYWxsIHRoZSB3aW5uZXIgb2YgYSBnYW1lIGZvciBmcmVlLiBPcGVuIHRvIGFueW9uZSBidXQgdGhlIHR3byBwbGF5ZXJzLCB1bnRpbAp0aGUgc2Vjb25kIHJldmVhbCBsYW5kczsgc2NvcmVkIGludG8gYGdldF9waWNrX3N0YXRzYCB3aGVuIHRoZSBnYW1lIHJlc29sdmVzLgAAAAAJbWFrZV9waWNrAAAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAJc3BlY3RhdG9yAAAAAAAAEwAAAAAAAAANYmFja2VkX3BsYXllcgAAAAAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAAAAAAAJcGxheV90dXJuAAAAAAAAAwAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAhtYXRjaF9pZAAAAAQAAAAAAAAABmFjdGlvbgAAAAAH0AAAAARNb3ZlAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAEtDdXQgdGhlIGN1cnJlbnQgc2NoZWR1bGUgdGFrZXMgZnJvbSB0aGUgcG90IHdoZW4gZWFjaCBwbGF5ZXIgc3Rha2VzIGB3YWdlcmAAAAAACXF1b3RlX2ZlZQAAAAAAAAEAAAAAAAAABXdhZ2VyAAAAAAAACwAAAAEAAAAL",
+        "AAAAAAAAAAAAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
+        "AAAAAAAAAD9OYW1lIChvciB3aXRoIGBOb25lYCwgcmVtb3ZlKSBgcGxheWVyYCdzIGNvYWNoIGZvciBgc2Vzc2lvbl9pZGAAAAAACXNldF9jb2FjaAAAAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAFY29hY2gAAAAAAAPoAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAFxUcmlwIG9yIHJlc2V0IHRoZSBjaXJjdWl0IGJyZWFrZXJzLiBSZXBsYWNlcyBhbGwgZmxhZ3MgYXQgb25jZTsgcGFzcyB0aGUKZGVmYXVsdCB0byB1bnBhdXNlLgAAAAlzZXRfcGF1c2UAAAAAAAABAAAAAAAAAAVmbGFncwAAAAAAB9AAAAAKUGF1c2VGbGFncwAAAAAAAA==",
+        "AAAAAAAAAKZSZXBsYWNlIHRoZSBjb21iYXQgcnVsZXMuIEFwcGxpZXMgdG8gZ2FtZXMgYW5kIGR1ZWxzIHN0YXJ0ZWQgYWZ0ZXJ3YXJkczsgdGhlCmB2ZXJzaW9uYCBwYXNzZWQgaW4gaXMgaWdub3JlZCBhbmQgdGhlIG5ldyBydWxlcyBnZXQgdGhlIG5leHQgdmVyc2lvbiwgd2hpY2gKaXMgcmV0dXJuZWQuAAAAAAAJc2V0X3J1bGVzAAAAAAAAAQAAAAAAAAAFcnVsZXMAAAAAAAfQAAAAB1J1bGVTZXQAAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAACxBZGQgYGZyaWVuZGAgdG8geW91ciBmcmllbmRzIGxpc3QgKHVwIHRvIDUwKQAAAAphZGRfZnJpZW5kAAAAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABmZyaWVuZAAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAAAAAAAKYWRkX3BvbGljeQAAAAAAAwAAAAAAAAAPY29udGV4dF9ydWxlX2lkAAAAAAQAAAAAAAAABnBvbGljeQAAAAAAEwAAAAAAAAANaW5zdGFsbF9wYXJhbQAAAAAAAAAAAAABAAAABA==",
+        "AAAAAAAAAAAAAAAKYWRkX3NpZ25lcgAAAAAAAgAAAAAAAAAPY29udGV4dF9ydWxlX2lkAAAAAAQAAAAAAAAABnNpZ25lcgAAAAAH0AAAAAZTaWduZXIAAAAAAAEAAAAE",
+        "AAAAAAAAABpNYXRjaCB0aGUgb3Bwb25lbnQncyByYWlzZQAAAAAACmNhbGxfcmFpc2UAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAI5DbG9zZSB0aGUgcnVubmluZyBzZWFzb24sIGZyZWV6aW5nIGl0cyBgdG9wX25gIGJlc3Qgc2Vhc29uIHJhdGluZ3MuIFRoZQpzZWFzb24gcmV3YXJkcywgaWYgc2V0LCBhcmUgdGFrZW4gZnJvbSB0aGUgYWRtaW4gYW5kIGJlY29tZSBjbGFpbWFibGUuAAAAAAAKZW5kX3NlYXNvbgAAAAAAAQAAAAAAAAAFdG9wX24AAAAAAAAEAAAAAQAAA+kAAAfQAAAADVNlYXNvblJlc3VsdHMAAAAAAAAD",
+        "AAAAAAAAAEZEZWNsaW5lIHRoZSBvcHBvbmVudCdzIHJhaXNlLCBjb25jZWRpbmcgdGhlIGdhbWUgYXQgdGhlIG9yaWdpbmFsIHN0YWtlAAAAAAAKZm9sZF9yYWlzZQAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAfQAAAADEJhdHRsZVJlc3VsdAAAAAM=",
+        "AAAAAAAAALBQZW5kaW5nIGNoYWxsZW5nZXMsIGdhbWVzIGF3YWl0aW5nIGBwbGF5ZXJgIGFuZCByZXdhcmRzIHRvIGNsYWltLCBhcyBjb3VudHMKZm9yIHRoZSBuYXZiYXIuIFRoZXkgY2FuIGxhZyB0aGUgY2xvY2s7IGBnZXRfcGxheWVyX2NoYWxsZW5nZXNgIGFuZApgZ2V0X2F3YWl0aW5nX2FjdGlvbmAgYXJlIGV4YWN0LgAAAApnZXRfYmFkZ2VzAAAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAH0AAAAAZCYWRnZXMAAA==",
+        "AAAAAAAAADFFc2Nyb3cgcmVjZWlwdCBmb3IgYSBnYW1lIG9yIGR1ZWwncyBsb2NrZWQgc3Rha2VzAAAAAAAACmdldF9lc2Nyb3cAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6AAAB9AAAAANRXNjcm93UmVjZWlwdAAAAA==",
+        "AAAAAAAAADNUaGUgcnVubmluZyBzZWFzb24sIG9yIHRoZSBsYXN0IG9uZSBpZiBpdCBoYXMgZW5kZWQAAAAACmdldF9zZWFzb24AAAAAAAAAAAABAAAD6AAAB9AAAAAGU2Vhc29uAAA=",
+        "AAAAAAAAAAAAAAAKZ2V0X3NlcmllcwAAAAAAAQAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAEAAAPpAAAH0AAAAAtNYXRjaFNlcmllcwAAAAAD",
+        "AAAAAAAAAJdRdWV1ZSBhdCBhIHdhZ2VyIHRpZXIgKDA6IDEwLCAxOiAxMDAsIDI6IDEwMDAgcG9pbnRzKS4gUmV0dXJucyB0aGUgc2Vzc2lvbiBpZCBpZgpzb21lb25lIHdhcyBhbHJlYWR5IHdhaXRpbmcgYW5kIGEgZ2FtZSBzdGFydGVkLCBgTm9uZWAgaWYgbm93IHdhaXRpbmcuAAAAAApqb2luX3F1ZXVlAAAAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABHRpZXIAAAAEAAAAAQAAA+kAAAPoAAAABAAAAAM=",
+        "AAAAAAAAAIZTdGFydCBhIHR1cm4tYnktdHVybiBkdWVsLiBTYW1lIEdhbWUgSHViIHNlc3Npb24gYW5kIHBvaW50cyBhcyBgc3RhcnRfZ2FtZWAsCmJ1dCBtb3ZlcyBhcmUgY29tbWl0dGVkIGFuZCByZXZlYWxlZCBvbmUgcm91bmQgYXQgYSB0aW1lLgAAAAAACnN0YXJ0X2R1ZWwAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAIpTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuIEJvdGggcGxheWVycyBzaWduIHRoZWlyIG93bgpzdGFrZSBpbiB0aGlzIG9uZSB0cmFuc2FjdGlvbjsgc2VlIGBwcm9wb3NlX2dhbWVgIG90aGVyd2lzZS4AAAAAAApzdGFydF9nYW1lAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAFZBbnlvbmUgY2FuIHNlbmQgYWxsIGZlZXMgY29sbGVjdGVkIGluIGB0b2tlbmAgdG8gdGhlIHRyZWFzdXJ5OyByZXR1cm5zIHRoZQphbW91bnQgc2VudAAAAAAACnN3ZWVwX2ZlZXMAAAAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAANhDYWxsIG9mZiBhIGdhbWUgbm9ib2R5IGhhcyBjb21taXR0ZWQgdG8gb25jZSBpdCBoYXMgc2F0IGZvciBhIGRheSAob3IsIGZvcgpjb3JyZXNwb25kZW5jZSwgcGFzdCBpdHMgY29tbWl0IGRlYWRsaW5lKS4gRWl0aGVyIHBsYXllciBvciB0aGUgYWRtaW4gY2FuCmNhbmNlbDsgdGhlIEdhbWUgSHViIHJlZnVuZHMgYm90aCBzdGFrZXMgYW5kIG5vIHJlc3VsdCBpcyByZWNvcmRlZC4AAAALY2FuY2VsX2dhbWUAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAHZDb2xsZWN0IHRoZSBwcml6ZSBjcmVkaXRlZCB0byBgcGxheWVyYCB3aGVuIHRoZSB0b3VybmFtZW50IGNvbXBsZXRlZCwgb3IgdGhlCm9yZ2FuaXplcidzIHBvb2wgYmFjayBpZiBpdCB3YXMgY2FuY2VsbGVkAAAAAAALY2xhaW1fcHJpemUAAAAAAgAAAAAAAAANdG91cm5hbWVudF9pZAAAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAAAAAAALY2xlYXJfcml2YWwAAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAA==",
+        "AAAAAAAAAKlBbnlvbmU6IHZvaWQgYSBnYW1lIHN0aWxsIHVucmVzb2x2ZWQgMTQgZGF5cyBhZnRlciBpdCBzdGFydGVkIChwbHVzIGFueSBhZ3JlZWQKcGF1c2VzKSwgd2hpY2hldmVyIHBoYXNlIGl0IHN0YWxsZWQgaW4uIEV2ZXJ5IHN0YWtlIGlzIHJlZnVuZGVkIGFuZCBubyByZXN1bHQKaXMgcmVjb3JkZWQuAAAAAAAAC2V4cGlyZV9nYW1lAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAEBGdWxsIHN0b3JlZCBzdGF0ZSBvZiBhIGdhbWUgaW4gb25lIHN0cnVjdCwgZm9yIGRpc2FzdGVyIHJlY292ZXJ5AAAAC2V4cG9ydF9nYW1lAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAKR2FtZUV4cG9ydAAAAAAAAw==",
+        "AAAAAAAAAIpUaGUgd2hvbGUgYnJhY2tldCwgbGFpZCBvdXQgd2hlbiBpdCBpcyBzZWVkZWQ6IHdpbm5lcnMnIGJyYWNrZXQgcm91bmQgYnkKcm91bmQsIHRoZW4gdGhlIGxvc2VycycgYnJhY2tldCBhbmQgdGhlIGdyYW5kIGZpbmFsIGFuZCBpdHMgcmVzZXQAAAAAAAtnZXRfYnJhY2tldAAAAAABAAAAAAAAAA10b3VybmFtZW50X2lkAAAAAAAABAAAAAEAAAPqAAAH0AAAAAxCcmFja2V0TWF0Y2g=",
+        "AAAAAAAAAAAAAAALZ2V0X3JlbWF0Y2gAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPoAAAH0AAAAAdSZW1hdGNoAA==",
+        "AAAAAAAAAK5SZXN0b3JlIGEgZ2FtZSBmcm9tIGBleHBvcnRfZ2FtZWAsIG92ZXJ3cml0aW5nIHdoYXRldmVyIGlzIHN0b3JlZCBmb3IgdGhhdApzZXNzaW9uLiBGb3IgcmVjb3ZlcmluZyBmcm9tIG1pZ3JhdGlvbnMgb3IgZXhwaXJlZCB0ZW1wb3Jhcnkgc3RvcmFnZTsgdGhlCkdhbWUgSHViIGlzIG5vdCBub3RpZmllZC4AAAAAAAtpbXBvcnRfZ2FtZQAAAAABAAAAAAAAAAZleHBvcnQAAAAAB9AAAAAKR2FtZUV4cG9ydAAAAAAAAA==",
+        "AAAAAAAAAEVNb2RlcmF0b3Igb25seSwgc28gdGhlIGZsYWcgc3RheXMgaGlkZGVuIGZyb20gdGhlIHBsYXllciBpdCBpcyBzZXQgb24AAAAAAAALaXNfc2hhZG93ZWQAAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAAAE=",
+        "AAAAAAAAAAAAAAALbGVhdmVfcXVldWUAAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAJJBZnRlciBib3RoIGNvbW1pdHMgYW5kIGJlZm9yZSBhbnkgcmV2ZWFsLCByYWlzZSB0aGUgc3Rha2UgYnkgYGFtb3VudGAgb2YgdGhlCnJhaXNlIHRva2VuLiBUaGUgb3Bwb25lbnQgbXVzdCBjYWxsIG9yIGZvbGQgYmVmb3JlIGFueW9uZSBjYW4gcmV2ZWFsLgAAAAAAC3JhaXNlX3dhZ2VyAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAAAAAAALcmVkZWVtX2NvZGUAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAARjb2RlAAAAEQAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAEhFbmQgYSBydW5uaW5nIHBhdXNlIGVhcmx5LCB0YWtpbmcgdGhlIHVudXNlZCB0aW1lIGJhY2sgb2ZmIHRoZSBkZWFkbGluZXMAAAALcmVzdW1lX2dhbWUAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAJlTdGFydCBhIHNpZWdlOiBgYXR0YWNrZXJgIG9ubHkgYXR0YWNrcyBhbmQgYGRlZmVuZGVyYCBvbmx5IGRlZmVuZHMsIHdpbm5pbmcgYnkKc3Vydml2aW5nIGV2ZXJ5IHR1cm4uIENvbW1pdCwgcmV2ZWFsIGFuZCByZXNvbHZlIHdvcmsgYXMgZm9yIGBzdGFydF9nYW1lYC4AAAAAAAALc3RhcnRfc2llZ2UAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAIYXR0YWNrZXIAAAATAAAAAAAAAAhkZWZlbmRlcgAAABMAAAAAAAAAD2F0dGFja2VyX3BvaW50cwAAAAALAAAAAAAAAA9kZWZlbmRlcl9wb2ludHMAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAAAAAAAMX19jaGVja19hdXRoAAAAAwAAAAAAAAARc2lnbmF0dXJlX3BheWxvYWQAAAAAAAPuAAAAIAAAAAAAAAAKc2lnbmF0dXJlcwAAAAAH0AAAAAtBdXRoUGF5bG9hZAAAAAAAAAAADWF1dGhfY29udGV4dHMAAAAAAAPqAAAH0AAAAAdDb250ZXh0AAAAAAEAAAPpAAAAAgAAB9AAAAARU21hcnRBY2NvdW50RXJyb3IAAAA=",
+        "AAAAAAAAAGZBY2NlcHQgdGhlIG9wcG9uZW50J3MgcGF1c2U6IGV2ZXJ5IG9wZW4gZGVhZGxpbmUgbW92ZXMgb3V0IGJ5IGl0cyBsZW5ndGguClJldHVybnMgd2hlbiB0aGUgcGF1c2UgZW5kcy4AAAAAAAxhY2NlcHRfcGF1c2UAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAAAYAAAAD",
+        "AAAAAAAAAJVDb21taXQgbW92ZSBzZXF1ZW5jZSB3aXRoIFpLIHByb29mClBsYXllciBwcm92ZXMgdGhleSBrbm93IHZhbGlkIG1vdmVzIFdJVEhPVVQgcmV2ZWFsaW5nIHRoZW0uClRoZSBwcm9vZidzIHB1YmxpYyBvdXRwdXQgKGNvbW1pdG1lbnQgaGFzaCkgaXMgc3RvcmVkLgAAAAAAAAxjb21taXRfbW92ZXMAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAADXB1YmxpY19pbnB1dHMAAAAAAAAOAAAAAAAAAAtwcm9vZl9ieXRlcwAAAAAOAAAAAQAAA+kAAAfQAAAADUNvbW1pdFJlY2VpcHQAAAAAAAAD",
+        "AAAAAAAAADlBY2NlcHQgYSBwcm9wb3NlZCBnYW1lLCBzdGFydGluZyBpdCBhcyBgc3RhcnRfZ2FtZWAgd291bGQAAAAAAAAMY29uZmlybV9nYW1lAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMgAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAAAAAAAMY29uZmlybV9saW5rAAAAAgAAAAAAAAAJc2Vjb25kYXJ5AAAAAAAAEwAAAAAAAAAHcHJpbWFyeQAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAERUaGUgcHJpbWFyeSBhZGRyZXNzIGBwbGF5ZXJgIHBsYXlzIGFzOyBgcGxheWVyYCBpdHNlbGYgaWYgbm90IGxpbmtlZAAAAAxnZXRfaWRlbnRpdHkAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAAEw==",
+        "AAAAAAAAAAAAAAAMZ2V0X3JlZmVycmVyAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAAAAAAAMZ2V0X3RyZWFzdXJ5AAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAENHZXQgdXNlcm5hbWUgZm9yIGFuIGFkZHJlc3MgKGEgbGlua2VkIGFkZHJlc3Mgc2hhcmVzIGl0cyBwcmltYXJ5J3MpAAAAAAxnZXRfdXNlcm5hbWUAAAABAAAAAAAAAAdhZGRyZXNzAAAAABMAAAABAAAD6AAAABA=",
+        "AAAAAAAAAAAAAAAMZ2V0X3ZlcmlmaWVyAAAAAQAAAAAAAAAHdmVyc2lvbgAAAAAEAAAAAQAAA+gAAAfQAAAADVZlcmlmaWVyRW50cnkAAAA=",
+        "AAAAAAAAADJUb2tlbiBhbW91bnRzIGBwbGF5ZXJgIGNhbiBzdGlsbCBjbGFpbSBmcm9tIGEgZ2FtZQAAAAAADGdldF93aW5uaW5ncwAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPsAAAAEwAAAAs=",
+        "AAAAAAAAALFBc2sgdG8gbGluayBgc2Vjb25kYXJ5YCB0byB0aGUgY2FsbGVyJ3MgaWRlbnRpdHk7IGBzZWNvbmRhcnlgIGNvbmZpcm1zIHdpdGgKYGNvbmZpcm1fbGlua2AuIEl0IG11c3QgaGF2ZSBubyBnYW1lcywgcmF0aW5nIG9yIG5hbWUgb2YgaXRzIG93biwgYW5kIG5vIGxpdmUKc2Vzc2lvbnMgb3IgY2hhbGxlbmdlcy4AAAAAAAAMbGlua19hZGRyZXNzAAAAAgAAAAAAAAAHcHJpbWFyeQAAAAATAAAAAAAAAAlzZWNvbmRhcnkAAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAGBPZmZlciBgcGxheWVyMmAgYSBjbGFzc2ljIGdhbWUgb24gYHNlc3Npb25faWRgOyBub3RoaW5nIGlzIGxvY2tlZCB1bnRpbCB0aGV5CmNhbGwgYGNvbmZpcm1fZ2FtZWAAAAAMcHJvcG9zZV9nYW1lAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAJhSZXZlYWwgbW92ZXMg4oCUIHBsYXllciByZS1wcm92ZXMgd2l0aCBtb3ZlcyBub3cgUFVCTElDLgpUaGUgY29udHJhY3QgdmVyaWZpZXMgdGhlIG5ldyBwcm9vZidzIGNvbW1pdG1lbnQgb3V0cHV0Cm1hdGNoZXMgd2hhdCB3YXMgc3RvcmVkIGF0IGNvbW1pdCB0aW1lLgAAAAxyZXZlYWxfbW92ZXMAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAADXB1YmxpY19pbnB1dHMAAAAAAAAOAAAAAAAAAAVtb3ZlcwAAAAAAA+oAAAfQAAAABE1vdmUAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAHlNb2RlcmF0b3I6IHF1aWV0bHkga2VlcCBgcGxheWVyYCBvdXQgb2YgcmF0ZWQgcGxheSwgdGhlIGxhZGRlcnMgYW5kCm1hdGNobWFraW5nIHdoaWxlIHRoZXkgYXJlIGludmVzdGlnYXRlZCwgb3IgbGlmdCB0aGF0AAAAAAAADHNldF9zaGFkb3dlZAAAAAIAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAIc2hhZG93ZWQAAAABAAAAAA==",
+        "AAAAAAAAACZXaGVyZSBgc3dlZXBfZmVlc2AgcGF5cyBjb2xsZWN0ZWQgZmVlcwAAAAAADHNldF90cmVhc3VyeQAAAAEAAAAAAAAACHRyZWFzdXJ5AAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAAMc2V0X3VzZXJuYW1lAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAh1c2VybmFtZQAAABAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAJZPcGVuIGEgc2Vhc29uIGVuZGluZyBhdCBgZW5kX3RpbWVzdGFtcGAuIFJhdGVkIHJlc3VsdHMgYXJlIGFsc28gdHJhY2tlZCBwZXIKc2Vhc29uIGZyb20gZXZlcnlvbmUncyBzdGFydGluZyByYXRpbmc7IGxpZmV0aW1lIHN0YXRzIGNhcnJ5IG9uIGFzIGJlZm9yZS4AAAAAAAxzdGFydF9zZWFzb24AAAACAAAAAAAAAAlzZWFzb25faWQAAAAAAAAEAAAAAAAAAA1lbmRfdGltZXN0YW1wAAAAAAAABgAAAAEAAAPpAAAH0AAAAAZTZWFzb24AAAAAAAM=",
+        "AAAAAAAAAMhTdGFydCBhIGJlc3Qtb2YtMyBvciBiZXN0LW9mLTUgc2VyaWVzIG9uIEdhbWUgSHViIHNlc3Npb24gYHNlcmllc19pZGAuIEVhY2gKcm91bmQgaXMgYSBjbGFzc2ljIGdhbWUgb24gaXRzIG93biBzZXNzaW9uOyB0aGUgbmV4dCBvbmUgc3RhcnRzIHdoZW4gYSByb3VuZApyZXNvbHZlcy4gUmV0dXJucyB0aGUgZmlyc3Qgcm91bmQncyBzZXNzaW9uIGlkLgAAAAxzdGFydF9zZXJpZXMAAAAGAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAAAAAAdiZXN0X29mAAAAAAQAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAADZJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4AAAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAwAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAAAAAAEXZlcmlmaWVyX2NvbnRyYWN0AAAAAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAANYWNjZXB0X2ludml0ZQAAAAAAAAIAAAAAAAAACGFjY2VwdGVyAAAAEwAAAAAAAAAIbWF0Y2hfaWQAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAAAAAAANY3JlYXRlX2ludml0ZQAAAAAAAAIAAAAAAAAAB2ludml0ZXIAAAAAEwAAAAAAAAAIb3Bwb25lbnQAAAATAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAIZOYW1lIGByaXZhbGAgYXMgeW91ciByaXZhbC4gT25jZSB0aGV5IG5hbWUgeW91IGJhY2ssIGdhbWVzIGJldHdlZW4geW91IHBheSBhCnJpdmFscnkgYm9udXMgYW5kIGNvdW50IHRvd2FyZHMgeW91ciBoZWFkLXRvLWhlYWQgcmVjb3JkLgAAAAAADWRlY2xhcmVfcml2YWwAAAAAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABXJpdmFsAAAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAADZUYWtlIGEgbmFtZSBhd2F5IGZyb20gd2hvZXZlciBob2xkcyBpdCwgZS5nLiBmb3IgYWJ1c2UAAAAAAA1mb3JjZV9yZWxlYXNlAAAAAAAAAQAAAAAAAAAIdXNlcm5hbWUAAAAQAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAGRBZGRyZXNzZXMgaG9sZGluZyB1cCB0byA1MCB1c2VybmFtZXMgaW4gb25lIGNhbGwsIGluIHRoZSBvcmRlciBnaXZlbjsgYE5vbmVgCmZvciBhIG5hbWUgbm9ib2R5IGhvbGRzAAAADWdldF9hZGRyZXNzZXMAAAAAAAABAAAAAAAAAAl1c2VybmFtZXMAAAAAAAPqAAAAEAAAAAEAAAPqAAAD6AAAABM=",
+        "AAAAAAAAAAAAAAANZ2V0X2NoYWxsZW5nZQAAAAAAAAEAAAAAAAAADGNoYWxsZW5nZV9pZAAAAAQAAAABAAAD6QAAB9AAAAAJQ2hhbGxlbmdlAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAANZ2V0X21vZGVyYXRvcgAAAAAAAAAAAAABAAAD6AAAABM=",
+        "AAAAAAAAAAAAAAANZ2V0X3RlYW1fZ2FtZQAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAIVGVhbUdhbWUAAAAD",
+        "AAAAAAAAAGJVc2VybmFtZXMgb2YgdXAgdG8gNTAgYWRkcmVzc2VzIGluIG9uZSBjYWxsLCBpbiB0aGUgb3JkZXIgZ2l2ZW47IGBOb25lYCBmb3IKYW4gYWRkcmVzcyB3aXRob3V0IG9uZQAAAAAADWdldF91c2VybmFtZXMAAAAAAAABAAAAAAAAAAlhZGRyZXNzZXMAAAAAAAPqAAAAEwAAAAEAAAPqAAAD6AAAABA=",
+        "AAAAAAAAAGNQcm9wb3NlIHBhdXNpbmcgYSBjb3JyZXNwb25kZW5jZSBnYW1lIGZvciBgZHVyYXRpb25fc2Vjc2A7IHRoZSBvcHBvbmVudAphY2NlcHRzIHdpdGggYGFjY2VwdF9wYXVzZWAAAAAADXByb3Bvc2VfcGF1c2UAAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAADWR1cmF0aW9uX3NlY3MAAAAAAAAGAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAAAAAAANcmVqZWN0X2ludml0ZQAAAAAAAAIAAAAAAAAACHJlamVjdGVyAAAAEwAAAAAAAAAIbWF0Y2hfaWQAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAACNUYWtlIGBmcmllbmRgIG9mZiB5b3VyIGZyaWVuZHMgbGlzdAAAAAANcmVtb3ZlX2ZyaWVuZAAAAAAAAAIAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAGZnJpZW5kAAAAAAATAAAAAA==",
+        "AAAAAAAAAAAAAAANcmVtb3ZlX3BvbGljeQAAAAAAAAIAAAAAAAAAD2NvbnRleHRfcnVsZV9pZAAAAAAEAAAAAAAAAAlwb2xpY3lfaWQAAAAAAAAEAAAAAA==",
+        "AAAAAAAAAAAAAAANcmVtb3ZlX3NpZ25lcgAAAAAAAAIAAAAAAAAAD2NvbnRleHRfcnVsZV9pZAAAAAAEAAAAAAAAAAlzaWduZXJfaWQAAAAAAAAEAAAAAA==",
+        "AAAAAAAAADhXaG8gc2V0cyBzaGFkb3cgZmxhZ3M7IGBOb25lYCBoYW5kcyBpdCBiYWNrIHRvIHRoZSBhZG1pbgAAAA1zZXRfbW9kZXJhdG9yAAAAAAAAAQAAAAAAAAAJbW9kZXJhdG9yAAAAAAAD6AAAABMAAAAA",
+        "AAAAAAAAAENTZW5kIGFsbCBmZWVzIGNvbGxlY3RlZCBpbiBgdG9rZW5gIHRvIGB0b2A7IHJldHVybnMgdGhlIGFtb3VudCBzZW50AAAAAA13aXRoZHJhd19mZWVzAAAAAAAAAgAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAEZBY2NlcHQgYSByZW1hdGNoIG9mZmVyIGFuZCBzdGFydCB0aGUgbmV3IGdhbWUuIFJldHVybnMgaXRzIHNlc3Npb24gaWQuAAAAAAAOYWNjZXB0X3JlbWF0Y2gAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAIJDb2xsZWN0IHRoZSB0b2tlbiB3aW5uaW5ncywgcmVmdW5kcyBhbmQgZm9yZmVpdGVkIGRlcG9zaXRzIG93ZWQgdG8gYHBsYXllcmAKZnJvbSBhIHNldHRsZWQgZ2FtZTsgcmV0dXJucyB0aGUgYW1vdW50IHBhaWQgcGVyIHRva2VuAAAAAAAOY2xhaW1fd2lubmluZ3MAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAD7AAAABMAAAALAAAAAw==",
+        "AAAAAAAAAAAAAAAOZ2V0X2dhbWVfcGF1c2UAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAH0AAAAAlHYW1lUGF1c2UAAAA=",
+        "AAAAAAAAAAAAAAAOZ2V0X21hdGNoX25vdGUAAAAAAAIAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPoAAAD7gAAACA=",
+        "AAAAAAAAAAAAAAAOZ2V0X3BpY2tfc3RhdHMAAAAAAAEAAAAAAAAACXNwZWN0YXRvcgAAAAAAABMAAAABAAAH0AAAAAlQaWNrU3RhdHMAAAA=",
+        "AAAAAAAAAAAAAAAOZ2V0X3ByZWRpY3Rpb24AAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAACXByZWRpY3RvcgAAAAAAABMAAAABAAAD6AAAB9AAAAAKUHJlZGljdGlvbgAA",
+        "AAAAAAAAAAAAAAAOZ2V0X3Byb21vX2NvZGUAAAAAAAEAAAAAAAAABGNvZGUAAAARAAAAAQAAA+gAAAfQAAAACVByb21vQ29kZQAAAA==",
+        "AAAAAAAAAAAAAAAOZ2V0X3RvdXJuYW1lbnQAAAAAAAEAAAAAAAAADXRvdXJuYW1lbnRfaWQAAAAAAAAEAAAAAQAAA+kAAAfQAAAAClRvdXJuYW1lbnQAAAAAAAM=",
+        "AAAAAAAAAD9SZXNvbHZlIHRoZSBiYXR0bGUgYWZ0ZXIgYm90aCBwbGF5ZXJzIGhhdmUgcmV2ZWFsZWQgdGhlaXIgbW92ZXMAAAAADnJlc29sdmVfYmF0dGxlAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAADEJhdHRsZVJlc3VsdAAAAAM=",
+        "AAAAAAAAACJTZW5kIGEgY2hhbGxlbmdlIHRvIGFub3RoZXIgcGxheWVyAAAAAAAOc2VuZF9jaGFsbGVuZ2UAAAAAAAMAAAAAAAAACmNoYWxsZW5nZXIAAAAAABMAAAAAAAAACmNoYWxsZW5nZWQAAAAAABMAAAAAAAAADnBvaW50c193YWdlcmVkAAAAAAALAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAElQaW4gYSBoYXNoIG9mIHlvdXIgKG9mZi1jaGFpbiwgZW5jcnlwdGVkKSBub3RlcyB0byBhIHNlc3Npb24geW91IGZpbmlzaGVkAAAAAAAADnNldF9tYXRjaF9ub3RlAAAAAAADAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAACW5vdGVfaGFzaAAAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAEhVbmRvIGEgbGluayBmcm9tIGVpdGhlciBzaWRlOyByYXRpbmdzIGFuZCB0aGUgbmFtZSBzdGF5IHdpdGggdGhlIHByaW1hcnkAAAAOdW5saW5rX2FkZHJlc3MAAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAJc2Vjb25kYXJ5AAAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAKxTZWVkIHRoZSBicmFja2V0IGZyb20gdGhlIGNoZWNrZWQtaW4gcGxheWVycyBvbmNlIHRoZSB0b3VybmFtZW50IHN0YXJ0cywgdGhlbgpyZWNvcmQgcmVzdWx0cyBhbmQgbW92ZSBwbGF5ZXJzIG9uIHRvIHRoZWlyIG5leHQgbWF0Y2hlcyBhcyBnYW1lcyByZXNvbHZlLgpBbnlvbmUgY2FuIGNhbGwgaXQuAAAAD2FkdmFuY2VfYnJhY2tldAAAAAABAAAAAAAAAA10b3VybmFtZW50X2lkAAAAAAAABAAAAAEAAAPpAAAH0AAAAApUb3VybmFtZW50AAAAAAAD",
+        "AAAAAAAAAAAAAAAPZ2V0X2NzaF9iYWxhbmNlAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAAAAAAAPZ2V0X2ZlZV93YWl2ZXJzAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAE",
+        "AAAAAAAAAAAAAAAPZ2V0X2dhbWVfY29uZmlnAAAAAAAAAAABAAAH0AAAAApHYW1lQ29uZmlnAAA=",
+        "AAAAAAAAADNXaGVuIGBleHBpcmVfZ2FtZWAgY2FuIGZpcnN0IGJlIGNhbGxlZCBvbiB0aGlzIGdhbWUAAAAAD2dldF9nYW1lX2V4cGlyeQAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAAGAAAAAw==",
+        "AAAAAAAAAAAAAAAPZ2V0X2ludml0ZV9jb2RlAAAAAAEAAAAAAAAABGNvZGUAAAAGAAAAAQAAA+gAAAfQAAAACkludml0ZUNvZGUAAA==",
+        "AAAAAAAAAClIb3cgbWFueSBwbGF5ZXJzIGFyZSB3YWl0aW5nIGF0IGVhY2ggdGllcgAAAAAAAA9nZXRfcXVldWVfc3RhdHMAAAAAAAAAAAEAAAPqAAAH0AAAAAlRdWV1ZVRpZXIAAAA=",
+        "AAAAAAAAACtTaWVnZSB3aW5zIGFuZCBsb3NzZXMgZm9yIGEgcGxheWVyLCBieSByb2xlAAAAAA9nZXRfc2llZ2Vfc3RhdHMAAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAB9AAAAAKU2llZ2VTdGF0cwAA",
+        "AAAAAAAAAD9TdGFrZXMgdGhlIHBvb2wgcHV0IHVwIGZvciBgc2Vzc2lvbl9pZGAsIHVudGlsIHRoZSBnYW1lIHNldHRsZXMAAAAAD2dldF9zcG9uc29yc2hpcAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+gAAAfQAAAAC1Nwb25zb3JzaGlwAA==",
+        "AAAAAAAAAExIaWdoZXN0LXJhdGVkIHBsYXllcnMsIGJlc3QgZmlyc3QgKGF0IG1vc3QgMTAwKSwgYWZ0ZXIgYW55IHJhdGluZyBkZWNheSBvd2VkAAAAD2dldF90b3BfcGxheWVycwAAAAABAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAB9AAAAAMUmFua2VkUGxheWVy",
+        "AAAAAAAAAR1SdW4gdGhlIGN1cnJlbnQgY2lyY3VpdCdzIHZlcmlmaWVyIG9uIGEgcHJvb2Ygd2l0aG91dCBjb21taXR0aW5nIGl0LCByZXR1cm5pbmcKdGhlIGNvbW1pdG1lbnQgaGFzaC4gTWVhbnQgdG8gYmUgc2ltdWxhdGVkIGJ5IGZyb250ZW5kcyBiZWZvcmUgc2VuZGluZyBgY29tbWl0X21vdmVzYDsgZmFpbHMKd2l0aCB0aGUgc2FtZSBgSW52YWxpZFB1YmxpY0lucHV0c2AsIGBOdWxsaWZpZXJVc2VkYCwgYEludmFsaWRQcm9vZmAgYW5kCmBWZXJpZmllclRyYXBwZWRgIGVycm9ycyBhIGNvbW1pdCB3b3VsZC4AAAAAAAAPcHJldmVyaWZ5X3Byb29mAAAAAAIAAAAAAAAADXB1YmxpY19pbnB1dHMAAAAAAAAOAAAAAAAAAAtwcm9vZl9ieXRlcwAAAAAOAAAAAQAAA+kAAAPuAAAAIAAAAAM=",
+        "AAAAAAAAAJdQcmV2aWV3IHRoZSBzdGFrZXMsIGRlcG9zaXRzLCByYWtlIGFuZCBkZWFkbGluZXMgb2Ygc2VuZGluZyBhIGNoYWxsZW5nZSBmb3IKYHBvaW50c193YWdlcmVkYCBpbiBgbW9kZWA7IGZhaWxzIGxpa2UgYHNlbmRfY2hhbGxlbmdlYCBvbiBhbiBpbnZhbGlkIHdhZ2VyAAAAAA9xdW90ZV9jaGFsbGVuZ2UAAAAAAgAAAAAAAAAOcG9pbnRzX3dhZ2VyZWQAAAAAAAsAAAAAAAAABG1vZGUAAAfQAAAACEdhbWVNb2RlAAAAAQAAA+kAAAfQAAAADkNoYWxsZW5nZVF1b3RlAAAAAAAD",
+        "AAAAAAAAAGFPZmZlciB0aGUgb3Bwb25lbnQgYSByZW1hdGNoIG9mIGZpbmlzaGVkIGdhbWUgYHNlc3Npb25faWRgIHdpdGggdGhlIHNhbWUKcGFpcmluZywgc3Rha2VzIGFuZCBtb2RlAAAAAAAAD3JlcXVlc3RfcmVtYXRjaAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAlyZXF1ZXN0ZXIAAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAADJgZW5kX3NlYXNvbmAsIHRoZW4gYHN0YXJ0X3NlYXNvbmAgZm9yIHRoZSBuZXh0IG9uZQAAAAAAD3JvbGxvdmVyX3NlYXNvbgAAAAADAAAAAAAAAAV0b3BfbgAAAAAAAAQAAAAAAAAADm5leHRfc2Vhc29uX2lkAAAAAAAEAAAAAAAAAA1lbmRfdGltZXN0YW1wAAAAAAAABgAAAAEAAAPpAAAH0AAAAA1TZWFzb25SZXN1bHRzAAAAAAAAAw==",
+        "AAAAAAAAADxVcGRhdGUgZ2FtZXBsYXkgdHVuaW5nLiBBcHBsaWVzIHRvIGdhbWVzIHN0YXJ0ZWQgYWZ0ZXJ3YXJkcy4AAAAPc2V0X2dhbWVfY29uZmlnAAAAAAEAAAAAAAAABmNvbmZpZwAAAAAH0AAAAApHYW1lQ29uZmlnAAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAIxTdGFydCBhIDJ2MiBnYW1lLiBFYWNoIHRlYW0gc3Rha2VzIGl0cyBwb2ludHMgdGhyb3VnaCBpdHMgY2FwdGFpbiwgdGhlIGZpcnN0CnBsYXllciBsaXN0ZWQsIHdobyBpcyBhbHNvIHdobyB0aGUgR2FtZSBIdWIgc2VlcyBmb3IgdGhhdCBzaWRlLgAAAA9zdGFydF90ZWFtX2dhbWUAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAFdGVhbTEAAAAAAAPqAAAAEwAAAAAAAAAFdGVhbTIAAAAAAAPqAAAAEwAAAAAAAAAMdGVhbTFfcG9pbnRzAAAACwAAAAAAAAAMdGVhbTJfcG9pbnRzAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAN9BY2NlcHQgYSBjaGFsbGVuZ2UgYW5kIHN0YXJ0IGEgZ2FtZS4gUmV0dXJucyB0aGUgc2Vzc2lvbiBpZCBhbGxvY2F0ZWQgZm9yIGl0LgpgY2hhbGxlbmdlX25vbmNlYCBpcyB0aGUgbm9uY2Ugb2YgdGhlIGNoYWxsZW5nZSB0aGUgcGxheWVyIGFncmVlZCB0bzsgaWYgdGhlCmNoYWxsZW5nZSBoYXMgY2hhbmdlZCBzaW5jZSwgYWNjZXB0YW5jZSBmYWlscyB3aXRoIGBTdGFsZUNoYWxsZW5nZWAuAAAAABBhY2NlcHRfY2hhbGxlbmdlAAAAAwAAAAAAAAAMY2hhbGxlbmdlX2lkAAAABAAAAAAAAAAKY2hhbGxlbmdlZAAAAAAAEwAAAAAAAAAPY2hhbGxlbmdlX25vbmNlAAAAAAQAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAAAAAAAQYWRkX2NvbnRleHRfcnVsZQAAAAUAAAAAAAAADGNvbnRleHRfdHlwZQAAB9AAAAAPQ29udGV4dFJ1bGVUeXBlAAAAAAAAAAAEbmFtZQAAABAAAAAAAAAAC3ZhbGlkX3VudGlsAAAAA+gAAAAEAAAAAAAAAAdzaWduZXJzAAAAA+oAAAfQAAAABlNpZ25lcgAAAAAAAAAAAAhwb2xpY2llcwAAA+wAAAATAAAAAAAAAAEAAAfQAAAAC0NvbnRleHRSdWxlAA==",
+        "AAAAAAAAAGFXaXRoZHJhdyBhIHNlbnQgY2hhbGxlbmdlIHRoYXQgaGFzbid0IGJlZW4gYWNjZXB0ZWQgeWV0LiBJdCBsZWF2ZXMgYm90aApwbGF5ZXJzJyBjaGFsbGVuZ2UgbGlzdHMuAAAAAAAAEGNhbmNlbF9jaGFsbGVuZ2UAAAACAAAAAAAAAAxjaGFsbGVuZ2VfaWQAAAAEAAAAAAAAAApjaGFsbGVuZ2VyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAJRDb2xsZWN0IGEgcHJlZGljdGlvbiBvbmNlIHRoZSBnYW1lIHNldHRsZXM6IGEgcHJvLXJhdGEgc2hhcmUgb2YgdGhlIGxvc2luZwpzaWRlIG9uIGEgY29ycmVjdCBjYWxsLCB0aGUgc3Rha2UgYmFjayBvbiBhIGRyYXc7IHJldHVybnMgdGhlIGFtb3VudCBwYWlkAAAAEGNsYWltX3ByZWRpY3Rpb24AAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAlwcmVkaWN0b3IAAAAAAAATAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAAD1Db21taXQgdGhpcyByb3VuZCdzIG1vdmUgYXMgYHNoYTI1NihhdHRhY2sgfCBkZWZlbnNlIHwgc2FsdClgAAAAAAAAEGNvbW1pdF9kdWVsX21vdmUAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAChCYWRnZXMgYHBsYXllcmAgaGFzIGVhcm5lZCwgb2xkZXN0IGZpcnN0AAAAEGdldF9hY2hpZXZlbWVudHMAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6gAAB9AAAAARRWFybmVkQWNoaWV2ZW1lbnQAAAA=",
+        "AAAAAAAAAElCb251cyBwb2ludHMgYHBsYXllcmAgaGFzIGVhcm5lZCBoZXJlIGFuZCBub3QgeWV0IHNldHRsZWQgdG8gdGhlIEdhbWUgSHViAAAAAAAAEGdldF9ib251c19wb2ludHMAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAACw==",
+        "AAAAAAAAAAAAAAAQZ2V0X2NvbnRleHRfcnVsZQAAAAEAAAAAAAAAD2NvbnRleHRfcnVsZV9pZAAAAAAEAAAAAQAAB9AAAAALQ29udGV4dFJ1bGUA",
+        "AAAAAAAAAAAAAAAQZ2V0X2ZlZV9zY2hlZHVsZQAAAAAAAAABAAAD6gAAB9AAAAAHRmVlVGllcgA=",
+        "AAAAAAAAAEZHYW1lIGNvdW50cywgYXR0YWNrIHVzYWdlIGFuZCBhdmVyYWdlIGJhdHRsZSBsZW5ndGggYWNyb3NzIGFsbCBwbGF5ZXJzAAAAAAAQZ2V0X2dsb2JhbF9zdGF0cwAAAAAAAAABAAAH0AAAAAtHbG9iYWxTdGF0cwA=",
+        "AAAAAAAAADVgcGxheWVyYCdzIHJlY29yZCBpbiByaXZhbCBtYXRjaGVzIGFnYWluc3QgYG9wcG9uZW50YAAAAAAAABBnZXRfaGVhZF90b19oZWFkAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAhvcHBvbmVudAAAABMAAAABAAAH0AAAAApIZWFkVG9IZWFkAAA=",
+        "AAAAAAAAAE9SYXRlZCByZWNvcmQgb2YgYHBsYXllcmA7IHBsYXllcnMgd2hvIGhhdmVuJ3QgcGxheWVkIGEgcmF0ZWQgZ2FtZSBzdGFydCBvbiAxNTAwAAAAABBnZXRfcGxheWVyX3N0YXRzAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAB9AAAAALUGxheWVyU3RhdHMA",
+        "AAAAAAAAAAAAAAAQZ2V0X3JhaXNlX2NvbmZpZwAAAAAAAAABAAAD6AAAB9AAAAALUmFpc2VDb25maWcA",
+        "AAAAAAAAAExgcGxheWVyYCdzIHJlY29yZCBpbiBhIHNlYXNvbjsgdGhlIHN0YXJ0aW5nIHJlY29yZCBpZiB0aGV5IGRpZG4ndCBwbGF5IGluIGl0AAAAEGdldF9zZWFzb25fc3RhdHMAAAACAAAAAAAAAAlzZWFzb25faWQAAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAH0AAAAAtQbGF5ZXJTdGF0cwA=",
+        "AAAAAAAAADRTcG9uc29yIHBvb2wgYmFsYW5jZSBub3Qgc2V0IGFzaWRlIGZvciBnYW1lcyBpbiBwbGF5AAAAEGdldF9zcG9uc29yX3Bvb2wAAAAAAAAAAQAAAAs=",
+        "AAAAAAAAAAAAAAAQZ2V0X3Nwb25zb3JfdGllcgAAAAAAAAABAAAD6AAAB9AAAAALU3BvbnNvclRpZXIA",
+        "AAAAAAAAAAAAAAAQZ2V0X3dhZ2VyX2xpbWl0cwAAAAAAAAABAAAH0AAAAAtXYWdlckxpbWl0cwA=",
+        "AAAAAAAAAIRTdGFrZSBgYW1vdW50YCBvZiB0aGUgcHJlZGljdGlvbiB0b2tlbiBvbiBgYmFja2VkX3BsYXllcmAgd2lubmluZy4gT3BlbiB0bwphbnlvbmUgYnV0IHRoZSB0d28gcGxheWVycywgdW50aWwgdGhlIGZpcnN0IGNvbW1pdCBsYW5kcy4AAAAQcGxhY2VfcHJlZGljdGlvbgAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAACXByZWRpY3RvcgAAAAAAABMAAAAAAAAADWJhY2tlZF9wbGF5ZXIAAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAC5GcmVlIHRoZSBjYWxsZXIncyB1c2VybmFtZSBmb3IgYW55b25lIHRvIGNsYWltAAAAAAAQcmVsZWFzZV91c2VybmFtZQAAAAEAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAD5SZXZlYWwgdGhpcyByb3VuZCdzIG1vdmU7IHRoZSBzZWNvbmQgcmV2ZWFsIHJlc29sdmVzIHRoZSByb3VuZAAAAAAAEHJldmVhbF9kdWVsX21vdmUAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABmFjdGlvbgAAAAAH0AAAAARNb3ZlAAAAAAAAAARzYWx0AAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAHlTZXQgdGhlIHJha2UgYnJlYWtwb2ludHMsIG9yZGVyZWQgYnkgYG1pbl93YWdlcmAuIEFuIGVtcHR5IHNjaGVkdWxlIGNoYXJnZXMKbm90aGluZy4gQXBwbGllcyB0byBnYW1lcyBzdGFydGVkIGFmdGVyd2FyZHMuAAAAAAAAEHNldF9mZWVfc2NoZWR1bGUAAAABAAAAAAAAAAhzY2hlZHVsZQAAA+oAAAfQAAAAB0ZlZVRpZXIAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAHdBbGxvdyBibGluZCByYWlzZXMgb2YgdXAgdG8gYG1heF9hbW91bnRgIG9mIGB0b2tlbmAuIEEgbWF4IG9mIDAgdHVybnMgcmFpc2luZwpvZmYuIEFwcGxpZXMgdG8gZ2FtZXMgc3RhcnRlZCBhZnRlcndhcmRzLgAAAAAQc2V0X3JhaXNlX2NvbmZpZwAAAAIAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAKbWF4X2Ftb3VudAAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAEZTZXQgdGhlIHNwb25zb3JlZCBmcmVlLXRvLXBsYXkgdGllcjsgYE5vbmVgIHR1cm5zIGl0IG9mZiBmb3IgbmV3IGdhbWVzAAAAAAAQc2V0X3Nwb25zb3JfdGllcgAAAAEAAAAAAAAABHRpZXIAAAPoAAAH0AAAAAtTcG9uc29yVGllcgAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAExTZXQgdGhlIGluY2x1c2l2ZSB3YWdlciBib3VuZHMgY2hlY2tlZCB3aGVuIGdhbWVzIGFuZCBjaGFsbGVuZ2VzIGFyZSBjcmVhdGVkAAAAEHNldF93YWdlcl9saW1pdHMAAAACAAAAAAAAAANtaW4AAAAACwAAAAAAAAADbWF4AAAAAAsAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAExDb21taXQgb25lIHRlYW0gbWVtYmVyJ3MgbW92ZSBzZXF1ZW5jZSB3aXRoIGEgWksgcHJvb2YsIGFzIGluIGBjb21taXRfbW92ZXNgAAAAEWNvbW1pdF90ZWFtX21vdmVzAAAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAA1wdWJsaWNfaW5wdXRzAAAAAAAADgAAAAAAAAALcHJvb2ZfYnl0ZXMAAAAADgAAAAEAAAPpAAAH0AAAAA1Db21taXRSZWNlaXB0AAAAAAAAAw==",
+        "AAAAAAAAAEJDcmVhdGUgKG9yIHJlcGxhY2UpIGEgcHJvbW8gY29kZSByZWRlZW1hYmxlIHVwIHRvIGBtYXhfdXNlc2AgdGltZXMAAAAAABFjcmVhdGVfcHJvbW9fY29kZQAAAAAAAAQAAAAAAAAABGNvZGUAAAARAAAAAAAAAAZyZXdhcmQAAAAAB9AAAAALUHJvbW9SZXdhcmQAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAACG1heF91c2VzAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAALdPcGVuIGEgc2luZ2xlLSBvciBkb3VibGUtZWxpbWluYXRpb24gdG91cm5hbWVudCBmb3IgYG1heF9wbGF5ZXJzYCAoYSBwb3dlciBvZgp0d28sIHVwIHRvIDMyLCBhbmQgYXQgbGVhc3QgNCBmb3IgZG91YmxlIGVsaW1pbmF0aW9uKTsgZWFjaCBtYXRjaCBzdGFrZXMKYGVudHJ5X3BvaW50c2AgZnJvbSBib3RoIHBsYXllcnMAAAAAEWNyZWF0ZV90b3VybmFtZW50AAAAAAAABQAAAAAAAAAJb3JnYW5pemVyAAAAAAAAEwAAAAAAAAALbWF4X3BsYXllcnMAAAAABAAAAAAAAAAMZW50cnlfcG9pbnRzAAAACwAAAAAAAAAVcmVnaXN0cmF0aW9uX2RlYWRsaW5lAAAAAAAABgAAAAAAAAAGZm9ybWF0AAAAAAfQAAAADUJyYWNrZXRGb3JtYXQAAAAAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAEhUdXJuIGRvd24gYSByZWNlaXZlZCBjaGFsbGVuZ2UuIEl0IGxlYXZlcyBib3RoIHBsYXllcnMnIGNoYWxsZW5nZSBsaXN0cy4AAAARZGVjbGluZV9jaGFsbGVuZ2UAAAAAAAACAAAAAAAAAAxjaGFsbGVuZ2VfaWQAAAAEAAAAAAAAAApjaGFsbGVuZ2VkAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAL1TZXR0bGUgYSBnYW1lIG9yIGR1ZWwgd2hvc2UgZGVhZGxpbmUgaGFzIHBhc3NlZCwgd2l0aCB0aGUgc2FtZSBvdXRjb21lIHRoZQpwbGF5ZXJzJyB0aW1lb3V0IGNsYWltcyB3b3VsZCBnaXZlLiBOZWVkcyBubyBzaWduYXR1cmUsIHNvIGtlZXBlcnMgYW5kIG90aGVyCm9ic2VydmVycyBjYW4gZW5kIGFiYW5kb25lZCBzZXNzaW9ucy4AAAAAAAARZW5mb3JjZV9kZWFkbGluZXMAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAADEJhdHRsZVJlc3VsdAAAAAM=",
+        "AAAAAAAAAI9IYW5kIGEgcmVjZWl2ZWQgY2hhbGxlbmdlIHRvIHNvbWVvbmUgZWxzZSAoZS5nLiBhIGNsYW5tYXRlKSBvbiB0aGUgc2FtZSB0ZXJtcy4KTmVlZHMgdGhlIGNoYWxsZW5nZWQgcGxheWVyJ3MgYXV0aCBhbmQgd29ya3Mgb25jZSBwZXIgY2hhbGxlbmdlLgAAAAARZm9yd2FyZF9jaGFsbGVuZ2UAAAAAAAACAAAAAAAAAAxjaGFsbGVuZ2VfaWQAAAAEAAAAAAAAAAJ0bwAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAE9QYXkgYGFtb3VudGAgb2YgdGhlIHRpZXIncyB0b2tlbiBpbnRvIHRoZSBzcG9uc29yIHBvb2w7IHJldHVybnMgdGhlIG5ldyBiYWxhbmNlAAAAABFmdW5kX3Nwb25zb3JfcG9vbAAAAAAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAAAAAAARZ2V0X2NhcHRhaW5fY2xhc3MAAAAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAH0AAAAAxDYXB0YWluQ2xhc3M=",
+        "AAAAAAAAAJJgcGxheWVyYCdzIHJldmVhbGVkIG1vdmVzLCBmb3IgdGhlaXIgY29hY2gsIG9uY2UgdGhlIGJhdHRsZSBpcyByZXNvbHZlZC4gVGhlCnNhbWUgbW92ZXMgYXJlIHB1YmxpYyB0aHJvdWdoIGBnZXRfZ2FtZWAgYXMgc29vbiBhcyB0aGV5J3JlIHJldmVhbGVkLgAAAAAAEWdldF9jb2FjaGVkX21vdmVzAAAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAFY29hY2gAAAAAAAATAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAA+oAAAfQAAAABE1vdmUAAAAD",
+        "AAAAAAAAADVHZXQgZGV0YWlsZWQgZ2FtZSBwbGF5YmFjayB3aXRoIGFsbCBtb3ZlcyBhbmQgcmVzdWx0cwAAAAAAABFnZXRfZ2FtZV9wbGF5YmFjawAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAMR2FtZVBsYXliYWNrAAAAAw==",
+        "AAAAAAAAAAAAAAARZ2V0X2dhbWVfcHJvcG9zYWwAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+gAAAfQAAAADEdhbWVQcm9wb3NhbA==",
+        "AAAAAAAAAGNgcGxheWVyYCdzIG1vc3QgcmVjZW50IHJlc29sdmVkIGJhdHRsZXMgKHVwIHRvIDIwKSwgbmV3ZXN0IGZpcnN0LiBVbmxpa2UKZ2FtZXMsIHRoZXNlIG5ldmVyIGV4cGlyZS4AAAAAEWdldF9tYXRjaF9oaXN0b3J5AAAAAAAAAwAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZvZmZzZXQAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAH0AAAAAxBcmNoaXZlZEdhbWU=",
+        "AAAAAAAAAAAAAAARZ2V0X3ByYWN0aWNlX2dhbWUAAAAAAAABAAAAAAAAAAtwcmFjdGljZV9pZAAAAAAEAAAAAQAAA+gAAAfQAAAADFByYWN0aWNlR2FtZQ==",
+        "AAAAAAAAAElDaGFuZ2VzIHRvIGBwbGF5ZXJgJ3MgZ2FtZXMsIGNoYWxsZW5nZXMsIGR1ZWxzIGFuZCB0ZWFtIGdhbWVzIHNpbmNlIGBzZXFgAAAAAAAAEWdldF91cGRhdGVzX3NpbmNlAAAAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAANzZXEAAAAABgAAAAEAAAfQAAAADVBsYXllclVwZGF0ZXMAAAA=",
+        "AAAAAAAAAFNOYW1lIHRoZSBwbGF5ZXIgd2hvIHJlZmVycmVkIGBuZXdfcGxheWVyYDsgb25jZSBwZXIgcGxheWVyLCBiZWZvcmUgdGhlaXIKZmlyc3QgZ2FtZQAAAAARcmVnaXN0ZXJfcmVmZXJyYWwAAAAAAAACAAAAAAAAAApuZXdfcGxheWVyAAAAAAATAAAAAAAAAAhyZWZlcnJlcgAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAN9SZWdpc3RlciB0aGUgdmVyaWZpZXIgY29udHJhY3QgZm9yIGNpcmN1aXQgYHZlcnNpb25gLCBvciByZXBsYWNlIGl0cyBhZGRyZXNzLgpBIHZlcnNpb24gYWJvdmUgdGhlIGN1cnJlbnQgb25lIGJlY29tZXMgY3VycmVudDogZ2FtZXMgY3JlYXRlZCBmcm9tIHRoZW4gb24KYXJlIHBpbm5lZCB0byBpdCwgd2hpbGUgZ2FtZXMgYWxyZWFkeSBydW5uaW5nIGtlZXAgdGhlaXIgb3duIHZlcnNpb24uAAAAABFyZWdpc3Rlcl92ZXJpZmllcgAAAAAAAAIAAAAAAAAAB3ZlcnNpb24AAAAABAAAAAAAAAAIdmVyaWZpZXIAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAADRSZXZlYWwgb25lIHRlYW0gbWVtYmVyJ3MgbW92ZXMsIGFzIGluIGByZXZlYWxfbW92ZXNgAAAAEXJldmVhbF90ZWFtX21vdmVzAAAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAA1wdWJsaWNfaW5wdXRzAAAAAAAADgAAAAAAAAAFbW92ZXMAAAAAAAPqAAAH0AAAAARNb3ZlAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAElQaWNrIHRoZSBjYXB0YWluIGNsYXNzIHRoZSBjYWxsZXIgYmF0dGxlcyBhcyBpbiBnYW1lcyBzdGFydGVkIGZyb20gbm93IG9uAAAAAAAAEXNldF9jYXB0YWluX2NsYXNzAAAAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAVjbGFzcwAAAAAAB9AAAAAMQ2FwdGFpbkNsYXNzAAAAAA==",
+        "AAAAAAAAAC5Nb3ZlIGBmcm9tYCdzIHVzZXJuYW1lIHRvIGB0b2A7IGJvdGggbXVzdCBzaWduAAAAAAARdHJhbnNmZXJfdXNlcm5hbWUAAAAAAAACAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAADJSZWNvbXB1dGUgYSBzdG9yZWQgc2VlZCBmcm9tIGl0cyBkb21haW4gYW5kIGlucHV0cwAAAAAAEXZlcmlmeV9yYW5kb21uZXNzAAAAAAAAAQAAAAAAAAAFYXVkaXQAAAAAAAfQAAAAD1JhbmRvbW5lc3NBdWRpdAAAAAABAAAAAQ==",
+        "AAAAAAAAAEFSZWRlZW0gYW4gaW52aXRlIGNvZGUgYW5kIHN0YXJ0IHRoZSBnYW1lLiBSZXR1cm5zIHRoZSBzZXNzaW9uIGlkLgAAAAAAABJhY2NlcHRfaW52aXRlX2NvZGUAAAAAAAIAAAAAAAAABGNvZGUAAAAGAAAAAAAAAAhhY2NlcHRvcgAAABMAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAEpFbmQgYSBkdWVsIHdob3NlIGN1cnJlbnQgcGhhc2UgZGVhZGxpbmUgcGFzc2VkIHdpdGhvdXQgdGhlIG9wcG9uZW50IGFjdGluZwAAAAAAEmNsYWltX2R1ZWxfdGltZW91dAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAJdDcmVhdGUgYSBvbmUtdGltZSBjb2RlIHRoYXQgbGV0cyBhbnlvbmUgc3RhcnQgYSBnYW1lIGFnYWluc3QgYGNyZWF0b3JgLCB3aXRob3V0Cmtub3dpbmcgdGhlaXIgYWRkcmVzcyBvciB1c2VybmFtZS4gVmFsaWQgZm9yIGB0dGxfc2Vjc2AgKHVwIHRvIDcgZGF5cykuAAAAABJjcmVhdGVfaW52aXRlX2NvZGUAAAAAAAMAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAOcG9pbnRzX3dhZ2VyZWQAAAAAAAsAAAAAAAAACHR0bF9zZWNzAAAABgAAAAEAAAPpAAAABgAAAAM=",
+        "AAAAAAAAALpTdG9wIGFjY2VwdGluZyBwcm9vZnMgZm9yIGNpcmN1aXQgYHZlcnNpb25gLCBlLmcuIGFmdGVyIGEgY2lyY3VpdCBidWcuIEdhbWVzCnBpbm5lZCB0byBpdCBjYW4gbm8gbG9uZ2VyIGNvbW1pdCBhbmQgaGF2ZSB0byBiZSBzZXR0bGVkIGJ5IHRpbWVvdXQuIFRoZQpjdXJyZW50IHZlcnNpb24gY2FuJ3QgYmUgZGVwcmVjYXRlZC4AAAAAABJkZXByZWNhdGVfdmVyaWZpZXIAAAAAAAEAAAAAAAAAB3ZlcnNpb24AAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAAAAAAASZ2V0X2NvbGxlY3RlZF9mZWVzAAAAAAABAAAAAAAAAAV0b2tlbgAAAAAAABMAAAABAAAACw==",
+        "AAAAAAAAAAAAAAASZ2V0X2NvbW1pdF9kZXBvc2l0AAAAAAAAAAAAAQAAA+gAAAfQAAAADUNvbW1pdERlcG9zaXQAAAA=",
+        "AAAAAAAAAFdSZWNlaXB0IGZvciBgcGxheWVyYCdzIGNvbW1pdCBpbiBhIGdhbWUgb3IgdGVhbSBnYW1lLCBzaG93aW5nIHdoaWNoIGxlZGdlcgppdCBsYW5kZWQgaW4AAAAAEmdldF9jb21taXRfcmVjZWlwdAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+gAAAfQAAAADUNvbW1pdFJlY2VpcHQAAAA=",
+        "AAAAAAAAAFFgcGxheWVyYCdzIGZyaWVuZHMgd2l0aCB0aGVpciBsb29raW5nLWZvci1nYW1lIGZsYWdzLCBmb3IgYSAicmVhZHkgdG8gZHVlbCIgcGFuZWwAAAAAAAASZ2V0X2ZyaWVuZHNfc3RhdHVzAAAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6gAAB9AAAAAMRnJpZW5kU3RhdHVz",
+        "AAAAAAAAAE5MYXN0IGJhdHRsZSBvciB1c2VybmFtZSBjbGFpbSwgYXMgdXNlZCBmb3IgdGhlIG5hbWUgZXhwaXJ5OyAwIGlmIG5vbmUgcmVjb3JkZWQAAAAAABJnZXRfbGFzdF9hY3RpdmVfYXQAAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAG",
+        "AAAAAAAAAAAAAAASZ2V0X21heF9nYW1lX3BhdXNlAAAAAAAAAAAAAQAAAAY=",
+        "AAAAAAAAAAAAAAASZ2V0X3BsYXllcl9tYXRjaGVzAAAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6gAAAAQ=",
+        "AAAAAAAAAAAAAAASZ2V0X3ByYWN0aWNlX3N0YXRzAAAAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmRpZmZpY3VsdHkAAAAAB9AAAAASUHJhY3RpY2VEaWZmaWN1bHR5AAAAAAABAAAH0AAAAA1QcmFjdGljZVN0YXRzAAAA",
+        "AAAAAAAAAAAAAAASZ2V0X3JlZmVycmFsX2JvbnVzAAAAAAAAAAAAAQAAAAs=",
+        "AAAAAAAAAAAAAAASZ2V0X3JlZmVycmFsX3N0YXRzAAAAAAABAAAAAAAAAAhyZWZlcnJlcgAAABMAAAABAAAH0AAAAA1SZWZlcnJhbFN0YXRzAAAA",
+        "AAAAAAAAAIJUaGUgZ3J1ZGdlIHNlcmllcyBgYWAgYW5kIGBiYCBhcmUgaW46IHRoZWlyIGdhbWVzIGFnYWluc3QgZWFjaCBvdGhlciBzaW5jZQplaXRoZXIgbGFzdCBwbGF5ZWQgc29tZW9uZSBlbHNlLCBzY29yZWQgZnJvbSBgYWAncyBzaWRlAAAAAAASZ2V0X3JpdmFscnlfc2VyaWVzAAAAAAACAAAAAAAAAAFhAAAAAAAAEwAAAAAAAAABYgAAAAAAABMAAAABAAAD6AAAB9AAAAANUml2YWxyeVNlcmllcwAAAA==",
+        "AAAAAAAAAAAAAAASZ2V0X3NlYXNvbl9yZXN1bHRzAAAAAAABAAAAAAAAAAlzZWFzb25faWQAAAAAAAAEAAAAAQAAA+gAAAfQAAAADVNlYXNvblJlc3VsdHMAAAA=",
+        "AAAAAAAAAAAAAAASZ2V0X3NlYXNvbl9yZXdhcmRzAAAAAAAAAAAAAQAAA+gAAAfQAAAADVNlYXNvblJld2FyZHMAAAA=",
+        "AAAAAAAAAAAAAAASZ2V0X3Nlc3Npb25fc2lnbmVyAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6AAAABM=",
+        "AAAAAAAAAAAAAAASZ2V0X3NldHRsZW1lbnRfa2V5AAAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6AAAA+4AAAAg",
+        "AAAAAAAAAAAAAAASZ2V0X3Rva2VuX2NvbnRyYWN0AAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAATFgcmV2ZWFsX21vdmVzYCwgdGhlbiwgaWYgYm90aCBwbGF5ZXJzIGhhdmUgbm93IHJldmVhbGVkLCBgcmVzb2x2ZV9iYXR0bGVgIGluIHRoZQpzYW1lIGludm9jYXRpb24sIHNvIHRoZSBsYXN0IHBsYXllciBmaW5pc2hlcyB0aGUgZ2FtZSBpbiBvbmUgdHJhbnNhY3Rpb24uClJldHVybnMgdGhlIHJlc3VsdCwgb3IgdGhlIHN0aWxsLXBlbmRpbmcgb25lIChyZWFzb24gYFBlbmRpbmdgKSB3aGVuIHRoZQpvcHBvbmVudCBoYXNuJ3QgcmV2ZWFsZWQsIHJlc29sdXRpb24gaXMgcGF1c2VkIG9yIGEgc2lnbmVkIHJlc3VsdCBpcyBwZW5kaW5nLgAAAAAAABJyZXZlYWxfYW5kX3Jlc29sdmUAAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAANcHVibGljX2lucHV0cwAAAAAAAA4AAAAAAAAABW1vdmVzAAAAAAAD6gAAB9AAAAAETW92ZQAAAAEAAAPpAAAH0AAAAAxCYXR0bGVSZXN1bHQAAAAD",
+        "AAAAAAAAAJRSZXF1aXJlIGEgcmVmdW5kYWJsZSBgYW1vdW50YCBvZiBgdG9rZW5gIGZyb20gZWFjaCBwbGF5ZXIgYXQgY29tbWl0IHRpbWUuCkFuIGFtb3VudCBvZiAwIHR1cm5zIHRoZSBkZXBvc2l0IG9mZi4gQXBwbGllcyB0byBnYW1lcyBzdGFydGVkIGFmdGVyd2FyZHMuAAAAEnNldF9jb21taXRfZGVwb3NpdAAAAAAAAgAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAEVNb3N0IHRpbWUgYSBjb3JyZXNwb25kZW5jZSBnYW1lIGNhbiBzcGVuZCBwYXVzZWQsIG92ZXIgYWxsIGl0cyBwYXVzZXMAAAAAAAASc2V0X21heF9nYW1lX3BhdXNlAAAAAAABAAAAAAAAAAhtYXhfc2VjcwAAAAYAAAAA",
+        "AAAAAAAAAE5DU0ggY3JlZGl0ZWQgdG8gYSByZWZlcnJlciB3aGVuIGEgcGxheWVyIHRoZXkgcmVmZXJyZWQgZmluaXNoZXMgYSBmaXJzdCBiYXR0bGUAAAAAABJzZXRfcmVmZXJyYWxfYm9udXMAAAAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAAGpSZXdhcmRzIGZvciB0aGUgdG9wIGZpbmlzaGVycyBvZiBzZWFzb25zIGVuZGVkIGZyb20gbm93IG9uLCBiZXN0IHJhbmsgZmlyc3QuCkFuIGVtcHR5IGxpc3QgdHVybnMgdGhlbSBvZmYuAAAAAAASc2V0X3NlYXNvbl9yZXdhcmRzAAAAAAACAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAAB2Ftb3VudHMAAAAD6gAAAAsAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAEFSZWdpc3RlciB0aGUgZWQyNTUxOSBrZXkgYHBsYXllcmAgY28tc2lnbnMgb2ZmLWNoYWluIHJlc3VsdHMgd2l0aAAAAAAAABJzZXRfc2V0dGxlbWVudF9rZXkAAAAAAAIAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAKcHVibGljX2tleQAAAAAD7gAAACAAAAAA",
+        "AAAAAAAAAAAAAAASc2V0X3Rva2VuX2NvbnRyYWN0AAAAAAABAAAAAAAAAA50b2tlbl9jb250cmFjdAAAAAAAEwAAAAA=",
+        "AAAAAAAAAMhDb21taXQgYHNoYTI1Nihtb3ZlcyB8fCBzYWx0KWAgb24gYSBoYXNoLW1vZGUgZ2FtZSwgd2hlcmUgZWFjaCBtb3ZlIGlzIGl0cwphdHRhY2sgYW5kIGRlZmVuc2UgYnl0ZSBhbmQgdGhlIHNhbHQgaXMgMzIgYnl0ZXMuIFRha2VzIHRoZSBwbGFjZSBvZgpgY29tbWl0X21vdmVzYCB3aGVuIHRoZSBnYW1lIGhhcyBubyBaSyB2ZXJpZmllciB0byBjYWxsLgAAABNjb21taXRfbW92ZXNfaGFzaGVkAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAABAAAD6QAAB9AAAAANQ29tbWl0UmVjZWlwdAAAAAAAAAM=",
+        "AAAAAAAAAJtHYW1lcyBhbmQgZHVlbHMgd2FpdGluZyBvbiBgcGxheWVyYCB0byBjb21taXQsIHJldmVhbCBvciBhbnN3ZXIgYSByYWlzZSwgYW1vbmcKdXAgdG8gYGxpbWl0YCBvZiB0aGVpciBsaXZlIHNlc3Npb25zIGZyb20gYG9mZnNldGAgKGEgcGxheWVyIGhhcyBhdCBtb3N0IDMwKQAAAAATZ2V0X2F3YWl0aW5nX2FjdGlvbgAAAAADAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABm9mZnNldAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAAE",
+        "AAAAAAAAACtUaGUgY2lyY3VpdCB2ZXJzaW9uIG5ldyBnYW1lcyBhcmUgcGlubmVkIHRvAAAAABNnZXRfY2lyY3VpdF92ZXJzaW9uAAAAAAAAAAABAAAABA==",
+        "AAAAAAAAAJ1XaGF0IGBwbGF5ZXJgIGhhcyByaWRpbmcgb24gYSBnYW1lOiB0aGVpciBHYW1lIEh1YiBzdGFrZSwgcGx1cyB0aGUgcmFpc2Ugb25jZQpjYWxsZWQuIFJldmVhbHMgd2FpdCBmb3IgYSBwZW5kaW5nIHJhaXNlLCBzbyB0aGlzIGlzIGZpbmFsIG9uY2UgYW55b25lIHJldmVhbHMuAAAAAAAAE2dldF9lZmZlY3RpdmVfd2FnZXIAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAAFJQYWdlIHRocm91Z2ggb3BlbiBjaGFsbGVuZ2VzIHdpdGggYSB3YWdlciBpbiBgbWluX3dhZ2VyLi49bWF4X3dhZ2VyYCwgb2xkZXN0CmZpcnN0AAAAAAATZ2V0X29wZW5fY2hhbGxlbmdlcwAAAAAEAAAAAAAAAAZvZmZzZXQAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAAAAAAJbWluX3dhZ2VyAAAAAAAACwAAAAAAAAAJbWF4X3dhZ2VyAAAAAAAACwAAAAEAAAPqAAAH0AAAAA1PcGVuQ2hhbGxlbmdlAAAA",
+        "AAAAAAAAAAAAAAATZ2V0X3ByZWRpY3Rpb25fcG9vbAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+gAAAfQAAAADlByZWRpY3Rpb25Qb29sAAA=",
+        "AAAAAAAAAAAAAAATZ2V0X3VzZXJuYW1lX2V4cGlyeQAAAAAAAAAAAQAAAAY=",
+        "AAAAAAAAAGlQb3N0IGEgY2hhbGxlbmdlIGFueW9uZSBjYW4gYWNjZXB0IHRvIHRoZSBvcGVuIGJvYXJkICh1cCB0byAzIGxpdmUgcGVyCnBsYXllcikuIFJldHVybnMgaXRzIGNoYWxsZW5nZSBpZC4AAAAAAAATcG9zdF9vcGVuX2NoYWxsZW5nZQAAAAACAAAAAAAAAApjaGFsbGVuZ2VyAAAAAAATAAAAAAAAAA5wb2ludHNfd2FnZXJlZAAAAAAACwAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAAAAAAATcmVtb3ZlX2NvbnRleHRfcnVsZQAAAAABAAAAAAAAAA9jb250ZXh0X3J1bGVfaWQAAAAABAAAAAA=",
+        "AAAAAAAAAGVQbGF5IG91dCBhIHRlYW0gZ2FtZSBvbmNlIGFsbCBmb3VyIHBsYXllcnMgaGF2ZSByZXZlYWxlZCwgYW5kIHJlcG9ydCB0aGUKd2lubmluZyB0ZWFtIHRvIHRoZSBHYW1lIEh1YgAAAAAAABNyZXNvbHZlX3RlYW1fYmF0dGxlAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAMQmF0dGxlUmVzdWx0AAAAAw==",
+        "AAAAAAAAAJdSZXZlYWwgdGhlIG1vdmVzIGFuZCBzYWx0IGJlaGluZCBhIGBjb21taXRfbW92ZXNfaGFzaGVkYCBjb21taXRtZW50LiBUaGUKZGlnZXN0IGlzIHJlY29tcHV0ZWQgb24tY2hhaW47IGV2ZXJ5dGhpbmcgYWZ0ZXIgdGhhdCBpcyBhcyBmb3IgYHJldmVhbF9tb3Zlc2AuAAAAABNyZXZlYWxfbW92ZXNfaGFzaGVkAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAFbW92ZXMAAAAAAAPqAAAH0AAAAARNb3ZlAAAAAAAAAARzYWx0AAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAHJTZWNvbmRzIHdpdGhvdXQgYSBiYXR0bGUgYWZ0ZXIgd2hpY2ggYSBwbGF5ZXIncyBuYW1lIGNhbiBiZSBjbGFpbWVkIGJ5Cm90aGVyczsgMCAodGhlIGRlZmF1bHQpIGtlZXBzIG5hbWVzIGZvcmV2ZXIAAAAAABNzZXRfdXNlcm5hbWVfZXhwaXJ5AAAAAAEAAAAAAAAAC2V4cGlyeV9zZWNzAAAAAAYAAAAA",
+        "AAAAAAAAAEhPcGVuIGFuIHVucmFua2VkIGdhbWUgYWdhaW5zdCB0aGUgb24tY2hhaW4gYm90LiBSZXR1cm5zIHRoZSBwcmFjdGljZSBpZC4AAAATc3RhcnRfcHJhY3RpY2VfZ2FtZQAAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmRpZmZpY3VsdHkAAAAAB9AAAAASUHJhY3RpY2VEaWZmaWN1bHR5AAAAAAABAAAABA==",
+        "AAAAAAAAAGhUYWtlIGEgcHJlZGljdGlvbiBiYWNrIGJlZm9yZSB0aGUgZmlyc3QgY29tbWl0IChvciBhZnRlciB0aGUgZ2FtZSBleHBpcmVkCnVuc2V0dGxlZCk7IHJldHVybnMgdGhlIHJlZnVuZAAAABN3aXRoZHJhd19wcmVkaWN0aW9uAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAACXByZWRpY3RvcgAAAAAAABMAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAAPJBZnRlciB0aGUgcmV2ZWFsIGRlYWRsaW5lLCBlbmQgYSBnYW1lIGluIHdoaWNoIGEgY29tbWl0dGVkIHBsYXllciBuZXZlciByZXZlYWxlZC4KVGhlIHBsYXllciB3aG8gcmV2ZWFsZWQgd2lucyBieSBmb3JmZWl0IGFuZCByZWNlaXZlcyB0aGUgb3RoZXIncyBkZXBvc2l0LgpDb3JyZXNwb25kZW5jZSBnYW1lcyBjYW4gYWxzbyBiZSBjbGFpbWVkIHdoZW4gdGhlIG9wcG9uZW50IG1pc3NlZCB0aGUgY29tbWl0CmRlYWRsaW5lLgAAAAAAFGNsYWltX3JldmVhbF90aW1lb3V0AAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAfQAAAADEJhdHRsZVJlc3VsdAAAAAM=",
+        "AAAAAAAAAAAAAAAUZ2V0X2xpbmtlZF9hZGRyZXNzZXMAAAABAAAAAAAAAAdwcmltYXJ5AAAAABMAAAABAAAD6gAAABM=",
+        "AAAAAAAAAAAAAAAUZ2V0X21pZ3JhdGlvbl9zdGF0dXMAAAAAAAAAAQAAB9AAAAAPTWlncmF0aW9uU3RhdHVzAA==",
+        "AAAAAAAAAAAAAAAUZ2V0X3ByZWRpY3Rpb25fdG9rZW4AAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAAAAAAAUZ2V0X3NlYXNvbl9wbGFjZW1lbnQAAAACAAAAAAAAAAlzZWFzb25faWQAAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6AAAB9AAAAAPU2Vhc29uUGxhY2VtZW50AA==",
+        "AAAAAAAAADBUZWxsIGZyaWVuZHMgeW91IGFyZSB1cCBmb3IgYSBnYW1lLCBvciBubyBsb25nZXIAAAAUc2V0X2xvb2tpbmdfZm9yX2dhbWUAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAB2xvb2tpbmcAAAAAAQAAAAA=",
+        "AAAAAAAAAIBUb2tlbiBzcGVjdGF0b3JzIHN0YWtlIHdpdGggYHBsYWNlX3ByZWRpY3Rpb25gOyBgTm9uZWAgdHVybnMgbmV3IHBvb2xzIG9mZi4KUG9vbHMgYWxyZWFkeSBvcGVuIGtlZXAgdGhlIHRva2VuIHRoZXkgc3RhcnRlZCB3aXRoLgAAABRzZXRfcHJlZGljdGlvbl90b2tlbgAAAAEAAAAAAAAABXRva2VuAAAAAAAD6AAAABMAAAAA",
+        "AAAAAAAAAGBNb3ZlIGBhbW91bnRgIG9mIHRoZSBwbGF5ZXIncyBib251cyBwb2ludHMgb250byB0aGUgR2FtZSBIdWIncyBsZWRnZXI7IHJldHVybnMKd2hhdCBpcyBsZWZ0IGhlcmUAAAAUc2V0dGxlX3BvaW50c190b19odWIAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAJdTdWJtaXQgdGhlIHJlc3VsdCBvZiBhIG1hdGNoIHBsYXllZCBvZmYtY2hhaW4sIHNpZ25lZCBieSBib3RoIHBsYXllcnMgb3Zlcgpgc2lnbmVkX3Jlc3VsdF9kaWdlc3RgLiBJdCBhcHBsaWVzIGFmdGVyIGEgY2hhbGxlbmdlIHdpbmRvdyB1bmxlc3MgZGlzcHV0ZWQuAAAAABRzZXR0bGVfc2lnbmVkX3Jlc3VsdAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnJlc3VsdAAAAAAH0AAAAAxTaWduZWRSZXN1bHQAAAAAAAAABnNpZ19wMQAAAAAD7gAAAEAAAAAAAAAABnNpZ19wMgAAAAAD7gAAAEAAAAABAAAD6QAAB9AAAAARUGVuZGluZ1NldHRsZW1lbnQAAAAAAAAD",
+        "AAAAAAAAADhUaGUgbWVzc2FnZSBlYWNoIHBsYXllciBzaWducyBmb3IgYHNldHRsZV9zaWduZWRfcmVzdWx0YAAAABRzaWduZWRfcmVzdWx0X2RpZ2VzdAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnJlc3VsdAAAAAAH0AAAAAxTaWduZWRSZXN1bHQAAAABAAAD7gAAACA=",
+        "AAAAAAAAAJtTdGFydCBhIGNvcnJlc3BvbmRlbmNlIGdhbWU6IGNsYXNzaWMgcnVsZXMsIGJ1dCBwbGF5ZXJzIGdldCBkYXlzIHJhdGhlciB0aGFuCmhvdXJzIHRvIGNvbW1pdCBhbmQgdG8gcmV2ZWFsLCBhbmQgYSBgV2luZG93T3BlbmVkYCBldmVudCBtYXJrcyBlYWNoIGRlYWRsaW5lLgAAAAAUc3RhcnRfY29ycmVzcG9uZGVuY2UAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAHpTdGFydCBhIGNsYXNzaWMgZ2FtZSB3aG9zZSBzdGFrZXMgdGhlIHNwb25zb3IgcG9vbCBwdXRzIHVwLiBCb3RoIHBsYXllcnMgc2lnbiwKYW5kIGJvdGggbXVzdCBxdWFsaWZ5IGZvciB0aGUgc3BvbnNvciB0aWVyLgAAAAAAFHN0YXJ0X3Nwb25zb3JlZF9nYW1lAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAHxBbnlvbmU6IG9uY2UgYSBzZWFzb24ncyBjbGFpbSB3aW5kb3cgaGFzIGNsb3NlZCwgbW92ZSBpdHMgdW5jbGFpbWVkIHJld2FyZHMKdG8gdGhlIGNvbGxlY3RlZCBmZWVzLiBSZXR1cm5zIHRoZSBhbW91bnQgbW92ZWQuAAAAFHN3ZWVwX3NlYXNvbl9yZXdhcmRzAAAAAQAAAAAAAAAJc2Vhc29uX2lkAAAAAAAABAAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAGpUYWtlIGFuIG9wZW4gY2hhbGxlbmdlIGFuZCBzdGFydCBhIGdhbWUsIGxpa2UgYGFjY2VwdF9jaGFsbGVuZ2VgLiBSZXR1cm5zIHRoZQpzZXNzaW9uIGlkIGFsbG9jYXRlZCBmb3IgaXQuAAAAAAAVYWNjZXB0X29wZW5fY2hhbGxlbmdlAAAAAAAAAgAAAAAAAAAMY2hhbGxlbmdlX2lkAAAABAAAAAAAAAAIYWNjZXB0b3IAAAATAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAG9SZWplY3QgYSBwZW5kaW5nIHNpZ25lZCByZXN1bHQgYW5kIGZhbGwgYmFjayB0byBmdWxsIFpLIHJlc29sdXRpb247IHRoZSBzZXNzaW9uCnRha2VzIG5vIGZ1cnRoZXIgc2lnbmVkIHJlc3VsdHMAAAAAFWRpc3B1dGVfc2lnbmVkX3Jlc3VsdAAAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAD9DaGVhcCBtYXJrZXIgdG8gcG9sbDogYnVtcGVkIGJ5IGV2ZXJ5IGNoYW5nZSBhZmZlY3RpbmcgYHBsYXllcmAAAAAAFWdldF9sYXN0X2FjdGl2aXR5X3NlcQAAAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAG",
+        "AAAAAAAAADJHZXQgYWxsIGNoYWxsZW5nZXMgZm9yIGEgcGxheWVyIChzb3J0ZWQgYnkgc3RhdHVzKQAAAAAAFWdldF9wbGF5ZXJfY2hhbGxlbmdlcwAAAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPtAAAAAwAAA+oAAAfQAAAACUNoYWxsZW5nZQAAAAAAA+oAAAfQAAAACUNoYWxsZW5nZQAAAAAAA+oAAAfQAAAACUNoYWxsZW5nZQAAAA==",
+        "AAAAAAAAAHFIb3cgYHBsYXllcmAgcmFua3MgYW1vbmcgYWxsIHJhdGVkIHBsYXllcnMgKCJ0b3AgNSUiKSwgcmVhZCBmcm9tIHRoZSByYXRpbmcKaGlzdG9ncmFtIGluc3RlYWQgb2YgdGhlIHdob2xlIGxhZGRlcgAAAAAAABVnZXRfcmF0aW5nX3BlcmNlbnRpbGUAAAAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAH0AAAABBSYXRpbmdQZXJjZW50aWxl",
+        "AAAAAAAAANlHYW1lcywgZHVlbHMgYW5kIHRlYW0gZ2FtZXMgY3JlYXRlZCBpbiBsZWRnZXJzIGBmcm9tX3NlcS4uPXRvX3NlcWAsIG9sZGVzdApmaXJzdC4gVGhlIHJhbmdlIG1heSBzcGFuIGF0IG1vc3QgYSBkYXkgb2YgbGVkZ2VyczsgYSBidXN5IG9uZSBpcyBsaXN0ZWQgb3ZlcgpzZXZlcmFsIGNhbGxzLCBzdGFydGluZyB3aXRoIGBwYWdlYCAwIGFuZCBmb2xsb3dpbmcgYG5leHRfcGFnZWAuAAAAAAAAFWdldF9zZXNzaW9uc19pbl9yYW5nZQAAAAAAAAMAAAAAAAAACGZyb21fc2VxAAAABAAAAAAAAAAGdG9fc2VxAAAAAAAEAAAAAAAAAARwYWdlAAAABAAAAAEAAAPpAAAH0AAAAAxTZXNzaW9uUmFuZ2UAAAAD",
+        "AAAAAAAAAAAAAAAVZ2V0X3RvdXJuYW1lbnRfcHJpemVzAAAAAAAAAQAAAAAAAAANdG91cm5hbWVudF9pZAAAAAAAAAQAAAABAAAD6AAAB9AAAAAQVG91cm5hbWVudFByaXplcw==",
+        "AAAAAAAAAEtTaWduIGBzZXNzaW9uX2lkYCdzIGNvbW1pdHMgYW5kIHJldmVhbHMgd2l0aCB0aGUgcGxheWVyJ3Mgb3duIGFjY291bnQgYWdhaW4AAAAAFXJldm9rZV9zZXNzaW9uX3NpZ25lcgAAAAAAAAIAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAALdQdXQgdXAgYSBwcml6ZSBwb29sIG9mIGBwb29sYCBpbiBgdG9rZW5gLCBzcGxpdCBieSBgcGF5b3V0c19icHNgIChmaXJzdCBwbGFjZQpmaXJzdCwgc3VtbWluZyB0byAxMF8wMDApIHdoZW4gdGhlIGJyYWNrZXQgY29tcGxldGVzLiBPcmdhbml6ZXIgb25seSwgb25jZSwKYmVmb3JlIHRoZSBicmFja2V0IGlzIHNlZWRlZC4AAAAAFXNldF90b3VybmFtZW50X3ByaXplcwAAAAAAAAQAAAAAAAAADXRvdXJuYW1lbnRfaWQAAAAAAAAEAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABHBvb2wAAAALAAAAAAAAAAtwYXlvdXRzX2JwcwAAAAPqAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAENUYWtlIGBhbW91bnRgIGJhY2sgb3V0IG9mIHRoZSBzcG9uc29yIHBvb2w7IHJldHVybnMgdGhlIG5ldyBiYWxhbmNlAAAAABV3aXRoZHJhd19zcG9uc29yX3Bvb2wAAAAAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAAElNaW50IHRoZSByZWZlcnJhbCBib251c2VzIGNyZWRpdGVkIHRvIGByZWZlcnJlcmA7IHJldHVybnMgdGhlIGFtb3VudCBwYWlkAAAAAAAAFmNsYWltX3JlZmVycmFsX3Jld2FyZHMAAAAAAAEAAAAAAAAACHJlZmVycmVyAAAAEwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAFBDb2xsZWN0IHRoZSByZXdhcmQgZm9yIGBwbGF5ZXJgJ3MgZmluaXNoIGluIGBzZWFzb25faWRgOyByZXR1cm5zIHRoZSBhbW91bnQgcGFpZAAAABZjbGFpbV9zZWFzb25fcGxhY2VtZW50AAAAAAACAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACXNlYXNvbl9pZAAAAAAAAAQAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAAEZBcHBseSBhbiB1bmRpc3B1dGVkIHNpZ25lZCByZXN1bHQgb25jZSBpdHMgY2hhbGxlbmdlIHdpbmRvdyBoYXMgY2xvc2VkAAAAAAAWZmluYWxpemVfc2lnbmVkX3Jlc3VsdAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAAxCYXR0bGVSZXN1bHQAAAAD",
+        "AAAAAAAAAAAAAAAWZ2V0X3BlbmRpbmdfc2V0dGxlbWVudAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPoAAAH0AAAABFQZW5kaW5nU2V0dGxlbWVudAAAAA==",
+        "AAAAAAAAAAAAAAAWZ2V0X3JlcXVpcmVfcmVnaXN0ZXJlZAAAAAAAAAAAAAEAAAAB",
+        "AAAAAAAAAIFXaGVuIG9uLCBnYW1lcyBvbmx5IHN0YXJ0IGlmIHRoZSBHYW1lIEh1YiByZXBvcnRzIGJvdGggcGxheWVycyBhcyByZWdpc3RlcmVkLApzbyB0aHJvd2F3YXkgYWNjb3VudHMgY2FuJ3QgZmFybSBsZWFkZXJib2FyZCBwb2ludHMAAAAAAAAWc2V0X3JlcXVpcmVfcmVnaXN0ZXJlZAAAAAAAAQAAAAAAAAAIcmVxdWlyZWQAAAABAAAAAA==",
+        "AAAAAAAAABpHZXQgYWRkcmVzcyBmb3IgYSB1c2VybmFtZQAAAAAAF2dldF9hZGRyZXNzX2J5X3VzZXJuYW1lAAAAAAEAAAAAAAAACHVzZXJuYW1lAAAAEAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAAAAAAAXZ2V0X2NvbnRleHRfcnVsZXNfY291bnQAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAAAAAAAXZ2V0X251bGxpZmllcl9yZXRlbnRpb24AAAAAAAAAAAEAAAAE",
+        "AAAAAAAAALRVcCB0byBgbGltaXRgIHNlc3Npb25zIGZyb20gYG9mZnNldGAgd2l0aCBib3RoIG1vdmVzIHJldmVhbGVkIHRoYXQgaGF2ZW4ndCBiZWVuCnJlc29sdmVkIHlldCwgZm9yIGJvdHMgYW5kIHRoZSBmcm9udGVuZCB0byByZXRyeSBgcmVzb2x2ZV9iYXR0bGVgIG9uLiBUaGUgbGlzdApob2xkcyB0aGUgbGF0ZXN0IDEwMC4AAAAXZ2V0X3BlbmRpbmdfcmVzb2x1dGlvbnMAAAAAAgAAAAAAAAAGb2Zmc2V0AAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAAAQ=",
+        "AAAAAAAAAJdTZXNzaW9ucyBvZiBgcGxheWVyYCdzIGdhbWVzIHRoYXQgaGF2ZW4ndCBzZXR0bGVkIHlldCwgb2xkZXN0IGZpcnN0LCBhbW9uZyB1cCB0bwpgbGltaXRgIG9mIHRoZWlyIGxpdmUgc2Vzc2lvbnMgZnJvbSBgb2Zmc2V0YCAoYSBwbGF5ZXIgaGFzIGF0IG1vc3QgMzApAAAAABdnZXRfcGxheWVyX2FjdGl2ZV9nYW1lcwAAAAADAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABm9mZnNldAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAAE",
+        "AAAAAAAAAAAAAAAXcmVnaXN0ZXJfZm9yX3RvdXJuYW1lbnQAAAAAAgAAAAAAAAANdG91cm5hbWVudF9pZAAAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAElQbGF5IGEgcHJhY3RpY2UgZ2FtZSB3aXRoIHRoZSBwbGF5ZXIncyBtb3ZlcyBhZ2FpbnN0IHRoZSBib3QncywgZHJhd24gbm93AAAAAAAAF3Jlc29sdmVfcHJhY3RpY2VfYmF0dGxlAAAAAAMAAAAAAAAAC3ByYWN0aWNlX2lkAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAFbW92ZXMAAAAAAAPqAAAH0AAAAARNb3ZlAAAAAQAAA+kAAAfQAAAADEJhdHRsZVJlc3VsdAAAAAM=",
+        "AAAAAAAAAHRQaWNrIGhvdyB0aGUgY2hhbGxlbmdlJ3MgZ2FtZSBicmVha3MgYSB0aWUuIE9ubHkgd2hpbGUgaXQgaXMgcGVuZGluZzsgYnVtcHMKdGhlIG5vbmNlIHRoZSBjaGFsbGVuZ2VkIHBsYXllciBhY2NlcHRzLgAAABdzZXRfY2hhbGxlbmdlX3RpZV9icmVhawAAAAACAAAAAAAAAAxjaGFsbGVuZ2VfaWQAAAAEAAAAAAAAAAl0aWVfYnJlYWsAAAAAAAfQAAAACFRpZUJyZWFrAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAI9LZWVwIHNwZW50IGNvbW1pdCBudWxsaWZpZXJzIGZvciBgbGVkZ2Vyc2AgbGVkZ2Vycy4gQXBwbGllcyB0byBjb21taXRzIG1hZGUKYWZ0ZXJ3YXJkczsgYSBwcm9vZiBjYW4gYmUgcmVwbGF5ZWQgb25jZSBpdHMgbnVsbGlmaWVyIGhhcyBleHBpcmVkLgAAAAAXc2V0X251bGxpZmllcl9yZXRlbnRpb24AAAAAAQAAAAAAAAAHbGVkZ2VycwAAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAH5MZXQgYHNpZ25lcmAgKGUuZy4gYSBob3Qga2V5IG9mIGEgcGFzc2tleSBzbWFydCBhY2NvdW50KSBzaWduIGBwbGF5ZXJgJ3MgY29tbWl0cwphbmQgcmV2ZWFscyBpbiBgc2Vzc2lvbl9pZGAsIGFuZCBub3RoaW5nIGVsc2UAAAAAABhhdXRob3JpemVfc2Vzc2lvbl9zaWduZXIAAAADAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAEdTcG9uc29yZWQgZ2FtZXMgYHBsYXllcmAgY2FuIHN0aWxsIHN0YXJ0IHRvZGF5OyAwIGlmIHRoZXkgZG9uJ3QgcXVhbGlmeQAAAAAYZ2V0X3Nwb25zb3JlZF9nYW1lc19sZWZ0AAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAAAQ=",
+        "AAAAAAAAAMtPbmUtb2ZmIG1pZ3JhdGlvbiBhZnRlciB1cGdyYWRpbmc6IGluZGV4IHRoZSBzZXNzaW9ucyBvZiBjaGFsbGVuZ2VzIGFjY2VwdGVkCmJlZm9yZSBgcmVzb2x2ZV9iYXR0bGVgIGxvb2tlZCB0aGVtIHVwIGJ5IHNlc3Npb24uIFBhZ2VkIHRvIHN0YXkgd2l0aGluIHRoZQppbnN0cnVjdGlvbiBidWRnZXQ7IHJldHVybnMgaG93IG1hbnkgd2VyZSBpbmRleGVkLgAAAAAYaW5kZXhfY2hhbGxlbmdlX3Nlc3Npb25zAAAAAgAAAAAAAAAIc3RhcnRfaWQAAAAEAAAAAAAAAAVjb3VudAAAAAAAAAQAAAABAAAABA==",
+        "AAAAAAAAAAAAAAAYdXBkYXRlX2NvbnRleHRfcnVsZV9uYW1lAAAAAgAAAAAAAAAPY29udGV4dF9ydWxlX2lkAAAAAAQAAAAAAAAABG5hbWUAAAAQAAAAAQAAB9AAAAALQ29udGV4dFJ1bGUA",
+        "AAAAAAAAAIJVcCB0byBgbGltaXRgIG9mIGEgcGxheWVyJ3MgY2hhbGxlbmdlcyBmcm9tIGBvZmZzZXRgLCBvbGRlc3QgZmlyc3Q7IGlkcyB3aG9zZQpjaGFsbGVuZ2UgaGFzIGV4cGlyZWQgYXJlIHBydW5lZCBhcyB0aGV5IGFyZSByZWFjaGVkAAAAAAAaZ2V0X3BsYXllcl9jaGFsbGVuZ2VzX3BhZ2UAAAAAAAMAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAGb2Zmc2V0AAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAB9AAAAAJQ2hhbGxlbmdlAAAA",
+        "AAAAAAAAAAAAAAAfdXBkYXRlX2NvbnRleHRfcnVsZV92YWxpZF91bnRpbAAAAAACAAAAAAAAAA9jb250ZXh0X3J1bGVfaWQAAAAABAAAAAAAAAALdmFsaWRfdW50aWwAAAAD6AAAAAQAAAABAAAH0AAAAAtDb250ZXh0UnVsZQA=",
+        "AAAAAAAAAMdWZXN0IHByaXplcyBhYm92ZSBgdGhyZXNob2xkYDogdGhleSBwYXkgYGltbWVkaWF0ZV9icHNgIG9mIHRoZW1zZWx2ZXMgd2hlbiB0aGUKdG91cm5hbWVudCBjb21wbGV0ZXMgYW5kIHRoZSByZXN0IGBsb2NrX3NlY3NgIGxhdGVyICh1cCB0byAzMCBkYXlzKS4KT3JnYW5pemVyIG9ubHksIG9uY2UsIGJlZm9yZSB0aGUgYnJhY2tldCBpcyBzZWVkZWQuAAAAABFzZXRfcHJpemVfdmVzdGluZwAAAAAAAAQAAAAAAAAADXRvdXJuYW1lbnRfaWQAAAAAAAAEAAAAAAAAAAl0aHJlc2hvbGQAAAAAAAALAAAAAAAAAA1pbW1lZGlhdGVfYnBzAAAAAAAABAAAAAAAAAAJbG9ja19zZWNzAAAAAAAABgAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAD5Db2xsZWN0IHRoZSBsb2NrZWQgcGFydCBvZiBgcGxheWVyYCdzIHByaXplIG9uY2UgaXQgaGFzIHZlc3RlZAAAAAAADGNsYWltX3Zlc3RlZAAAAAIAAAAAAAAADXRvdXJuYW1lbnRfaWQAAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAAAAAAAARZ2V0X3ByaXplX3Zlc3RpbmcAAAAAAAABAAAAAAAAAA10b3VybmFtZW50X2lkAAAAAAAABAAAAAEAAAPoAAAH0AAAAAxQcml6ZVZlc3Rpbmc=",
+        "AAAAAAAAAAAAAAAQZ2V0X3Zlc3RlZF9wcml6ZQAAAAIAAAAAAAAADXRvdXJuYW1lbnRfaWQAAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6AAAB9AAAAALVmVzdGVkUHJpemUA",
+        "AAAAAAAAAGBIYXZlIGluYWN0aXZlIHBsYXllcnMnIHJhdGluZ3MgZGVjYXkgKHNlZSBgUmF0aW5nRGVjYXlgKTsgMCBmb3IgZWl0aGVyIHR1cm5zCml0IG9mZi4gQWRtaW4gb25seS4AAAAQc2V0X3JhdGluZ19kZWNheQAAAAIAAAAAAAAADWluYWN0aXZlX3NlY3MAAAAAAAAGAAAAAAAAAAZwb2ludHMAAAAAAAQAAAAA",
+        "AAAAAAAAAAAAAAAQZ2V0X3JhdGluZ19kZWNheQAAAAAAAAABAAAH0AAAAAtSYXRpbmdEZWNheQA=",
+        "AAAAAAAAALpXcml0ZSB0aGUgZGVjYXkgYHBsYXllcmAgb3dlcyBmb3IgaW5hY3Rpdml0eSBpbnRvIHRoZWlyIHN0b3JlZCByZWNvcmQsIHRoZQpoaXN0b2dyYW0gYW5kIHRoZSBsZWFkZXJib2FyZC4gUmVhZHMgYWxyZWFkeSBzaG93IGl0OyBhbnlvbmUgY2FuIGNhbGwgdGhpcwp0byBicmluZyB0aGUgbGVhZGVyYm9hcmQgdXAgdG8gZGF0ZS4AAAAAAAxkZWNheV9yYXRpbmcAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAH0AAAAAtQbGF5ZXJTdGF0cwA=",
+        "AAAAAAAAAHpXaGV0aGVyIGBwbGF5ZXJgJ3MgcmF0aW5nIGlzIHN0aWxsIHByb3Zpc2lvbmFsOiB1bmRlciAxMCByYXRlZCBnYW1lcywgZWFjaAptb3ZpbmcgaXQgdHdpY2UgYXMgZmFyIGFzIGFuIGVzdGFibGlzaGVkIHJhdGluZwAAAAAADmlzX3Byb3Zpc2lvbmFsAAAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAAAQ==",
+        "AAAAAAAAAD9UYWtlIHlvdXIgb3BlbiBjaGFsbGVuZ2Ugb2ZmIHRoZSBib2FyZCBiZWZvcmUgYW55b25lIGFjY2VwdHMgaXQAAAAAFWNhbmNlbF9vcGVuX2NoYWxsZW5nZQAAAAAAAAIAAAAAAAAADGNoYWxsZW5nZV9pZAAAAAQAAAAAAAAACmNoYWxsZW5nZXIAAAAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAI9DYWxsIG9mZiBhIHRvdXJuYW1lbnQgYmVmb3JlIGl0IGNvbXBsZXRlcy4gT3JnYW5pemVyIG9yIGFkbWluOyB0aGUgcHJpemUgcG9vbAppcyBjcmVkaXRlZCBiYWNrIHRvIHRoZSBvcmdhbml6ZXIsIHRvIGNvbGxlY3Qgd2l0aCBgY2xhaW1fcHJpemVgLgAAAAARY2FuY2VsX3RvdXJuYW1lbnQAAAAAAAACAAAAAAAAAA10b3VybmFtZW50X2lkAAAAAAAABAAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAQAAA+kAAAfQAAAAClRvdXJuYW1lbnQAAAAAAAM=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    get_hub: this.txFromJSON<string>,
+    status: this.txFromJSON<ContractStatus>,
+        forfeit: this.txFromJSON<Result<BattleResult>>,
+        get_hub: this.txFromJSON<string>,
+        migrate: this.txFromJSON<MigrationStatus>,
         set_hub: this.txFromJSON<null>,
         upgrade: this.txFromJSON<null>,
+        version: this.txFromJSON<ContractVersion>,
+        check_in: this.txFromJSON<Result<void>>,
+        get_duel: this.txFromJSON<Result<Duel>>,
         get_game: this.txFromJSON<Result<Game>>,
         end_match: this.txFromJSON<Result<void>>,
         get_admin: this.txFromJSON<string>,
+        get_games: this.txFromJSON<Array<SessionGame>>,
         get_match: this.txFromJSON<Result<PvPMatch>>,
+        get_pause: this.txFromJSON<PauseFlags>,
+        get_picks: this.txFromJSON<Array<SpectatorPick>>,
+        get_rival: this.txFromJSON<Option<string>>,
+        get_rules: this.txFromJSON<Rules>,
+        make_pick: this.txFromJSON<Result<void>>,
         play_turn: this.txFromJSON<Result<void>>,
+        quote_fee: this.txFromJSON<i128>,
         set_admin: this.txFromJSON<null>,
+        set_coach: this.txFromJSON<Result<void>>,
+        set_pause: this.txFromJSON<null>,
+        set_rules: this.txFromJSON<Result<u32>>,
+        add_friend: this.txFromJSON<Result<void>>,
         add_policy: this.txFromJSON<u32>,
         add_signer: this.txFromJSON<u32>,
+        call_raise: this.txFromJSON<Result<void>>,
+        end_season: this.txFromJSON<Result<SeasonResults>>,
+        fold_raise: this.txFromJSON<Result<BattleResult>>,
+        get_badges: this.txFromJSON<Badges>,
+        get_escrow: this.txFromJSON<Option<EscrowReceipt>>,
+        get_season: this.txFromJSON<Option<Season>>,
+        get_series: this.txFromJSON<Result<MatchSeries>>,
+        join_queue: this.txFromJSON<Result<Option<u32>>>,
+        start_duel: this.txFromJSON<Result<void>>,
         start_game: this.txFromJSON<Result<void>>,
-        commit_moves: this.txFromJSON<Result<Buffer>>,
+        sweep_fees: this.txFromJSON<Result<i128>>,
+        cancel_game: this.txFromJSON<Result<void>>,
+        claim_prize: this.txFromJSON<Result<i128>>,
+        clear_rival: this.txFromJSON<null>,
+        expire_game: this.txFromJSON<Result<void>>,
+        export_game: this.txFromJSON<Result<GameExport>>,
+        get_bracket: this.txFromJSON<Array<BracketMatch>>,
+        get_rematch: this.txFromJSON<Option<Rematch>>,
+        import_game: this.txFromJSON<null>,
+        is_shadowed: this.txFromJSON<boolean>,
+        leave_queue: this.txFromJSON<Result<void>>,
+        raise_wager: this.txFromJSON<Result<void>>,
+        redeem_code: this.txFromJSON<Result<void>>,
+        resume_game: this.txFromJSON<Result<void>>,
+        start_siege: this.txFromJSON<Result<void>>,
+        accept_pause: this.txFromJSON<Result<u64>>,
+        claim_vested: this.txFromJSON<Result<i128>>,
+        commit_moves: this.txFromJSON<Result<CommitReceipt>>,
+        confirm_game: this.txFromJSON<Result<void>>,
+        confirm_link: this.txFromJSON<Result<void>>,
+        decay_rating: this.txFromJSON<PlayerStats>,
+        get_identity: this.txFromJSON<string>,
+        get_referrer: this.txFromJSON<Option<string>>,
+        get_treasury: this.txFromJSON<Option<string>>,
         get_username: this.txFromJSON<Option<string>>,
+        get_verifier: this.txFromJSON<Option<VerifierEntry>>,
+        get_winnings: this.txFromJSON<Map<string, i128>>,
+        link_address: this.txFromJSON<Result<void>>,
+        propose_game: this.txFromJSON<Result<void>>,
         reveal_moves: this.txFromJSON<Result<void>>,
+        set_shadowed: this.txFromJSON<null>,
+        set_treasury: this.txFromJSON<null>,
         set_username: this.txFromJSON<Result<void>>,
+        start_season: this.txFromJSON<Result<Season>>,
+        start_series: this.txFromJSON<Result<u32>>,
         accept_invite: this.txFromJSON<Result<void>>,
         create_invite: this.txFromJSON<Result<u32>>,
+        declare_rival: this.txFromJSON<Result<void>>,
+        force_release: this.txFromJSON<Result<void>>,
+        get_addresses: this.txFromJSON<Array<Option<string>>>,
+        get_challenge: this.txFromJSON<Result<Challenge>>,
+        get_moderator: this.txFromJSON<Option<string>>,
+        get_team_game: this.txFromJSON<Result<TeamGame>>,
+        get_usernames: this.txFromJSON<Array<Option<string>>>,
+        propose_pause: this.txFromJSON<Result<void>>,
         reject_invite: this.txFromJSON<Result<void>>,
+        remove_friend: this.txFromJSON<null>,
         remove_policy: this.txFromJSON<null>,
         remove_signer: this.txFromJSON<null>,
+        set_moderator: this.txFromJSON<null>,
+        withdraw_fees: this.txFromJSON<i128>,
+        accept_rematch: this.txFromJSON<Result<u32>>,
+        claim_winnings: this.txFromJSON<Result<Map<string, i128>>>,
+        get_game_pause: this.txFromJSON<GamePause>,
+        get_match_note: this.txFromJSON<Option<Buffer>>,
+        get_pick_stats: this.txFromJSON<PickStats>,
+        get_prediction: this.txFromJSON<Option<Prediction>>,
+        get_promo_code: this.txFromJSON<Option<PromoCode>>,
+        get_tournament: this.txFromJSON<Result<Tournament>>,
+        is_provisional: this.txFromJSON<boolean>,
         resolve_battle: this.txFromJSON<Result<BattleResult>>,
         send_challenge: this.txFromJSON<Result<u32>>,
+        set_match_note: this.txFromJSON<Result<void>>,
+        unlink_address: this.txFromJSON<Result<void>>,
+        advance_bracket: this.txFromJSON<Result<Tournament>>,
         get_csh_balance: this.txFromJSON<i128>,
-        accept_challenge: this.txFromJSON<Result<void>>,
+        get_fee_waivers: this.txFromJSON<u32>,
+        get_game_config: this.txFromJSON<GameConfig>,
+        get_game_expiry: this.txFromJSON<Result<u64>>,
+        get_invite_code: this.txFromJSON<Option<InviteCode>>,
+        get_queue_stats: this.txFromJSON<Array<QueueTier>>,
+        get_siege_stats: this.txFromJSON<SiegeStats>,
+        get_sponsorship: this.txFromJSON<Option<Sponsorship>>,
+        get_top_players: this.txFromJSON<Array<RankedPlayer>>,
+        preverify_proof: this.txFromJSON<Result<Buffer>>,
+        quote_challenge: this.txFromJSON<Result<ChallengeQuote>>,
+        request_rematch: this.txFromJSON<Result<void>>,
+        rollover_season: this.txFromJSON<Result<SeasonResults>>,
+        set_game_config: this.txFromJSON<Result<void>>,
+        start_team_game: this.txFromJSON<Result<void>>,
+        accept_challenge: this.txFromJSON<Result<u32>>,
         add_context_rule: this.txFromJSON<ContextRule>,
+        cancel_challenge: this.txFromJSON<Result<void>>,
+        claim_prediction: this.txFromJSON<Result<i128>>,
+        commit_duel_move: this.txFromJSON<Result<void>>,
+        get_achievements: this.txFromJSON<Array<EarnedAchievement>>,
+        get_bonus_points: this.txFromJSON<i128>,
         get_context_rule: this.txFromJSON<ContextRule>,
+        get_fee_schedule: this.txFromJSON<Array<FeeTier>>,
+        get_global_stats: this.txFromJSON<GlobalStats>,
+        get_head_to_head: this.txFromJSON<HeadToHead>,
+        get_player_stats: this.txFromJSON<PlayerStats>,
+        get_raise_config: this.txFromJSON<Option<RaiseConfig>>,
+        get_rating_decay: this.txFromJSON<RatingDecay>,
+        get_season_stats: this.txFromJSON<PlayerStats>,
+        get_sponsor_pool: this.txFromJSON<i128>,
+        get_sponsor_tier: this.txFromJSON<Option<SponsorTier>>,
+        get_vested_prize: this.txFromJSON<Option<VestedPrize>>,
+        get_wager_limits: this.txFromJSON<WagerLimits>,
+        place_prediction: this.txFromJSON<Result<void>>,
+        release_username: this.txFromJSON<Result<void>>,
+        reveal_duel_move: this.txFromJSON<Result<void>>,
+        set_fee_schedule: this.txFromJSON<Result<void>>,
+        set_raise_config: this.txFromJSON<Result<void>>,
+        set_rating_decay: this.txFromJSON<null>,
+        set_sponsor_tier: this.txFromJSON<Result<void>>,
+        set_wager_limits: this.txFromJSON<Result<void>>,
+        cancel_tournament: this.txFromJSON<Result<Tournament>>,
+        commit_team_moves: this.txFromJSON<Result<CommitReceipt>>,
+        create_promo_code: this.txFromJSON<Result<void>>,
+        create_tournament: this.txFromJSON<Result<u32>>,
+        decline_challenge: this.txFromJSON<Result<void>>,
+        enforce_deadlines: this.txFromJSON<Result<BattleResult>>,
+        forward_challenge: this.txFromJSON<Result<void>>,
+        fund_sponsor_pool: this.txFromJSON<Result<i128>>,
+        get_captain_class: this.txFromJSON<CaptainClass>,
+        get_coached_moves: this.txFromJSON<Result<Array<Move>>>,
         get_game_playback: this.txFromJSON<Result<GamePlayback>>,
+        get_game_proposal: this.txFromJSON<Option<GameProposal>>,
+        get_match_history: this.txFromJSON<Array<ArchivedGame>>,
+        get_practice_game: this.txFromJSON<Option<PracticeGame>>,
+        get_prize_vesting: this.txFromJSON<Option<PrizeVesting>>,
+        get_updates_since: this.txFromJSON<PlayerUpdates>,
+        register_referral: this.txFromJSON<Result<void>>,
+        register_verifier: this.txFromJSON<Result<void>>,
+        reveal_team_moves: this.txFromJSON<Result<void>>,
+        set_captain_class: this.txFromJSON<null>,
+        set_prize_vesting: this.txFromJSON<Result<void>>,
+        transfer_username: this.txFromJSON<Result<void>>,
+        verify_randomness: this.txFromJSON<boolean>,
+        accept_invite_code: this.txFromJSON<Result<u32>>,
+        claim_duel_timeout: this.txFromJSON<Result<void>>,
+        create_invite_code: this.txFromJSON<Result<u64>>,
+        deprecate_verifier: this.txFromJSON<Result<void>>,
+        get_collected_fees: this.txFromJSON<i128>,
+        get_commit_deposit: this.txFromJSON<Option<CommitDeposit>>,
+        get_commit_receipt: this.txFromJSON<Option<CommitReceipt>>,
+        get_friends_status: this.txFromJSON<Array<FriendStatus>>,
+        get_last_active_at: this.txFromJSON<u64>,
+        get_max_game_pause: this.txFromJSON<u64>,
         get_player_matches: this.txFromJSON<Array<u32>>,
+        get_practice_stats: this.txFromJSON<PracticeStats>,
+        get_referral_bonus: this.txFromJSON<i128>,
+        get_referral_stats: this.txFromJSON<ReferralStats>,
+        get_rivalry_series: this.txFromJSON<Option<RivalrySeries>>,
+        get_season_results: this.txFromJSON<Option<SeasonResults>>,
+        get_season_rewards: this.txFromJSON<Option<SeasonRewards>>,
+        get_session_signer: this.txFromJSON<Option<string>>,
+        get_settlement_key: this.txFromJSON<Option<Buffer>>,
         get_token_contract: this.txFromJSON<Option<string>>,
+        reveal_and_resolve: this.txFromJSON<Result<BattleResult>>,
+        set_commit_deposit: this.txFromJSON<Result<void>>,
+        set_max_game_pause: this.txFromJSON<null>,
+        set_referral_bonus: this.txFromJSON<null>,
+        set_season_rewards: this.txFromJSON<Result<void>>,
+        set_settlement_key: this.txFromJSON<null>,
         set_token_contract: this.txFromJSON<null>,
+        commit_moves_hashed: this.txFromJSON<Result<CommitReceipt>>,
+        get_awaiting_action: this.txFromJSON<Array<u32>>,
+        get_circuit_version: this.txFromJSON<u32>,
+        get_effective_wager: this.txFromJSON<Result<i128>>,
+        get_open_challenges: this.txFromJSON<Array<OpenChallenge>>,
+        get_prediction_pool: this.txFromJSON<Option<PredictionPool>>,
+        get_username_expiry: this.txFromJSON<u64>,
+        post_open_challenge: this.txFromJSON<Result<u32>>,
         remove_context_rule: this.txFromJSON<null>,
+        resolve_team_battle: this.txFromJSON<Result<BattleResult>>,
+        reveal_moves_hashed: this.txFromJSON<Result<void>>,
+        set_username_expiry: this.txFromJSON<null>,
+        start_practice_game: this.txFromJSON<u32>,
+        withdraw_prediction: this.txFromJSON<Result<i128>>,
+        claim_reveal_timeout: this.txFromJSON<Result<BattleResult>>,
+        get_linked_addresses: this.txFromJSON<Array<string>>,
+        get_migration_status: this.txFromJSON<MigrationStatus>,
+        get_prediction_token: this.txFromJSON<Option<string>>,
+        get_season_placement: this.txFromJSON<Option<SeasonPlacement>>,
+        set_looking_for_game: this.txFromJSON<null>,
+        set_prediction_token: this.txFromJSON<null>,
+        settle_points_to_hub: this.txFromJSON<Result<i128>>,
+        settle_signed_result: this.txFromJSON<Result<PendingSettlement>>,
+        signed_result_digest: this.txFromJSON<Buffer>,
+        start_correspondence: this.txFromJSON<Result<void>>,
+        start_sponsored_game: this.txFromJSON<Result<void>>,
+        sweep_season_rewards: this.txFromJSON<Result<i128>>,
+        accept_open_challenge: this.txFromJSON<Result<u32>>,
+        cancel_open_challenge: this.txFromJSON<Result<void>>,
+        dispute_signed_result: this.txFromJSON<Result<void>>,
+        get_last_activity_seq: this.txFromJSON<u64>,
         get_player_challenges: this.txFromJSON<readonly [Array<Challenge>, Array<Challenge>, Array<Challenge>]>,
+        get_rating_percentile: this.txFromJSON<RatingPercentile>,
+        get_sessions_in_range: this.txFromJSON<Result<SessionRange>>,
+        get_tournament_prizes: this.txFromJSON<Option<TournamentPrizes>>,
+        revoke_session_signer: this.txFromJSON<Result<void>>,
+        set_tournament_prizes: this.txFromJSON<Result<void>>,
+        withdraw_sponsor_pool: this.txFromJSON<Result<i128>>,
+        claim_referral_rewards: this.txFromJSON<Result<i128>>,
+        claim_season_placement: this.txFromJSON<Result<i128>>,
+        finalize_signed_result: this.txFromJSON<Result<BattleResult>>,
+        get_pending_settlement: this.txFromJSON<Option<PendingSettlement>>,
+        get_require_registered: this.txFromJSON<boolean>,
+        set_require_registered: this.txFromJSON<null>,
         get_address_by_username: this.txFromJSON<Option<string>>,
         get_context_rules_count: this.txFromJSON<u32>,
+        get_nullifier_retention: this.txFromJSON<u32>,
+        get_pending_resolutions: this.txFromJSON<Array<u32>>,
+        get_player_active_games: this.txFromJSON<Array<u32>>,
+        register_for_tournament: this.txFromJSON<Result<void>>,
+        resolve_practice_battle: this.txFromJSON<Result<BattleResult>>,
+        set_challenge_tie_break: this.txFromJSON<Result<void>>,
+        set_nullifier_retention: this.txFromJSON<Result<void>>,
+        authorize_session_signer: this.txFromJSON<Result<void>>,
+        get_sponsored_games_left: this.txFromJSON<u32>,
+        index_challenge_sessions: this.txFromJSON<u32>,
         update_context_rule_name: this.txFromJSON<ContextRule>,
+        get_player_challenges_page: this.txFromJSON<Array<Challenge>>,
         update_context_rule_valid_until: this.txFromJSON<ContextRule>
   }
 }
\ No newline at end of file
@@ -7,6 +7,7 @@ import {
   type BattleResult,
   type PvPMatch,
   type MatchState,
+  type FriendStatus,
   Attack,
   Defense
 } from './bindings';
@@ -82,6 +83,23 @@ function rethrowWithSmartAccountWasmHint(err: unknown, action: string): never {
   throw err instanceof Error ? err : new Error(String(err));
 }
 
+/** UI copy for the contract's `OutcomeReason` (u32), so every screen explains an ending the same way */
+export const OUTCOME_REASON_COPY: Record<number, string> = {
+  1: 'Decided in battle',
+  2: 'Fought to a draw',
+  3: 'Forfeited',
+  4: 'Commit deadline missed',
+  5: 'Reveal deadline missed',
+  6: 'Called off, stakes refunded',
+};
+
+/** Captain classes by their contract value (`CaptainClass`); 0 is no class */
+export const CAPTAIN_CLASS_COPY: Record<number, { name: string; perk: string }> = {
+  1: { name: 'Brawler', perk: 'Slashes that land deal 10% more' },
+  2: { name: 'Trickster', perk: 'A Dodge that misses still takes 25% off the hit' },
+  3: { name: 'Gunner', perk: 'Fireball crits hit 50 points harder' },
+};
+
 /**
  * Service for interacting with the ClashGame contract
 
@@ -181,9 +199,33 @@ export class ClashGameService {
     }
   }
 
+  /** Up to 50 addresses per call, in order; null where an address has no name */
+  async getUsernames(addresses: string[]): Promise<(string | null)[]> {
+    try {
+      const tx = await this.baseClient.get_usernames({ addresses });
+      const result = await tx.simulate();
+      return (result?.result ?? []).map((name: string | undefined) => name ?? null);
+    } catch (err) {
+      console.log('[getUsernames] Error querying usernames:', err);
+      return addresses.map(() => null);
+    }
+  }
+
+  /** Up to 50 usernames per call, in order; null where nobody holds the name */
+  async getAddresses(usernames: string[]): Promise<(string | null)[]> {
+    try {
+      const tx = await this.baseClient.get_addresses({ usernames });
+      const result = await tx.simulate();
+      return (result?.result ?? []).map((address: string | undefined) => address ?? null);
+    } catch (err) {
+      console.log('[getAddresses] Error querying addresses:', err);
+      return usernames.map(() => null);
+    }
+  }
+
   async getCshBalance(player: string): Promise<bigint> {
     try {
-      const tx = await (this.baseClient as any).get_csh_balance({ player });
+      const tx = await this.baseClient.get_csh_balance({ player });
       const result = await tx.simulate();
       const raw = result?.result;
       if (typeof raw === 'bigint') return raw;
@@ -196,6 +238,103 @@ export class ClashGameService {
     }
   }
 
+  /** Bumped by every change to the player's games, challenges, duels and team games; null if unreadable */
+  /** Session IDs of the player's unsettled games, straight from the contract's index */
+  async getPlayerActiveGames(player: string): Promise<number[]> {
+    try {
+      const tx = await this.baseClient.get_player_active_games({ player, offset: 0, limit: 30 });
+      const result = await tx.simulate();
+      return ((result?.result ?? []) as Array<number | bigint>).map(Number);
+    } catch (err) {
+      console.log('[getPlayerActiveGames] Error querying active games:', err);
+      return [];
+    }
+  }
+
+  /** Several games in one simulation (the contract reads at most 20); missing sessions are left out */
+  async getGames(sessionIds: number[]): Promise<Array<{ sessionId: number; game: Game }>> {
+    if (sessionIds.length === 0) return [];
+    try {
+      const tx = await this.baseClient.get_games({ session_ids: sessionIds });
+      const result = await tx.simulate();
+      return ((result?.result ?? []) as Array<{ session_id: number; game: Game }>).map((entry) => ({
+        sessionId: Number(entry.session_id),
+        game: entry.game,
+      }));
+    } catch (err) {
+      console.log('[getGames] Error querying games:', err);
+      return [];
+    }
+  }
+
+  /** Sponsored games the player can still start today; 0 if they don't qualify */
+  async getSponsoredGamesLeft(player: string): Promise<number> {
+    try {
+      const tx = await this.baseClient.get_sponsored_games_left({ player });
+      const result = await tx.simulate();
+      return Number(result?.result ?? 0);
+    } catch (err) {
+      console.log('[getSponsoredGamesLeft] Error querying sponsored games:', err);
+      return 0;
+    }
+  }
+
+  async getReferralStats(referrer: string): Promise<{
+    referred: number;
+    completed: number;
+    earned: bigint;
+    unclaimed: bigint;
+  } | null> {
+    try {
+      const tx = await this.baseClient.get_referral_stats({ referrer });
+      const result = await tx.simulate();
+      return result?.result ?? null;
+    } catch (err) {
+      console.log('[getReferralStats] Error querying referrals:', err);
+      return null;
+    }
+  }
+
+  /** Counts for the navbar badges, in one read; they can lag a passed deadline */
+  async getBadges(player: string): Promise<{
+    pending_challenges: number;
+    awaiting_action: number;
+    unclaimed_rewards: number;
+  } | null> {
+    try {
+      const tx = await this.baseClient.get_badges({ player });
+      const result = await tx.simulate();
+      return result?.result ?? null;
+    } catch (err) {
+      console.log('[getBadges] Error querying badges:', err);
+      return null;
+    }
+  }
+
+  async getCaptainClass(player: string): Promise<number> {
+    try {
+      const tx = await this.baseClient.get_captain_class({ player });
+      const result = await tx.simulate();
+      return Number(result?.result ?? 0);
+    } catch (err) {
+      console.log('[getCaptainClass] Error querying captain class:', err);
+      return 0;
+    }
+  }
+
+  async getLastActivitySeq(player: string): Promise<bigint | null> {
+    try {
+      const tx = await this.baseClient.get_last_activity_seq({ player });
+      const result = await tx.simulate();
+      const raw = result?.result;
+      if (raw === undefined || raw === null) return null;
+      return BigInt(raw);
+    } catch (err) {
+      console.log('[getLastActivitySeq] Error querying activity seq:', err);
+      return null;
+    }
+  }
+
   // ========================================================================
   // Challenge System
   // ========================================================================
@@ -242,7 +381,7 @@ export class ClashGameService {
   async acceptChallenge(
     challengeId: number,
     challenged: string,
-    sessionId: number,
+    challengeNonce: number,
     signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
     authTtlMinutes?: number
   ) {
@@ -250,7 +389,7 @@ export class ClashGameService {
     const tx = await client.accept_challenge({
       challenge_id: challengeId,
       challenged,
-      session_id: sessionId,
+      challenge_nonce: challengeNonce,
     }, DEFAULT_METHOD_OPTIONS);
 
     const validUntilLedgerSeq = authTtlMinutes
@@ -304,6 +443,285 @@ export class ClashGameService {
     }
   }
 
+  /** Start a game whose stakes the sponsor pool puts up; both players sign */
+  async startSponsoredGameWithSmartAccount(
+    sessionId: number,
+    player1: string,
+    player2: string,
+    smartAccountService: SmartAccountService
+  ): Promise<void> {
+    try {
+      await smartAccountService.ensureSigningReady();
+      const tx = await this.baseClient.start_sponsored_game(
+        { session_id: sessionId, player1, player2 },
+        DEFAULT_METHOD_OPTIONS
+      );
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'start_sponsored_game',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'start_sponsored_game');
+    } catch (error) {
+      console.error('❌ start_sponsored_game failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'start_sponsored_game');
+    }
+  }
+
+  async setCaptainClassWithSmartAccount(
+    player: string,
+    captainClass: number,
+    smartAccountService: SmartAccountService
+  ): Promise<void> {
+    try {
+      await smartAccountService.ensureSigningReady();
+      const tx = await this.baseClient.set_captain_class(
+        { player, class: captainClass },
+        DEFAULT_METHOD_OPTIONS
+      );
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'set_captain_class',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'set_captain_class');
+    } catch (error) {
+      console.error('❌ set_captain_class failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'set_captain_class');
+    }
+  }
+
+  async addFriendWithSmartAccount(
+    player: string,
+    friend: string,
+    smartAccountService: SmartAccountService
+  ): Promise<void> {
+    try {
+      await smartAccountService.ensureSigningReady();
+      const tx = await this.baseClient.add_friend(
+        { player, friend },
+        DEFAULT_METHOD_OPTIONS
+      );
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'add_friend',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'add_friend');
+    } catch (error) {
+      console.error('❌ add_friend failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'add_friend');
+    }
+  }
+
+  async removeFriendWithSmartAccount(
+    player: string,
+    friend: string,
+    smartAccountService: SmartAccountService
+  ): Promise<void> {
+    try {
+      await smartAccountService.ensureSigningReady();
+      const tx = await this.baseClient.remove_friend(
+        { player, friend },
+        DEFAULT_METHOD_OPTIONS
+      );
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'remove_friend',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'remove_friend');
+    } catch (error) {
+      console.error('❌ remove_friend failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'remove_friend');
+    }
+  }
+
+  async setLookingForGameWithSmartAccount(
+    player: string,
+    looking: boolean,
+    smartAccountService: SmartAccountService
+  ): Promise<void> {
+    try {
+      await smartAccountService.ensureSigningReady();
+      const tx = await this.baseClient.set_looking_for_game(
+        { player, looking },
+        DEFAULT_METHOD_OPTIONS
+      );
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'set_looking_for_game',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'set_looking_for_game');
+    } catch (error) {
+      console.error('❌ set_looking_for_game failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'set_looking_for_game');
+    }
+  }
+
+  /** Friends with their looking-for-game flags, for the "ready to duel" panel */
+  async getFriendsStatus(player: string): Promise<FriendStatus[]> {
+    try {
+      const tx = await this.baseClient.get_friends_status({ player });
+      const result = await tx.simulate();
+      return result?.result ?? [];
+    } catch (err) {
+      console.log('[getFriendsStatus] Error querying friends:', err);
+      return [];
+    }
+  }
+  async registerReferralWithSmartAccount(
+    newPlayer: string,
+    referrer: string,
+    smartAccountService: SmartAccountService
+  ): Promise<void> {
+    try {
+      await smartAccountService.ensureSigningReady();
+      const tx = await this.baseClient.register_referral(
+        { new_player: newPlayer, referrer },
+        DEFAULT_METHOD_OPTIONS
+      );
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'register_referral',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'register_referral');
+    } catch (error) {
+      console.error('❌ register_referral failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'register_referral');
+    }
+  }
+
+  async claimReferralRewardsWithSmartAccount(
+    referrer: string,
+    smartAccountService: SmartAccountService
+  ): Promise<void> {
+    try {
+      await smartAccountService.ensureSigningReady();
+      const tx = await this.baseClient.claim_referral_rewards(
+        { referrer },
+        DEFAULT_METHOD_OPTIONS
+      );
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'claim_referral_rewards',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'claim_referral_rewards');
+    } catch (error) {
+      console.error('❌ claim_referral_rewards failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'claim_referral_rewards');
+    }
+  }
+
+  async getBonusPoints(player: string): Promise<bigint> {
+    try {
+      const tx = await this.baseClient.get_bonus_points({ player });
+      const result = await tx.simulate();
+      return BigInt(result?.result ?? 0);
+    } catch (err) {
+      console.log('[getBonusPoints] Error querying bonus points:', err);
+      return 0n;
+    }
+  }
+
+  /** Moves earned bonus points onto the Game Hub balance */
+  async settlePointsToHubWithSmartAccount(
+    player: string,
+    amount: bigint,
+    smartAccountService: SmartAccountService
+  ): Promise<void> {
+    try {
+      await smartAccountService.ensureSigningReady();
+      const tx = await this.baseClient.settle_points_to_hub(
+        { player, amount },
+        DEFAULT_METHOD_OPTIONS
+      );
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'settle_points_to_hub',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'settle_points_to_hub');
+    } catch (error) {
+      console.error('❌ settle_points_to_hub failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'settle_points_to_hub');
+    }
+  }
+
+  /** Sent from the primary address; the secondary then calls confirmLinkWithSmartAccount */
+  async linkAddressWithSmartAccount(
+    primary: string,
+    secondary: string,
+    smartAccountService: SmartAccountService
+  ): Promise<void> {
+    try {
+      await smartAccountService.ensureSigningReady();
+      const tx = await this.baseClient.link_address(
+        { primary, secondary },
+        DEFAULT_METHOD_OPTIONS
+      );
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'link_address',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'link_address');
+    } catch (error) {
+      console.error('❌ link_address failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'link_address');
+    }
+  }
+
+  async confirmLinkWithSmartAccount(
+    secondary: string,
+    primary: string,
+    smartAccountService: SmartAccountService
+  ): Promise<void> {
+    try {
+      await smartAccountService.ensureSigningReady();
+      const tx = await this.baseClient.confirm_link(
+        { secondary, primary },
+        DEFAULT_METHOD_OPTIONS
+      );
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'confirm_link',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'confirm_link');
+    } catch (error) {
+      console.error('❌ confirm_link failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'confirm_link');
+    }
+  }
+
+  async unlinkAddressWithSmartAccount(
+    caller: string,
+    secondary: string,
+    smartAccountService: SmartAccountService
+  ): Promise<void> {
+    try {
+      await smartAccountService.ensureSigningReady();
+      const tx = await this.baseClient.unlink_address(
+        { caller, secondary },
+        DEFAULT_METHOD_OPTIONS
+      );
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'unlink_address',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'unlink_address');
+    } catch (error) {
+      console.error('❌ unlink_address failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'unlink_address');
+    }
+  }
+
+  /** The primary address `player` plays as; `player` itself when not linked */
+  async getIdentity(player: string): Promise<string> {
+    try {
+      const tx = await this.baseClient.get_identity({ player });
+      const result = await tx.simulate();
+      return result?.result ?? player;
+    } catch (err) {
+      console.log('[getIdentity] Error querying identity:', err);
+      return player;
+    }
+  }
+
   async setUsernameWithSmartAccount(
     caller: string,
     username: string,
@@ -350,19 +768,25 @@ export class ClashGameService {
     }
   }
 
+  /**
+   * Accept a challenge. The contract allocates the game's session id; it is read
+   * back from the accepted challenge once the transaction lands. `challengeNonce`
+   * is the nonce of the challenge as shown to the player, so a challenge changed
+   * in the meantime is rejected instead of accepted on new terms.
+   */
   async acceptChallengeWithSmartAccount(
     challengeId: number,
     challenged: string,
-    sessionId: number,
+    challengeNonce: number,
     smartAccountService: SmartAccountService
-  ): Promise<void> {
+  ): Promise<number> {
     try {
       await smartAccountService.ensureSigningReady();
       const tx = await this.baseClient.accept_challenge(
         {
           challenge_id: challengeId,
           challenged,
-          session_id: sessionId,
+          challenge_nonce: challengeNonce,
         },
         DEFAULT_METHOD_OPTIONS
       );
@@ -371,12 +795,45 @@ export class ClashGameService {
         clashContractId: this.contractId,
       });
       assertSmartAccountSubmitResult(result, 'accept_challenge');
+
+      const challengeTx = await this.baseClient.get_challenge({ challenge_id: challengeId });
+      const challenge = (await challengeTx.simulate()).result.unwrap();
+      if (challenge.session_id === undefined) {
+        throw new Error('Challenge accepted but no session id was recorded');
+      }
+      return challenge.session_id;
     } catch (error) {
       console.error('❌ accept_challenge failed:', error);
       rethrowWithSmartAccountWasmHint(error, 'accept_challenge');
     }
   }
 
+  /**
+   * Change how a level battle is settled, while the challenge is pending:
+   * 0 sudden death, 1 challenger wins, 2 damage dealt, 3 draw. Bumps the nonce.
+   */
+  async setChallengeTieBreakWithSmartAccount(
+    challengeId: number,
+    tieBreak: number,
+    smartAccountService: SmartAccountService
+  ): Promise<void> {
+    try {
+      await smartAccountService.ensureSigningReady();
+      const tx = await this.baseClient.set_challenge_tie_break(
+        { challenge_id: challengeId, tie_break: tieBreak },
+        DEFAULT_METHOD_OPTIONS
+      );
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'set_challenge_tie_break',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'set_challenge_tie_break');
+    } catch (error) {
+      console.error('❌ set_challenge_tie_break failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'set_challenge_tie_break');
+    }
+  }
+
   // ========================================================================
   // Game Flow 
   // ========================================================================
@@ -1201,7 +1658,11 @@ export class ClashGameService {
   // ZK session game (commit / reveal) — SmartAccount signing
   // ========================================================================
 
-  async startGameWithSmartAccount(
+  /**
+   * `start_game` needs both players' signatures in one transaction, which a passkey
+   * wallet can't gather; propose here and let the opponent confirm with their own.
+   */
+  async proposeGameWithSmartAccount(
     sessionId: number,
     player1: string,
     player2: string,
@@ -1211,8 +1672,8 @@ export class ClashGameService {
   ): Promise<void> {
     try {
       await smartAccountService.ensureSigningReady();
-      console.log('🎮 start_game (ZK session)...');
-      const tx = await this.baseClient.start_game(
+      console.log('🎮 propose_game (ZK session)...');
+      const tx = await this.baseClient.propose_game(
         {
           session_id: sessionId,
           player1,
@@ -1224,14 +1685,174 @@ export class ClashGameService {
       );
 
       const result = await smartAccountService.signAndSubmit(tx, {
-        label: 'start_game',
+        label: 'propose_game',
         clashContractId: this.contractId,
       });
-      assertSmartAccountSubmitResult(result, 'start_game');
-      console.log('✅ Game started on-chain');
+      assertSmartAccountSubmitResult(result, 'propose_game');
+      console.log('✅ Game proposed on-chain');
     } catch (error) {
-      console.error('❌ start_game failed:', error);
-      rethrowWithSmartAccountWasmHint(error, 'start_game');
+      console.error('❌ propose_game failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'propose_game');
+    }
+  }
+
+  /** Player 2 agrees to a proposed game, which starts it and locks both stakes */
+  async confirmGameWithSmartAccount(
+    sessionId: number,
+    player2: string,
+    smartAccountService: SmartAccountService
+  ): Promise<void> {
+    try {
+      await smartAccountService.ensureSigningReady();
+      const tx = await this.baseClient.confirm_game(
+        { session_id: sessionId, player2 },
+        DEFAULT_METHOD_OPTIONS
+      );
+
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'confirm_game',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'confirm_game');
+      console.log('✅ Game confirmed on-chain');
+    } catch (error) {
+      console.error('❌ confirm_game failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'confirm_game');
+    }
+  }
+
+  async getGameProposal(sessionId: number): Promise<{ player1: string; player2: string } | null> {
+    try {
+      const tx = await this.baseClient.get_game_proposal({ session_id: sessionId });
+      const result = await tx.simulate();
+      return result?.result ?? null;
+    } catch (err) {
+      console.log('[getGameProposal] Error querying proposal:', err);
+      return null;
+    }
+  }
+
+  /** Correspondence games only; the opponent accepts with acceptPauseWithSmartAccount */
+  async proposePauseWithSmartAccount(
+    sessionId: number,
+    player: string,
+    durationSecs: number,
+    smartAccountService: SmartAccountService
+  ): Promise<void> {
+    try {
+      await smartAccountService.ensureSigningReady();
+      const tx = await this.baseClient.propose_pause(
+        { session_id: sessionId, player, duration_secs: BigInt(durationSecs) },
+        DEFAULT_METHOD_OPTIONS
+      );
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'propose_pause',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'propose_pause');
+    } catch (error) {
+      console.error('❌ propose_pause failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'propose_pause');
+    }
+  }
+
+  async acceptPauseWithSmartAccount(
+    sessionId: number,
+    player: string,
+    smartAccountService: SmartAccountService
+  ): Promise<void> {
+    try {
+      await smartAccountService.ensureSigningReady();
+      const tx = await this.baseClient.accept_pause(
+        { session_id: sessionId, player },
+        DEFAULT_METHOD_OPTIONS
+      );
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'accept_pause',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'accept_pause');
+    } catch (error) {
+      console.error('❌ accept_pause failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'accept_pause');
+    }
+  }
+
+  async resumeGameWithSmartAccount(
+    sessionId: number,
+    player: string,
+    smartAccountService: SmartAccountService
+  ): Promise<void> {
+    try {
+      await smartAccountService.ensureSigningReady();
+      const tx = await this.baseClient.resume_game(
+        { session_id: sessionId, player },
+        DEFAULT_METHOD_OPTIONS
+      );
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'resume_game',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'resume_game');
+    } catch (error) {
+      console.error('❌ resume_game failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'resume_game');
+    }
+  }
+
+  /** Free call on the winner; open until the second reveal lands */
+  async makePickWithSmartAccount(
+    sessionId: number,
+    spectator: string,
+    backedPlayer: string,
+    smartAccountService: SmartAccountService
+  ): Promise<void> {
+    try {
+      await smartAccountService.ensureSigningReady();
+      const tx = await this.baseClient.make_pick(
+        { session_id: sessionId, spectator, backed_player: backedPlayer },
+        DEFAULT_METHOD_OPTIONS
+      );
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'make_pick',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'make_pick');
+    } catch (error) {
+      console.error('❌ make_pick failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'make_pick');
+    }
+  }
+
+  async getPickStats(spectator: string): Promise<{
+    picks: number;
+    correct: number;
+    streak: number;
+    best_streak: number;
+  } | null> {
+    try {
+      const tx = await this.baseClient.get_pick_stats({ spectator });
+      const result = await tx.simulate();
+      return result?.result ?? null;
+    } catch (err) {
+      console.log('[getPickStats] Error querying pick stats:', err);
+      return null;
+    }
+  }
+
+  async getGamePause(sessionId: number): Promise<{
+    proposed_by: string | undefined;
+    proposed_secs: bigint;
+    paused_until: bigint;
+    used_secs: bigint;
+  } | null> {
+    try {
+      const tx = await this.baseClient.get_game_pause({ session_id: sessionId });
+      const result = await tx.simulate();
+      return result?.result ?? null;
+    } catch (err) {
+      console.log('[getGamePause] Error querying pause:', err);
+      return null;
     }
   }
 
@@ -1317,25 +1938,72 @@ export class ClashGameService {
       });
       assertSmartAccountSubmitResult(result, 'resolve_battle');
       console.log('✅ resolve_battle submitted');
-
-      try {
-        const pb = await this.getGamePlayback(sessionId);
-        if (pb && !pb.is_draw) {
-          const wStr = pb.winner?.toString?.() ?? '';
-          if (wStr) {
-            const loserAddr = wStr === pb.player1 ? pb.player2 : pb.player1;
-            // Must complete before UI refreshes totals — otherwise get_points simulates stale state.
-            await submitPointsRecordAfterResolve(wStr, loserAddr);
-          }
-        }
-      } catch (e) {
-        console.warn('[resolveBattleWithSmartAccount] points tracker hook:', e);
-      }
+      await this.recordPointsAfterResolve(sessionId);
     } catch (error) {
       console.error('❌ resolve_battle failed:', error);
       rethrowWithSmartAccountWasmHint(error, 'resolve_battle');
     }
   }
+
+  /**
+   * Reveal and, when this is the second reveal, resolve in the same transaction.
+   * Returns whether the battle was resolved.
+   */
+  async revealAndResolveWithSmartAccount(
+    sessionId: number,
+    player: string,
+    publicInputs: Uint8Array | Buffer,
+    moves: Move[],
+    smartAccountService: SmartAccountService
+  ): Promise<boolean> {
+    if (moves.length !== 3) {
+      throw new Error('Must provide exactly 3 moves');
+    }
+    try {
+      await smartAccountService.ensureSigningReady();
+      const pub = Buffer.isBuffer(publicInputs) ? publicInputs : Buffer.from(publicInputs);
+      const tx = await this.baseClient.reveal_and_resolve(
+        {
+          session_id: sessionId,
+          player,
+          public_inputs: pub,
+          moves,
+        },
+        DEFAULT_METHOD_OPTIONS
+      );
+
+      const result = await smartAccountService.signAndSubmit(tx, {
+        label: 'reveal_and_resolve',
+        clashContractId: this.contractId,
+      });
+      assertSmartAccountSubmitResult(result, 'reveal_and_resolve');
+      console.log('✅ reveal_and_resolve submitted');
+    } catch (error) {
+      console.error('❌ reveal_and_resolve failed:', error);
+      rethrowWithSmartAccountWasmHint(error, 'reveal_and_resolve');
+    }
+
+    const game = await this.getGame(sessionId);
+    if (!game?.has_battle_result) return false;
+    await this.recordPointsAfterResolve(sessionId);
+    return true;
+  }
+
+  private async recordPointsAfterResolve(sessionId: number): Promise<void> {
+    try {
+      const pb = await this.getGamePlayback(sessionId);
+      if (pb && !pb.is_draw) {
+        const wStr = pb.winner?.toString?.() ?? '';
+        if (wStr) {
+          const loserAddr = wStr === pb.player1 ? pb.player2 : pb.player1;
+          // Must complete before UI refreshes totals — otherwise get_points simulates stale state.
+          await submitPointsRecordAfterResolve(wStr, loserAddr);
+        }
+      }
+    } catch (e) {
+      console.warn('[recordPointsAfterResolve] points tracker hook:', e);
+    }
+  }
 }
 
 
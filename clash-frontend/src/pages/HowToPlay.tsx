@@ -81,7 +81,7 @@ export function HowToPlay({ onBack }: Props) {
                 <td>Cannon Blast</td>
                 <td>🔥</td>
                 <td>40</td>
-                <td>Riposte (Counter)</td>
+                <td>Parry (Counter)</td>
               </tr>
               <tr>
                 <td>Lightning Strike</td>
@@ -89,6 +89,18 @@ export function HowToPlay({ onBack }: Props) {
                 <td>35</td>
                 <td>Raised Shield (Block)</td>
               </tr>
+              <tr>
+                <td>Broadside</td>
+                <td>💣</td>
+                <td>50, never combos</td>
+                <td>Quick Sidestep (Dodge)</td>
+              </tr>
+              <tr>
+                <td>Grappling Hook</td>
+                <td>🪝</td>
+                <td>20, ignores first-turn guard</td>
+                <td>Riposte</td>
+              </tr>
             </tbody>
           </table>
         </div>
@@ -117,10 +129,15 @@ export function HowToPlay({ onBack }: Props) {
                 <td>Cutlass Slash</td>
               </tr>
               <tr>
-                <td>Riposte — Counter</td>
+                <td>Parry — Counter</td>
                 <td>🔄</td>
                 <td>Cannon Blast</td>
               </tr>
+              <tr>
+                <td>Riposte</td>
+                <td>🤺</td>
+                <td>Grappling Hook, and sends half its damage back</td>
+              </tr>
             </tbody>
           </table>
         </div>
@@ -128,6 +145,10 @@ export function HowToPlay({ onBack }: Props) {
         <p className="howto-p howto-note">
           Example: if you Dodge but they Lightning Strike, Dodge does <strong>not</strong> stop lightning — you take 35.
         </p>
+        <p className="howto-p howto-note">
+          Broadside, Grappling Hook and Riposte can't be picked in the ZK arena yet: the commit circuit still only proves
+          the first three attacks and defenses.
+        </p>
       </section>
 
       <section className="howto-section" aria-labelledby="howto-combo">
@@ -157,7 +178,7 @@ export function HowToPlay({ onBack }: Props) {
         </h2>
         <ul className="howto-bullets">
           <li>
-            <strong>Risk vs reward:</strong> Cannon Blast hits hardest but is easier to shut down with Counter.
+            <strong>Risk vs reward:</strong> Cannon Blast hits hard but is easier to shut down with Counter.
           </li>
           <li>
             <strong>Prediction:</strong> try to read their attack pattern across three rounds — commit phase hides it,
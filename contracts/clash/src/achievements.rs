@@ -0,0 +1,116 @@
+//! Badges for milestones, awarded when `resolve_battle` settles a battle so the
+//! frontend can show them without an indexer. Each badge is earned once, and keeps
+//! the session and time it was earned in.
+//!
+//! Milestones that depend on the ladder (first win, streaks, battles played) read the
+//! rated record, so sieges don't count towards them. Flawless victories and combo
+//! finishers are judged from the battle itself. Spectators earn the pick-streak
+//! badges from their free picks (see `picks`).
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::ratings;
+use crate::storage;
+use crate::{Achievement, AchievementUnlocked, Attack, EarnedAchievement, Game, Move};
+
+/// Same attacks in a row for the top combo bonus
+const TRIPLE_COMBO: u32 = 3;
+
+/// Rated wins in a row for [`Achievement::WinStreak3`]
+const WIN_STREAK: i32 = 3;
+
+/// Rated battles for [`Achievement::TenBattles`]
+const VETERAN_BATTLES: u32 = 10;
+
+/// Correct spectator picks in a row for [`Achievement::SharpEye`]
+const SHARP_EYE_STREAK: u32 = 3;
+
+/// Correct spectator picks in a row for [`Achievement::Oracle`]
+const ORACLE_STREAK: u32 = 10;
+
+/// Check both players of a freshly resolved battle for new badges
+pub(crate) fn record_battle(env: &Env, session_id: u32, game: &Game) {
+    let result = &game.battle_result;
+    for (player, hp, moves) in [
+        (&game.player1, result.player1_hp, &game.player1_commitment.moves.moves),
+        (&game.player2, result.player2_hp, &game.player2_commitment.moves.moves),
+    ] {
+        let stats = ratings::player_stats(env, player);
+        let won = result.winner.as_ref() == Some(player);
+        let played = stats.wins + stats.losses + stats.draws;
+
+        let mut earned = storage::achievements(env, player);
+        let already_earned = earned.len();
+        let mut award = |achievement| award_once(env, &mut earned, player, achievement, session_id);
+        if won && stats.wins >= 1 {
+            award(Achievement::FirstWin);
+        }
+        if won && stats.streak >= WIN_STREAK {
+            award(Achievement::WinStreak3);
+        }
+        if won && hp == game.rules.starting_hp {
+            award(Achievement::Flawless);
+        }
+        if won && finished_with_triple_combo(game, moves) {
+            award(Achievement::TripleComboFinisher);
+        }
+        if played >= VETERAN_BATTLES {
+            award(Achievement::TenBattles);
+        }
+        if earned.len() > already_earned {
+            storage::save_achievements(env, player, &earned);
+        }
+    }
+}
+
+/// Check a spectator whose pick just came good for a streak badge
+pub(crate) fn record_pick_streak(env: &Env, session_id: u32, spectator: &Address, streak: u32) {
+    let mut earned = storage::achievements(env, spectator);
+    let already_earned = earned.len();
+    if streak >= SHARP_EYE_STREAK {
+        award_once(env, &mut earned, spectator, Achievement::SharpEye, session_id);
+    }
+    if streak >= ORACLE_STREAK {
+        award_once(env, &mut earned, spectator, Achievement::Oracle, session_id);
+    }
+    if earned.len() > already_earned {
+        storage::save_achievements(env, spectator, &earned);
+    }
+}
+
+fn award_once(
+    env: &Env,
+    earned: &mut Vec<EarnedAchievement>,
+    player: &Address,
+    achievement: Achievement,
+    session_id: u32,
+) {
+    if earned.iter().any(|badge| badge.achievement == achievement) {
+        return;
+    }
+    earned.push_back(EarnedAchievement {
+        achievement,
+        session_id,
+        earned_at: env.ledger().timestamp(),
+    });
+    AchievementUnlocked {
+        player: player.clone(),
+        achievement,
+        session_id,
+    }
+    .publish(env);
+}
+
+/// The knockout blow was the third of the same attack in a row. Broadside never
+/// combos, and overtime turns replay the moves, so neither counts.
+fn finished_with_triple_combo(game: &Game, moves: &Vec<Move>) -> bool {
+    let result = &game.battle_result;
+    let knockout = result.player1_hp <= 0 || result.player2_hp <= 0;
+    let turns = result.turn_results.len();
+    if !knockout || result.went_to_overtime || turns < TRIPLE_COMBO {
+        return false;
+    }
+    let last = moves.get(turns - 1).unwrap().attack;
+    last != Attack::Broadside
+        && (turns - TRIPLE_COMBO..turns).all(|turn| moves.get(turn).unwrap().attack == last)
+}
@@ -0,0 +1,54 @@
+//! Delta sync: every player has an activity log of the games, challenges, duels and
+//! team games that changed for them, each tagged with a per-player sequence number.
+//! A frontend keeps the last `last_activity_seq` it saw and polls
+//! `get_updates_since` instead of re-fetching whole lists.
+//!
+//! Changes are recorded by the storage layer whenever one of those entities is
+//! saved, so no write path can skip them. The log keeps the latest change per
+//! entity and the most recent `ACTIVITY_LOG_SIZE` entities; a client that fell
+//! further behind is told to fetch everything again.
+
+use soroban_sdk::{vec, Address, Env};
+
+use crate::storage;
+use crate::{ActivityKind, EntityChange, PlayerUpdates};
+
+/// Most entities kept in a player's activity log
+const ACTIVITY_LOG_SIZE: u32 = 30;
+
+/// Bump `player`'s sequence and mark the entity as changed at it
+pub(crate) fn touch(env: &Env, player: &Address, kind: ActivityKind, id: u32) {
+    let mut log = storage::activity_log(env, player);
+    log.last_activity_seq += 1;
+    if let Some(index) = log
+        .changes
+        .iter()
+        .position(|change| change.kind == kind && change.id == id)
+    {
+        log.changes.remove(index as u32);
+    } else if log.changes.len() >= ACTIVITY_LOG_SIZE {
+        log.dropped_seq = log.changes.pop_front_unchecked().seq;
+    }
+    log.changes.push_back(EntityChange {
+        kind,
+        id,
+        seq: log.last_activity_seq,
+    });
+    storage::save_activity_log(env, player, &log);
+}
+
+/// Entities changed for `player` after `seq`, oldest change first
+pub(crate) fn get_updates_since(env: &Env, player: &Address, seq: u64) -> PlayerUpdates {
+    let log = storage::activity_log(env, player);
+    let mut changes = vec![env];
+    for change in log.changes.iter() {
+        if change.seq > seq {
+            changes.push_back(change);
+        }
+    }
+    PlayerUpdates {
+        last_activity_seq: log.last_activity_seq,
+        is_complete: seq >= log.dropped_seq,
+        changes,
+    }
+}
@@ -0,0 +1,138 @@
+//! Agreed pauses for correspondence games: one player proposes a break with
+//! `propose_pause`, the other accepts it, and the game's deadlines move out by the
+//! agreed length, so nobody can claim a timeout while the pause runs. Either player
+//! can resume early, which pulls the deadlines back by the time left and returns it
+//! to the allowance.
+//!
+//! The paused time over a whole game is capped by the admin's maximum. Moves can
+//! still be made while paused; only the clock stops, so a reveal window that opens
+//! during a pause starts when the pause ends.
+
+use soroban_sdk::{Address, Env};
+
+use crate::game;
+use crate::storage;
+use crate::{Error, Game, GameMode, GamePause, GamePaused, GameResumed, GameWindow};
+
+/// Default most a game can spend paused, over all its pauses
+pub(crate) const DEFAULT_MAX_GAME_PAUSE_SECS: u64 = 14 * 24 * 60 * 60;
+
+/// Ask the opponent for a pause of `duration_secs`, replacing any earlier proposal
+pub(crate) fn propose_pause(env: &Env, session_id: u32, player: Address, duration_secs: u64) -> Result<(), Error> {
+    player.require_auth();
+
+    pausable_game(env, session_id, &player)?;
+    let mut pause = storage::game_pause(env, session_id);
+    check_allowance(env, &pause, duration_secs)?;
+    if pause.paused_until > env.ledger().timestamp() {
+        return Err(Error::InvalidPause);
+    }
+    pause.proposed_by = Some(player);
+    pause.proposed_secs = duration_secs;
+    storage::save_game_pause(env, session_id, &pause);
+    Ok(())
+}
+
+/// Accept the opponent's proposal: the pause starts now and every open deadline
+/// moves out by its length
+pub(crate) fn accept_pause(env: &Env, session_id: u32, player: Address) -> Result<u64, Error> {
+    player.require_auth();
+
+    let mut game = pausable_game(env, session_id, &player)?;
+    let mut pause = storage::game_pause(env, session_id);
+    match &pause.proposed_by {
+        Some(proposer) if *proposer != player => {}
+        _ => return Err(Error::InvalidPause),
+    }
+    let duration = pause.proposed_secs;
+    check_allowance(env, &pause, duration)?;
+
+    let now = env.ledger().timestamp();
+    shift_deadlines(&mut game, |deadline| deadline + duration);
+    pause.proposed_by = None;
+    pause.proposed_secs = 0;
+    pause.paused_until = now + duration;
+    pause.used_secs += duration;
+    save(env, session_id, &game, &pause);
+    GamePaused {
+        session_id,
+        paused_until: pause.paused_until,
+    }
+    .publish(env);
+    Ok(pause.paused_until)
+}
+
+/// End a running pause early; the unused time comes off the deadlines and back into
+/// the allowance
+pub(crate) fn resume_game(env: &Env, session_id: u32, player: Address) -> Result<(), Error> {
+    player.require_auth();
+
+    let mut game = pausable_game(env, session_id, &player)?;
+    let mut pause = storage::game_pause(env, session_id);
+    let now = env.ledger().timestamp();
+    if pause.paused_until <= now {
+        return Err(Error::InvalidPause);
+    }
+
+    let remaining = pause.paused_until - now;
+    shift_deadlines(&mut game, |deadline| deadline - remaining);
+    pause.paused_until = now;
+    pause.used_secs -= remaining;
+    save(env, session_id, &game, &pause);
+    GameResumed {
+        session_id,
+        resumed_by: player,
+    }
+    .publish(env);
+    Ok(())
+}
+
+/// When a window opening now starts counting: the end of a running pause, if any.
+/// A deadline set this way moves with the pause like the ones it already covered.
+pub(crate) fn clock_start(env: &Env, session_id: u32) -> u64 {
+    let now = env.ledger().timestamp();
+    now.max(storage::game_pause(env, session_id).paused_until)
+}
+
+/// The unfinished correspondence game `player` is in
+fn pausable_game(env: &Env, session_id: u32, player: &Address) -> Result<Game, Error> {
+    let game = storage::load_game(env, session_id)?;
+    if *player != game.player1 && *player != game.player2 {
+        return Err(Error::NotPlayer);
+    }
+    if game.has_battle_result {
+        return Err(Error::GameAlreadyEnded);
+    }
+    if game.mode != GameMode::Correspondence {
+        return Err(Error::InvalidPause);
+    }
+    Ok(game)
+}
+
+fn check_allowance(env: &Env, pause: &GamePause, duration_secs: u64) -> Result<(), Error> {
+    if duration_secs == 0 || pause.used_secs + duration_secs > storage::max_game_pause_secs(env) {
+        return Err(Error::InvalidPause);
+    }
+    Ok(())
+}
+
+/// Move every deadline that is set; 0 still means "no deadline"
+fn shift_deadlines(game: &mut Game, shift: impl Fn(u64) -> u64) {
+    if game.commit_deadline != 0 {
+        game.commit_deadline = shift(game.commit_deadline);
+    }
+    if game.reveal_deadline != 0 {
+        game.reveal_deadline = shift(game.reveal_deadline);
+    }
+}
+
+/// Store both, and announce the open window's moved deadline
+fn save(env: &Env, session_id: u32, game: &Game, pause: &GamePause) {
+    storage::save_game(env, session_id, game);
+    storage::save_game_pause(env, session_id, pause);
+    if game.reveal_deadline != 0 {
+        game::announce_window(env, session_id, game, GameWindow::Reveal, game.reveal_deadline);
+    } else {
+        game::announce_window(env, session_id, game, GameWindow::Commit, game.commit_deadline);
+    }
+}
@@ -0,0 +1,121 @@
+//! "Your move" tracking: each player's live games and duels, and which of them are
+//! waiting on that player to commit or reveal.
+//!
+//! Sessions are added when a game or duel starts and dropped when it settles, is
+//! voided or expires. A player can be in at most `MAX_LIVE_SESSIONS` at once, which
+//! keeps the index, and every read of it, bounded; a game left to lapse holds its
+//! slot until someone calls `expire_game` on it. The same index answers "what games
+//! am I in?" for the lobby.
+
+use soroban_sdk::{vec, Address, Env, Vec};
+
+use crate::badges;
+use crate::pause;
+use crate::storage;
+use crate::{DuelPhase, Error, Game, RaiseStatus};
+
+/// Most unsettled games and duels one player can be in
+pub(crate) const MAX_LIVE_SESSIONS: u32 = 30;
+
+/// Refuse a new game or duel if either player is already in as many as they can be
+pub(crate) fn check_room(env: &Env, player1: &Address, player2: &Address) -> Result<(), Error> {
+    for player in [player1, player2] {
+        if storage::player_sessions(env, player).len() >= MAX_LIVE_SESSIONS {
+            return Err(Error::TooManyLiveSessions);
+        }
+    }
+    Ok(())
+}
+
+/// Add a newly started game or duel to both players' sessions
+pub(crate) fn track(env: &Env, session_id: u32, player1: &Address, player2: &Address) {
+    for player in [player1, player2] {
+        let mut sessions = storage::player_sessions(env, player);
+        sessions.push_back(session_id);
+        storage::save_player_sessions(env, player, &sessions);
+    }
+}
+
+/// Drop a settled game or duel from both players' sessions
+pub(crate) fn untrack(env: &Env, session_id: u32, player1: &Address, player2: &Address) {
+    badges::clear_awaiting(env, session_id, player1, player2);
+    for player in [player1, player2] {
+        let mut sessions = storage::player_sessions(env, player);
+        if let Some(index) = sessions.first_index_of(session_id) {
+            sessions.remove(index);
+            storage::save_player_sessions(env, player, &sessions);
+        }
+    }
+}
+
+/// Up to `limit` of `player`'s live sessions from `offset`, oldest first
+fn sessions_page(env: &Env, player: &Address, offset: u32, limit: u32) -> Vec<u32> {
+    let sessions = storage::player_sessions(env, player);
+    let start = offset.min(sessions.len());
+    let end = start.saturating_add(limit).min(sessions.len());
+    sessions.slice(start..end)
+}
+
+/// The unsettled games (duels excluded) among a page of `player`'s sessions
+pub(crate) fn get_active_games(env: &Env, player: &Address, offset: u32, limit: u32) -> Vec<u32> {
+    let mut games = vec![env];
+    for session_id in sessions_page(env, player, offset, limit).iter() {
+        if storage::load_game(env, session_id).is_ok_and(|game| !game.is_settled) {
+            games.push_back(session_id);
+        }
+    }
+    games
+}
+
+/// The sessions among a page of `player`'s where it is their turn: a commit, reveal
+/// or raise answer is due from them and its deadline hasn't passed
+pub(crate) fn get_awaiting_action(env: &Env, player: &Address, offset: u32, limit: u32) -> Vec<u32> {
+    let mut awaiting = vec![env];
+    for session_id in sessions_page(env, player, offset, limit).iter() {
+        let waiting_on_player = if let Ok(game) = storage::load_game(env, session_id) {
+            game_awaits(env, &game, player)
+        } else if let Ok(duel) = storage::load_duel(env, session_id) {
+            let turn = if *player == duel.player1 {
+                &duel.player1_turn
+            } else {
+                &duel.player2_turn
+            };
+            let done = match duel.phase {
+                DuelPhase::Commit => turn.has_committed,
+                DuelPhase::Reveal => turn.has_revealed,
+                DuelPhase::Finished => true,
+            };
+            !done && env.ledger().timestamp() <= pause::deadline(env, duel.deadline)
+        } else {
+            false
+        };
+        if waiting_on_player {
+            awaiting.push_back(session_id);
+        }
+    }
+    awaiting
+}
+
+fn game_awaits(env: &Env, game: &Game, player: &Address) -> bool {
+    if game.has_battle_result {
+        return false;
+    }
+    let (committed, revealed) = if *player == game.player1 {
+        (game.has_player1_commitment, game.player1_commitment.has_revealed)
+    } else {
+        (game.has_player2_commitment, game.player2_commitment.has_revealed)
+    };
+    let now = env.ledger().timestamp();
+
+    if !committed {
+        return game.commit_deadline == 0 || now <= pause::deadline(env, game.commit_deadline);
+    }
+    if !game.has_player1_commitment || !game.has_player2_commitment {
+        return false;
+    }
+    // Reveals wait until the raise is answered, which is on the other player
+    if game.raise.status == RaiseStatus::Pending {
+        return game.raise.raiser.as_ref() != Some(player) && now <= pause::deadline(env, game.reveal_deadline);
+    }
+    !revealed && now <= pause::deadline(env, game.reveal_deadline)
+}
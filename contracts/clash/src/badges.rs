@@ -0,0 +1,99 @@
+//! Navbar badges: per-player counts of incoming challenges still pending, live games
+//! and duels waiting on the player, and payouts left to claim. The entrypoints that
+//! change one of them adjust the count as they go, so `get_badges` is a single read.
+//!
+//! The counts follow state changes, not the clock: a challenge that lapses
+//! unanswered still counts until it is declined or cancelled, and a game whose
+//! deadline has passed still counts until it settles. `get_player_challenges` and
+//! `get_awaiting_action` give the exact lists.
+
+use soroban_sdk::{Address, Env};
+
+use crate::storage;
+use crate::{Badges, Duel, DuelPhase, DuelTurn, Game};
+
+pub(crate) fn challenge_opened(env: &Env, challenged: &Address) {
+    adjust(env, challenged, |badges| badges.pending_challenges += 1);
+}
+
+pub(crate) fn challenge_closed(env: &Env, challenged: &Address) {
+    adjust(env, challenged, |badges| {
+        badges.pending_challenges = badges.pending_challenges.saturating_sub(1)
+    });
+}
+
+/// `player` has something new to claim: a session's winnings or a referral bonus
+pub(crate) fn reward_credited(env: &Env, player: &Address) {
+    adjust(env, player, |badges| badges.unclaimed_rewards += 1);
+}
+
+pub(crate) fn reward_claimed(env: &Env, player: &Address) {
+    adjust(env, player, |badges| {
+        badges.unclaimed_rewards = badges.unclaimed_rewards.saturating_sub(1)
+    });
+}
+
+/// Count the game for each player who still owes it a commit or reveal
+pub(crate) fn sync_game(env: &Env, session_id: u32, game: &Game) {
+    let both_committed = game.has_player1_commitment && game.has_player2_commitment;
+    let owes = |committed: bool, revealed: bool| {
+        !game.has_battle_result && (!committed || (both_committed && !revealed))
+    };
+    set_awaiting(
+        env,
+        session_id,
+        [&game.player1, &game.player2],
+        [
+            owes(game.has_player1_commitment, game.player1_commitment.has_revealed),
+            owes(game.has_player2_commitment, game.player2_commitment.has_revealed),
+        ],
+    );
+}
+
+/// Count the duel for each player who still owes the current round a move
+pub(crate) fn sync_duel(env: &Env, session_id: u32, duel: &Duel) {
+    let owes = |turn: &DuelTurn| match duel.phase {
+        DuelPhase::Commit => !turn.has_committed,
+        DuelPhase::Reveal => !turn.has_revealed,
+        DuelPhase::Finished => false,
+    };
+    set_awaiting(
+        env,
+        session_id,
+        [&duel.player1, &duel.player2],
+        [owes(&duel.player1_turn), owes(&duel.player2_turn)],
+    );
+}
+
+/// Stop counting a settled or cancelled session for either player
+pub(crate) fn clear_awaiting(env: &Env, session_id: u32, player1: &Address, player2: &Address) {
+    set_awaiting(env, session_id, [player1, player2], [false, false]);
+}
+
+/// Move each player's count by what changed since the session was last counted
+fn set_awaiting(env: &Env, session_id: u32, players: [&Address; 2], owes: [bool; 2]) {
+    let before = storage::badge_owed(env, session_id);
+    let mut after = 0;
+    for (i, (player, owes)) in players.into_iter().zip(owes).enumerate() {
+        let bit = 1 << i;
+        if owes {
+            after |= bit;
+        }
+        match (before & bit != 0, owes) {
+            (false, true) => adjust(env, player, |badges| badges.awaiting_action += 1),
+            (true, false) => adjust(env, player, |badges| {
+                badges.awaiting_action = badges.awaiting_action.saturating_sub(1)
+            }),
+            _ => {}
+        }
+    }
+    if after != before {
+        storage::save_badge_owed(env, session_id, after);
+    }
+}
+
+fn adjust(env: &Env, player: &Address, update: impl FnOnce(&mut Badges)) {
+    let mut badges = storage::badges(env, player);
+    update(&mut badges);
+    storage::save_badges(env, player, &badges);
+}
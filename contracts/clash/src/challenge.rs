@@ -0,0 +1,644 @@
+//! Challenge system: players challenge each other and accepting starts a game.
+
+use soroban_sdk::{vec, Address, Env, Vec};
+
+use crate::badges;
+use crate::storage;
+use crate::{
+    fees, game, moderation, rivals, Challenge, ChallengeAccepted, ChallengeQuote, ChallengeSent,
+    ChallengeStatus, Error, GameMode, InviteCode, OpenChallenge, TieBreak, CSH_REWARD_PER_WIN,
+};
+
+/// How long a challenge stays open (7 days in seconds)
+pub(crate) const CHALLENGE_DURATION_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// Most challenges the open board holds at once, so listing it stays within one
+/// transaction's ledger entry budget
+const MAX_OPEN_CHALLENGES: u32 = 50;
+
+/// Most live open challenges one player can have posted, so nobody can fill the
+/// board alone
+const MAX_OPEN_CHALLENGES_PER_PLAYER: u32 = 3;
+
+/// Most challenge ids kept in a player's list, so `get_player_challenges` stays
+/// within one transaction's ledger entry budget
+const MAX_PLAYER_CHALLENGES: u32 = 50;
+
+/// Oldest list entries checked for a finished challenge before evicting the oldest
+/// one outright
+const EVICTION_SCAN: u32 = 5;
+
+/// Send a challenge to another player
+pub(crate) fn send_challenge(
+    env: &Env,
+    challenger: Address,
+    challenged: Address,
+    points_wagered: i128,
+) -> Result<u32, Error> {
+    challenger.require_auth();
+
+    // Cannot challenge self
+    if challenger == challenged {
+        return Err(Error::CannotChallengeSelf);
+    }
+    game::validate_wager(env, points_wagered)?;
+
+    let challenge_id = storage::next_challenge_id(env);
+
+    // Create challenge (expires in 7 days)
+    let current_time = env.ledger().timestamp();
+    let challenge = Challenge {
+        challenge_id,
+        challenger: challenger.clone(),
+        challenged: challenged.clone(),
+        points_wagered,
+        created_at: current_time,
+        expires_at: current_time + CHALLENGE_DURATION_SECS,
+        is_accepted: false,
+        is_completed: false,
+        session_id: None,
+        forwarded_from: None,
+        status: ChallengeStatus::Pending,
+        is_rival_match: rivals::is_rivalry(env, &challenger, &challenged),
+        created_ledger: env.ledger().sequence(),
+        nonce: 0,
+        tie_break: storage::rule_set(env).tie_break,
+    };
+
+    // Store challenge
+    storage::store_new_challenge(env, &challenge);
+
+    // Add to both players' lists
+    list_for_player(env, &challenged, challenge_id);
+    list_for_player(env, &challenger, challenge_id);
+    badges::challenge_opened(env, &challenged);
+
+    ChallengeSent {
+        challenge_id,
+        challenger,
+        challenged,
+        points_wagered,
+    }
+    .publish(env);
+
+    Ok(challenge_id)
+}
+
+/// Everything `send_challenge` and the game it starts would apply right now
+pub(crate) fn quote_challenge(
+    env: &Env,
+    points_wagered: i128,
+    mode: GameMode,
+) -> Result<ChallengeQuote, Error> {
+    game::validate_wager(env, points_wagered)?;
+
+    let deposit = storage::commit_deposit(env);
+    let raise = storage::raise_config(env);
+    let max_raise = raise.as_ref().map(|r| r.max_amount).unwrap_or(0);
+    Ok(ChallengeQuote {
+        points_wagered,
+        payout: points_wagered * 2,
+        win_reward: if storage::token_contract(env).is_some() {
+            CSH_REWARD_PER_WIN
+        } else {
+            0
+        },
+        deposit_token: deposit.as_ref().map(|d| d.token.clone()),
+        deposit_amount: deposit.map(|d| d.amount).unwrap_or(0),
+        raise_token: raise.map(|r| r.token),
+        max_raise,
+        max_raise_fee: fees::quote(&storage::fee_schedule(env), max_raise),
+        expires_at: env.ledger().timestamp() + CHALLENGE_DURATION_SECS,
+        commit_window_secs: match mode {
+            GameMode::Correspondence => game::CORRESPONDENCE_WINDOW_SECS,
+            GameMode::Classic | GameMode::Siege => 0,
+        },
+        reveal_window_secs: game::reveal_window(env, mode),
+    })
+}
+
+/// Accept a challenge and start a game on a freshly allocated session.
+///
+/// The game is started on the Game Hub *before* the challenge is marked accepted;
+/// if the hub call fails the whole call fails and the challenge stays open.
+pub(crate) fn accept_challenge(
+    env: &Env,
+    challenge_id: u32,
+    challenged: Address,
+    challenge_nonce: u32,
+) -> Result<u32, Error> {
+    challenged.require_auth();
+
+    let mut challenge = storage::load_challenge(env, challenge_id)?;
+
+    // Verify challenged is correct player
+    if challenge.challenged != challenged {
+        return Err(Error::NotPlayer);
+    }
+
+    check_pending(&challenge)?;
+
+    // An acceptance signed against an earlier version of the challenge can't be
+    // replayed onto the current one
+    if challenge.nonce != challenge_nonce {
+        return Err(Error::StaleChallenge);
+    }
+
+    // Check if expired
+    if env.ledger().timestamp() > challenge.expires_at {
+        return Err(Error::ChallengeExpired);
+    }
+
+    // Limits may have changed since the challenge was sent
+    game::validate_wager(env, challenge.points_wagered)?;
+
+    // Start game with wagered points. Challenger auth was already provided when
+    // sending challenge, so acceptance should not require challenger to sign again.
+    let session_id = start_challenge_game(
+        env,
+        challenge.challenger.clone(),
+        challenge.challenged.clone(),
+        challenge.points_wagered,
+        Some(challenge.tie_break),
+    )?;
+
+    // Only now that the game exists, mark as accepted
+    challenge.is_accepted = true;
+    challenge.status = ChallengeStatus::Accepted;
+    challenge.session_id = Some(session_id);
+    storage::save_challenge(env, &challenge);
+    storage::save_challenge_by_session(env, session_id, challenge_id);
+    badges::challenge_closed(env, &challenged);
+
+    ChallengeAccepted {
+        challenge_id,
+        session_id,
+        challenged,
+        challenger: challenge.challenger,
+    }
+    .publish(env);
+
+    Ok(session_id)
+}
+
+/// The challenger picks the tie-break for the game; the nonce moves so an
+/// acceptance signed against the old terms fails
+pub(crate) fn set_tie_break(env: &Env, challenge_id: u32, tie_break: TieBreak) -> Result<(), Error> {
+    let mut challenge = storage::load_challenge(env, challenge_id)?;
+    challenge.challenger.require_auth();
+
+    check_pending(&challenge)?;
+    if env.ledger().timestamp() > challenge.expires_at {
+        return Err(Error::ChallengeExpired);
+    }
+    challenge.tie_break = tie_break;
+    challenge.nonce += 1;
+    storage::save_challenge(env, &challenge);
+    Ok(())
+}
+
+/// Pass an open challenge on to `to`, keeping the wager and expiry
+pub(crate) fn forward_challenge(env: &Env, challenge_id: u32, to: Address) -> Result<(), Error> {
+    let mut challenge = storage::load_challenge(env, challenge_id)?;
+    challenge.challenged.require_auth();
+
+    if challenge.forwarded_from.is_some() {
+        return Err(Error::ChallengeAlreadyForwarded);
+    }
+    check_pending(&challenge)?;
+    if env.ledger().timestamp() > challenge.expires_at {
+        return Err(Error::ChallengeExpired);
+    }
+    if to == challenge.challenger || to == challenge.challenged {
+        return Err(Error::CannotChallengeSelf);
+    }
+
+    challenge.forwarded_from = Some(challenge.challenged.clone());
+    challenge.challenged = to.clone();
+    challenge.is_rival_match = rivals::is_rivalry(env, &challenge.challenger, &to);
+    challenge.nonce += 1;
+    storage::save_challenge(env, &challenge);
+
+    // The original player keeps it in their list, marked as forwarded
+    list_for_player(env, &to, challenge_id);
+    if let Some(original) = &challenge.forwarded_from {
+        badges::challenge_closed(env, original);
+    }
+    badges::challenge_opened(env, &to);
+
+    Ok(())
+}
+
+/// The challenged player turns a pending challenge down
+pub(crate) fn decline_challenge(
+    env: &Env,
+    challenge_id: u32,
+    challenged: Address,
+) -> Result<(), Error> {
+    challenged.require_auth();
+
+    let challenge = storage::load_challenge(env, challenge_id)?;
+    if challenge.challenged != challenged {
+        return Err(Error::NotPlayer);
+    }
+    close(env, challenge, ChallengeStatus::Declined)
+}
+
+/// The challenger withdraws a challenge nobody has accepted yet
+pub(crate) fn cancel_challenge(
+    env: &Env,
+    challenge_id: u32,
+    challenger: Address,
+) -> Result<(), Error> {
+    challenger.require_auth();
+
+    let challenge = storage::load_challenge(env, challenge_id)?;
+    if challenge.challenger != challenger {
+        return Err(Error::NotPlayer);
+    }
+    close(env, challenge, ChallengeStatus::Cancelled)
+}
+
+/// Record why a pending challenge ended and drop it from everyone's lists. The
+/// challenge itself stays readable with `get_challenge`.
+fn close(env: &Env, mut challenge: Challenge, status: ChallengeStatus) -> Result<(), Error> {
+    check_pending(&challenge)?;
+
+    challenge.status = status;
+    storage::save_challenge(env, &challenge);
+
+    let challenge_id = challenge.challenge_id;
+    storage::remove_player_challenge(env, &challenge.challenger, challenge_id);
+    storage::remove_player_challenge(env, &challenge.challenged, challenge_id);
+    if let Some(original) = &challenge.forwarded_from {
+        storage::remove_player_challenge(env, original, challenge_id);
+    }
+    badges::challenge_closed(env, &challenge.challenged);
+    Ok(())
+}
+
+/// Only pending challenges can be accepted, forwarded, declined or cancelled
+fn check_pending(challenge: &Challenge) -> Result<(), Error> {
+    match challenge.status {
+        ChallengeStatus::Pending => Ok(()),
+        ChallengeStatus::Accepted | ChallengeStatus::Completed => {
+            Err(Error::ChallengeAlreadyAccepted)
+        }
+        ChallengeStatus::Declined | ChallengeStatus::Cancelled => Err(Error::ChallengeClosed),
+    }
+}
+
+/// Post a challenge to the open board for anyone to accept. Expired or vanished
+/// entries are dropped from the board first to make room.
+pub(crate) fn post_open_challenge(
+    env: &Env,
+    challenger: Address,
+    points_wagered: i128,
+) -> Result<u32, Error> {
+    challenger.require_auth();
+    game::validate_wager(env, points_wagered)?;
+
+    let now = env.ledger().timestamp();
+    let mut board = vec![env];
+    let mut posted = 0;
+    for challenge_id in storage::open_challenges(env).iter() {
+        if let Some(open) = storage::load_open_challenge(env, challenge_id) {
+            if now <= open.expires_at {
+                board.push_back(challenge_id);
+                if open.challenger == challenger {
+                    posted += 1;
+                }
+            }
+        }
+    }
+    if board.len() >= MAX_OPEN_CHALLENGES || posted >= MAX_OPEN_CHALLENGES_PER_PLAYER {
+        return Err(Error::TooManyOpenChallenges);
+    }
+
+    let challenge_id = storage::next_challenge_id(env);
+    let open = OpenChallenge {
+        challenge_id,
+        challenger: challenger.clone(),
+        points_wagered,
+        created_at: now,
+        expires_at: now + CHALLENGE_DURATION_SECS,
+    };
+    storage::store_new_open_challenge(env, &open);
+    board.push_back(challenge_id);
+    storage::save_open_challenges(env, &board);
+
+    Ok(challenge_id)
+}
+
+/// Live open challenges with a wager in `min_wager..=max_wager`; `offset` and
+/// `limit` count matching entries
+pub(crate) fn get_open_challenges(
+    env: &Env,
+    offset: u32,
+    limit: u32,
+    min_wager: i128,
+    max_wager: i128,
+) -> Vec<OpenChallenge> {
+    let now = env.ledger().timestamp();
+    let mut page = vec![env];
+    let mut skipped = 0;
+    for challenge_id in storage::open_challenges(env).iter() {
+        if page.len() >= limit {
+            break;
+        }
+        let Some(open) = storage::load_open_challenge(env, challenge_id) else {
+            continue;
+        };
+        if now > open.expires_at || !(min_wager..=max_wager).contains(&open.points_wagered) {
+            continue;
+        }
+        // Shadowed posters stay off the board without being told
+        if moderation::is_shadowed(env, &open.challenger) {
+            continue;
+        }
+        if skipped < offset {
+            skipped += 1;
+            continue;
+        }
+        page.push_back(open);
+    }
+    page
+}
+
+/// Take an open challenge off the board and start a game against its poster. From
+/// here on it is a regular accepted challenge, listed for both players.
+pub(crate) fn accept_open_challenge(
+    env: &Env,
+    challenge_id: u32,
+    acceptor: Address,
+) -> Result<u32, Error> {
+    acceptor.require_auth();
+
+    let open = storage::load_open_challenge(env, challenge_id).ok_or(Error::ChallengeNotFound)?;
+    if open.challenger == acceptor {
+        return Err(Error::CannotChallengeSelf);
+    }
+    if env.ledger().timestamp() > open.expires_at {
+        return Err(Error::ChallengeExpired);
+    }
+    game::validate_wager(env, open.points_wagered)?;
+
+    let session_id =
+        start_challenge_game(env, open.challenger.clone(), acceptor.clone(), open.points_wagered, None)?;
+    take_off_board(env, challenge_id);
+
+    let challenge = Challenge {
+        challenge_id,
+        challenger: open.challenger.clone(),
+        challenged: acceptor.clone(),
+        points_wagered: open.points_wagered,
+        created_at: open.created_at,
+        expires_at: open.expires_at,
+        is_accepted: true,
+        is_completed: false,
+        session_id: Some(session_id),
+        forwarded_from: None,
+        status: ChallengeStatus::Accepted,
+        is_rival_match: rivals::is_rivalry(env, &open.challenger, &acceptor),
+        created_ledger: env.ledger().sequence(),
+        nonce: 0,
+        tie_break: storage::rule_set(env).tie_break,
+    };
+    storage::store_new_challenge(env, &challenge);
+    storage::save_challenge_by_session(env, session_id, challenge_id);
+    list_for_player(env, &open.challenger, challenge_id);
+    list_for_player(env, &acceptor, challenge_id);
+
+    ChallengeAccepted {
+        challenge_id,
+        session_id,
+        challenged: acceptor,
+        challenger: open.challenger,
+    }
+    .publish(env);
+
+    Ok(session_id)
+}
+
+/// Withdraw an open challenge its poster no longer wants taken
+pub(crate) fn cancel_open_challenge(env: &Env, challenge_id: u32, challenger: Address) -> Result<(), Error> {
+    challenger.require_auth();
+
+    let open = storage::load_open_challenge(env, challenge_id).ok_or(Error::ChallengeNotFound)?;
+    if open.challenger != challenger {
+        return Err(Error::NotPlayer);
+    }
+    take_off_board(env, challenge_id);
+    Ok(())
+}
+
+/// Remove an open challenge and its place on the board
+fn take_off_board(env: &Env, challenge_id: u32) {
+    storage::remove_open_challenge(env, challenge_id);
+    let mut board = storage::open_challenges(env);
+    if let Some(index) = board.first_index_of(challenge_id) {
+        board.remove(index);
+        storage::save_open_challenges(env, &board);
+    }
+}
+
+/// Open a challenge to whoever presents the returned code, valid for `ttl_secs`
+/// (at most as long as a regular challenge). Meant to be shared as a link.
+pub(crate) fn create_invite_code(
+    env: &Env,
+    creator: Address,
+    points_wagered: i128,
+    ttl_secs: u64,
+) -> Result<u64, Error> {
+    creator.require_auth();
+
+    if ttl_secs == 0 || ttl_secs > CHALLENGE_DURATION_SECS {
+        return Err(Error::InvalidTtl);
+    }
+    game::validate_wager(env, points_wagered)?;
+
+    // Random rather than sequential so codes can't be walked; retry on the rare clash
+    let code = loop {
+        let code = env.prng().gen::<u64>();
+        if storage::load_invite_code(env, code).is_none() {
+            break code;
+        }
+    };
+    let invite = InviteCode {
+        creator,
+        points_wagered,
+        expires_at: env.ledger().timestamp() + ttl_secs,
+    };
+    storage::store_new_invite_code(env, code, &invite);
+
+    Ok(code)
+}
+
+/// Redeem an invite code and start a game against its creator. Codes are one-time:
+/// the code is gone once a game has started from it.
+pub(crate) fn accept_invite_code(env: &Env, code: u64, acceptor: Address) -> Result<u32, Error> {
+    acceptor.require_auth();
+
+    let invite = storage::load_invite_code(env, code).ok_or(Error::ChallengeNotFound)?;
+
+    if invite.creator == acceptor {
+        return Err(Error::CannotChallengeSelf);
+    }
+    if env.ledger().timestamp() > invite.expires_at {
+        return Err(Error::ChallengeExpired);
+    }
+    game::validate_wager(env, invite.points_wagered)?;
+
+    let session_id = start_challenge_game(env, invite.creator, acceptor, invite.points_wagered, None)?;
+    storage::remove_invite_code(env, code);
+
+    Ok(session_id)
+}
+
+/// Start a classic game for an accepted challenge on a freshly allocated session.
+/// The challenger picked the timing, so the challenged player gets initiative.
+fn start_challenge_game(
+    env: &Env,
+    challenger: Address,
+    challenged: Address,
+    points_wagered: i128,
+    tie_break: Option<TieBreak>, // None keeps the rule set's
+) -> Result<u32, Error> {
+    let session_id = game::allocate_session_id(env);
+    game::start_game_after_auth(
+        env,
+        session_id,
+        challenger,
+        challenged,
+        points_wagered,
+        points_wagered,
+        GameMode::Classic,
+    )?;
+
+    let mut game = storage::load_game(env, session_id)?;
+    game.initiative_bonus = storage::game_config(env).initiative_bonus;
+    if let Some(tie_break) = tie_break {
+        game.rules.tie_break = tie_break;
+    }
+    storage::save_game(env, session_id, &game);
+
+    Ok(session_id)
+}
+
+/// Get all challenges for a player (sorted by status)
+pub(crate) fn get_player_challenges(
+    env: &Env,
+    player: Address,
+) -> (Vec<Challenge>, Vec<Challenge>, Vec<Challenge>) {
+    let mut active = vec![env];
+    let mut completed = vec![env];
+    let mut expired = vec![env];
+
+    let current_time = env.ledger().timestamp();
+
+    for challenge in get_player_challenges_page(env, player, 0, MAX_PLAYER_CHALLENGES).iter() {
+        if challenge.is_completed {
+            completed.push_back(challenge);
+        } else if current_time > challenge.expires_at {
+            expired.push_back(challenge);
+        } else {
+            active.push_back(challenge);
+        }
+    }
+
+    (active, completed, expired)
+}
+
+/// Up to `limit` of a player's challenges from `offset`, oldest first. Ids whose
+/// challenge has expired out of storage are pruned from the list as they are
+/// reached, so they never take up a slot in the page.
+pub(crate) fn get_player_challenges_page(
+    env: &Env,
+    player: Address,
+    offset: u32,
+    limit: u32,
+) -> Vec<Challenge> {
+    let mut challenge_ids = storage::player_challenges(env, &player);
+    let limit = limit.min(MAX_PLAYER_CHALLENGES);
+    let mut page = vec![env];
+    let mut pruned = false;
+    let mut index = offset;
+    while index < challenge_ids.len() && page.len() < limit {
+        match storage::load_challenge(env, challenge_ids.get_unchecked(index)) {
+            Ok(challenge) => {
+                page.push_back(challenge);
+                index += 1;
+            }
+            Err(_) => {
+                challenge_ids.remove(index);
+                pruned = true;
+            }
+        }
+    }
+    if pruned {
+        storage::save_player_challenges(env, &player, &challenge_ids);
+    }
+    page
+}
+
+/// Add a challenge to a player's list. Once the list is full, the oldest entry among
+/// the first few that is completed, expired or gone makes room; if they are all still
+/// pending, the oldest entry goes. An evicted challenge stays readable with
+/// `get_challenge`.
+fn list_for_player(env: &Env, player: &Address, challenge_id: u32) {
+    let mut challenge_ids = storage::player_challenges(env, player);
+    challenge_ids.push_back(challenge_id);
+    if challenge_ids.len() > MAX_PLAYER_CHALLENGES {
+        let now = env.ledger().timestamp();
+        let evict = (0..EVICTION_SCAN)
+            .find(|&index| match storage::load_challenge(env, challenge_ids.get_unchecked(index)) {
+                Ok(challenge) => challenge.is_completed || now > challenge.expires_at,
+                Err(_) => true,
+            })
+            .unwrap_or(0);
+        challenge_ids.remove(evict);
+    }
+    storage::save_player_challenges(env, player, &challenge_ids);
+}
+
+/// Find the challenge that started `session_id` (if any) and mark it completed.
+/// Until the migration to storage version 1 has indexed older challenges, one
+/// missing from the index is looked for in `player`'s challenge list.
+pub(crate) fn mark_challenge_completed(env: &Env, session_id: u32, player: &Address) {
+    let indexed = storage::challenge_by_session(env, session_id);
+    let Some(challenge_id) = indexed.or_else(|| {
+        if storage::storage_version(env) >= 1 {
+            return None;
+        }
+        storage::player_challenges(env, player).iter().find(|&challenge_id| {
+            storage::load_challenge(env, challenge_id).is_ok_and(|challenge| challenge.session_id == Some(session_id))
+        })
+    }) else {
+        return;
+    };
+    if let Ok(mut challenge) = storage::load_challenge(env, challenge_id) {
+        challenge.is_completed = true;
+        challenge.status = ChallengeStatus::Completed;
+        storage::save_challenge(env, &challenge);
+    }
+}
+
+/// Backfill the session index for up to `count` challenges from `start_id`, for
+/// challenges accepted before it existed. Returns the number indexed; call with
+/// consecutive ranges until `start_id` passes the challenge counter.
+pub(crate) fn index_challenge_sessions(env: &Env, start_id: u32, count: u32) -> u32 {
+    let end = start_id
+        .saturating_add(count)
+        .min(storage::challenge_counter(env));
+    let mut indexed = 0;
+    for challenge_id in start_id..end {
+        let Ok(challenge) = storage::load_challenge(env, challenge_id) else {
+            continue;
+        };
+        if let (Some(session_id), false) = (challenge.session_id, challenge.is_completed) {
+            storage::save_challenge_by_session(env, session_id, challenge_id);
+            indexed += 1;
+        }
+    }
+    indexed
+}
@@ -0,0 +1,77 @@
+//! Coaching slots: each player can name a coach for a game, who can then read the
+//! player's revealed moves through `get_coached_moves` once the battle is resolved.
+//! The grant is kept on the game itself, so it expires with it.
+//!
+//! This hides nothing: revealed moves are public as soon as they're revealed, through
+//! `get_game` and `get_game_playback` as well as raw ledger state. It only gives
+//! coaching tools one endpoint that won't answer for a live match, without them
+//! tracking game phases.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage;
+use crate::{CoachSet, Error, Game, Move};
+
+/// Name `coach` for `player` in `session_id`, replacing any coach set before.
+/// `None` removes the coach.
+pub(crate) fn set_coach(
+    env: &Env,
+    session_id: u32,
+    player: Address,
+    coach: Option<Address>,
+) -> Result<(), Error> {
+    player.require_auth();
+
+    let mut game = storage::load_game(env, session_id)?;
+    if coach.as_ref() == Some(&player) {
+        return Err(Error::InvalidCoach);
+    }
+    *coach_slot(&mut game, &player)? = coach.clone();
+    storage::save_game(env, session_id, &game);
+    CoachSet {
+        session_id,
+        player,
+        coach,
+    }
+    .publish(env);
+    Ok(())
+}
+
+/// `player`'s revealed moves in `session_id`, for their coach. Empty if the player
+/// never revealed.
+pub(crate) fn get_coached_moves(
+    env: &Env,
+    session_id: u32,
+    coach: Address,
+    player: Address,
+) -> Result<Vec<Move>, Error> {
+    coach.require_auth();
+
+    let mut game = storage::load_game(env, session_id)?;
+    if coach_slot(&mut game, &player)?.as_ref() != Some(&coach) {
+        return Err(Error::InvalidCoach);
+    }
+    if !game.has_battle_result {
+        return Err(Error::CoachingLocked);
+    }
+
+    let commitment = if player == game.player1 {
+        game.player1_commitment
+    } else {
+        game.player2_commitment
+    };
+    if !commitment.has_revealed {
+        return Ok(Vec::new(env));
+    }
+    Ok(commitment.moves.moves)
+}
+
+fn coach_slot<'a>(game: &'a mut Game, player: &Address) -> Result<&'a mut Option<Address>, Error> {
+    if *player == game.player1 {
+        Ok(&mut game.player1_coach)
+    } else if *player == game.player2 {
+        Ok(&mut game.player2_coach)
+    } else {
+        Err(Error::NotPlayer)
+    }
+}
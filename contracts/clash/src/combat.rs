@@ -0,0 +1,1038 @@
+//! Pure battle engine.
+//!
+//! Everything here works on plain slices and values (no `Env`), so the combat rules
+//! can be exercised on the host without deploying the contract. The contract wraps
+//! these results into its `contracttype` structs.
+
+use core::cmp::Ordering;
+
+use crate::{Attack, CaptainClass, Defense, Move, RuleSet, TieBreak};
+
+/// Damage and defense outcome of a single turn.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TurnOutcome {
+    pub turn: u32,
+    pub player1_damage_dealt: i32,
+    pub player2_damage_dealt: i32,
+    pub player1_hp_remaining: i32,
+    pub player2_hp_remaining: i32,
+    pub player1_defense_successful: bool,
+    pub player2_defense_successful: bool,
+    /// Momentum each player carries into the next turn
+    pub player1_momentum: bool,
+    pub player2_momentum: bool,
+    /// The player's attack landed as a critical hit
+    pub player1_critical: bool,
+    pub player2_critical: bool,
+    /// Damage the player's Riposte sent back at the opponent
+    pub player1_reflected: i32,
+    pub player2_reflected: i32,
+}
+
+/// Critical hits rolled for a battle. Bit `t` of a player's mask makes their attack
+/// on turn `t` a crit: if it lands, its damage (base plus combo) is scaled by
+/// `multiplier_pct` percent before momentum and guard.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Crits {
+    pub player1: u32,
+    pub player2: u32,
+    pub multiplier_pct: i32,
+}
+
+impl Crits {
+    pub const NONE: Crits = Crits {
+        player1: 0,
+        player2: 0,
+        multiplier_pct: 100,
+    };
+
+    /// `damage` after the crit roll for `turn`, with `bonus_pct` added to the
+    /// multiplier if it crits
+    fn scale(self, mask: u32, turn: u32, damage: i32, bonus_pct: i32) -> (i32, bool) {
+        if damage == 0 || mask & (1 << turn) == 0 {
+            return (damage, false);
+        }
+        (damage * (self.multiplier_pct + bonus_pct) / 100, true)
+    }
+}
+
+/// Extra damage on a Brawler's Slash that lands, in percent
+pub const BRAWLER_SLASH_BONUS_PCT: i32 = 10;
+
+/// Damage a Trickster's Dodge takes off an attack it doesn't stop, in percent
+pub const TRICKSTER_DODGE_GRAZE_PCT: i32 = 25;
+
+/// Added to the crit multiplier when a Gunner's Fireball crits, in percentage points
+pub const GUNNER_FIREBALL_CRIT_BONUS_PCT: i32 = 50;
+
+/// Each player's captain class. The modifiers are passive and apply to every turn,
+/// overtime included:
+/// - Brawler: a Slash that lands deals [`BRAWLER_SLASH_BONUS_PCT`] percent more.
+/// - Trickster: a Dodge that doesn't stop the attack still takes
+///   [`TRICKSTER_DODGE_GRAZE_PCT`] percent off it (never below 1).
+/// - Gunner: a Fireball crit adds [`GUNNER_FIREBALL_CRIT_BONUS_PCT`] to the multiplier.
+///
+/// Class modifiers apply to base plus combo damage, before crits, momentum and guard.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Captains {
+    pub player1: CaptainClass,
+    pub player2: CaptainClass,
+}
+
+impl Captains {
+    pub const NONE: Captains = Captains {
+        player1: CaptainClass::None,
+        player2: CaptainClass::None,
+    };
+}
+
+/// Damage of an unblocked `attack` after the attacker's and defender's class modifiers
+fn class_damage(attacker: CaptainClass, defender: CaptainClass, attack: Attack, defense: Defense, damage: i32) -> i32 {
+    if damage == 0 {
+        return 0;
+    }
+    let mut damage = damage;
+    if attacker == CaptainClass::Brawler && attack == Attack::Slash {
+        damage = damage * (100 + BRAWLER_SLASH_BONUS_PCT) / 100;
+    }
+    if defender == CaptainClass::Trickster && defense == Defense::Dodge {
+        damage = (damage * (100 - TRICKSTER_DODGE_GRAZE_PCT) / 100).max(1);
+    }
+    damage
+}
+
+/// Crit multiplier bonus `class` gets on `attack`
+fn crit_bonus(class: CaptainClass, attack: Attack) -> i32 {
+    if class == CaptainClass::Gunner && attack == Attack::Fireball {
+        GUNNER_FIREBALL_CRIT_BONUS_PCT
+    } else {
+        0
+    }
+}
+
+/// What carries over from one turn to the next.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BattleState {
+    pub player1_hp: i32,
+    pub player2_hp: i32,
+    pub player1_momentum: bool,
+    pub player2_momentum: bool,
+    /// Damage taken off each player's next incoming hit that lands; spent after one turn
+    pub player1_guard: i32,
+    pub player2_guard: i32,
+}
+
+impl BattleState {
+    /// Full HP under `rules`, no momentum and no guard
+    pub fn start(rules: &RuleSet) -> BattleState {
+        BattleState {
+            player1_hp: rules.starting_hp,
+            player2_hp: rules.starting_hp,
+            player1_momentum: false,
+            player2_momentum: false,
+            player1_guard: 0,
+            player2_guard: 0,
+        }
+    }
+
+    /// Start with player2 guarding `bonus` damage on turn one (the challenged player's
+    /// initiative)
+    pub fn with_initiative(rules: &RuleSet, bonus: i32) -> BattleState {
+        BattleState {
+            player2_guard: bonus,
+            ..BattleState::start(rules)
+        }
+    }
+
+    /// State left behind by `turn`
+    pub fn after(turn: &TurnOutcome) -> BattleState {
+        BattleState {
+            player1_hp: turn.player1_hp_remaining,
+            player2_hp: turn.player2_hp_remaining,
+            player1_momentum: turn.player1_momentum,
+            player2_momentum: turn.player2_momentum,
+            player1_guard: 0,
+            player2_guard: 0,
+        }
+    }
+}
+
+/// Who won a battle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Winner {
+    Player1,
+    Player2,
+    Draw,
+}
+
+/// Final state of a battle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BattleOutcome {
+    pub player1_hp: i32,
+    pub player2_hp: i32,
+    pub winner: Winner,
+    /// Sudden-death turns played after a tie; the last this many turns reported
+    pub overtime_turns: u32,
+}
+
+/// Base damage for each attack type
+pub fn base_damage(rules: &RuleSet, attack: Attack) -> i32 {
+    match attack {
+        Attack::Slash => rules.slash_damage,
+        Attack::Fireball => rules.fireball_damage,
+        Attack::Lightning => rules.lightning_damage,
+        Attack::Broadside => rules.broadside_damage,
+        Attack::Hook => rules.hook_damage,
+    }
+}
+
+/// Pure RPS: each attack is stopped by exactly one defense
+pub fn is_blocked(attack: Attack, defense: Defense) -> bool {
+    matches!(
+        (attack, defense),
+        (Attack::Slash, Defense::Dodge)
+            | (Attack::Fireball, Defense::Counter)
+            | (Attack::Lightning, Defense::Block)
+            | (Attack::Broadside, Defense::Dodge)
+            | (Attack::Hook, Defense::Riposte)
+    )
+}
+
+/// The one defense that stops `attack`
+pub fn blocked_by(attack: Attack) -> Defense {
+    [Defense::Block, Defense::Dodge, Defense::Counter, Defense::Riposte]
+        .into_iter()
+        .find(|&defense| is_blocked(attack, defense))
+        .unwrap()
+}
+
+/// Damage dealt by `moves[turn].attack` against `defense`, and whether the
+/// defense stopped it.
+pub fn calculate_damage_and_defense(
+    rules: &RuleSet,
+    moves: &[Move],
+    turn: usize,
+    defense: Defense,
+) -> (i32, bool) {
+    // If blocked, no damage and defense was successful
+    if is_blocked(moves[turn].attack, defense) {
+        return (0, true);
+    }
+    (attack_damage(rules, moves, turn), false)
+}
+
+/// Base damage plus combo bonus of `moves[turn].attack`, whatever it meets.
+/// Broadside never combos: the guns need reloading between shots.
+fn attack_damage(rules: &RuleSet, moves: &[Move], turn: usize) -> i32 {
+    let attack = moves[turn].attack;
+
+    // Calculate combo bonus
+    let mut combo_bonus = 0;
+    if attack != Attack::Broadside && turn >= 1 && moves[turn - 1].attack == attack {
+        combo_bonus = rules.combo_2_bonus;
+        if turn >= 2 && moves[turn - 2].attack == attack {
+            combo_bonus = rules.combo_3_bonus;
+        }
+    }
+
+    base_damage(rules, attack) + combo_bonus
+}
+
+/// Damage a Riposte sends back at `moves[turn].attack`: `riposte_reflect_pct` percent
+/// of what the attack would have dealt, when the Riposte stops it. Zero otherwise.
+pub fn reflected_damage(rules: &RuleSet, moves: &[Move], turn: usize, defense: Defense) -> i32 {
+    if defense != Defense::Riposte || !is_blocked(moves[turn].attack, defense) {
+        return 0;
+    }
+    attack_damage(rules, moves, turn) * rules.riposte_reflect_pct / 100
+}
+
+/// Decide the winner from final HP. A tie is a draw, as is a double knockout.
+pub fn determine_winner(player1_hp: i32, player2_hp: i32) -> Winner {
+    if player1_hp <= 0 && player2_hp <= 0 {
+        // Both died - it's a DRAW
+        Winner::Draw
+    } else if player2_hp > player1_hp {
+        Winner::Player2
+    } else if player1_hp > player2_hp {
+        Winner::Player1
+    } else {
+        Winner::Draw
+    }
+}
+
+/// Adjust an attack that lands for the attacker's momentum and the target's guard.
+/// A Hook pierces the guard. A landed attack always deals at least 1, so zero damage
+/// still means blocked.
+fn landed_damage(rules: &RuleSet, attack: Attack, damage: i32, momentum: bool, guard: i32) -> i32 {
+    if damage == 0 {
+        return 0;
+    }
+    let bonus = if momentum { rules.momentum_bonus } else { 0 };
+    let guard = if attack == Attack::Hook { 0 } else { guard };
+    (damage + bonus - guard).max(1)
+}
+
+/// Play a single turn on top of `state`. Damage is applied simultaneously.
+///
+/// A player holding momentum adds the momentum bonus to an attack that lands. Dealing
+/// more damage than received (Riposte reflections included) wins the turn: the
+/// winner gains momentum and the loser loses it. On an even turn both keep what they
+/// had. A guard in `state` is taken off the damage the guarding player receives.
+/// `captains` adds each player's class modifiers.
+///
+/// Only `moves[..=turn]` are read (earlier moves feed the combo bonus), so this also
+/// works for modes that reveal one move at a time.
+pub fn play_turn(
+    rules: &RuleSet,
+    p1_moves: &[Move],
+    p2_moves: &[Move],
+    turn: u32,
+    state: BattleState,
+    crits: Crits,
+    captains: Captains,
+) -> TurnOutcome {
+    let t = turn as usize;
+    let (p1_attack, p2_attack) = (p1_moves[t].attack, p2_moves[t].attack);
+    let (p1_defense, p2_defense) = (p1_moves[t].defense, p2_moves[t].defense);
+    let (p1_damage, p1_defense_success) = calculate_damage_and_defense(rules, p1_moves, t, p2_defense);
+    let (p2_damage, p2_defense_success) = calculate_damage_and_defense(rules, p2_moves, t, p1_defense);
+    let p1_damage = class_damage(captains.player1, captains.player2, p1_attack, p2_defense, p1_damage);
+    let p2_damage = class_damage(captains.player2, captains.player1, p2_attack, p1_defense, p2_damage);
+    let (p1_damage, player1_critical) =
+        crits.scale(crits.player1, turn, p1_damage, crit_bonus(captains.player1, p1_attack));
+    let (p2_damage, player2_critical) =
+        crits.scale(crits.player2, turn, p2_damage, crit_bonus(captains.player2, p2_attack));
+    let p1_damage =
+        landed_damage(rules, p1_attack, p1_damage, state.player1_momentum, state.player2_guard);
+    let p2_damage =
+        landed_damage(rules, p2_attack, p2_damage, state.player2_momentum, state.player1_guard);
+    let p1_reflected = reflected_damage(rules, p2_moves, t, p1_defense);
+    let p2_reflected = reflected_damage(rules, p1_moves, t, p2_defense);
+    let (p1_total, p2_total) = (p1_damage + p1_reflected, p2_damage + p2_reflected);
+
+    let (player1_momentum, player2_momentum) = match p1_total.cmp(&p2_total) {
+        core::cmp::Ordering::Greater => (true, false),
+        core::cmp::Ordering::Less => (false, true),
+        core::cmp::Ordering::Equal => (state.player1_momentum, state.player2_momentum),
+    };
+
+    TurnOutcome {
+        turn,
+        player1_damage_dealt: p1_damage,
+        player2_damage_dealt: p2_damage,
+        player1_hp_remaining: state.player1_hp - p2_total,
+        player2_hp_remaining: state.player2_hp - p1_total,
+        player1_defense_successful: p1_defense_success,
+        player2_defense_successful: p2_defense_success,
+        player1_momentum,
+        player2_momentum,
+        player1_critical,
+        player2_critical,
+        player1_reflected: p1_reflected,
+        player2_reflected: p2_reflected,
+    }
+}
+
+/// Most turns any battle plays, whatever length its format asks for, so the cost of
+/// resolving a battle stays bounded as longer formats are added
+pub const MAX_TURNS: u32 = 16;
+
+/// Most sudden-death turns played after a tie before the battle is called a draw
+pub const MAX_OVERTIME_TURNS: u32 = 6;
+
+/// Damage added by each successive overtime turn, in percent of the normal amount
+pub const OVERTIME_ESCALATION_PCT: i32 = 50;
+
+/// Run a full battle of `rules.turns_per_battle` turns from `start`. `on_turn` is
+/// called once per turn played, in order, overtime included.
+///
+/// Damage is applied simultaneously each turn and the battle stops early on a
+/// knockout. If both players are still standing on equal HP, `rules.tie_break`
+/// decides. Under [`TieBreak::SuddenDeath`] the battle goes to overtime: the moves
+/// are played again from the first, overtime turn `k` dealing `100 + 50 * (k + 1)`
+/// percent damage, until the HP differs. Overtime turns are numbered on from the
+/// last regular turn and roll no crits. A tie that survives [`MAX_OVERTIME_TURNS`]
+/// is a draw. [`TieBreak::DamageDealt`] compares the attack damage each player
+/// landed, then their biggest single hit, and is a draw if both are level.
+pub fn simulate(
+    rules: &RuleSet,
+    p1_moves: &[Move],
+    p2_moves: &[Move],
+    start: BattleState,
+    crits: Crits,
+    captains: Captains,
+    mut on_turn: impl FnMut(TurnOutcome),
+) -> BattleOutcome {
+    let mut played = 0;
+    // (total, biggest single hit) of attack damage each player landed
+    let mut landed = [(0, 0); 2];
+    let play = |turn, state| play_turn(rules, p1_moves, p2_moves, turn, state, crits, captains);
+    let mut state = run_turns(p1_moves, p2_moves, rules.turns_per_battle, start, play, |t| {
+        played += 1;
+        for (landed, dealt) in landed.iter_mut().zip([t.player1_damage_dealt, t.player2_damage_dealt]) {
+            *landed = (landed.0 + dealt, landed.1.max(dealt));
+        }
+        on_turn(t);
+    });
+
+    let tied = state.player1_hp == state.player2_hp && state.player1_hp > 0 && played > 0;
+    let decided = match rules.tie_break {
+        _ if !tied => None,
+        TieBreak::SuddenDeath | TieBreak::TrueDraw => None,
+        TieBreak::Player1Wins => Some(Winner::Player1),
+        TieBreak::DamageDealt => Some(match landed[0].cmp(&landed[1]) {
+            Ordering::Greater => Winner::Player1,
+            Ordering::Less => Winner::Player2,
+            Ordering::Equal => Winner::Draw,
+        }),
+    };
+
+    let mut overtime_turns = 0;
+    while rules.tie_break == TieBreak::SuddenDeath
+        && state.player1_hp == state.player2_hp
+        && state.player1_hp > 0
+        && played > 0
+        && overtime_turns < MAX_OVERTIME_TURNS
+    {
+        let escalation = 100 + OVERTIME_ESCALATION_PCT * (overtime_turns as i32 + 1);
+        let rules = escalated(rules, escalation);
+        let turn = overtime_turns % played;
+        let mut outcome = play_turn(&rules, p1_moves, p2_moves, turn, state, Crits::NONE, captains);
+        outcome.turn = played + overtime_turns;
+        state = BattleState::after(&outcome);
+        on_turn(outcome);
+        overtime_turns += 1;
+    }
+
+    BattleOutcome {
+        player1_hp: state.player1_hp,
+        player2_hp: state.player2_hp,
+        winner: decided.unwrap_or_else(|| determine_winner(state.player1_hp, state.player2_hp)),
+        overtime_turns,
+    }
+}
+
+/// `rules` with every attack's damage and combo bonus scaled to `pct` percent
+fn escalated(rules: &RuleSet, pct: i32) -> RuleSet {
+    let scale = |damage: i32| damage * pct / 100;
+    RuleSet {
+        slash_damage: scale(rules.slash_damage),
+        fireball_damage: scale(rules.fireball_damage),
+        lightning_damage: scale(rules.lightning_damage),
+        broadside_damage: scale(rules.broadside_damage),
+        hook_damage: scale(rules.hook_damage),
+        combo_2_bonus: scale(rules.combo_2_bonus),
+        combo_3_bonus: scale(rules.combo_3_bonus),
+        ..rules.clone()
+    }
+}
+
+/// Run a 2v2 battle: each team shares one HP pool of twice the starting HP, and
+/// member `k` of one team trades blows with member `k` of the other. Each lane plays
+/// as a normal turn (combos come from the member's own moves) and the team totals
+/// are applied together, so in the outcome player1 is team one and player2 team two.
+/// Momentum belongs to the team that dealt more damage over both lanes. No crits or
+/// captain classes.
+pub fn simulate_team(
+    rules: &RuleSet,
+    team1: [&[Move]; 2],
+    team2: [&[Move]; 2],
+    on_turn: impl FnMut(TurnOutcome),
+) -> BattleOutcome {
+    let play = |turn: u32, state: BattleState| {
+        let [a, b] = [0, 1].map(|k| play_turn(rules, team1[k], team2[k], turn, state, Crits::NONE, Captains::NONE));
+        let (p1_damage, p2_damage) = (
+            a.player1_damage_dealt + b.player1_damage_dealt,
+            a.player2_damage_dealt + b.player2_damage_dealt,
+        );
+        let (p1_reflected, p2_reflected) = (
+            a.player1_reflected + b.player1_reflected,
+            a.player2_reflected + b.player2_reflected,
+        );
+        let (p1_total, p2_total) = (p1_damage + p1_reflected, p2_damage + p2_reflected);
+
+        let (player1_momentum, player2_momentum) = match p1_total.cmp(&p2_total) {
+            core::cmp::Ordering::Greater => (true, false),
+            core::cmp::Ordering::Less => (false, true),
+            core::cmp::Ordering::Equal => (state.player1_momentum, state.player2_momentum),
+        };
+
+        TurnOutcome {
+            turn,
+            player1_damage_dealt: p1_damage,
+            player2_damage_dealt: p2_damage,
+            player1_hp_remaining: state.player1_hp - p2_total,
+            player2_hp_remaining: state.player2_hp - p1_total,
+            player1_defense_successful: p1_damage == 0,
+            player2_defense_successful: p2_damage == 0,
+            player1_momentum,
+            player2_momentum,
+            player1_critical: false,
+            player2_critical: false,
+            player1_reflected: p1_reflected,
+            player2_reflected: p2_reflected,
+        }
+    };
+    let start = BattleState {
+        player1_hp: rules.starting_hp * 2,
+        player2_hp: rules.starting_hp * 2,
+        ..BattleState::start(rules)
+    };
+    // Every lane must have a move for each turn played
+    let turns = rules
+        .turns_per_battle
+        .min(team1[1].len() as u32)
+        .min(team2[1].len() as u32);
+    let state = run_turns(team1[0], team2[0], turns, start, play, on_turn);
+
+    BattleOutcome {
+        player1_hp: state.player1_hp,
+        player2_hp: state.player2_hp,
+        winner: determine_winner(state.player1_hp, state.player2_hp),
+        overtime_turns: 0,
+    }
+}
+
+/// The turn loop every format shares. Plays up to `turns` turns, capped at
+/// [`MAX_TURNS`] and at the shorter move sequence, and stops after a knockout.
+/// Returns the state left by the last turn played.
+fn run_turns(
+    p1_moves: &[Move],
+    p2_moves: &[Move],
+    turns: u32,
+    start: BattleState,
+    mut play: impl FnMut(u32, BattleState) -> TurnOutcome,
+    mut on_turn: impl FnMut(TurnOutcome),
+) -> BattleState {
+    let turns = turns
+        .min(MAX_TURNS)
+        .min(p1_moves.len() as u32)
+        .min(p2_moves.len() as u32);
+    let mut state = start;
+
+    for turn in 0..turns {
+        let outcome = play(turn, state);
+        state = BattleState::after(&outcome);
+        on_turn(outcome);
+
+        // Check for knockout AFTER storing the result
+        if state.player1_hp <= 0 || state.player2_hp <= 0 {
+            break;
+        }
+    }
+    state
+}
+
+/// Attack fired by a siege attacker's second volley. The attacker never defends, so
+/// the defense slot of their move picks a follow-up attack instead:
+/// Block -> Slash, Dodge -> Fireball, Counter -> Lightning, Riposte -> Hook.
+pub fn siege_volley(slot: Defense) -> Attack {
+    match slot {
+        Defense::Block => Attack::Slash,
+        Defense::Dodge => Attack::Fireball,
+        Defense::Counter => Attack::Lightning,
+        Defense::Riposte => Attack::Hook,
+    }
+}
+
+/// Run a siege: player1 only attacks, player2 only defends and wins by surviving
+/// every turn. Each turn the attacker fires its main attack (with combos, as in a
+/// normal battle) plus a half-damage volley; the defender's one defense can stop
+/// either. Neither side ever regains HP, and the attacker cannot be damaged, so a
+/// Riposte stops a Hook but reflects nothing. There is no momentum in a siege.
+pub fn simulate_siege(
+    rules: &RuleSet,
+    attacker_moves: &[Move],
+    defender_moves: &[Move],
+    on_turn: impl FnMut(TurnOutcome),
+) -> BattleOutcome {
+    let play = |turn: u32, state: BattleState| {
+        let t = turn as usize;
+        let defense = defender_moves[t].defense;
+        let (main_damage, _) = calculate_damage_and_defense(rules, attacker_moves, t, defense);
+        let volley = siege_volley(attacker_moves[t].defense);
+        let volley_damage = if is_blocked(volley, defense) {
+            0
+        } else {
+            base_damage(rules, volley) / 2
+        };
+        let damage = main_damage + volley_damage;
+
+        TurnOutcome {
+            turn,
+            player1_damage_dealt: damage,
+            player2_damage_dealt: 0,
+            player1_hp_remaining: rules.starting_hp,
+            player2_hp_remaining: state.player2_hp - damage,
+            player1_defense_successful: damage == 0,
+            player2_defense_successful: true, // The defender never attacks
+            player1_momentum: false,
+            player2_momentum: false,
+            player1_critical: false,
+            player2_critical: false,
+            player1_reflected: 0,
+            player2_reflected: 0,
+        }
+    };
+    let start = BattleState::start(rules);
+    let turns = rules.turns_per_battle;
+    let state = run_turns(attacker_moves, defender_moves, turns, start, play, on_turn);
+    let defender_hp = state.player2_hp;
+
+    BattleOutcome {
+        player1_hp: rules.starting_hp,
+        player2_hp: defender_hp,
+        winner: if defender_hp <= 0 {
+            Winner::Player1
+        } else {
+            Winner::Player2
+        },
+        overtime_turns: 0,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use crate::TURNS_PER_BATTLE;
+    use std::vec::Vec;
+
+    const CASES: u32 = 10_000;
+
+    const RULES: RuleSet = RuleSet::DEFAULT;
+
+    /// The default rules, played over `turns` turns
+    fn lasting(turns: u32) -> RuleSet {
+        RuleSet {
+            turns_per_battle: turns,
+            ..RULES
+        }
+    }
+
+    /// Small xorshift generator so the suite is reproducible without extra dependencies.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn attack(&mut self) -> Attack {
+            match self.next() % 5 {
+                0 => Attack::Slash,
+                1 => Attack::Fireball,
+                2 => Attack::Lightning,
+                3 => Attack::Broadside,
+                _ => Attack::Hook,
+            }
+        }
+
+        fn defense(&mut self) -> Defense {
+            match self.next() % 4 {
+                0 => Defense::Block,
+                1 => Defense::Dodge,
+                2 => Defense::Counter,
+                _ => Defense::Riposte,
+            }
+        }
+
+        fn moves(&mut self) -> Vec<Move> {
+            (0..TURNS_PER_BATTLE)
+                .map(|_| Move {
+                    attack: self.attack(),
+                    defense: self.defense(),
+                })
+                .collect()
+        }
+    }
+
+    /// Run `check` against `CASES` random battles.
+    fn for_random_battles(check: impl Fn(&[Move], &[Move], &[TurnOutcome], &BattleOutcome)) {
+        let mut rng = Rng(0x05ee_dc1a_50f9_a7e5);
+        for _ in 0..CASES {
+            let p1 = rng.moves();
+            let p2 = rng.moves();
+            let mut turns = Vec::new();
+            let outcome = simulate(&RULES, &p1, &p2, BattleState::start(&RULES), Crits::NONE, Captains::NONE, |t| turns.push(t));
+            check(&p1, &p2, &turns, &outcome);
+        }
+    }
+
+    #[test]
+    fn hp_never_exceeds_start_and_never_increases() {
+        for_random_battles(|_, _, turns, outcome| {
+            let mut prev = (RULES.starting_hp, RULES.starting_hp);
+            for t in turns {
+                assert!(t.player1_hp_remaining <= prev.0);
+                assert!(t.player2_hp_remaining <= prev.1);
+                prev = (t.player1_hp_remaining, t.player2_hp_remaining);
+            }
+            assert!(outcome.player1_hp <= RULES.starting_hp);
+            assert!(outcome.player2_hp <= RULES.starting_hp);
+        });
+    }
+
+    #[test]
+    fn winner_has_at_least_loser_hp() {
+        for_random_battles(|_, _, _, outcome| match outcome.winner {
+            Winner::Player1 => assert!(outcome.player1_hp >= outcome.player2_hp),
+            Winner::Player2 => assert!(outcome.player2_hp >= outcome.player1_hp),
+            Winner::Draw => {}
+        });
+    }
+
+    #[test]
+    fn draw_iff_both_knocked_out_or_level_after_overtime() {
+        for_random_battles(|_, _, _, outcome| {
+            let both_down = outcome.player1_hp <= 0 && outcome.player2_hp <= 0;
+            let level = outcome.player1_hp == outcome.player2_hp;
+            assert_eq!(outcome.winner == Winner::Draw, both_down || level);
+            if level && !both_down {
+                assert_eq!(outcome.overtime_turns, MAX_OVERTIME_TURNS);
+            }
+        });
+    }
+
+    #[test]
+    fn tie_goes_to_escalating_sudden_death() {
+        // Each side lands one unanswered 40 in the regular turns
+        let p1 = std::vec![Move { attack: Attack::Slash, defense: Defense::Block }; 2];
+        let p2 = std::vec![
+            Move { attack: Attack::Fireball, defense: Defense::Dodge },
+            Move { attack: Attack::Lightning, defense: Defense::Block },
+        ];
+        let mut turns = Vec::new();
+        let outcome = simulate(&lasting(2), &p1, &p2, BattleState::start(&RULES), Crits::NONE, Captains::NONE, |t| turns.push(t));
+
+        assert_eq!((turns[1].player1_hp_remaining, turns[1].player2_hp_remaining), (60, 60));
+        assert_eq!(outcome.overtime_turns, 1);
+        // The first overtime turn replays turn one's moves at 150% damage
+        let t = turns[2];
+        assert_eq!(t.turn, 2);
+        assert_eq!(t.player2_damage_dealt, turns[0].player2_damage_dealt * 3 / 2);
+        assert_eq!(outcome.winner, Winner::Player2);
+    }
+
+    #[test]
+    fn tie_break_policy_decides_a_level_battle() {
+        // Level on HP, but a quarter of player2's losses came back off a Riposte
+        let p1 = [
+            Move { attack: Attack::Slash, defense: Defense::Riposte },
+            Move { attack: Attack::Lightning, defense: Defense::Block },
+        ];
+        let p2 = [
+            Move { attack: Attack::Hook, defense: Defense::Block },
+            Move { attack: Attack::Fireball, defense: Defense::Block },
+        ];
+        let under = |tie_break| {
+            let rules = RuleSet { tie_break, ..lasting(2) };
+            let mut turns = Vec::new();
+            let outcome = simulate(&rules, &p1, &p2, BattleState::start(&rules), Crits::NONE, Captains::NONE, |t| turns.push(t));
+            (outcome, turns)
+        };
+
+        let (outcome, turns) = under(TieBreak::TrueDraw);
+        assert_eq!((turns[1].player1_hp_remaining, turns[1].player2_hp_remaining), (60, 60));
+        assert_eq!((turns[0].player1_reflected, turns[0].player1_damage_dealt, turns[1].player2_damage_dealt), (10, 30, 40));
+        assert_eq!((outcome.winner, outcome.overtime_turns), (Winner::Draw, 0));
+
+        assert_eq!(under(TieBreak::Player1Wins).0.winner, Winner::Player1);
+        assert_eq!(under(TieBreak::DamageDealt).0.winner, Winner::Player2);
+        assert!(under(TieBreak::SuddenDeath).0.overtime_turns > 0);
+        // Policies only apply to a tie
+        let p2_wins = [Move { attack: Attack::Hook, defense: Defense::Block }, Move { attack: Attack::Broadside, defense: Defense::Block }];
+        let rules = RuleSet { tie_break: TieBreak::Player1Wins, ..lasting(2) };
+        let outcome = simulate(&rules, &p1, &p2_wins, BattleState::start(&rules), Crits::NONE, Captains::NONE, |_| {});
+        assert_eq!(outcome.winner, Winner::Player2);
+    }
+
+    #[test]
+    fn battle_stops_at_first_knockout() {
+        for_random_battles(|_, _, turns, _| {
+            for t in &turns[..turns.len() - 1] {
+                assert!(t.player1_hp_remaining > 0 && t.player2_hp_remaining > 0);
+            }
+        });
+    }
+
+    #[test]
+    fn combos_are_monotone() {
+        for_random_battles(|p1, p2, turns, outcome| {
+            let regular = turns.len() - outcome.overtime_turns as usize;
+            for t in &turns[1..regular] {
+                let i = t.turn as usize;
+                let prev = &turns[i - 1];
+                if p1[i].attack == p1[i - 1].attack
+                    && p1[i].attack != Attack::Broadside
+                    && !t.player1_defense_successful
+                    && !prev.player1_defense_successful
+                {
+                    assert!(t.player1_damage_dealt > prev.player1_damage_dealt);
+                }
+                if p2[i].attack == p2[i - 1].attack
+                    && p2[i].attack != Attack::Broadside
+                    && !t.player2_defense_successful
+                    && !prev.player2_defense_successful
+                {
+                    assert!(t.player2_damage_dealt > prev.player2_damage_dealt);
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn blocked_attacks_deal_no_damage() {
+        for_random_battles(|_, _, turns, _| {
+            // `playerN_defense_successful` records whether playerN's attack was stopped
+            for t in turns {
+                assert_eq!(t.player1_defense_successful, t.player1_damage_dealt == 0);
+                assert_eq!(t.player2_defense_successful, t.player2_damage_dealt == 0);
+            }
+        });
+    }
+
+    #[test]
+    fn momentum_goes_to_turn_winner() {
+        for_random_battles(|_, _, turns, _| {
+            let mut prev = (false, false);
+            for t in turns {
+                let now = (t.player1_momentum, t.player2_momentum);
+                let dealt = (
+                    t.player1_damage_dealt + t.player1_reflected,
+                    t.player2_damage_dealt + t.player2_reflected,
+                );
+                match dealt.0.cmp(&dealt.1) {
+                    core::cmp::Ordering::Greater => assert_eq!(now, (true, false)),
+                    core::cmp::Ordering::Less => assert_eq!(now, (false, true)),
+                    core::cmp::Ordering::Equal => assert_eq!(now, prev),
+                }
+                assert!(!(now.0 && now.1));
+                prev = now;
+            }
+        });
+    }
+
+    #[test]
+    fn momentum_adds_bonus_to_landed_attacks() {
+        // Turn 0: player1's Slash lands, player2's Fireball is countered
+        let slash = Move { attack: Attack::Slash, defense: Defense::Counter };
+        let p1 = [slash.clone(), slash];
+        let p2 = [
+            Move { attack: Attack::Fireball, defense: Defense::Block },
+            Move { attack: Attack::Lightning, defense: Defense::Block },
+        ];
+        let mut turns = Vec::new();
+        simulate(&lasting(2), &p1, &p2, BattleState::start(&RULES), Crits::NONE, Captains::NONE, |t| turns.push(t));
+
+        assert_eq!((turns[0].player1_damage_dealt, turns[0].player2_damage_dealt), (30, 0));
+        assert!(turns[0].player1_momentum);
+        // Turn 1: Slash combo plus momentum
+        assert_eq!(turns[1].player1_damage_dealt, 30 + RULES.combo_2_bonus + RULES.momentum_bonus);
+    }
+
+    #[test]
+    fn initiative_guards_player2_on_turn_one_only() {
+        let mut rng = Rng(0x1417_1a71_7e00_0001);
+        for _ in 0..CASES {
+            let p1 = rng.moves();
+            let p2 = rng.moves();
+            let mut plain = Vec::new();
+            let mut guarded = Vec::new();
+            simulate(&lasting(1), &p1, &p2, BattleState::start(&RULES), Crits::NONE, Captains::NONE, |t| plain.push(t));
+            simulate(&lasting(1), &p1, &p2, BattleState::with_initiative(&RULES, 5), Crits::NONE, Captains::NONE, |t| guarded.push(t));
+
+            let (plain, guarded) = (plain[0], guarded[0]);
+            assert_eq!(guarded.player2_damage_dealt, plain.player2_damage_dealt);
+            if plain.player1_damage_dealt == 0 || p1[0].attack == Attack::Hook {
+                assert_eq!(guarded.player1_damage_dealt, plain.player1_damage_dealt);
+            } else {
+                assert_eq!(guarded.player1_damage_dealt, plain.player1_damage_dealt - 5);
+            }
+        }
+    }
+
+    #[test]
+    fn riposte_stops_hook_and_reflects_half() {
+        for_random_battles(|p1, p2, turns, _| {
+            for t in turns {
+                // Overtime turns replay the regular moves from the start
+                let i = t.turn as usize % p1.len();
+                let p1_ripostes = p1[i].defense == Defense::Riposte && p2[i].attack == Attack::Hook;
+                let p2_ripostes = p2[i].defense == Defense::Riposte && p1[i].attack == Attack::Hook;
+                assert_eq!(t.player1_reflected > 0, p1_ripostes);
+                assert_eq!(t.player2_reflected > 0, p2_ripostes);
+                if p2_ripostes {
+                    assert_eq!(t.player1_damage_dealt, 0);
+                }
+            }
+        });
+
+        let hook = Move { attack: Attack::Hook, defense: Defense::Block };
+        let riposte = Move { attack: Attack::Slash, defense: Defense::Riposte };
+        let mut turns = Vec::new();
+        simulate(&lasting(1), &[hook], &[riposte], BattleState::start(&RULES), Crits::NONE, Captains::NONE, |t| turns.push(t));
+
+        let t = turns[0];
+        assert_eq!((t.player1_damage_dealt, t.player2_damage_dealt), (0, 30));
+        assert_eq!(t.player2_reflected, RULES.hook_damage / 2);
+        assert_eq!(t.player1_hp_remaining, RULES.starting_hp - 30 - RULES.hook_damage / 2);
+        assert!(t.player2_momentum);
+    }
+
+    #[test]
+    fn broadside_never_combos() {
+        let broadside = Move { attack: Attack::Broadside, defense: Defense::Block };
+        let p1 = std::vec![broadside; 3];
+        let p2 = std::vec![Move { attack: Attack::Slash, defense: Defense::Block }; 3];
+        let rules = RuleSet { starting_hp: 1000, ..RULES };
+        let mut turns = Vec::new();
+        simulate(&rules, &p1, &p2, BattleState::start(&rules), Crits::NONE, Captains::NONE, |t| turns.push(t));
+
+        // Momentum from winning turn one is the only bonus
+        let dealt: Vec<i32> = turns.iter().map(|t| t.player1_damage_dealt).collect();
+        let heavy = RULES.broadside_damage;
+        assert_eq!(dealt, [heavy, heavy + RULES.momentum_bonus, heavy + RULES.momentum_bonus]);
+    }
+
+    #[test]
+    fn crits_scale_landed_first_turn_attacks_only() {
+        let mut rng = Rng(0xc417_0000_1234_5678);
+        let crits = Crits { player1: 1, player2: 0, multiplier_pct: 150 };
+        for _ in 0..CASES {
+            let p1 = rng.moves();
+            let p2 = rng.moves();
+            let mut plain = Vec::new();
+            let mut critted = Vec::new();
+            simulate(&lasting(1), &p1, &p2, BattleState::start(&RULES), Crits::NONE, Captains::NONE, |t| plain.push(t));
+            simulate(&lasting(1), &p1, &p2, BattleState::start(&RULES), crits, Captains::NONE, |t| critted.push(t));
+
+            let (plain, critted) = (plain[0], critted[0]);
+            assert_eq!(critted.player2_damage_dealt, plain.player2_damage_dealt);
+            assert!(!critted.player2_critical);
+            assert_eq!(critted.player1_critical, plain.player1_damage_dealt > 0);
+            assert_eq!(critted.player1_damage_dealt, plain.player1_damage_dealt * 3 / 2);
+        }
+    }
+
+    #[test]
+    fn captain_classes_modify_landed_damage() {
+        let slash = Move { attack: Attack::Slash, defense: Defense::Dodge };
+        let fireball = Move { attack: Attack::Fireball, defense: Defense::Block };
+        let first_turn = |crits, player1, player2| {
+            let mut turns = Vec::new();
+            let captains = Captains { player1, player2 };
+            simulate(&lasting(1), core::slice::from_ref(&slash), core::slice::from_ref(&fireball), BattleState::start(&RULES), crits, captains, |t| turns.push(t));
+            (turns[0].player1_damage_dealt, turns[0].player2_damage_dealt)
+        };
+        let gunner_crit = Crits { player1: 0, player2: 1, multiplier_pct: 150 };
+        use CaptainClass::{Brawler, Gunner, Trickster};
+
+        assert_eq!(first_turn(Crits::NONE, CaptainClass::None, CaptainClass::None), (30, 40));
+        assert_eq!(first_turn(Crits::NONE, Brawler, Gunner), (33, 40));
+        // The Trickster's Dodge doesn't stop a Fireball but grazes it
+        assert_eq!(first_turn(Crits::NONE, Trickster, Brawler), (30, 30));
+        assert_eq!(first_turn(gunner_crit, CaptainClass::None, Gunner), (30, 80));
+        assert_eq!(first_turn(gunner_crit, Trickster, Gunner), (30, 60));
+        assert_eq!(first_turn(gunner_crit, Gunner, Trickster), (30, 60));
+    }
+
+    #[test]
+    fn siege_attacker_wins_iff_defender_falls() {
+        let mut rng = Rng(0x51e9_e000_0bad_cafe);
+        for _ in 0..CASES {
+            let attacker = rng.moves();
+            let defender = rng.moves();
+            let mut turns = Vec::new();
+            let outcome = simulate_siege(&RULES, &attacker, &defender, |t| turns.push(t));
+
+            let mut prev_hp = RULES.starting_hp;
+            for t in &turns {
+                // No regen, and the attacker is never hit
+                assert!(t.player2_hp_remaining <= prev_hp);
+                assert_eq!(t.player1_hp_remaining, RULES.starting_hp);
+                assert_eq!(t.player2_damage_dealt, 0);
+                prev_hp = t.player2_hp_remaining;
+            }
+            assert_ne!(outcome.winner, Winner::Draw);
+            assert_eq!(outcome.winner == Winner::Player1, outcome.player2_hp <= 0);
+            assert_eq!(outcome.player2_hp, prev_hp);
+        }
+    }
+
+    #[test]
+    fn turn_loop_is_bounded_by_cap_and_moves() {
+        // Every attack is dodged, so nothing ends the battle early,
+        let stalemate = Move {
+            attack: Attack::Slash,
+            defense: Defense::Dodge,
+        };
+        let long: Vec<Move> = (0..MAX_TURNS + 4).map(|_| stalemate.clone()).collect();
+
+        // and the level battle runs its full overtime on top
+        let mut played = 0;
+        let outcome =
+            simulate(&lasting(u32::MAX), &long, &long, BattleState::start(&RULES), Crits::NONE, Captains::NONE, |_| played += 1);
+        assert_eq!(outcome.overtime_turns, MAX_OVERTIME_TURNS);
+        assert_eq!(played - outcome.overtime_turns, MAX_TURNS);
+
+        played = 0;
+        let outcome =
+            simulate(&lasting(u32::MAX), &long, &long[..2], BattleState::start(&RULES), Crits::NONE, Captains::NONE, |_| played += 1);
+        assert_eq!(played - outcome.overtime_turns, 2);
+
+        // Fireball with a Fireball volley, both stopped by Counter
+        let volley = |defense| Move {
+            attack: Attack::Fireball,
+            defense,
+        };
+        let attacker: Vec<Move> = (0..MAX_TURNS + 4).map(|_| volley(Defense::Dodge)).collect();
+        let defender: Vec<Move> = (0..MAX_TURNS + 4).map(|_| volley(Defense::Counter)).collect();
+        played = 0;
+        let outcome = simulate_siege(&lasting(u32::MAX), &attacker, &defender, |_| played += 1);
+        assert_eq!((played, outcome.winner), (MAX_TURNS, Winner::Player2));
+    }
+
+    #[test]
+    fn team_lanes_add_up_against_shared_pools() {
+        let fireball = std::vec![Move { attack: Attack::Fireball, defense: Defense::Counter }; 3];
+        let slash = std::vec![Move { attack: Attack::Slash, defense: Defense::Block }; 3];
+        let hook = std::vec![Move { attack: Attack::Hook, defense: Defense::Riposte }; 3];
+        let mut turns = Vec::new();
+        let outcome = simulate_team(&RULES, [&fireball, &hook], [&slash, &slash], |t| turns.push(t));
+
+        // Lane one: Fireball 40 against Slash 30; lane two: Hook 20 against Slash 30.
+        // Both teams deal 60, so neither takes momentum
+        let t = turns[0];
+        assert_eq!((t.player1_damage_dealt, t.player2_damage_dealt), (60, 60));
+        assert_eq!(t.player1_hp_remaining, 2 * RULES.starting_hp - 60);
+        assert_eq!(t.player2_hp_remaining, 2 * RULES.starting_hp - 60);
+        assert!(!t.player1_momentum && !t.player2_momentum);
+        // Combos build per member: 40+10 and 20+10 against 30+10 twice
+        assert_eq!((turns[1].player1_damage_dealt, turns[1].player2_damage_dealt), (80, 80));
+        assert_eq!(outcome.winner, Winner::Draw);
+    }
+
+    #[test]
+    fn rule_set_drives_damage_hp_and_length() {
+        let rules = RuleSet {
+            starting_hp: 60,
+            slash_damage: 50,
+            combo_2_bonus: 1,
+            ..lasting(2)
+        };
+        let p = std::vec![Move { attack: Attack::Slash, defense: Defense::Block }; 3];
+        let mut turns = Vec::new();
+        let outcome = simulate(&rules, &p, &p, BattleState::start(&rules), Crits::NONE, Captains::NONE, |t| turns.push(t));
+
+        assert_eq!(turns.len(), 2);
+        assert_eq!((turns[0].player1_damage_dealt, turns[0].player2_hp_remaining), (50, 10));
+        assert_eq!(turns[1].player1_damage_dealt, 51);
+        assert_eq!(outcome.winner, Winner::Draw);
+    }
+}
@@ -0,0 +1,159 @@
+//! Cost benchmarks for tuning the battle engine, compiled only with the `debug` feature.
+//!
+//! A contract can't read its own budget, so each entrypoint just runs a representative
+//! workload and the counters come from the host: simulate the call against a debug
+//! build (`simulateTransaction` reports CPU instructions and memory bytes), or run
+//! `cargo test --features debug` which reads them from the test host and fails when a
+//! workload grows past its ceiling.
+
+use soroban_sdk::{contractimpl, vec, Address, BytesN, Env};
+
+use crate::challenge;
+use crate::combat::Crits;
+use crate::randomness;
+use crate::storage;
+use crate::{
+    game, Attack, BattleResult, CaptainClass, Challenge, ChallengeStatus, ClashContract, ClashContractArgs,
+    ClashContractClient, CommitMode, Defense, Error, Game, GameMode, Move, OutcomeReason,
+    MoveSequence, PlayerCommitment, Raise, RaiseStatus, RuleSet, TieBreak, TURNS_PER_BATTLE,
+};
+
+/// Session id used for the benchmark game; far above anything the hub hands out
+const BENCH_SESSION_ID: u32 = u32::MAX;
+
+#[contractimpl]
+impl ClashContract {
+    /// Resolve a max-length game: both players reveal the same attack three times, so
+    /// every turn is played and both combo bonuses apply. Touches storage like
+    /// `resolve_battle` but never calls the Game Hub or mints rewards.
+    pub fn bench_resolve_battle(env: Env, player1: Address, player2: Address) -> Result<BattleResult, Error> {
+        if player1 == player2 {
+            return Err(Error::CannotChallengeSelf);
+        }
+
+        let worst_case = Move {
+            attack: Attack::Slash,
+            defense: Defense::Block,
+        };
+        let mut moves = vec![&env];
+        for _ in 0..TURNS_PER_BATTLE {
+            moves.push_back(worst_case.clone());
+        }
+        let revealed = PlayerCommitment {
+            proof_id: BytesN::from_array(&env, &[0u8; 32]),
+            has_revealed: true,
+            moves: MoveSequence { moves },
+        };
+        let empty_result = BattleResult {
+            player1_hp: 0,
+            player2_hp: 0,
+            winner: None,
+            is_draw: false,
+            turn_results: vec![&env],
+            entropy: randomness::none(&env),
+            went_to_overtime: false,
+            overtime_turns: vec![&env],
+            reason: OutcomeReason::Pending,
+        };
+
+        storage::store_new_game(
+            &env,
+            BENCH_SESSION_ID,
+            &Game {
+                player1,
+                player2,
+                player1_username: None,
+                player2_username: None,
+                player1_points: 0,
+                player2_points: 0,
+                mode: GameMode::Classic,
+                has_player1_commitment: true,
+                player1_commitment: revealed.clone(),
+                has_player2_commitment: true,
+                player2_commitment: revealed,
+                has_battle_result: false,
+                battle_result: empty_result,
+                is_settled: false,
+                commit_deadline: 0,
+                reveal_deadline: 0,
+                deposit_token: None,
+                deposit_amount: 0,
+                initiative_bonus: 0,
+                rules: RuleSet::DEFAULT,
+                crit_chance_bps: 0,
+                crit_multiplier_pct: 100,
+                raise: Raise {
+                    token: None,
+                    max_amount: 0,
+                    status: RaiseStatus::None,
+                    raiser: None,
+                    amount: 0,
+                    fee_schedule: vec![&env],
+                },
+                commit_mode: CommitMode::Zk,
+                circuit_version: 1,
+                started_at: env.ledger().timestamp(),
+                resolved_at: 0,
+                is_cancelled: false,
+                player1_coach: None,
+                player2_coach: None,
+                player1_class: CaptainClass::None,
+                player2_class: CaptainClass::None,
+            },
+        );
+
+        let mut game = storage::load_game(&env, BENCH_SESSION_ID)?;
+        let result = game::simulate_battle(&env, &game, Crits::NONE);
+        game.battle_result = result.clone();
+        game.has_battle_result = true;
+        storage::save_game(&env, BENCH_SESSION_ID, &game);
+
+        Ok(result)
+    }
+
+    /// Seed `count` open challenges from `opponent` to `player`. A transaction can write
+    /// at most 50 ledger entries, so seed in batches. Returns the player's challenge count.
+    pub fn bench_seed_challenges(env: Env, player: Address, opponent: Address, count: u32) -> Result<u32, Error> {
+        if player == opponent {
+            return Err(Error::CannotChallengeSelf);
+        }
+
+        let now = env.ledger().timestamp();
+        let mut challenge_ids = storage::player_challenges(&env, &player);
+        for _ in 0..count {
+            let challenge_id = storage::next_challenge_id(&env);
+            storage::store_new_challenge(
+                &env,
+                &Challenge {
+                    challenge_id,
+                    challenger: opponent.clone(),
+                    challenged: player.clone(),
+                    points_wagered: 0,
+                    created_at: now,
+                    expires_at: now + 1,
+                    is_accepted: false,
+                    is_completed: false,
+                    session_id: None,
+                    forwarded_from: None,
+                    status: ChallengeStatus::Pending,
+                    is_rival_match: false,
+                    created_ledger: env.ledger().sequence(),
+                    nonce: 0,
+                    tie_break: TieBreak::SuddenDeath,
+                },
+            );
+            challenge_ids.push_back(challenge_id);
+        }
+        // Written directly, like a list from before the cap, so it can outgrow it
+        storage::save_player_challenges(&env, &player, &challenge_ids);
+
+        Ok(challenge_ids.len())
+    }
+
+    /// Load one page of `player`'s challenges, `limit` entries from `start`. A transaction
+    /// can touch at most 100 ledger entries, so a full history has to be listed in pages.
+    /// Returns how many challenges were loaded.
+    pub fn bench_player_challenges(env: Env, player: Address, start: u32, limit: u32) -> u32 {
+        challenge::get_player_challenges_page(&env, player, start, limit).len()
+    }
+}
@@ -0,0 +1,44 @@
+//! Refundable anti-grief deposit taken at commit time.
+//!
+//! When the admin configures a deposit, each player pays it (in any SEP-41 token) when
+//! they commit and gets it back when they reveal. A player who commits and then never
+//! reveals before the reveal deadline loses it to the opponent via
+//! `claim_reveal_timeout`, which credits it as claimable winnings. The token and amount are snapshotted onto the game at start,
+//! so changing the config never affects games already running.
+
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, Env};
+
+use crate::{promo, winnings, Game};
+
+/// Take the deposit from `player` into the contract, paying out any promo bonus
+/// waiting on it
+pub(crate) fn collect(env: &Env, game: &Game, player: &Address) {
+    if let Some(token) = &game.deposit_token {
+        TokenClient::new(env, token).transfer(
+            player,
+            env.current_contract_address(),
+            &game.deposit_amount,
+        );
+        promo::pay_deposit_bonus(env, player);
+    }
+}
+
+/// Hand a player's own deposit straight back when they reveal
+pub(crate) fn refund(env: &Env, game: &Game, to: &Address) {
+    if let Some(token) = &game.deposit_token {
+        TokenClient::new(env, token).transfer(
+            &env.current_contract_address(),
+            to,
+            &game.deposit_amount,
+        );
+    }
+}
+
+/// Credit one held deposit to `to` at settlement (a refund, or a forfeit to the
+/// opponent), for them to collect with `claim_winnings`
+pub(crate) fn award(env: &Env, session_id: u32, game: &Game, to: &Address) {
+    if let Some(token) = &game.deposit_token {
+        winnings::credit(env, session_id, to, token, game.deposit_amount);
+    }
+}
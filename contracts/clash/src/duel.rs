@@ -0,0 +1,365 @@
+//! Turn-by-turn duels: each round both players commit one move, then reveal it, and the
+//! round resolves as soon as the second reveal lands.
+//!
+//! Single moves are committed as `sha256(attack | defense | salt)` rather than with a
+//! ZK proof (the circuit proves a full 3-move sequence). Every phase has a deadline;
+//! a player who acted in time can claim the duel once the opponent misses it, or
+//! anyone can enforce the deadline on their behalf.
+
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env, IntoVal, Vec};
+
+use crate::combat::{self, BattleState, Captains, Crits, Winner};
+use crate::{awaiting, badges, escrow, identity, pause, randomness, ratings, rivals, signers};
+use crate::storage;
+use crate::{
+    game, Attack, BattleResult, Defense, Duel, DuelPhase, DuelTurn, Error, GameHubClient, Move, OutcomeReason,
+    TurnResult, TURNS_PER_BATTLE,
+};
+
+/// Time each player has to commit, and then to reveal, every round (5 minutes)
+const DUEL_PHASE_SECS: u64 = 5 * 60;
+
+/// Commitment for a single duel move: `sha256(attack | defense | salt)`, with the
+/// attack and defense as one byte each.
+pub(crate) fn move_commitment(env: &Env, action: &Move, salt: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &[action.attack as u8, action.defense as u8]);
+    preimage.append(&Bytes::from(salt.clone()));
+    env.crypto().sha256(&preimage).into()
+}
+
+pub(crate) fn start_duel(
+    env: &Env,
+    session_id: u32,
+    player1: Address,
+    player2: Address,
+    player1_points: i128,
+    player2_points: i128,
+) -> Result<(), Error> {
+    if identity::primary(env, &player1) == identity::primary(env, &player2) {
+        return Err(Error::CannotChallengeSelf);
+    }
+    game::validate_wager(env, player1_points)?;
+    game::validate_wager(env, player2_points)?;
+
+    player1.require_auth_for_args(vec![
+        env,
+        session_id.into_val(env),
+        player1_points.into_val(env),
+    ]);
+    player2.require_auth_for_args(vec![
+        env,
+        session_id.into_val(env),
+        player2_points.into_val(env),
+    ]);
+
+    let game_hub = GameHubClient::new(env, &storage::game_hub(env));
+    game::check_registered(env, &game_hub, &player1, &player2)?;
+    awaiting::check_room(env, &player1, &player2)?;
+    game_hub.start_game(
+        &env.current_contract_address(),
+        &session_id,
+        &player1,
+        &player2,
+        &player1_points,
+        &player2_points,
+    );
+    escrow::lock(env, session_id, &player1, &player2, player1_points, player2_points);
+
+    let rules = storage::rule_set(env);
+    let duel = Duel {
+        player1,
+        player2,
+        player1_points,
+        player2_points,
+        phase: DuelPhase::Commit,
+        round: 0,
+        deadline: env.ledger().timestamp() + DUEL_PHASE_SECS,
+        player1_turn: empty_turn(env),
+        player2_turn: empty_turn(env),
+        player1_moves: vec![env],
+        player2_moves: vec![env],
+        battle_result: BattleResult {
+            player1_hp: rules.starting_hp,
+            player2_hp: rules.starting_hp,
+            winner: None,
+            is_draw: false,
+            turn_results: vec![env],
+            entropy: randomness::none(env),
+            went_to_overtime: false,
+            overtime_turns: vec![env],
+            reason: OutcomeReason::Pending,
+        },
+        rules,
+    };
+
+    storage::store_new_duel(env, session_id, &duel);
+    awaiting::track(env, session_id, &duel.player1, &duel.player2);
+    badges::sync_duel(env, session_id, &duel);
+
+    Ok(())
+}
+
+pub(crate) fn commit_move(
+    env: &Env,
+    session_id: u32,
+    player: Address,
+    commitment: BytesN<32>,
+) -> Result<(), Error> {
+    signers::require_move_auth(env, session_id, &player);
+
+    let mut duel = storage::load_duel(env, session_id)?;
+
+    if duel.phase == DuelPhase::Finished {
+        return Err(Error::GameAlreadyEnded);
+    }
+    if env.ledger().timestamp() > pause::deadline(env, duel.deadline) {
+        return Err(Error::DeadlinePassed);
+    }
+
+    let turn = turn_mut(&mut duel, &player)?;
+    if turn.has_committed {
+        return Err(Error::AlreadyCommitted);
+    }
+    turn.commitment = commitment;
+    turn.has_committed = true;
+
+    // Both in: open the reveal window
+    if duel.player1_turn.has_committed && duel.player2_turn.has_committed {
+        duel.phase = DuelPhase::Reveal;
+        duel.deadline = env.ledger().timestamp() + DUEL_PHASE_SECS;
+    }
+
+    storage::save_duel(env, session_id, &duel);
+    badges::sync_duel(env, session_id, &duel);
+    Ok(())
+}
+
+pub(crate) fn reveal_move(
+    env: &Env,
+    session_id: u32,
+    player: Address,
+    action: Move,
+    salt: BytesN<32>,
+) -> Result<(), Error> {
+    signers::require_move_auth(env, session_id, &player);
+
+    let mut duel = storage::load_duel(env, session_id)?;
+
+    match duel.phase {
+        DuelPhase::Finished => return Err(Error::GameAlreadyEnded),
+        DuelPhase::Commit => return Err(Error::BothPlayersNotCommitted),
+        DuelPhase::Reveal => {}
+    }
+    if env.ledger().timestamp() > pause::deadline(env, duel.deadline) {
+        return Err(Error::DeadlinePassed);
+    }
+
+    let commitment = move_commitment(env, &action, &salt);
+    let turn = turn_mut(&mut duel, &player)?;
+    if turn.has_revealed {
+        return Err(Error::AlreadyRevealed);
+    }
+    if turn.commitment != commitment {
+        return Err(Error::CommitmentMismatch);
+    }
+    turn.action = action;
+    turn.has_revealed = true;
+
+    // The second reveal resolves the round (and stores the duel itself)
+    if duel.player1_turn.has_revealed && duel.player2_turn.has_revealed {
+        resolve_round(env, session_id, &mut duel);
+    } else {
+        storage::save_duel(env, session_id, &duel);
+    }
+    badges::sync_duel(env, session_id, &duel);
+    Ok(())
+}
+
+pub(crate) fn claim_timeout(env: &Env, session_id: u32, player: Address) -> Result<(), Error> {
+    player.require_auth();
+
+    let duel = storage::load_duel(env, session_id)?;
+
+    if duel.phase == DuelPhase::Finished {
+        return Err(Error::GameAlreadyEnded);
+    }
+    if player != duel.player1 && player != duel.player2 {
+        return Err(Error::NotPlayer);
+    }
+    apply_timeout(env, session_id, duel)?;
+    Ok(())
+}
+
+/// `claim_timeout` without a claimant, for `enforce_deadlines`
+pub(crate) fn enforce_deadline(env: &Env, session_id: u32) -> Result<BattleResult, Error> {
+    let duel = storage::load_duel(env, session_id)?;
+    if duel.phase == DuelPhase::Finished {
+        return Err(Error::GameAlreadyEnded);
+    }
+    apply_timeout(env, session_id, duel)
+}
+
+fn apply_timeout(env: &Env, session_id: u32, mut duel: Duel) -> Result<BattleResult, Error> {
+    if env.ledger().timestamp() <= pause::deadline(env, duel.deadline) {
+        return Err(Error::DeadlineNotReached);
+    }
+
+    let (p1_acted, p2_acted, reason) = match duel.phase {
+        DuelPhase::Commit => (
+            duel.player1_turn.has_committed,
+            duel.player2_turn.has_committed,
+            OutcomeReason::CommitTimeout,
+        ),
+        _ => (
+            duel.player1_turn.has_revealed,
+            duel.player2_turn.has_revealed,
+            OutcomeReason::RevealTimeout,
+        ),
+    };
+
+    // Whoever acted in time wins; if neither did, close it as a draw so the
+    // wagered points are not locked forever.
+    let winner = match (p1_acted, p2_acted) {
+        (true, false) => Winner::Player1,
+        (false, true) => Winner::Player2,
+        _ => Winner::Draw,
+    };
+    finish(env, session_id, &mut duel, winner, reason);
+
+    Ok(duel.battle_result)
+}
+
+/// Both moves are revealed: play the round, then finish on a knockout or after the
+/// last round, otherwise open the next commit window.
+fn resolve_round(env: &Env, session_id: u32, duel: &mut Duel) {
+    // The first round's commitments fix the duel's seed
+    if duel.round == 0 {
+        duel.battle_result.entropy = randomness::commit_window(
+            env,
+            randomness::BATTLE_DOMAIN,
+            session_id,
+            &duel.player1_turn.commitment,
+            &duel.player2_turn.commitment,
+        );
+    }
+
+    duel.player1_moves.push_back(duel.player1_turn.action.clone());
+    duel.player2_moves.push_back(duel.player2_turn.action.clone());
+
+    // Momentum carries over from the previous round's result
+    let previous = duel.battle_result.turn_results.last();
+    let state = BattleState {
+        player1_hp: duel.battle_result.player1_hp,
+        player2_hp: duel.battle_result.player2_hp,
+        player1_momentum: previous.as_ref().is_some_and(|t| t.player1_momentum),
+        player2_momentum: previous.as_ref().is_some_and(|t| t.player2_momentum),
+        ..BattleState::start(&duel.rules)
+    };
+    // No crits: the seed is public from the first round on, so later rounds could
+    // be planned around it
+    let t = combat::play_turn(
+        &duel.rules,
+        &moves_array(&duel.player1_moves),
+        &moves_array(&duel.player2_moves),
+        duel.round,
+        state,
+        Crits::NONE,
+        Captains::NONE,
+    );
+
+    duel.battle_result.player1_hp = t.player1_hp_remaining;
+    duel.battle_result.player2_hp = t.player2_hp_remaining;
+    duel.battle_result.turn_results.push_back(TurnResult {
+        turn: t.turn,
+        player1_damage_dealt: t.player1_damage_dealt,
+        player2_damage_dealt: t.player2_damage_dealt,
+        player1_hp_remaining: t.player1_hp_remaining,
+        player2_hp_remaining: t.player2_hp_remaining,
+        player1_defense_successful: t.player1_defense_successful,
+        player2_defense_successful: t.player2_defense_successful,
+        player1_momentum: t.player1_momentum,
+        player2_momentum: t.player2_momentum,
+        player1_was_critical: t.player1_critical,
+        player2_was_critical: t.player2_critical,
+        player1_reflected: t.player1_reflected,
+        player2_reflected: t.player2_reflected,
+    });
+
+    let knockout = t.player1_hp_remaining <= 0 || t.player2_hp_remaining <= 0;
+    if knockout || duel.round + 1 == duel.rules.turns_per_battle {
+        let winner = combat::determine_winner(t.player1_hp_remaining, t.player2_hp_remaining);
+        let reason = match winner {
+            Winner::Draw => OutcomeReason::Draw,
+            _ => OutcomeReason::BattleDecision,
+        };
+        finish(env, session_id, duel, winner, reason);
+        return;
+    }
+
+    duel.round += 1;
+    duel.phase = DuelPhase::Commit;
+    duel.deadline = env.ledger().timestamp() + DUEL_PHASE_SECS;
+    duel.player1_turn = empty_turn(env);
+    duel.player2_turn = empty_turn(env);
+    storage::save_duel(env, session_id, duel);
+}
+
+/// Record the outcome and store it, then report it to the Game Hub and pay out the
+/// winner. The `Finished` phase is persisted before any external call, so nothing can
+/// re-enter and settle the same duel twice.
+fn finish(env: &Env, session_id: u32, duel: &mut Duel, winner: Winner, reason: OutcomeReason) {
+    duel.phase = DuelPhase::Finished;
+    duel.battle_result.reason = reason;
+    match winner {
+        Winner::Draw => duel.battle_result.is_draw = true,
+        Winner::Player1 => duel.battle_result.winner = Some(duel.player1.clone()),
+        Winner::Player2 => duel.battle_result.winner = Some(duel.player2.clone()),
+    }
+    storage::save_duel(env, session_id, duel);
+    awaiting::untrack(env, session_id, &duel.player1, &duel.player2);
+    identity::record_activity(env, &duel.player1);
+    identity::record_activity(env, &duel.player2);
+
+    let game_hub = GameHubClient::new(env, &storage::game_hub(env));
+    game_hub.end_game(&session_id, &(winner == Winner::Player1));
+    escrow::release(env, session_id);
+    let winner = duel.battle_result.winner.as_ref();
+    ratings::record_result(env, &duel.player1, &duel.player2, winner);
+    rivals::record_result(env, session_id, &duel.player1, &duel.player2, winner);
+    if let Some(winner_addr) = duel.battle_result.winner.clone() {
+        game::mint_csh_reward(env, winner_addr);
+    }
+}
+
+fn turn_mut<'a>(duel: &'a mut Duel, player: &Address) -> Result<&'a mut DuelTurn, Error> {
+    if *player == duel.player1 {
+        Ok(&mut duel.player1_turn)
+    } else if *player == duel.player2 {
+        Ok(&mut duel.player2_turn)
+    } else {
+        Err(Error::NotPlayer)
+    }
+}
+
+fn empty_turn(env: &Env) -> DuelTurn {
+    DuelTurn {
+        has_committed: false,
+        commitment: BytesN::from_array(env, &[0u8; 32]),
+        has_revealed: false,
+        action: Move {
+            attack: Attack::Slash,
+            defense: Defense::Block,
+        },
+    }
+}
+
+/// Copy the revealed moves into a fixed array for the combat engine. Rounds not yet
+/// played are padded; `combat::play_turn` only reads up to the current round.
+fn moves_array(moves: &Vec<Move>) -> [Move; TURNS_PER_BATTLE as usize] {
+    core::array::from_fn(|i| {
+        moves.get(i as u32).unwrap_or(Move {
+            attack: Attack::Slash,
+            defense: Defense::Block,
+        })
+    })
+}
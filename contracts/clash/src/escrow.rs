@@ -0,0 +1,43 @@
+//! Escrow receipts: a record of what the Game Hub holds for each session, issued when
+//! the stakes are locked and stamped when they are released, so wallets and explorers
+//! can show players exactly what is locked and why.
+//!
+//! Both run right after a successful Game Hub call, so they also stamp the time the
+//! hub was last reached, for `status`.
+
+use soroban_sdk::{Address, Env};
+
+use crate::storage;
+use crate::EscrowReceipt;
+
+/// Issue the receipt for stakes the Game Hub just locked
+pub(crate) fn lock(
+    env: &Env,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    player1_amount: i128,
+    player2_amount: i128,
+) {
+    let receipt = EscrowReceipt {
+        session_id,
+        asset: storage::game_hub(env),
+        player1: player1.clone(),
+        player1_amount,
+        player2: player2.clone(),
+        player2_amount,
+        locked_at: env.ledger().timestamp(),
+        released_at: 0,
+    };
+    storage::save_escrow(env, &receipt);
+    storage::save_hub_last_ok_at(env, receipt.locked_at);
+}
+
+/// Mark the stakes as paid out or refunded
+pub(crate) fn release(env: &Env, session_id: u32) {
+    storage::save_hub_last_ok_at(env, env.ledger().timestamp());
+    if let Some(mut receipt) = storage::escrow(env, session_id) {
+        receipt.released_at = env.ledger().timestamp();
+        storage::save_escrow(env, &receipt);
+    }
+}
@@ -0,0 +1,71 @@
+//! Tiered rake on token pots.
+//!
+//! The admin sets a fee schedule as breakpoints on the per-player wager: each tier
+//! charges `fee_bps` of the pot for wagers from its `min_wager` up to the next tier,
+//! so micro-stakes can pay a higher rate than large ones. The schedule is snapshotted
+//! onto each game's raise state at start and applied when a called raise settles.
+//! Fees accumulate per token until the admin withdraws them, or anyone sweeps them
+//! to the treasury once the admin has set one.
+
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage;
+use crate::{Error, FeeTier};
+
+/// Highest rate any tier may charge (10%)
+const MAX_FEE_BPS: u32 = 1_000;
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Tiers must be ordered by strictly increasing `min_wager`, start at a non-negative
+/// wager and charge at most [`MAX_FEE_BPS`]
+pub(crate) fn validate(schedule: &Vec<FeeTier>) -> Result<(), Error> {
+    let mut previous: Option<i128> = None;
+    for tier in schedule.iter() {
+        let ordered = match previous {
+            None => tier.min_wager >= 0,
+            Some(min_wager) => tier.min_wager > min_wager,
+        };
+        if !ordered || tier.fee_bps > MAX_FEE_BPS {
+            return Err(Error::InvalidFeeSchedule);
+        }
+        previous = Some(tier.min_wager);
+    }
+    Ok(())
+}
+
+/// Cut taken from the pot when each player stakes `wager`
+pub(crate) fn quote(schedule: &Vec<FeeTier>, wager: i128) -> i128 {
+    let fee_bps = schedule
+        .iter()
+        .filter(|tier| tier.min_wager <= wager)
+        .last()
+        .map(|tier| tier.fee_bps)
+        .unwrap_or(0);
+    wager * 2 * fee_bps as i128 / BPS_DENOMINATOR
+}
+
+/// Add a fee already held by the contract to the withdrawable balance
+pub(crate) fn collect(env: &Env, token: &Address, amount: i128) {
+    if amount > 0 {
+        let collected = storage::collected_fees(env, token);
+        storage::save_collected_fees(env, token, collected + amount);
+    }
+}
+
+/// Send every collected fee in `token` to `to`
+pub(crate) fn withdraw(env: &Env, token: &Address, to: &Address) -> i128 {
+    let amount = storage::collected_fees(env, token);
+    if amount > 0 {
+        storage::save_collected_fees(env, token, 0);
+        TokenClient::new(env, token).transfer(&env.current_contract_address(), to, &amount);
+    }
+    amount
+}
+
+/// Send every collected fee in `token` to the treasury
+pub(crate) fn sweep(env: &Env, token: &Address) -> Result<i128, Error> {
+    let treasury = storage::treasury(env).ok_or(Error::NoTreasury)?;
+    Ok(withdraw(env, token, &treasury))
+}
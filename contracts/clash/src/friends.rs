@@ -0,0 +1,60 @@
+//! Friends lists and a looking-for-game flag, so the arena can show which friends
+//! are ready to duel straight from contract state.
+//!
+//! A friends list is one-sided, like following: adding someone doesn't add you to
+//! theirs. The flag is whatever the player last set. Lists and flags are kept for a
+//! game TTL after they last change, so an abandoned account's entries lapse.
+
+use soroban_sdk::{vec, Address, Env, Vec};
+
+use crate::storage;
+use crate::{Error, FriendStatus};
+
+/// Most friends one player can list
+const MAX_FRIENDS: u32 = 50;
+
+/// Add `friend` to `player`'s list; adding a listed friend again does nothing
+pub(crate) fn add_friend(env: &Env, player: Address, friend: Address) -> Result<(), Error> {
+    player.require_auth();
+    if player == friend {
+        return Err(Error::CannotFriendSelf);
+    }
+
+    let mut friends = storage::friends(env, &player);
+    if friends.contains(&friend) {
+        return Ok(());
+    }
+    if friends.len() >= MAX_FRIENDS {
+        return Err(Error::TooManyFriends);
+    }
+    friends.push_back(friend);
+    storage::save_friends(env, &player, &friends);
+    Ok(())
+}
+
+/// Take `friend` off `player`'s list; removing someone who isn't listed does nothing
+pub(crate) fn remove_friend(env: &Env, player: Address, friend: Address) {
+    player.require_auth();
+    let mut friends = storage::friends(env, &player);
+    if let Some(index) = friends.first_index_of(&friend) {
+        friends.remove(index);
+        storage::save_friends(env, &player, &friends);
+    }
+}
+
+pub(crate) fn set_looking_for_game(env: &Env, player: Address, looking: bool) {
+    player.require_auth();
+    storage::save_looking_for_game(env, &player, looking);
+}
+
+/// `player`'s friends in the order they were added, each with their flag
+pub(crate) fn get_friends_status(env: &Env, player: &Address) -> Vec<FriendStatus> {
+    let mut statuses = vec![env];
+    for friend in storage::friends(env, player).iter() {
+        statuses.push_back(FriendStatus {
+            looking_for_game: storage::looking_for_game(env, &friend),
+            friend,
+        });
+    }
+    statuses
+}
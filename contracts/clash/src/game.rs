@@ -0,0 +1,1164 @@
+//! Game lifecycle: starting sessions on the Game Hub, resolving battles and playback.
+
+use soroban_sdk::{vec, Address, BytesN, Env, IntoVal, Vec};
+
+use crate::combat::{self, BattleState, Winner};
+use crate::{achievements, awaiting, badges, duel, escrow, history, identity, picks, points, prediction, raise, randomness, ratings, referral, rivals, series, sponsor, stats};
+use crate::pause::{self, Breaker};
+use crate::storage;
+use crate::{
+    challenge, deposit, Attack, AttackRule, BattleResolved, BattleResult, ClashTokenClient, DailyBonusAwarded, DetailedTurnResult, Error, ExportedSettlement, Game, GameCancelled, GameExpired,
+    GameExport, GameHubClient, GameMode, GameProposal, GameWindow, WindowOpened, GamePlayback, Move, MoveSequence, OutcomeReason,
+    PlayerCommitment, PracticeGame, Raise, RaiseStatus, RandomnessAudit, RuleSet, Rules, SessionGame, TeamGame, TurnResult,
+    CSH_REWARD_PER_WIN, DAILY_WIN_BONUS, TURNS_PER_BATTLE,
+};
+
+/// Largest starting HP a rule set can give
+const MAX_STARTING_HP: i32 = 1_000;
+
+/// How long a game nobody has committed to must sit before it can be cancelled
+const STALE_GAME_SECS: u64 = 24 * 60 * 60;
+
+/// Longest a game can stay unresolved, plus any agreed pauses, before anyone can
+/// void it with `expire_game`
+pub(crate) const MAX_GAME_LIFETIME_SECS: u64 = 14 * 24 * 60 * 60;
+
+/// Length of the days the daily win bonus is counted in
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Most games one `get_games` call returns
+pub(crate) const MAX_BULK_GAMES: u32 = 20;
+
+/// Most sessions `get_pending_resolutions` keeps, newest last
+const MAX_PENDING_RESOLUTIONS: u32 = 100;
+
+/// Start a new game between two players with points. Each player signs for their own
+/// stake, so nobody can lock another player's points; games from challenges get the
+/// same consent from the send and the accept.
+pub(crate) fn start_game(
+    env: &Env,
+    session_id: u32,
+    player1: Address,
+    player2: Address,
+    player1_points: i128,
+    player2_points: i128,
+) -> Result<(), Error> {
+    // Prevent self-play
+    if player1 == player2 {
+        return Err(Error::CannotChallengeSelf);
+    }
+
+    // Require authentication from both players
+    player1.require_auth_for_args(vec![
+        env,
+        session_id.into_val(env),
+        player1_points.into_val(env),
+    ]);
+    player2.require_auth_for_args(vec![
+        env,
+        session_id.into_val(env),
+        player2_points.into_val(env),
+    ]);
+
+    start_game_after_auth(
+        env,
+        session_id,
+        player1,
+        player2,
+        player1_points,
+        player2_points,
+        GameMode::Classic,
+    )
+}
+
+/// First half of a start for wallets that can't gather both signatures in one
+/// transaction: `player1` offers the game and stakes nothing yet. Proposals last
+/// as long as a challenge.
+pub(crate) fn propose_game(
+    env: &Env,
+    session_id: u32,
+    player1: Address,
+    player2: Address,
+    player1_points: i128,
+    player2_points: i128,
+) -> Result<(), Error> {
+    player1.require_auth();
+
+    if player1 == player2 {
+        return Err(Error::CannotChallengeSelf);
+    }
+    validate_wager(env, player1_points)?;
+    validate_wager(env, player2_points)?;
+    if storage::game_proposal(env, session_id).is_some() || storage::load_game(env, session_id).is_ok() {
+        return Err(Error::SessionTaken);
+    }
+
+    storage::store_new_game_proposal(
+        env,
+        session_id,
+        &GameProposal {
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            expires_at: env.ledger().timestamp() + challenge::CHALLENGE_DURATION_SECS,
+        },
+    );
+    Ok(())
+}
+
+/// `player2` agrees to a proposed game, which starts it and locks both stakes
+pub(crate) fn confirm_game(env: &Env, session_id: u32, player2: Address) -> Result<(), Error> {
+    player2.require_auth();
+
+    let proposal = storage::game_proposal(env, session_id).ok_or(Error::GameNotFound)?;
+    if proposal.player2 != player2 {
+        return Err(Error::NotPlayer);
+    }
+    if env.ledger().timestamp() > proposal.expires_at {
+        return Err(Error::ChallengeExpired);
+    }
+    storage::remove_game_proposal(env, session_id);
+
+    start_game_after_auth(
+        env,
+        session_id,
+        proposal.player1,
+        player2,
+        proposal.player1_points,
+        proposal.player2_points,
+        GameMode::Classic,
+    )
+}
+
+/// Commit and reveal windows in correspondence games (3 days each)
+pub(crate) const CORRESPONDENCE_WINDOW_SECS: u64 = 3 * 24 * 60 * 60;
+
+/// How long players have to reveal in a game of `mode`; the admin configures it for
+/// everything but correspondence
+pub(crate) fn reveal_window(env: &Env, mode: GameMode) -> u64 {
+    match mode {
+        GameMode::Correspondence => CORRESPONDENCE_WINDOW_SECS,
+        GameMode::Classic | GameMode::Siege => storage::game_config(env).reveal_window_secs,
+    }
+}
+
+/// Announce a newly opened window. Only correspondence games publish these; the
+/// other modes are short enough to play in one sitting.
+pub(crate) fn announce_window(
+    env: &Env,
+    session_id: u32,
+    game: &Game,
+    window: GameWindow,
+    deadline: u64,
+) {
+    if game.mode == GameMode::Correspondence {
+        WindowOpened {
+            session_id,
+            window,
+            deadline,
+        }
+        .publish(env);
+    }
+}
+
+/// Pick an unused session id for a game the contract starts on a player's behalf.
+/// Random rather than sequential so it is unlikely to collide with sessions other
+/// games have opened on the shared Game Hub.
+pub(crate) fn allocate_session_id(env: &Env) -> u32 {
+    loop {
+        let session_id = env.prng().gen_range::<u64>(1..=u32::MAX as u64) as u32;
+        if storage::load_game(env, session_id).is_err() {
+            return session_id;
+        }
+    }
+}
+
+/// With the registration gate on, both players must be registered with the hub
+pub(crate) fn check_registered(
+    env: &Env,
+    game_hub: &GameHubClient,
+    player1: &Address,
+    player2: &Address,
+) -> Result<(), Error> {
+    if !storage::require_registered(env) {
+        return Ok(());
+    }
+    if !game_hub.is_registered(player1) || !game_hub.is_registered(player2) {
+        return Err(Error::PlayerNotRegistered);
+    }
+    Ok(())
+}
+
+/// Reject negative wagers and wagers outside the admin-configured limits
+pub(crate) fn validate_wager(env: &Env, points: i128) -> Result<(), Error> {
+    let limits = storage::wager_limits(env);
+    if points < 0 || points < limits.min || points > limits.max {
+        return Err(Error::InvalidWager);
+    }
+    Ok(())
+}
+
+/// Start a siege: `attacker` plays as player1, `defender` as player2
+pub(crate) fn start_siege(
+    env: &Env,
+    session_id: u32,
+    attacker: Address,
+    defender: Address,
+    attacker_points: i128,
+    defender_points: i128,
+) -> Result<(), Error> {
+    if attacker == defender {
+        return Err(Error::CannotChallengeSelf);
+    }
+
+    attacker.require_auth_for_args(vec![
+        env,
+        session_id.into_val(env),
+        attacker_points.into_val(env),
+    ]);
+    defender.require_auth_for_args(vec![
+        env,
+        session_id.into_val(env),
+        defender_points.into_val(env),
+    ]);
+
+    start_game_after_auth(
+        env,
+        session_id,
+        attacker,
+        defender,
+        attacker_points,
+        defender_points,
+        GameMode::Siege,
+    )
+}
+
+/// Start a correspondence game: classic rules with multi-day windows
+pub(crate) fn start_correspondence(
+    env: &Env,
+    session_id: u32,
+    player1: Address,
+    player2: Address,
+    player1_points: i128,
+    player2_points: i128,
+) -> Result<(), Error> {
+    if player1 == player2 {
+        return Err(Error::CannotChallengeSelf);
+    }
+
+    player1.require_auth_for_args(vec![
+        env,
+        session_id.into_val(env),
+        player1_points.into_val(env),
+    ]);
+    player2.require_auth_for_args(vec![
+        env,
+        session_id.into_val(env),
+        player2_points.into_val(env),
+    ]);
+
+    start_game_after_auth(
+        env,
+        session_id,
+        player1,
+        player2,
+        player1_points,
+        player2_points,
+        GameMode::Correspondence,
+    )
+}
+
+pub(crate) fn start_game_after_auth(
+    env: &Env,
+    session_id: u32,
+    player1: Address,
+    player2: Address,
+    player1_points: i128,
+    player2_points: i128,
+    mode: GameMode,
+) -> Result<(), Error> {
+    // Two addresses of one identity would be playing themselves
+    if identity::primary(env, &player1) == identity::primary(env, &player2) {
+        return Err(Error::CannotChallengeSelf);
+    }
+    validate_wager(env, player1_points)?;
+    validate_wager(env, player2_points)?;
+
+    open_hub_session(env, session_id, &player1, &player2, player1_points, player2_points)?;
+    create_game(env, session_id, player1, player2, player1_points, player2_points, mode);
+    Ok(())
+}
+
+/// Start the session on the Game Hub, locking both players' points, and issue the
+/// escrow receipt
+pub(crate) fn open_hub_session(
+    env: &Env,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    player1_points: i128,
+    player2_points: i128,
+) -> Result<(), Error> {
+    let game_hub = GameHubClient::new(env, &storage::game_hub(env));
+    check_registered(env, &game_hub, player1, player2)?;
+    awaiting::check_room(env, player1, player2)?;
+    let started = game_hub.try_start_game(
+        &env.current_contract_address(),
+        &session_id,
+        player1,
+        player2,
+        &player1_points,
+        &player2_points,
+    );
+    if !matches!(started, Ok(Ok(()))) {
+        return Err(Error::GameHubCallFailed);
+    }
+    escrow::lock(env, session_id, player1, player2, player1_points, player2_points);
+    Ok(())
+}
+
+/// Store a fresh game for a session the Game Hub already knows about (or, for series
+/// rounds, one that is played under the series' hub session)
+pub(crate) fn create_game(
+    env: &Env,
+    session_id: u32,
+    player1: Address,
+    player2: Address,
+    player1_points: i128,
+    player2_points: i128,
+    mode: GameMode,
+) {
+    // Snapshot the commit deposit, raise, crit and commit mode settings so later
+    // config changes don't affect this game
+    let deposit = storage::commit_deposit(env);
+    let config = storage::game_config(env);
+    let raise_config = storage::raise_config(env);
+    let fee_schedule = match raise_config {
+        Some(_) => storage::fee_schedule(env),
+        None => vec![env],
+    };
+
+    // Create empty default commitment
+    let empty_commitment = PlayerCommitment {
+        proof_id: BytesN::from_array(env, &[0u8; 32]),
+        has_revealed: false,
+        moves: MoveSequence {
+            moves: vec![env],
+        },
+    };
+
+    // Create empty battle result
+    let empty_result = BattleResult {
+        player1_hp: 0,
+        player2_hp: 0,
+        winner: None,
+        is_draw: false,
+        turn_results: vec![env],
+        entropy: randomness::none(env),
+        went_to_overtime: false,
+        overtime_turns: vec![env],
+        reason: OutcomeReason::Pending,
+    };
+
+    // Names and classes are kept by the identity a linked address plays as
+    let identity1 = identity::primary(env, &player1);
+    let identity2 = identity::primary(env, &player2);
+
+    // Create game
+    let game = Game {
+        player1_username: storage::username(env, &identity1),
+        player2_username: storage::username(env, &identity2),
+        player1_class: storage::captain_class(env, &identity1),
+        player2_class: storage::captain_class(env, &identity2),
+        player1,
+        player2,
+        player1_points,
+        player2_points,
+        mode,
+        has_player1_commitment: false,
+        player1_commitment: empty_commitment.clone(),
+        has_player2_commitment: false,
+        player2_commitment: empty_commitment,
+        has_battle_result: false,
+        battle_result: empty_result,
+        is_settled: false,
+        commit_deadline: match mode {
+            GameMode::Correspondence => env.ledger().timestamp() + CORRESPONDENCE_WINDOW_SECS,
+            GameMode::Classic | GameMode::Siege => 0,
+        },
+        reveal_deadline: 0,
+        deposit_token: deposit.as_ref().map(|d| d.token.clone()),
+        deposit_amount: deposit.map(|d| d.amount).unwrap_or(0),
+        initiative_bonus: 0,
+        rules: storage::rule_set(env),
+        crit_chance_bps: config.crit_chance_bps,
+        crit_multiplier_pct: config.crit_multiplier_pct,
+        raise: Raise {
+            token: raise_config.as_ref().map(|r| r.token.clone()),
+            max_amount: raise_config.map(|r| r.max_amount).unwrap_or(0),
+            status: RaiseStatus::None,
+            raiser: None,
+            amount: 0,
+            fee_schedule,
+        },
+        commit_mode: config.commit_mode,
+        circuit_version: storage::circuit_version(env),
+        started_at: env.ledger().timestamp(),
+        resolved_at: 0,
+        is_cancelled: false,
+        player1_coach: None,
+        player2_coach: None,
+    };
+
+    // Store game with TTL (persistent for correspondence, temporary otherwise)
+    storage::store_new_game(env, session_id, &game);
+    awaiting::track(env, session_id, &game.player1, &game.player2);
+    badges::sync_game(env, session_id, &game);
+    announce_window(env, session_id, &game, GameWindow::Commit, game.commit_deadline);
+}
+
+/// The combat rules new games start under, with the engine's block table
+pub(crate) fn get_rules(env: &Env) -> Rules {
+    let rules = storage::rule_set(env);
+    let config = storage::game_config(env);
+    let mut attacks = vec![env];
+    for attack in [
+        Attack::Slash,
+        Attack::Fireball,
+        Attack::Lightning,
+        Attack::Broadside,
+        Attack::Hook,
+    ] {
+        attacks.push_back(AttackRule {
+            attack,
+            damage: combat::base_damage(&rules, attack),
+            blocked_by: combat::blocked_by(attack),
+        });
+    }
+    Rules {
+        version: rules.version,
+        starting_hp: rules.starting_hp,
+        turns_per_battle: rules.turns_per_battle,
+        attacks,
+        combo_2_bonus: rules.combo_2_bonus,
+        combo_3_bonus: rules.combo_3_bonus,
+        momentum_bonus: rules.momentum_bonus,
+        riposte_reflect_pct: rules.riposte_reflect_pct,
+        tie_break: rules.tie_break,
+        initiative_bonus: config.initiative_bonus,
+        crit_chance_bps: config.crit_chance_bps,
+        crit_multiplier_pct: config.crit_multiplier_pct,
+    }
+}
+
+/// Store new combat rules under the next version number. Admin auth is checked by
+/// the caller.
+pub(crate) fn set_rules(env: &Env, rules: RuleSet) -> Result<u32, Error> {
+    let hp = rules.starting_hp;
+    let damages = [
+        rules.slash_damage,
+        rules.fireball_damage,
+        rules.lightning_damage,
+        rules.broadside_damage,
+        rules.hook_damage,
+    ];
+    let valid = (1..=MAX_STARTING_HP).contains(&hp)
+        && (1..=TURNS_PER_BATTLE).contains(&rules.turns_per_battle)
+        // Every attack must do some damage, so 0 keeps meaning "blocked"
+        && damages.iter().all(|damage| (1..=hp).contains(damage))
+        && (0..=rules.combo_3_bonus).contains(&rules.combo_2_bonus)
+        && rules.combo_3_bonus <= hp
+        && (0..=hp).contains(&rules.momentum_bonus)
+        && (0..=100).contains(&rules.riposte_reflect_pct);
+    if !valid {
+        return Err(Error::InvalidRules);
+    }
+
+    let version = storage::rule_set(env).version + 1;
+    storage::save_rule_set(env, &RuleSet { version, ..rules });
+    Ok(version)
+}
+
+/// Resolve `session_id` if it's ready to: both moves revealed, resolution not paused
+/// and no signed result pending. Otherwise its result as it stands, so a reveal made
+/// in the same invocation never fails on the resolution.
+pub(crate) fn resolve_if_revealed(env: &Env, session_id: u32) -> Result<BattleResult, Error> {
+    let game = storage::load_game(env, session_id)?;
+    let revealed = game.player1_commitment.has_revealed && game.player2_commitment.has_revealed;
+    if !revealed
+        || pause::check(env, Breaker::Resolution).is_err()
+        || storage::pending_settlement(env, session_id).is_some()
+    {
+        return Ok(game.battle_result);
+    }
+    resolve_battle(env, session_id)
+}
+
+/// Resolve the battle after both players have revealed their moves
+///
+/// Settlement is two-phase: the outcome is recorded first, then `is_settled` is
+/// persisted *before* any external call (Game Hub, token mint). A re-entrant or
+/// repeated call sees the flag and returns the recorded result without paying again.
+pub(crate) fn resolve_battle(env: &Env, session_id: u32) -> Result<BattleResult, Error> {
+    let mut game = storage::load_game(env, session_id)?;
+    // A repeat call is a single read
+    if game.is_settled {
+        return Ok(game.battle_result);
+    }
+
+    // Phase 1: record the outcome (once)
+    if !game.has_battle_result {
+        // A co-signed result must be disputed before falling back to the ZK path
+        if storage::pending_settlement(env, session_id).is_some() {
+            return Err(Error::SettlementPending);
+        }
+        // Check both players have revealed moves
+        if !game.player1_commitment.has_revealed || !game.player2_commitment.has_revealed {
+            return Err(Error::BothPlayersNotCommitted);
+        }
+
+        let entropy = battle_entropy(env, session_id, &game);
+        let crits = crit_rolls(&entropy.seed, game.crit_chance_bps, game.crit_multiplier_pct);
+        game.battle_result = simulate_battle(env, &game, crits);
+        game.battle_result.entropy = entropy;
+        game.has_battle_result = true;
+        game.resolved_at = env.ledger().timestamp();
+        storage::save_playback_turns(env, session_id, &detailed_turns(env, &game));
+        stats::record_battle(env, &game);
+        history::archive(env, session_id, &game);
+        BattleResolved {
+            session_id,
+            winner: game.battle_result.winner.clone(),
+            is_draw: game.battle_result.is_draw,
+        }
+        .publish(env);
+    }
+
+    // Phase 2: settle exactly once, with the game written back in one go
+    game.is_settled = true;
+    storage::save_game(env, session_id, &game);
+    settle(env, session_id, &game);
+    // After settling, so the milestones see the updated ratings
+    achievements::record_battle(env, session_id, &game);
+    // Rated wins only: sieges aren't rated, a series is rated as a whole, and the
+    // pair must be one the ladder rates
+    let rated = game.mode != GameMode::Siege
+        && storage::series_by_session(env, session_id).is_none()
+        && ratings::is_rated_pair(env, &game.player1, &game.player2);
+    if let Some(winner) = game.battle_result.winner.as_ref().filter(|_| rated) {
+        award_daily_bonus(env, winner);
+    }
+
+    Ok(game.battle_result)
+}
+
+/// Track a game whose moves are both revealed until it settles or is voided, so a
+/// resolve that keeps failing (e.g. the hub is down) can be found and retried. The
+/// list is a retry hint, not a record: once it is full the oldest entry makes room.
+pub(crate) fn queue_resolution(env: &Env, session_id: u32) {
+    let mut pending = storage::pending_resolutions(env);
+    if pending.len() >= MAX_PENDING_RESOLUTIONS {
+        pending.pop_front();
+    }
+    pending.push_back(session_id);
+    storage::save_pending_resolutions(env, &pending);
+}
+
+/// Drop a session from the pending list as it settles or is voided
+fn dequeue_resolution(env: &Env, session_id: u32) {
+    let mut pending = storage::pending_resolutions(env);
+    if let Some(index) = pending.first_index_of(session_id) {
+        pending.remove(index);
+        storage::save_pending_resolutions(env, &pending);
+    }
+}
+
+/// Up to `limit` fully revealed sessions still waiting to be resolved from
+/// `offset`, oldest first
+pub(crate) fn get_pending_resolutions(env: &Env, offset: u32, limit: u32) -> Vec<u32> {
+    let pending = storage::pending_resolutions(env);
+    let start = offset.min(pending.len());
+    let end = start.saturating_add(limit).min(pending.len());
+    pending.slice(start..end)
+}
+
+/// After the reveal deadline, end a game that a committed player never revealed.
+/// The player who did reveal wins by forfeit and takes the no-show's deposit; if
+/// neither revealed it is a draw and both deposits are returned.
+///
+/// Games with a commit deadline (correspondence) can also be claimed once it passes
+/// without both commits: whoever committed wins and gets their deposit back.
+pub(crate) fn claim_reveal_timeout(
+    env: &Env,
+    session_id: u32,
+    player: Address,
+) -> Result<BattleResult, Error> {
+    player.require_auth();
+
+    let game = storage::load_game(env, session_id)?;
+    if player != game.player1 && player != game.player2 {
+        return Err(Error::NotPlayer);
+    }
+    apply_timeout(env, session_id, game)
+}
+
+/// Apply whatever a passed deadline decides for a game or duel, as the players'
+/// own timeout claims would. Anyone can call this, so a no-show is settled without
+/// the player who is owed the win having to come back online.
+pub(crate) fn enforce_deadlines(env: &Env, session_id: u32) -> Result<BattleResult, Error> {
+    match storage::load_game(env, session_id) {
+        Ok(game) => apply_timeout(env, session_id, game),
+        Err(_) => duel::enforce_deadline(env, session_id),
+    }
+}
+
+fn apply_timeout(env: &Env, session_id: u32, mut game: Game) -> Result<BattleResult, Error> {
+    if game.has_battle_result {
+        return Err(Error::GameAlreadyEnded);
+    }
+    if storage::pending_settlement(env, session_id).is_some() {
+        return Err(Error::SettlementPending);
+    }
+
+    let both_committed = game.has_player1_commitment && game.has_player2_commitment;
+    let (p1_acted, p2_acted, deadline, reason) = if both_committed {
+        (
+            game.player1_commitment.has_revealed,
+            game.player2_commitment.has_revealed,
+            game.reveal_deadline,
+            OutcomeReason::RevealTimeout,
+        )
+    } else if game.commit_deadline != 0 {
+        (
+            game.has_player1_commitment,
+            game.has_player2_commitment,
+            game.commit_deadline,
+            OutcomeReason::CommitTimeout,
+        )
+    } else {
+        return Err(Error::BothPlayersNotCommitted);
+    };
+    if env.ledger().timestamp() <= pause::deadline(env, deadline) {
+        return Err(Error::DeadlineNotReached);
+    }
+
+    let winner = match (p1_acted, p2_acted) {
+        (true, true) => return Err(Error::NothingToClaim),
+        (true, false) => Some(game.player1.clone()),
+        (false, true) => Some(game.player2.clone()),
+        (false, false) => None,
+    };
+
+    game.battle_result = BattleResult {
+        player1_hp: game.rules.starting_hp,
+        player2_hp: game.rules.starting_hp,
+        winner: winner.clone(),
+        is_draw: winner.is_none(),
+        turn_results: vec![env],
+        entropy: battle_entropy(env, session_id, &game),
+        went_to_overtime: false,
+        overtime_turns: vec![env],
+        reason,
+    };
+    game.has_battle_result = true;
+    game.resolved_at = env.ledger().timestamp();
+    game.is_settled = true;
+    storage::save_game(env, session_id, &game);
+
+    // A revealer's own deposit was already refunded at reveal, so the winner collects
+    // the no-show's; after a missed commit the winner's own deposit is the only one held
+    match &winner {
+        Some(winner) => deposit::award(env, session_id, &game, winner),
+        None if both_committed => {
+            deposit::award(env, session_id, &game, &game.player1);
+            deposit::award(env, session_id, &game, &game.player2);
+        }
+        None => {}
+    }
+    settle(env, session_id, &game);
+
+    Ok(game.battle_result)
+}
+
+/// Call off a game neither player has committed to, once it has gone stale.
+/// The game is closed without a result: nothing is rated or paid, and the Game Hub
+/// refunds both stakes.
+pub(crate) fn cancel_game(env: &Env, session_id: u32, caller: Address) -> Result<(), Error> {
+    caller.require_auth();
+
+    let game = storage::load_game(env, session_id)?;
+    if caller != game.player1 && caller != game.player2 && caller != storage::admin(env) {
+        return Err(Error::NotPlayer);
+    }
+    if game.has_battle_result {
+        return Err(Error::GameAlreadyEnded);
+    }
+    if game.has_player1_commitment || game.has_player2_commitment {
+        return Err(Error::GameInProgress);
+    }
+    let stale_at = match game.commit_deadline {
+        0 => game.started_at + STALE_GAME_SECS,
+        deadline => deadline,
+    };
+    if env.ledger().timestamp() <= stale_at {
+        return Err(Error::DeadlineNotReached);
+    }
+
+    void(env, session_id, game);
+    GameCancelled {
+        session_id,
+        cancelled_by: caller,
+    }
+    .publish(env);
+    Ok(())
+}
+
+/// Void a game still unresolved past its maximum lifetime, whatever phase it stalled
+/// in. Anyone can call it; every stake is refunded and no result is recorded. For a
+/// series round that means calling off the series.
+pub(crate) fn expire_game(env: &Env, session_id: u32) -> Result<(), Error> {
+    let game = storage::load_game(env, session_id)?;
+    if game.has_battle_result {
+        return Err(Error::GameAlreadyEnded);
+    }
+    if env.ledger().timestamp() <= expires_at(env, session_id, &game) {
+        return Err(Error::DeadlineNotReached);
+    }
+
+    void(env, session_id, game);
+    GameExpired { session_id }.publish(env);
+    Ok(())
+}
+
+/// When `expire_game` opens: the maximum lifetime from the start, pushed back by the
+/// time spent in agreed pauses
+pub(crate) fn expires_at(env: &Env, session_id: u32, game: &Game) -> u64 {
+    game.started_at + MAX_GAME_LIFETIME_SECS + storage::game_pause(env, session_id).used_secs
+}
+
+/// Close an unfinished game with no result and hand back every stake: the Game Hub
+/// points, spectators' predictions, sponsor stakes, held deposits and any raise
+fn void(env: &Env, session_id: u32, mut game: Game) {
+    // Closed before the hub is called, like a settled game
+    game.is_cancelled = true;
+    game.battle_result.reason = OutcomeReason::Voided;
+    game.has_battle_result = true;
+    game.resolved_at = env.ledger().timestamp();
+    game.is_settled = true;
+    storage::save_game(env, session_id, &game);
+    awaiting::untrack(env, session_id, &game.player1, &game.player2);
+    dequeue_resolution(env, session_id);
+    // Spectators get their stakes back, as on a draw
+    prediction::settle(env, session_id, None);
+    picks::score(env, session_id, None);
+    // A deposit is held from a player's commit until their reveal
+    for (player, committed, revealed) in [
+        (&game.player1, game.has_player1_commitment, game.player1_commitment.has_revealed),
+        (&game.player2, game.has_player2_commitment, game.player2_commitment.has_revealed),
+    ] {
+        if committed && !revealed {
+            deposit::refund(env, &game, player);
+        }
+    }
+    // With no winner, a called raise goes back to both sides and any other to the raiser
+    raise::pay_out(env, session_id, &game);
+
+    // A series round has no hub session of its own; the whole series is called off
+    if let Some(series_id) = storage::series_by_session(env, session_id) {
+        series::call_off(env, series_id);
+        return;
+    }
+    GameHubClient::new(env, &storage::game_hub(env)).cancel_game(&session_id);
+    escrow::release(env, session_id);
+    sponsor::settle(env, session_id, None);
+}
+
+/// Voluntary surrender: `player` concedes and the opponent wins. Unlike a missed
+/// reveal this isn't griefing, so any held commit deposits go back to their owners.
+pub(crate) fn forfeit(env: &Env, session_id: u32, player: Address) -> Result<BattleResult, Error> {
+    player.require_auth();
+
+    let mut game = storage::load_game(env, session_id)?;
+
+    let winner = if player == game.player1 {
+        game.player2.clone()
+    } else if player == game.player2 {
+        game.player1.clone()
+    } else {
+        return Err(Error::NotPlayer);
+    };
+    if game.has_battle_result {
+        return Err(Error::GameAlreadyEnded);
+    }
+    if storage::pending_settlement(env, session_id).is_some() {
+        return Err(Error::SettlementPending);
+    }
+
+    game.battle_result = BattleResult {
+        player1_hp: game.rules.starting_hp,
+        player2_hp: game.rules.starting_hp,
+        winner: Some(winner),
+        is_draw: false,
+        turn_results: vec![env],
+        entropy: battle_entropy(env, session_id, &game),
+        went_to_overtime: false,
+        overtime_turns: vec![env],
+        reason: OutcomeReason::Forfeit,
+    };
+    game.has_battle_result = true;
+    game.resolved_at = env.ledger().timestamp();
+    game.is_settled = true;
+    storage::save_game(env, session_id, &game);
+
+    if game.has_player1_commitment && !game.player1_commitment.has_revealed {
+        deposit::award(env, session_id, &game, &game.player1);
+    }
+    if game.has_player2_commitment && !game.player2_commitment.has_revealed {
+        deposit::award(env, session_id, &game, &game.player2);
+    }
+    settle(env, session_id, &game);
+
+    Ok(game.battle_result)
+}
+
+/// Seed for the battle, fixed once both players have committed
+pub(crate) fn battle_entropy(env: &Env, session_id: u32, game: &Game) -> RandomnessAudit {
+    randomness::commit_window(
+        env,
+        randomness::BATTLE_DOMAIN,
+        session_id,
+        &game.player1_commitment.proof_id,
+        &game.player2_commitment.proof_id,
+    )
+}
+
+/// Crit rolls for each turn, read off the battle seed: turn `t` uses bytes `4t..4t+2`
+/// for player1 and `4t+2..4t+4` for player2, each a big-endian u16 taken mod 10 000
+/// and compared against `chance_bps`
+pub(crate) fn crit_rolls(seed: &BytesN<32>, chance_bps: u32, multiplier_pct: i32) -> combat::Crits {
+    let seed = seed.to_array();
+    let rolls_crit = |offset: usize| {
+        u32::from(u16::from_be_bytes([seed[offset], seed[offset + 1]])) % 10_000 < chance_bps
+    };
+
+    let mut crits = combat::Crits {
+        multiplier_pct,
+        ..combat::Crits::NONE
+    };
+    for turn in 0..TURNS_PER_BATTLE {
+        let offset = 4 * turn as usize;
+        if rolls_crit(offset) {
+            crits.player1 |= 1 << turn;
+        }
+        if rolls_crit(offset + 2) {
+            crits.player2 |= 1 << turn;
+        }
+    }
+    crits
+}
+
+/// Report a recorded outcome to the Game Hub and pay out. Only called after
+/// `is_settled` has been stored.
+pub(crate) fn settle(env: &Env, session_id: u32, game: &Game) {
+    awaiting::untrack(env, session_id, &game.player1, &game.player2);
+    dequeue_resolution(env, session_id);
+    identity::record_activity(env, &game.player1);
+    identity::record_activity(env, &game.player2);
+    referral::credit_first_battle(env, &game.player1);
+    referral::credit_first_battle(env, &game.player2);
+    // One indexed lookup (a list scan until migrated); nothing to do for games not
+    // started from a challenge
+    challenge::mark_challenge_completed(env, session_id, &game.player1);
+    prediction::settle(env, session_id, game.battle_result.winner.clone());
+    picks::score(env, session_id, game.battle_result.winner.as_ref());
+
+    // Series rounds settle their own side pots; the series reports to the hub
+    if let Some(series_id) = storage::series_by_session(env, session_id) {
+        raise::pay_out(env, session_id, game);
+        series::record_round(env, series_id, game);
+        return;
+    }
+
+    let battle_result = &game.battle_result;
+    let game_hub = GameHubClient::new(env, &storage::game_hub(env));
+    if battle_result.is_draw {
+        // For a draw, we don't care about player1_won value
+        // GameHub should detect this is a draw and handle accordingly (refund points, etc.)
+        // You can use false as a convention for draws, or the GameHub can be updated
+        // to check if both players have same points remaining
+        game_hub.end_game(&session_id, &false);
+    } else {
+        let winner = battle_result.winner.as_ref().unwrap();
+        let player1_won = winner == &game.player1;
+        game_hub.end_game(&session_id, &player1_won);
+        mint_csh_reward(env, winner.clone());
+
+        if game.mode == GameMode::Siege {
+            record_siege_result(env, &game.player1, &game.player2, player1_won);
+        }
+    }
+    if game.mode != GameMode::Siege {
+        ratings::record_result(env, &game.player1, &game.player2, battle_result.winner.as_ref());
+        rivals::record_result(env, session_id, &game.player1, &game.player2, battle_result.winner.as_ref());
+    }
+    escrow::release(env, session_id);
+    raise::pay_out(env, session_id, game);
+    sponsor::settle(env, session_id, battle_result.winner.as_ref());
+}
+
+/// The games stored under `session_ids`, in order, skipping sessions with no game.
+/// Only the first [`MAX_BULK_GAMES`] IDs are read.
+pub(crate) fn get_games(env: &Env, session_ids: Vec<u32>) -> Vec<SessionGame> {
+    let mut games = vec![env];
+    for session_id in session_ids.iter().take(MAX_BULK_GAMES as usize) {
+        if let Ok(game) = storage::load_game(env, session_id) {
+            games.push_back(SessionGame { session_id, game });
+        }
+    }
+    games
+}
+
+/// Get detailed game playback with all moves and results
+pub(crate) fn get_game_playback(env: &Env, session_id: u32) -> Result<GamePlayback, Error> {
+    let game = storage::load_game(env, session_id)?;
+
+    // Check if both players have revealed
+    if !game.player1_commitment.has_revealed || !game.player2_commitment.has_revealed {
+        return Err(Error::BothPlayersNotCommitted);
+    }
+
+    // Stored when the battle resolved; games resolved before that are built from
+    // their stored battle instead
+    let turn_results = storage::playback_turns(env, session_id)
+        .unwrap_or_else(|| detailed_turns(env, &game));
+
+    Ok(GamePlayback {
+        session_id,
+        player1: game.player1,
+        player2: game.player2,
+        player1_username: game.player1_username,
+        player2_username: game.player2_username,
+        turn_results,
+        final_player1_hp: game.battle_result.player1_hp,
+        final_player2_hp: game.battle_result.player2_hp,
+        winner: game.battle_result.winner,
+        is_draw: game.battle_result.is_draw,
+        reason: game.battle_result.reason,
+        player1_class: game.player1_class,
+        player2_class: game.player2_class,
+        player2_initiative_bonus: game.initiative_bonus,
+        went_to_overtime: game.battle_result.went_to_overtime,
+        rematch_of: storage::rematch_of(env, session_id),
+        rematched_as: storage::rematch(env, session_id).and_then(|rematch| rematch.rematch_session_id),
+    })
+}
+
+/// Pair every played turn of a resolved battle with the moves behind it. Overtime
+/// turns replay the regular turns' moves in order.
+fn detailed_turns(env: &Env, game: &Game) -> Vec<DetailedTurnResult> {
+    let regular_turns = game.battle_result.turn_results.len();
+    let mut all_turns = game.battle_result.turn_results.clone();
+    all_turns.append(&game.battle_result.overtime_turns);
+    let mut detailed_turns = vec![env];
+    for turn_result in all_turns.iter() {
+        let move_index = turn_result.turn % regular_turns;
+        let p1_move = game.player1_commitment.moves.moves.get(move_index).unwrap();
+        let p2_move = game.player2_commitment.moves.moves.get(move_index).unwrap();
+
+        detailed_turns.push_back(DetailedTurnResult {
+            turn: turn_result.turn,
+            player1_move: p1_move,
+            player2_move: p2_move,
+            player1_damage_dealt: turn_result.player1_damage_dealt,
+            player2_damage_dealt: turn_result.player2_damage_dealt,
+            player1_damage_taken: turn_result.player2_damage_dealt + turn_result.player2_reflected,
+            player2_damage_taken: turn_result.player1_damage_dealt + turn_result.player1_reflected,
+            player1_hp_remaining: turn_result.player1_hp_remaining,
+            player2_hp_remaining: turn_result.player2_hp_remaining,
+            player1_defense_successful: turn_result.player1_defense_successful,
+            player2_defense_successful: turn_result.player2_defense_successful,
+            player1_momentum: turn_result.player1_momentum,
+            player2_momentum: turn_result.player2_momentum,
+            player1_was_critical: turn_result.player1_was_critical,
+            player2_was_critical: turn_result.player2_was_critical,
+            player1_reflected: turn_result.player1_reflected,
+            player2_reflected: turn_result.player2_reflected,
+        });
+    }
+    detailed_turns
+}
+
+pub(crate) fn export_game(env: &Env, session_id: u32) -> Result<GameExport, Error> {
+    let game = storage::load_game(env, session_id)?;
+
+    Ok(GameExport {
+        session_id,
+        exported_at: env.ledger().sequence(),
+        game,
+        pending_settlement: match storage::pending_settlement(env, session_id) {
+            Some(pending) => ExportedSettlement::Pending(pending),
+            None => ExportedSettlement::None,
+        },
+    })
+}
+
+/// Write an exported game back with a fresh TTL. Admin auth is checked by the caller.
+pub(crate) fn import_game(env: &Env, export: GameExport) {
+    storage::store_new_game(env, export.session_id, &export.game);
+    match &export.pending_settlement {
+        ExportedSettlement::Pending(pending) => storage::save_pending_settlement(env, export.session_id, pending),
+        ExportedSettlement::None => storage::remove_pending_settlement(env, export.session_id),
+    }
+}
+
+/// Play out a revealed game, with player2 guarding the game's `initiative_bonus` on
+/// turn one (0 for games not started from a challenge) and each player's captain
+/// class modifiers. Sieges ignore `crits` and the classes.
+pub(crate) fn simulate_battle(env: &Env, game: &Game, crits: combat::Crits) -> BattleResult {
+    let p1 = moves_array(&game.player1_commitment.moves.moves);
+    let p2 = moves_array(&game.player2_commitment.moves.moves);
+    let mut turn_results = Vec::new(env);
+
+    let record_turn = |t: combat::TurnOutcome| turn_results.push_back(turn_result(&t));
+    let outcome = match game.mode {
+        GameMode::Classic | GameMode::Correspondence => {
+            let start = BattleState::with_initiative(&game.rules, game.initiative_bonus);
+            let captains = combat::Captains {
+                player1: game.player1_class,
+                player2: game.player2_class,
+            };
+            combat::simulate(&game.rules, &p1, &p2, start, crits, captains, record_turn)
+        }
+        GameMode::Siege => combat::simulate_siege(&game.rules, &p1, &p2, record_turn),
+    };
+
+    battle_result(env, outcome, &game.player1, &game.player2, turn_results)
+}
+
+/// Play out a team game from its four revealed sequences, merged lane by lane: each
+/// team's first players face each other, and so do the second. The result's winner
+/// is the winning team's captain.
+pub(crate) fn simulate_team_battle(env: &Env, team_game: &TeamGame) -> BattleResult {
+    let sequences: [[Move; TURNS_PER_BATTLE as usize]; 4] =
+        core::array::from_fn(|i| moves_array(&team_game.commitments.get(i as u32).unwrap().moves.moves));
+    let [a1, b1, a2, b2] = &sequences;
+    let mut turn_results = Vec::new(env);
+
+    let record_turn = |t: combat::TurnOutcome| turn_results.push_back(turn_result(&t));
+    let outcome = combat::simulate_team(&team_game.rules, [a1, b1], [a2, b2], record_turn);
+
+    let captain1 = team_game.team1.get(0).unwrap();
+    let captain2 = team_game.team2.get(0).unwrap();
+    battle_result(env, outcome, &captain1, &captain2, turn_results)
+}
+
+/// Play out a practice game: the player's moves against the bot's, under the rules
+/// snapshotted at start, with no initiative, crits or captain classes
+pub(crate) fn simulate_practice_battle(env: &Env, practice: &PracticeGame) -> BattleResult {
+    let player = moves_array(&practice.player_moves);
+    let bot = moves_array(&practice.bot_moves);
+    let mut turn_results = Vec::new(env);
+
+    let record_turn = |t: combat::TurnOutcome| turn_results.push_back(turn_result(&t));
+    let start = BattleState::start(&practice.rules);
+    let outcome = combat::simulate(&practice.rules, &player, &bot, start, combat::Crits::NONE, combat::Captains::NONE, record_turn);
+
+    battle_result(env, outcome, &practice.player, &env.current_contract_address(), turn_results)
+}
+
+fn turn_result(t: &combat::TurnOutcome) -> TurnResult {
+    TurnResult {
+        turn: t.turn,
+        player1_damage_dealt: t.player1_damage_dealt,
+        player2_damage_dealt: t.player2_damage_dealt,
+        player1_hp_remaining: t.player1_hp_remaining,
+        player2_hp_remaining: t.player2_hp_remaining,
+        player1_defense_successful: t.player1_defense_successful,
+        player2_defense_successful: t.player2_defense_successful,
+        player1_momentum: t.player1_momentum,
+        player2_momentum: t.player2_momentum,
+        player1_was_critical: t.player1_critical,
+        player2_was_critical: t.player2_critical,
+        player1_reflected: t.player1_reflected,
+        player2_reflected: t.player2_reflected,
+    }
+}
+
+/// Wrap a simulated outcome, moving the overtime turns (the last ones played) out of
+/// `turns` into their own list
+fn battle_result(
+    env: &Env,
+    outcome: combat::BattleOutcome,
+    player1: &Address,
+    player2: &Address,
+    turns: Vec<TurnResult>,
+) -> BattleResult {
+    let (winner, is_draw, reason) = match outcome.winner {
+        Winner::Player1 => (Some(player1.clone()), false, OutcomeReason::BattleDecision),
+        Winner::Player2 => (Some(player2.clone()), false, OutcomeReason::BattleDecision),
+        Winner::Draw => (None, true, OutcomeReason::Draw),
+    };
+    let regular_turns = turns.len() - outcome.overtime_turns;
+
+    BattleResult {
+        player1_hp: outcome.player1_hp,
+        player2_hp: outcome.player2_hp,
+        winner,
+        is_draw,
+        turn_results: turns.slice(..regular_turns),
+        entropy: randomness::none(env),
+        went_to_overtime: outcome.overtime_turns > 0,
+        overtime_turns: turns.slice(regular_turns..),
+        reason,
+    }
+}
+
+/// Copy a revealed (length-checked) move sequence into a fixed array for the combat engine.
+fn moves_array(moves: &Vec<Move>) -> [Move; TURNS_PER_BATTLE as usize] {
+    core::array::from_fn(|i| moves.get(i as u32).unwrap())
+}
+
+fn record_siege_result(env: &Env, attacker: &Address, defender: &Address, attacker_won: bool) {
+    let mut attacker_stats = storage::siege_stats(env, attacker);
+    let mut defender_stats = storage::siege_stats(env, defender);
+    if attacker_won {
+        attacker_stats.attacker_wins += 1;
+        defender_stats.defender_losses += 1;
+    } else {
+        attacker_stats.attacker_losses += 1;
+        defender_stats.defender_wins += 1;
+    }
+    storage::save_siege_stats(env, attacker, &attacker_stats);
+    storage::save_siege_stats(env, defender, &defender_stats);
+}
+
+/// Credit the daily bonus points and mint the daily CSH bonus if `winner`'s identity
+/// hasn't had them yet today (days are counted from the ledger timestamp, in UTC)
+fn award_daily_bonus(env: &Env, winner: &Address) {
+    let day = env.ledger().timestamp() / SECS_PER_DAY;
+    let identity = identity::primary(env, winner);
+    if storage::daily_bonus_day(env, &identity) == Some(day) {
+        return;
+    }
+    storage::save_daily_bonus_day(env, &identity, day);
+    points::credit(env, winner, points::DAILY_WIN_POINTS);
+    let Some(token_addr) = storage::token_contract(env) else {
+        return;
+    };
+    ClashTokenClient::new(env, &token_addr).mint(winner, &DAILY_WIN_BONUS);
+    DailyBonusAwarded {
+        player: winner.clone(),
+        day,
+        amount: DAILY_WIN_BONUS,
+    }
+    .publish(env);
+}
+
+pub(crate) fn mint_csh_reward(env: &Env, winner: Address) {
+    let Some(token_addr) = storage::token_contract(env) else {
+        return;
+    };
+    let token = ClashTokenClient::new(env, &token_addr);
+    token.mint(&winner, &CSH_REWARD_PER_WIN);
+}
@@ -0,0 +1,88 @@
+//! Match history: games are temporary and expire, so every resolved battle is also
+//! archived in persistent storage for both players, keeping each player's most
+//! recent games. Players can also pin a note hash to any session they finished, as
+//! an anchor for notes kept (encrypted) off-chain.
+
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+use crate::storage;
+use crate::{ArchivedGame, DuelPhase, Error, Game};
+
+/// Most games kept per player; the oldest drops off when a new one comes in
+const HISTORY_SIZE: u32 = 20;
+
+/// Archive a resolved battle for both players
+pub(crate) fn archive(env: &Env, session_id: u32, game: &Game) {
+    let record = ArchivedGame {
+        session_id,
+        mode: game.mode,
+        player1: game.player1.clone(),
+        player2: game.player2.clone(),
+        player1_moves: game.player1_commitment.moves.moves.clone(),
+        player2_moves: game.player2_commitment.moves.moves.clone(),
+        turn_results: game.battle_result.turn_results.clone(),
+        winner: game.battle_result.winner.clone(),
+        is_draw: game.battle_result.is_draw,
+        resolved_at: env.ledger().timestamp(),
+    };
+    push(env, &game.player1, &record);
+    push(env, &game.player2, &record);
+}
+
+/// A page of `player`'s archived games, newest first
+pub(crate) fn get_match_history(
+    env: &Env,
+    player: &Address,
+    offset: u32,
+    limit: u32,
+) -> Vec<ArchivedGame> {
+    let history = storage::match_history(env, player);
+    let start = offset.min(history.len());
+    let end = start.saturating_add(limit).min(history.len());
+    history.slice(start..end)
+}
+
+/// Attach (or replace) `player`'s note hash for a finished game or duel
+pub(crate) fn set_match_note(
+    env: &Env,
+    player: Address,
+    session_id: u32,
+    note_hash: BytesN<32>,
+) -> Result<(), Error> {
+    player.require_auth();
+    check_finished_session(env, &player, session_id)?;
+    storage::save_match_note(env, &player, session_id, &note_hash);
+    Ok(())
+}
+
+/// `player` must have played `session_id` and it must be over. Games and duels
+/// expire, so sessions only left in the player's history count too.
+fn check_finished_session(env: &Env, player: &Address, session_id: u32) -> Result<(), Error> {
+    let (player1, player2, finished) = if let Ok(game) = storage::load_game(env, session_id) {
+        (game.player1, game.player2, game.has_battle_result)
+    } else if let Ok(duel) = storage::load_duel(env, session_id) {
+        (duel.player1, duel.player2, duel.phase == DuelPhase::Finished)
+    } else {
+        let archived = storage::match_history(env, player)
+            .iter()
+            .any(|record| record.session_id == session_id);
+        return if archived { Ok(()) } else { Err(Error::GameNotFound) };
+    };
+
+    if *player != player1 && *player != player2 {
+        return Err(Error::NotPlayer);
+    }
+    if !finished {
+        return Err(Error::GameNotEnded);
+    }
+    Ok(())
+}
+
+fn push(env: &Env, player: &Address, record: &ArchivedGame) {
+    let mut history = storage::match_history(env, player);
+    history.push_front(record.clone());
+    if history.len() > HISTORY_SIZE {
+        history.pop_back();
+    }
+    storage::save_match_history(env, player, &history);
+}
@@ -0,0 +1,270 @@
+//! Username registry: validation and the two-way Address <-> Username mapping.
+//!
+//! Names can be released, transferred with both parties' auth, or taken back by the
+//! admin. When the admin sets an inactivity expiry, a name whose holder hasn't
+//! battled (or claimed it) for that long can be claimed by someone else; it stays
+//! with the holder until then. Both mapping directions always change together.
+//!
+//! A player can also link secondary addresses to their primary one, so a new wallet
+//! carries on the same pirate: the primary asks with `link_address` and the
+//! secondary confirms. Ratings, the username, the captain class and activity of a
+//! linked address all resolve to the primary. Only addresses with no record of
+//! their own can be linked, so nothing needs merging; unlinking leaves everything
+//! with the primary.
+
+use soroban_sdk::{vec, Address, Env, String, Vec};
+
+use crate::storage::{self, DataKey};
+use crate::{AddressLinked, AddressUnlinked, Error, UsernameReleased, UsernameSet};
+
+/// Most secondary addresses one identity can have
+const MAX_LINKED_ADDRESSES: u32 = 5;
+
+/// Maximum username length in bytes
+const USERNAME_MAX_LEN: u32 = 20;
+
+/// Minimum username length in bytes
+const USERNAME_MIN_LEN: u32 = 3;
+
+/// Most names or addresses one bulk lookup resolves
+pub(crate) const MAX_BULK_LOOKUPS: u32 = 50;
+
+pub(crate) fn set_username(env: &Env, caller: Address, username: String) -> Result<(), Error> {
+    caller.require_auth();
+    let caller = primary(env, &caller);
+
+    // Validate username length (max 20 characters, min 3)
+    if username.len() > USERNAME_MAX_LEN {
+        return Err(Error::UsernameTooLong);
+    }
+    if username.len() < USERNAME_MIN_LEN {
+        return Err(Error::UsernameTooShort);
+    }
+
+    // Validate username format (lowercase alphanumeric + underscore only)
+    if !validate_username_format(&username) {
+        return Err(Error::InvalidUsernameFormat);
+    }
+
+    // Check for reserved usernames
+    let reserved_names = vec![
+        env,
+        String::from_str(env, "admin"),
+        String::from_str(env, "system"),
+        String::from_str(env, "moderator"),
+        String::from_str(env, "support"),
+    ];
+
+    for reserved_name in reserved_names.iter() {
+        if username == reserved_name {
+            return Err(Error::UsernameReserved);
+        }
+    }
+
+    // Check if username is already taken by someone else; an expired name is freed
+    if let Some(existing_address) = storage::address_by_username(env, &username) {
+        if existing_address == caller {
+            return Ok(());
+        }
+        if !is_expired(env, &existing_address) {
+            return Err(Error::UsernameAlreadyTaken);
+        }
+        unassign(env, &existing_address);
+    }
+
+    assign(env, &caller, username);
+    Ok(())
+}
+
+/// Give up the caller's username so anyone can claim it
+pub(crate) fn release_username(env: &Env, caller: Address) -> Result<(), Error> {
+    caller.require_auth();
+    unassign(env, &primary(env, &caller)).ok_or(Error::NoUsername)?;
+    Ok(())
+}
+
+/// Admin removal of an abusive name from whoever holds it
+pub(crate) fn force_release(env: &Env, username: String) -> Result<(), Error> {
+    let holder = storage::address_by_username(env, &username).ok_or(Error::NoUsername)?;
+    unassign(env, &holder);
+    Ok(())
+}
+
+/// Hand `from`'s username to `to`, replacing any name `to` had
+pub(crate) fn transfer_username(env: &Env, from: Address, to: Address) -> Result<(), Error> {
+    from.require_auth();
+    to.require_auth();
+
+    let (from, to) = (primary(env, &from), primary(env, &to));
+    if from == to {
+        return Err(Error::UsernameAlreadyTaken);
+    }
+    let username = unassign(env, &from).ok_or(Error::NoUsername)?;
+    unassign(env, &to);
+    assign(env, &to, username);
+    Ok(())
+}
+
+/// Mark `player` as active, which keeps their name from expiring
+pub(crate) fn record_activity(env: &Env, player: &Address) {
+    storage::save_last_active_at(env, &primary(env, player), env.ledger().timestamp());
+}
+
+/// Usernames of `addresses`, position for position; `None` where there is none. Only
+/// the first `MAX_BULK_LOOKUPS` are resolved.
+pub(crate) fn get_usernames(env: &Env, addresses: Vec<Address>) -> Vec<Option<String>> {
+    let mut usernames = vec![env];
+    for address in addresses.iter().take(MAX_BULK_LOOKUPS as usize) {
+        usernames.push_back(storage::username(env, &primary(env, &address)));
+    }
+    usernames
+}
+
+/// Holders of `usernames`, position for position, like `get_usernames`
+pub(crate) fn get_addresses(env: &Env, usernames: Vec<String>) -> Vec<Option<Address>> {
+    let mut addresses = vec![env];
+    for username in usernames.iter().take(MAX_BULK_LOOKUPS as usize) {
+        addresses.push_back(storage::address_by_username(env, &username));
+    }
+    addresses
+}
+
+/// The address `player` plays as: the primary they're linked to, or themselves
+pub(crate) fn primary(env: &Env, player: &Address) -> Address {
+    storage::linked_to(env, player).unwrap_or_else(|| player.clone())
+}
+
+/// Ask to link `secondary` to `primary`; `secondary` confirms with `confirm_link`
+pub(crate) fn link_address(env: &Env, primary: Address, secondary: Address) -> Result<(), Error> {
+    primary.require_auth();
+    check_linkable(env, &primary, &secondary)?;
+    storage::save_link_request(env, &secondary, &primary);
+    Ok(())
+}
+
+pub(crate) fn confirm_link(env: &Env, secondary: Address, primary: Address) -> Result<(), Error> {
+    secondary.require_auth();
+    if storage::link_request(env, &secondary).as_ref() != Some(&primary) {
+        return Err(Error::InvalidLink);
+    }
+    // Checked again: either side may have played or linked since the request
+    check_linkable(env, &primary, &secondary)?;
+
+    storage::remove_link_request(env, &secondary);
+    let mut linked = storage::linked_addresses(env, &primary);
+    linked.push_back(secondary.clone());
+    storage::save_linked_addresses(env, &primary, &linked);
+    storage::save_linked_to(env, &secondary, &primary);
+    AddressLinked { primary, secondary }.publish(env);
+    Ok(())
+}
+
+/// Either side can undo a link; the primary keeps the record
+pub(crate) fn unlink_address(env: &Env, caller: Address, secondary: Address) -> Result<(), Error> {
+    caller.require_auth();
+    let primary = storage::linked_to(env, &secondary).ok_or(Error::InvalidLink)?;
+    if caller != primary && caller != secondary {
+        return Err(Error::InvalidLink);
+    }
+
+    let mut linked = storage::linked_addresses(env, &primary);
+    if let Some(index) = linked.first_index_of(&secondary) {
+        linked.remove(index);
+    }
+    storage::save_linked_addresses(env, &primary, &linked);
+    storage::remove_linked_to(env, &secondary);
+    AddressUnlinked { primary, secondary }.publish(env);
+    Ok(())
+}
+
+/// `secondary` must be a fresh address and `primary` not itself linked or full
+fn check_linkable(env: &Env, primary: &Address, secondary: &Address) -> Result<(), Error> {
+    let has_record = storage::player_stats(env, secondary).is_some()
+        || storage::username(env, secondary).is_some()
+        || !storage::linked_addresses(env, secondary).is_empty();
+    if primary == secondary
+        || has_record
+        || is_busy(env, secondary)
+        || storage::linked_to(env, primary).is_some()
+        || storage::linked_to(env, secondary).is_some()
+        || storage::linked_addresses(env, primary).len() >= MAX_LINKED_ADDRESSES
+    {
+        return Err(Error::InvalidLink);
+    }
+    Ok(())
+}
+
+/// Whether `player` is in a live session, has a challenge listed or a post on the
+/// open board. Linking it then could pit one identity against itself.
+fn is_busy(env: &Env, player: &Address) -> bool {
+    !storage::player_sessions(env, player).is_empty()
+        || !storage::player_challenges(env, player).is_empty()
+        || storage::open_challenges(env)
+            .iter()
+            .filter_map(|challenge_id| storage::load_open_challenge(env, challenge_id))
+            .any(|open| open.challenger == *player)
+}
+
+/// Holders with no recorded activity (names claimed before it was tracked) never
+/// expire
+fn is_expired(env: &Env, holder: &Address) -> bool {
+    let expiry_secs = storage::username_expiry_secs(env);
+    let last_active_at = storage::last_active_at(env, holder);
+    expiry_secs > 0 && last_active_at > 0 && env.ledger().timestamp() > last_active_at + expiry_secs
+}
+
+/// Point both mappings at `player`, dropping their previous name
+fn assign(env: &Env, player: &Address, username: String) {
+    unassign(env, player);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Username(player.clone()), &username);
+    env.storage()
+        .persistent()
+        .set(&DataKey::AddressByUsername(username.clone()), player);
+    record_activity(env, player);
+
+    UsernameSet {
+        player: player.clone(),
+        username,
+    }
+    .publish(env);
+}
+
+/// Remove `player`'s name from both mappings; returns it if they had one
+fn unassign(env: &Env, player: &Address) -> Option<String> {
+    let username = storage::username(env, player)?;
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Username(player.clone()));
+    if storage::address_by_username(env, &username).as_ref() == Some(player) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AddressByUsername(username.clone()));
+    }
+
+    UsernameReleased {
+        player: player.clone(),
+        username: username.clone(),
+    }
+    .publish(env);
+    Some(username)
+}
+
+fn validate_username_format(username: &String) -> bool {
+    let bytes = username.to_bytes();
+
+    for i in 0..bytes.len() {
+        let byte = bytes.get(i).unwrap();
+        // Only allow lowercase a-z (97-122), 0-9 (48-57), and underscore (95)
+        let is_valid = (97..=122).contains(&byte) || // a-z only
+                       (48..=57).contains(&byte) ||  // 0-9
+                       byte == 95;                     // underscore
+
+        if !is_valid {
+            return false;
+        }
+    }
+
+    true
+}
@@ -8,12 +8,62 @@
 //!
 //! **Game Hub Integration:**
 //! All games must be played through the Game Hub contract for points tracking.
+//!
+//! **Layout:** this file holds the shared types and the contract entrypoints, which
+//! delegate to the domain modules:
+//! - [`combat`]: pure battle engine (damage, combos, winner)
+//! - `game`: game lifecycle on the Game Hub (start, resolve, playback)
+//! - `zk`: proof verification and commit/reveal
+//! - `signers`: session keys that commit and reveal for a player
+//! - `deposit`: refundable anti-grief deposit taken at commit
+//! - `raise`: blind wager raises between commit and reveal
+//! - `agreed_pause`: agreed breaks in correspondence games
+//! - `coaching`: coaches who read a game's moves after a delay
+//! - `duel`: turn-by-turn duels, one committed move per round
+//! - `pvp`: invite-based turn-by-turn matches
+//! - `team`: 2v2 team games
+//! - `practice`: practice games against an on-chain bot
+//! - `series`: best-of-N series over linked classic games
+//! - `tournament`: single- and double-elimination brackets and their prizes
+//! - `challenge`: challenges between players and the open challenge board
+//! - `rematch`: rematch offers after a battle
+//! - `lobby`: wager-tier matchmaking queues
+//! - `friends`: friends lists and the looking-for-game flag
+//! - `rivals`: named rivalries and their bonus
+//! - `identity`: usernames and linked addresses
+//! - `referral`: referral bonuses
+//! - `moderation`: shadow flags for suspected cheaters
+//! - `ratings`: Elo ratings, the leaderboard and the rating histogram
+//! - `seasons`: season ladders and their rewards
+//! - `achievements`: milestone badges awarded at resolve
+//! - `history`: permanent match history of resolved battles
+//! - `stats`: contract-wide battle telemetry
+//! - `picks`: free spectator picks
+//! - `prediction`: spectator prediction pools settled with the game
+//! - `sponsor`: admin-funded free-to-play tier
+//! - `points`: bonus points settled to the Game Hub
+//! - `promo`: admin-created promo codes
+//! - `fees`: tiered rake on token pots
+//! - `escrow`: receipts for what the Game Hub holds per session
+//! - `winnings`: pull-model token payouts
+//! - `settlement`: optimistic settlement of co-signed off-chain results
+//! - `randomness`: auditable seeds derived from the commit window
+//! - `awaiting`: each player's live sessions and the moves due from them
+//! - `badges`: navbar counts of what awaits a player
+//! - `activity`: per-player activity logs for delta sync
+//! - `registry`: session registry for indexers
+//! - `pause`: circuit breakers for incidents
+//! - `migration`: storage schema versions and the batched migration
+//! - `storage`: storage keys and typed accessors
+//! - `debug`: cost benchmark entrypoints (`debug` feature only)
+//! - `scenarios`: canonical battle fixtures (`scenarios` feature only)
+//! - `testutils`: builders for tests (`testutils` feature only)
 
 use soroban_sdk::auth::{Context, CustomAccountInterface};
 use soroban_sdk::crypto::Hash;
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, Map, String, Vec, contract, contracterror, contractimpl,
-    contracttype, vec, Vec as SorobanVec, Val, InvokeError, Symbol,
+    Address, Bytes, BytesN, Env, Map, String, Symbol, Vec, contract, contracterror, contractevent,
+    contractimpl, contracttype, Val,
 };
 use stellar_accounts::smart_account::{
     AuthPayload, ContextRule, ContextRuleType, Signer, SmartAccount, SmartAccountError,
@@ -25,12 +75,63 @@ use stellar_accounts::smart_account::{
     update_context_rule_valid_until as smart_update_context_rule_valid_until,
 };
 
-// use ultrahonk_soroban_verifier::PROOF_BYTES;
-
-// Import UltraHonk verifier contract
-// mod ultrahonk_contract {
-//     soroban_sdk::contractimport!(file = "ultrahonk_soroban_contract.wasm");
-// }
+use pause::Breaker;
+
+mod achievements;
+mod activity;
+mod agreed_pause;
+mod awaiting;
+mod badges;
+mod challenge;
+mod coaching;
+pub mod combat;
+#[cfg(feature = "debug")]
+mod debug;
+mod deposit;
+mod duel;
+mod escrow;
+mod fees;
+mod friends;
+mod game;
+mod history;
+mod identity;
+mod lobby;
+mod migration;
+mod moderation;
+mod pause;
+mod picks;
+mod points;
+mod promo;
+mod practice;
+mod prediction;
+mod pvp;
+mod raise;
+mod randomness;
+mod ratings;
+mod referral;
+mod registry;
+mod rematch;
+mod rivals;
+#[cfg(any(test, feature = "scenarios"))]
+pub mod scenarios;
+mod seasons;
+mod series;
+mod settlement;
+mod signers;
+mod sponsor;
+mod stats;
+mod storage;
+mod team;
+mod tournament;
+mod winnings;
+mod zk;
+
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
+
+mod test;
+
+pub use storage::DataKey;
 
 // Import GameHub contract interface
 #[soroban_sdk::contractclient(name = "GameHubClient")]
@@ -46,6 +147,14 @@ pub trait GameHub {
     );
 
     fn end_game(env: Env, session_id: u32, player1_won: bool);
+
+    /// End a session with no result, returning both players' locked points
+    fn cancel_game(env: Env, session_id: u32);
+
+    fn is_registered(env: Env, player: Address) -> bool;
+
+    /// Add points `game_id` awarded outside a session to `player`'s balance
+    fn add_points(env: Env, game_id: Address, player: Address, points: i128);
 }
 
 #[soroban_sdk::contractclient(name = "ClashTokenClient")]
@@ -58,31 +167,72 @@ pub trait ClashToken {
 // Constants
 // ============================================================================
 
-// pub const ULTRAHONK_CONTRACT_ADDRESS: &str = "CCSORRUPEPDR4KPXLWIF4WCHERHJDOAHRAK6NTFSI2WLPPFTDVTATM74";
-
-/// Each player starts with 100 HP
+/// Each player starts with 100 HP (under the default rules)
 const STARTING_HP: i32 = 100;
 
-/// Number of turns per battle
+/// Moves in a committed sequence: the circuit proves exactly three, so no rule set
+/// can play more turns than this
 const TURNS_PER_BATTLE: u32 = 3;
 
-/// Combo bonus damage for 2 consecutive same attacks
+/// Default combo bonus damage for 2 consecutive same attacks
 const COMBO_2_BONUS: i32 = 10;
 
-/// Combo bonus damage for 3 consecutive same attacks
+/// Default combo bonus damage for 3 consecutive same attacks
 const COMBO_3_BONUS: i32 = 25;
 
-/// TTL for game storage (30 days in ledgers)
-const GAME_TTL_LEDGERS: u32 = 518_400;
+/// Default bonus damage on a landed attack for a player who won the previous turn
+const MOMENTUM_BONUS: i32 = 5;
+
+/// Default turn-one damage reduction for the player who accepted a challenge
+const DEFAULT_INITIATIVE_BONUS: i32 = 5;
+
+/// Upper bound on the initiative bonus, well below the weakest attack (30)
+const MAX_INITIATIVE_BONUS: i32 = 10;
+
+/// Default chance of a critical hit per attack, in basis points (off until the admin
+/// turns it on)
+const DEFAULT_CRIT_CHANCE_BPS: u32 = 0;
+
+/// Upper bound on the crit chance (50%)
+const MAX_CRIT_CHANCE_BPS: u32 = 5_000;
+
+/// Default damage of a critical hit, as a percentage of the normal hit
+const DEFAULT_CRIT_MULTIPLIER_PCT: i32 = 150;
+
+/// Upper bound on the crit multiplier (triple damage)
+const MAX_CRIT_MULTIPLIER_PCT: i32 = 300;
+
+/// Default time players have to reveal once both have committed (24 hours)
+const DEFAULT_REVEAL_WINDOW_SECS: u64 = 24 * 60 * 60;
+
+/// Bounds on the configurable reveal window (5 minutes to 7 days)
+const MIN_REVEAL_WINDOW_SECS: u64 = 5 * 60;
+const MAX_REVEAL_WINDOW_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// Bounds on how long spent nullifiers are kept (7 to 180 days in ledgers): never
+/// shorter than a challenge can sit open, never past the network's maximum TTL
+const MIN_NULLIFIER_RETENTION_LEDGERS: u32 = 120_960;
+const MAX_NULLIFIER_RETENTION_LEDGERS: u32 = 3_110_400;
 
-/// TTL for challenges (7 days in ledgers)
-const CHALLENGE_TTL_LEDGERS: u32 = 120_960;
 const CSH_REWARD_PER_WIN: i128 = 10_0000000; // 10 CSH (7 decimals)
 
+/// Extra CSH for a player's first rated win of each day
+const DAILY_WIN_BONUS: i128 = 5_0000000;
+
+/// Default wager ceiling until the admin configures one: 1,000,000 points (7 decimals)
+const DEFAULT_MAX_WAGER: i128 = 1_000_000 * 10_000_000;
+
+/// Semver of the contract API reported by `version()`. Bump the minor version with
+/// every release that adds entrypoints or fields, and the major one when a release
+/// removes or changes them.
+const CONTRACT_VERSION: &str = "1.0.0";
+
 // ============================================================================
 // Errors
 // ============================================================================
 
+/// Every entrypoint returns this single error type. Codes are stable so the
+/// frontend can map them to messages; new variants are only ever appended.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
@@ -92,28 +242,186 @@ pub enum Error {
     AlreadyCommitted = 3,
     BothPlayersNotCommitted = 4,
     GameAlreadyEnded = 5,
+    /// The verifier ran and rejected the proof
     InvalidProof = 6,
+    /// Catch-all verifier failure from before the causes were split; no longer
+    /// returned, see `InvalidProof` and `VerifierTrapped`
     ProofVerificationFailed = 7,
     InvalidMoveSequence = 8,
     UsernameAlreadyTaken = 9,
     UsernameTooLong = 10,
     ChallengeNotFound = 11,
     ChallengeExpired = 12,
+    /// A player tried to challenge, invite or start a game against themselves, or
+    /// against an address linked to them
     CannotChallengeSelf = 13,
     UsernameTooShort = 14,
     InvalidUsernameFormat = 15,
     UsernameReserved = 16,
-    AlreadyRevealed     = 17,
-    CommitmentMismatch  = 18,
+    AlreadyRevealed = 17,
+    CommitmentMismatch = 18,
+    /// Public inputs are not the expected `[player | session_id | commitment]` layout
     InvalidPublicInputs = 19,
-}
-#[contracterror]
-#[repr(u32)]
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub enum ClashError {
-    CommitmentExists = 1,
-    NullifierUsed = 2,
-    VerificationFailed = 3,
+    /// The current duel phase closed before this action arrived
+    DeadlinePassed = 20,
+    /// A timeout was claimed while the opponent still has time to act
+    DeadlineNotReached = 21,
+    /// Wager is negative or outside the configured limits
+    InvalidWager = 22,
+    ChallengeAlreadyAccepted = 23,
+    /// The Game Hub refused or failed to start the session
+    GameHubCallFailed = 24,
+    /// Both players revealed in time, so there is no timeout to claim
+    NothingToClaim = 25,
+    /// Ranked play requires both players to be registered with the Game Hub
+    PlayerNotRegistered = 26,
+    /// A co-signed result is waiting out its challenge window for this game
+    SettlementPending = 27,
+    /// There is no co-signed result to dispute or finalize
+    NoPendingSettlement = 28,
+    /// The signed result names a non-player as winner, is inconsistent, or a player
+    /// has no settlement key registered
+    InvalidSignedResult = 29,
+    /// A `GameConfig` value or the nullifier retention is out of range
+    InvalidGameConfig = 30,
+    /// Raise amount is out of bounds, or raising is off for this game
+    InvalidRaise = 31,
+    /// Raises are only allowed once per game, after both commits and before any reveal
+    RaiseNotAllowed = 32,
+    /// There is no raise from the opponent to call or fold
+    NoRaisePending = 33,
+    /// The pending raise must be called or folded before revealing
+    RaisePending = 34,
+    /// Invite code lifetime is zero or longer than a challenge may stay open
+    InvalidTtl = 35,
+    /// A challenge can only be forwarded once
+    ChallengeAlreadyForwarded = 36,
+    /// No lobby tier with that number
+    InvalidTier = 37,
+    /// The player is already waiting in a lobby
+    AlreadyQueued = 38,
+    /// The player is not waiting in any lobby
+    NotQueued = 39,
+    /// Fee tiers are out of order or charge more than the maximum rate
+    InvalidFeeSchedule = 40,
+    /// Promo code is unknown, used up, or created with a zero amount or cap
+    InvalidPromoCode = 41,
+    /// Each player can redeem a promo code once
+    PromoAlreadyRedeemed = 42,
+    /// The challenge was declined or cancelled
+    ChallengeClosed = 43,
+    /// The open challenge board is full, or the player already has 3 challenges on
+    /// it; wait for some to be taken or expire, or cancel one
+    TooManyOpenChallenges = 44,
+    /// The player's friends list is full
+    TooManyFriends = 45,
+    /// The session hasn't finished yet
+    GameNotEnded = 46,
+    /// A series must be best of 3 or best of 5
+    InvalidSeriesLength = 47,
+    SeriesAlreadyExists = 48,
+    SeriesNotFound = 49,
+    TournamentNotFound = 50,
+    /// Bad field size (a power of two up to 32), deadline or entry
+    InvalidTournament = 51,
+    TournamentFull = 52,
+    RegistrationClosed = 53,
+    AlreadyRegistered = 54,
+    /// These public inputs were already used for a commit
+    NullifierUsed = 55,
+    /// A `RuleSet` value is out of range
+    InvalidRules = 56,
+    /// Reserved: reveals once carried a wager field checked against the stake
+    WagerMismatch = 57,
+    /// Teams must be two players each, with nobody on both sides
+    InvalidTeam = 58,
+    RematchNotFound = 59,
+    /// A rematch of this session has already been offered
+    RematchAlreadyRequested = 60,
+    /// The game commits the other way: ZK calls on a hash-mode game or vice versa
+    WrongCommitMode = 61,
+    /// No verifier is registered for this circuit version
+    UnknownCircuitVersion = 62,
+    /// The game's circuit version has been deprecated, so its proofs are refused
+    CircuitDeprecated = 63,
+    /// New games are pinned to this version; register a newer one first
+    CircuitInUse = 64,
+    /// Predictions on this game closed when the first commit landed
+    PredictionsClosed = 65,
+    /// Bad amount, backed player or side, a player predicting on their own game, or
+    /// no prediction token configured
+    InvalidPrediction = 66,
+    /// The running season has to end before the next one starts
+    SeasonInProgress = 67,
+    /// Season ID not above the last one, end time already passed, or no season to end
+    InvalidSeason = 68,
+    /// The claim window for this reward has closed
+    ClaimExpired = 69,
+    /// A player has already committed, so the game can only end through play or
+    /// its deadlines
+    GameInProgress = 70,
+    /// A session signer must be a different address from the player, can't be set for
+    /// a game that takes a commit deposit, and can only be revoked once set
+    InvalidSessionSigner = 71,
+    /// `sweep_fees` needs a treasury set with `set_treasury`
+    NoTreasury = 72,
+    /// The challenge changed (e.g. was forwarded) since the nonce the acceptance was
+    /// built against
+    StaleChallenge = 73,
+    /// The admin has paused this entrypoint (or the whole contract); see `get_pause`
+    ContractPaused = 74,
+    /// Not the player's coach for the game, or a player naming themselves
+    InvalidCoach = 75,
+    /// Coaches see the moves only once the battle is resolved
+    CoachingLocked = 76,
+    /// The player (or name) has no username to release or transfer
+    NoUsername = 77,
+    /// A game or game proposal already uses this session ID
+    SessionTaken = 78,
+    /// No sponsor tier is set, or a player has played too many rated games for it
+    NotSponsored = 79,
+    /// A player has used up today's sponsored games
+    SponsorLimitReached = 80,
+    /// The sponsor pool can't cover both stakes, or the withdrawal asked for
+    SponsorPoolEmpty = 81,
+    /// Stake or daily limit not positive, or a token change while the pool holds funds
+    InvalidSponsorTier = 82,
+    /// Not a live correspondence game, no pause to accept or resume, one already
+    /// running, or more paused time than the game's allowance
+    InvalidPause = 83,
+    /// The player already named a referrer
+    AlreadyReferred = 84,
+    /// Self-referral, a player who has already played, or a referrer who hasn't
+    InvalidReferral = 85,
+    /// Self link, no matching request, a secondary with a record, live games or
+    /// challenges of its own, a primary that is itself linked, or too many linked
+    /// addresses
+    InvalidLink = 86,
+    /// Settling more bonus points than the player has, or none
+    InsufficientPoints = 87,
+    /// The game already holds the most spectator picks it takes
+    PicksFull = 88,
+    /// A ledger range that is reversed or spans more than a day
+    InvalidRange = 89,
+    /// A signed result for this session was disputed; it must be played out on-chain
+    SettlementDisputed = 90,
+    /// A player tried to add themselves as a friend
+    CannotFriendSelf = 91,
+    /// A player is already in as many unsettled games and duels as they can be
+    TooManyLiveSessions = 92,
+    /// Prize shares that are empty, pay more places than the field or don't sum to
+    /// 100%, a pool that is empty or already set, or vesting that pays everything at
+    /// once, locks for no time or too long, or is already set
+    InvalidPrizeSchedule = 93,
+    /// Check-in is only open in the half hour before a tournament starts
+    CheckInClosed = 94,
+    /// The locked part of a tournament prize hasn't vested yet
+    PrizeStillVesting = 95,
+    /// The tournament has already completed or been cancelled
+    TournamentFinished = 96,
+    /// The verifier trapped or returned something other than `()`, so the proof
+    /// could not be checked at all (misconfigured or broken verifier contract)
+    VerifierTrapped = 97,
 }
 
 // ============================================================================
@@ -127,6 +435,8 @@ pub enum Attack {
     Slash = 0,    // 30 damage, stopped by Dodge
     Fireball = 1, // 40 damage, stopped by Counter
     Lightning = 2, // 35 damage, stopped by Block
+    Broadside = 3, // 50 damage, stopped by Dodge; too slow to combo
+    Hook = 4,      // 20 damage, stopped by Riposte; ignores the target's guard
 }
 
 #[contracttype]
@@ -136,6 +446,7 @@ pub enum Defense {
     Block = 0,   // Stops Lightning 
     Dodge = 1,   // Stops Slash 
     Counter = 2, // Stops Fireball
+    Riposte = 3, // Stops Hook and sends part of its damage back
 }
 
 #[contracttype]
@@ -167,6 +478,56 @@ pub struct BattleResult {
     pub winner: Option<Address>,  
     pub is_draw: bool,            
     pub turn_results: Vec<TurnResult>,
+    pub entropy: RandomnessAudit, // Seed any chance-based mechanic of this battle draws from
+    pub went_to_overtime: bool, // Tied after the regular turns and played sudden death
+    pub overtime_turns: Vec<TurnResult>, // Numbered on from the last regular turn
+    pub reason: OutcomeReason, // Why the game ended, for stats, payouts and UI copy alike
+}
+
+/// Why a game, duel or team game ended
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum OutcomeReason {
+    Pending = 0,        // No result yet
+    BattleDecision = 1, // Played out (or agreed off-chain) with a winner
+    Draw = 2,           // Played out (or agreed off-chain) level
+    Forfeit = 3,        // A player conceded or folded a raise
+    CommitTimeout = 4,  // Commit deadline missed; a draw if neither committed
+    RevealTimeout = 5,  // Reveal deadline missed; a draw if neither revealed
+    Voided = 6,         // Called off before any commit with `cancel_game`; stakes refunded
+}
+
+/// Pirate captain a player battles as, with a passive modifier (see `combat::Captains`)
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum CaptainClass {
+    None = 0,      // No class picked: no modifier
+    Brawler = 1,   // Slashes that land deal 10% more
+    Trickster = 2, // A Dodge that doesn't stop an attack still takes a quarter off it
+    Gunner = 3,    // Fireball crits deal 50 percentage points more
+}
+
+/// How a battle still level after its regular turns is decided (see `combat::simulate`)
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum TieBreak {
+    SuddenDeath = 0, // Escalating overtime turns until the HP differs
+    Player1Wins = 1, // The challenger (player1) takes it
+    DamageDealt = 2, // More attack damage landed, not counting reflections
+    TrueDraw = 3,    // Left as a draw
+}
+
+/// How a random seed was derived, so anyone can recompute it:
+/// `seed = sha256(domain | inputs)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RandomnessAudit {
+    pub domain: String,
+    pub inputs: Bytes, // session_id (4 bytes BE) | player1 commitment | player2 commitment
+    pub seed: BytesN<32>,
 }
 
 #[contracttype]
@@ -179,6 +540,265 @@ pub struct TurnResult {
     pub player2_hp_remaining: i32,
     pub player1_defense_successful: bool,
     pub player2_defense_successful: bool,
+    pub player1_momentum: bool, // Carried into the next turn: won this one, or kept it on a tie
+    pub player2_momentum: bool,
+    pub player1_was_critical: bool, // The player's attack landed as a critical hit
+    pub player2_was_critical: bool,
+    pub player1_reflected: i32, // Damage the player's Riposte sent back at the opponent
+    pub player2_reflected: i32,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum GameMode {
+    Classic = 0, // Both players attack and defend
+    Siege = 1,   // player1 only attacks, player2 only defends and wins by surviving
+    Correspondence = 2, // Classic rules with multi-day windows, kept in persistent storage
+}
+
+/// How a game's moves are committed and revealed
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum CommitMode {
+    Zk = 0,   // UltraHonk proof checked by the verifier contract
+    Hash = 1, // Plain sha256(moves || salt), for deployments without the verifier
+}
+
+/// A circuit version's verifier contract in the registry
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifierEntry {
+    pub address: Address,
+    pub deprecated: bool, // Commits pinned to this version are refused
+}
+
+/// Which deadline a [`WindowOpened`] event announces
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum GameWindow {
+    Commit = 0,
+    Reveal = 1,
+}
+
+/// Published when a correspondence game opens its commit or reveal window, so
+/// off-chain services can schedule reminders ahead of `deadline`
+#[contractevent(topics = ["window_opened"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WindowOpened {
+    #[topic]
+    pub session_id: u32,
+    pub window: GameWindow,
+    pub deadline: u64,
+}
+
+#[contractevent(topics = ["challenge_sent"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChallengeSent {
+    #[topic]
+    pub challenge_id: u32,
+    #[topic]
+    pub challenger: Address,
+    #[topic]
+    pub challenged: Address,
+    pub points_wagered: i128,
+}
+
+#[contractevent(topics = ["challenge_accepted"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChallengeAccepted {
+    #[topic]
+    pub challenge_id: u32,
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub challenged: Address,
+    pub challenger: Address,
+}
+
+#[contractevent(topics = ["rematch_requested"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RematchRequested {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub opponent: Address,
+    pub requester: Address,
+}
+
+#[contractevent(topics = ["rematch_accepted"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RematchAccepted {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub rematch_session_id: u32,
+    pub accepted_by: Address,
+}
+
+#[contractevent(topics = ["achievement_unlocked"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AchievementUnlocked {
+    #[topic]
+    pub player: Address,
+    pub achievement: Achievement,
+    pub session_id: u32,
+}
+
+#[contractevent(topics = ["daily_bonus_awarded"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DailyBonusAwarded {
+    #[topic]
+    pub player: Address,
+    pub day: u64, // Ledger timestamp / 86400
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["points_settled"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PointsSettled {
+    #[topic]
+    pub player: Address,
+    pub amount: i128, // Moved from the local balance to the Game Hub
+}
+
+#[contractevent(topics = ["game_cancelled"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameCancelled {
+    #[topic]
+    pub session_id: u32,
+    pub cancelled_by: Address,
+}
+
+#[contractevent(topics = ["game_expired"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameExpired {
+    #[topic]
+    pub session_id: u32,
+}
+
+#[contractevent(topics = ["session_signer_set"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionSignerSet {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player: Address,
+    pub signer: Option<Address>, // None when revoked
+}
+
+#[contractevent(topics = ["coach_set"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoachSet {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player: Address,
+    pub coach: Option<Address>, // None when removed
+}
+
+#[contractevent(topics = ["moves_committed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MovesCommitted {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player: Address,
+    pub commitment: BytesN<32>,
+}
+
+#[contractevent(topics = ["moves_revealed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MovesRevealed {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player: Address,
+    pub moves: Vec<Move>,
+}
+
+/// Published once, when the battle is simulated; a repeated `resolve_battle` is silent
+#[contractevent(topics = ["battle_resolved"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BattleResolved {
+    #[topic]
+    pub session_id: u32,
+    pub winner: Option<Address>,
+    pub is_draw: bool,
+}
+
+#[contractevent(topics = ["username_set"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UsernameSet {
+    #[topic]
+    pub player: Address,
+    pub username: String,
+}
+
+#[contractevent(topics = ["referral_credited"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralCredited {
+    #[topic]
+    pub referrer: Address,
+    pub player: Address, // The referred player, after their first battle
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["game_paused"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GamePaused {
+    #[topic]
+    pub session_id: u32,
+    pub paused_until: u64,
+}
+
+#[contractevent(topics = ["game_resumed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameResumed {
+    #[topic]
+    pub session_id: u32,
+    pub resumed_by: Address,
+}
+
+#[contractevent(topics = ["captain_class_set"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CaptainClassSet {
+    #[topic]
+    pub player: Address,
+    pub class: CaptainClass,
+}
+
+#[contractevent(topics = ["storage_migrated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageMigrated {
+    #[topic]
+    pub version: u32, // Layout every stored entry is now on
+}
+
+#[contractevent(topics = ["address_linked"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddressLinked {
+    #[topic]
+    pub primary: Address,
+    pub secondary: Address,
+}
+
+#[contractevent(topics = ["address_unlinked"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddressUnlinked {
+    #[topic]
+    pub primary: Address,
+    pub secondary: Address,
+}
+
+#[contractevent(topics = ["username_released"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UsernameReleased {
+    #[topic]
+    pub player: Address,
+    pub username: String,
 }
 
 #[contracttype]
@@ -186,14 +806,71 @@ pub struct TurnResult {
 pub struct Game {
     pub player1: Address,
     pub player2: Address,
+    pub player1_username: Option<String>, // Usernames as of the start, for playback
+    pub player2_username: Option<String>,
     pub player1_points: i128,
     pub player2_points: i128,
+    pub mode: GameMode,
     pub has_player1_commitment: bool,
     pub player1_commitment: PlayerCommitment,
     pub has_player2_commitment: bool,
     pub player2_commitment: PlayerCommitment,
     pub has_battle_result: bool,
     pub battle_result: BattleResult,
+    pub is_settled: bool, // Hub reported and rewards paid; set before those calls are made
+    pub commit_deadline: u64, // Correspondence only; 0 means commits never close
+    pub reveal_deadline: u64, // Set once both players committed; 0 before that
+    pub deposit_token: Option<Address>, // Commit deposit snapshotted at start; None if disabled
+    pub deposit_amount: i128,
+    pub initiative_bonus: i32, // Turn-one guard for player2 when the game came from a challenge
+    pub rules: RuleSet, // Rules snapshotted at start
+    pub crit_chance_bps: u32, // Crit settings snapshotted at start
+    pub crit_multiplier_pct: i32,
+    pub raise: Raise,
+    pub commit_mode: CommitMode, // Snapshotted at start from the game config
+    pub circuit_version: u32, // Verifier registry version its ZK commits are checked by
+    pub started_at: u64,
+    pub resolved_at: u64, // When the result was recorded; 0 before that
+    pub is_cancelled: bool, // Nobody committed and the stakes were refunded; no result
+    pub player1_coach: Option<Address>, // Can read player1's moves once resolved
+    pub player2_coach: Option<Address>,
+    pub player1_class: CaptainClass, // Captain classes snapshotted at start
+    pub player2_class: CaptainClass,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum RaiseStatus {
+    None = 0,
+    Pending = 1, // Waiting for the opponent to call or fold
+    Called = 2,
+    Folded = 3,
+}
+
+/// Blind raise state of a game, with the raise settings snapshotted at start
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Raise {
+    pub token: Option<Address>, // Escrow token; None when raising is off
+    pub max_amount: i128,
+    pub status: RaiseStatus,
+    pub raiser: Option<Address>,
+    pub amount: i128, // Each side's stake in escrow once called
+    pub fee_schedule: Vec<FeeTier>, // Rake on a called pot; empty for none
+}
+
+/// Lifecycle of a challenge. Expiry isn't stored: a pending challenge past its
+/// `expires_at` is expired.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ChallengeStatus {
+    Pending = 0,
+    Accepted = 1,
+    Completed = 2,
+    Declined = 3,  // Turned down by the challenged player
+    Cancelled = 4, // Withdrawn by the challenger before it was accepted
 }
 
 #[contracttype]
@@ -208,6 +885,114 @@ pub struct Challenge {
     pub is_accepted: bool,
     pub is_completed: bool,
     pub session_id: Option<u32>,
+    pub forwarded_from: Option<Address>, // Originally challenged player, if forwarded
+    pub status: ChallengeStatus,
+    pub is_rival_match: bool, // The two players have named each other as rivals
+    pub created_ledger: u32,
+    pub nonce: u32, // Bumped whenever the challenge changes; `accept_challenge` must match it
+    pub tie_break: TieBreak, // The rule set's when sent; the challenger can change it
+}
+
+/// Offer to replay a finished game with the same players, stakes and mode
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rematch {
+    pub session_id: u32, // The finished game
+    pub requester: Address,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub mode: GameMode,
+    pub expires_at: u64,
+    pub rematch_session_id: Option<u32>, // The new game, once accepted
+}
+
+/// Spectator stakes on one game, escrowed in the prediction token
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PredictionPool {
+    pub session_id: u32,
+    pub token: Address, // Prediction token snapshotted at the first prediction
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_total: i128, // Staked on player1 winning
+    pub player2_total: i128,
+    pub is_settled: bool,
+    pub winner: Option<Address>, // Once settled; None on a draw
+}
+
+/// One spectator's stake in a [`PredictionPool`]
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Prediction {
+    pub backed_player: Address,
+    pub amount: i128,
+}
+
+/// What a challenge sent now would cost and pay, under the current configuration
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChallengeQuote {
+    pub points_wagered: i128,
+    pub payout: i128,                   // Points the winner takes: both stakes
+    pub win_reward: i128,               // CSH minted to the winner (0 without a token)
+    pub deposit_token: Option<Address>,
+    pub deposit_amount: i128,           // Per player, held from commit until reveal
+    pub raise_token: Option<Address>,
+    pub max_raise: i128,
+    pub max_raise_fee: i128,            // Rake on a called raise of `max_raise`
+    pub expires_at: u64,                // Last moment the challenge can be accepted
+    pub commit_window_secs: u64,        // 0 when there is no commit deadline
+    pub reveal_window_secs: u64,        // From the second commit
+}
+
+/// A challenge posted to the public board for anyone to accept
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OpenChallenge {
+    pub challenge_id: u32, // Becomes a regular challenge with this id once accepted
+    pub challenger: Address,
+    pub points_wagered: i128,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+/// A listed friend and whether they are looking for a game
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FriendStatus {
+    pub friend: Address,
+    pub looking_for_game: bool,
+}
+
+/// Waiting count for one lobby tier
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueueTier {
+    pub tier: u32,
+    pub wager: i128, // Stake per player
+    pub waiting: u32,
+}
+
+/// A game `player1` has offered with `propose_game`, waiting on `confirm_game`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameProposal {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub expires_at: u64,
+}
+
+/// Open challenge anyone holding its code can accept
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InviteCode {
+    pub creator: Address,
+    pub points_wagered: i128,
+    pub expires_at: u64,
 }
 
 #[contracttype]
@@ -224,6 +1009,12 @@ pub struct DetailedTurnResult {
     pub player2_hp_remaining: i32,
     pub player1_defense_successful: bool,
     pub player2_defense_successful: bool,
+    pub player1_momentum: bool,
+    pub player2_momentum: bool,
+    pub player1_was_critical: bool,
+    pub player2_was_critical: bool,
+    pub player1_reflected: i32,
+    pub player2_reflected: i32,
 }
 
 #[contracttype]
@@ -239,6 +1030,13 @@ pub struct GamePlayback {
     pub final_player2_hp: i32,
     pub winner: Option<Address>, 
     pub is_draw: bool, 
+    pub reason: OutcomeReason,
+    pub player1_class: CaptainClass,
+    pub player2_class: CaptainClass,
+    pub player2_initiative_bonus: i32, // Damage taken off player2's turn-one hit as the challenged player
+    pub went_to_overtime: bool, // Trailing entries of turn_results are sudden-death overtime turns
+    pub rematch_of: Option<u32>,   // Session this game is a rematch of
+    pub rematched_as: Option<u32>, // Session of the accepted rematch of this game
 }
 
 #[contracttype]
@@ -261,373 +1059,1785 @@ pub struct PvPMatch {
     pub current_turn: u32, // 0 for player1's turn, 1 for player2's turn
     pub player1_hp: i32,
     pub player2_hp: i32,
-    pub last_action: Option<Move>,
+    pub has_last_action: bool,
+    pub last_action: Move,
     pub winner: Option<Address>,
 }
 
 #[contracttype]
-#[derive(Clone)]
-pub enum DataKey {
-    Game(u32),
-    GameHubAddress,
-    Admin,
-    Ultrahonkverifier,
-    Username(Address),           // Address -> Username
-    AddressByUsername(String),   // Username -> Address
-    Challenge(u32),              // Challenge ID -> Challenge
-    ChallengeCounter,            // Counter for challenge IDs
-    PlayerChallenges(Address),   // Address -> Vec<challenge_id>
-    Match(u32),                  // Match ID -> PvPMatch
-    MatchCounter,                // Counter for match IDs
-    PlayerMatches(Address),      // Address -> Vec<match_id>
-    TokenContractAddress,
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum DuelPhase {
+    Commit = 0,   // Both players commit their move for the round
+    Reveal = 1,   // Both players reveal it; the round resolves on the second reveal
+    Finished = 2,
 }
 
-// ============================================================================
-// Contract Definition
-// ============================================================================
-
-#[contract]
-pub struct ClashContract;
-
-
-fn verify_proof(
-    env: &Env,
-    verifier: &Address,
-    public_inputs: Bytes,
-    proof_bytes: Bytes,
-) -> Result<BytesN<32>, ClashError> {
-    let mut args: SorobanVec<Val> = SorobanVec::new(env);
-    args.push_back(public_inputs.clone().into_val(env));
-    args.push_back(proof_bytes.into_val(env));
-
-    env.try_invoke_contract::<(), InvokeError>(verifier, &Symbol::new(env, "verify_proof"), args)
-        .map_err(|_| ClashError::VerificationFailed)?
-        .map_err(|_| ClashError::VerificationFailed)?;
-
-    // The circuit's public return value (commitment hash) is the last 32 bytes
-    // of public_inputs. Layout: [player_address(32), session_id(32), hash(32)]
-    let len = public_inputs.len();
-    if len < 32 {
-        return Err(ClashError::VerificationFailed);
-    }
-
-    // Copy the last 32 bytes into a fixed-size array
-    let mut hash_array = [0u8; 32];
-    let start = len - 32;
-    for i in 0..32u32 {
-        hash_array[i as usize] = public_inputs.get(start + i)
-            .ok_or(ClashError::VerificationFailed)?;
-    }
+/// One player's side of the current duel round
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DuelTurn {
+    pub has_committed: bool,
+    pub commitment: BytesN<32>, // sha256(attack | defense | salt)
+    pub has_revealed: bool,
+    pub action: Move,
+}
 
-    Ok(BytesN::from_array(env, &hash_array))
+/// Turn-by-turn duel: one move per round is committed, revealed and resolved,
+/// so players can adapt to what the opponent did last round.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Duel {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub phase: DuelPhase,
+    pub round: u32,
+    pub deadline: u64, // Ledger timestamp the current phase closes at
+    pub player1_turn: DuelTurn,
+    pub player2_turn: DuelTurn,
+    pub player1_moves: Vec<Move>, // Revealed moves so far (feed the combo bonus)
+    pub player2_moves: Vec<Move>,
+    pub battle_result: BattleResult, // Running HP and per-round results; winner once finished
+    pub rules: RuleSet, // Rules snapshotted at start
 }
 
+/// A 2v2 battle. Every player commits and reveals their own ZK-proven move
+/// sequence; each team fights from one shared HP pool. The Game Hub sees the two
+/// captains (the first member of each team) as the session's players.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TeamGame {
+    pub team1: Vec<Address>, // Two players, captain first
+    pub team2: Vec<Address>,
+    pub team1_points: i128,
+    pub team2_points: i128,
+    pub commitments: Vec<PlayerCommitment>, // team1[0], team1[1], team2[0], team2[1]
+    pub has_committed: Vec<bool>,           // Same order as `commitments`
+    pub battle_result: BattleResult, // player1 is team1, player2 team2; winner is a captain
+    pub has_battle_result: bool,
+    pub rules: RuleSet, // Rules snapshotted at start
+    pub circuit_version: u32, // Verifier registry version, as in `Game`
+}
 
-#[contractimpl]
+/// Best-of-N series played as linked games under one Game Hub session
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchSeries {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub best_of: u32,
+    pub sessions: Vec<u32>, // Round games in order; the last is in play until finished
+    pub player1_wins: u32,
+    pub player2_wins: u32,
+    pub draws: u32,
+    pub is_finished: bool,
+    pub winner: Option<Address>, // None while in play, or after a drawn series
+    pub is_cancelled: bool,      // A round was voided, so the series was called off unrated
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum TournamentStatus {
+    Registration = 0,
+    InProgress = 1,
+    Completed = 2,
+    Cancelled = 3, // Registration closed with fewer than two players
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum BracketFormat {
+    SingleElimination = 0,
+    DoubleElimination = 1, // Losers drop into a losers' bracket; a second loss knocks them out
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum BracketSide {
+    Winners = 0,
+    Losers = 1,
+    GrandFinal = 2, // Round 0 is the grand final, round 1 its reset
+}
+
+/// A tournament's prize pool and how it is split by final standing, kept beside the
+/// tournament
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TournamentPrizes {
+    pub token: Address,
+    pub pool: i128,
+    pub payouts_bps: Vec<u32>, // Share of the pool per place, first place first; sums to 10_000
+}
+
+/// How a tournament's large prizes vest: a prize above `threshold` pays
+/// `immediate_bps` of itself straight away and the rest `lock_secs` after the
+/// tournament completes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrizeVesting {
+    pub threshold: i128,
+    pub immediate_bps: u32,
+    pub lock_secs: u64,
+}
+
+/// The locked part of a player's tournament prize
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestedPrize {
+    pub amount: i128,
+    pub unlocks_at: u64,
+}
+
+/// Elimination tournament; its matches are kept separately as the bracket
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tournament {
+    pub tournament_id: u32,
+    pub organizer: Address,
+    pub max_players: u32,
+    pub entry_points: i128, // Staked by each player in every match
+    pub registration_deadline: u64, // When the tournament starts; check-in opens CHECK_IN_SECS before
+    pub format: BracketFormat,
+    pub players: Vec<Address>, // In registration order, then the checked-in players in seed order once seeded
+    pub checked_in: Vec<Address>,
+    pub status: TournamentStatus,
+    pub round: u32, // Round of the first undecided match, in bracket order, from 0
+    pub champion: Option<Address>,
+}
+
+/// One match of a bracket. A match with a single player is a bye, and one left with
+/// none is skipped.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BracketMatch {
+    pub side: BracketSide,
+    pub round: u32, // Round within its side, from 0
+    pub index: u32, // Position within the round
+    pub player1: Option<Address>,
+    pub player2: Option<Address>,
+    pub session_id: Option<u32>, // Current game, once started; a match with no winner is replayed once on a new one
+    pub winner: Option<Address>,
+    pub replayed: bool,
+    pub is_walkover: bool,      // Won because the opponent never committed, not by battle
+    pub pending: u32,           // Slots still waiting on an earlier match
+    pub winner_to: Option<u32>, // Slot the winner moves to: bracket position * 2, plus 1 for player2
+    pub loser_to: Option<u32>,  // Slot the loser drops to, the same way; None if they are out
+}
+
+/// A player's record on the rated ladder
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerStats {
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    pub rating: i32,
+    pub streak: i32, // Consecutive wins if positive, losses if negative; 0 after a draw
+}
+
+/// Compact, permanent record of a resolved battle, kept in both players' history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArchivedGame {
+    pub session_id: u32,
+    pub mode: GameMode,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_moves: Vec<Move>,
+    pub player2_moves: Vec<Move>,
+    pub turn_results: Vec<TurnResult>,
+    pub winner: Option<Address>,
+    pub is_draw: bool,
+    pub resolved_at: u64,
+}
+
+/// One player's results against a rival
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HeadToHead {
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+}
+
+/// Unbroken run of games between two players, with its running score. Stored from
+/// the lower address's side; `get_rivalry_series` returns it from the caller's.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RivalrySeries {
+    pub player_a: Address,
+    pub player_b: Address,
+    pub sessions: Vec<u32>, // Games in order, the latest 20
+    pub a_wins: u32,
+    pub b_wins: u32,
+    pub draws: u32,
+    pub started_at: u64,
+    pub last_played_at: u64,
+}
+
+/// Where a player's rating stands among every rated player
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RatingPercentile {
+    pub rating: i32,
+    pub rated_players: u32,
+    pub top_pct: u32, // Share of rated players in the player's 50-point bucket or above, rounded up; 0 if unrated
+    pub is_provisional: bool, // Fewer than 10 rated games, so the rating is still settling
+}
+
+/// Rating decay for inactive players: a rating above the starting 1500 loses
+/// `points` for every `inactive_secs` without a rated game, down to 1500. Off while
+/// either is 0.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RatingDecay {
+    pub inactive_secs: u64,
+    pub points: u32,
+}
+
+/// Leaderboard entry
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RankedPlayer {
+    pub player: Address,
+    pub rating: i32,
+}
+
+/// A competitive period with its own ratings ladder
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Season {
+    pub season_id: u32,
+    pub started_at: u64,
+    pub ends_at: u64, // Results settled after this don't count towards the season
+    pub has_ended: bool,
+}
+
+/// Ranked snapshot of a season, frozen when it ends
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeasonResults {
+    pub season_id: u32,
+    pub started_at: u64,
+    pub ended_at: u64,
+    pub standings: Vec<RankedPlayer>, // Season ratings, best first
+}
+
+/// Token paid to a season's top finishers when it ends: `amounts[0]` to first place,
+/// and so on down the standings
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeasonRewards {
+    pub token: Address,
+    pub amounts: Vec<i128>,
+}
+
+/// A player's finish in an ended season, and the reward it pays
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeasonPlacement {
+    pub season_id: u32,
+    pub player: Address,
+    pub rank: u32, // 1 for the season winner
+    pub rating: i32, // Final season rating
+    pub token: Option<Address>, // None if the rank carried no reward
+    pub amount: i128, // Left to claim; 0 once swept to the fees after expiry
+    pub expires_at: u64,
+    pub claimed: bool,
+}
+
+/// Milestone badges awarded when a battle is resolved
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Achievement {
+    FirstWin = 0,
+    WinStreak3 = 1,          // Three rated wins in a row
+    Flawless = 2,            // Won without losing any HP
+    TripleComboFinisher = 3, // Knocked the opponent out with a third straight attack
+    TenBattles = 4,          // Ten rated battles played
+    SharpEye = 5,            // Three correct spectator picks in a row
+    Oracle = 6,              // Ten correct spectator picks in a row
+}
+
+/// A badge as held by a player
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EarnedAchievement {
+    pub achievement: Achievement,
+    pub session_id: u32, // The battle that earned it
+    pub earned_at: u64,
+}
+
+/// Totals over every battle resolved on-chain
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GlobalStats {
+    pub total_games: u64,
+    pub total_draws: u64,
+    pub slash_count: u64, // Attacks thrown, over turns actually played
+    pub fireball_count: u64,
+    pub lightning_count: u64,
+    pub broadside_count: u64,
+    pub hook_count: u64,
+    pub total_turns: u64,
+    pub average_turns_x100: u64, // Average battle length in turns, times 100
+}
+
+/// Siege results for one player, tracked apart from classic games
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SiegeStats {
+    pub attacker_wins: u32,
+    pub attacker_losses: u32,
+    pub defender_wins: u32,
+    pub defender_losses: u32,
+}
+
+/// How hard the practice bot plays
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum PracticeDifficulty {
+    Easy = 0,   // Predictable: repeats one random move
+    Normal = 1, // Balanced: random moves
+    Hard = 2,   // Counter-prone: strongest combo, defending against popular attacks
+}
+
+/// A game against the on-chain bot: no Game Hub, no wager, no reward
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PracticeGame {
+    pub player: Address,
+    pub difficulty: PracticeDifficulty,
+    pub rules: RuleSet, // Rules snapshotted at start
+    pub started_at: u64,
+    pub has_battle_result: bool,
+    pub player_moves: Vec<Move>, // Empty until resolved
+    pub bot_moves: Vec<Move>,    // Drawn when resolved
+    pub battle_result: BattleResult, // player2 is the contract, standing in for the bot
+}
+
+/// Practice results for one player at one difficulty, tracked apart from rated play
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PracticeStats {
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+}
+
+/// Inclusive bounds on points wagered per player, set by the admin
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WagerLimits {
+    pub min: i128,
+    pub max: i128,
+}
+
+/// Players a referrer brought in, and the bonus credited for them
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReferralStats {
+    pub referred: u32,  // Registered referrals
+    pub completed: u32, // Referred players whose first battle has resolved
+    pub earned: i128,   // CSH credited in total
+    pub unclaimed: i128,
+}
+
+/// A spectator's free call on who wins a game
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpectatorPick {
+    pub spectator: Address,
+    pub backed_player: Address,
+}
+
+/// A spectator's record over their scored picks; draws are not counted
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PickStats {
+    pub picks: u32,
+    pub correct: u32,
+    pub streak: u32, // Correct picks in a row, up to the latest
+    pub best_streak: u32,
+}
+
+/// Navbar counts for one player, kept current by the entrypoints that change them
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Badges {
+    pub pending_challenges: u32, // Challenges sent to this player and not yet answered
+    pub awaiting_action: u32,    // Live games and duels with a commit or reveal due from them
+    pub unclaimed_rewards: u32,  // Sessions with winnings to claim, plus unclaimed referral bonuses
+}
+
+/// Free-to-play tier: an admin-funded pool stakes new players in micro-wager games
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SponsorTier {
+    pub token: Address,
+    pub stake: i128,          // Put up for each player; the winner is paid both
+    pub max_rated_games: u32, // Players with more rated games than this don't qualify
+    pub daily_games: u32,     // Sponsored games each player can start per UTC day
+}
+
+/// Agreed pauses of a correspondence game
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GamePause {
+    pub proposed_by: Option<Address>, // Player waiting for the other to accept
+    pub proposed_secs: u64,
+    pub paused_until: u64, // Deadlines were moved out to cover the pause up to here
+    pub used_secs: u64,    // Paused time so far, counted against the maximum
+}
+
+/// Stakes the pool put up for a sponsored game, held until it settles
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sponsorship {
+    pub token: Address,
+    pub stake: i128,
+}
+
+/// Circuit breakers set by the admin. `all` pauses every mutating entrypoint the
+/// others cover; views keep working either way.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PauseFlags {
+    pub all: bool,
+    pub new_games: bool,  // Challenges, queues, invites, tournaments and direct starts
+    pub commits: bool,    // Commits, reveals and raises
+    pub resolution: bool, // Resolving, forfeits, cancellations and deadline claims
+}
+
+/// Damage of one attack and the defense that stops it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttackRule {
+    pub attack: Attack,
+    pub damage: i32,
+    pub blocked_by: Defense,
+}
+
+/// Combat numbers set by the admin. Each game and duel keeps the rule set it started
+/// under, so a rebalance never changes a battle already in play.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RuleSet {
+    pub version: u32, // 0 for the built-in rules; bumped by every `set_rules`
+    pub starting_hp: i32,
+    pub turns_per_battle: u32, // At most the three moves a sequence holds
+    pub slash_damage: i32,
+    pub fireball_damage: i32,
+    pub lightning_damage: i32,
+    pub broadside_damage: i32,
+    pub hook_damage: i32,
+    pub riposte_reflect_pct: i32, // Share of a stopped attack a Riposte sends back
+    pub combo_2_bonus: i32,
+    pub combo_3_bonus: i32,
+    pub momentum_bonus: i32,
+    pub tie_break: TieBreak,
+}
+
+impl RuleSet {
+    /// The rules in force until the admin sets others
+    pub const DEFAULT: RuleSet = RuleSet {
+        version: 0,
+        starting_hp: STARTING_HP,
+        turns_per_battle: TURNS_PER_BATTLE,
+        slash_damage: 30,
+        fireball_damage: 40,
+        lightning_damage: 35,
+        broadside_damage: 50,
+        hook_damage: 20,
+        riposte_reflect_pct: 50,
+        combo_2_bonus: COMBO_2_BONUS,
+        combo_3_bonus: COMBO_3_BONUS,
+        momentum_bonus: MOMENTUM_BONUS,
+        tie_break: TieBreak::SuddenDeath,
+    };
+}
+
+/// The combat rules new games are played under, for help screens and move planners
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rules {
+    pub version: u32, // Version of the rule set below
+    pub starting_hp: i32,
+    pub turns_per_battle: u32,
+    pub attacks: Vec<AttackRule>,
+    pub combo_2_bonus: i32, // Second consecutive use of the same attack
+    pub combo_3_bonus: i32, // Third consecutive use
+    pub momentum_bonus: i32, // On a landed attack after winning the previous turn
+    pub riposte_reflect_pct: i32, // Share of a stopped attack a Riposte sends back
+    pub tie_break: TieBreak, // Challenges can pick another
+    pub initiative_bonus: i32, // Turn-one guard for the challenged player
+    pub crit_chance_bps: u32, // Chance of a critical hit per attack
+    pub crit_multiplier_pct: i32, // Damage of a critical hit, percent of a normal one
+}
+
+/// Gameplay tuning set by the admin; values are snapshotted onto each game at start
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameConfig {
+    pub initiative_bonus: i32, // Turn-one damage reduction for the challenged player
+    pub reveal_window_secs: u64, // Reveal deadline after the second commit (not correspondence)
+    pub crit_chance_bps: u32, // Chance of a critical hit per attack, in basis points
+    pub crit_multiplier_pct: i32, // Damage of a critical hit, percent of a normal one
+    pub commit_mode: CommitMode, // How new games commit their moves
+}
+
+/// Token and upper bound for blind raises
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RaiseConfig {
+    pub token: Address,
+    pub max_amount: i128,
+}
+
+/// Breakpoint of the fee schedule: wagers of at least `min_wager` (up to the next
+/// tier) pay `fee_bps` of the pot
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeTier {
+    pub min_wager: i128,
+    pub fee_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum PromoReward {
+    FeeWaiver = 0,    // `amount` games with the rake waived
+    DepositBonus = 1, // `amount` CSH minted with the next commit deposit
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PromoCode {
+    pub reward: PromoReward,
+    pub amount: i128,
+    pub max_uses: u32,
+    pub uses: u32,
+}
+
+/// Refundable deposit each player pays at commit time
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitDeposit {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Stakes the Game Hub holds for one session
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowReceipt {
+    pub session_id: u32,
+    pub asset: Address, // Contract holding the stakes (the Game Hub, in points)
+    pub player1: Address,
+    pub player1_amount: i128,
+    pub player2: Address,
+    pub player2_amount: i128,
+    pub locked_at: u64,
+    pub released_at: u64, // Paid out or refunded at this time; 0 while still locked
+}
+
+/// Proof that a player committed, anchored to the ledger the commit landed in
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitReceipt {
+    pub session_id: u32,
+    pub player: Address,
+    pub commitment: BytesN<32>,
+    pub ledger: u32,    // Ledger sequence the commit was included in
+    pub timestamp: u64, // Ledger close time of that ledger
+}
+
+/// Outcome of a match played off-chain, signed by both players
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedResult {
+    pub winner: Option<Address>, // None for a draw
+    pub is_draw: bool,
+    pub player1_hp: i32,
+    pub player2_hp: i32,
+}
+
+/// A co-signed result that either player can still dispute
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingSettlement {
+    pub result: SignedResult,
+    pub challenge_deadline: u64, // Finalizable once the ledger time is past this
+}
+
+/// A game with the session it's stored under, for bulk views
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionGame {
+    pub session_id: u32,
+    pub game: Game,
+}
+
+/// Everything stored for one game, for backup and restore across migrations
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameExport {
+    pub session_id: u32,
+    pub exported_at: u32, // Ledger sequence of the export
+    pub game: Game,       // Commitments, reveals and any (partial) battle result
+    pub pending_settlement: ExportedSettlement,
+}
+
+/// The signed result a game was waiting on when exported, if any. An
+/// `Option<PendingSettlement>` in effect: an optional struct field builds for the
+/// contract, but not with `testutils`, whose XDR conversions need an `Into<ScVal>`
+/// that contract types don't implement.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExportedSettlement {
+    None,
+    Pending(PendingSettlement),
+}
+
+/// What kind of entity an activity log entry points at
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ActivityKind {
+    Game = 0,      // id is the session ID
+    Challenge = 1, // id is the challenge ID
+    Duel = 2,      // id is the session ID
+    TeamGame = 3,  // id is the session ID
+}
+
+/// A session as listed in the registry, with the ledger it was created in
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionRecord {
+    pub session_id: u32,
+    pub kind: ActivityKind, // Game, Duel or TeamGame
+    pub ledger: u32,
+}
+
+/// Answer to `get_sessions_in_range`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionRange {
+    pub sessions: Vec<SessionRecord>,
+    pub next_page: Option<u32>, // Pass as `page` for the rest of the range; None once all of it is listed
+}
+
+/// An entity that changed for a player, and the player's sequence it changed at
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntityChange {
+    pub kind: ActivityKind,
+    pub id: u32,
+    pub seq: u64,
+}
+
+/// A player's recent changes, latest per entity, oldest first
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActivityLog {
+    pub last_activity_seq: u64, // Bumped by every change affecting the player
+    pub dropped_seq: u64,       // Seq of the last change pushed out of the log; 0 if none
+    pub changes: Vec<EntityChange>,
+}
+
+/// Answer to `get_updates_since`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerUpdates {
+    pub last_activity_seq: u64, // Pass this as `seq` on the next poll
+    pub is_complete: bool, // False if older changes were dropped: re-fetch everything
+    pub changes: Vec<EntityChange>,
+}
+
+/// Build metadata recorded when the contract is deployed or upgraded
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuildInfo {
+    pub wasm_hash: Option<BytesN<32>>, // None for the originally deployed WASM
+    pub installed_ledger: u32,
+    pub installed_at: u64,
+}
+
+/// Progress of `migrate` towards the storage layout this WASM writes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationStatus {
+    pub version: u32,        // Layout stored entries are on; 0 from before versioning
+    pub latest_version: u32, // Layout this WASM writes
+    pub cursor: u32,         // Where the running step carries on
+}
+
+/// Readiness of the contract's dependencies and settings, for maintenance banners
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractStatus {
+    pub hub: Address,
+    pub hub_last_ok_at: u64, // Last time the hub locked or released stakes; 0 if never
+    pub circuit_version: u32,
+    pub verifier_ready: bool, // The current circuit version has a live verifier
+    pub commit_mode: CommitMode, // How new games commit
+    pub rules_version: u32, // Rules new games are played under; 0 for the built-in ones
+    pub season_id: u32,       // The latest season; 0 if none has been started
+    pub season_running: bool, // Rated results currently count towards it
+    pub pause: PauseFlags,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractVersion {
+    pub version: String,
+    pub build: BuildInfo,
+}
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct ClashContract;
+
+#[contractimpl]
 impl ClashContract {
     /// Initialize the contract with GameHub address and admin
     pub fn __constructor(env: Env, admin: Address, game_hub: Address, verifier_contract: Address) {
         env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage().instance().set(&DataKey::Ultrahonkverifier, &verifier_contract);
+        storage::set_verifier_entry(
+            &env,
+            1,
+            &VerifierEntry {
+                address: verifier_contract,
+                deprecated: false,
+            },
+        );
         env.storage()
             .instance()
             .set(&DataKey::GameHubAddress, &game_hub);
         env.storage().instance().set(&DataKey::ChallengeCounter, &0u32);
+        storage::save_storage_version(&env, migration::STORAGE_VERSION);
+        Self::record_build_info(&env, None);
+    }
+
+    // ========================================================================
+    // Username Management
+    // ========================================================================
+
+    pub fn set_username(env: Env, caller: Address, username: String) -> Result<(), Error> {
+        identity::set_username(&env, caller, username)
+    }
+
+    /// Free the caller's username for anyone to claim
+    pub fn release_username(env: Env, caller: Address) -> Result<(), Error> {
+        identity::release_username(&env, caller)
+    }
+
+    /// Move `from`'s username to `to`; both must sign
+    pub fn transfer_username(env: Env, from: Address, to: Address) -> Result<(), Error> {
+        identity::transfer_username(&env, from, to)
+    }
+
+    /// Take a name away from whoever holds it, e.g. for abuse
+    pub fn force_release(env: Env, username: String) -> Result<(), Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        identity::force_release(&env, username)
+    }
+
+    /// Seconds without a battle after which a player's name can be claimed by
+    /// others; 0 (the default) keeps names forever
+    pub fn set_username_expiry(env: Env, expiry_secs: u64) {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::UsernameExpiry, &expiry_secs);
+    }
+
+    pub fn get_username_expiry(env: Env) -> u64 {
+        storage::username_expiry_secs(&env)
+    }
+
+    /// Last battle or username claim, as used for the name expiry; 0 if none recorded
+    pub fn get_last_active_at(env: Env, player: Address) -> u64 {
+        storage::last_active_at(&env, &player)
+    }
+
+    /// Pick the captain class the caller battles as in games started from now on
+    pub fn set_captain_class(env: Env, player: Address, class: CaptainClass) {
+        player.require_auth();
+        let player = identity::primary(&env, &player);
+        storage::save_captain_class(&env, &player, class);
+        CaptainClassSet { player, class }.publish(&env);
+    }
+
+    pub fn get_captain_class(env: Env, player: Address) -> CaptainClass {
+        storage::captain_class(&env, &identity::primary(&env, &player))
+    }
+
+    /// Get username for an address (a linked address shares its primary's)
+    pub fn get_username(env: Env, address: Address) -> Option<String> {
+        storage::username(&env, &identity::primary(&env, &address))
+    }
+
+    /// Ask to link `secondary` to the caller's identity; `secondary` confirms with
+    /// `confirm_link`. It must have no games, rating or name of its own, and no live
+    /// sessions or challenges.
+    pub fn link_address(env: Env, primary: Address, secondary: Address) -> Result<(), Error> {
+        identity::link_address(&env, primary, secondary)
+    }
+
+    pub fn confirm_link(env: Env, secondary: Address, primary: Address) -> Result<(), Error> {
+        identity::confirm_link(&env, secondary, primary)
+    }
+
+    /// Undo a link from either side; ratings and the name stay with the primary
+    pub fn unlink_address(env: Env, caller: Address, secondary: Address) -> Result<(), Error> {
+        identity::unlink_address(&env, caller, secondary)
+    }
+
+    /// The primary address `player` plays as; `player` itself if not linked
+    pub fn get_identity(env: Env, player: Address) -> Address {
+        identity::primary(&env, &player)
+    }
+
+    pub fn get_linked_addresses(env: Env, primary: Address) -> Vec<Address> {
+        storage::linked_addresses(&env, &primary)
+    }
+
+    /// Get address for a username
+    pub fn get_address_by_username(env: Env, username: String) -> Option<Address> {
+        storage::address_by_username(&env, &username)
+    }
+
+    /// Usernames of up to 50 addresses in one call, in the order given; `None` for
+    /// an address without one
+    pub fn get_usernames(env: Env, addresses: Vec<Address>) -> Vec<Option<String>> {
+        identity::get_usernames(&env, addresses)
+    }
+
+    /// Addresses holding up to 50 usernames in one call, in the order given; `None`
+    /// for a name nobody holds
+    pub fn get_addresses(env: Env, usernames: Vec<String>) -> Vec<Option<Address>> {
+        identity::get_addresses(&env, usernames)
+    }
+
+    // ========================================================================
+    // Lobbies
+    // ========================================================================
+
+    /// Queue at a wager tier (0: 10, 1: 100, 2: 1000 points). Returns the session id if
+    /// someone was already waiting and a game started, `None` if now waiting.
+    pub fn join_queue(env: Env, player: Address, tier: u32) -> Result<Option<u32>, Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        lobby::join_queue(&env, player, tier)
+    }
+
+    pub fn leave_queue(env: Env, player: Address) -> Result<(), Error> {
+        lobby::leave_queue(&env, player)
+    }
+
+    /// How many players are waiting at each tier
+    pub fn get_queue_stats(env: Env) -> Vec<QueueTier> {
+        lobby::get_queue_stats(&env)
+    }
+
+    // ========================================================================
+    // Challenge System
+    // ========================================================================
+
+    /// Send a challenge to another player
+    pub fn send_challenge(
+        env: Env,
+        challenger: Address,
+        challenged: Address,
+        points_wagered: i128,
+    ) -> Result<u32, Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        challenge::send_challenge(&env, challenger, challenged, points_wagered)
+    }
+
+    /// Preview the stakes, deposits, rake and deadlines of sending a challenge for
+    /// `points_wagered` in `mode`; fails like `send_challenge` on an invalid wager
+    pub fn quote_challenge(env: Env, points_wagered: i128, mode: GameMode) -> Result<ChallengeQuote, Error> {
+        challenge::quote_challenge(&env, points_wagered, mode)
+    }
+
+    /// Accept a challenge and start a game. Returns the session id allocated for it.
+    /// `challenge_nonce` is the nonce of the challenge the player agreed to; if the
+    /// challenge has changed since, acceptance fails with `StaleChallenge`.
+    pub fn accept_challenge(env: Env, challenge_id: u32, challenged: Address, challenge_nonce: u32) -> Result<u32, Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        challenge::accept_challenge(&env, challenge_id, challenged, challenge_nonce)
+    }
+
+    /// Pick how the challenge's game breaks a tie. Only while it is pending; bumps
+    /// the nonce the challenged player accepts.
+    pub fn set_challenge_tie_break(env: Env, challenge_id: u32, tie_break: TieBreak) -> Result<(), Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        challenge::set_tie_break(&env, challenge_id, tie_break)
+    }
+
+    /// Hand a received challenge to someone else (e.g. a clanmate) on the same terms.
+    /// Needs the challenged player's auth and works once per challenge.
+    pub fn forward_challenge(env: Env, challenge_id: u32, to: Address) -> Result<(), Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        challenge::forward_challenge(&env, challenge_id, to)
+    }
+
+    /// Turn down a received challenge. It leaves both players' challenge lists.
+    pub fn decline_challenge(env: Env, challenge_id: u32, challenged: Address) -> Result<(), Error> {
+        challenge::decline_challenge(&env, challenge_id, challenged)
+    }
+
+    /// Withdraw a sent challenge that hasn't been accepted yet. It leaves both
+    /// players' challenge lists.
+    pub fn cancel_challenge(env: Env, challenge_id: u32, challenger: Address) -> Result<(), Error> {
+        challenge::cancel_challenge(&env, challenge_id, challenger)
+    }
+
+    /// Post a challenge anyone can accept to the open board (up to 3 live per
+    /// player). Returns its challenge id.
+    pub fn post_open_challenge(env: Env, challenger: Address, points_wagered: i128) -> Result<u32, Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        challenge::post_open_challenge(&env, challenger, points_wagered)
+    }
+
+    /// Page through open challenges with a wager in `min_wager..=max_wager`, oldest
+    /// first
+    pub fn get_open_challenges(
+        env: Env,
+        offset: u32,
+        limit: u32,
+        min_wager: i128,
+        max_wager: i128,
+    ) -> Vec<OpenChallenge> {
+        challenge::get_open_challenges(&env, offset, limit, min_wager, max_wager)
+    }
+
+    /// Take an open challenge and start a game, like `accept_challenge`. Returns the
+    /// session id allocated for it.
+    pub fn accept_open_challenge(env: Env, challenge_id: u32, acceptor: Address) -> Result<u32, Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        challenge::accept_open_challenge(&env, challenge_id, acceptor)
+    }
+
+    /// Take your open challenge off the board before anyone accepts it
+    pub fn cancel_open_challenge(env: Env, challenge_id: u32, challenger: Address) -> Result<(), Error> {
+        challenge::cancel_open_challenge(&env, challenge_id, challenger)
+    }
+
+    /// Add `friend` to your friends list (up to 50)
+    pub fn add_friend(env: Env, player: Address, friend: Address) -> Result<(), Error> {
+        friends::add_friend(&env, player, friend)
+    }
+
+    /// Take `friend` off your friends list
+    pub fn remove_friend(env: Env, player: Address, friend: Address) {
+        friends::remove_friend(&env, player, friend)
+    }
+
+    /// Tell friends you are up for a game, or no longer
+    pub fn set_looking_for_game(env: Env, player: Address, looking: bool) {
+        friends::set_looking_for_game(&env, player, looking)
+    }
+
+    /// `player`'s friends with their looking-for-game flags, for a "ready to duel" panel
+    pub fn get_friends_status(env: Env, player: Address) -> Vec<FriendStatus> {
+        friends::get_friends_status(&env, &player)
+    }
+
+    /// Create a one-time code that lets anyone start a game against `creator`, without
+    /// knowing their address or username. Valid for `ttl_secs` (up to 7 days).
+    pub fn create_invite_code(env: Env, creator: Address, points_wagered: i128, ttl_secs: u64) -> Result<u64, Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        challenge::create_invite_code(&env, creator, points_wagered, ttl_secs)
+    }
+
+    /// Redeem an invite code and start the game. Returns the session id.
+    pub fn accept_invite_code(env: Env, code: u64, acceptor: Address) -> Result<u32, Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        challenge::accept_invite_code(&env, code, acceptor)
+    }
+
+    pub fn get_invite_code(env: Env, code: u64) -> Option<InviteCode> {
+        storage::load_invite_code(&env, code)
+    }
+
+    pub fn get_challenge(env: Env, challenge_id: u32) -> Result<Challenge, Error> {
+        storage::load_challenge(&env, challenge_id)
+    }
+
+    /// Get all challenges for a player (sorted by status)
+    pub fn get_player_challenges(env: Env, player: Address) -> (Vec<Challenge>, Vec<Challenge>, Vec<Challenge>) {
+        challenge::get_player_challenges(&env, player)
+    }
+
+    /// Up to `limit` of a player's challenges from `offset`, oldest first; ids whose
+    /// challenge has expired are pruned as they are reached
+    pub fn get_player_challenges_page(env: Env, player: Address, offset: u32, limit: u32) -> Vec<Challenge> {
+        challenge::get_player_challenges_page(&env, player, offset, limit)
+    }
+
+    /// Offer the opponent a rematch of finished game `session_id` with the same
+    /// pairing, stakes and mode
+    pub fn request_rematch(env: Env, session_id: u32, requester: Address) -> Result<(), Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        rematch::request_rematch(&env, session_id, requester)
+    }
+
+    /// Accept a rematch offer and start the new game. Returns its session id.
+    pub fn accept_rematch(env: Env, session_id: u32, player: Address) -> Result<u32, Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        rematch::accept_rematch(&env, session_id, player)
+    }
+
+    pub fn get_rematch(env: Env, session_id: u32) -> Option<Rematch> {
+        storage::rematch(&env, session_id)
+    }
+
+    // ========================================================================
+    // Game Playback
+    // ========================================================================
+
+    /// Get detailed game playback with all moves and results
+    pub fn get_game_playback(env: Env, session_id: u32) -> Result<GamePlayback, Error> {
+        game::get_game_playback(&env, session_id)
+    }
+
+    // ========================================================================
+    // Existing Game Functions 
+    // ========================================================================
+
+    /// Start a new game between two players with points. Both players sign their own
+    /// stake in this one transaction; see `propose_game` otherwise.
+    pub fn start_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        game::start_game(&env, session_id, player1, player2, player1_points, player2_points)
+    }
+
+    /// Start a classic game whose stakes the sponsor pool puts up. Both players sign,
+    /// and both must qualify for the sponsor tier.
+    pub fn start_sponsored_game(env: Env, session_id: u32, player1: Address, player2: Address) -> Result<(), Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        sponsor::start_sponsored_game(&env, session_id, player1, player2)
+    }
+
+    /// Set the sponsored free-to-play tier; `None` turns it off for new games
+    pub fn set_sponsor_tier(env: Env, tier: Option<SponsorTier>) -> Result<(), Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        sponsor::set_tier(&env, tier)
+    }
+
+    pub fn get_sponsor_tier(env: Env) -> Option<SponsorTier> {
+        storage::sponsor_tier(&env)
+    }
+
+    /// Pay `amount` of the tier's token into the sponsor pool; returns the new balance
+    pub fn fund_sponsor_pool(env: Env, amount: i128) -> Result<i128, Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        sponsor::fund(&env, &admin, amount)
+    }
+
+    /// Take `amount` back out of the sponsor pool; returns the new balance
+    pub fn withdraw_sponsor_pool(env: Env, amount: i128) -> Result<i128, Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        sponsor::withdraw(&env, &admin, amount)
+    }
+
+    /// Sponsor pool balance not set aside for games in play
+    pub fn get_sponsor_pool(env: Env) -> i128 {
+        storage::sponsor_pool(&env)
+    }
+
+    /// Sponsored games `player` can still start today; 0 if they don't qualify
+    pub fn get_sponsored_games_left(env: Env, player: Address) -> u32 {
+        sponsor::games_left(&env, &player)
+    }
+
+    /// Stakes the pool put up for `session_id`, until the game settles
+    pub fn get_sponsorship(env: Env, session_id: u32) -> Option<Sponsorship> {
+        storage::sponsorship(&env, session_id)
+    }
+
+    /// Propose pausing a correspondence game for `duration_secs`; the opponent
+    /// accepts with `accept_pause`
+    pub fn propose_pause(env: Env, session_id: u32, player: Address, duration_secs: u64) -> Result<(), Error> {
+        pause::check(&env, Breaker::Commits)?;
+        agreed_pause::propose_pause(&env, session_id, player, duration_secs)
+    }
+
+    /// Accept the opponent's pause: every open deadline moves out by its length.
+    /// Returns when the pause ends.
+    pub fn accept_pause(env: Env, session_id: u32, player: Address) -> Result<u64, Error> {
+        pause::check(&env, Breaker::Commits)?;
+        agreed_pause::accept_pause(&env, session_id, player)
+    }
+
+    /// End a running pause early, taking the unused time back off the deadlines
+    pub fn resume_game(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        pause::check(&env, Breaker::Commits)?;
+        agreed_pause::resume_game(&env, session_id, player)
+    }
+
+    pub fn get_game_pause(env: Env, session_id: u32) -> GamePause {
+        storage::game_pause(&env, session_id)
+    }
+
+    /// Most time a correspondence game can spend paused, over all its pauses
+    pub fn set_max_game_pause(env: Env, max_secs: u64) {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::MaxGamePause, &max_secs);
+    }
+
+    pub fn get_max_game_pause(env: Env) -> u64 {
+        storage::max_game_pause_secs(&env)
+    }
+
+    /// Offer `player2` a classic game on `session_id`; nothing is locked until they
+    /// call `confirm_game`
+    pub fn propose_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        game::propose_game(&env, session_id, player1, player2, player1_points, player2_points)
+    }
+
+    /// Accept a proposed game, starting it as `start_game` would
+    pub fn confirm_game(env: Env, session_id: u32, player2: Address) -> Result<(), Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        game::confirm_game(&env, session_id, player2)
+    }
+
+    pub fn get_game_proposal(env: Env, session_id: u32) -> Option<GameProposal> {
+        storage::game_proposal(&env, session_id)
+    }
+
+    /// Start a siege: `attacker` only attacks and `defender` only defends, winning by
+    /// surviving every turn. Commit, reveal and resolve work as for `start_game`.
+    pub fn start_siege(
+        env: Env,
+        session_id: u32,
+        attacker: Address,
+        defender: Address,
+        attacker_points: i128,
+        defender_points: i128,
+    ) -> Result<(), Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        game::start_siege(&env, session_id, attacker, defender, attacker_points, defender_points)
+    }
+
+    /// Start a correspondence game: classic rules, but players get days rather than
+    /// hours to commit and to reveal, and a `WindowOpened` event marks each deadline.
+    pub fn start_correspondence(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        game::start_correspondence(&env, session_id, player1, player2, player1_points, player2_points)
+    }
+
+    /// Changes to `player`'s games, challenges, duels and team games since `seq`
+    pub fn get_updates_since(env: Env, player: Address, seq: u64) -> PlayerUpdates {
+        activity::get_updates_since(&env, &player, seq)
+    }
+
+    /// Games, duels and team games created in ledgers `from_seq..=to_seq`, oldest
+    /// first. The range may span at most a day of ledgers; a busy one is listed over
+    /// several calls, starting with `page` 0 and following `next_page`.
+    pub fn get_sessions_in_range(env: Env, from_seq: u32, to_seq: u32, page: u32) -> Result<SessionRange, Error> {
+        registry::get_sessions_in_range(&env, from_seq, to_seq, page)
+    }
+
+    /// Cheap marker to poll: bumped by every change affecting `player`
+    pub fn get_last_activity_seq(env: Env, player: Address) -> u64 {
+        storage::activity_log(&env, &player).last_activity_seq
+    }
+
+    /// `player`'s most recent resolved battles (up to 20), newest first. Unlike
+    /// games, these never expire.
+    pub fn get_match_history(env: Env, player: Address, offset: u32, limit: u32) -> Vec<ArchivedGame> {
+        history::get_match_history(&env, &player, offset, limit)
+    }
+
+    /// Pin a hash of your (off-chain, encrypted) notes to a session you finished
+    pub fn set_match_note(env: Env, player: Address, session_id: u32, note_hash: BytesN<32>) -> Result<(), Error> {
+        history::set_match_note(&env, player, session_id, note_hash)
+    }
+
+    pub fn get_match_note(env: Env, player: Address, session_id: u32) -> Option<BytesN<32>> {
+        storage::match_note(&env, &player, session_id)
     }
 
-    // ========================================================================
-    // Username Management
-    // ========================================================================
+    /// Rated record of `player`; players who haven't played a rated game start on 1500
+    pub fn get_player_stats(env: Env, player: Address) -> PlayerStats {
+        ratings::player_stats(&env, &player)
+    }
 
-    pub fn set_username(env: Env, caller: Address, username: String) -> Result<(), Error> {
-        caller.require_auth();
-    
-        // Validate username length (max 20 characters, min 3)
-        if username.len() > 20 {
-            return Err(Error::UsernameTooLong);
-        }
-        if username.len() < 3 {
-            return Err(Error::UsernameTooShort);
-        }
-    
-        // Validate username format (lowercase alphanumeric + underscore only)
-        if !Self::validate_username_format(&username) {
-            return Err(Error::InvalidUsernameFormat);
-        }
-    
-        // Check for reserved usernames
-        let reserved_names = vec![
-            &env,
-            String::from_str(&env, "admin"),
-            String::from_str(&env, "system"),
-            String::from_str(&env, "moderator"),
-            String::from_str(&env, "support"),
-        ];
-        
-        for reserved_name in reserved_names.iter() {
-            if username == reserved_name {
-                return Err(Error::UsernameReserved);
-            }
-        }
-    
-        // Check if username is already taken by someone else
-        let username_key = DataKey::AddressByUsername(username.clone());
-        if let Some(existing_address) = env.storage().persistent().get::<DataKey, Address>(&username_key) {
-            if existing_address != caller {
-                return Err(Error::UsernameAlreadyTaken);
-            }
-            return Ok(());
+    /// Whether `player`'s rating is still provisional: under 10 rated games, each
+    /// moving it twice as far as an established rating
+    pub fn is_provisional(env: Env, player: Address) -> bool {
+        ratings::is_provisional(&ratings::player_stats(&env, &player))
+    }
+
+    /// Name `rival` as your rival. Once they name you back, games between you pay a
+    /// rivalry bonus and count towards your head-to-head record.
+    pub fn declare_rival(env: Env, player: Address, rival: Address) -> Result<(), Error> {
+        rivals::declare_rival(&env, player, rival)
+    }
+
+    pub fn clear_rival(env: Env, player: Address) {
+        rivals::clear_rival(&env, player)
+    }
+
+    pub fn get_rival(env: Env, player: Address) -> Option<Address> {
+        storage::rival(&env, &player)
+    }
+
+    /// `player`'s record in rival matches against `opponent`
+    pub fn get_head_to_head(env: Env, player: Address, opponent: Address) -> HeadToHead {
+        rivals::head_to_head(&env, &player, &opponent)
+    }
+
+    /// The grudge series `a` and `b` are in: their games against each other since
+    /// either last played someone else, scored from `a`'s side
+    pub fn get_rivalry_series(env: Env, a: Address, b: Address) -> Option<RivalrySeries> {
+        rivals::rivalry_series(&env, &a, &b)
+    }
+
+    /// How `player` ranks among all rated players ("top 5%"), read from the rating
+    /// histogram instead of the whole ladder
+    pub fn get_rating_percentile(env: Env, player: Address) -> RatingPercentile {
+        ratings::get_rating_percentile(&env, &player)
+    }
+
+    /// Highest-rated players, best first (at most 100), after any rating decay owed
+    pub fn get_top_players(env: Env, limit: u32) -> Vec<RankedPlayer> {
+        ratings::get_top_players(&env, limit)
+    }
+
+    /// Have inactive players' ratings decay (see `RatingDecay`); 0 for either turns
+    /// it off. Admin only.
+    pub fn set_rating_decay(env: Env, inactive_secs: u64, points: u32) {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        let decay = RatingDecay { inactive_secs, points };
+        env.storage().instance().set(&DataKey::RatingDecay, &decay);
+    }
+
+    pub fn get_rating_decay(env: Env) -> RatingDecay {
+        storage::rating_decay(&env)
+    }
+
+    /// Write the decay `player` owes for inactivity into their stored record, the
+    /// histogram and the leaderboard. Reads already show it; anyone can call this
+    /// to bring the leaderboard up to date.
+    pub fn decay_rating(env: Env, player: Address) -> PlayerStats {
+        ratings::decay_rating(&env, &player)
+    }
+
+    /// Open a season ending at `end_timestamp`. Rated results are also tracked per
+    /// season from everyone's starting rating; lifetime stats carry on as before.
+    pub fn start_season(env: Env, season_id: u32, end_timestamp: u64) -> Result<Season, Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        seasons::start_season(&env, season_id, end_timestamp)
+    }
+
+    /// Close the running season, freezing its `top_n` best season ratings. The
+    /// season rewards, if set, are taken from the admin and become claimable.
+    pub fn end_season(env: Env, top_n: u32) -> Result<SeasonResults, Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        seasons::end_season(&env, &admin, top_n)
+    }
+
+    /// `end_season`, then `start_season` for the next one
+    pub fn rollover_season(
+        env: Env,
+        top_n: u32,
+        next_season_id: u32,
+        end_timestamp: u64,
+    ) -> Result<SeasonResults, Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        seasons::rollover_season(&env, &admin, top_n, next_season_id, end_timestamp)
+    }
+
+    /// Rewards for the top finishers of seasons ended from now on, best rank first.
+    /// An empty list turns them off.
+    pub fn set_season_rewards(env: Env, token: Address, amounts: Vec<i128>) -> Result<(), Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        if amounts.iter().any(|amount| amount < 0) || amounts.len() > ratings::LEADERBOARD_SIZE {
+            return Err(Error::InvalidSeason);
         }
-    
-        // Get the caller's current username (if any)
-        let old_username_key = DataKey::Username(caller.clone());
-        if let Some(old_username) = env.storage().persistent().get::<DataKey, String>(&old_username_key) {
-            let old_reverse_key = DataKey::AddressByUsername(old_username.clone());
-            if let Some(mapped_address) = env.storage().persistent().get::<DataKey, Address>(&old_reverse_key) {
-                if mapped_address == caller {
-                    env.storage().persistent().remove(&old_reverse_key);
-                }
-            }
+        if amounts.is_empty() {
+            env.storage().instance().remove(&DataKey::SeasonRewards);
+        } else {
+            env.storage()
+                .instance()
+                .set(&DataKey::SeasonRewards, &SeasonRewards { token, amounts });
         }
-    
-        // Set new username mappings
-        env.storage().persistent().set(&old_username_key, &username);
-        env.storage().persistent().set(&username_key, &caller);
-    
         Ok(())
     }
-    
-    /// Get username for an address
-    pub fn get_username(env: Env, address: Address) -> Option<String> {
-        env.storage().persistent().get(&DataKey::Username(address))
+
+    pub fn get_season_rewards(env: Env) -> Option<SeasonRewards> {
+        storage::season_rewards(&env)
     }
-    
-    /// Get address for a username
-    pub fn get_address_by_username(env: Env, username: String) -> Option<Address> {
-        env.storage().persistent().get(&DataKey::AddressByUsername(username))
+
+    /// Collect the reward for `player`'s finish in `season_id`; returns the amount paid
+    pub fn claim_season_placement(env: Env, player: Address, season_id: u32) -> Result<i128, Error> {
+        seasons::claim_season_placement(&env, player, season_id)
     }
 
-    // ========================================================================
-    // Challenge System
-    // ========================================================================
+    pub fn get_season_placement(env: Env, season_id: u32, player: Address) -> Option<SeasonPlacement> {
+        storage::season_placement(&env, season_id, &player)
+    }
 
-    /// Send a challenge to another player
-    pub fn send_challenge(
+    /// Anyone: once a season's claim window has closed, move its unclaimed rewards
+    /// to the collected fees. Returns the amount moved.
+    pub fn sweep_season_rewards(env: Env, season_id: u32) -> Result<i128, Error> {
+        seasons::sweep_season_rewards(&env, season_id)
+    }
+
+    /// The running season, or the last one if it has ended
+    pub fn get_season(env: Env) -> Option<Season> {
+        storage::season(&env)
+    }
+
+    /// `player`'s record in a season; the starting record if they didn't play in it
+    pub fn get_season_stats(env: Env, season_id: u32, player: Address) -> PlayerStats {
+        seasons::season_stats(&env, season_id, &player)
+    }
+
+    pub fn get_season_results(env: Env, season_id: u32) -> Option<SeasonResults> {
+        storage::season_results(&env, season_id)
+    }
+
+    /// Badges `player` has earned, oldest first
+    pub fn get_achievements(env: Env, player: Address) -> Vec<EarnedAchievement> {
+        storage::achievements(&env, &player)
+    }
+
+    /// Game counts, attack usage and average battle length across all players
+    pub fn get_global_stats(env: Env) -> GlobalStats {
+        storage::global_stats(&env)
+    }
+
+    /// Siege wins and losses for a player, by role
+    pub fn get_siege_stats(env: Env, player: Address) -> SiegeStats {
+        storage::siege_stats(&env, &player)
+    }
+
+    /// Open an unranked game against the on-chain bot. Returns the practice id.
+    pub fn start_practice_game(env: Env, player: Address, difficulty: PracticeDifficulty) -> u32 {
+        practice::start_practice_game(&env, player, difficulty)
+    }
+
+    /// Play a practice game with the player's moves against the bot's, drawn now
+    pub fn resolve_practice_battle(
         env: Env,
-        challenger: Address,
-        challenged: Address,
-        points_wagered: i128,
-    ) -> Result<u32, Error> {
-        challenger.require_auth();
+        practice_id: u32,
+        player: Address,
+        moves: Vec<Move>,
+    ) -> Result<BattleResult, Error> {
+        practice::resolve_practice_battle(&env, practice_id, player, moves)
+    }
 
-        // Cannot challenge self
-        if challenger == challenged {
-            return Err(Error::CannotChallengeSelf);
-        }
+    pub fn get_practice_game(env: Env, practice_id: u32) -> Option<PracticeGame> {
+        storage::practice_game(&env, practice_id)
+    }
 
-        // Get and increment challenge counter
-        let challenge_id: u32 = env.storage()
-            .instance()
-            .get(&DataKey::ChallengeCounter)
-            .unwrap_or(0);
-        env.storage().instance().set(&DataKey::ChallengeCounter, &(challenge_id + 1));
-
-        // Create challenge (expires in 7 days)
-        let current_time = env.ledger().timestamp();
-        let challenge = Challenge {
-            challenge_id,
-            challenger: challenger.clone(),
-            challenged: challenged.clone(),
-            points_wagered,
-            created_at: current_time,
-            expires_at: current_time + (7 * 24 * 60 * 60), // 7 days in seconds
-            is_accepted: false,
-            is_completed: false,
-            session_id: None,
-        };
+    pub fn get_practice_stats(env: Env, player: Address, difficulty: PracticeDifficulty) -> PracticeStats {
+        storage::practice_stats(&env, &player, difficulty)
+    }
 
-        // Store challenge
-        let challenge_key = DataKey::Challenge(challenge_id);
-        env.storage().temporary().set(&challenge_key, &challenge);
-        env.storage().temporary().extend_ttl(&challenge_key, CHALLENGE_TTL_LEDGERS, CHALLENGE_TTL_LEDGERS);
+    /// Let `signer` (e.g. a hot key of a passkey smart account) sign `player`'s commits
+    /// and reveals in `session_id`, and nothing else
+    pub fn authorize_session_signer(env: Env, player: Address, signer: Address, session_id: u32) -> Result<(), Error> {
+        signers::authorize_session_signer(&env, player, signer, session_id)
+    }
+
+    /// Sign `session_id`'s commits and reveals with the player's own account again
+    pub fn revoke_session_signer(env: Env, player: Address, session_id: u32) -> Result<(), Error> {
+        signers::revoke_session_signer(&env, player, session_id)
+    }
 
-        // Add to challenged player's list
-        let mut challenges = env.storage()
-            .persistent()
-            .get::<DataKey, Vec<u32>>(&DataKey::PlayerChallenges(challenged.clone()))
-            .unwrap_or(vec![&env]);
-        challenges.push_back(challenge_id);
-        env.storage().persistent().set(&DataKey::PlayerChallenges(challenged.clone()), &challenges);
+    pub fn get_session_signer(env: Env, session_id: u32, player: Address) -> Option<Address> {
+        storage::session_signer(&env, session_id, &player)
+    }
 
-        // Add to challenger's list
-        let mut challenger_challenges = env.storage()
-            .persistent()
-            .get::<DataKey, Vec<u32>>(&DataKey::PlayerChallenges(challenger.clone()))
-            .unwrap_or(vec![&env]);
-        challenger_challenges.push_back(challenge_id);
-        env.storage().persistent().set(&DataKey::PlayerChallenges(challenger), &challenger_challenges);
+    /// Name (or with `None`, remove) `player`'s coach for `session_id`
+    pub fn set_coach(env: Env, session_id: u32, player: Address, coach: Option<Address>) -> Result<(), Error> {
+        coaching::set_coach(&env, session_id, player, coach)
+    }
 
-        Ok(challenge_id)
+    /// `player`'s revealed moves, for their coach, once the battle is resolved. The
+    /// same moves are public through `get_game` as soon as they're revealed.
+    pub fn get_coached_moves(env: Env, session_id: u32, coach: Address, player: Address) -> Result<Vec<Move>, Error> {
+        coaching::get_coached_moves(&env, session_id, coach, player)
     }
 
-    /// Accept a challenge and start a game
-    pub fn accept_challenge(
+    /// Commit move sequence with ZK proof
+    /// Player proves they know valid moves WITHOUT revealing them.
+    /// The proof's public output (commitment hash) is stored.
+    pub fn commit_moves(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        public_inputs: Bytes,
+        proof_bytes: Bytes,
+    ) -> Result<CommitReceipt, Error> {
+        pause::check(&env, Breaker::Commits)?;
+        zk::commit_moves(&env, session_id, player, public_inputs, proof_bytes)
+    }
+
+    /// Receipt for `player`'s commit in a game or team game, showing which ledger
+    /// it landed in
+    pub fn get_commit_receipt(env: Env, session_id: u32, player: Address) -> Option<CommitReceipt> {
+        storage::commit_receipt(&env, session_id, &player)
+    }
+
+    /// Commit `sha256(moves || salt)` on a hash-mode game, where each move is its
+    /// attack and defense byte and the salt is 32 bytes. Takes the place of
+    /// `commit_moves` when the game has no ZK verifier to call.
+    pub fn commit_moves_hashed(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<CommitReceipt, Error> {
+        pause::check(&env, Breaker::Commits)?;
+        zk::commit_moves_hashed(&env, session_id, player, commitment)
+    }
+
+    /// Run the current circuit's verifier on a proof without committing it, returning
+    /// the commitment hash. Meant to be simulated by frontends before sending `commit_moves`; fails
+    /// with the same `InvalidPublicInputs`, `NullifierUsed`, `InvalidProof` and
+    /// `VerifierTrapped` errors a commit would.
+    pub fn preverify_proof(env: Env, public_inputs: Bytes, proof_bytes: Bytes) -> Result<BytesN<32>, Error> {
+        zk::preverify_proof(&env, public_inputs, proof_bytes)
+    }
+
+    /// Reveal moves — player re-proves with moves now PUBLIC.
+    /// The contract verifies the new proof's commitment output
+    /// matches what was stored at commit time.
+    pub fn reveal_moves(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        public_inputs: Bytes,
+        moves: Vec<Move>,
+    ) -> Result<(), Error> {
+        pause::check(&env, Breaker::Commits)?;
+        zk::reveal_moves(&env, session_id, player, public_inputs, moves)
+    }
+
+    /// `reveal_moves`, then, if both players have now revealed, `resolve_battle` in the
+    /// same invocation, so the last player finishes the game in one transaction.
+    /// Returns the result, or the still-pending one (reason `Pending`) when the
+    /// opponent hasn't revealed, resolution is paused or a signed result is pending.
+    pub fn reveal_and_resolve(
         env: Env,
-        challenge_id: u32,
-        challenged: Address,
         session_id: u32,
+        player: Address,
+        public_inputs: Bytes,
+        moves: Vec<Move>,
+    ) -> Result<BattleResult, Error> {
+        pause::check(&env, Breaker::Commits)?;
+        zk::reveal_moves(&env, session_id, player, public_inputs, moves)?;
+        game::resolve_if_revealed(&env, session_id)
+    }
+
+    /// Reveal the moves and salt behind a `commit_moves_hashed` commitment. The
+    /// digest is recomputed on-chain; everything after that is as for `reveal_moves`.
+    pub fn reveal_moves_hashed(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        moves: Vec<Move>,
+        salt: BytesN<32>,
     ) -> Result<(), Error> {
-        challenged.require_auth();
+        pause::check(&env, Breaker::Commits)?;
+        zk::reveal_moves_hashed(&env, session_id, player, moves, salt)
+    }
 
-        // Get challenge
-        let challenge_key = DataKey::Challenge(challenge_id);
-        let mut challenge: Challenge = env.storage()
-            .temporary()
-            .get(&challenge_key)
-            .ok_or(Error::ChallengeNotFound)?;
+    /// After both commits and before any reveal, raise the stake by `amount` of the
+    /// raise token. The opponent must call or fold before anyone can reveal.
+    pub fn raise_wager(env: Env, session_id: u32, player: Address, amount: i128) -> Result<(), Error> {
+        pause::check(&env, Breaker::Commits)?;
+        raise::raise_wager(&env, session_id, player, amount)
+    }
 
-        // Verify challenged is correct player
-        if challenge.challenged != challenged {
+    /// What `player` has riding on a game: their Game Hub stake, plus the raise once
+    /// called. Reveals wait for a pending raise, so this is final once anyone reveals.
+    pub fn get_effective_wager(env: Env, session_id: u32, player: Address) -> Result<i128, Error> {
+        let game = storage::load_game(&env, session_id)?;
+        if player != game.player1 && player != game.player2 {
             return Err(Error::NotPlayer);
         }
+        Ok(raise::effective_wager(&game, &player))
+    }
 
-        // Check if expired
-        if env.ledger().timestamp() > challenge.expires_at {
-            return Err(Error::ChallengeExpired);
-        }
+    /// Match the opponent's raise
+    pub fn call_raise(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        pause::check(&env, Breaker::Commits)?;
+        raise::call_raise(&env, session_id, player)
+    }
 
-        // Mark as accepted
-        challenge.is_accepted = true;
-        challenge.session_id = Some(session_id);
-        env.storage().temporary().set(&challenge_key, &challenge);
-
-        // Start game with wagered points. Challenger auth was already provided when
-        // sending challenge, so acceptance should not require challenger to sign again.
-        Self::start_game_after_auth(
-            env,
-            session_id,
-            challenge.challenger.clone(),
-            challenge.challenged.clone(),
-            challenge.points_wagered,
-            challenge.points_wagered,
-        )?;
+    /// Decline the opponent's raise, conceding the game at the original stake
+    pub fn fold_raise(env: Env, session_id: u32, player: Address) -> Result<BattleResult, Error> {
+        pause::check(&env, Breaker::Resolution)?;
+        raise::fold_raise(&env, session_id, player)
+    }
 
-        Ok(())
+    /// Resolve the battle after both players have revealed their moves
+    pub fn resolve_battle(env: Env, session_id: u32) -> Result<BattleResult, Error> {
+        pause::check(&env, Breaker::Resolution)?;
+        game::resolve_battle(&env, session_id)
     }
 
-    /// Get all challenges for a player (sorted by status)
-    pub fn get_player_challenges(env: Env, player: Address) -> (Vec<Challenge>, Vec<Challenge>, Vec<Challenge>) {
-        let challenge_ids = env.storage()
-            .persistent()
-            .get::<DataKey, Vec<u32>>(&DataKey::PlayerChallenges(player))
-            .unwrap_or(vec![&env]);
-
-        let mut active = vec![&env];
-        let mut completed = vec![&env];
-        let mut expired = vec![&env];
-
-        let current_time = env.ledger().timestamp();
-
-        for i in 0..challenge_ids.len() {
-            let challenge_id = challenge_ids.get(i).unwrap();
-            if let Some(challenge) = env.storage().temporary().get::<DataKey, Challenge>(&DataKey::Challenge(challenge_id)) {
-                if challenge.is_completed {
-                    completed.push_back(challenge);
-                } else if current_time > challenge.expires_at {
-                    expired.push_back(challenge);
-                } else {
-                    active.push_back(challenge);
-                }
-            }
-        }
+    /// Games and duels waiting on `player` to commit, reveal or answer a raise, among
+    /// up to `limit` of their live sessions from `offset` (a player has at most 30)
+    pub fn get_awaiting_action(env: Env, player: Address, offset: u32, limit: u32) -> Vec<u32> {
+        awaiting::get_awaiting_action(&env, &player, offset, limit)
+    }
 
-        (active, completed, expired)
+    /// Pending challenges, games awaiting `player` and rewards to claim, as counts
+    /// for the navbar. They can lag the clock; `get_player_challenges` and
+    /// `get_awaiting_action` are exact.
+    pub fn get_badges(env: Env, player: Address) -> Badges {
+        storage::badges(&env, &player)
     }
 
-    // ========================================================================
-    // Game Playback
-    // ========================================================================
+    /// Up to `limit` sessions from `offset` with both moves revealed that haven't been
+    /// resolved yet, for bots and the frontend to retry `resolve_battle` on. The list
+    /// holds the latest 100.
+    pub fn get_pending_resolutions(env: Env, offset: u32, limit: u32) -> Vec<u32> {
+        game::get_pending_resolutions(&env, offset, limit)
+    }
 
-    /// Get detailed game playback with all moves and results
-    pub fn get_game_playback(env: Env, session_id: u32) -> Result<GamePlayback, Error> {
-        let key = DataKey::Game(session_id);
-        let game: Game = env.storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
-
-        // Check if both players have revealed
-        if !game.player1_commitment.has_revealed || !game.player2_commitment.has_revealed {
-            return Err(Error::BothPlayersNotCommitted);
-        }
+    /// After the reveal deadline, end a game in which a committed player never revealed.
+    /// The player who revealed wins by forfeit and receives the other's deposit.
+    /// Correspondence games can also be claimed when the opponent missed the commit
+    /// deadline.
+    pub fn claim_reveal_timeout(env: Env, session_id: u32, player: Address) -> Result<BattleResult, Error> {
+        pause::check_deadline_claim(&env)?;
+        game::claim_reveal_timeout(&env, session_id, player)
+    }
 
-        // Get usernames
-        let player1_username = Self::get_username(env.clone(), game.player1.clone());
-        let player2_username = Self::get_username(env.clone(), game.player2.clone());
-
-        // Build detailed turn results
-        let mut detailed_turns = vec![&env];
-        
-        for turn in 0..TURNS_PER_BATTLE {
-            let p1_move = game.player1_commitment.moves.moves.get(turn).unwrap();
-            let p2_move = game.player2_commitment.moves.moves.get(turn).unwrap();
-
-            // Calculate damage and defense success for this turn
-            let (p1_damage, p1_defense_success) = Self::calculate_damage_and_defense(
-                &env,
-                p1_move.attack,
-                p2_move.defense,
-                &game.player1_commitment.moves.moves,
-                turn,
-            );
-
-            let (p2_damage, p2_defense_success) = Self::calculate_damage_and_defense(
-                &env,
-                p2_move.attack,
-                p1_move.defense,
-                &game.player2_commitment.moves.moves,
-                turn,
-            );
-
-            // Get HP from battle result
-            let turn_result = game.battle_result.turn_results.get(turn).unwrap();
-
-            detailed_turns.push_back(DetailedTurnResult {
-                turn,
-                player1_move: p1_move,
-                player2_move: p2_move,
-                player1_damage_dealt: p1_damage,
-                player2_damage_dealt: p2_damage,
-                player1_damage_taken: p2_damage,
-                player2_damage_taken: p1_damage,
-                player1_hp_remaining: turn_result.player1_hp_remaining,
-                player2_hp_remaining: turn_result.player2_hp_remaining,
-                player1_defense_successful: p1_defense_success,
-                player2_defense_successful: p2_defense_success,
-            });
-        }
+    /// Settle a game or duel whose deadline has passed, with the same outcome the
+    /// players' timeout claims would give. Needs no signature, so keepers and other
+    /// observers can end abandoned sessions.
+    pub fn enforce_deadlines(env: Env, session_id: u32) -> Result<BattleResult, Error> {
+        pause::check_deadline_claim(&env)?;
+        game::enforce_deadlines(&env, session_id)
+    }
+
+    /// Call off a game nobody has committed to once it has sat for a day (or, for
+    /// correspondence, past its commit deadline). Either player or the admin can
+    /// cancel; the Game Hub refunds both stakes and no result is recorded.
+    pub fn cancel_game(env: Env, session_id: u32, caller: Address) -> Result<(), Error> {
+        pause::check(&env, Breaker::Resolution)?;
+        game::cancel_game(&env, session_id, caller)
+    }
+
+    /// Anyone: void a game still unresolved 14 days after it started (plus any agreed
+    /// pauses), whichever phase it stalled in. Every stake is refunded and no result
+    /// is recorded.
+    pub fn expire_game(env: Env, session_id: u32) -> Result<(), Error> {
+        pause::check_deadline_claim(&env)?;
+        game::expire_game(&env, session_id)
+    }
+
+    /// When `expire_game` can first be called on this game
+    pub fn get_game_expiry(env: Env, session_id: u32) -> Result<u64, Error> {
+        let game = storage::load_game(&env, session_id)?;
+        Ok(game::expires_at(&env, session_id, &game))
+    }
+
+    /// Concede an unfinished game; the opponent wins and the Game Hub is told
+    pub fn forfeit(env: Env, session_id: u32, player: Address) -> Result<BattleResult, Error> {
+        pause::check(&env, Breaker::Resolution)?;
+        game::forfeit(&env, session_id, player)
+    }
+
+    /// Recompute a stored seed from its domain and inputs
+    pub fn verify_randomness(env: Env, audit: RandomnessAudit) -> bool {
+        randomness::verify(&env, &audit)
+    }
+
+    /// Register the ed25519 key `player` co-signs off-chain results with
+    pub fn set_settlement_key(env: Env, player: Address, public_key: BytesN<32>) {
+        settlement::set_settlement_key(&env, player, public_key)
+    }
+
+    pub fn get_settlement_key(env: Env, player: Address) -> Option<BytesN<32>> {
+        storage::settlement_key(&env, &player)
+    }
+
+    /// Submit the result of a match played off-chain, signed by both players over
+    /// `signed_result_digest`. It applies after a challenge window unless disputed.
+    pub fn settle_signed_result(
+        env: Env,
+        session_id: u32,
+        result: SignedResult,
+        sig_p1: BytesN<64>,
+        sig_p2: BytesN<64>,
+    ) -> Result<PendingSettlement, Error> {
+        pause::check(&env, Breaker::Resolution)?;
+        settlement::settle_signed_result(&env, session_id, result, sig_p1, sig_p2)
+    }
+
+    /// Reject a pending signed result and fall back to full ZK resolution; the session
+    /// takes no further signed results
+    pub fn dispute_signed_result(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        settlement::dispute_signed_result(&env, session_id, player)
+    }
+
+    /// Apply an undisputed signed result once its challenge window has closed
+    pub fn finalize_signed_result(env: Env, session_id: u32) -> Result<BattleResult, Error> {
+        pause::check(&env, Breaker::Resolution)?;
+        settlement::finalize_signed_result(&env, session_id)
+    }
+
+    /// The message each player signs for `settle_signed_result`
+    pub fn signed_result_digest(env: Env, session_id: u32, result: SignedResult) -> BytesN<32> {
+        settlement::result_digest(&env, session_id, &result)
+    }
+
+    pub fn get_pending_settlement(env: Env, session_id: u32) -> Option<PendingSettlement> {
+        storage::pending_settlement(&env, session_id)
+    }
+
+    /// Full stored state of a game in one struct, for disaster recovery
+    pub fn export_game(env: Env, session_id: u32) -> Result<GameExport, Error> {
+        game::export_game(&env, session_id)
+    }
+
+    /// Collect the token winnings, refunds and forfeited deposits owed to `player`
+    /// from a settled game; returns the amount paid per token
+    pub fn claim_winnings(env: Env, session_id: u32, player: Address) -> Result<Map<Address, i128>, Error> {
+        winnings::claim_winnings(&env, session_id, player)
+    }
+
+    /// Token amounts `player` can still claim from a game
+    pub fn get_winnings(env: Env, session_id: u32, player: Address) -> Map<Address, i128> {
+        storage::winnings(&env, session_id, &player)
+    }
+
+    /// Stake `amount` of the prediction token on `backed_player` winning. Open to
+    /// anyone but the two players, until the first commit lands.
+    pub fn place_prediction(
+        env: Env,
+        session_id: u32,
+        predictor: Address,
+        backed_player: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        prediction::place_prediction(&env, session_id, predictor, backed_player, amount)
+    }
+
+    /// Take a prediction back before the first commit (or after the game expired
+    /// unsettled); returns the refund
+    pub fn withdraw_prediction(env: Env, session_id: u32, predictor: Address) -> Result<i128, Error> {
+        prediction::withdraw_prediction(&env, session_id, predictor)
+    }
+
+    /// Collect a prediction once the game settles: a pro-rata share of the losing
+    /// side on a correct call, the stake back on a draw; returns the amount paid
+    pub fn claim_prediction(env: Env, session_id: u32, predictor: Address) -> Result<i128, Error> {
+        prediction::claim_prediction(&env, session_id, predictor)
+    }
+
+    pub fn get_prediction_pool(env: Env, session_id: u32) -> Option<PredictionPool> {
+        storage::prediction_pool(&env, session_id)
+    }
+
+    pub fn get_prediction(env: Env, session_id: u32, predictor: Address) -> Option<Prediction> {
+        storage::prediction(&env, session_id, &predictor)
+    }
+
+    /// Call the winner of a game for free. Open to anyone but the two players, until
+    /// the second reveal lands; scored into `get_pick_stats` when the game resolves.
+    pub fn make_pick(env: Env, session_id: u32, spectator: Address, backed_player: Address) -> Result<(), Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        picks::make_pick(&env, session_id, spectator, backed_player)
+    }
+
+    /// Picks made on a game that has not been scored yet
+    pub fn get_picks(env: Env, session_id: u32) -> Vec<SpectatorPick> {
+        storage::picks(&env, session_id)
+    }
+
+    pub fn get_pick_stats(env: Env, spectator: Address) -> PickStats {
+        storage::pick_stats(&env, &spectator)
+    }
+
+    /// Escrow receipt for a game or duel's locked stakes
+    pub fn get_escrow(env: Env, session_id: u32) -> Option<EscrowReceipt> {
+        storage::escrow(&env, session_id)
+    }
+
+    /// Get game information
+    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+        storage::load_game(&env, session_id)
+    }
+
+    /// Several games at once, each with its session ID; sessions with no game are
+    /// left out, and at most 20 are read
+    pub fn get_games(env: Env, session_ids: Vec<u32>) -> Vec<SessionGame> {
+        game::get_games(&env, session_ids)
+    }
 
-        Ok(GamePlayback {
-            session_id,
-            player1: game.player1,
-            player2: game.player2,
-            player1_username,
-            player2_username,
-            turn_results: detailed_turns,
-            final_player1_hp: game.battle_result.player1_hp,
-            final_player2_hp: game.battle_result.player2_hp,
-            winner: game.battle_result.winner,
-            is_draw: game.battle_result.is_draw,
-        })
+    /// Sessions of `player`'s games that haven't settled yet, oldest first, among up to
+    /// `limit` of their live sessions from `offset` (a player has at most 30)
+    pub fn get_player_active_games(env: Env, player: Address, offset: u32, limit: u32) -> Vec<u32> {
+        awaiting::get_active_games(&env, &player, offset, limit)
     }
 
     // ========================================================================
-    // Existing Game Functions 
+    // Turn-by-turn Duels
     // ========================================================================
 
-    /// Start a new game between two players with points
-    pub fn start_game(
+    /// Start a turn-by-turn duel. Same Game Hub session and points as `start_game`,
+    /// but moves are committed and revealed one round at a time.
+    pub fn start_duel(
         env: Env,
         session_id: u32,
         player1: Address,
@@ -635,761 +2845,720 @@ impl ClashContract {
         player1_points: i128,
         player2_points: i128,
     ) -> Result<(), Error> {
-        // Prevent self-play
-        if player1 == player2 {
-            panic!("Cannot play against yourself");
-        }
+        pause::check(&env, Breaker::NewGames)?;
+        duel::start_duel(&env, session_id, player1, player2, player1_points, player2_points)
+    }
 
-        // Require authentication from both players
-        player1.require_auth_for_args(vec![
-            &env,
-            session_id.into_val(&env),
-            player1_points.into_val(&env),
-        ]);
-        // player2.require_auth_for_args(vec![
-        //     &env,
-        //     session_id.into_val(&env),
-        //     player2_points.into_val(&env),
-        // ]);
-
-        Self::start_game_after_auth(
-            env,
-            session_id,
-            player1,
-            player2,
-            player1_points,
-            player2_points,
-        )
-    }
-
-    fn start_game_after_auth(
+    /// Commit this round's move as `sha256(attack | defense | salt)`
+    pub fn commit_duel_move(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        pause::check(&env, Breaker::Commits)?;
+        duel::commit_move(&env, session_id, player, commitment)
+    }
+
+    /// Reveal this round's move; the second reveal resolves the round
+    pub fn reveal_duel_move(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        action: Move,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        pause::check(&env, Breaker::Commits)?;
+        duel::reveal_move(&env, session_id, player, action, salt)
+    }
+
+    /// End a duel whose current phase deadline passed without the opponent acting
+    pub fn claim_duel_timeout(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        pause::check_deadline_claim(&env)?;
+        duel::claim_timeout(&env, session_id, player)
+    }
+
+    pub fn get_duel(env: Env, session_id: u32) -> Result<Duel, Error> {
+        storage::load_duel(&env, session_id)
+    }
+
+    // ========================================================================
+    // 2v2 Team Games
+    // ========================================================================
+
+    /// Start a 2v2 game. Each team stakes its points through its captain, the first
+    /// player listed, who is also who the Game Hub sees for that side.
+    pub fn start_team_game(
+        env: Env,
+        session_id: u32,
+        team1: Vec<Address>,
+        team2: Vec<Address>,
+        team1_points: i128,
+        team2_points: i128,
+    ) -> Result<(), Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        team::start_team_game(&env, session_id, team1, team2, team1_points, team2_points)
+    }
+
+    /// Commit one team member's move sequence with a ZK proof, as in `commit_moves`
+    pub fn commit_team_moves(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        public_inputs: Bytes,
+        proof_bytes: Bytes,
+    ) -> Result<CommitReceipt, Error> {
+        pause::check(&env, Breaker::Commits)?;
+        team::commit_team_moves(&env, session_id, player, public_inputs, proof_bytes)
+    }
+
+    /// Reveal one team member's moves, as in `reveal_moves`
+    pub fn reveal_team_moves(
         env: Env,
         session_id: u32,
+        player: Address,
+        public_inputs: Bytes,
+        moves: Vec<Move>,
+    ) -> Result<(), Error> {
+        pause::check(&env, Breaker::Commits)?;
+        team::reveal_team_moves(&env, session_id, player, public_inputs, moves)
+    }
+
+    /// Play out a team game once all four players have revealed, and report the
+    /// winning team to the Game Hub
+    pub fn resolve_team_battle(env: Env, session_id: u32) -> Result<BattleResult, Error> {
+        pause::check(&env, Breaker::Resolution)?;
+        team::resolve_team_battle(&env, session_id)
+    }
+
+    pub fn get_team_game(env: Env, session_id: u32) -> Result<TeamGame, Error> {
+        storage::load_team_game(&env, session_id)
+    }
+
+    /// Start a best-of-3 or best-of-5 series on Game Hub session `series_id`. Each
+    /// round is a classic game on its own session; the next one starts when a round
+    /// resolves. Returns the first round's session id.
+    pub fn start_series(
+        env: Env,
+        series_id: u32,
         player1: Address,
         player2: Address,
         player1_points: i128,
         player2_points: i128,
-    ) -> Result<(), Error> {
-        if player1 == player2 {
-            panic!("Cannot play against yourself");
-        }
-
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
-        // Call Game Hub to start the session and lock points
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
+        best_of: u32,
+    ) -> Result<u32, Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        series::start_series(&env, series_id, player1, player2, player1_points, player2_points, best_of)
+    }
 
-        // Create empty default commitment
-        let empty_commitment = PlayerCommitment {
-            proof_id: BytesN::from_array(&env, &[0u8; 32]),
-            has_revealed: false,
-            moves: MoveSequence {
-                moves: vec![&env],
-            },
-        };
+    pub fn get_series(env: Env, series_id: u32) -> Result<MatchSeries, Error> {
+        storage::series(&env, series_id).ok_or(Error::SeriesNotFound)
+    }
 
-        // Create empty battle result
-        let empty_result = BattleResult {
-            player1_hp: 0,
-            player2_hp: 0,
-            winner: None,
-            is_draw: false,
-            turn_results: vec![&env],
-        };
+    // ============================================================================
+    // Tournaments
+    // ============================================================================
 
-        // Create game
-        let game = Game {
-            player1: player1.clone(),
-            player2: player2.clone(),
-            player1_points,
-            player2_points,
-            has_player1_commitment: false,
-            player1_commitment: empty_commitment.clone(),
-            has_player2_commitment: false,
-            player2_commitment: empty_commitment,
-            has_battle_result: false,
-            battle_result: empty_result,
-        };
+    /// Open a single- or double-elimination tournament for `max_players` (a power of
+    /// two, up to 32, and at least 4 for double elimination); each match stakes
+    /// `entry_points` from both players
+    pub fn create_tournament(
+        env: Env,
+        organizer: Address,
+        max_players: u32,
+        entry_points: i128,
+        registration_deadline: u64,
+        format: BracketFormat,
+    ) -> Result<u32, Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        tournament::create_tournament(&env, organizer, max_players, entry_points, registration_deadline, format)
+    }
 
-        // Store game in temporary storage with TTL
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    pub fn register_for_tournament(env: Env, tournament_id: u32, player: Address) -> Result<(), Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        tournament::register_for_tournament(&env, tournament_id, player)
+    }
 
-        Ok(())
+    /// Confirm a registered player will play, in the 30 minutes before the
+    /// tournament starts. Only checked-in players are seeded.
+    pub fn check_in(env: Env, tournament_id: u32, player: Address) -> Result<(), Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        tournament::check_in(&env, tournament_id, player)
     }
 
-    /// Commit move sequence with ZK proof
-/// Player proves they know valid moves WITHOUT revealing them.
-/// The proof's public output (commitment hash) is stored.
-pub fn commit_moves(
-    env: Env,
-    session_id: u32,
-    player: Address,
-    public_inputs: Bytes,
-    proof_bytes: Bytes,
-) -> Result<BytesN<32>, Error> {
-    player.require_auth();
-
-    let key = DataKey::Game(session_id);
-    let mut game: Game = env
-        .storage()
-        .temporary()
-        .get(&key)
-        .ok_or(Error::GameNotFound)?;
-
-    if game.has_battle_result {
-        return Err(Error::GameAlreadyEnded);
-    }
-
-    let ultrahonk_addr = env.storage()
-    .instance()
-    .get(&DataKey::Ultrahonkverifier)
-    .expect("verifier address not set");
-            
-    // verify_proof now returns Result<BytesN<32>, ClashError>
-    let commitment_hash = verify_proof(&env, &ultrahonk_addr, public_inputs, proof_bytes)
-        .map_err(|_| Error::ProofVerificationFailed)?;
-
-    let commitment = PlayerCommitment {
-        proof_id: commitment_hash.clone(),
-        has_revealed: false,
-        moves: MoveSequence { moves: vec![&env] },
-    };
+    /// Seed the bracket from the checked-in players once the tournament starts, then
+    /// record results and move players on to their next matches as games resolve.
+    /// Anyone can call it.
+    pub fn advance_bracket(env: Env, tournament_id: u32) -> Result<Tournament, Error> {
+        pause::check(&env, Breaker::Resolution)?;
+        tournament::advance_bracket(&env, tournament_id)
+    }
 
-    if player == game.player1 {
-        if game.has_player1_commitment {
-            return Err(Error::AlreadyCommitted);
-        }
-        game.player1_commitment = commitment;
-        game.has_player1_commitment = true;
-    } else if player == game.player2 {
-        if game.has_player2_commitment {
-            return Err(Error::AlreadyCommitted);
-        }
-        game.player2_commitment = commitment;
-        game.has_player2_commitment = true;
-    } else {
-        return Err(Error::NotPlayer);
+    /// Call off a tournament before it completes. Organizer or admin; the prize pool
+    /// is credited back to the organizer, to collect with `claim_prize`.
+    pub fn cancel_tournament(env: Env, tournament_id: u32, caller: Address) -> Result<Tournament, Error> {
+        tournament::cancel_tournament(&env, tournament_id, caller)
     }
 
-    env.storage().temporary().set(&key, &game);
-    Ok(commitment_hash)
-}
+    /// Put up a prize pool of `pool` in `token`, split by `payouts_bps` (first place
+    /// first, summing to 10_000) when the bracket completes. Organizer only, once,
+    /// before the bracket is seeded.
+    pub fn set_tournament_prizes(
+        env: Env,
+        tournament_id: u32,
+        token: Address,
+        pool: i128,
+        payouts_bps: Vec<u32>,
+    ) -> Result<(), Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        tournament::set_prizes(&env, tournament_id, token, pool, payouts_bps)
+    }
 
-/// Reveal moves — player re-proves with moves now PUBLIC.
-/// The contract verifies the new proof's commitment output
-/// matches what was stored at commit time.
-pub fn reveal_moves(
-    env: Env,
-    session_id: u32,
-    player: Address,
-    public_inputs: Bytes,
-    moves: Vec<Move>,
-) -> Result<(), Error> {
-    player.require_auth();
+    /// Vest prizes above `threshold`: they pay `immediate_bps` of themselves when the
+    /// tournament completes and the rest `lock_secs` later (up to 30 days).
+    /// Organizer only, once, before the bracket is seeded.
+    pub fn set_prize_vesting(
+        env: Env,
+        tournament_id: u32,
+        threshold: i128,
+        immediate_bps: u32,
+        lock_secs: u64,
+    ) -> Result<(), Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        tournament::set_prize_vesting(&env, tournament_id, threshold, immediate_bps, lock_secs)
+    }
 
-    if moves.len() != TURNS_PER_BATTLE {
-        return Err(Error::InvalidMoveSequence);
+    /// Collect the prize credited to `player` when the tournament completed, or the
+    /// organizer's pool back if it was cancelled
+    pub fn claim_prize(env: Env, tournament_id: u32, player: Address) -> Result<i128, Error> {
+        tournament::claim_prize(&env, tournament_id, player)
     }
 
-    let key = DataKey::Game(session_id);
-    let mut game: Game = env
-        .storage()
-        .temporary()
-        .get(&key)
-        .ok_or(Error::GameNotFound)?;
+    /// Collect the locked part of `player`'s prize once it has vested
+    pub fn claim_vested(env: Env, tournament_id: u32, player: Address) -> Result<i128, Error> {
+        tournament::claim_vested(&env, tournament_id, player)
+    }
 
-    if !game.has_player1_commitment || !game.has_player2_commitment {
-        return Err(Error::BothPlayersNotCommitted);
+    pub fn get_tournament(env: Env, tournament_id: u32) -> Result<Tournament, Error> {
+        tournament::load(&env, tournament_id)
     }
 
-    let revealed_hash = Self::extract_commitment_hash(&env, &public_inputs)?;
+    pub fn get_tournament_prizes(env: Env, tournament_id: u32) -> Option<TournamentPrizes> {
+        storage::tournament_prizes(&env, tournament_id)
+    }
 
-    if player == game.player1 {
-        if game.player1_commitment.has_revealed {
-            return Err(Error::AlreadyRevealed);
-        }
-        if revealed_hash != game.player1_commitment.proof_id {
-            return Err(Error::CommitmentMismatch);
-        }
-        game.player1_commitment.moves = MoveSequence { moves };
-        game.player1_commitment.has_revealed = true;
-    } else if player == game.player2 {
-        if game.player2_commitment.has_revealed {
-            return Err(Error::AlreadyRevealed);
-        }
-        if revealed_hash != game.player2_commitment.proof_id {
-            return Err(Error::CommitmentMismatch);
-        }
-        game.player2_commitment.moves = MoveSequence { moves };
-        game.player2_commitment.has_revealed = true;
-    } else {
-        return Err(Error::NotPlayer);
+    pub fn get_prize_vesting(env: Env, tournament_id: u32) -> Option<PrizeVesting> {
+        storage::prize_vesting(&env, tournament_id)
     }
 
-    env.storage().temporary().set(&key, &game);
-    Ok(())
-}
+    pub fn get_vested_prize(env: Env, tournament_id: u32, player: Address) -> Option<VestedPrize> {
+        storage::vested_prize(&env, tournament_id, &player)
+    }
 
+    /// The whole bracket, laid out when it is seeded: winners' bracket round by
+    /// round, then the losers' bracket and the grand final and its reset
+    pub fn get_bracket(env: Env, tournament_id: u32) -> Vec<BracketMatch> {
+        storage::bracket(&env, tournament_id)
+    }
 
-    /// Resolve the battle after both players have revealed their moves
-    pub fn resolve_battle(env: Env, session_id: u32) -> Result<BattleResult, Error> {
-        // Get game from storage
-        let key = DataKey::Game(session_id);
-        let mut game: Game = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
-
-        // Check if already resolved
-        if game.has_battle_result {
-            // Update associated challenge if exists
-            Self::mark_challenge_completed(&env, session_id);
-            return Ok(game.battle_result.clone());
-        }
+    // ============================================================================
+    // PVP Match Functions
+    // ============================================================================
 
-        // Check both players have revealed moves
-        if !game.player1_commitment.has_revealed || !game.player2_commitment.has_revealed {
-            return Err(Error::BothPlayersNotCommitted);
-        }
+    pub fn create_invite(env: Env, inviter: Address, opponent: Address) -> Result<u32, Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        pvp::create_invite(&env, inviter, opponent)
+    }
 
-        // Simulate battle
-        let battle_result = Self::simulate_battle(
-            &env,
-            &game.player1,
-            &game.player2,
-            &game.player1_commitment.moves,
-            &game.player2_commitment.moves,
-        );
+    pub fn accept_invite(env: Env, accepter: Address, match_id: u32) -> Result<(), Error> {
+        pause::check(&env, Breaker::NewGames)?;
+        pvp::accept_invite(&env, accepter, match_id)
+    }
 
-        // Store result
-        game.battle_result = battle_result.clone();
-        game.has_battle_result = true;
-        env.storage().temporary().set(&key, &game);
+    pub fn reject_invite(env: Env, rejecter: Address, match_id: u32) -> Result<(), Error> {
+        pvp::reject_invite(&env, rejecter, match_id)
+    }
 
-        // Mark challenge as completed
-        Self::mark_challenge_completed(&env, session_id);
+    pub fn play_turn(env: Env, player: Address, match_id: u32, action: Move) -> Result<(), Error> {
+        pause::check(&env, Breaker::Commits)?;
+        pvp::play_turn(&env, player, match_id, action)
+    }
 
-        // Report to GameHub
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-        if battle_result.is_draw {
-            // For a draw, we don't care about player1_won value
-            // GameHub should detect this is a draw and handle accordingly (refund points, etc.)
-            // You can use false as a convention for draws, or the GameHub can be updated
-            // to check if both players have same points remaining
-            game_hub.end_game(&session_id, &false);
-        } else {
-            let winner = battle_result.winner.as_ref().unwrap();
-            let player1_won = winner == &game.player1;
-            game_hub.end_game(&session_id, &player1_won);
-            Self::mint_csh_reward(&env, winner.clone());
-        }
+    pub fn end_match(env: Env, player: Address, match_id: u32) -> Result<(), Error> {
+        pause::check(&env, Breaker::Resolution)?;
+        pvp::end_match(&env, player, match_id)
+    }
 
-        Ok(battle_result)
+    pub fn get_match(env: Env, match_id: u32) -> Result<PvPMatch, Error> {
+        storage::load_match(&env, match_id)
     }
 
-    /// Get game information
-    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
-        let key = DataKey::Game(session_id);
-        env.storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)
+    pub fn get_player_matches(env: Env, player: Address) -> Vec<u32> {
+        pvp::get_player_matches(&env, player)
     }
 
     // ========================================================================
-    // Internal Battle Logic
+    // Admin Functions
     // ========================================================================
 
-    fn extract_commitment_hash(env: &Env, public_inputs: &Bytes) -> Result<BytesN<32>, Error> {
-        // Public inputs structure: [player_address (32 bytes), session_id (32 bytes), commitment_hash (32 bytes)]
-        // The commitment hash is the last 32 bytes
-        
-        let len = public_inputs.len();
-        if len < 32 {
-            return Err(Error::InvalidPublicInputs);
-        }
-        
-        // Extract last 32 bytes
-        let hash_slice = public_inputs.slice((len - 32)..len);
-        
-        // Convert to array
-        let mut array = [0u8; 32];
-        for i in 0..32 {
-            array[i] = hash_slice.get(i as u32).ok_or(Error::InvalidPublicInputs)?;
-        }
-        
-        Ok(BytesN::from_array(env, &array))
-    }
-
-
-    fn validate_username_format(username: &String) -> bool {
-        let bytes = username.to_bytes();
-        
-        for i in 0..bytes.len() {
-            let byte = bytes.get(i).unwrap();
-            // Only allow lowercase a-z (97-122), 0-9 (48-57), and underscore (95)
-            let is_valid = (byte >= 97 && byte <= 122) || // a-z only
-                           (byte >= 48 && byte <= 57) ||  // 0-9
-                           byte == 95;                     // underscore
-            
-            if !is_valid {
-                return false;
-            }
-        }
-        
-        true
+    pub fn get_admin(env: Env) -> Address {
+        storage::admin(&env)
     }
 
-    fn simulate_battle(
-        env: &Env,
-        player1: &Address,
-        player2: &Address,
-        p1_moves: &MoveSequence,
-        p2_moves: &MoveSequence,
-    ) -> BattleResult {
-        let mut p1_hp = STARTING_HP;
-        let mut p2_hp = STARTING_HP;
-        let mut turn_results = Vec::new(env);
-    
-        for turn in 0..TURNS_PER_BATTLE {
-            let p1_move = &p1_moves.moves.get(turn).unwrap();
-            let p2_move = &p2_moves.moves.get(turn).unwrap();
-    
-            // Calculate damage
-            let (p1_damage, p1_defense_success) = Self::calculate_damage_and_defense(
-                env,
-                p1_move.attack,
-                p2_move.defense,
-                &p1_moves.moves,
-                turn,
-            );
-            let (p2_damage, p2_defense_success) = Self::calculate_damage_and_defense(
-                env,
-                p2_move.attack,
-                p1_move.defense,
-                &p2_moves.moves,
-                turn,
-            );
-    
-            // Apply damage SIMULTANEOUSLY
-            p1_hp -= p2_damage;
-            p2_hp -= p1_damage;
-    
-            // Store turn result
-            turn_results.push_back(TurnResult {
-                turn,
-                player1_damage_dealt: p1_damage,
-                player2_damage_dealt: p2_damage,
-                player1_hp_remaining: p1_hp,
-                player2_hp_remaining: p2_hp,
-                player1_defense_successful: p1_defense_success,
-                player2_defense_successful: p2_defense_success,
-            });
-    
-            // Check for knockout AFTER storing the result
-            if p1_hp <= 0 || p2_hp <= 0 {
-                break;
-            }
-        }
-    
-        // Determine winner with proper draw handling
-        let (winner, is_draw) = if p1_hp <= 0 && p2_hp <= 0 {
-            // Both died - it's a DRAW
-            (None, true)
-        } else if p1_hp > p2_hp {
-            // Player 1 wins
-            (Some(player1.clone()), false)
-        } else if p2_hp > p1_hp {
-            // Player 2 wins
-            (Some(player2.clone()), false)
-        } else {
-            // Equal HP but both alive - tie-breaker to Player 1
-            (Some(player1.clone()), false)
-        };
-    
-        BattleResult {
-            player1_hp: p1_hp,
-            player2_hp: p2_hp,
-            winner,
-            is_draw,
-            turn_results,
-        }
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
     }
 
-    
-    fn calculate_damage_and_defense(
-        _env: &Env,
-        attack: Attack,
-        defense: Defense,
-        move_sequence: &Vec<Move>,
-        current_turn: u32,
-    ) -> (i32, bool) {
-        // Base damage for each attack type
-        let base_damage = match attack {
-            Attack::Slash => 30,
-            Attack::Fireball => 40,
-            Attack::Lightning => 35,
-        };
+    pub fn get_hub(env: Env) -> Address {
+        storage::game_hub(&env)
+    }
 
-        // Pure RPS: Check if defense STOPS the attack
-        let blocked = match (attack, defense) {
-            (Attack::Slash, Defense::Dodge) => true,
-            (Attack::Fireball, Defense::Counter) => true,
-            (Attack::Lightning, Defense::Block) => true,
-            _ => false,
-        };
+    pub fn set_hub(env: Env, new_hub: Address) {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &new_hub);
+    }
 
-        // If blocked, no damage and defense was successful
-        if blocked {
-            return (0, true);
+    /// Register the verifier contract for circuit `version`, or replace its address.
+    /// A version above the current one becomes current: games created from then on
+    /// are pinned to it, while games already running keep their own version.
+    pub fn register_verifier(env: Env, version: u32, verifier: Address) -> Result<(), Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        if version == 0 {
+            return Err(Error::UnknownCircuitVersion);
         }
-
-        // Calculate combo bonus
-        let mut combo_bonus = 0;
-        if current_turn >= 1 {
-            let prev_attack = move_sequence.get(current_turn - 1).unwrap().attack;
-            if prev_attack == attack {
-                combo_bonus = COMBO_2_BONUS;
-            }
+        let entry = VerifierEntry {
+            address: verifier,
+            deprecated: false,
+        };
+        storage::set_verifier_entry(&env, version, &entry);
+        if version > storage::circuit_version(&env) {
+            storage::set_circuit_version(&env, version);
         }
-        if current_turn >= 2 {
-            let prev2_attack = move_sequence.get(current_turn - 2).unwrap().attack;
-            let prev1_attack = move_sequence.get(current_turn - 1).unwrap().attack;
-            if prev2_attack == attack && prev1_attack == attack {
-                combo_bonus = COMBO_3_BONUS;
-            }
+        Ok(())
+    }
+
+    /// Stop accepting proofs for circuit `version`, e.g. after a circuit bug. Games
+    /// pinned to it can no longer commit and have to be settled by timeout. The
+    /// current version can't be deprecated.
+    pub fn deprecate_verifier(env: Env, version: u32) -> Result<(), Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        let mut entry =
+            storage::verifier_entry(&env, version).ok_or(Error::UnknownCircuitVersion)?;
+        if version == storage::circuit_version(&env) {
+            return Err(Error::CircuitInUse);
         }
+        entry.deprecated = true;
+        storage::set_verifier_entry(&env, version, &entry);
+        Ok(())
+    }
 
-        (base_damage + combo_bonus, false)
+    pub fn get_verifier(env: Env, version: u32) -> Option<VerifierEntry> {
+        storage::verifier_entry(&env, version)
     }
 
-    // fn calculate_damage(
-    //     env: &Env,
-    //     attack: Attack,
-    //     defense: Defense,
-    //     move_sequence: &Vec<Move>,
-    //     current_turn: u32,
-    // ) -> i32 {
-    //     let (damage, _) = Self::calculate_damage_and_defense(env, attack, defense, move_sequence, current_turn);
-    //     damage
-    // }
+    /// The circuit version new games are pinned to
+    pub fn get_circuit_version(env: Env) -> u32 {
+        storage::circuit_version(&env)
+    }
 
-    fn mark_challenge_completed(env: &Env, session_id: u32) {
-        // Find and mark challenge as completed
-        let challenge_counter: u32 = env.storage()
+    pub fn set_token_contract(env: Env, token_contract: Address) {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        env.storage()
             .instance()
-            .get(&DataKey::ChallengeCounter)
-            .unwrap_or(0);
-
-        for challenge_id in 0..challenge_counter {
-            if let Some(mut challenge) = env.storage().temporary().get::<DataKey, Challenge>(&DataKey::Challenge(challenge_id)) {
-                if let Some(chal_session_id) = challenge.session_id {
-                    if chal_session_id == session_id {
-                        challenge.is_completed = true;
-                        env.storage().temporary().set(&DataKey::Challenge(challenge_id), &challenge);
-                        break;
-                    }
-                }
-            }
-        }
+            .set(&DataKey::TokenContractAddress, &token_contract);
     }
 
-    // ============================================================================
-    // PVP Match Functions
-    // ============================================================================
-
-    pub fn create_invite(env: Env, inviter: Address, opponent: Address) -> Result<u32, Error> {
-        inviter.require_auth();
-
-        if inviter == opponent {
-            return Err(Error::CannotChallengeSelf);
-        }
+    pub fn get_token_contract(env: Env) -> Option<Address> {
+        storage::token_contract(&env)
+    }
 
-        let match_id: u32 = env.storage()
-            .instance()
-            .get(&DataKey::MatchCounter)
-            .unwrap_or(0);
-        env.storage().instance().set(&DataKey::MatchCounter, &(match_id + 1));
-
-        let pvp_match = PvPMatch {
-            match_id,
-            player1: inviter.clone(),
-            player2: opponent.clone(),
-            state: MatchState::Created,
-            current_turn: 0,
-            player1_hp: STARTING_HP,
-            player2_hp: STARTING_HP,
-            last_action: None,
-            winner: None,
+    pub fn get_csh_balance(env: Env, player: Address) -> i128 {
+        let Some(token_addr) = Self::get_token_contract(env.clone()) else {
+            return 0;
         };
+        let token = ClashTokenClient::new(&env, &token_addr);
+        token.balance(&player)
+    }
 
-        env.storage().persistent().set(&DataKey::Match(match_id), &pvp_match);
+    /// Restore a game from `export_game`, overwriting whatever is stored for that
+    /// session. For recovering from migrations or expired temporary storage; the
+    /// Game Hub is not notified.
+    pub fn import_game(env: Env, export: GameExport) {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        game::import_game(&env, export);
+    }
 
-        // Add to players' lists
-        let mut inviter_matches = env.storage()
-            .persistent()
-            .get::<DataKey, Vec<u32>>(&DataKey::PlayerMatches(inviter.clone()))
-            .unwrap_or(vec![&env]);
-        inviter_matches.push_back(match_id);
-        env.storage().persistent().set(&DataKey::PlayerMatches(inviter), &inviter_matches);
+    /// Update gameplay tuning. Applies to games started afterwards.
+    pub fn set_game_config(env: Env, config: GameConfig) -> Result<(), Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        if !(0..=MAX_INITIATIVE_BONUS).contains(&config.initiative_bonus)
+            || !(MIN_REVEAL_WINDOW_SECS..=MAX_REVEAL_WINDOW_SECS).contains(&config.reveal_window_secs)
+            || config.crit_chance_bps > MAX_CRIT_CHANCE_BPS
+            || !(100..=MAX_CRIT_MULTIPLIER_PCT).contains(&config.crit_multiplier_pct)
+        {
+            return Err(Error::InvalidGameConfig);
+        }
+        env.storage().instance().set(&DataKey::GameConfig, &config);
+        Ok(())
+    }
 
-        let mut opponent_matches = env.storage()
-            .persistent()
-            .get::<DataKey, Vec<u32>>(&DataKey::PlayerMatches(opponent.clone()))
-            .unwrap_or(vec![&env]);
-        opponent_matches.push_back(match_id);
-        env.storage().persistent().set(&DataKey::PlayerMatches(opponent), &opponent_matches);
+    pub fn get_game_config(env: Env) -> GameConfig {
+        storage::game_config(&env)
+    }
 
-        Ok(match_id)
+    /// Damage table, blocks, combo, momentum and crit settings new games are played with
+    pub fn get_rules(env: Env) -> Rules {
+        game::get_rules(&env)
     }
 
-    pub fn accept_invite(env: Env, accepter: Address, match_id: u32) -> Result<(), Error> {
-        accepter.require_auth();
+    /// Replace the combat rules. Applies to games and duels started afterwards; the
+    /// `version` passed in is ignored and the new rules get the next version, which
+    /// is returned.
+    pub fn set_rules(env: Env, rules: RuleSet) -> Result<u32, Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        game::set_rules(&env, rules)
+    }
 
-        let mut pvp_match: PvPMatch = env.storage()
-            .persistent()
-            .get(&DataKey::Match(match_id))
-            .ok_or(Error::GameNotFound)?;
+    /// Set the inclusive wager bounds checked when games and challenges are created
+    pub fn set_wager_limits(env: Env, min: i128, max: i128) -> Result<(), Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        if min < 0 || min > max {
+            return Err(Error::InvalidWager);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::WagerLimits, &WagerLimits { min, max });
+        Ok(())
+    }
 
-        if pvp_match.player2 != accepter || pvp_match.state != MatchState::Created {
-            return Err(Error::NotPlayer);
+    /// Require a refundable `amount` of `token` from each player at commit time.
+    /// An amount of 0 turns the deposit off. Applies to games started afterwards.
+    pub fn set_commit_deposit(env: Env, token: Address, amount: i128) -> Result<(), Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        if amount < 0 {
+            return Err(Error::InvalidWager);
         }
+        if amount == 0 {
+            env.storage().instance().remove(&DataKey::CommitDeposit);
+        } else {
+            env.storage()
+                .instance()
+                .set(&DataKey::CommitDeposit, &CommitDeposit { token, amount });
+        }
+        Ok(())
+    }
 
-        pvp_match.state = MatchState::Active;
-        env.storage().persistent().set(&DataKey::Match(match_id), &pvp_match);
+    pub fn get_commit_deposit(env: Env) -> Option<CommitDeposit> {
+        storage::commit_deposit(&env)
+    }
 
+    /// Keep spent commit nullifiers for `ledgers` ledgers. Applies to commits made
+    /// afterwards; a proof can be replayed once its nullifier has expired.
+    pub fn set_nullifier_retention(env: Env, ledgers: u32) -> Result<(), Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        if !(MIN_NULLIFIER_RETENTION_LEDGERS..=MAX_NULLIFIER_RETENTION_LEDGERS).contains(&ledgers) {
+            return Err(Error::InvalidGameConfig);
+        }
+        storage::set_nullifier_retention(&env, ledgers);
         Ok(())
     }
 
-    pub fn reject_invite(env: Env, rejecter: Address, match_id: u32) -> Result<(), Error> {
-        rejecter.require_auth();
+    pub fn get_nullifier_retention(env: Env) -> u32 {
+        storage::nullifier_retention(&env)
+    }
+
+    /// Allow blind raises of up to `max_amount` of `token`. A max of 0 turns raising
+    /// off. Applies to games started afterwards.
+    pub fn set_raise_config(env: Env, token: Address, max_amount: i128) -> Result<(), Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        if max_amount < 0 {
+            return Err(Error::InvalidRaise);
+        }
+        if max_amount == 0 {
+            env.storage().instance().remove(&DataKey::RaiseConfig);
+        } else {
+            env.storage()
+                .instance()
+                .set(&DataKey::RaiseConfig, &RaiseConfig { token, max_amount });
+        }
+        Ok(())
+    }
 
-        let mut pvp_match: PvPMatch = env.storage()
-            .persistent()
-            .get(&DataKey::Match(match_id))
-            .ok_or(Error::GameNotFound)?;
+    pub fn get_raise_config(env: Env) -> Option<RaiseConfig> {
+        storage::raise_config(&env)
+    }
 
-        if pvp_match.player2 != rejecter || pvp_match.state != MatchState::Created {
-            return Err(Error::NotPlayer);
+    /// Token spectators stake with `place_prediction`; `None` turns new pools off.
+    /// Pools already open keep the token they started with.
+    pub fn set_prediction_token(env: Env, token: Option<Address>) {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        match token {
+            Some(token) => env.storage().instance().set(&DataKey::PredictionToken, &token),
+            None => env.storage().instance().remove(&DataKey::PredictionToken),
         }
+    }
 
-        pvp_match.state = MatchState::Finished;
-        env.storage().persistent().set(&DataKey::Match(match_id), &pvp_match);
+    pub fn get_prediction_token(env: Env) -> Option<Address> {
+        storage::prediction_token(&env)
+    }
 
+    /// Set the rake breakpoints, ordered by `min_wager`. An empty schedule charges
+    /// nothing. Applies to games started afterwards.
+    pub fn set_fee_schedule(env: Env, schedule: Vec<FeeTier>) -> Result<(), Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        fees::validate(&schedule)?;
+        env.storage().instance().set(&DataKey::FeeSchedule, &schedule);
         Ok(())
     }
 
-    pub fn play_turn(env: Env, player: Address, match_id: u32, action: Move) -> Result<(), Error> {
-        player.require_auth();
+    pub fn get_fee_schedule(env: Env) -> Vec<FeeTier> {
+        storage::fee_schedule(&env)
+    }
 
-        let mut pvp_match: PvPMatch = env.storage()
-            .persistent()
-            .get(&DataKey::Match(match_id))
-            .ok_or(Error::GameNotFound)?;
+    /// Cut the current schedule takes from the pot when each player stakes `wager`
+    pub fn quote_fee(env: Env, wager: i128) -> i128 {
+        fees::quote(&storage::fee_schedule(&env), wager)
+    }
 
-        if pvp_match.state != MatchState::Active {
-            return Err(Error::GameAlreadyEnded);
-        }
+    /// Name the player who referred `new_player`; once per player, before their
+    /// first game
+    pub fn register_referral(env: Env, new_player: Address, referrer: Address) -> Result<(), Error> {
+        referral::register_referral(&env, new_player, referrer)
+    }
 
-        let is_player1 = pvp_match.player1 == player;
-        let is_player2 = pvp_match.player2 == player;
-        if !is_player1 && !is_player2 {
-            return Err(Error::NotPlayer);
-        }
+    pub fn get_referrer(env: Env, player: Address) -> Option<Address> {
+        storage::referrer(&env, &player)
+    }
 
-        let current_player_turn = pvp_match.current_turn % 2 == 0;
-        if (current_player_turn && !is_player1) || (!current_player_turn && !is_player2) {
-            return Err(Error::NotPlayer); // not their turn
-        }
+    pub fn get_referral_stats(env: Env, referrer: Address) -> ReferralStats {
+        storage::referral_stats(&env, &referrer)
+    }
 
-        // Apply the action
-        if let Some(last_move) = pvp_match.last_action {
-            // Resolve with last move
-            let (damage1, damage2) = Self::resolve_turn(last_move, action);
-            pvp_match.player1_hp -= damage1;
-            pvp_match.player2_hp -= damage2;
-
-            if pvp_match.player1_hp <= 0 || pvp_match.player2_hp <= 0 {
-                pvp_match.state = MatchState::Finished;
-                if pvp_match.player1_hp <= 0 && pvp_match.player2_hp <= 0 {
-                    pvp_match.winner = None; // draw
-                } else if pvp_match.player1_hp <= 0 {
-                    pvp_match.winner = Some(pvp_match.player2.clone());
-                } else {
-                    pvp_match.winner = Some(pvp_match.player1.clone());
-                }
-            } else {
-                pvp_match.current_turn += 1;
-            }
-            pvp_match.last_action = None;
-        } else {
-            pvp_match.last_action = Some(action);
-            pvp_match.current_turn += 1;
-        }
+    /// Mint the referral bonuses credited to `referrer`; returns the amount paid
+    pub fn claim_referral_rewards(env: Env, referrer: Address) -> Result<i128, Error> {
+        referral::claim_rewards(&env, referrer)
+    }
 
-        env.storage().persistent().set(&DataKey::Match(match_id), &pvp_match);
+    /// Bonus points `player` has earned here and not yet settled to the Game Hub
+    pub fn get_bonus_points(env: Env, player: Address) -> i128 {
+        storage::bonus_points(&env, &player)
+    }
 
-        Ok(())
+    /// Move `amount` of the player's bonus points onto the Game Hub's ledger; returns
+    /// what is left here
+    pub fn settle_points_to_hub(env: Env, player: Address, amount: i128) -> Result<i128, Error> {
+        points::settle_to_hub(&env, player, amount)
     }
 
-    fn resolve_turn(move1: Move, move2: Move) -> (i32, i32) {
-        let mut damage1 = 0;
-        let mut damage2 = 0;
+    /// CSH credited to a referrer when a player they referred finishes a first battle
+    pub fn set_referral_bonus(env: Env, amount: i128) {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::ReferralBonus, &amount.max(0));
+    }
 
-        // Simple resolution: if attack not defended, deal damage
-        if move1.attack == Attack::Slash && move2.defense != Defense::Dodge {
-            damage2 += 30;
-        }
-        if move1.attack == Attack::Fireball && move2.defense != Defense::Counter {
-            damage2 += 40;
-        }
-        if move1.attack == Attack::Lightning && move2.defense != Defense::Block {
-            damage2 += 35;
-        }
+    pub fn get_referral_bonus(env: Env) -> i128 {
+        storage::referral_bonus(&env)
+    }
 
-        if move2.attack == Attack::Slash && move1.defense != Defense::Dodge {
-            damage1 += 30;
-        }
-        if move2.attack == Attack::Fireball && move1.defense != Defense::Counter {
-            damage1 += 40;
-        }
-        if move2.attack == Attack::Lightning && move1.defense != Defense::Block {
-            damage1 += 35;
-        }
+    /// Create (or replace) a promo code redeemable up to `max_uses` times
+    pub fn create_promo_code(
+        env: Env,
+        code: Symbol,
+        reward: PromoReward,
+        amount: i128,
+        max_uses: u32,
+    ) -> Result<(), Error> {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        promo::create_promo_code(&env, code, reward, amount, max_uses)
+    }
 
-        (damage1, damage2)
+    pub fn get_promo_code(env: Env, code: Symbol) -> Option<PromoCode> {
+        storage::promo_code(&env, &code)
     }
 
-    pub fn end_match(env: Env, player: Address, match_id: u32) -> Result<(), Error> {
-        player.require_auth();
+    pub fn redeem_code(env: Env, player: Address, code: Symbol) -> Result<(), Error> {
+        promo::redeem_code(&env, player, code)
+    }
 
-        let mut pvp_match: PvPMatch = env.storage()
-            .persistent()
-            .get(&DataKey::Match(match_id))
-            .ok_or(Error::GameNotFound)?;
+    pub fn get_fee_waivers(env: Env, player: Address) -> u32 {
+        storage::fee_waivers(&env, &player)
+    }
 
-        if pvp_match.player1 != player && pvp_match.player2 != player {
-            return Err(Error::NotPlayer);
-        }
+    pub fn get_collected_fees(env: Env, token: Address) -> i128 {
+        storage::collected_fees(&env, &token)
+    }
 
-        if pvp_match.state == MatchState::Finished {
-            return Err(Error::GameAlreadyEnded);
-        }
+    /// Send all fees collected in `token` to `to`; returns the amount sent
+    pub fn withdraw_fees(env: Env, token: Address, to: Address) -> i128 {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        fees::withdraw(&env, &token, &to)
+    }
 
-        pvp_match.state = MatchState::Finished;
-        env.storage().persistent().set(&DataKey::Match(match_id), &pvp_match);
+    /// Where `sweep_fees` pays collected fees
+    pub fn set_treasury(env: Env, treasury: Address) {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+    }
 
-        Ok(())
+    pub fn get_treasury(env: Env) -> Option<Address> {
+        storage::treasury(&env)
     }
 
-    pub fn get_match(env: Env, match_id: u32) -> Result<PvPMatch, Error> {
-        env.storage()
-            .persistent()
-            .get(&DataKey::Match(match_id))
-            .ok_or(Error::GameNotFound)
+    /// Who sets shadow flags; `None` hands it back to the admin
+    pub fn set_moderator(env: Env, moderator: Option<Address>) {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        match moderator {
+            Some(moderator) => env.storage().instance().set(&DataKey::Moderator, &moderator),
+            None => env.storage().instance().remove(&DataKey::Moderator),
+        }
     }
 
-    pub fn get_player_matches(env: Env, player: Address) -> Vec<u32> {
-        env.storage()
-            .persistent()
-            .get::<DataKey, Vec<u32>>(&DataKey::PlayerMatches(player))
-            .unwrap_or(vec![&env])
+    pub fn get_moderator(env: Env) -> Option<Address> {
+        storage::moderator(&env)
     }
 
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
+    /// Moderator: quietly keep `player` out of rated play, the ladders and
+    /// matchmaking while they are investigated, or lift that
+    pub fn set_shadowed(env: Env, player: Address, shadowed: bool) {
+        moderation::set_shadowed(&env, player, shadowed);
+    }
 
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+    /// Moderator only, so the flag stays hidden from the player it is set on
+    pub fn is_shadowed(env: Env, player: Address) -> bool {
+        moderation::get_shadowed(&env, &player)
     }
 
-    pub fn set_admin(env: Env, new_admin: Address) {
+    /// Anyone can send all fees collected in `token` to the treasury; returns the
+    /// amount sent
+    pub fn sweep_fees(env: Env, token: Address) -> Result<i128, Error> {
+        fees::sweep(&env, &token)
+    }
+
+    /// Trip or reset the circuit breakers. Replaces all flags at once; pass the
+    /// default to unpause.
+    pub fn set_pause(env: Env, flags: PauseFlags) {
         let admin: Address = Self::get_admin(env.clone());
         admin.require_auth();
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        pause::set(&env, flags);
     }
 
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+    pub fn get_pause(env: Env) -> PauseFlags {
+        storage::pause_flags(&env)
     }
 
-    pub fn set_hub(env: Env, new_hub: Address) {
+    /// When on, games only start if the Game Hub reports both players as registered,
+    /// so throwaway accounts can't farm leaderboard points
+    pub fn set_require_registered(env: Env, required: bool) {
         let admin: Address = Self::get_admin(env.clone());
         admin.require_auth();
         env.storage()
             .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+            .set(&DataKey::RequireRegistered, &required);
     }
 
-    pub fn set_token_contract(env: Env, token_contract: Address) {
+    pub fn get_require_registered(env: Env) -> bool {
+        storage::require_registered(&env)
+    }
+
+    pub fn get_wager_limits(env: Env) -> WagerLimits {
+        storage::wager_limits(&env)
+    }
+
+    /// One-off migration after upgrading: index the sessions of challenges accepted
+    /// before `resolve_battle` looked them up by session. Paged to stay within the
+    /// instruction budget; returns how many were indexed.
+    pub fn index_challenge_sessions(env: Env, start_id: u32, count: u32) -> u32 {
         let admin: Address = Self::get_admin(env.clone());
         admin.require_auth();
-        env.storage()
-            .instance()
-            .set(&DataKey::TokenContractAddress, &token_contract);
+        challenge::index_challenge_sessions(&env, start_id, count)
     }
 
-    pub fn get_token_contract(env: Env) -> Option<Address> {
-        env.storage().instance().get(&DataKey::TokenContractAddress)
+    /// After an upgrade that changes the storage layout, rewrite up to `batch_size`
+    /// old entries. Call until `version` reaches `latest_version`.
+    pub fn migrate(env: Env, batch_size: u32) -> MigrationStatus {
+        let admin: Address = Self::get_admin(env.clone());
+        admin.require_auth();
+        migration::migrate(&env, batch_size)
     }
 
-    pub fn get_csh_balance(env: Env, player: Address) -> i128 {
-        let Some(token_addr) = Self::get_token_contract(env.clone()) else {
-            return 0;
-        };
-        let token = ClashTokenClient::new(&env, &token_addr);
-        token.balance(&player)
+    pub fn get_migration_status(env: Env) -> MigrationStatus {
+        migration::status(&env)
     }
 
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
         let admin: Address = Self::get_admin(env.clone());
         admin.require_auth();
+        Self::record_build_info(&env, Some(new_wasm_hash.clone()));
         env.deployer().update_current_contract_wasm(new_wasm_hash);
     }
 
-    fn mint_csh_reward(env: &Env, winner: Address) {
-        let Some(token_addr) = env
+    /// Everything a frontend needs to decide whether to show a maintenance banner,
+    /// in one call
+    pub fn status(env: Env) -> ContractStatus {
+        let circuit_version = storage::circuit_version(&env);
+        let season = storage::season(&env);
+        let now = env.ledger().timestamp();
+        ContractStatus {
+            hub: storage::game_hub(&env),
+            hub_last_ok_at: storage::hub_last_ok_at(&env),
+            circuit_version,
+            verifier_ready: storage::verifier(&env, circuit_version).is_ok(),
+            commit_mode: storage::game_config(&env).commit_mode,
+            rules_version: storage::rule_set(&env).version,
+            season_id: season.as_ref().map_or(0, |s| s.season_id),
+            season_running: season.is_some_and(|s| !s.has_ended && now <= s.ends_at),
+            pause: storage::pause_flags(&env),
+        }
+    }
+
+    /// API semver of the running contract plus the build metadata recorded at deploy/upgrade time,
+    /// so clients can feature-detect before calling newer entrypoints.
+    pub fn version(env: Env) -> ContractVersion {
+        let build = env
             .storage()
             .instance()
-            .get::<DataKey, Address>(&DataKey::TokenContractAddress)
-        else {
-            return;
+            .get(&DataKey::BuildInfo)
+            .unwrap_or(BuildInfo {
+                wasm_hash: None,
+                installed_ledger: 0,
+                installed_at: 0,
+            });
+
+        ContractVersion {
+            version: String::from_str(&env, CONTRACT_VERSION),
+            build,
+        }
+    }
+
+    fn record_build_info(env: &Env, wasm_hash: Option<BytesN<32>>) {
+        let build = BuildInfo {
+            wasm_hash,
+            installed_ledger: env.ledger().sequence(),
+            installed_at: env.ledger().timestamp(),
         };
-        let token = ClashTokenClient::new(env, &token_addr);
-        token.mint(&winner, &CSH_REWARD_PER_WIN);
+        env.storage().instance().set(&DataKey::BuildInfo, &build);
     }
 }
 
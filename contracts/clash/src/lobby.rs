@@ -0,0 +1,98 @@
+//! Wager-tier lobbies: players queue at a fixed stake and are paired first come,
+//! first served, except that shadowed players (see `moderation`) are only paired
+//! with each other. Waiting counts per tier are public so the frontend can steer players
+//! towards busy tiers.
+
+use soroban_sdk::{vec, Address, Env, Vec};
+
+use crate::storage;
+use crate::{game, moderation, Error, GameMode, QueueTier};
+
+/// One point, in the Game Hub's 7-decimal units
+const POINT: i128 = 10_000_000;
+
+/// Stake per player for each tier, indexed by tier number
+const WAGER_TIERS: [i128; 3] = [10 * POINT, 100 * POINT, 1_000 * POINT];
+
+/// Join the queue for `tier`. If someone is already waiting there, the two are paired
+/// straight away and the new session id is returned; otherwise the player waits.
+pub(crate) fn join_queue(env: &Env, player: Address, tier: u32) -> Result<Option<u32>, Error> {
+    player.require_auth();
+
+    let wager = *WAGER_TIERS.get(tier as usize).ok_or(Error::InvalidTier)?;
+    if storage::queued_tier(env, &player).is_some() {
+        return Err(Error::AlreadyQueued);
+    }
+    game::validate_wager(env, wager)?;
+
+    // Shadowed players are only ever paired with each other
+    let mut waiting = storage::queue(env, tier);
+    let shadowed = moderation::is_shadowed(env, &player);
+    let Some(index) = waiting
+        .iter()
+        .position(|queued| moderation::is_shadowed(env, &queued) == shadowed)
+    else {
+        wait(env, tier, &mut waiting, player);
+        return Ok(None);
+    };
+    let opponent = waiting.get_unchecked(index as u32);
+    waiting.remove(index as u32);
+    storage::set_queued_tier(env, &opponent, None);
+
+    // The waiting player authorized this stake when they joined. If the game can't
+    // start with them any more (they've since left the Game Hub, or can't cover the
+    // stake), they're dropped from the queue rather than blocking the tier, and the
+    // new player waits in their place.
+    let session_id = game::allocate_session_id(env);
+    let started = game::start_game_after_auth(
+        env,
+        session_id,
+        opponent,
+        player.clone(),
+        wager,
+        wager,
+        GameMode::Classic,
+    );
+    if started.is_err() {
+        wait(env, tier, &mut waiting, player);
+        return Ok(None);
+    }
+    storage::save_queue(env, tier, &waiting);
+
+    Ok(Some(session_id))
+}
+
+fn wait(env: &Env, tier: u32, waiting: &mut Vec<Address>, player: Address) {
+    waiting.push_back(player.clone());
+    storage::save_queue(env, tier, waiting);
+    storage::set_queued_tier(env, &player, Some(tier));
+}
+
+/// Stop waiting
+pub(crate) fn leave_queue(env: &Env, player: Address) -> Result<(), Error> {
+    player.require_auth();
+
+    let tier = storage::queued_tier(env, &player).ok_or(Error::NotQueued)?;
+    let mut waiting = storage::queue(env, tier);
+    if let Some(index) = waiting.first_index_of(&player) {
+        waiting.remove(index);
+    }
+    storage::save_queue(env, tier, &waiting);
+    storage::set_queued_tier(env, &player, None);
+
+    Ok(())
+}
+
+/// Stake and number of waiting players for every tier
+pub(crate) fn get_queue_stats(env: &Env) -> Vec<QueueTier> {
+    let mut stats = vec![env];
+    for (tier, wager) in WAGER_TIERS.iter().enumerate() {
+        let tier = tier as u32;
+        stats.push_back(QueueTier {
+            tier,
+            wager: *wager,
+            waiting: storage::queue(env, tier).len(),
+        });
+    }
+    stats
+}
@@ -0,0 +1,280 @@
+//! Storage schema versions. `upgrade` swaps the WASM but leaves stored entries as
+//! they were written, so a release that changes a stored layout bumps
+//! `STORAGE_VERSION` and adds a step to `migrate` that rewrites the old entries.
+//! After upgrading, the admin calls `migrate(batch_size)` until it reports the
+//! latest version; each call handles at most `batch_size` entries, so large tables
+//! are spread over several transactions.
+//!
+//! Until its step has run, reads of a changed layout must handle both: the old
+//! layout is kept here as its own struct, and storage reads pick the layout from the
+//! entry and upgrade an old one as they read it. A new index falls back to the data
+//! it is built from (see `challenge::mark_challenge_completed`). Contracts deployed
+//! before versioning report version 0; new deployments start on the latest.
+//!
+//! Version 0 is the original layout of games and challenges. Its step rewrites every
+//! challenge; games can't be listed, so an old game is upgraded on each read and
+//! stored in the new layout the next time it is saved.
+
+use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, FromVal, Map, Symbol, Val, Vec};
+
+use crate::challenge;
+use crate::game;
+use crate::randomness;
+use crate::storage;
+use crate::{
+    BattleResult, CaptainClass, Challenge, ChallengeStatus, CommitMode, Game, GameMode, MigrationStatus,
+    OutcomeReason, PlayerCommitment, Raise, RaiseStatus, RuleSet, StorageMigrated, TurnResult,
+    DEFAULT_CRIT_MULTIPLIER_PCT,
+};
+
+/// Layout this WASM writes
+pub(crate) const STORAGE_VERSION: u32 = 1;
+
+/// Run up to `batch_size` entries of the next pending step. A finished step moves
+/// the stored version on by one; the next call starts the step after it.
+pub(crate) fn migrate(env: &Env, batch_size: u32) -> MigrationStatus {
+    let version = storage::storage_version(env);
+    if version >= STORAGE_VERSION {
+        return status(env);
+    }
+
+    let start = storage::migration_cursor(env);
+    let cursor = start.saturating_add(batch_size);
+    let finished = match version {
+        // 0 -> 1: rewrite challenges in the current layout and index the accepted
+        // ones by session
+        0 => {
+            if storage::migration_started_at(env).is_none() {
+                storage::save_migration_started_at(env, env.ledger().timestamp());
+            }
+            rewrite_challenges(env, start, batch_size);
+            challenge::index_challenge_sessions(env, start, batch_size);
+            cursor >= storage::challenge_counter(env)
+        }
+        _ => true,
+    };
+
+    if finished {
+        storage::save_storage_version(env, version + 1);
+        storage::save_migration_cursor(env, 0);
+        StorageMigrated { version: version + 1 }.publish(env);
+    } else {
+        storage::save_migration_cursor(env, cursor);
+    }
+    status(env)
+}
+
+pub(crate) fn status(env: &Env) -> MigrationStatus {
+    MigrationStatus {
+        version: storage::storage_version(env),
+        latest_version: STORAGE_VERSION,
+        cursor: storage::migration_cursor(env),
+    }
+}
+
+// ============================================================================
+// Version 0 layouts
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct ChallengeV0 {
+    pub challenge_id: u32,
+    pub challenger: Address,
+    pub challenged: Address,
+    pub points_wagered: i128,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub is_accepted: bool,
+    pub is_completed: bool,
+    pub session_id: Option<u32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct GameV0 {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub has_player1_commitment: bool,
+    pub player1_commitment: PlayerCommitment,
+    pub has_player2_commitment: bool,
+    pub player2_commitment: PlayerCommitment,
+    pub has_battle_result: bool,
+    pub battle_result: BattleResultV0,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct BattleResultV0 {
+    pub player1_hp: i32,
+    pub player2_hp: i32,
+    pub winner: Option<Address>,
+    pub is_draw: bool,
+    pub turn_results: Vec<TurnResultV0>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct TurnResultV0 {
+    pub turn: u32,
+    pub player1_damage_dealt: i32,
+    pub player2_damage_dealt: i32,
+    pub player1_hp_remaining: i32,
+    pub player2_hp_remaining: i32,
+    pub player1_defense_successful: bool,
+    pub player2_defense_successful: bool,
+}
+
+/// A stored challenge in the current layout. Once the step from version 0 has run
+/// every challenge is on it; before that, one without the newer fields is upgraded.
+pub(crate) fn read_challenge(env: &Env, stored: Val) -> Challenge {
+    if storage::storage_version(env) == 0 && lacks_field(env, &stored, symbol_short!("nonce")) {
+        return upgrade_challenge(ChallengeV0::from_val(env, &stored));
+    }
+    Challenge::from_val(env, &stored)
+}
+
+/// A stored game in the current layout, upgrading one stored before versioning
+pub(crate) fn read_game(env: &Env, stored: Val) -> Game {
+    if lacks_field(env, &stored, symbol_short!("mode")) {
+        return upgrade_game(env, GameV0::from_val(env, &stored));
+    }
+    Game::from_val(env, &stored)
+}
+
+/// Struct entries are maps keyed by field name, so a field added since tells the
+/// layouts apart
+fn lacks_field(env: &Env, stored: &Val, field: Symbol) -> bool {
+    !Map::<Symbol, Val>::from_val(env, stored).contains_key(field)
+}
+
+fn rewrite_challenges(env: &Env, start_id: u32, count: u32) {
+    let end = start_id
+        .saturating_add(count)
+        .min(storage::challenge_counter(env));
+    for challenge_id in start_id..end {
+        // Reading upgrades an old entry; writing it back stores the new layout
+        if let Ok(challenge) = storage::load_challenge(env, challenge_id) {
+            storage::rewrite_challenge(env, &challenge);
+        }
+    }
+}
+
+fn upgrade_challenge(old: ChallengeV0) -> Challenge {
+    let status = if old.is_completed {
+        ChallengeStatus::Completed
+    } else if old.is_accepted {
+        ChallengeStatus::Accepted
+    } else {
+        ChallengeStatus::Pending
+    };
+    Challenge {
+        challenge_id: old.challenge_id,
+        challenger: old.challenger,
+        challenged: old.challenged,
+        points_wagered: old.points_wagered,
+        created_at: old.created_at,
+        expires_at: old.expires_at,
+        is_accepted: old.is_accepted,
+        is_completed: old.is_completed,
+        session_id: old.session_id,
+        forwarded_from: None,
+        status,
+        is_rival_match: false,
+        created_ledger: 0,
+        nonce: 0,
+        tie_break: RuleSet::DEFAULT.tie_break,
+    }
+}
+
+/// Version 0 games were classic ZK games under the built-in rules, settled with the
+/// hub as soon as they resolved. Their start time wasn't stored, so the migration's
+/// start stands in for it, and for the opening of a reveal window already running.
+fn upgrade_game(env: &Env, old: GameV0) -> Game {
+    let epoch = storage::migration_started_at(env).unwrap_or(env.ledger().timestamp());
+    let both_committed = old.has_player1_commitment && old.has_player2_commitment;
+    let result = old.battle_result;
+    let reason = if !old.has_battle_result {
+        OutcomeReason::Pending
+    } else if result.is_draw {
+        OutcomeReason::Draw
+    } else {
+        OutcomeReason::BattleDecision
+    };
+    let mut turn_results = vec![env];
+    for turn in result.turn_results.iter() {
+        turn_results.push_back(TurnResult {
+            turn: turn.turn,
+            player1_damage_dealt: turn.player1_damage_dealt,
+            player2_damage_dealt: turn.player2_damage_dealt,
+            player1_hp_remaining: turn.player1_hp_remaining,
+            player2_hp_remaining: turn.player2_hp_remaining,
+            player1_defense_successful: turn.player1_defense_successful,
+            player2_defense_successful: turn.player2_defense_successful,
+            player1_momentum: false,
+            player2_momentum: false,
+            player1_was_critical: false,
+            player2_was_critical: false,
+            player1_reflected: 0,
+            player2_reflected: 0,
+        });
+    }
+
+    Game {
+        player1_username: storage::username(env, &old.player1),
+        player2_username: storage::username(env, &old.player2),
+        player1: old.player1,
+        player2: old.player2,
+        player1_points: old.player1_points,
+        player2_points: old.player2_points,
+        mode: GameMode::Classic,
+        has_player1_commitment: old.has_player1_commitment,
+        player1_commitment: old.player1_commitment,
+        has_player2_commitment: old.has_player2_commitment,
+        player2_commitment: old.player2_commitment,
+        has_battle_result: old.has_battle_result,
+        battle_result: BattleResult {
+            player1_hp: result.player1_hp,
+            player2_hp: result.player2_hp,
+            winner: result.winner,
+            is_draw: result.is_draw,
+            turn_results,
+            entropy: randomness::none(env),
+            went_to_overtime: false,
+            overtime_turns: vec![env],
+            reason,
+        },
+        is_settled: old.has_battle_result,
+        commit_deadline: 0,
+        reveal_deadline: if both_committed && !old.has_battle_result {
+            epoch + game::reveal_window(env, GameMode::Classic)
+        } else {
+            0
+        },
+        deposit_token: None,
+        deposit_amount: 0,
+        initiative_bonus: 0,
+        rules: RuleSet::DEFAULT,
+        crit_chance_bps: 0,
+        crit_multiplier_pct: DEFAULT_CRIT_MULTIPLIER_PCT,
+        raise: Raise {
+            token: None,
+            max_amount: 0,
+            status: RaiseStatus::None,
+            raiser: None,
+            amount: 0,
+            fee_schedule: vec![env],
+        },
+        commit_mode: CommitMode::Zk,
+        circuit_version: 1,
+        started_at: epoch,
+        resolved_at: if old.has_battle_result { epoch } else { 0 },
+        is_cancelled: false,
+        player1_coach: None,
+        player2_coach: None,
+        player1_class: CaptainClass::None,
+        player2_class: CaptainClass::None,
+    }
+}
@@ -0,0 +1,55 @@
+//! Shadow flags for suspected cheaters, set by the moderator (the admin until one is
+//! named) while an investigation runs. A shadowed player can still play, but nothing
+//! tells them they are restricted:
+//!
+//! - their games are never rated, so they drop off the lifetime and season ladders
+//!   and stay off them,
+//! - the wager-tier lobby only pairs them with other shadowed players,
+//! - their open challenges are left off the public board.
+//!
+//! The flag follows the player's identity, so a linked address is shadowed with its
+//! primary. No event is emitted for it, and only the moderator can read it back.
+
+use soroban_sdk::{Address, Env};
+
+use crate::identity;
+use crate::storage;
+
+pub(crate) fn is_shadowed(env: &Env, player: &Address) -> bool {
+    storage::shadowed(env, &identity::primary(env, player))
+}
+
+/// `is_shadowed` for the moderator's own tools; anyone else could use it to tell a
+/// player they are being watched
+pub(crate) fn get_shadowed(env: &Env, player: &Address) -> bool {
+    moderator(env).require_auth();
+    is_shadowed(env, player)
+}
+
+pub(crate) fn set_shadowed(env: &Env, player: Address, shadowed: bool) {
+    moderator(env).require_auth();
+
+    let player = identity::primary(env, &player);
+    storage::save_shadowed(env, &player, shadowed);
+    if !shadowed {
+        return;
+    }
+
+    let mut leaderboard = storage::leaderboard(env);
+    if let Some(index) = leaderboard.iter().position(|entry| entry.player == player) {
+        leaderboard.remove(index as u32);
+        storage::save_leaderboard(env, &leaderboard);
+    }
+    let Some(season) = storage::season(env).filter(|season| !season.has_ended) else {
+        return;
+    };
+    let mut leaderboard = storage::season_leaderboard(env, season.season_id);
+    if let Some(index) = leaderboard.iter().position(|entry| entry.player == player) {
+        leaderboard.remove(index as u32);
+        storage::save_season_leaderboard(env, season.season_id, &leaderboard);
+    }
+}
+
+fn moderator(env: &Env) -> Address {
+    storage::moderator(env).unwrap_or_else(|| storage::admin(env))
+}
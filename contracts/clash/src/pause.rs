@@ -0,0 +1,100 @@
+//! Circuit breakers for incidents such as a verifier exploit or a Game Hub outage.
+//!
+//! The admin can pause everything at once, or one area at a time: new games
+//! (challenges, queues, invites, tournaments and direct starts), commits (commits,
+//! reveals and raises) or resolution (resolving, forfeits, cancellations and
+//! deadline claims). Paused entrypoints fail with `ContractPaused`; views, claims of
+//! what is already owed and the admin's own calls keep working.
+//!
+//! Deadline claims are stopped by a commit pause as well as a resolution pause, so a
+//! player blocked from moving can't lose on time. A deadline that falls while moves
+//! are paused (`all` or `commits`) is pushed to `GRACE_SECS` after the pause lifts,
+//! so nobody is timed out the moment play resumes.
+
+use soroban_sdk::{Env, Vec};
+
+use crate::storage;
+use crate::{Error, PauseFlags};
+
+/// Time players get to move after a pause that stopped moves is lifted
+pub(crate) const GRACE_SECS: u64 = 3600;
+
+/// Most recent move pauses kept for deadline checks
+const MAX_MOVE_PAUSES: u32 = 10;
+
+/// Area of play a pause flag covers
+pub(crate) enum Breaker {
+    NewGames,
+    Commits,
+    Resolution,
+}
+
+/// Fail with `ContractPaused` if `breaker`'s area, or the whole contract, is paused
+pub(crate) fn check(env: &Env, breaker: Breaker) -> Result<(), Error> {
+    let flags = storage::pause_flags(env);
+    let paused = flags.all
+        || match breaker {
+            Breaker::NewGames => flags.new_games,
+            Breaker::Commits => flags.commits,
+            Breaker::Resolution => flags.resolution,
+        };
+    if paused {
+        return Err(Error::ContractPaused);
+    }
+    Ok(())
+}
+
+/// A deadline claim: a player who can't move mustn't lose on time
+pub(crate) fn check_deadline_claim(env: &Env) -> Result<(), Error> {
+    check(env, Breaker::Commits)?;
+    check(env, Breaker::Resolution)
+}
+
+/// Replace the flags, recording when moves stop and start again
+pub(crate) fn set(env: &Env, flags: PauseFlags) {
+    let was_stopped = stops_moves(&storage::pause_flags(env));
+    let now = env.ledger().timestamp();
+    let mut pauses = storage::move_pauses(env);
+    match (was_stopped, stops_moves(&flags)) {
+        (false, true) => {
+            if pauses.len() >= MAX_MOVE_PAUSES {
+                pauses.pop_front();
+            }
+            pauses.push_back((now, 0));
+            storage::save_move_pauses(env, &pauses);
+        }
+        (true, false) => {
+            if let Some((start, _)) = pauses.last() {
+                pauses.set(pauses.len() - 1, (start, now.max(start + 1)));
+                storage::save_move_pauses(env, &pauses);
+            }
+        }
+        _ => {}
+    }
+    storage::save_pause_flags(env, &flags);
+}
+
+/// `deadline` as it applies: pushed past the grace window of any move pause it fell
+/// in, or never passed while such a pause is still on
+pub(crate) fn deadline(env: &Env, deadline: u64) -> u64 {
+    effective_deadline(&storage::move_pauses(env), deadline)
+}
+
+fn effective_deadline(pauses: &Vec<(u64, u64)>, mut deadline: u64) -> u64 {
+    for (start, end) in pauses.iter() {
+        if deadline < start {
+            continue;
+        }
+        if end == 0 {
+            return u64::MAX;
+        }
+        if deadline < end + GRACE_SECS {
+            deadline = end + GRACE_SECS;
+        }
+    }
+    deadline
+}
+
+fn stops_moves(flags: &PauseFlags) -> bool {
+    flags.all || flags.commits
+}
@@ -0,0 +1,74 @@
+//! Free spectator picks: anyone but the two players can call the winner of a game,
+//! at no stake, until the second reveal lands. When the game resolves every pick is
+//! scored into the spectator's `PickStats`, and runs of correct picks earn the
+//! `SharpEye` and `Oracle` badges.
+//!
+//! A draw scores nobody and leaves streaks as they were; voided games are never
+//! scored. Each game takes a limited number of picks, which keeps scoring within the
+//! resolve budget.
+
+use soroban_sdk::{vec, Address, Env};
+
+use crate::achievements;
+use crate::storage;
+use crate::{Error, SpectatorPick};
+
+/// Most picks one game takes
+pub(crate) const MAX_PICKS_PER_GAME: u32 = 30;
+
+pub(crate) fn make_pick(env: &Env, session_id: u32, spectator: Address, backed_player: Address) -> Result<(), Error> {
+    spectator.require_auth();
+
+    let game = storage::load_game(env, session_id)?;
+    let both_revealed = game.player1_commitment.has_revealed && game.player2_commitment.has_revealed;
+    if game.has_battle_result || both_revealed {
+        return Err(Error::PredictionsClosed);
+    }
+    if spectator == game.player1 || spectator == game.player2 {
+        return Err(Error::InvalidPrediction);
+    }
+    if backed_player != game.player1 && backed_player != game.player2 {
+        return Err(Error::InvalidPrediction);
+    }
+
+    let mut picks = storage::picks(env, session_id);
+    if picks.iter().any(|pick| pick.spectator == spectator) {
+        return Err(Error::InvalidPrediction);
+    }
+    if picks.len() >= MAX_PICKS_PER_GAME {
+        return Err(Error::PicksFull);
+    }
+    picks.push_back(SpectatorPick {
+        spectator,
+        backed_player,
+    });
+    storage::save_picks(env, session_id, &picks);
+    Ok(())
+}
+
+/// Score the game's picks against its winner, once it settles. With no winner (a
+/// draw, or a voided game) the picks are dropped unscored.
+pub(crate) fn score(env: &Env, session_id: u32, winner: Option<&Address>) {
+    let picks = storage::picks(env, session_id);
+    if picks.is_empty() {
+        return;
+    }
+    storage::save_picks(env, session_id, &vec![env]);
+    let Some(winner) = winner else {
+        return;
+    };
+
+    for pick in picks.iter() {
+        let mut stats = storage::pick_stats(env, &pick.spectator);
+        stats.picks += 1;
+        if pick.backed_player == *winner {
+            stats.correct += 1;
+            stats.streak += 1;
+            stats.best_streak = stats.best_streak.max(stats.streak);
+            achievements::record_pick_streak(env, session_id, &pick.spectator, stats.streak);
+        } else {
+            stats.streak = 0;
+        }
+        storage::save_pick_stats(env, &pick.spectator, &stats);
+    }
+}
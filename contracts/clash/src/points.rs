@@ -0,0 +1,38 @@
+//! Bonus points: Clash runs the earning mechanics and keeps a local balance per
+//! player, and `settle_points_to_hub` moves them onto the Game Hub's ledger, which
+//! stays the one source of truth for points. A player's first win of each day earns
+//! `DAILY_WIN_POINTS`.
+
+use soroban_sdk::{Address, Env};
+
+use crate::storage;
+use crate::{Error, GameHubClient, PointsSettled};
+
+/// Bonus points for a player's first win of each day (7 decimals)
+pub(crate) const DAILY_WIN_POINTS: i128 = 10_0000000;
+
+pub(crate) fn credit(env: &Env, player: &Address, amount: i128) {
+    let balance = storage::bonus_points(env, player);
+    storage::save_bonus_points(env, player, balance + amount);
+}
+
+/// Take `amount` off the local balance and add it to the player's hub points
+pub(crate) fn settle_to_hub(env: &Env, player: Address, amount: i128) -> Result<i128, Error> {
+    player.require_auth();
+
+    let balance = storage::bonus_points(env, &player);
+    if amount <= 0 || amount > balance {
+        return Err(Error::InsufficientPoints);
+    }
+    // Debited before the hub call so a re-entrant settle finds the new balance
+    let remaining = balance - amount;
+    storage::save_bonus_points(env, &player, remaining);
+
+    GameHubClient::new(env, &storage::game_hub(env)).add_points(
+        &env.current_contract_address(),
+        &player,
+        &amount,
+    );
+    PointsSettled { player, amount }.publish(env);
+    Ok(remaining)
+}
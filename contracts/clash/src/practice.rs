@@ -0,0 +1,177 @@
+//! Practice games against an on-chain bot, for learning the combat system without
+//! risking points. Practice never touches the Game Hub, takes no wager and pays no
+//! reward. Results count towards practice stats kept per difficulty, never towards
+//! ratings, so the tiers work as a ladder to climb before playing for points.
+//!
+//! The bot's moves are drawn from the ledger PRNG only once the player submits
+//! theirs, so they can't be read beforehand. The difficulty biases them:
+//! - Easy (predictable): one random move, repeated every turn
+//! - Normal (balanced): a random attack and defense every turn
+//! - Hard (counter-prone): the attack whose full combo hits hardest under the game's
+//!   rules, repeated, behind defenses drawn towards whatever stops the attacks
+//!   players throw most (from the global attack counts)
+
+use soroban_sdk::{vec, Address, Env, Vec};
+
+use crate::combat;
+use crate::storage;
+use crate::{
+    game, randomness, Attack, BattleResult, Defense, Error, Move, OutcomeReason, PracticeDifficulty,
+    PracticeGame, TURNS_PER_BATTLE,
+};
+
+const ATTACKS: [Attack; 5] = [
+    Attack::Slash,
+    Attack::Fireball,
+    Attack::Lightning,
+    Attack::Broadside,
+    Attack::Hook,
+];
+
+const DEFENSES: [Defense; 4] = [Defense::Block, Defense::Dodge, Defense::Counter, Defense::Riposte];
+
+/// Open a practice game under the current rules. Returns its practice id.
+pub(crate) fn start_practice_game(
+    env: &Env,
+    player: Address,
+    difficulty: PracticeDifficulty,
+) -> u32 {
+    player.require_auth();
+
+    let rules = storage::rule_set(env);
+    let practice_id = storage::next_practice_id(env);
+    let practice = PracticeGame {
+        player,
+        difficulty,
+        started_at: env.ledger().timestamp(),
+        has_battle_result: false,
+        player_moves: vec![env],
+        bot_moves: vec![env],
+        battle_result: BattleResult {
+            player1_hp: rules.starting_hp,
+            player2_hp: rules.starting_hp,
+            winner: None,
+            is_draw: false,
+            turn_results: vec![env],
+            entropy: randomness::none(env),
+            went_to_overtime: false,
+            overtime_turns: vec![env],
+            reason: OutcomeReason::Pending,
+        },
+        rules,
+    };
+    storage::store_new_practice_game(env, practice_id, &practice);
+    practice_id
+}
+
+/// Submit the player's moves, draw the bot's and play the battle. The bot is
+/// `player2` of the result, as the contract's own address.
+pub(crate) fn resolve_practice_battle(
+    env: &Env,
+    practice_id: u32,
+    player: Address,
+    moves: Vec<Move>,
+) -> Result<BattleResult, Error> {
+    player.require_auth();
+
+    let mut practice = storage::practice_game(env, practice_id).ok_or(Error::GameNotFound)?;
+    if practice.player != player {
+        return Err(Error::NotPlayer);
+    }
+    if practice.has_battle_result {
+        return Err(Error::GameAlreadyEnded);
+    }
+    if moves.len() != TURNS_PER_BATTLE {
+        return Err(Error::InvalidMoveSequence);
+    }
+
+    practice.player_moves = moves;
+    practice.bot_moves = bot_moves(env, &practice);
+    practice.battle_result = game::simulate_practice_battle(env, &practice);
+    practice.has_battle_result = true;
+    storage::save_practice_game(env, practice_id, &practice);
+
+    let mut stats = storage::practice_stats(env, &player, practice.difficulty);
+    match &practice.battle_result.winner {
+        None => stats.draws += 1,
+        Some(winner) if *winner == player => stats.wins += 1,
+        Some(_) => stats.losses += 1,
+    }
+    storage::save_practice_stats(env, &player, practice.difficulty, &stats);
+
+    Ok(practice.battle_result)
+}
+
+fn bot_moves(env: &Env, practice: &PracticeGame) -> Vec<Move> {
+    let mut moves = vec![env];
+    match practice.difficulty {
+        PracticeDifficulty::Easy => {
+            let repeated = Move {
+                attack: pick(env, &ATTACKS),
+                defense: pick(env, &DEFENSES),
+            };
+            for _ in 0..TURNS_PER_BATTLE {
+                moves.push_back(repeated.clone());
+            }
+        }
+        PracticeDifficulty::Normal => {
+            for _ in 0..TURNS_PER_BATTLE {
+                moves.push_back(Move {
+                    attack: pick(env, &ATTACKS),
+                    defense: pick(env, &DEFENSES),
+                });
+            }
+        }
+        PracticeDifficulty::Hard => {
+            let attack = strongest_combo(practice);
+            for _ in 0..TURNS_PER_BATTLE {
+                moves.push_back(Move {
+                    attack,
+                    defense: combat::blocked_by(likely_attack(env)),
+                });
+            }
+        }
+    }
+    moves
+}
+
+/// An attack drawn in proportion to how often players throw it. Every attack gets
+/// one extra count, so none is ever ruled out.
+fn likely_attack(env: &Env) -> Attack {
+    let stats = storage::global_stats(env);
+    let weights = [
+        stats.slash_count,
+        stats.fireball_count,
+        stats.lightning_count,
+        stats.broadside_count,
+        stats.hook_count,
+    ]
+    .map(|count| count + 1);
+    let mut roll = env.prng().gen_range::<u64>(0..weights.iter().sum());
+    for (attack, weight) in ATTACKS.into_iter().zip(weights) {
+        if roll < weight {
+            return attack;
+        }
+        roll -= weight;
+    }
+    unreachable!()
+}
+
+/// Attack dealing the most over a full sequence of itself. Broadside never combos.
+fn strongest_combo(practice: &PracticeGame) -> Attack {
+    let rules = &practice.rules;
+    ATTACKS
+        .into_iter()
+        .max_by_key(|&attack| {
+            let combo = match attack {
+                Attack::Broadside => 0,
+                _ => rules.combo_2_bonus + rules.combo_3_bonus,
+            };
+            combat::base_damage(rules, attack) * TURNS_PER_BATTLE as i32 + combo
+        })
+        .unwrap()
+}
+
+fn pick<T: Copy, const N: usize>(env: &Env, options: &[T; N]) -> T {
+    options[env.prng().gen_range::<u64>(0..N as u64) as usize]
+}
@@ -0,0 +1,171 @@
+//! Spectator predictions: third parties back one of the players of a game before
+//! either commit lands, staking the admin's prediction token.
+//!
+//! The Game Hub only holds the players' own stakes, so prediction stakes are escrowed
+//! here. Settling the game records the outcome on the pool, and each predictor then
+//! claims their share:
+//! - backed the winner: their stake plus a pro-rata share of the losing side's pool
+//! - draw, or nobody backed the winner: their stake back
+//!
+//! Shares are rounded down, so a few units of dust can stay in the contract.
+
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, Env};
+
+use crate::storage;
+use crate::{Error, Game, Prediction, PredictionPool};
+
+/// Back `backed_player` with `amount` of the prediction token. Adding to an existing
+/// prediction is allowed, switching sides is not.
+pub(crate) fn place_prediction(
+    env: &Env,
+    session_id: u32,
+    predictor: Address,
+    backed_player: Address,
+    amount: i128,
+) -> Result<(), Error> {
+    predictor.require_auth();
+
+    let game = storage::load_game(env, session_id)?;
+    if !is_open(&game) {
+        return Err(Error::PredictionsClosed);
+    }
+    // Players can't bet on their own game
+    if predictor == game.player1 || predictor == game.player2 {
+        return Err(Error::InvalidPrediction);
+    }
+    let backs_player1 = backed_player == game.player1;
+    if (!backs_player1 && backed_player != game.player2) || amount <= 0 {
+        return Err(Error::InvalidPrediction);
+    }
+
+    let mut pool = match storage::prediction_pool(env, session_id) {
+        Some(pool) => pool,
+        None => PredictionPool {
+            session_id,
+            token: storage::prediction_token(env).ok_or(Error::InvalidPrediction)?,
+            player1: game.player1.clone(),
+            player2: game.player2.clone(),
+            player1_total: 0,
+            player2_total: 0,
+            is_settled: false,
+            winner: None,
+        },
+    };
+    let mut prediction = match storage::prediction(env, session_id, &predictor) {
+        Some(existing) if existing.backed_player != backed_player => {
+            return Err(Error::InvalidPrediction);
+        }
+        Some(existing) => existing,
+        None => Prediction {
+            backed_player,
+            amount: 0,
+        },
+    };
+
+    prediction.amount += amount;
+    if backs_player1 {
+        pool.player1_total += amount;
+    } else {
+        pool.player2_total += amount;
+    }
+    storage::save_prediction(env, session_id, &predictor, &prediction);
+    storage::save_prediction_pool(env, &pool);
+
+    TokenClient::new(env, &pool.token).transfer(&predictor, env.current_contract_address(), &amount);
+    Ok(())
+}
+
+/// Take a prediction back while the pool is still open, or once the game has
+/// expired without being settled. Returns the amount refunded.
+pub(crate) fn withdraw_prediction(
+    env: &Env,
+    session_id: u32,
+    predictor: Address,
+) -> Result<i128, Error> {
+    predictor.require_auth();
+
+    let still_open = match storage::load_game(env, session_id) {
+        Ok(game) => is_open(&game),
+        Err(_) => true,
+    };
+    let mut pool = storage::prediction_pool(env, session_id).ok_or(Error::NothingToClaim)?;
+    if !still_open || pool.is_settled {
+        return Err(Error::PredictionsClosed);
+    }
+    let prediction =
+        storage::prediction(env, session_id, &predictor).ok_or(Error::NothingToClaim)?;
+
+    if prediction.backed_player == pool.player1 {
+        pool.player1_total -= prediction.amount;
+    } else {
+        pool.player2_total -= prediction.amount;
+    }
+    storage::remove_prediction(env, session_id, &predictor);
+    storage::save_prediction_pool(env, &pool);
+
+    TokenClient::new(env, &pool.token).transfer(
+        &env.current_contract_address(),
+        &predictor,
+        &prediction.amount,
+    );
+    Ok(prediction.amount)
+}
+
+/// Collect a settled prediction. Returns the amount paid.
+pub(crate) fn claim_prediction(
+    env: &Env,
+    session_id: u32,
+    predictor: Address,
+) -> Result<i128, Error> {
+    predictor.require_auth();
+
+    let pool = storage::prediction_pool(env, session_id).ok_or(Error::NothingToClaim)?;
+    if !pool.is_settled {
+        return Err(Error::GameNotEnded);
+    }
+    let prediction =
+        storage::prediction(env, session_id, &predictor).ok_or(Error::NothingToClaim)?;
+    let payout = payout(&pool, &prediction);
+    if payout == 0 {
+        return Err(Error::NothingToClaim);
+    }
+    // Cleared before transferring so a re-entrant claim finds nothing
+    storage::remove_prediction(env, session_id, &predictor);
+
+    TokenClient::new(env, &pool.token).transfer(&env.current_contract_address(), &predictor, &payout);
+    Ok(payout)
+}
+
+/// Record the game's outcome on its pool, if it has one. Called from `game::settle`.
+pub(crate) fn settle(env: &Env, session_id: u32, winner: Option<Address>) {
+    if let Some(mut pool) = storage::prediction_pool(env, session_id) {
+        pool.is_settled = true;
+        pool.winner = winner;
+        storage::save_prediction_pool(env, &pool);
+    }
+}
+
+/// What a prediction pays on a settled pool
+fn payout(pool: &PredictionPool, prediction: &Prediction) -> i128 {
+    let Some(winner) = &pool.winner else {
+        return prediction.amount;
+    };
+    let (winning_total, losing_total) = if *winner == pool.player1 {
+        (pool.player1_total, pool.player2_total)
+    } else {
+        (pool.player2_total, pool.player1_total)
+    };
+    if winning_total == 0 {
+        prediction.amount
+    } else if prediction.backed_player == *winner {
+        prediction.amount + prediction.amount * losing_total / winning_total
+    } else {
+        0
+    }
+}
+
+/// Predictions close as soon as either player commits
+fn is_open(game: &Game) -> bool {
+    !game.has_battle_result && !game.has_player1_commitment && !game.has_player2_commitment
+}
@@ -0,0 +1,82 @@
+//! Admin-created promo codes.
+//!
+//! A code grants either fee-free games (the rake on a called raise is waived when
+//! the holder wins the pot) or bonus CSH, minted alongside the player's next commit
+//! deposit. Each code has a usage cap and each player can redeem a code once.
+
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage;
+use crate::{ClashTokenClient, Error, PromoCode, PromoReward};
+
+/// Register `code`; redeeming it grants `amount` of `reward`, up to `max_uses` times
+pub(crate) fn create_promo_code(
+    env: &Env,
+    code: Symbol,
+    reward: PromoReward,
+    amount: i128,
+    max_uses: u32,
+) -> Result<(), Error> {
+    if amount <= 0 || max_uses == 0 {
+        return Err(Error::InvalidPromoCode);
+    }
+    let promo = PromoCode {
+        reward,
+        amount,
+        max_uses,
+        uses: 0,
+    };
+    storage::save_promo_code(env, &code, &promo);
+    Ok(())
+}
+
+pub(crate) fn redeem_code(env: &Env, player: Address, code: Symbol) -> Result<(), Error> {
+    player.require_auth();
+
+    let mut promo = storage::promo_code(env, &code).ok_or(Error::InvalidPromoCode)?;
+    if storage::promo_redeemed(env, &code, &player) {
+        return Err(Error::PromoAlreadyRedeemed);
+    }
+    if promo.uses >= promo.max_uses {
+        return Err(Error::InvalidPromoCode);
+    }
+
+    promo.uses += 1;
+    storage::save_promo_code(env, &code, &promo);
+    storage::set_promo_redeemed(env, &code, &player);
+
+    match promo.reward {
+        PromoReward::FeeWaiver => {
+            let games = u32::try_from(promo.amount).unwrap_or(u32::MAX);
+            let waivers = storage::fee_waivers(env, &player).saturating_add(games);
+            storage::save_fee_waivers(env, &player, waivers);
+        }
+        PromoReward::DepositBonus => {
+            let bonus = storage::deposit_bonus(env, &player) + promo.amount;
+            storage::save_deposit_bonus(env, &player, bonus);
+        }
+    }
+    Ok(())
+}
+
+/// Spend one of `player`'s fee waivers, if they have any
+pub(crate) fn use_fee_waiver(env: &Env, player: &Address) -> bool {
+    let waivers = storage::fee_waivers(env, player);
+    if waivers == 0 {
+        return false;
+    }
+    storage::save_fee_waivers(env, player, waivers - 1);
+    true
+}
+
+/// Mint any bonus CSH `player` is owed now that they've paid a deposit
+pub(crate) fn pay_deposit_bonus(env: &Env, player: &Address) {
+    let Some(token_addr) = storage::token_contract(env) else {
+        return;
+    };
+    let bonus = storage::deposit_bonus(env, player);
+    if bonus > 0 {
+        storage::save_deposit_bonus(env, player, 0);
+        ClashTokenClient::new(env, &token_addr).mint(player, &bonus);
+    }
+}
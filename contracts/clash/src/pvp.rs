@@ -0,0 +1,184 @@
+//! Invite-based PvP matches played one action at a time.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage;
+use crate::{Attack, Defense, Error, MatchState, Move, PvPMatch, STARTING_HP};
+
+pub(crate) fn create_invite(env: &Env, inviter: Address, opponent: Address) -> Result<u32, Error> {
+    inviter.require_auth();
+
+    if inviter == opponent {
+        return Err(Error::CannotChallengeSelf);
+    }
+
+    let match_id = storage::next_match_id(env);
+
+    let pvp_match = PvPMatch {
+        match_id,
+        player1: inviter.clone(),
+        player2: opponent.clone(),
+        state: MatchState::Created,
+        current_turn: 0,
+        player1_hp: STARTING_HP,
+        player2_hp: STARTING_HP,
+        has_last_action: false,
+        last_action: Move {
+            attack: Attack::Slash,
+            defense: Defense::Block,
+        },
+        winner: None,
+    };
+
+    storage::save_match(env, &pvp_match);
+
+    // Add to players' lists
+    storage::add_player_match(env, &inviter, match_id);
+    storage::add_player_match(env, &opponent, match_id);
+
+    Ok(match_id)
+}
+
+pub(crate) fn accept_invite(env: &Env, accepter: Address, match_id: u32) -> Result<(), Error> {
+    accepter.require_auth();
+
+    let mut pvp_match = storage::load_match(env, match_id)?;
+
+    if pvp_match.player2 != accepter || pvp_match.state != MatchState::Created {
+        return Err(Error::NotPlayer);
+    }
+
+    pvp_match.state = MatchState::Active;
+    storage::save_match(env, &pvp_match);
+
+    Ok(())
+}
+
+pub(crate) fn reject_invite(env: &Env, rejecter: Address, match_id: u32) -> Result<(), Error> {
+    rejecter.require_auth();
+
+    let mut pvp_match = storage::load_match(env, match_id)?;
+
+    if pvp_match.player2 != rejecter || pvp_match.state != MatchState::Created {
+        return Err(Error::NotPlayer);
+    }
+
+    pvp_match.state = MatchState::Finished;
+    storage::save_match(env, &pvp_match);
+
+    Ok(())
+}
+
+pub(crate) fn play_turn(env: &Env, player: Address, match_id: u32, action: Move) -> Result<(), Error> {
+    player.require_auth();
+
+    let mut pvp_match = storage::load_match(env, match_id)?;
+
+    if pvp_match.state != MatchState::Active {
+        return Err(Error::GameAlreadyEnded);
+    }
+
+    let is_player1 = pvp_match.player1 == player;
+    let is_player2 = pvp_match.player2 == player;
+    if !is_player1 && !is_player2 {
+        return Err(Error::NotPlayer);
+    }
+
+    let current_player_turn = pvp_match.current_turn.is_multiple_of(2);
+    if (current_player_turn && !is_player1) || (!current_player_turn && !is_player2) {
+        return Err(Error::NotPlayer); // not their turn
+    }
+
+    // Apply the action
+    if pvp_match.has_last_action {
+        // Resolve with last move
+        let (damage1, damage2) = resolve_turn(pvp_match.last_action.clone(), action);
+        pvp_match.player1_hp -= damage1;
+        pvp_match.player2_hp -= damage2;
+
+        if pvp_match.player1_hp <= 0 || pvp_match.player2_hp <= 0 {
+            pvp_match.state = MatchState::Finished;
+            if pvp_match.player1_hp <= 0 && pvp_match.player2_hp <= 0 {
+                pvp_match.winner = None; // draw
+            } else if pvp_match.player1_hp <= 0 {
+                pvp_match.winner = Some(pvp_match.player2.clone());
+            } else {
+                pvp_match.winner = Some(pvp_match.player1.clone());
+            }
+        } else {
+            pvp_match.current_turn += 1;
+        }
+        pvp_match.has_last_action = false;
+    } else {
+        pvp_match.last_action = action;
+        pvp_match.has_last_action = true;
+        pvp_match.current_turn += 1;
+    }
+
+    storage::save_match(env, &pvp_match);
+
+    Ok(())
+}
+
+fn resolve_turn(move1: Move, move2: Move) -> (i32, i32) {
+    let mut damage1 = 0;
+    let mut damage2 = 0;
+
+    // Simple resolution: if attack not defended, deal damage
+    if move1.attack == Attack::Slash && move2.defense != Defense::Dodge {
+        damage2 += 30;
+    }
+    if move1.attack == Attack::Fireball && move2.defense != Defense::Counter {
+        damage2 += 40;
+    }
+    if move1.attack == Attack::Lightning && move2.defense != Defense::Block {
+        damage2 += 35;
+    }
+    if move1.attack == Attack::Broadside && move2.defense != Defense::Dodge {
+        damage2 += 50;
+    }
+    if move1.attack == Attack::Hook && move2.defense != Defense::Riposte {
+        damage2 += 20;
+    }
+
+    if move2.attack == Attack::Slash && move1.defense != Defense::Dodge {
+        damage1 += 30;
+    }
+    if move2.attack == Attack::Fireball && move1.defense != Defense::Counter {
+        damage1 += 40;
+    }
+    if move2.attack == Attack::Lightning && move1.defense != Defense::Block {
+        damage1 += 35;
+    }
+    if move2.attack == Attack::Broadside && move1.defense != Defense::Dodge {
+        damage1 += 50;
+    }
+    if move2.attack == Attack::Hook && move1.defense != Defense::Riposte {
+        damage1 += 20;
+    }
+
+    (damage1, damage2)
+}
+
+pub(crate) fn end_match(env: &Env, player: Address, match_id: u32) -> Result<(), Error> {
+    player.require_auth();
+
+    let mut pvp_match = storage::load_match(env, match_id)?;
+
+    if pvp_match.player1 != player && pvp_match.player2 != player {
+        return Err(Error::NotPlayer);
+    }
+
+    if pvp_match.state == MatchState::Finished {
+        return Err(Error::GameAlreadyEnded);
+    }
+
+    pvp_match.state = MatchState::Finished;
+    storage::save_match(env, &pvp_match);
+
+    Ok(())
+}
+
+pub(crate) fn get_player_matches(env: &Env, player: Address) -> Vec<u32> {
+    storage::player_matches(env, &player)
+}
@@ -0,0 +1,170 @@
+//! Blind wager escalation between commit and reveal.
+//!
+//! Once both players have committed (and before anyone reveals), either player may
+//! raise once by up to the game's `max_amount`. The opponent then calls, matching
+//! the raise, or folds, conceding the game at the original Game Hub stake.
+//!
+//! The Game Hub only locks the stake given at start, so raises are escrowed in the
+//! raise token snapshotted onto the game and credited as claimable winnings by
+//! [`pay_out`] when the game settles:
+//! - pending or folded: the raiser gets their raise back
+//! - called: the winner takes both raises less the snapshotted rake (see
+//!   [`fees`]), unless they hold a promo fee waiver; on a draw each gets theirs back
+
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{vec, Address, Env};
+
+use crate::storage;
+use crate::{deposit, fees, game, promo, winnings, BattleResult, Error, Game, OutcomeReason, RaiseStatus};
+
+/// Raise the stake by `amount`, paid into escrow now
+pub(crate) fn raise_wager(
+    env: &Env,
+    session_id: u32,
+    player: Address,
+    amount: i128,
+) -> Result<(), Error> {
+    player.require_auth();
+
+    let mut game = storage::load_game(env, session_id)?;
+
+    if player != game.player1 && player != game.player2 {
+        return Err(Error::NotPlayer);
+    }
+    if game.has_battle_result {
+        return Err(Error::GameAlreadyEnded);
+    }
+    // Only blind: both moves locked in and neither shown yet
+    let both_committed = game.has_player1_commitment && game.has_player2_commitment;
+    let any_revealed =
+        game.player1_commitment.has_revealed || game.player2_commitment.has_revealed;
+    if !both_committed || any_revealed || game.raise.status != RaiseStatus::None {
+        return Err(Error::RaiseNotAllowed);
+    }
+    let Some(token) = game.raise.token.clone() else {
+        return Err(Error::InvalidRaise);
+    };
+    if amount <= 0 || amount > game.raise.max_amount {
+        return Err(Error::InvalidRaise);
+    }
+
+    game.raise.status = RaiseStatus::Pending;
+    game.raise.raiser = Some(player.clone());
+    game.raise.amount = amount;
+    storage::save_game(env, session_id, &game);
+
+    TokenClient::new(env, &token).transfer(&player, env.current_contract_address(), &amount);
+    Ok(())
+}
+
+/// Match a pending raise; the game then continues to reveal as normal
+pub(crate) fn call_raise(env: &Env, session_id: u32, player: Address) -> Result<(), Error> {
+    player.require_auth();
+
+    let mut game = storage::load_game(env, session_id)?;
+    check_responder(&game, &player)?;
+
+    game.raise.status = RaiseStatus::Called;
+    storage::save_game(env, session_id, &game);
+
+    if let Some(token) = &game.raise.token {
+        TokenClient::new(env, token).transfer(
+            &player,
+            env.current_contract_address(),
+            &game.raise.amount,
+        );
+    }
+    Ok(())
+}
+
+/// Decline a pending raise and concede at the original stake
+pub(crate) fn fold_raise(
+    env: &Env,
+    session_id: u32,
+    player: Address,
+) -> Result<BattleResult, Error> {
+    player.require_auth();
+
+    let mut game = storage::load_game(env, session_id)?;
+    check_responder(&game, &player)?;
+
+    game.raise.status = RaiseStatus::Folded;
+    game.battle_result = BattleResult {
+        player1_hp: game.rules.starting_hp,
+        player2_hp: game.rules.starting_hp,
+        winner: game.raise.raiser.clone(),
+        is_draw: false,
+        turn_results: vec![env],
+        entropy: game::battle_entropy(env, session_id, &game),
+        went_to_overtime: false,
+        overtime_turns: vec![env],
+        reason: OutcomeReason::Forfeit,
+    };
+    game.has_battle_result = true;
+    game.resolved_at = env.ledger().timestamp();
+    game.is_settled = true;
+    storage::save_game(env, session_id, &game);
+
+    // Folding is not a no-show: both commit deposits go back
+    deposit::award(env, session_id, &game, &game.player1);
+    deposit::award(env, session_id, &game, &game.player2);
+    game::settle(env, session_id, &game);
+
+    Ok(game.battle_result)
+}
+
+/// Credit escrowed raises for a game whose outcome is recorded. Called from
+/// `game::settle`, after `is_settled` has been stored.
+pub(crate) fn pay_out(env: &Env, session_id: u32, game: &Game) {
+    let (Some(token), Some(raiser)) = (&game.raise.token, &game.raise.raiser) else {
+        return;
+    };
+    let amount = game.raise.amount;
+
+    match (game.raise.status, &game.battle_result.winner) {
+        (RaiseStatus::None, _) => {}
+        (RaiseStatus::Pending | RaiseStatus::Folded, _) => {
+            winnings::credit(env, session_id, raiser, token, amount);
+        }
+        (RaiseStatus::Called, Some(winner)) => {
+            let mut fee = fees::quote(&game.raise.fee_schedule, amount);
+            if fee > 0 && promo::use_fee_waiver(env, winner) {
+                fee = 0;
+            }
+            fees::collect(env, token, fee);
+            winnings::credit(env, session_id, winner, token, amount * 2 - fee);
+        }
+        (RaiseStatus::Called, None) => {
+            winnings::credit(env, session_id, &game.player1, token, amount);
+            winnings::credit(env, session_id, &game.player2, token, amount);
+        }
+    }
+}
+
+/// What `player` has riding on `game`: their Game Hub stake, plus their side of the
+/// raise once it's called
+pub(crate) fn effective_wager(game: &Game, player: &Address) -> i128 {
+    let stake = if *player == game.player1 {
+        game.player1_points
+    } else {
+        game.player2_points
+    };
+    match game.raise.status {
+        RaiseStatus::Called => stake + game.raise.amount,
+        _ => stake,
+    }
+}
+
+/// Only the raiser's opponent can answer a pending raise
+fn check_responder(game: &Game, player: &Address) -> Result<(), Error> {
+    if *player != game.player1 && *player != game.player2 {
+        return Err(Error::NotPlayer);
+    }
+    if game.has_battle_result || game.raise.status != RaiseStatus::Pending {
+        return Err(Error::NoRaisePending);
+    }
+    if game.raise.raiser.as_ref() == Some(player) {
+        return Err(Error::NotPlayer);
+    }
+    Ok(())
+}
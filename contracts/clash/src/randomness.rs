@@ -0,0 +1,62 @@
+//! Auditable randomness.
+//!
+//! Any mechanic whose outcome depends on chance must draw its seed through here and
+//! keep the returned [`RandomnessAudit`] on its result struct. The seed is
+//! `sha256(domain | inputs)`, and the inputs are fixed by the commit window: the
+//! session id and *both* players' commitments, which are locked in before either
+//! player reveals. The contract adds nothing of its own, so anyone can recompute the
+//! seed from the stored inputs.
+//!
+//! The seed is not unbiasable. The first commitment is public by the time the second
+//! player commits, so the second player can try commitments (other move sequences, or
+//! other salts for the same moves) until the seed they produce looks favourable, and
+//! commit that one. Mechanics drawing on it should keep the stakes of any one roll
+//! small.
+
+use soroban_sdk::{Bytes, BytesN, Env, String};
+
+use crate::RandomnessAudit;
+
+/// Domain for the per-battle seed recorded on every resolved `BattleResult`
+pub(crate) const BATTLE_DOMAIN: &str = "clash/battle/v1";
+
+/// Derive a seed for `domain` from the commit window of a game
+pub(crate) fn commit_window(
+    env: &Env,
+    domain: &str,
+    session_id: u32,
+    player1_commitment: &BytesN<32>,
+    player2_commitment: &BytesN<32>,
+) -> RandomnessAudit {
+    let mut inputs = Bytes::from_array(env, &session_id.to_be_bytes());
+    inputs.append(&Bytes::from(player1_commitment.clone()));
+    inputs.append(&Bytes::from(player2_commitment.clone()));
+
+    let domain = String::from_str(env, domain);
+    let seed = derive(env, &domain, &inputs);
+    RandomnessAudit {
+        domain,
+        inputs,
+        seed,
+    }
+}
+
+/// Placeholder for results that have not drawn any randomness (yet)
+pub(crate) fn none(env: &Env) -> RandomnessAudit {
+    RandomnessAudit {
+        domain: String::from_str(env, ""),
+        inputs: Bytes::new(env),
+        seed: BytesN::from_array(env, &[0u8; 32]),
+    }
+}
+
+/// Whether `audit.seed` really is the derivation of its domain and inputs
+pub(crate) fn verify(env: &Env, audit: &RandomnessAudit) -> bool {
+    derive(env, &audit.domain, &audit.inputs) == audit.seed
+}
+
+fn derive(env: &Env, domain: &String, inputs: &Bytes) -> BytesN<32> {
+    let mut preimage = domain.to_bytes();
+    preimage.append(inputs);
+    env.crypto().sha256(&preimage).into()
+}
@@ -0,0 +1,359 @@
+//! Elo ladder: per-player records and ratings, updated whenever a rated game settles,
+//! plus a leaderboard of the top ratings kept sorted as results come in. These are
+//! lifetime records; while a season runs, each result is rated again on the season's
+//! own ladder (see `seasons`).
+//!
+//! A histogram of every rated player's rating, in `RATING_BUCKET_SIZE` buckets, is
+//! kept alongside so a percentile can be read without the whole ladder.
+//!
+//! Once the admin turns it on, ratings above the starting 1500 decay while a player
+//! goes without rated games. The decay is worked out on read, and only written back
+//! (to the record, histogram and leaderboard) by the player's next rated game or by
+//! `decay_rating`, so idle players cost nothing. The top players and percentiles
+//! re-rank the leaderboard by decayed rating as they read it.
+//!
+//! A player's first `PROVISIONAL_GAMES` rated games are provisional: their rating
+//! moves at `PROVISIONAL_K_FACTOR` so it finds its level quickly, and views flag it
+//! so opponents know a 1500 newcomer may be much stronger or weaker.
+//!
+//! Siege games are asymmetric and tracked separately, so they aren't rated.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{identity, moderation, seasons};
+use crate::storage;
+use crate::{PlayerStats, RankedPlayer, RatingDecay, RatingPercentile};
+
+/// Rating every player starts on
+pub(crate) const STARTING_RATING: i32 = 1500;
+
+/// Most a single game can move an established rating
+pub(crate) const K_FACTOR: i32 = 32;
+
+/// Rated games a player's rating stays provisional for
+const PROVISIONAL_GAMES: u32 = 10;
+
+/// Most a single game can move a provisional rating
+const PROVISIONAL_K_FACTOR: i32 = 64;
+
+/// Most players kept on the leaderboard
+pub(crate) const LEADERBOARD_SIZE: u32 = 100;
+
+/// Expected score (per mille) for the higher-rated player, at rating gaps of 0, 50,
+/// 100, ... 800: `1000 / (1 + 10^(-gap / 400))`
+const EXPECTED_SCORE: [i32; 17] = [
+    500, 571, 640, 703, 760, 808, 849, 882, 909, 930, 947, 960, 969, 977, 983, 987, 990,
+];
+
+const GAP_STEP: i32 = 50;
+
+/// Rating points per histogram bucket
+const RATING_BUCKET_SIZE: i32 = 50;
+
+/// Histogram buckets, covering 0 to 3000; ratings outside fall in the end buckets
+const RATING_BUCKETS: u32 = 60;
+
+pub(crate) fn player_stats(env: &Env, player: &Address) -> PlayerStats {
+    let player = identity::primary(env, player);
+    match storage::player_stats(env, &player) {
+        Some(stats) => decayed(env, &player, stats).0,
+        None => NEW_PLAYER,
+    }
+}
+
+/// `stats` less the decay owed for inactivity, and the time decay counts from
+/// once it is applied. A whole number of periods is taken, so the rest of the
+/// current one still counts.
+fn decayed(env: &Env, player: &Address, mut stats: PlayerStats) -> (PlayerStats, u64) {
+    let (rating, since) = decayed_rating(env, &storage::rating_decay(env), player, stats.rating);
+    stats.rating = rating;
+    (stats, since)
+}
+
+/// `rating` less the decay `player` owes under `decay`, as in [`decayed`]
+fn decayed_rating(env: &Env, decay: &RatingDecay, player: &Address, rating: i32) -> (i32, u64) {
+    let since = storage::decay_since(env, player);
+    if !is_decay_on(decay) || since == 0 || rating <= STARTING_RATING {
+        return (rating, since);
+    }
+    let periods = env.ledger().timestamp().saturating_sub(since) / decay.inactive_secs;
+    let owed = periods.saturating_mul(u64::from(decay.points));
+    let rating = rating - owed.min((rating - STARTING_RATING) as u64) as i32;
+    (rating, since + periods * decay.inactive_secs)
+}
+
+fn is_decay_on(decay: &RatingDecay) -> bool {
+    decay.inactive_secs > 0 && decay.points > 0
+}
+
+/// Write the decay `player` owes into their record, the histogram and the
+/// leaderboard
+pub(crate) fn decay_rating(env: &Env, player: &Address) -> PlayerStats {
+    let player = &identity::primary(env, player);
+    let Some(stored) = storage::player_stats(env, player) else {
+        return NEW_PLAYER;
+    };
+    let previous = stored.rating;
+    let (stats, since) = decayed(env, player, stored);
+    if stats.rating == previous {
+        return stats;
+    }
+    storage::save_player_stats(env, player, &stats);
+    storage::save_decay_since(env, player, since);
+
+    let mut histogram = rating_histogram(env);
+    move_in_histogram(&mut histogram, Some(previous), stats.rating);
+    storage::save_rating_histogram(env, &histogram);
+    let mut leaderboard = storage::leaderboard(env);
+    place(&mut leaderboard, player, stats.rating);
+    storage::save_leaderboard(env, &leaderboard);
+    stats
+}
+
+/// Whether `stats` has fewer rated games than it takes to be established
+pub(crate) fn is_provisional(stats: &PlayerStats) -> bool {
+    stats.wins + stats.losses + stats.draws < PROVISIONAL_GAMES
+}
+
+/// How far one game can move the rating of the player with `stats`
+fn k_factor(stats: &PlayerStats) -> i32 {
+    if is_provisional(stats) {
+        PROVISIONAL_K_FACTOR
+    } else {
+        K_FACTOR
+    }
+}
+
+/// Record of a player with no rated games
+pub(crate) const NEW_PLAYER: PlayerStats = PlayerStats {
+    wins: 0,
+    losses: 0,
+    draws: 0,
+    rating: STARTING_RATING,
+    streak: 0,
+};
+
+/// Whether a game between these two counts as rated: not two addresses of one
+/// identity, and neither player shadowed. Everything that rewards rated results
+/// checks this, so ratings and bonuses can't disagree.
+pub(crate) fn is_rated_pair(env: &Env, player1: &Address, player2: &Address) -> bool {
+    let (player1, player2) = (identity::primary(env, player1), identity::primary(env, player2));
+    player1 != player2 && !moderation::is_shadowed(env, &player1) && !moderation::is_shadowed(env, &player2)
+}
+
+/// Rate a settled game. `winner` is None for a draw. Linked addresses are rated
+/// as their primary. Games that aren't a rated pair (see `is_rated_pair`) are left
+/// out.
+pub(crate) fn record_result(
+    env: &Env,
+    player1: &Address,
+    player2: &Address,
+    winner: Option<&Address>,
+) {
+    if !is_rated_pair(env, player1, player2) {
+        return;
+    }
+    let player1_won = winner == Some(player1);
+    let (player1, player2) = (&identity::primary(env, player1), &identity::primary(env, player2));
+    let winner = winner.map(|_| if player1_won { player1 } else { player2 });
+
+    let stored1 = storage::player_stats(env, player1);
+    let stored2 = storage::player_stats(env, player2);
+    // Stored ratings before this game, for the histogram; None for a first rated game
+    let previous1 = stored1.as_ref().map(|stats| stats.rating);
+    let previous2 = stored2.as_ref().map(|stats| stats.rating);
+    // Any decay owed is taken before the game is rated
+    let mut stats1 = stored1.map_or(NEW_PLAYER, |stats| decayed(env, player1, stats).0);
+    let mut stats2 = stored2.map_or(NEW_PLAYER, |stats| decayed(env, player2, stats).0);
+    let k_factors = (k_factor(&stats1), k_factor(&stats2));
+    rate(&mut stats1, &mut stats2, k_factors, player1, winner);
+    storage::save_player_stats(env, player1, &stats1);
+    storage::save_player_stats(env, player2, &stats2);
+    let now = env.ledger().timestamp();
+    storage::save_decay_since(env, player1, now);
+    storage::save_decay_since(env, player2, now);
+
+    let mut histogram = rating_histogram(env);
+    move_in_histogram(&mut histogram, previous1, stats1.rating);
+    move_in_histogram(&mut histogram, previous2, stats2.rating);
+    storage::save_rating_histogram(env, &histogram);
+
+    let mut leaderboard = storage::leaderboard(env);
+    place(&mut leaderboard, player1, stats1.rating);
+    place(&mut leaderboard, player2, stats2.rating);
+    storage::save_leaderboard(env, &leaderboard);
+
+    seasons::record_result(env, player1, player2, winner);
+}
+
+/// Apply one result to both records: the Elo swing, each side moving by its own
+/// K-factor, and the win, loss or draw
+pub(crate) fn rate(
+    stats1: &mut PlayerStats,
+    stats2: &mut PlayerStats,
+    k_factors: (i32, i32),
+    player1: &Address,
+    winner: Option<&Address>,
+) {
+    // Score per mille for player1
+    let score1 = match winner {
+        None => 500,
+        Some(winner) if winner == player1 => 1000,
+        Some(_) => 0,
+    };
+    // Rounded to the nearest point so small swings between close ratings still count
+    let surprise = score1 - expected_score(stats1.rating, stats2.rating);
+    let change = |k_factor: i32| {
+        let swing = k_factor * surprise;
+        (swing + swing.signum() * 500) / 1000
+    };
+    stats1.rating += change(k_factors.0);
+    stats2.rating -= change(k_factors.1);
+
+    record_outcome(stats1, score1);
+    record_outcome(stats2, 1000 - score1);
+}
+
+/// The best `limit` ratings, highest first, after any decay owed
+pub(crate) fn get_top_players(env: &Env, limit: u32) -> Vec<RankedPlayer> {
+    let leaderboard = decayed_leaderboard(env);
+    leaderboard.slice(..limit.min(leaderboard.len()))
+}
+
+/// The leaderboard re-ranked by decayed ratings. Its stored ratings are the
+/// players' stored ones, since every rating write also places the player.
+fn decayed_leaderboard(env: &Env) -> Vec<RankedPlayer> {
+    let stored = storage::leaderboard(env);
+    let decay = storage::rating_decay(env);
+    if !is_decay_on(&decay) {
+        return stored;
+    }
+    let mut leaderboard = Vec::new(env);
+    for entry in stored.iter() {
+        let (rating, _) = decayed_rating(env, &decay, &entry.player, entry.rating);
+        place(&mut leaderboard, &entry.player, rating);
+    }
+    leaderboard
+}
+
+/// Where `player`'s rating stands among every rated player, to within a bucket.
+/// Players sharing the player's bucket count as above them, so `top_pct` never
+/// flatters. The player and everyone on the leaderboard count at their decayed
+/// ratings; idle players below the leaderboard count at their last written one
+/// until `decay_rating` or their next game updates the histogram.
+pub(crate) fn get_rating_percentile(env: &Env, player: &Address) -> RatingPercentile {
+    let mut histogram = rating_histogram(env);
+    let rated_players = histogram.iter().sum();
+    let player = identity::primary(env, player);
+    let Some(stats) = storage::player_stats(env, &player) else {
+        return RatingPercentile {
+            rating: STARTING_RATING,
+            rated_players,
+            top_pct: 0,
+            is_provisional: true,
+        };
+    };
+    let stored_rating = stats.rating;
+    let (stats, _) = decayed(env, &player, stats);
+    let decay = storage::rating_decay(env);
+    if is_decay_on(&decay) {
+        for entry in storage::leaderboard(env).iter().filter(|entry| entry.player != player) {
+            let (rating, _) = decayed_rating(env, &decay, &entry.player, entry.rating);
+            move_in_histogram(&mut histogram, Some(entry.rating), rating);
+        }
+        move_in_histogram(&mut histogram, Some(stored_rating), stats.rating);
+    }
+    let at_or_above: u32 = histogram.slice(bucket(stats.rating)..).iter().sum();
+    RatingPercentile {
+        rating: stats.rating,
+        rated_players,
+        top_pct: (at_or_above * 100).div_ceil(rated_players.max(1)),
+        is_provisional: is_provisional(&stats),
+    }
+}
+
+/// Histogram bucket `rating` falls in
+fn bucket(rating: i32) -> u32 {
+    (rating / RATING_BUCKET_SIZE).clamp(0, RATING_BUCKETS as i32 - 1) as u32
+}
+
+/// The stored histogram, with every bucket present
+fn rating_histogram(env: &Env) -> Vec<u32> {
+    let mut histogram = storage::rating_histogram(env);
+    while histogram.len() < RATING_BUCKETS {
+        histogram.push_back(0);
+    }
+    histogram
+}
+
+/// Count a player under `rating`, taking them out of their `previous` rating's
+/// bucket if they were rated before
+fn move_in_histogram(histogram: &mut Vec<u32>, previous: Option<i32>, rating: i32) {
+    if let Some(previous) = previous {
+        let index = bucket(previous);
+        histogram.set(index, histogram.get_unchecked(index).saturating_sub(1));
+    }
+    let index = bucket(rating);
+    histogram.set(index, histogram.get_unchecked(index) + 1);
+}
+
+/// Expected score (per mille) of a `rating` player against `opponent`,
+/// interpolated from [`EXPECTED_SCORE`]
+fn expected_score(rating: i32, opponent: i32) -> i32 {
+    let gap = (rating - opponent).abs();
+    let step = (gap / GAP_STEP) as usize;
+    let favourite = match EXPECTED_SCORE.get(step + 1) {
+        Some(next) => {
+            let base = EXPECTED_SCORE[step];
+            base + (next - base) * (gap % GAP_STEP) / GAP_STEP
+        }
+        None => EXPECTED_SCORE[EXPECTED_SCORE.len() - 1],
+    };
+    if rating >= opponent {
+        favourite
+    } else {
+        1000 - favourite
+    }
+}
+
+fn record_outcome(stats: &mut PlayerStats, score: i32) {
+    match score {
+        1000 => {
+            stats.wins += 1;
+            stats.streak = stats.streak.max(0) + 1;
+        }
+        0 => {
+            stats.losses += 1;
+            stats.streak = stats.streak.min(0) - 1;
+        }
+        _ => {
+            stats.draws += 1;
+            stats.streak = 0;
+        }
+    }
+}
+
+/// Move `player` to where `rating` belongs on the leaderboard. A player who drops
+/// below a full board falls off until their next rated game puts them back on.
+pub(crate) fn place(leaderboard: &mut Vec<RankedPlayer>, player: &Address, rating: i32) {
+    if let Some(index) = leaderboard.iter().position(|entry| entry.player == *player) {
+        leaderboard.remove(index as u32);
+    }
+    let index = leaderboard
+        .iter()
+        .position(|entry| entry.rating < rating)
+        .map(|index| index as u32)
+        .unwrap_or(leaderboard.len());
+    if index < LEADERBOARD_SIZE {
+        leaderboard.insert(
+            index,
+            RankedPlayer {
+                player: player.clone(),
+                rating,
+            },
+        );
+        if leaderboard.len() > LEADERBOARD_SIZE {
+            leaderboard.pop_back();
+        }
+    }
+}
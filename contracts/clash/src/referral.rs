@@ -0,0 +1,78 @@
+//! Referrals: a new player names who brought them in with `register_referral`,
+//! before their first game. When their first battle resolves, the referrer is
+//! credited the referral bonus in an internal ledger and collects it as CSH with
+//! `claim_referral_rewards`.
+//!
+//! Only players who have finished a game can refer, which keeps fresh addresses
+//! from referring each other in a loop.
+
+use soroban_sdk::{Address, Env};
+
+use crate::badges;
+use crate::storage;
+use crate::{ClashTokenClient, Error, ReferralCredited};
+
+/// Default CSH credited per referred player (7 decimals)
+pub(crate) const DEFAULT_REFERRAL_BONUS: i128 = 20_0000000;
+
+pub(crate) fn register_referral(env: &Env, new_player: Address, referrer: Address) -> Result<(), Error> {
+    new_player.require_auth();
+
+    if storage::referrer(env, &new_player).is_some() {
+        return Err(Error::AlreadyReferred);
+    }
+    if new_player == referrer || has_played(env, &new_player) || storage::match_history(env, &referrer).is_empty() {
+        return Err(Error::InvalidReferral);
+    }
+    storage::save_referrer(env, &new_player, &referrer);
+    let mut stats = storage::referral_stats(env, &referrer);
+    stats.referred += 1;
+    storage::save_referral_stats(env, &referrer, &stats);
+    Ok(())
+}
+
+/// Credit the referrer of `player`, if they were referred and this is their first
+/// resolved battle. The referral is dropped once credited.
+pub(crate) fn credit_first_battle(env: &Env, player: &Address) {
+    let Some(referrer) = storage::referrer(env, player) else {
+        return;
+    };
+    storage::remove_referrer(env, player);
+    let bonus = storage::referral_bonus(env);
+    let mut stats = storage::referral_stats(env, &referrer);
+    if stats.unclaimed == 0 {
+        badges::reward_credited(env, &referrer);
+    }
+    stats.completed += 1;
+    stats.earned += bonus;
+    stats.unclaimed += bonus;
+    storage::save_referral_stats(env, &referrer, &stats);
+    ReferralCredited {
+        referrer,
+        player: player.clone(),
+        amount: bonus,
+    }
+    .publish(env);
+}
+
+/// Mint everything credited to `referrer` so far; returns the amount paid
+pub(crate) fn claim_rewards(env: &Env, referrer: Address) -> Result<i128, Error> {
+    referrer.require_auth();
+
+    let token_addr = storage::token_contract(env).ok_or(Error::NothingToClaim)?;
+    let mut stats = storage::referral_stats(env, &referrer);
+    let amount = stats.unclaimed;
+    if amount <= 0 {
+        return Err(Error::NothingToClaim);
+    }
+    stats.unclaimed = 0;
+    storage::save_referral_stats(env, &referrer, &stats);
+    badges::reward_claimed(env, &referrer);
+    ClashTokenClient::new(env, &token_addr).mint(&referrer, &amount);
+    Ok(amount)
+}
+
+/// Whether `player` has a resolved battle on record or a game in play
+fn has_played(env: &Env, player: &Address) -> bool {
+    !storage::match_history(env, player).is_empty() || !storage::player_sessions(env, player).is_empty()
+}
@@ -0,0 +1,91 @@
+//! Session registry for indexers: every game, duel and team game is appended, with
+//! the ledger it was created in, to a bucket of `BUCKET_LEDGERS` ledgers. Indexers
+//! and dashboards page through `get_sessions_in_range` instead of scanning events
+//! from genesis.
+//!
+//! Like the activity log, sessions are recorded by the storage layer when a new one
+//! is stored, so no creation path can skip it. A bucket is written in pages of
+//! `PAGE_RECORDS`, so an append costs the same however busy the hour is, and a query
+//! reads at most `MAX_PAGES_PER_QUERY` pages, handing back where to continue. Pages
+//! are persistent and kept for a game TTL after their last append; an indexer that
+//! falls further behind restores them first.
+
+use soroban_sdk::{vec, Env};
+
+use crate::storage;
+use crate::{ActivityKind, Error, SessionRange, SessionRecord};
+
+/// Ledgers per registry bucket (about an hour)
+pub(crate) const BUCKET_LEDGERS: u32 = 720;
+
+/// Most buckets one query may span (about a day)
+const MAX_BUCKETS_PER_QUERY: u32 = 24;
+
+/// Most records one page of a bucket holds
+pub(crate) const PAGE_RECORDS: u32 = 50;
+
+/// Most pages one query reads
+pub(crate) const MAX_PAGES_PER_QUERY: u32 = 24;
+
+pub(crate) fn record(env: &Env, kind: ActivityKind, session_id: u32) {
+    let ledger = env.ledger().sequence();
+    let bucket = ledger / BUCKET_LEDGERS;
+    let mut pages = storage::session_bucket_pages(env, bucket);
+    let mut sessions = match pages {
+        0 => vec![env],
+        _ => storage::session_page(env, bucket, pages - 1),
+    };
+    if pages == 0 || sessions.len() >= PAGE_RECORDS {
+        sessions = vec![env];
+        pages += 1;
+    }
+    sessions.push_back(SessionRecord {
+        session_id,
+        kind,
+        ledger,
+    });
+    storage::save_session_page(env, bucket, pages - 1, &sessions);
+    storage::save_session_bucket_pages(env, bucket, pages);
+}
+
+/// Sessions created in ledgers `from_seq..=to_seq`, in creation order, from the
+/// range's pages starting at `page`
+pub(crate) fn get_sessions_in_range(env: &Env, from_seq: u32, to_seq: u32, page: u32) -> Result<SessionRange, Error> {
+    if from_seq > to_seq {
+        return Err(Error::InvalidRange);
+    }
+    let (first, last) = (from_seq / BUCKET_LEDGERS, to_seq / BUCKET_LEDGERS);
+    if last - first >= MAX_BUCKETS_PER_QUERY {
+        return Err(Error::InvalidRange);
+    }
+
+    let mut sessions = vec![env];
+    let mut skip = page;
+    let mut budget = MAX_PAGES_PER_QUERY;
+    for bucket in first..=last {
+        let pages = storage::session_bucket_pages(env, bucket);
+        if skip >= pages {
+            skip -= pages;
+            continue;
+        }
+        for index in skip..pages {
+            if budget == 0 {
+                return Ok(SessionRange {
+                    sessions,
+                    next_page: Some(page + MAX_PAGES_PER_QUERY),
+                });
+            }
+            budget -= 1;
+            for record in storage::session_page(env, bucket, index).iter() {
+                if (from_seq..=to_seq).contains(&record.ledger) {
+                    sessions.push_back(record);
+                }
+            }
+        }
+        skip = 0;
+    }
+    Ok(SessionRange {
+        sessions,
+        next_page: None,
+    })
+}
@@ -0,0 +1,92 @@
+//! Rematches: after a battle either player can offer to play again with the same
+//! pairing, stakes and mode. The opponent accepts in one call, which starts the new
+//! session on the Game Hub. Each session keeps a link to the one it rematched, so a
+//! run of rematches can be played back as a chain.
+
+use soroban_sdk::{Address, Env};
+
+use crate::challenge::CHALLENGE_DURATION_SECS;
+use crate::storage;
+use crate::{game, Error, Rematch, RematchAccepted, RematchRequested};
+
+/// Offer a rematch of the finished game `session_id`. The offer stays open as long
+/// as a challenge would.
+pub(crate) fn request_rematch(env: &Env, session_id: u32, requester: Address) -> Result<(), Error> {
+    requester.require_auth();
+
+    let game = storage::load_game(env, session_id)?;
+    let opponent = if requester == game.player1 {
+        game.player2.clone()
+    } else if requester == game.player2 {
+        game.player1.clone()
+    } else {
+        return Err(Error::NotPlayer);
+    };
+    if !game.has_battle_result {
+        return Err(Error::GameNotEnded);
+    }
+    if storage::rematch(env, session_id).is_some() {
+        return Err(Error::RematchAlreadyRequested);
+    }
+
+    let rematch = Rematch {
+        session_id,
+        requester: requester.clone(),
+        player1: game.player1,
+        player2: game.player2,
+        player1_points: game.player1_points,
+        player2_points: game.player2_points,
+        mode: game.mode,
+        expires_at: env.ledger().timestamp() + CHALLENGE_DURATION_SECS,
+        rematch_session_id: None,
+    };
+    storage::save_rematch(env, &rematch);
+
+    RematchRequested {
+        session_id,
+        opponent,
+        requester,
+    }
+    .publish(env);
+    Ok(())
+}
+
+/// Accept the rematch offered on `session_id` and start it. Returns the new session id.
+pub(crate) fn accept_rematch(env: &Env, session_id: u32, player: Address) -> Result<u32, Error> {
+    player.require_auth();
+
+    let mut rematch = storage::rematch(env, session_id).ok_or(Error::RematchNotFound)?;
+    if (player != rematch.player1 && player != rematch.player2) || player == rematch.requester {
+        return Err(Error::NotPlayer);
+    }
+    if rematch.rematch_session_id.is_some() {
+        return Err(Error::ChallengeAlreadyAccepted);
+    }
+    if env.ledger().timestamp() > rematch.expires_at {
+        return Err(Error::ChallengeExpired);
+    }
+
+    // The requester authorized the stakes with the offer, as a challenger does
+    let new_session_id = game::allocate_session_id(env);
+    game::start_game_after_auth(
+        env,
+        new_session_id,
+        rematch.player1.clone(),
+        rematch.player2.clone(),
+        rematch.player1_points,
+        rematch.player2_points,
+        rematch.mode,
+    )?;
+
+    rematch.rematch_session_id = Some(new_session_id);
+    storage::save_rematch(env, &rematch);
+    storage::save_rematch_of(env, new_session_id, session_id);
+
+    RematchAccepted {
+        session_id,
+        rematch_session_id: new_session_id,
+        accepted_by: player,
+    }
+    .publish(env);
+    Ok(new_session_id)
+}
@@ -0,0 +1,152 @@
+//! Rivalries: two players who name each other as rival get a bonus for beating one
+//! another and a head-to-head record, flagged on the challenges between them.
+//!
+//! Naming a rival is one-sided until the other player names you back; each player
+//! has at most one rival at a time.
+//!
+//! Separately, any two players who keep playing each other build up a grudge series:
+//! consecutive games between them are linked into one running score, until either
+//! player finishes a game against someone else.
+
+use soroban_sdk::{vec, Address, Env};
+
+use crate::storage;
+use crate::{ClashTokenClient, Error, HeadToHead, RivalrySeries};
+
+/// Extra CSH for winning a rival match, on top of the usual win reward
+const RIVALRY_BONUS: i128 = 5_0000000;
+
+/// Most sessions a grudge series lists; the score counts every game in it
+const MAX_SERIES_SESSIONS: u32 = 20;
+
+/// Name `rival` as `player`'s rival, replacing any previous one
+pub(crate) fn declare_rival(env: &Env, player: Address, rival: Address) -> Result<(), Error> {
+    player.require_auth();
+    if player == rival {
+        return Err(Error::CannotChallengeSelf);
+    }
+    storage::set_rival(env, &player, Some(&rival));
+    Ok(())
+}
+
+pub(crate) fn clear_rival(env: &Env, player: Address) {
+    player.require_auth();
+    storage::set_rival(env, &player, None);
+}
+
+/// Whether the two players have named each other
+pub(crate) fn is_rivalry(env: &Env, player: &Address, opponent: &Address) -> bool {
+    storage::rival(env, player).as_ref() == Some(opponent)
+        && storage::rival(env, opponent).as_ref() == Some(player)
+}
+
+/// `player`'s record against `opponent`
+pub(crate) fn head_to_head(env: &Env, player: &Address, opponent: &Address) -> HeadToHead {
+    let record = storage::head_to_head(env, player, opponent);
+    if player < opponent {
+        record
+    } else {
+        flip(record)
+    }
+}
+
+/// `player`'s current grudge series against `opponent`, seen from `player`'s side
+pub(crate) fn rivalry_series(env: &Env, player: &Address, opponent: &Address) -> Option<RivalrySeries> {
+    let series = storage::rivalry_series(env, player, opponent)?;
+    Some(if series.player_a == *player { series } else { flip_series(series) })
+}
+
+/// Count a settled result towards the pair's grudge series. Rival matches also go on
+/// the head-to-head record and pay the winner's bonus.
+pub(crate) fn record_result(
+    env: &Env,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    winner: Option<&Address>,
+) {
+    record_series(env, session_id, player1, player2, winner);
+    if !is_rivalry(env, player1, player2) {
+        return;
+    }
+
+    // Stored from the point of view of the lower address
+    let (low, high) = if player1 < player2 {
+        (player1, player2)
+    } else {
+        (player2, player1)
+    };
+    let mut record = storage::head_to_head(env, low, high);
+    match winner {
+        None => record.draws += 1,
+        Some(winner) if winner == low => record.wins += 1,
+        Some(_) => record.losses += 1,
+    }
+    storage::save_head_to_head(env, low, high, &record);
+
+    if let (Some(winner), Some(token)) = (winner, storage::token_contract(env)) {
+        ClashTokenClient::new(env, &token).mint(winner, &RIVALRY_BONUS);
+    }
+}
+
+/// Extend the pair's series, or start a new one if either player has finished a
+/// game against someone else since their last one together
+fn record_series(
+    env: &Env,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    winner: Option<&Address>,
+) {
+    let unbroken = storage::last_opponent(env, player1).as_ref() == Some(player2)
+        && storage::last_opponent(env, player2).as_ref() == Some(player1);
+    let now = env.ledger().timestamp();
+    let mut series = match storage::rivalry_series(env, player1, player2) {
+        Some(series) if unbroken => series,
+        _ => {
+            let (low, high) = if player1 < player2 { (player1, player2) } else { (player2, player1) };
+            RivalrySeries {
+                player_a: low.clone(),
+                player_b: high.clone(),
+                sessions: vec![env],
+                a_wins: 0,
+                b_wins: 0,
+                draws: 0,
+                started_at: now,
+                last_played_at: now,
+            }
+        }
+    };
+
+    series.sessions.push_back(session_id);
+    if series.sessions.len() > MAX_SERIES_SESSIONS {
+        series.sessions.pop_front();
+    }
+    match winner {
+        None => series.draws += 1,
+        Some(winner) if *winner == series.player_a => series.a_wins += 1,
+        Some(_) => series.b_wins += 1,
+    }
+    series.last_played_at = now;
+    storage::save_rivalry_series(env, &series);
+    storage::set_last_opponent(env, player1, player2);
+    storage::set_last_opponent(env, player2, player1);
+}
+
+fn flip_series(series: RivalrySeries) -> RivalrySeries {
+    RivalrySeries {
+        player_a: series.player_b,
+        player_b: series.player_a,
+        a_wins: series.b_wins,
+        b_wins: series.a_wins,
+        ..series
+    }
+}
+
+fn flip(record: HeadToHead) -> HeadToHead {
+    HeadToHead {
+        wins: record.losses,
+        losses: record.wins,
+        draws: record.draws,
+    }
+}
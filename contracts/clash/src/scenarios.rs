@@ -0,0 +1,197 @@
+//! Canonical battles for the engine, compiled only with the `scenarios` feature (and
+//! in tests). Each fixes both move sequences and a tie-break under the default rules,
+//! with the HP after every turn and the outcome the engine must produce.
+//!
+//! The unit tests below play every scenario through [`combat::simulate`]. The same
+//! fixtures are exported as JSON to
+//! `clash-frontend/src/games/clash/fixtures/battle-scenarios.json`, so the replay
+//! renderer can be checked against them; after changing the engine, regenerate it
+//! with `CLASH_WRITE_SCENARIOS=1 cargo test -p clash scenarios`.
+
+use core::fmt::{self, Write};
+
+use crate::combat::{self, BattleOutcome, BattleState, Captains, Crits, TurnOutcome, Winner};
+use crate::{Attack, Defense, Move, RuleSet, TieBreak};
+
+/// One battle and the result it has to come out with
+pub struct Scenario {
+    pub name: &'static str,
+    pub player1_moves: [Move; 3],
+    pub player2_moves: [Move; 3],
+    pub tie_break: TieBreak,
+    /// (player1, player2) HP after each turn played, overtime included
+    pub hp_after_turns: &'static [(i32, i32)],
+    pub outcome: BattleOutcome,
+}
+
+const fn moves(attack: Attack, defense: Defense) -> [Move; 3] {
+    [
+        Move { attack, defense },
+        Move { attack, defense },
+        Move { attack, defense },
+    ]
+}
+
+pub const ALL: [Scenario; 5] = [
+    // Player2 blocks every Lightning while their Slashes combo through
+    Scenario {
+        name: "perfect_block",
+        player1_moves: moves(Attack::Lightning, Defense::Block),
+        player2_moves: moves(Attack::Slash, Defense::Block),
+        tie_break: TieBreak::SuddenDeath,
+        hp_after_turns: &[(70, 100), (25, 100), (-35, 100)],
+        outcome: BattleOutcome {
+            player1_hp: -35,
+            player2_hp: 100,
+            winner: Winner::Player2,
+            overtime_turns: 0,
+        },
+    },
+    // Three unanswered Fireballs, the last two with combo bonuses
+    Scenario {
+        name: "triple_combo_blowout",
+        player1_moves: moves(Attack::Fireball, Defense::Dodge),
+        player2_moves: moves(Attack::Slash, Defense::Block),
+        tie_break: TieBreak::SuddenDeath,
+        hp_after_turns: &[(100, 60), (100, 5), (100, -65)],
+        outcome: BattleOutcome {
+            player1_hp: 100,
+            player2_hp: -65,
+            winner: Winner::Player1,
+            overtime_turns: 0,
+        },
+    },
+    // Mirror Slashes: both are knocked out on the same turn, which is a draw
+    Scenario {
+        name: "mutual_ko",
+        player1_moves: moves(Attack::Slash, Defense::Block),
+        player2_moves: moves(Attack::Slash, Defense::Block),
+        tie_break: TieBreak::SuddenDeath,
+        hp_after_turns: &[(70, 70), (30, 30), (-25, -25)],
+        outcome: BattleOutcome {
+            player1_hp: -25,
+            player2_hp: -25,
+            winner: Winner::Draw,
+            overtime_turns: 0,
+        },
+    },
+    // One unanswered 40 each leaves it level; overtime replays turn one's Fireball
+    Scenario {
+        name: "sudden_death_tie_break",
+        player1_moves: moves(Attack::Slash, Defense::Block),
+        player2_moves: LEVEL_AFTER_THREE,
+        tie_break: TieBreak::SuddenDeath,
+        hp_after_turns: &[(60, 100), (60, 60), (60, 60), (0, 60)],
+        outcome: BattleOutcome {
+            player1_hp: 0,
+            player2_hp: 60,
+            winner: Winner::Player2,
+            overtime_turns: 1,
+        },
+    },
+    // The same level battle under DamageDealt: equal totals and biggest hits
+    Scenario {
+        name: "damage_dealt_tie_break",
+        player1_moves: moves(Attack::Slash, Defense::Block),
+        player2_moves: LEVEL_AFTER_THREE,
+        tie_break: TieBreak::DamageDealt,
+        hp_after_turns: &[(60, 100), (60, 60), (60, 60)],
+        outcome: BattleOutcome {
+            player1_hp: 60,
+            player2_hp: 60,
+            winner: Winner::Draw,
+            overtime_turns: 0,
+        },
+    },
+];
+
+/// Against Slash/Block, lands one hit of 40 and takes one
+const LEVEL_AFTER_THREE: [Move; 3] = [
+    Move { attack: Attack::Fireball, defense: Defense::Dodge },
+    Move { attack: Attack::Lightning, defense: Defense::Block },
+    Move { attack: Attack::Lightning, defense: Defense::Dodge },
+];
+
+/// Play `scenario` through the engine, reporting each turn to `on_turn`
+pub fn play(scenario: &Scenario, on_turn: impl FnMut(TurnOutcome)) -> BattleOutcome {
+    let rules = RuleSet {
+        tie_break: scenario.tie_break,
+        ..RuleSet::DEFAULT
+    };
+    combat::simulate(
+        &rules,
+        &scenario.player1_moves,
+        &scenario.player2_moves,
+        BattleState::start(&rules),
+        Crits::NONE,
+        Captains::NONE,
+        on_turn,
+    )
+}
+
+/// All scenarios as a JSON array, in the frontend's field naming
+pub fn write_json(out: &mut impl Write) -> fmt::Result {
+    out.write_str("[\n")?;
+    for (i, scenario) in ALL.iter().enumerate() {
+        let outcome = &scenario.outcome;
+        writeln!(out, "  {{\n    \"name\": \"{}\",", scenario.name)?;
+        writeln!(out, "    \"tieBreak\": \"{:?}\",", scenario.tie_break)?;
+        for (key, moves) in [("player1Moves", &scenario.player1_moves), ("player2Moves", &scenario.player2_moves)] {
+            write!(out, "    \"{key}\": [")?;
+            for (k, m) in moves.iter().enumerate() {
+                let sep = if k == 0 { "" } else { ", " };
+                write!(out, "{sep}{{ \"attack\": \"{:?}\", \"defense\": \"{:?}\" }}", m.attack, m.defense)?;
+            }
+            out.write_str("],\n")?;
+        }
+        out.write_str("    \"hpAfterTurns\": [")?;
+        for (k, (hp1, hp2)) in scenario.hp_after_turns.iter().enumerate() {
+            let sep = if k == 0 { "" } else { ", " };
+            write!(out, "{sep}[{hp1}, {hp2}]")?;
+        }
+        out.write_str("],\n")?;
+        writeln!(out, "    \"player1Hp\": {},", outcome.player1_hp)?;
+        writeln!(out, "    \"player2Hp\": {},", outcome.player2_hp)?;
+        writeln!(out, "    \"winner\": \"{:?}\",", outcome.winner)?;
+        writeln!(out, "    \"overtimeTurns\": {}", outcome.overtime_turns)?;
+        out.write_str(if i + 1 == ALL.len() { "  }\n" } else { "  },\n" })?;
+    }
+    out.write_str("]\n")
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use std::string::String;
+    use std::vec::Vec;
+
+    const FRONTEND_FIXTURES: &str = "../../clash-frontend/src/games/clash/fixtures/battle-scenarios.json";
+
+    #[test]
+    fn scenarios_play_out_as_recorded() {
+        for scenario in ALL.iter() {
+            let mut hp = Vec::new();
+            let outcome = play(scenario, |t| hp.push((t.player1_hp_remaining, t.player2_hp_remaining)));
+            assert_eq!(outcome, scenario.outcome, "{}", scenario.name);
+            assert_eq!(hp, scenario.hp_after_turns, "{}", scenario.name);
+        }
+    }
+
+    #[test]
+    fn frontend_fixtures_match_the_scenarios() {
+        let mut json = String::new();
+        write_json(&mut json).unwrap();
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(FRONTEND_FIXTURES);
+        if std::env::var_os("CLASH_WRITE_SCENARIOS").is_some() {
+            std::fs::write(&path, &json).unwrap();
+        }
+        let exported = std::fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            exported == json,
+            "{} is stale; regenerate it with CLASH_WRITE_SCENARIOS=1",
+            FRONTEND_FIXTURES
+        );
+    }
+}
@@ -0,0 +1,199 @@
+//! Competitive seasons: the admin opens a season with an end time, and every rated
+//! result settled before then is also rated on that season's own ladder, which
+//! starts everyone back at the starting rating. Ending the season freezes its top
+//! players as a ranked snapshot. Lifetime records in `ratings` are never reset.
+//!
+//! If the admin has set season rewards, ending a season also pays the reward pot in
+//! from the admin and records a placement for each ranked player, paying the reward
+//! for their rank. Placements are claimed with `claim_season_placement` within
+//! [`CLAIM_WINDOW_SECS`]; after that anyone can sweep what is left into the
+//! collected fees. A placement stays on record after it is claimed or swept, as the
+//! player's finish for that season.
+
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, Env};
+
+use crate::ratings::{self, LEADERBOARD_SIZE, NEW_PLAYER};
+use crate::storage;
+use crate::{fees, Error, PlayerStats, Season, SeasonPlacement, SeasonResults};
+
+/// Time ranked players have to claim their season rewards (30 days)
+const CLAIM_WINDOW_SECS: u64 = 30 * 24 * 60 * 60;
+
+/// Open season `season_id`, which must be above every earlier season's ID
+pub(crate) fn start_season(env: &Env, season_id: u32, end_timestamp: u64) -> Result<Season, Error> {
+    let now = env.ledger().timestamp();
+    if let Some(current) = storage::season(env) {
+        if !current.has_ended {
+            return Err(Error::SeasonInProgress);
+        }
+        if season_id <= current.season_id {
+            return Err(Error::InvalidSeason);
+        }
+    }
+    if end_timestamp <= now {
+        return Err(Error::InvalidSeason);
+    }
+
+    let season = Season {
+        season_id,
+        started_at: now,
+        ends_at: end_timestamp,
+        has_ended: false,
+    };
+    storage::save_season(env, &season);
+    Ok(season)
+}
+
+/// Close the running season and snapshot its `top_n` best ratings (at most 100).
+/// Can be called early; results settled after `ends_at` already don't count.
+pub(crate) fn end_season(env: &Env, admin: &Address, top_n: u32) -> Result<SeasonResults, Error> {
+    let mut season = storage::season(env).ok_or(Error::InvalidSeason)?;
+    if season.has_ended {
+        return Err(Error::InvalidSeason);
+    }
+    season.has_ended = true;
+    storage::save_season(env, &season);
+
+    let leaderboard = storage::season_leaderboard(env, season.season_id);
+    let results = SeasonResults {
+        season_id: season.season_id,
+        started_at: season.started_at,
+        ended_at: env.ledger().timestamp(),
+        standings: leaderboard.slice(..top_n.min(LEADERBOARD_SIZE).min(leaderboard.len())),
+    };
+    storage::save_season_results(env, &results);
+    // The live ladder is superseded by the snapshot
+    storage::remove_season_leaderboard(env, season.season_id);
+    award_placements(env, admin, &results);
+    Ok(results)
+}
+
+/// End the running season and open the next one in the same call
+pub(crate) fn rollover_season(
+    env: &Env,
+    admin: &Address,
+    top_n: u32,
+    next_season_id: u32,
+    end_timestamp: u64,
+) -> Result<SeasonResults, Error> {
+    let results = end_season(env, admin, top_n)?;
+    start_season(env, next_season_id, end_timestamp)?;
+    Ok(results)
+}
+
+/// Pay out a placement's reward to its player
+pub(crate) fn claim_season_placement(
+    env: &Env,
+    player: Address,
+    season_id: u32,
+) -> Result<i128, Error> {
+    player.require_auth();
+
+    let mut placement =
+        storage::season_placement(env, season_id, &player).ok_or(Error::NothingToClaim)?;
+    let Some(token) = placement.token.clone() else {
+        return Err(Error::NothingToClaim);
+    };
+    if placement.claimed || placement.amount == 0 {
+        return Err(Error::NothingToClaim);
+    }
+    if env.ledger().timestamp() > placement.expires_at {
+        return Err(Error::ClaimExpired);
+    }
+    placement.claimed = true;
+    storage::save_season_placement(env, &placement);
+
+    TokenClient::new(env, &token).transfer(&env.current_contract_address(), &player, &placement.amount);
+    Ok(placement.amount)
+}
+
+/// Move the rewards left unclaimed after the claim window into the collected fees.
+/// Returns the amount swept.
+pub(crate) fn sweep_season_rewards(env: &Env, season_id: u32) -> Result<i128, Error> {
+    let results = storage::season_results(env, season_id).ok_or(Error::InvalidSeason)?;
+    if env.ledger().timestamp() <= results.ended_at + CLAIM_WINDOW_SECS {
+        return Err(Error::DeadlineNotReached);
+    }
+
+    let mut swept = 0;
+    for ranked in results.standings.iter() {
+        let Some(mut placement) = storage::season_placement(env, season_id, &ranked.player) else {
+            continue;
+        };
+        let Some(token) = placement.token.clone() else {
+            continue;
+        };
+        if placement.claimed || placement.amount == 0 {
+            continue;
+        }
+        fees::collect(env, &token, placement.amount);
+        swept += placement.amount;
+        placement.amount = 0;
+        storage::save_season_placement(env, &placement);
+    }
+    Ok(swept)
+}
+
+/// Record each ranked player's finish, with the configured reward for their rank,
+/// and take the rewards from `admin`
+fn award_placements(env: &Env, admin: &Address, results: &SeasonResults) {
+    let rewards = storage::season_rewards(env);
+    let mut total = 0;
+    for (index, ranked) in results.standings.iter().enumerate() {
+        let amount = rewards
+            .as_ref()
+            .and_then(|rewards| rewards.amounts.get(index as u32))
+            .unwrap_or(0);
+        total += amount;
+        storage::save_season_placement(
+            env,
+            &SeasonPlacement {
+                season_id: results.season_id,
+                player: ranked.player,
+                rank: index as u32 + 1,
+                rating: ranked.rating,
+                token: rewards.as_ref().filter(|_| amount > 0).map(|r| r.token.clone()),
+                amount,
+                expires_at: results.ended_at + CLAIM_WINDOW_SECS,
+                claimed: false,
+            },
+        );
+    }
+    if let Some(rewards) = rewards.filter(|_| total > 0) {
+        TokenClient::new(env, &rewards.token).transfer(admin, env.current_contract_address(), &total);
+    }
+}
+
+/// Rate a result on the running season's ladder, if a season is running
+pub(crate) fn record_result(
+    env: &Env,
+    player1: &Address,
+    player2: &Address,
+    winner: Option<&Address>,
+) {
+    let Some(season) = storage::season(env) else {
+        return;
+    };
+    if season.has_ended || env.ledger().timestamp() > season.ends_at {
+        return;
+    }
+    let season_id = season.season_id;
+
+    let mut stats1 = season_stats(env, season_id, player1);
+    let mut stats2 = season_stats(env, season_id, player2);
+    // Everyone restarts each season, so season ratings are never provisional
+    let k_factors = (ratings::K_FACTOR, ratings::K_FACTOR);
+    ratings::rate(&mut stats1, &mut stats2, k_factors, player1, winner);
+    storage::save_season_stats(env, season_id, player1, &stats1);
+    storage::save_season_stats(env, season_id, player2, &stats2);
+
+    let mut leaderboard = storage::season_leaderboard(env, season_id);
+    ratings::place(&mut leaderboard, player1, stats1.rating);
+    ratings::place(&mut leaderboard, player2, stats2.rating);
+    storage::save_season_leaderboard(env, season_id, &leaderboard);
+}
+
+pub(crate) fn season_stats(env: &Env, season_id: u32, player: &Address) -> PlayerStats {
+    storage::season_stats(env, season_id, player).unwrap_or(NEW_PLAYER)
+}
@@ -0,0 +1,159 @@
+//! Best-of-N series: one Game Hub session played out over linked classic games.
+//!
+//! The series owns the hub session and its escrow; each round is an ordinary game on
+//! its own session that never touches the hub. When a round settles the series counts
+//! it and starts the next one, until a player has won a majority or every round has
+//! been played. Only then is the hub told the aggregate winner, and the series is
+//! rated and rewarded as a single result.
+
+use soroban_sdk::{vec, Address, Env, IntoVal};
+
+use crate::storage;
+use crate::{awaiting, escrow, game, identity, ratings, rivals};
+use crate::{Error, Game, GameHubClient, GameMode, MatchSeries};
+
+/// Start a best-of-`best_of` series (3 or 5) on hub session `series_id`. Returns the
+/// session of the first round.
+pub(crate) fn start_series(
+    env: &Env,
+    series_id: u32,
+    player1: Address,
+    player2: Address,
+    player1_points: i128,
+    player2_points: i128,
+    best_of: u32,
+) -> Result<u32, Error> {
+    if identity::primary(env, &player1) == identity::primary(env, &player2) {
+        return Err(Error::CannotChallengeSelf);
+    }
+    if best_of != 3 && best_of != 5 {
+        return Err(Error::InvalidSeriesLength);
+    }
+    if storage::series(env, series_id).is_some() {
+        return Err(Error::SeriesAlreadyExists);
+    }
+    game::validate_wager(env, player1_points)?;
+    game::validate_wager(env, player2_points)?;
+
+    player1.require_auth_for_args(vec![
+        env,
+        series_id.into_val(env),
+        player1_points.into_val(env),
+    ]);
+    player2.require_auth_for_args(vec![
+        env,
+        series_id.into_val(env),
+        player2_points.into_val(env),
+    ]);
+
+    let game_hub = GameHubClient::new(env, &storage::game_hub(env));
+    game::check_registered(env, &game_hub, &player1, &player2)?;
+    awaiting::check_room(env, &player1, &player2)?;
+    let started = game_hub.try_start_game(
+        &env.current_contract_address(),
+        &series_id,
+        &player1,
+        &player2,
+        &player1_points,
+        &player2_points,
+    );
+    if !matches!(started, Ok(Ok(()))) {
+        return Err(Error::GameHubCallFailed);
+    }
+    escrow::lock(env, series_id, &player1, &player2, player1_points, player2_points);
+
+    let mut series = MatchSeries {
+        player1,
+        player2,
+        player1_points,
+        player2_points,
+        best_of,
+        sessions: vec![env],
+        player1_wins: 0,
+        player2_wins: 0,
+        draws: 0,
+        is_finished: false,
+        winner: None,
+        is_cancelled: false,
+    };
+    let session_id = start_round(env, series_id, &mut series);
+    storage::save_series(env, series_id, &series);
+    Ok(session_id)
+}
+
+/// Count a settled round, then start the next one or conclude the series
+pub(crate) fn record_round(env: &Env, series_id: u32, game: &Game) {
+    let Some(mut series) = storage::series(env, series_id) else {
+        return;
+    };
+    match &game.battle_result.winner {
+        None => series.draws += 1,
+        Some(winner) if *winner == series.player1 => series.player1_wins += 1,
+        Some(_) => series.player2_wins += 1,
+    }
+
+    let to_win = series.best_of / 2 + 1;
+    let decided = series.player1_wins >= to_win || series.player2_wins >= to_win;
+    if !decided && series.sessions.len() < series.best_of {
+        start_round(env, series_id, &mut series);
+        storage::save_series(env, series_id, &series);
+        return;
+    }
+
+    // Out of rounds without a majority: more round wins takes it, level is a draw
+    series.is_finished = true;
+    series.winner = if series.player1_wins > series.player2_wins {
+        Some(series.player1.clone())
+    } else if series.player2_wins > series.player1_wins {
+        Some(series.player2.clone())
+    } else {
+        None
+    };
+    storage::save_series(env, series_id, &series);
+
+    let game_hub = GameHubClient::new(env, &storage::game_hub(env));
+    let player1_won = series.winner.as_ref() == Some(&series.player1);
+    game_hub.end_game(&series_id, &player1_won);
+    escrow::release(env, series_id);
+    let winner = series.winner.as_ref();
+    ratings::record_result(env, &series.player1, &series.player2, winner);
+    rivals::record_result(env, series_id, &series.player1, &series.player2, winner);
+    if let Some(winner) = series.winner.clone() {
+        game::mint_csh_reward(env, winner);
+    }
+}
+
+/// Call off a series whose round in play was voided (cancelled or expired). The
+/// round has no hub session or escrow of its own, so the series' are closed instead:
+/// nothing is rated and the Game Hub refunds both stakes.
+pub(crate) fn call_off(env: &Env, series_id: u32) {
+    let Some(mut series) = storage::series(env, series_id) else {
+        return;
+    };
+    if series.is_finished {
+        return;
+    }
+    series.is_finished = true;
+    series.is_cancelled = true;
+    storage::save_series(env, series_id, &series);
+
+    GameHubClient::new(env, &storage::game_hub(env)).cancel_game(&series_id);
+    escrow::release(env, series_id);
+}
+
+/// Create the next round's game on a fresh session and link it to the series
+fn start_round(env: &Env, series_id: u32, series: &mut MatchSeries) -> u32 {
+    let session_id = game::allocate_session_id(env);
+    game::create_game(
+        env,
+        session_id,
+        series.player1.clone(),
+        series.player2.clone(),
+        series.player1_points,
+        series.player2_points,
+        GameMode::Classic,
+    );
+    storage::save_series_by_session(env, session_id, series_id);
+    series.sessions.push_back(session_id);
+    session_id
+}
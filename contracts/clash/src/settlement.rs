@@ -0,0 +1,178 @@
+//! Optimistic settlement of matches played off-chain.
+//!
+//! Players who fight a match off-chain with the same engine can both sign its result
+//! and submit it with `settle_signed_result`. The result is not applied straight away:
+//! it waits out a challenge window during which either player can dispute it, which
+//! throws it away and leaves the game to be resolved the normal way (ZK commit and
+//! reveal). A disputed session takes no further signed results, so the same
+//! signatures can't simply be submitted again. Once the window passes undisputed,
+//! anyone can finalize it.
+//!
+//! Signatures are ed25519 over [`result_digest`], made with a settlement key each
+//! player registers beforehand with `set_settlement_key`. A dedicated key (rather
+//! than the account's own signers) lets game clients sign results without asking
+//! the wallet every match.
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env};
+
+use crate::storage;
+use crate::{
+    deposit, game, randomness, BattleResult, Error, Game, OutcomeReason, PendingSettlement,
+    SignedResult,
+};
+
+/// Domain separating settlement signatures from anything else the players sign
+const SETTLEMENT_DOMAIN: &[u8] = b"clash/settle/v1";
+
+/// How long either player can dispute a submitted result (24 hours)
+const CHALLENGE_WINDOW_SECS: u64 = 24 * 60 * 60;
+
+/// What each player signs: `sha256(domain | contract | session_id | result)`, with the
+/// contract address and result in XDR, so a signature can't be replayed on another
+/// deployment or session.
+pub(crate) fn result_digest(env: &Env, session_id: u32, result: &SignedResult) -> BytesN<32> {
+    let mut message = Bytes::from_slice(env, SETTLEMENT_DOMAIN);
+    message.append(&env.current_contract_address().to_xdr(env));
+    message.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    message.append(&result.clone().to_xdr(env));
+    env.crypto().sha256(&message).into()
+}
+
+/// Register (or rotate) the ed25519 key `player` signs settlement results with
+pub(crate) fn set_settlement_key(env: &Env, player: Address, public_key: BytesN<32>) {
+    player.require_auth();
+    storage::save_settlement_key(env, &player, &public_key);
+}
+
+/// Submit a co-signed result and open its challenge window
+pub(crate) fn settle_signed_result(
+    env: &Env,
+    session_id: u32,
+    result: SignedResult,
+    sig_p1: BytesN<64>,
+    sig_p2: BytesN<64>,
+) -> Result<PendingSettlement, Error> {
+    let game = storage::load_game(env, session_id)?;
+
+    if game.has_battle_result {
+        return Err(Error::GameAlreadyEnded);
+    }
+    if storage::pending_settlement(env, session_id).is_some() {
+        return Err(Error::SettlementPending);
+    }
+    if storage::settlement_disputed(env, session_id) {
+        return Err(Error::SettlementDisputed);
+    }
+    check_result(&game, &result)?;
+
+    let digest = Bytes::from(result_digest(env, session_id, &result));
+    verify_signature(env, &game.player1, &digest, &sig_p1)?;
+    verify_signature(env, &game.player2, &digest, &sig_p2)?;
+
+    let pending = PendingSettlement {
+        result,
+        challenge_deadline: env.ledger().timestamp() + CHALLENGE_WINDOW_SECS,
+    };
+    storage::save_pending_settlement(env, session_id, &pending);
+
+    Ok(pending)
+}
+
+/// Reject a pending result; the game must then be resolved with ZK commit/reveal
+pub(crate) fn dispute_signed_result(
+    env: &Env,
+    session_id: u32,
+    player: Address,
+) -> Result<(), Error> {
+    player.require_auth();
+
+    let game = storage::load_game(env, session_id)?;
+
+    if player != game.player1 && player != game.player2 {
+        return Err(Error::NotPlayer);
+    }
+    let pending =
+        storage::pending_settlement(env, session_id).ok_or(Error::NoPendingSettlement)?;
+    if env.ledger().timestamp() > pending.challenge_deadline {
+        return Err(Error::DeadlinePassed);
+    }
+
+    storage::remove_pending_settlement(env, session_id);
+    storage::set_settlement_disputed(env, session_id);
+    Ok(())
+}
+
+/// Apply an undisputed result once its challenge window has closed
+pub(crate) fn finalize_signed_result(env: &Env, session_id: u32) -> Result<BattleResult, Error> {
+    let mut game = storage::load_game(env, session_id)?;
+
+    if game.has_battle_result {
+        return Err(Error::GameAlreadyEnded);
+    }
+    let pending =
+        storage::pending_settlement(env, session_id).ok_or(Error::NoPendingSettlement)?;
+    if env.ledger().timestamp() <= pending.challenge_deadline {
+        return Err(Error::DeadlineNotReached);
+    }
+
+    let result = pending.result;
+    game.battle_result = BattleResult {
+        player1_hp: result.player1_hp,
+        player2_hp: result.player2_hp,
+        winner: result.winner,
+        is_draw: result.is_draw,
+        turn_results: vec![env],
+        entropy: randomness::none(env),
+        went_to_overtime: false,
+        overtime_turns: vec![env],
+        reason: match result.is_draw {
+            true => OutcomeReason::Draw,
+            false => OutcomeReason::BattleDecision,
+        },
+    };
+    game.has_battle_result = true;
+    game.resolved_at = env.ledger().timestamp();
+    game.is_settled = true;
+    storage::save_game(env, session_id, &game);
+    storage::remove_pending_settlement(env, session_id);
+
+    // Anyone who committed on-chain in the meantime but never revealed gets the
+    // deposit back; revealers were refunded at reveal.
+    if game.has_player1_commitment && !game.player1_commitment.has_revealed {
+        deposit::award(env, session_id, &game, &game.player1);
+    }
+    if game.has_player2_commitment && !game.player2_commitment.has_revealed {
+        deposit::award(env, session_id, &game, &game.player2);
+    }
+    game::settle(env, session_id, &game);
+
+    Ok(game.battle_result)
+}
+
+/// The result must name one of the two players as winner, or be a draw, and its HP
+/// must be within what the engine can produce
+fn check_result(game: &Game, result: &SignedResult) -> Result<(), Error> {
+    let consistent = match &result.winner {
+        None => result.is_draw,
+        Some(winner) => !result.is_draw && (*winner == game.player1 || *winner == game.player2),
+    };
+    let hp = game.rules.starting_hp;
+    if !consistent || result.player1_hp > hp || result.player2_hp > hp {
+        return Err(Error::InvalidSignedResult);
+    }
+    Ok(())
+}
+
+/// Check `signature` against `player`'s settlement key. An invalid signature traps
+/// the host.
+fn verify_signature(
+    env: &Env,
+    player: &Address,
+    digest: &Bytes,
+    signature: &BytesN<64>,
+) -> Result<(), Error> {
+    let public_key = storage::settlement_key(env, player).ok_or(Error::InvalidSignedResult)?;
+    env.crypto().ed25519_verify(&public_key, digest, signature);
+    Ok(())
+}
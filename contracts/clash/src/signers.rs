@@ -0,0 +1,76 @@
+//! Session signers: a player can hand one session's commits and reveals to a hot key,
+//! so a passkey smart account doesn't have to sign every move of a game with the
+//! main account. The key is good for that session only; everything else (stakes,
+//! claims, challenges) still needs the player's own auth.
+//!
+//! While a signer is set, the commit and reveal calls for the session take the
+//! signer's auth instead of the player's. Moves are still recorded for the player.
+//!
+//! A commit deposit is paid from the player's own account, which a signer can't
+//! authorize, so no signer can be set for a game that takes one. A session that
+//! hasn't started yet goes by the current deposit config.
+
+use soroban_sdk::{Address, Env};
+
+use crate::storage;
+use crate::{Error, SessionSignerSet};
+
+/// Let `signer` commit and reveal for `player` in `session_id`, replacing any
+/// signer set before
+pub(crate) fn authorize_session_signer(
+    env: &Env,
+    player: Address,
+    signer: Address,
+    session_id: u32,
+) -> Result<(), Error> {
+    player.require_auth();
+
+    if signer == player || takes_deposit(env, session_id) {
+        return Err(Error::InvalidSessionSigner);
+    }
+    storage::save_session_signer(env, session_id, &player, &signer);
+    SessionSignerSet {
+        session_id,
+        player,
+        signer: Some(signer),
+    }
+    .publish(env);
+    Ok(())
+}
+
+/// Take commits and reveals for `session_id` back to the player's own account
+pub(crate) fn revoke_session_signer(env: &Env, player: Address, session_id: u32) -> Result<(), Error> {
+    player.require_auth();
+
+    if storage::session_signer(env, session_id, &player).is_none() {
+        return Err(Error::InvalidSessionSigner);
+    }
+    storage::remove_session_signer(env, session_id, &player);
+    SessionSignerSet {
+        session_id,
+        player,
+        signer: None,
+    }
+    .publish(env);
+    Ok(())
+}
+
+/// Whether commits in `session_id` take a deposit. Duels and team games never do;
+/// a classic game goes by its snapshot, and a session not started yet by the config.
+fn takes_deposit(env: &Env, session_id: u32) -> bool {
+    if let Ok(game) = storage::load_game(env, session_id) {
+        return game.deposit_token.is_some();
+    }
+    if storage::load_duel(env, session_id).is_ok() || storage::load_team_game(env, session_id).is_ok() {
+        return false;
+    }
+    storage::commit_deposit(env).is_some()
+}
+
+/// Auth for a commit or reveal by `player`: their session signer's if they set one
+pub(crate) fn require_move_auth(env: &Env, session_id: u32, player: &Address) {
+    match storage::session_signer(env, session_id, player) {
+        Some(signer) => signer.require_auth(),
+        None => player.require_auth(),
+    }
+}
@@ -0,0 +1,149 @@
+//! Sponsored free-to-play tier: an admin-funded token pool stakes both players in
+//! micro-wager games, so new players see a real payout before they hold any points.
+//!
+//! The Game Hub session is opened with zero points. Starting a sponsored game sets
+//! both stakes aside from the pool; the winner is credited both when the game settles,
+//! to collect with `claim_winnings`, and a draw or cancellation puts them back. Only players with few rated games
+//! qualify, each for a limited number of sponsored games per UTC day.
+
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, Env};
+
+use crate::storage;
+use crate::{game, identity, ratings, winnings};
+use crate::{Error, GameMode, SponsorTier, Sponsorship};
+
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Replace the tier, or turn it off with `None`. The token can't change while the
+/// pool still holds the old one.
+pub(crate) fn set_tier(env: &Env, tier: Option<SponsorTier>) -> Result<(), Error> {
+    if let Some(tier) = &tier {
+        if tier.stake <= 0 || tier.daily_games == 0 {
+            return Err(Error::InvalidSponsorTier);
+        }
+        let token_changed = storage::sponsor_tier(env).is_some_and(|current| current.token != tier.token);
+        if token_changed && storage::sponsor_pool(env) > 0 {
+            return Err(Error::InvalidSponsorTier);
+        }
+    }
+    storage::save_sponsor_tier(env, tier.as_ref());
+    Ok(())
+}
+
+/// Move `amount` of the tier's token from the admin into the pool
+pub(crate) fn fund(env: &Env, admin: &Address, amount: i128) -> Result<i128, Error> {
+    let tier = storage::sponsor_tier(env).ok_or(Error::NotSponsored)?;
+    if amount <= 0 {
+        return Err(Error::InvalidSponsorTier);
+    }
+    TokenClient::new(env, &tier.token).transfer(admin, env.current_contract_address(), &amount);
+    let pool = storage::sponsor_pool(env) + amount;
+    storage::save_sponsor_pool(env, pool);
+    Ok(pool)
+}
+
+/// Pay `amount` of the pool back to the admin. Stakes of games in play aren't part
+/// of the pool, so they can't be withdrawn.
+pub(crate) fn withdraw(env: &Env, admin: &Address, amount: i128) -> Result<i128, Error> {
+    let tier = storage::sponsor_tier(env).ok_or(Error::NotSponsored)?;
+    let pool = storage::sponsor_pool(env);
+    if amount <= 0 || amount > pool {
+        return Err(Error::SponsorPoolEmpty);
+    }
+    TokenClient::new(env, &tier.token).transfer(&env.current_contract_address(), admin, &amount);
+    storage::save_sponsor_pool(env, pool - amount);
+    Ok(pool - amount)
+}
+
+/// Start a classic game with both stakes put up by the pool. Both players sign.
+pub(crate) fn start_sponsored_game(
+    env: &Env,
+    session_id: u32,
+    player1: Address,
+    player2: Address,
+) -> Result<(), Error> {
+    player1.require_auth();
+    player2.require_auth();
+    if identity::primary(env, &player1) == identity::primary(env, &player2) {
+        return Err(Error::CannotChallengeSelf);
+    }
+    if storage::load_game(env, session_id).is_ok() {
+        return Err(Error::SessionTaken);
+    }
+
+    let tier = storage::sponsor_tier(env).ok_or(Error::NotSponsored)?;
+    let day = env.ledger().timestamp() / SECS_PER_DAY;
+    for player in [&player1, &player2] {
+        if !is_new(env, &tier, player) {
+            return Err(Error::NotSponsored);
+        }
+        if games_today(env, player, day) >= tier.daily_games {
+            return Err(Error::SponsorLimitReached);
+        }
+    }
+    let pool = storage::sponsor_pool(env);
+    if pool < tier.stake * 2 {
+        return Err(Error::SponsorPoolEmpty);
+    }
+
+    game::open_hub_session(env, session_id, &player1, &player2, 0, 0)?;
+    storage::save_sponsor_pool(env, pool - tier.stake * 2);
+    storage::save_sponsorship(
+        env,
+        session_id,
+        &Sponsorship {
+            token: tier.token,
+            stake: tier.stake,
+        },
+    );
+    for player in [&player1, &player2] {
+        storage::save_sponsored_games(env, player, day, games_today(env, player, day) + 1);
+    }
+    game::create_game(env, session_id, player1, player2, 0, 0, GameMode::Classic);
+    Ok(())
+}
+
+/// Sponsored games `player` can still start today; 0 if they don't qualify
+pub(crate) fn games_left(env: &Env, player: &Address) -> u32 {
+    let Some(tier) = storage::sponsor_tier(env) else {
+        return 0;
+    };
+    if !is_new(env, &tier, player) {
+        return 0;
+    }
+    let day = env.ledger().timestamp() / SECS_PER_DAY;
+    tier.daily_games.saturating_sub(games_today(env, player, day))
+}
+
+/// Credit a sponsored game's stakes to `winner`, or put them back into the pool on a draw or
+/// cancellation. Nothing to do for other games.
+pub(crate) fn settle(env: &Env, session_id: u32, winner: Option<&Address>) {
+    let Some(sponsorship) = storage::sponsorship(env, session_id) else {
+        return;
+    };
+    storage::remove_sponsorship(env, session_id);
+    let pot = sponsorship.stake * 2;
+    match winner {
+        Some(winner) => winnings::credit(env, session_id, winner, &sponsorship.token, pot),
+        // Owed to the admin instead if the pool has since moved to another token
+        None => match storage::sponsor_tier(env) {
+            Some(tier) if tier.token == sponsorship.token => {
+                storage::save_sponsor_pool(env, storage::sponsor_pool(env) + pot);
+            }
+            _ => winnings::credit(env, session_id, &storage::admin(env), &sponsorship.token, pot),
+        },
+    }
+}
+
+fn is_new(env: &Env, tier: &SponsorTier, player: &Address) -> bool {
+    let stats = ratings::player_stats(env, player);
+    stats.wins + stats.losses + stats.draws <= tier.max_rated_games
+}
+
+fn games_today(env: &Env, player: &Address, day: u64) -> u32 {
+    match storage::sponsored_games(env, player) {
+        Some((games_day, games)) if games_day == day => games,
+        _ => 0,
+    }
+}
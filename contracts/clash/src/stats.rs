@@ -0,0 +1,39 @@
+//! Contract-wide battle telemetry: game and draw counts, how often each attack is
+//! picked and how long battles last, so balance can be watched on-chain without an
+//! indexer. Updated once per battle when `resolve_battle` settles it.
+
+use soroban_sdk::Env;
+
+use crate::storage;
+use crate::{Attack, Game, GameMode};
+
+/// Fold a resolved battle into the global stats
+pub(crate) fn record_battle(env: &Env, game: &Game) {
+    let mut stats = storage::global_stats(env);
+    let turns_played = game.battle_result.turn_results.len();
+
+    stats.total_games += 1;
+    if game.battle_result.is_draw {
+        stats.total_draws += 1;
+    }
+
+    // Only attacks actually thrown: turns after a knockout never happen, and the
+    // siege defender never attacks
+    let mut attacks = game.player1_commitment.moves.moves.slice(..turns_played);
+    if game.mode != GameMode::Siege {
+        attacks.append(&game.player2_commitment.moves.moves.slice(..turns_played));
+    }
+    for action in attacks.iter() {
+        match action.attack {
+            Attack::Slash => stats.slash_count += 1,
+            Attack::Fireball => stats.fireball_count += 1,
+            Attack::Lightning => stats.lightning_count += 1,
+            Attack::Broadside => stats.broadside_count += 1,
+            Attack::Hook => stats.hook_count += 1,
+        }
+    }
+
+    stats.total_turns += turns_played as u64;
+    stats.average_turns_x100 = stats.total_turns * 100 / stats.total_games;
+    storage::save_global_stats(env, &stats);
+}
@@ -0,0 +1,1817 @@
+//! Storage keys, TTLs and typed accessors shared by the contract modules.
+
+use soroban_sdk::{contracttype, vec, Address, BytesN, Env, Map, String, Symbol, Val, Vec};
+
+use crate::activity;
+use crate::registry;
+use crate::agreed_pause;
+use crate::migration;
+use crate::referral;
+use crate::{
+    ActivityKind, ActivityLog, ArchivedGame, Badges, BracketMatch, CaptainClass, EarnedAchievement, Challenge, CommitDeposit, CommitMode, CommitReceipt, DetailedTurnResult, Duel, Error, EscrowReceipt, FeeTier, Game, GlobalStats, HeadToHead, GameConfig, GameMode, GamePause, GameProposal, InviteCode, MatchSeries, OpenChallenge, PauseFlags, PendingSettlement, PickStats, PlayerStats, PracticeDifficulty, PracticeGame, PracticeStats, Prediction, PredictionPool, PrizeVesting, PromoCode, PvPMatch, RaiseConfig, RankedPlayer, RatingDecay, ReferralStats, Rematch, RivalrySeries, RuleSet, Season, SeasonPlacement, SeasonResults, SeasonRewards, SessionRecord, SiegeStats, SpectatorPick, SponsorTier, Sponsorship, TeamGame, Tournament, TournamentPrizes, VerifierEntry, VestedPrize, WagerLimits, DEFAULT_CRIT_CHANCE_BPS, DEFAULT_CRIT_MULTIPLIER_PCT, DEFAULT_INITIATIVE_BONUS, DEFAULT_MAX_WAGER, DEFAULT_REVEAL_WINDOW_SECS,
+};
+
+/// TTL for game storage (30 days in ledgers)
+pub(crate) const GAME_TTL_LEDGERS: u32 = 518_400;
+
+/// TTL for challenges (7 days in ledgers)
+pub(crate) const CHALLENGE_TTL_LEDGERS: u32 = 120_960;
+
+/// Default time a spent nullifier is remembered (the same 30 days as a game)
+pub(crate) const DEFAULT_NULLIFIER_RETENTION_LEDGERS: u32 = GAME_TTL_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Game(u32),
+    GameHubAddress,
+    Admin,
+    Verifier(u32),               // Circuit version -> VerifierEntry
+    CircuitVersion,              // Version new games are pinned to; 1 if unset
+    PredictionToken,             // Token spectators stake on games; predictions off if unset
+    PredictionPool(u32),         // Session ID -> PredictionPool
+    Prediction(u32, Address),    // (Session ID, predictor) -> Prediction
+    Picks(u32),                  // Session ID -> Vec<SpectatorPick> until the game is scored
+    PickStats(Address),          // Spectator -> PickStats
+    Season,                      // The running season, or the last one once ended
+    SeasonStats(u32, Address),   // (Season ID, player) -> PlayerStats for that season
+    SeasonLeaderboard(u32),      // Season ID -> Vec<RankedPlayer> while it runs
+    SeasonResults(u32),          // Season ID -> SeasonResults, once ended
+    SeasonRewards,               // SeasonRewards paid when a season ends; none if unset
+    SeasonPlacement(u32, Address), // (Season ID, player) -> SeasonPlacement
+    Achievements(Address),       // Address -> Vec<EarnedAchievement>, oldest first
+    DailyBonusDay(Address),      // Address -> day their last daily win bonus was paid
+    BonusPoints(Address),        // Address -> i128 bonus points not yet settled to the Game Hub
+    SponsorTier,                 // SponsorTier for sponsored games; none if unset
+    SponsorPool,                 // i128 of the tier's token free for new sponsored games
+    Sponsorship(u32),            // Session ID -> Sponsorship, until the game settles
+    GamePause(u32),              // Session ID -> GamePause of a correspondence game
+    MaxGamePause,                // u64 most secs a game can spend paused in total
+    Referrer(Address),           // Address -> referrer, until their first battle resolves
+    ReferralStats(Address),      // Referrer -> ReferralStats
+    ReferralBonus,               // i128 CSH credited per completed referral
+    SponsoredGames(Address),     // Address -> (day, sponsored games started that day)
+    HubLastOk,                   // Timestamp the Game Hub last locked or released stakes
+    Username(Address),           // Address -> Username
+    AddressByUsername(String),   // Username -> Address
+    LastActiveAt(Address),       // Address -> last battle or username claim, for name expiry
+    UsernameExpiry,              // u64 inactivity secs after which names are claimable; 0 = never
+    CaptainClass(Address),       // Address -> CaptainClass picked for new games
+    LinkedTo(Address),           // Secondary address -> the primary it plays as
+    LinkedAddresses(Address),    // Primary address -> Vec<Address> of its secondaries
+    LinkRequest(Address),        // Secondary address -> primary waiting for it to confirm
+    Challenge(u32),              // Challenge ID -> Challenge
+    ChallengeCounter,            // Counter for challenge IDs
+    PlayerChallenges(Address),   // Address -> Vec<challenge_id>
+    Match(u32),                  // Match ID -> PvPMatch
+    MatchCounter,                // Counter for match IDs
+    PlayerMatches(Address),      // Address -> Vec<match_id>
+    TokenContractAddress,
+    BuildInfo,
+    StorageVersion,              // u32 layout stored entries are on; absent before versioning
+    MigrationCursor,             // u32 progress of the running migration step
+    MigrationStartedAt,          // u64 when the step from version 0 started; stands in for legacy start times
+    Duel(u32),                   // Session ID -> Duel
+    SiegeStats(Address),         // Address -> SiegeStats
+    PracticeCounter,             // Counter for practice game IDs
+    PracticeGame(u32),           // Practice ID -> PracticeGame
+    PracticeStats(Address, PracticeDifficulty), // (player, tier) -> PracticeStats
+    WagerLimits,
+    CommitDeposit,
+    RequireRegistered,
+    PendingSettlement(u32),      // Session ID -> PendingSettlement
+    SettlementDisputed(u32),     // Session ID -> true once a signed result was disputed
+    SettlementKey(Address),      // Address -> ed25519 public key
+    GameConfig,
+    RaiseConfig,
+    InviteCode(u64),             // Code -> InviteCode, removed once redeemed
+    GameProposal(u32),           // Session ID -> GameProposal, removed once confirmed
+    Queue(u32),                  // Lobby tier -> Vec<Address> waiting, oldest first
+    QueuedTier(Address),         // Address -> lobby tier it is waiting in
+    Escrow(u32),                 // Session ID -> EscrowReceipt
+    FeeSchedule,
+    OpenChallenge(u32),          // Challenge ID -> OpenChallenge, until accepted
+    OpenChallenges,              // Vec<challenge_id> on the open board, oldest first
+    Friends(Address),            // Address -> Vec<Address> of friends, in the order added
+    LookingForGame(Address),     // Address -> true while they flag themselves as looking for a game
+    GlobalStats,
+    PlayerStats(Address),        // Address -> PlayerStats
+    Leaderboard,                 // Vec<RankedPlayer>, highest rating first
+    RatingHistogram,             // Vec<u32> count of rated players per rating bucket
+    RatingDecay,                 // RatingDecay for inactive players; off if unset
+    DecaySince(Address),         // Address -> u64 time decay counts from: last rated game or decay applied
+    MatchHistory(Address),       // Address -> Vec<ArchivedGame>, newest first
+    MatchNote(Address, u32),     // (player, session ID) -> note hash
+    Rival(Address),              // Address -> the rival they named
+    HeadToHead(Address, Address), // (lower, higher address) -> lower's HeadToHead
+    CollectedFees(Address),      // Token -> fees withdrawable by the admin
+    Treasury,                    // Address `sweep_fees` pays collected fees to
+    Moderator,                   // Address that sets shadow flags; the admin if unset
+    Shadowed(Address),           // Primary address -> true while shadowed
+    Pause,                       // PauseFlags set by the admin
+    Winnings(u32, Address),      // (Session ID, player) -> token -> claimable amount
+    PromoCode(Symbol),           // Code -> PromoCode
+    PromoRedeemed(Symbol, Address), // Set once the player has redeemed the code
+    FeeWaivers(Address),         // Address -> fee-free games left
+    DepositBonus(Address),       // Address -> CSH minted with their next commit deposit
+    PendingResolutions,          // Vec<session_id> fully revealed but not yet resolved
+    ChallengeBySession(u32),     // Session ID -> the accepted challenge that started it
+    SessionSigner(u32, Address), // (Session ID, player) -> key allowed to commit and reveal for them
+    PlaybackTurns(u32),          // Session ID -> Vec<DetailedTurnResult>, written at resolve
+    Series(u32),                 // Series (hub session) ID -> MatchSeries
+    SeriesBySession(u32),        // Round session ID -> its series
+    PlayerSessions(Address),     // Address -> Vec<session_id> of live games and duels
+    Badges(Address),             // Address -> Badges
+    BadgeOwed(u32),              // Session ID -> players it counts as awaiting (bit 0 player1, bit 1 player2)
+    ActivityLog(Address),        // Address -> ActivityLog for delta sync
+    SessionBucket(u32),          // Ledger sequence / registry::BUCKET_LEDGERS -> u32 pages written to it
+    SessionPage(u32, u32),       // (Registry bucket, page) -> Vec<SessionRecord>
+    TournamentCounter,
+    Tournament(u32),             // Tournament ID -> Tournament
+    Bracket(u32),                // Tournament ID -> Vec<BracketMatch>, in bracket order
+    TournamentPrizes(u32),       // Tournament ID -> TournamentPrizes, once the organizer puts a pool up
+    TournamentPrize(u32, Address), // (Tournament ID, player) -> prize token amount to claim
+    PrizeVesting(u32),           // Tournament ID -> PrizeVesting, if its large prizes vest
+    VestedPrize(u32, Address),   // (Tournament ID, player) -> VestedPrize still locked
+    NullifierRetention,          // Ledgers a spent nullifier is kept for
+    Nullifier(BytesN<32>),       // sha256 of spent commit public inputs -> unit
+    RuleSet,                     // Combat rules for new games; RuleSet::DEFAULT if unset
+    TeamGame(u32),               // Session ID -> TeamGame
+    CommitReceipt(u32, Address), // (Session ID, player) -> CommitReceipt
+    Rematch(u32),                // Finished session ID -> Rematch offered on it
+    RematchOf(u32),              // Rematch session ID -> the session it replays
+    RivalrySeries(Address, Address), // (lower, higher address) -> their current grudge series
+    LastOpponent(Address),       // Address -> opponent in their last settled game
+    MovePauses,                  // Vec<(start, end)> of recent pauses that stopped moves; end 0 while on
+}
+
+// ============================================================================
+// Instance Config
+// ============================================================================
+
+pub(crate) fn admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .expect("Admin not set")
+}
+
+pub(crate) fn game_hub(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::GameHubAddress)
+        .expect("GameHub address not set")
+}
+
+pub(crate) fn verifier_entry(env: &Env, version: u32) -> Option<VerifierEntry> {
+    env.storage().instance().get(&DataKey::Verifier(version))
+}
+
+pub(crate) fn set_verifier_entry(env: &Env, version: u32, entry: &VerifierEntry) {
+    env.storage().instance().set(&DataKey::Verifier(version), entry);
+}
+
+/// The verifier for `version`, if it is registered and not deprecated
+pub(crate) fn verifier(env: &Env, version: u32) -> Result<Address, Error> {
+    match verifier_entry(env, version) {
+        None => Err(Error::UnknownCircuitVersion),
+        Some(entry) if entry.deprecated => Err(Error::CircuitDeprecated),
+        Some(entry) => Ok(entry.address),
+    }
+}
+
+pub(crate) fn circuit_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::CircuitVersion)
+        .unwrap_or(1)
+}
+
+pub(crate) fn set_circuit_version(env: &Env, version: u32) {
+    env.storage().instance().set(&DataKey::CircuitVersion, &version);
+}
+
+pub(crate) fn hub_last_ok_at(env: &Env) -> u64 {
+    env.storage().instance().get(&DataKey::HubLastOk).unwrap_or(0)
+}
+
+pub(crate) fn save_hub_last_ok_at(env: &Env, timestamp: u64) {
+    env.storage().instance().set(&DataKey::HubLastOk, &timestamp);
+}
+
+pub(crate) fn token_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::TokenContractAddress)
+}
+
+pub(crate) fn require_registered(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::RequireRegistered)
+        .unwrap_or(false)
+}
+
+pub(crate) fn commit_deposit(env: &Env) -> Option<CommitDeposit> {
+    env.storage().instance().get(&DataKey::CommitDeposit)
+}
+
+pub(crate) fn game_config(env: &Env) -> GameConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::GameConfig)
+        .unwrap_or(GameConfig {
+            initiative_bonus: DEFAULT_INITIATIVE_BONUS,
+            reveal_window_secs: DEFAULT_REVEAL_WINDOW_SECS,
+            crit_chance_bps: DEFAULT_CRIT_CHANCE_BPS,
+            crit_multiplier_pct: DEFAULT_CRIT_MULTIPLIER_PCT,
+            commit_mode: CommitMode::Zk,
+        })
+}
+
+pub(crate) fn rule_set(env: &Env) -> RuleSet {
+    env.storage()
+        .instance()
+        .get(&DataKey::RuleSet)
+        .unwrap_or(RuleSet::DEFAULT)
+}
+
+pub(crate) fn save_rule_set(env: &Env, rules: &RuleSet) {
+    env.storage().instance().set(&DataKey::RuleSet, rules);
+}
+
+pub(crate) fn raise_config(env: &Env) -> Option<RaiseConfig> {
+    env.storage().instance().get(&DataKey::RaiseConfig)
+}
+
+pub(crate) fn fee_schedule(env: &Env) -> Vec<FeeTier> {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeSchedule)
+        .unwrap_or(vec![env])
+}
+
+pub(crate) fn collected_fees(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::CollectedFees(token.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn save_collected_fees(env: &Env, token: &Address, amount: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::CollectedFees(token.clone()), &amount);
+}
+
+pub(crate) fn treasury(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Treasury)
+}
+
+pub(crate) fn moderator(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Moderator)
+}
+
+pub(crate) fn shadowed(env: &Env, player: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Shadowed(player.clone()))
+        .unwrap_or(false)
+}
+
+/// Cleared flags are removed rather than stored as false
+pub(crate) fn save_shadowed(env: &Env, player: &Address, shadowed: bool) {
+    let key = DataKey::Shadowed(player.clone());
+    if shadowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub(crate) fn pause_flags(env: &Env) -> PauseFlags {
+    env.storage()
+        .instance()
+        .get(&DataKey::Pause)
+        .unwrap_or_default()
+}
+
+pub(crate) fn save_pause_flags(env: &Env, flags: &PauseFlags) {
+    env.storage().instance().set(&DataKey::Pause, flags);
+}
+
+pub(crate) fn move_pauses(env: &Env) -> Vec<(u64, u64)> {
+    env.storage()
+        .instance()
+        .get(&DataKey::MovePauses)
+        .unwrap_or(vec![env])
+}
+
+pub(crate) fn save_move_pauses(env: &Env, pauses: &Vec<(u64, u64)>) {
+    env.storage().instance().set(&DataKey::MovePauses, pauses);
+}
+
+pub(crate) fn global_stats(env: &Env) -> GlobalStats {
+    env.storage()
+        .instance()
+        .get(&DataKey::GlobalStats)
+        .unwrap_or_default()
+}
+
+pub(crate) fn save_global_stats(env: &Env, stats: &GlobalStats) {
+    env.storage().instance().set(&DataKey::GlobalStats, stats);
+}
+
+pub(crate) fn wager_limits(env: &Env) -> WagerLimits {
+    env.storage()
+        .instance()
+        .get(&DataKey::WagerLimits)
+        .unwrap_or(WagerLimits {
+            min: 0,
+            max: DEFAULT_MAX_WAGER,
+        })
+}
+
+// ============================================================================
+// Games
+// ============================================================================
+
+/// Correspondence games can run for weeks, so they live in persistent storage;
+/// every other game is temporary
+/// Read in whichever layout it was stored in (see `migration::read_game`)
+pub(crate) fn load_game(env: &Env, session_id: u32) -> Result<Game, Error> {
+    let game_key = DataKey::Game(session_id);
+    let stored: Val = env
+        .storage()
+        .temporary()
+        .get(&game_key)
+        .or_else(|| env.storage().persistent().get(&game_key))
+        .ok_or(Error::GameNotFound)?;
+    Ok(migration::read_game(env, stored))
+}
+
+pub(crate) fn save_game(env: &Env, session_id: u32, game: &Game) {
+    let game_key = DataKey::Game(session_id);
+    if game.mode == GameMode::Correspondence {
+        env.storage().persistent().set(&game_key, game);
+    } else {
+        env.storage().temporary().set(&game_key, game);
+    }
+    activity::touch(env, &game.player1, ActivityKind::Game, session_id);
+    activity::touch(env, &game.player2, ActivityKind::Game, session_id);
+}
+
+/// Store a freshly created game and give it the full game TTL
+pub(crate) fn store_new_game(env: &Env, session_id: u32, game: &Game) {
+    let game_key = DataKey::Game(session_id);
+    save_game(env, session_id, game);
+    if game.mode == GameMode::Correspondence {
+        env.storage()
+            .persistent()
+            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    } else {
+        env.storage()
+            .temporary()
+            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+    registry::record(env, ActivityKind::Game, session_id);
+}
+
+pub(crate) fn pending_resolutions(env: &Env) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PendingResolutions)
+        .unwrap_or(vec![env])
+}
+
+pub(crate) fn save_pending_resolutions(env: &Env, session_ids: &Vec<u32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PendingResolutions, session_ids);
+}
+
+/// A pending settlement lives in temporary storage alongside its game
+pub(crate) fn pending_settlement(env: &Env, session_id: u32) -> Option<PendingSettlement> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::PendingSettlement(session_id))
+}
+
+pub(crate) fn save_pending_settlement(env: &Env, session_id: u32, pending: &PendingSettlement) {
+    let key = DataKey::PendingSettlement(session_id);
+    env.storage().temporary().set(&key, pending);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn remove_pending_settlement(env: &Env, session_id: u32) {
+    env.storage()
+        .temporary()
+        .remove(&DataKey::PendingSettlement(session_id));
+}
+
+pub(crate) fn settlement_disputed(env: &Env, session_id: u32) -> bool {
+    env.storage()
+        .temporary()
+        .has(&DataKey::SettlementDisputed(session_id))
+}
+
+pub(crate) fn set_settlement_disputed(env: &Env, session_id: u32) {
+    let key = DataKey::SettlementDisputed(session_id);
+    env.storage().temporary().set(&key, &true);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Escrow receipts live in temporary storage with the same TTL as a game
+pub(crate) fn escrow(env: &Env, session_id: u32) -> Option<EscrowReceipt> {
+    env.storage().temporary().get(&DataKey::Escrow(session_id))
+}
+
+pub(crate) fn save_escrow(env: &Env, receipt: &EscrowReceipt) {
+    let key = DataKey::Escrow(receipt.session_id);
+    env.storage().temporary().set(&key, receipt);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Commit receipts live in temporary storage with the same TTL as a game
+pub(crate) fn commit_receipt(env: &Env, session_id: u32, player: &Address) -> Option<CommitReceipt> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::CommitReceipt(session_id, player.clone()))
+}
+
+pub(crate) fn save_commit_receipt(env: &Env, receipt: &CommitReceipt) {
+    let key = DataKey::CommitReceipt(receipt.session_id, receipt.player.clone());
+    env.storage().temporary().set(&key, receipt);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Rematch offers and links live in temporary storage with the same TTL as a game,
+/// so playback can follow them for as long as the games themselves exist
+pub(crate) fn rematch(env: &Env, session_id: u32) -> Option<Rematch> {
+    env.storage().temporary().get(&DataKey::Rematch(session_id))
+}
+
+pub(crate) fn save_rematch(env: &Env, rematch: &Rematch) {
+    let key = DataKey::Rematch(rematch.session_id);
+    env.storage().temporary().set(&key, rematch);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn rematch_of(env: &Env, session_id: u32) -> Option<u32> {
+    env.storage().temporary().get(&DataKey::RematchOf(session_id))
+}
+
+pub(crate) fn save_rematch_of(env: &Env, session_id: u32, previous_session_id: u32) {
+    let key = DataKey::RematchOf(session_id);
+    env.storage().temporary().set(&key, &previous_session_id);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Claimable winnings are persistent: they are owed funds and must not expire
+/// with the game
+pub(crate) fn winnings(env: &Env, session_id: u32, player: &Address) -> Map<Address, i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Winnings(session_id, player.clone()))
+        .unwrap_or(Map::new(env))
+}
+
+pub(crate) fn save_winnings(env: &Env, session_id: u32, player: &Address, owed: &Map<Address, i128>) {
+    let key = DataKey::Winnings(session_id, player.clone());
+    env.storage().persistent().set(&key, owed);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn remove_winnings(env: &Env, session_id: u32, player: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Winnings(session_id, player.clone()));
+}
+
+pub(crate) fn prediction_token(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PredictionToken)
+}
+
+/// Prediction pools and stakes are persistent, like winnings: they are owed funds
+pub(crate) fn prediction_pool(env: &Env, session_id: u32) -> Option<PredictionPool> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PredictionPool(session_id))
+}
+
+pub(crate) fn save_prediction_pool(env: &Env, pool: &PredictionPool) {
+    let key = DataKey::PredictionPool(pool.session_id);
+    env.storage().persistent().set(&key, pool);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn prediction(env: &Env, session_id: u32, predictor: &Address) -> Option<Prediction> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Prediction(session_id, predictor.clone()))
+}
+
+pub(crate) fn save_prediction(env: &Env, session_id: u32, predictor: &Address, prediction: &Prediction) {
+    let key = DataKey::Prediction(session_id, predictor.clone());
+    env.storage().persistent().set(&key, prediction);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn remove_prediction(env: &Env, session_id: u32, predictor: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Prediction(session_id, predictor.clone()));
+}
+
+pub(crate) fn picks(env: &Env, session_id: u32) -> Vec<SpectatorPick> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Picks(session_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn save_picks(env: &Env, session_id: u32, picks: &Vec<SpectatorPick>) {
+    let key = DataKey::Picks(session_id);
+    if picks.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, picks);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn pick_stats(env: &Env, spectator: &Address) -> PickStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PickStats(spectator.clone()))
+        .unwrap_or_default()
+}
+
+pub(crate) fn save_pick_stats(env: &Env, spectator: &Address, stats: &PickStats) {
+    let key = DataKey::PickStats(spectator.clone());
+    env.storage().persistent().set(&key, stats);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn settlement_key(env: &Env, player: &Address) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SettlementKey(player.clone()))
+}
+
+pub(crate) fn save_settlement_key(env: &Env, player: &Address, public_key: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SettlementKey(player.clone()), public_key);
+}
+
+pub(crate) fn siege_stats(env: &Env, player: &Address) -> SiegeStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SiegeStats(player.clone()))
+        .unwrap_or_default()
+}
+
+pub(crate) fn save_siege_stats(env: &Env, player: &Address, stats: &SiegeStats) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SiegeStats(player.clone()), stats);
+}
+
+pub(crate) fn next_practice_id(env: &Env) -> u32 {
+    let practice_id: u32 = env.storage().instance().get(&DataKey::PracticeCounter).unwrap_or(0);
+    env.storage().instance().set(&DataKey::PracticeCounter, &(practice_id + 1));
+    practice_id
+}
+
+pub(crate) fn practice_game(env: &Env, practice_id: u32) -> Option<PracticeGame> {
+    env.storage().temporary().get(&DataKey::PracticeGame(practice_id))
+}
+
+pub(crate) fn save_practice_game(env: &Env, practice_id: u32, practice: &PracticeGame) {
+    env.storage()
+        .temporary()
+        .set(&DataKey::PracticeGame(practice_id), practice);
+}
+
+/// Store a new practice game for as long as a regular game lives
+pub(crate) fn store_new_practice_game(env: &Env, practice_id: u32, practice: &PracticeGame) {
+    let key = DataKey::PracticeGame(practice_id);
+    env.storage().temporary().set(&key, practice);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn practice_stats(env: &Env, player: &Address, difficulty: PracticeDifficulty) -> PracticeStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PracticeStats(player.clone(), difficulty))
+        .unwrap_or_default()
+}
+
+pub(crate) fn save_practice_stats(
+    env: &Env,
+    player: &Address,
+    difficulty: PracticeDifficulty,
+    stats: &PracticeStats,
+) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PracticeStats(player.clone(), difficulty), stats);
+}
+
+pub(crate) fn player_sessions(env: &Env, player: &Address) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PlayerSessions(player.clone()))
+        .unwrap_or(vec![env])
+}
+
+pub(crate) fn save_player_sessions(env: &Env, player: &Address, session_ids: &Vec<u32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PlayerSessions(player.clone()), session_ids);
+}
+
+pub(crate) fn badges(env: &Env, player: &Address) -> Badges {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Badges(player.clone()))
+        .unwrap_or_default()
+}
+
+pub(crate) fn save_badges(env: &Env, player: &Address, badges: &Badges) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Badges(player.clone()), badges);
+}
+
+pub(crate) fn badge_owed(env: &Env, session_id: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BadgeOwed(session_id))
+        .unwrap_or(0)
+}
+
+pub(crate) fn save_badge_owed(env: &Env, session_id: u32, owed: u32) {
+    let key = DataKey::BadgeOwed(session_id);
+    if owed == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, &owed);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn session_bucket_pages(env: &Env, bucket: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SessionBucket(bucket))
+        .unwrap_or(0)
+}
+
+pub(crate) fn save_session_bucket_pages(env: &Env, bucket: u32, pages: u32) {
+    let key = DataKey::SessionBucket(bucket);
+    env.storage().persistent().set(&key, &pages);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn session_page(env: &Env, bucket: u32, page: u32) -> Vec<SessionRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SessionPage(bucket, page))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn save_session_page(env: &Env, bucket: u32, page: u32, sessions: &Vec<SessionRecord>) {
+    let key = DataKey::SessionPage(bucket, page);
+    env.storage().persistent().set(&key, sessions);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn activity_log(env: &Env, player: &Address) -> ActivityLog {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ActivityLog(player.clone()))
+        .unwrap_or(ActivityLog {
+            last_activity_seq: 0,
+            dropped_seq: 0,
+            changes: vec![env],
+        })
+}
+
+pub(crate) fn save_activity_log(env: &Env, player: &Address, log: &ActivityLog) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ActivityLog(player.clone()), log);
+}
+
+/// Series are persistent: the hub session stays open until the last round, however
+/// long the rounds take between them
+pub(crate) fn series(env: &Env, series_id: u32) -> Option<MatchSeries> {
+    env.storage().persistent().get(&DataKey::Series(series_id))
+}
+
+pub(crate) fn save_series(env: &Env, series_id: u32, series: &MatchSeries) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Series(series_id), series);
+}
+
+pub(crate) fn series_by_session(env: &Env, session_id: u32) -> Option<u32> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::SeriesBySession(session_id))
+}
+
+/// Lives as long as the round's game
+pub(crate) fn save_series_by_session(env: &Env, session_id: u32, series_id: u32) {
+    let key = DataKey::SeriesBySession(session_id);
+    env.storage().temporary().set(&key, &series_id);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+// ============================================================================
+// Duels
+// ============================================================================
+
+pub(crate) fn load_duel(env: &Env, session_id: u32) -> Result<Duel, Error> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::Duel(session_id))
+        .ok_or(Error::GameNotFound)
+}
+
+pub(crate) fn save_duel(env: &Env, session_id: u32, duel: &Duel) {
+    env.storage().temporary().set(&DataKey::Duel(session_id), duel);
+    activity::touch(env, &duel.player1, ActivityKind::Duel, session_id);
+    activity::touch(env, &duel.player2, ActivityKind::Duel, session_id);
+}
+
+/// Store a freshly created duel and give it the full game TTL
+pub(crate) fn store_new_duel(env: &Env, session_id: u32, duel: &Duel) {
+    let duel_key = DataKey::Duel(session_id);
+    save_duel(env, session_id, duel);
+    env.storage()
+        .temporary()
+        .extend_ttl(&duel_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    registry::record(env, ActivityKind::Duel, session_id);
+}
+
+// ============================================================================
+// Team Games
+// ============================================================================
+
+pub(crate) fn load_team_game(env: &Env, session_id: u32) -> Result<TeamGame, Error> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::TeamGame(session_id))
+        .ok_or(Error::GameNotFound)
+}
+
+pub(crate) fn save_team_game(env: &Env, session_id: u32, team_game: &TeamGame) {
+    env.storage()
+        .temporary()
+        .set(&DataKey::TeamGame(session_id), team_game);
+    for player in team_game.team1.iter().chain(team_game.team2.iter()) {
+        activity::touch(env, &player, ActivityKind::TeamGame, session_id);
+    }
+}
+
+/// Store a freshly created team game and give it the full game TTL
+pub(crate) fn store_new_team_game(env: &Env, session_id: u32, team_game: &TeamGame) {
+    let key = DataKey::TeamGame(session_id);
+    save_team_game(env, session_id, team_game);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    registry::record(env, ActivityKind::TeamGame, session_id);
+}
+
+pub(crate) fn storage_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::StorageVersion)
+        .unwrap_or(0)
+}
+
+pub(crate) fn save_storage_version(env: &Env, version: u32) {
+    env.storage().instance().set(&DataKey::StorageVersion, &version);
+}
+
+pub(crate) fn migration_cursor(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MigrationCursor)
+        .unwrap_or(0)
+}
+
+pub(crate) fn save_migration_cursor(env: &Env, cursor: u32) {
+    env.storage().instance().set(&DataKey::MigrationCursor, &cursor);
+}
+
+pub(crate) fn migration_started_at(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&DataKey::MigrationStartedAt)
+}
+
+pub(crate) fn save_migration_started_at(env: &Env, timestamp: u64) {
+    env.storage().instance().set(&DataKey::MigrationStartedAt, &timestamp);
+}
+
+// ============================================================================
+// Challenges
+// ============================================================================
+
+pub(crate) fn challenge_counter(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ChallengeCounter)
+        .unwrap_or(0)
+}
+
+/// Get and increment the challenge counter
+pub(crate) fn next_challenge_id(env: &Env) -> u32 {
+    let challenge_id = challenge_counter(env);
+    env.storage().instance().set(&DataKey::ChallengeCounter, &(challenge_id + 1));
+    challenge_id
+}
+
+/// Read in whichever layout it was stored in (see `migration::read_challenge`)
+pub(crate) fn load_challenge(env: &Env, challenge_id: u32) -> Result<Challenge, Error> {
+    let stored: Val = env
+        .storage()
+        .temporary()
+        .get(&DataKey::Challenge(challenge_id))
+        .ok_or(Error::ChallengeNotFound)?;
+    Ok(migration::read_challenge(env, stored))
+}
+
+/// Store a challenge read from an old layout back in the current one, keeping its
+/// TTL and activity as they were
+pub(crate) fn rewrite_challenge(env: &Env, challenge: &Challenge) {
+    env.storage()
+        .temporary()
+        .set(&DataKey::Challenge(challenge.challenge_id), challenge);
+}
+
+/// Forwarded challenges stay in the original player's list, so their activity too
+pub(crate) fn save_challenge(env: &Env, challenge: &Challenge) {
+    env.storage()
+        .temporary()
+        .set(&DataKey::Challenge(challenge.challenge_id), challenge);
+    let id = challenge.challenge_id;
+    activity::touch(env, &challenge.challenger, ActivityKind::Challenge, id);
+    activity::touch(env, &challenge.challenged, ActivityKind::Challenge, id);
+    if let Some(forwarded_from) = &challenge.forwarded_from {
+        activity::touch(env, forwarded_from, ActivityKind::Challenge, id);
+    }
+}
+
+/// Store a freshly created challenge and give it the full challenge TTL
+pub(crate) fn store_new_challenge(env: &Env, challenge: &Challenge) {
+    let challenge_key = DataKey::Challenge(challenge.challenge_id);
+    save_challenge(env, challenge);
+    env.storage()
+        .temporary()
+        .extend_ttl(&challenge_key, CHALLENGE_TTL_LEDGERS, CHALLENGE_TTL_LEDGERS);
+}
+
+pub(crate) fn challenge_by_session(env: &Env, session_id: u32) -> Option<u32> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::ChallengeBySession(session_id))
+}
+
+/// Lives as long as the game it points from, so resolution can always find it
+pub(crate) fn save_challenge_by_session(env: &Env, session_id: u32, challenge_id: u32) {
+    let key = DataKey::ChallengeBySession(session_id);
+    env.storage().temporary().set(&key, &challenge_id);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn session_signer(env: &Env, session_id: u32, player: &Address) -> Option<Address> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::SessionSigner(session_id, player.clone()))
+}
+
+/// Lives as long as a game, so the signer lasts the whole session
+pub(crate) fn save_session_signer(env: &Env, session_id: u32, player: &Address, signer: &Address) {
+    let key = DataKey::SessionSigner(session_id, player.clone());
+    env.storage().temporary().set(&key, signer);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn remove_session_signer(env: &Env, session_id: u32, player: &Address) {
+    env.storage()
+        .temporary()
+        .remove(&DataKey::SessionSigner(session_id, player.clone()));
+}
+
+pub(crate) fn playback_turns(env: &Env, session_id: u32) -> Option<Vec<DetailedTurnResult>> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::PlaybackTurns(session_id))
+}
+
+/// Lives as long as the game it replays
+pub(crate) fn save_playback_turns(env: &Env, session_id: u32, turns: &Vec<DetailedTurnResult>) {
+    let key = DataKey::PlaybackTurns(session_id);
+    env.storage().temporary().set(&key, turns);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn player_challenges(env: &Env, player: &Address) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, Vec<u32>>(&DataKey::PlayerChallenges(player.clone()))
+        .unwrap_or(vec![env])
+}
+
+pub(crate) fn save_player_challenges(env: &Env, player: &Address, challenges: &Vec<u32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PlayerChallenges(player.clone()), challenges);
+}
+
+pub(crate) fn remove_player_challenge(env: &Env, player: &Address, challenge_id: u32) {
+    let mut challenges = player_challenges(env, player);
+    if let Some(index) = challenges.first_index_of(challenge_id) {
+        challenges.remove(index);
+        save_player_challenges(env, player, &challenges);
+    }
+}
+
+pub(crate) fn load_open_challenge(env: &Env, challenge_id: u32) -> Option<OpenChallenge> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::OpenChallenge(challenge_id))
+}
+
+/// Store a new open challenge; it lives as long as a regular challenge
+pub(crate) fn store_new_open_challenge(env: &Env, open: &OpenChallenge) {
+    let key = DataKey::OpenChallenge(open.challenge_id);
+    env.storage().temporary().set(&key, open);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, CHALLENGE_TTL_LEDGERS, CHALLENGE_TTL_LEDGERS);
+}
+
+pub(crate) fn remove_open_challenge(env: &Env, challenge_id: u32) {
+    env.storage()
+        .temporary()
+        .remove(&DataKey::OpenChallenge(challenge_id));
+}
+
+pub(crate) fn open_challenges(env: &Env) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OpenChallenges)
+        .unwrap_or(vec![env])
+}
+
+pub(crate) fn save_open_challenges(env: &Env, challenge_ids: &Vec<u32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::OpenChallenges, challenge_ids);
+}
+
+pub(crate) fn friends(env: &Env, player: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Friends(player.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn save_friends(env: &Env, player: &Address, friends: &Vec<Address>) {
+    let key = DataKey::Friends(player.clone());
+    env.storage().persistent().set(&key, friends);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn looking_for_game(env: &Env, player: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LookingForGame(player.clone()))
+        .unwrap_or(false)
+}
+
+pub(crate) fn save_looking_for_game(env: &Env, player: &Address, looking: bool) {
+    let key = DataKey::LookingForGame(player.clone());
+    if looking {
+        env.storage().persistent().set(&key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub(crate) fn load_invite_code(env: &Env, code: u64) -> Option<InviteCode> {
+    env.storage().temporary().get(&DataKey::InviteCode(code))
+}
+
+/// Store a new invite code; it never outlives a regular challenge
+pub(crate) fn store_new_invite_code(env: &Env, code: u64, invite: &InviteCode) {
+    let invite_key = DataKey::InviteCode(code);
+    env.storage().temporary().set(&invite_key, invite);
+    env.storage()
+        .temporary()
+        .extend_ttl(&invite_key, CHALLENGE_TTL_LEDGERS, CHALLENGE_TTL_LEDGERS);
+}
+
+pub(crate) fn remove_invite_code(env: &Env, code: u64) {
+    env.storage().temporary().remove(&DataKey::InviteCode(code));
+}
+
+pub(crate) fn game_proposal(env: &Env, session_id: u32) -> Option<GameProposal> {
+    env.storage().temporary().get(&DataKey::GameProposal(session_id))
+}
+
+/// Store a new game proposal; it lives as long as a regular challenge
+pub(crate) fn store_new_game_proposal(env: &Env, session_id: u32, proposal: &GameProposal) {
+    let key = DataKey::GameProposal(session_id);
+    env.storage().temporary().set(&key, proposal);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, CHALLENGE_TTL_LEDGERS, CHALLENGE_TTL_LEDGERS);
+}
+
+pub(crate) fn remove_game_proposal(env: &Env, session_id: u32) {
+    env.storage()
+        .temporary()
+        .remove(&DataKey::GameProposal(session_id));
+}
+
+// ============================================================================
+// Lobbies
+// ============================================================================
+
+pub(crate) fn queue(env: &Env, tier: u32) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Queue(tier))
+        .unwrap_or(vec![env])
+}
+
+pub(crate) fn save_queue(env: &Env, tier: u32, waiting: &Vec<Address>) {
+    env.storage().persistent().set(&DataKey::Queue(tier), waiting);
+}
+
+pub(crate) fn queued_tier(env: &Env, player: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::QueuedTier(player.clone()))
+}
+
+pub(crate) fn set_queued_tier(env: &Env, player: &Address, tier: Option<u32>) {
+    let key = DataKey::QueuedTier(player.clone());
+    match tier {
+        Some(tier) => env.storage().persistent().set(&key, &tier),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ============================================================================
+// Usernames
+// ============================================================================
+
+pub(crate) fn username(env: &Env, address: &Address) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Username(address.clone()))
+}
+
+pub(crate) fn address_by_username(env: &Env, username: &String) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AddressByUsername(username.clone()))
+}
+
+pub(crate) fn last_active_at(env: &Env, player: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LastActiveAt(player.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn save_last_active_at(env: &Env, player: &Address, timestamp: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::LastActiveAt(player.clone()), &timestamp);
+}
+
+pub(crate) fn captain_class(env: &Env, player: &Address) -> CaptainClass {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CaptainClass(player.clone()))
+        .unwrap_or(CaptainClass::None)
+}
+
+pub(crate) fn save_captain_class(env: &Env, player: &Address, class: CaptainClass) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CaptainClass(player.clone()), &class);
+}
+
+pub(crate) fn linked_to(env: &Env, player: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LinkedTo(player.clone()))
+}
+
+pub(crate) fn save_linked_to(env: &Env, secondary: &Address, primary: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::LinkedTo(secondary.clone()), primary);
+}
+
+pub(crate) fn remove_linked_to(env: &Env, secondary: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::LinkedTo(secondary.clone()));
+}
+
+pub(crate) fn linked_addresses(env: &Env, primary: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LinkedAddresses(primary.clone()))
+        .unwrap_or(vec![env])
+}
+
+pub(crate) fn save_linked_addresses(env: &Env, primary: &Address, linked: &Vec<Address>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::LinkedAddresses(primary.clone()), linked);
+}
+
+pub(crate) fn link_request(env: &Env, secondary: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LinkRequest(secondary.clone()))
+}
+
+pub(crate) fn save_link_request(env: &Env, secondary: &Address, primary: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::LinkRequest(secondary.clone()), primary);
+}
+
+pub(crate) fn remove_link_request(env: &Env, secondary: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::LinkRequest(secondary.clone()));
+}
+
+pub(crate) fn username_expiry_secs(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::UsernameExpiry)
+        .unwrap_or(0)
+}
+
+// ============================================================================
+// PvP Matches
+// ============================================================================
+
+/// Get and increment the match counter
+pub(crate) fn next_match_id(env: &Env) -> u32 {
+    let match_id: u32 = env.storage()
+        .instance()
+        .get(&DataKey::MatchCounter)
+        .unwrap_or(0);
+    env.storage().instance().set(&DataKey::MatchCounter, &(match_id + 1));
+    match_id
+}
+
+pub(crate) fn load_match(env: &Env, match_id: u32) -> Result<PvPMatch, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Match(match_id))
+        .ok_or(Error::GameNotFound)
+}
+
+pub(crate) fn save_match(env: &Env, pvp_match: &PvPMatch) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Match(pvp_match.match_id), pvp_match);
+}
+
+pub(crate) fn player_matches(env: &Env, player: &Address) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, Vec<u32>>(&DataKey::PlayerMatches(player.clone()))
+        .unwrap_or(vec![env])
+}
+
+pub(crate) fn add_player_match(env: &Env, player: &Address, match_id: u32) {
+    let mut matches = player_matches(env, player);
+    matches.push_back(match_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::PlayerMatches(player.clone()), &matches);
+}
+
+// ============================================================================
+// Promo Codes
+// ============================================================================
+
+pub(crate) fn promo_code(env: &Env, code: &Symbol) -> Option<PromoCode> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PromoCode(code.clone()))
+}
+
+pub(crate) fn save_promo_code(env: &Env, code: &Symbol, promo: &PromoCode) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PromoCode(code.clone()), promo);
+}
+
+pub(crate) fn promo_redeemed(env: &Env, code: &Symbol, player: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::PromoRedeemed(code.clone(), player.clone()))
+}
+
+pub(crate) fn set_promo_redeemed(env: &Env, code: &Symbol, player: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PromoRedeemed(code.clone(), player.clone()), &true);
+}
+
+pub(crate) fn fee_waivers(env: &Env, player: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FeeWaivers(player.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn save_fee_waivers(env: &Env, player: &Address, waivers: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::FeeWaivers(player.clone()), &waivers);
+}
+
+pub(crate) fn deposit_bonus(env: &Env, player: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DepositBonus(player.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn save_deposit_bonus(env: &Env, player: &Address, bonus: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DepositBonus(player.clone()), &bonus);
+}
+
+pub(crate) fn referrer(env: &Env, player: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Referrer(player.clone()))
+}
+
+pub(crate) fn save_referrer(env: &Env, player: &Address, referrer: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Referrer(player.clone()), referrer);
+}
+
+pub(crate) fn remove_referrer(env: &Env, player: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Referrer(player.clone()));
+}
+
+pub(crate) fn referral_stats(env: &Env, referrer: &Address) -> ReferralStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReferralStats(referrer.clone()))
+        .unwrap_or_default()
+}
+
+pub(crate) fn save_referral_stats(env: &Env, referrer: &Address, stats: &ReferralStats) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReferralStats(referrer.clone()), stats);
+}
+
+pub(crate) fn referral_bonus(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReferralBonus)
+        .unwrap_or(referral::DEFAULT_REFERRAL_BONUS)
+}
+
+// ============================================================================
+// Ratings
+// ============================================================================
+
+pub(crate) fn player_stats(env: &Env, player: &Address) -> Option<PlayerStats> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PlayerStats(player.clone()))
+}
+
+pub(crate) fn save_player_stats(env: &Env, player: &Address, stats: &PlayerStats) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PlayerStats(player.clone()), stats);
+}
+
+pub(crate) fn rating_decay(env: &Env) -> RatingDecay {
+    env.storage()
+        .instance()
+        .get(&DataKey::RatingDecay)
+        .unwrap_or(RatingDecay {
+            inactive_secs: 0,
+            points: 0,
+        })
+}
+
+/// 0 for a player rated before decay was tracked, who doesn't decay until their next
+/// rated game
+pub(crate) fn decay_since(env: &Env, player: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DecaySince(player.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn save_decay_since(env: &Env, player: &Address, timestamp: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DecaySince(player.clone()), &timestamp);
+}
+
+pub(crate) fn leaderboard(env: &Env) -> Vec<RankedPlayer> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Leaderboard)
+        .unwrap_or(vec![env])
+}
+
+pub(crate) fn save_leaderboard(env: &Env, leaderboard: &Vec<RankedPlayer>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Leaderboard, leaderboard);
+}
+
+pub(crate) fn rating_histogram(env: &Env) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RatingHistogram)
+        .unwrap_or(vec![env])
+}
+
+pub(crate) fn save_rating_histogram(env: &Env, histogram: &Vec<u32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RatingHistogram, histogram);
+}
+
+pub(crate) fn game_pause(env: &Env, session_id: u32) -> GamePause {
+    env.storage()
+        .persistent()
+        .get(&DataKey::GamePause(session_id))
+        .unwrap_or_default()
+}
+
+pub(crate) fn save_game_pause(env: &Env, session_id: u32, pause: &GamePause) {
+    let key = DataKey::GamePause(session_id);
+    env.storage().persistent().set(&key, pause);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn max_game_pause_secs(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxGamePause)
+        .unwrap_or(agreed_pause::DEFAULT_MAX_GAME_PAUSE_SECS)
+}
+
+// ============================================================================
+// Sponsored games
+// ============================================================================
+
+pub(crate) fn sponsor_tier(env: &Env) -> Option<SponsorTier> {
+    env.storage().instance().get(&DataKey::SponsorTier)
+}
+
+pub(crate) fn save_sponsor_tier(env: &Env, tier: Option<&SponsorTier>) {
+    match tier {
+        Some(tier) => env.storage().instance().set(&DataKey::SponsorTier, tier),
+        None => env.storage().instance().remove(&DataKey::SponsorTier),
+    }
+}
+
+pub(crate) fn sponsor_pool(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SponsorPool)
+        .unwrap_or(0)
+}
+
+pub(crate) fn save_sponsor_pool(env: &Env, balance: i128) {
+    env.storage().instance().set(&DataKey::SponsorPool, &balance);
+}
+
+pub(crate) fn sponsorship(env: &Env, session_id: u32) -> Option<Sponsorship> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Sponsorship(session_id))
+}
+
+pub(crate) fn save_sponsorship(env: &Env, session_id: u32, sponsorship: &Sponsorship) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Sponsorship(session_id), sponsorship);
+}
+
+pub(crate) fn remove_sponsorship(env: &Env, session_id: u32) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Sponsorship(session_id));
+}
+
+pub(crate) fn sponsored_games(env: &Env, player: &Address) -> Option<(u64, u32)> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SponsoredGames(player.clone()))
+}
+
+pub(crate) fn save_sponsored_games(env: &Env, player: &Address, day: u64, games: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SponsoredGames(player.clone()), &(day, games));
+}
+
+pub(crate) fn season(env: &Env) -> Option<Season> {
+    env.storage().instance().get(&DataKey::Season)
+}
+
+pub(crate) fn save_season(env: &Env, season: &Season) {
+    env.storage().instance().set(&DataKey::Season, season);
+}
+
+pub(crate) fn season_stats(env: &Env, season_id: u32, player: &Address) -> Option<PlayerStats> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SeasonStats(season_id, player.clone()))
+}
+
+pub(crate) fn save_season_stats(env: &Env, season_id: u32, player: &Address, stats: &PlayerStats) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SeasonStats(season_id, player.clone()), stats);
+}
+
+pub(crate) fn season_leaderboard(env: &Env, season_id: u32) -> Vec<RankedPlayer> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SeasonLeaderboard(season_id))
+        .unwrap_or(vec![env])
+}
+
+pub(crate) fn save_season_leaderboard(env: &Env, season_id: u32, leaderboard: &Vec<RankedPlayer>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SeasonLeaderboard(season_id), leaderboard);
+}
+
+pub(crate) fn remove_season_leaderboard(env: &Env, season_id: u32) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::SeasonLeaderboard(season_id));
+}
+
+pub(crate) fn season_results(env: &Env, season_id: u32) -> Option<SeasonResults> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SeasonResults(season_id))
+}
+
+pub(crate) fn save_season_results(env: &Env, results: &SeasonResults) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SeasonResults(results.season_id), results);
+}
+
+pub(crate) fn season_rewards(env: &Env) -> Option<SeasonRewards> {
+    env.storage().instance().get(&DataKey::SeasonRewards)
+}
+
+pub(crate) fn season_placement(env: &Env, season_id: u32, player: &Address) -> Option<SeasonPlacement> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SeasonPlacement(season_id, player.clone()))
+}
+
+pub(crate) fn save_season_placement(env: &Env, placement: &SeasonPlacement) {
+    env.storage().persistent().set(
+        &DataKey::SeasonPlacement(placement.season_id, placement.player.clone()),
+        placement,
+    );
+}
+
+pub(crate) fn achievements(env: &Env, player: &Address) -> Vec<EarnedAchievement> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Achievements(player.clone()))
+        .unwrap_or(vec![env])
+}
+
+pub(crate) fn save_achievements(env: &Env, player: &Address, earned: &Vec<EarnedAchievement>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Achievements(player.clone()), earned);
+}
+
+pub(crate) fn daily_bonus_day(env: &Env, player: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DailyBonusDay(player.clone()))
+}
+
+pub(crate) fn save_daily_bonus_day(env: &Env, player: &Address, day: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DailyBonusDay(player.clone()), &day);
+}
+
+pub(crate) fn bonus_points(env: &Env, player: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BonusPoints(player.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn save_bonus_points(env: &Env, player: &Address, points: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::BonusPoints(player.clone()), &points);
+}
+
+pub(crate) fn rival(env: &Env, player: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Rival(player.clone()))
+}
+
+pub(crate) fn set_rival(env: &Env, player: &Address, rival: Option<&Address>) {
+    let key = DataKey::Rival(player.clone());
+    match rival {
+        Some(rival) => env.storage().persistent().set(&key, rival),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Head-to-head records are keyed by the address pair in order, `low < high`
+pub(crate) fn head_to_head(env: &Env, low: &Address, high: &Address) -> HeadToHead {
+    let (low, high) = if low < high { (low, high) } else { (high, low) };
+    env.storage()
+        .persistent()
+        .get(&DataKey::HeadToHead(low.clone(), high.clone()))
+        .unwrap_or_default()
+}
+
+pub(crate) fn save_head_to_head(env: &Env, low: &Address, high: &Address, record: &HeadToHead) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::HeadToHead(low.clone(), high.clone()), record);
+}
+
+pub(crate) fn rivalry_series(env: &Env, player: &Address, opponent: &Address) -> Option<RivalrySeries> {
+    let (low, high) = if player < opponent { (player, opponent) } else { (opponent, player) };
+    env.storage()
+        .persistent()
+        .get(&DataKey::RivalrySeries(low.clone(), high.clone()))
+}
+
+pub(crate) fn save_rivalry_series(env: &Env, series: &RivalrySeries) {
+    let key = DataKey::RivalrySeries(series.player_a.clone(), series.player_b.clone());
+    env.storage().persistent().set(&key, series);
+}
+
+pub(crate) fn last_opponent(env: &Env, player: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LastOpponent(player.clone()))
+}
+
+pub(crate) fn set_last_opponent(env: &Env, player: &Address, opponent: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::LastOpponent(player.clone()), opponent);
+}
+
+// ============================================================================
+// Match History
+// ============================================================================
+
+pub(crate) fn match_history(env: &Env, player: &Address) -> Vec<ArchivedGame> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MatchHistory(player.clone()))
+        .unwrap_or(vec![env])
+}
+
+pub(crate) fn save_match_history(env: &Env, player: &Address, history: &Vec<ArchivedGame>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::MatchHistory(player.clone()), history);
+}
+
+pub(crate) fn match_note(env: &Env, player: &Address, session_id: u32) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MatchNote(player.clone(), session_id))
+}
+
+pub(crate) fn save_match_note(env: &Env, player: &Address, session_id: u32, note_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::MatchNote(player.clone(), session_id), note_hash);
+}
+
+// ============================================================================
+// Tournaments
+// ============================================================================
+
+pub(crate) fn next_tournament_id(env: &Env) -> u32 {
+    let tournament_id = env
+        .storage()
+        .instance()
+        .get(&DataKey::TournamentCounter)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::TournamentCounter, &(tournament_id + 1));
+    tournament_id
+}
+
+pub(crate) fn tournament(env: &Env, tournament_id: u32) -> Option<Tournament> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Tournament(tournament_id))
+}
+
+pub(crate) fn save_tournament(env: &Env, tournament: &Tournament) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Tournament(tournament.tournament_id), tournament);
+}
+
+pub(crate) fn bracket(env: &Env, tournament_id: u32) -> Vec<BracketMatch> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Bracket(tournament_id))
+        .unwrap_or(vec![env])
+}
+
+pub(crate) fn save_bracket(env: &Env, tournament_id: u32, bracket: &Vec<BracketMatch>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Bracket(tournament_id), bracket);
+}
+
+pub(crate) fn tournament_prizes(env: &Env, tournament_id: u32) -> Option<TournamentPrizes> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TournamentPrizes(tournament_id))
+}
+
+pub(crate) fn save_tournament_prizes(env: &Env, tournament_id: u32, prizes: &TournamentPrizes) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TournamentPrizes(tournament_id), prizes);
+}
+
+pub(crate) fn tournament_prize(env: &Env, tournament_id: u32, player: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TournamentPrize(tournament_id, player.clone()))
+        .unwrap_or(0)
+}
+
+/// Owed funds, kept like winnings for a game TTL after they are credited
+pub(crate) fn save_tournament_prize(env: &Env, tournament_id: u32, player: &Address, amount: i128) {
+    let key = DataKey::TournamentPrize(tournament_id, player.clone());
+    env.storage().persistent().set(&key, &amount);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn remove_tournament_prize(env: &Env, tournament_id: u32, player: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::TournamentPrize(tournament_id, player.clone()));
+}
+
+pub(crate) fn prize_vesting(env: &Env, tournament_id: u32) -> Option<PrizeVesting> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PrizeVesting(tournament_id))
+}
+
+pub(crate) fn save_prize_vesting(env: &Env, tournament_id: u32, vesting: &PrizeVesting) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PrizeVesting(tournament_id), vesting);
+}
+
+pub(crate) fn vested_prize(env: &Env, tournament_id: u32, player: &Address) -> Option<VestedPrize> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VestedPrize(tournament_id, player.clone()))
+}
+
+/// Locked funds, kept for a game TTL past the longest lock
+pub(crate) fn save_vested_prize(env: &Env, tournament_id: u32, player: &Address, vested: &VestedPrize) {
+    let key = DataKey::VestedPrize(tournament_id, player.clone());
+    env.storage().persistent().set(&key, vested);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, 2 * GAME_TTL_LEDGERS, 2 * GAME_TTL_LEDGERS);
+}
+
+pub(crate) fn remove_vested_prize(env: &Env, tournament_id: u32, player: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::VestedPrize(tournament_id, player.clone()));
+}
+
+// ============================================================================
+// Nullifiers
+// ============================================================================
+
+pub(crate) fn nullifier_retention(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::NullifierRetention)
+        .unwrap_or(DEFAULT_NULLIFIER_RETENTION_LEDGERS)
+}
+
+pub(crate) fn set_nullifier_retention(env: &Env, ledgers: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::NullifierRetention, &ledgers);
+}
+
+pub(crate) fn is_nullifier_spent(env: &Env, nullifier: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Nullifier(nullifier.clone()))
+}
+
+/// Mark `nullifier` spent for the configured retention window
+pub(crate) fn spend_nullifier(env: &Env, nullifier: &BytesN<32>) {
+    let key = DataKey::Nullifier(nullifier.clone());
+    let retention = nullifier_retention(env);
+    env.storage().persistent().set(&key, &());
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, retention, retention);
+}
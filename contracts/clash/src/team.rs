@@ -0,0 +1,240 @@
+//! 2v2 team games.
+//!
+//! Two pairs of players each commit and reveal their own move sequence through the
+//! usual ZK commit/reveal, and each team fights from one shared HP pool (see
+//! `combat::simulate_team`). The Game Hub only knows two players per session, so each
+//! team is represented there by its captain, the first player listed: the captains'
+//! stakes are locked at start and the hub is told which captain's team won.
+
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env, IntoVal, Vec};
+
+use crate::storage;
+use crate::{escrow, game, identity, randomness, signers, zk};
+use crate::{
+    BattleResolved, BattleResult, CommitReceipt, Error, GameHubClient, Move, MoveSequence, OutcomeReason,
+    MovesRevealed, PlayerCommitment, TeamGame, TURNS_PER_BATTLE,
+};
+
+const TEAM_SIZE: u32 = 2;
+
+pub(crate) fn start_team_game(
+    env: &Env,
+    session_id: u32,
+    team1: Vec<Address>,
+    team2: Vec<Address>,
+    team1_points: i128,
+    team2_points: i128,
+) -> Result<(), Error> {
+    if team1.len() != TEAM_SIZE || team2.len() != TEAM_SIZE {
+        return Err(Error::InvalidTeam);
+    }
+    let mut players = team1.clone();
+    players.append(&team2);
+    for (i, player) in players.iter().enumerate() {
+        if players.first_index_of(&player) != Some(i as u32) {
+            return Err(Error::InvalidTeam);
+        }
+    }
+    game::validate_wager(env, team1_points)?;
+    game::validate_wager(env, team2_points)?;
+
+    // Each captain stakes for their team
+    let captain1 = team1.get(0).unwrap();
+    let captain2 = team2.get(0).unwrap();
+    captain1.require_auth_for_args(vec![
+        env,
+        session_id.into_val(env),
+        team1_points.into_val(env),
+    ]);
+    captain2.require_auth_for_args(vec![
+        env,
+        session_id.into_val(env),
+        team2_points.into_val(env),
+    ]);
+
+    let game_hub = GameHubClient::new(env, &storage::game_hub(env));
+    game::check_registered(env, &game_hub, &captain1, &captain2)?;
+    game_hub.start_game(
+        &env.current_contract_address(),
+        &session_id,
+        &captain1,
+        &captain2,
+        &team1_points,
+        &team2_points,
+    );
+    escrow::lock(env, session_id, &captain1, &captain2, team1_points, team2_points);
+
+    let rules = storage::rule_set(env);
+    let empty_commitment = PlayerCommitment {
+        proof_id: BytesN::from_array(env, &[0u8; 32]),
+        has_revealed: false,
+        moves: MoveSequence { moves: vec![env] },
+    };
+    let team_game = TeamGame {
+        team1,
+        team2,
+        team1_points,
+        team2_points,
+        commitments: vec![
+            env,
+            empty_commitment.clone(),
+            empty_commitment.clone(),
+            empty_commitment.clone(),
+            empty_commitment,
+        ],
+        has_committed: Vec::from_array(env, [false; 4]),
+        battle_result: BattleResult {
+            player1_hp: rules.starting_hp * 2,
+            player2_hp: rules.starting_hp * 2,
+            winner: None,
+            is_draw: false,
+            turn_results: vec![env],
+            entropy: randomness::none(env),
+            went_to_overtime: false,
+            overtime_turns: vec![env],
+            reason: OutcomeReason::Pending,
+        },
+        has_battle_result: false,
+        rules,
+        circuit_version: storage::circuit_version(env),
+    };
+    storage::store_new_team_game(env, session_id, &team_game);
+    Ok(())
+}
+
+pub(crate) fn commit_team_moves(
+    env: &Env,
+    session_id: u32,
+    player: Address,
+    public_inputs: Bytes,
+    proof_bytes: Bytes,
+) -> Result<CommitReceipt, Error> {
+    signers::require_move_auth(env, session_id, &player);
+
+    let mut team_game = storage::load_team_game(env, session_id)?;
+    if team_game.has_battle_result {
+        return Err(Error::GameAlreadyEnded);
+    }
+    let slot = slot(&team_game, &player)?;
+    if team_game.has_committed.get(slot).unwrap() {
+        return Err(Error::AlreadyCommitted);
+    }
+
+    let nullifier = zk::nullifier(env, &public_inputs);
+    let verifier = storage::verifier(env, team_game.circuit_version)?;
+    let commitment_hash =
+        zk::verify_proof(env, &verifier, &player, session_id, public_inputs, proof_bytes)?;
+    if storage::is_nullifier_spent(env, &nullifier) {
+        return Err(Error::NullifierUsed);
+    }
+    storage::spend_nullifier(env, &nullifier);
+
+    team_game.commitments.set(
+        slot,
+        PlayerCommitment {
+            proof_id: commitment_hash.clone(),
+            has_revealed: false,
+            moves: MoveSequence { moves: vec![env] },
+        },
+    );
+    team_game.has_committed.set(slot, true);
+    storage::save_team_game(env, session_id, &team_game);
+    Ok(zk::record_commit(env, session_id, &player, commitment_hash))
+}
+
+/// Reveal one member's moves once all four have committed
+pub(crate) fn reveal_team_moves(
+    env: &Env,
+    session_id: u32,
+    player: Address,
+    public_inputs: Bytes,
+    moves: Vec<Move>,
+) -> Result<(), Error> {
+    signers::require_move_auth(env, session_id, &player);
+
+    if moves.len() != TURNS_PER_BATTLE {
+        return Err(Error::InvalidMoveSequence);
+    }
+
+    let mut team_game = storage::load_team_game(env, session_id)?;
+    if team_game.has_committed.contains(false) {
+        return Err(Error::BothPlayersNotCommitted);
+    }
+    let slot = slot(&team_game, &player)?;
+    let revealed_hash = zk::parse_public_inputs(env, &public_inputs, &player, session_id)?;
+
+    let mut commitment = team_game.commitments.get(slot).unwrap();
+    if commitment.has_revealed {
+        return Err(Error::AlreadyRevealed);
+    }
+    if revealed_hash != commitment.proof_id {
+        return Err(Error::CommitmentMismatch);
+    }
+    commitment.moves = MoveSequence { moves: moves.clone() };
+    commitment.has_revealed = true;
+    team_game.commitments.set(slot, commitment);
+    storage::save_team_game(env, session_id, &team_game);
+
+    MovesRevealed {
+        session_id,
+        player,
+        moves,
+    }
+    .publish(env);
+    Ok(())
+}
+
+/// Play the battle once everyone has revealed, then report the winning team's captain
+/// to the Game Hub. The result is stored before the hub is called, so a repeat call
+/// just returns it.
+pub(crate) fn resolve_team_battle(env: &Env, session_id: u32) -> Result<BattleResult, Error> {
+    let mut team_game = storage::load_team_game(env, session_id)?;
+    if team_game.has_battle_result {
+        return Ok(team_game.battle_result);
+    }
+    if team_game.commitments.iter().any(|commitment| !commitment.has_revealed) {
+        return Err(Error::BothPlayersNotCommitted);
+    }
+
+    team_game.battle_result = game::simulate_team_battle(env, &team_game);
+    team_game.has_battle_result = true;
+    storage::save_team_game(env, session_id, &team_game);
+    for player in team_game.team1.iter().chain(team_game.team2.iter()) {
+        identity::record_activity(env, &player);
+    }
+
+    let result = team_game.battle_result;
+    BattleResolved {
+        session_id,
+        winner: result.winner.clone(),
+        is_draw: result.is_draw,
+    }
+    .publish(env);
+
+    let captain1 = team_game.team1.get(0).unwrap();
+    let team1_won = result.winner.as_ref() == Some(&captain1);
+    let game_hub = GameHubClient::new(env, &storage::game_hub(env));
+    game_hub.end_game(&session_id, &team1_won);
+    escrow::release(env, session_id);
+
+    let winners = match (&result.winner, team1_won) {
+        (None, _) => vec![env],
+        (Some(_), true) => team_game.team1,
+        (Some(_), false) => team_game.team2,
+    };
+    for winner in winners.iter() {
+        game::mint_csh_reward(env, winner);
+    }
+    Ok(result)
+}
+
+/// Index of `player` in the game's commitments: team1's two players, then team2's
+fn slot(team_game: &TeamGame, player: &Address) -> Result<u32, Error> {
+    if let Some(index) = team_game.team1.first_index_of(player) {
+        Ok(index)
+    } else if let Some(index) = team_game.team2.first_index_of(player) {
+        Ok(TEAM_SIZE + index)
+    } else {
+        Err(Error::NotPlayer)
+    }
+}
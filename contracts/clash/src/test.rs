@@ -6,9 +6,22 @@
 // Note: These tests use a minimal mock for isolation and speed.
 // For full integration tests with the real Game Hub contract, see the platform repo.
 
-use crate::{Error, ClashContract, ClashContractClient};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use crate::testutils::{
+    commitment_for, duel_commitment, hashed_commitment, play_duel_round, play_started_game, player_address, public_inputs, register_mock_verifier,
+    ChallengeBuilder, GameBuilder, GamePhase, MoveSeq, DEFAULT_POINTS,
+};
+use crate::migration::{BattleResultV0, ChallengeV0, GameV0};
+use crate::{combat, fees, game, identity, registry, storage, tournament};
+use crate::{
+    Achievement, ActivityKind, AchievementUnlocked, Attack, Badges, BattleResolved, BracketFormat, BracketSide, CaptainClass, Challenge, ChallengeAccepted, ChallengeSent, ChallengeStatus, ClashContract, ClashContractClient, CommitMode, DailyBonusAwarded, DataKey, Defense, DetailedTurnResult, DuelPhase, Error, ExportedSettlement, FriendStatus, FeeTier, OutcomeReason, PauseFlags, PickStats, PracticeDifficulty,
+    GameMode, GameWindow, Move, MoveSequence, MovesCommitted, MovesRevealed, MigrationStatus, PlayerCommitment, PromoReward, ReferralStats, RuleSet, SessionRecord, SignedResult, SponsorTier, TournamentStatus,
+    StorageMigrated, TieBreak, UsernameSet, WindowOpened,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, Events as _, Ledger as _};
+use soroban_sdk::Event as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contracterror, contractimpl, vec, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -35,22 +48,127 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
+    pub fn cancel_game(_env: Env, _session_id: u32) {
+        // Mock implementation - does nothing
+    }
+
     pub fn add_game(_env: Env, _game_address: Address) {
         // Mock implementation - does nothing
     }
+
+    pub fn register_player(env: Env, player: Address) {
+        env.storage().instance().set(&player, &true);
+    }
+
+    pub fn is_registered(env: Env, player: Address) -> bool {
+        env.storage().instance().has(&player)
+    }
+
+    pub fn add_points(env: Env, _game_id: Address, player: Address, points: i128) {
+        let key = (Symbol::new(&env, "points"), player);
+        let balance: i128 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(balance + points));
+    }
+
+    pub fn points(env: Env, player: Address) -> i128 {
+        env.storage().instance().get(&(Symbol::new(&env, "points"), player)).unwrap_or(0)
+    }
+}
+
+/// Hub that refuses to start any session
+#[contract]
+pub struct FailingGameHub;
+
+#[contractimpl]
+impl FailingGameHub {
+    pub fn start_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+        panic!("session rejected");
+    }
+}
+
+// ============================================================================
+// Mock CSH Token
+// ============================================================================
+
+/// Token that just keeps balances, to count reward payouts
+#[contract]
+pub struct MockToken;
+
+#[contractimpl]
+impl MockToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance: i128 = env.storage().instance().get(&to).unwrap_or(0);
+        env.storage().instance().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().instance().get(&id).unwrap_or(0)
+    }
+}
+
+// ============================================================================
+// Failing Verifiers
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum VerifierError {
+    VerificationFailed = 3,
+}
+
+/// Verifier that checks the proof and rejects it, like UltraHonk on a bad proof
+#[contract]
+pub struct RejectingVerifier;
+
+#[contractimpl]
+impl RejectingVerifier {
+    pub fn verify_proof(
+        _env: Env,
+        _public_inputs: Bytes,
+        _proof_bytes: Bytes,
+    ) -> Result<(), VerifierError> {
+        Err(VerifierError::VerificationFailed)
+    }
+}
+
+/// Verifier that traps instead of returning
+#[contract]
+pub struct TrappingVerifier;
+
+#[contractimpl]
+impl TrappingVerifier {
+    pub fn verify_proof(_env: Env, _public_inputs: Bytes, _proof_bytes: Bytes) {
+        panic!("verifier out of budget");
+    }
 }
 
 // ============================================================================
 // Test Helpers
 // ============================================================================
 
-fn setup_test() -> (
+type TestSetup = (
     Env,
     ClashContractClient<'static>,
     MockGameHubClient<'static>,
     Address,
     Address,
-) {
+);
+
+fn setup_test() -> TestSetup {
+    setup_test_with_verifier(register_mock_verifier)
+}
+
+/// Same as [`setup_test`] but with a custom verifier contract
+fn setup_test_with_verifier(register_verifier: fn(&Env) -> Address) -> TestSetup {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -73,31 +191,34 @@ fn setup_test() -> (
     // Create admin address
     let admin = Address::generate(&env);
 
-    // Deploy clash with admin and GameHub address
-    let contract_id = env.register(ClashContract, (&admin, &hub_addr));
+    // Deploy the verifier (by default one that accepts every proof)
+    let verifier_addr = register_verifier(&env);
+
+    // Deploy clash with admin, GameHub and verifier addresses
+    let contract_id = env.register(ClashContract, (&admin, &hub_addr, &verifier_addr));
     let client = ClashContractClient::new(&env, &contract_id);
 
     // Register clash as a whitelisted game (mock does nothing)
     game_hub.add_game(&contract_id);
 
-    let player1 = Address::generate(&env);
-    let player2 = Address::generate(&env);
+    let player1 = player_address(&env, 1);
+    let player2 = player_address(&env, 2);
 
     (env, client, game_hub, player1, player2)
 }
 
-/// Assert that a Result contains a specific number_guess error
+/// Assert that a Result contains a specific clash error
 ///
 /// This helper provides type-safe error assertions following Stellar/Soroban best practices.
-/// Instead of using `assert_eq!(result, Err(Ok(Error::AlreadyGuessed)))`, this pattern:
+/// Instead of using `assert_eq!(result, Err(Ok(Error::AlreadyCommitted)))`, this pattern:
 /// - Provides compile-time error checking
 /// - Makes tests more readable with named errors
 /// - Gives better failure messages
 ///
 /// # Example
 /// ```
-/// let result = client.try_make_guess(&session_id, &player, &7);
-/// assert_number_guess_error(&result, Error::AlreadyGuessed);
+/// let result = client.try_commit_moves(&session_id, &player, &inputs, &proof);
+/// assert_clash_error(&result, Error::AlreadyCommitted);
 /// ```
 ///
 /// # Type Signature
@@ -106,7 +227,7 @@ fn setup_test() -> (
 /// - Ok(Err(conv_err)): Call succeeded, decode failed
 /// - Err(Ok(error)): Contract reverted with custom error (THIS IS WHAT WE TEST)
 /// - Err(Err(invoke_err)): Low-level invocation failure
-fn assert_number_guess_error<T, E>(
+fn assert_clash_error<T, E>(
     result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
     expected_error: Error,
 ) {
@@ -139,66 +260,60 @@ fn assert_number_guess_error<T, E>(
     }
 }
 
+
 // ============================================================================
 // Basic Game Flow Tests
 // ============================================================================
 
 #[test]
 fn test_complete_game() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 1u32;
     let points = 100_0000000;
 
     // Start game
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    GameBuilder::new(&client, session_id, &player1, &player2)
+        .points(points, points)
+        .build(GamePhase::Started);
 
     // Get game to verify state
     let game = client.get_game(&session_id);
-    assert!(game.winning_number.is_none()); // Winning number not set yet
-    assert!(game.winner.is_none()); // Game is still active
+    assert!(!game.has_player1_commitment);
+    assert!(!game.has_battle_result); // Game is still active
     assert_eq!(game.player1, player1);
     assert_eq!(game.player2, player2);
     assert_eq!(game.player1_points, points);
     assert_eq!(game.player2_points, points);
 
-    // Make guesses
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
-
-    // Reveal winner
-    let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
+    // Commit and reveal
+    let p1_commitment = commitment_for(&env, &player1, session_id);
+    let p2_commitment = commitment_for(&env, &player2, session_id);
+    let p1_inputs = public_inputs(&env, &player1, session_id, &p1_commitment);
+    let p2_inputs = public_inputs(&env, &player2, session_id, &p2_commitment);
+    let proof = Bytes::new(&env);
 
-    // Verify game is ended and winning number is now set
-    let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some()); // Game has ended
-    assert_eq!(final_game.winner.unwrap(), winner);
-    assert!(final_game.winning_number.is_some());
-    let winning_number = final_game.winning_number.unwrap();
-    assert!(winning_number >= 1 && winning_number <= 10);
-}
-
-#[test]
-fn test_winning_number_in_range() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    assert_eq!(client.commit_moves(&session_id, &player1, &p1_inputs, &proof).commitment, p1_commitment);
+    assert_eq!(client.commit_moves(&session_id, &player2, &p2_inputs, &proof).commitment, p2_commitment);
 
-    let session_id = 2u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let p1_moves = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let p2_moves = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    client.reveal_moves(&session_id, &player1, &p1_inputs, &p1_moves);
+    client.reveal_moves(&session_id, &player2, &p2_inputs, &p2_moves);
 
-    // Make guesses and reveal winner to generate winning number
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
-    client.reveal_winner(&session_id);
+    // Fireball combo plus momentum from turn 2 (40, 55, 70) against an undefended
+    // player2; every Slash is dodged
+    let result = client.resolve_battle(&session_id);
+    assert_eq!(result.winner, Some(player1.clone()));
+    assert!(!result.is_draw);
+    assert_eq!(result.player1_hp, 100);
+    assert_eq!(result.player2_hp, -65);
+    assert_eq!(result.turn_results.len(), 3);
 
-    let game = client.get_game(&session_id);
-    let winning_number = game
-        .winning_number
-        .expect("Winning number should be set after reveal");
-    assert!(
-        winning_number >= 1 && winning_number <= 10,
-        "Winning number should be between 1 and 10"
-    );
+    // Verify game is ended
+    let final_game = client.get_game(&session_id);
+    assert!(final_game.has_battle_result);
+    assert_eq!(final_game.battle_result, result);
 }
 
 #[test]
@@ -210,8 +325,10 @@ fn test_multiple_sessions() {
     let session1 = 3u32;
     let session2 = 4u32;
 
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    GameBuilder::new(&client, session1, &player1, &player2).build(GamePhase::Started);
+    GameBuilder::new(&client, session2, &player3, &player4)
+        .points(50_0000000, 50_0000000)
+        .build(GamePhase::Started);
 
     // Verify both games exist and are independent
     let game1 = client.get_game(&session1);
@@ -222,98 +339,103 @@ fn test_multiple_sessions() {
 }
 
 // ============================================================================
-// Guess Logic Tests
+// Battle Logic Tests
 // ============================================================================
 
 #[test]
-fn test_closest_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-
-    let session_id = 5u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+fn test_mutual_knockout_is_draw() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    // Player1 guesses closer (1 away from any number between 1-10)
-    // Player2 guesses further (at least 2 away)
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &10);
+    let moves = MoveSeq::repeat(&env, Attack::Fireball, Defense::Block);
+    let result = GameBuilder::new(&client, 5, &player1, &player2)
+        .moves(moves.clone(), moves)
+        .resolve();
 
-    let winner = client.reveal_winner(&session_id);
+    assert!(result.is_draw);
+    assert!(result.winner.is_none());
+    assert!(result.player1_hp <= 0 && result.player2_hp <= 0);
+}
 
-    // Get the final game state to check the winning number
-    let game = client.get_game(&session_id);
-    let winning_number = game.winning_number.unwrap();
+#[test]
+fn test_tie_game_is_draw_after_overtime() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    // Calculate which player should have won based on distances
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
+    // Every attack is dodged, so both players finish on full HP, overtime included
+    let moves = MoveSeq::repeat(&env, Attack::Slash, Defense::Dodge);
+    let result = GameBuilder::new(&client, 6, &player1, &player2)
+        .moves(moves.clone(), moves)
+        .resolve();
 
-    let expected_winner = if distance1 <= distance2 {
-        player1.clone()
-    } else {
-        player2.clone()
-    };
-    assert_eq!(
-        winner, expected_winner,
-        "Player with closer guess should win"
-    );
+    assert_eq!(result.player1_hp, result.player2_hp);
+    assert!(result.went_to_overtime);
+    assert_eq!(result.turn_results.len(), 3);
+    assert_eq!(result.overtime_turns.len(), 6);
+    assert!(result.is_draw);
+    assert!(result.winner.is_none());
 }
 
 #[test]
-fn test_tie_game_player1_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_overtime_breaks_a_tie() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 6u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    // Each side lands one unanswered hit of 40 and the rest miss, so the regular
+    // turns end level and overtime replays turn one's Fireball for player2
+    let p1_moves = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    let p2_moves = MoveSeq::of(
+        &env,
+        &[
+            (Attack::Fireball, Defense::Dodge),
+            (Attack::Lightning, Defense::Block),
+            (Attack::Lightning, Defense::Dodge),
+        ],
+    );
+    let result = GameBuilder::new(&client, 7, &player1, &player2)
+        .moves(p1_moves, p2_moves)
+        .resolve();
 
-    // Both players guess the same number (guaranteed tie)
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &5);
+    assert!(result.went_to_overtime);
+    let overtime = result.overtime_turns.get(0).unwrap();
+    assert_eq!(overtime.turn, 3);
+    assert_eq!(result.winner, Some(player2));
 
-    let winner = client.reveal_winner(&session_id);
-    assert_eq!(winner, player1, "Player1 should win in a tie");
+    let playback = client.get_game_playback(&7);
+    assert!(playback.went_to_overtime);
+    assert_eq!(playback.turn_results.len(), 3 + result.overtime_turns.len());
+    let replayed = playback.turn_results.get(3).unwrap();
+    assert_eq!(replayed.player2_move.attack, Attack::Fireball);
 }
 
 #[test]
-fn test_exact_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-
-    let session_id = 7u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
-
-    // Player1 guesses 5 (middle), player2 guesses 10 (edge)
-    // Player1 is more likely to be closer to the winning number
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &10);
+fn test_blocked_attack_deals_no_damage() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let winner = client.reveal_winner(&session_id);
-    let game = client.get_game(&session_id);
-    let winning_number = game.winning_number.unwrap();
+    let p1_moves = MoveSeq::of(
+        &env,
+        &[
+            (Attack::Lightning, Defense::Block),
+            (Attack::Slash, Defense::Block),
+            (Attack::Fireball, Defense::Block),
+        ],
+    );
+    let p2_moves = MoveSeq::of(
+        &env,
+        &[
+            (Attack::Lightning, Defense::Block),
+            (Attack::Lightning, Defense::Dodge),
+            (Attack::Lightning, Defense::Counter),
+        ],
+    );
+    let result = GameBuilder::new(&client, 7, &player1, &player2)
+        .moves(p1_moves, p2_moves)
+        .resolve();
 
-    // Verify the winner matches the distance calculation
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
-    let expected_winner = if distance1 <= distance2 {
-        player1.clone()
-    } else {
-        player2.clone()
-    };
-    assert_eq!(winner, expected_winner);
+    // Player1 blocks every Lightning; player2 blocks each of player1's attacks
+    for turn in result.turn_results.iter() {
+        assert_eq!(turn.player1_damage_dealt, 0);
+        assert_eq!(turn.player2_damage_dealt, 0);
+        assert!(turn.player1_defense_successful);
+        assert!(turn.player2_defense_successful);
+    }
 }
 
 // ============================================================================
@@ -321,219 +443,4152 @@ fn test_exact_guess_wins() {
 // ============================================================================
 
 #[test]
-fn test_cannot_guess_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_cannot_commit_twice() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 8u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    GameBuilder::new(&client, session_id, &player1, &player2).build(GamePhase::Committed);
 
-    // Make first guess
-    client.make_guess(&session_id, &player1, &5);
-
-    // Try to guess again - should fail
-    let result = client.try_make_guess(&session_id, &player1, &6);
-    assert_number_guess_error(&result, Error::AlreadyGuessed);
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    let result = client.try_commit_moves(&session_id, &player1, &inputs, &Bytes::new(&env));
+    assert_clash_error(&result, Error::AlreadyCommitted);
 }
 
 #[test]
-fn test_cannot_reveal_before_both_guesses() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_cannot_reveal_before_both_commitments() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 9u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    GameBuilder::new(&client, session_id, &player1, &player2).build(GamePhase::Started);
 
-    // Only player1 guesses
-    client.make_guess(&session_id, &player1, &5);
+    // Only player1 commits
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    client.commit_moves(&session_id, &player1, &inputs, &Bytes::new(&env));
 
-    // Try to reveal winner - should fail
-    let result = client.try_reveal_winner(&session_id);
-    assert_number_guess_error(&result, Error::BothPlayersNotGuessed);
+    let moves = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    let result = client.try_reveal_moves(&session_id, &player1, &inputs, &moves);
+    assert_clash_error(&result, Error::BothPlayersNotCommitted);
 }
 
 #[test]
-#[should_panic(expected = "Guess must be between 1 and 10")]
-fn test_cannot_guess_below_range() {
-    let (env, client, _hub, player1, _player2) = setup_test();
+fn test_cannot_reveal_wrong_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 10u32;
-    client.start_game(
-        &session_id,
-        &player1,
-        &Address::generate(&env),
-        &100_0000000,
-        &100_0000000,
-    );
+    GameBuilder::new(&client, session_id, &player1, &player2).build(GamePhase::Committed);
 
-    // Try to guess 0 (below range) - should panic
-    client.make_guess(&session_id, &player1, &0);
+    let wrong_inputs = public_inputs(&env, &player1, session_id, &BytesN::from_array(&env, &[7u8; 32]));
+    let moves = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    let result = client.try_reveal_moves(&session_id, &player1, &wrong_inputs, &moves);
+    assert_clash_error(&result, Error::CommitmentMismatch);
 }
 
 #[test]
-#[should_panic(expected = "Guess must be between 1 and 10")]
-fn test_cannot_guess_above_range() {
-    let (env, client, _hub, player1, _player2) = setup_test();
+fn test_cannot_reveal_short_move_sequence() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 11u32;
-    client.start_game(
-        &session_id,
-        &player1,
-        &Address::generate(&env),
-        &100_0000000,
-        &100_0000000,
-    );
+    GameBuilder::new(&client, session_id, &player1, &player2).build(GamePhase::Committed);
 
-    // Try to guess 11 (above range) - should panic
-    client.make_guess(&session_id, &player1, &11);
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    let moves = MoveSeq::of(&env, &[(Attack::Slash, Defense::Block)]);
+    let result = client.try_reveal_moves(&session_id, &player1, &inputs, &moves);
+    assert_clash_error(&result, Error::InvalidMoveSequence);
 }
 
 #[test]
-fn test_non_player_cannot_guess() {
+fn test_non_player_cannot_commit() {
     let (env, client, _hub, player1, player2) = setup_test();
     let non_player = Address::generate(&env);
 
-    let session_id = 11u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = 12u32;
+    GameBuilder::new(&client, session_id, &player1, &player2).build(GamePhase::Started);
 
-    // Non-player tries to guess
-    let result = client.try_make_guess(&session_id, &non_player, &5);
-    assert_number_guess_error(&result, Error::NotPlayer);
+    let commitment = commitment_for(&env, &non_player, session_id);
+    let inputs = public_inputs(&env, &non_player, session_id, &commitment);
+    let result = client.try_commit_moves(&session_id, &non_player, &inputs, &Bytes::new(&env));
+    assert_clash_error(&result, Error::NotPlayer);
 }
 
 #[test]
-fn test_cannot_reveal_nonexistent_game() {
+fn test_cannot_resolve_nonexistent_game() {
     let (_env, client, _hub, _player1, _player2) = setup_test();
 
-    let result = client.try_reveal_winner(&999);
-    assert_number_guess_error(&result, Error::GameNotFound);
+    let result = client.try_resolve_battle(&999);
+    assert_clash_error(&result, Error::GameNotFound);
 }
 
 #[test]
-fn test_cannot_guess_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_cannot_commit_after_game_ended() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 12u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = 13u32;
+    GameBuilder::new(&client, session_id, &player1, &player2).build(GamePhase::Resolved);
 
-    // Both players make guesses
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    let result = client.try_commit_moves(&session_id, &player1, &inputs, &Bytes::new(&env));
+    assert_clash_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_rejected_proof_is_invalid_proof() {
+    let (env, client, _hub, player1, player2) =
+        setup_test_with_verifier(|env| env.register(RejectingVerifier, ()));
 
-    // Reveal winner - game ends
-    let _winner = client.reveal_winner(&session_id);
+    let session_id = 15u32;
+    GameBuilder::new(&client, session_id, &player1, &player2).build(GamePhase::Started);
 
-    // Try to make another guess after game has ended - should fail
-    let result = client.try_make_guess(&session_id, &player1, &3);
-    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    let result = client.try_commit_moves(&session_id, &player1, &inputs, &Bytes::new(&env));
+    assert_clash_error(&result, Error::InvalidProof);
 }
 
 #[test]
-fn test_cannot_reveal_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_trapping_verifier_is_reported_separately() {
+    let (env, client, _hub, player1, player2) =
+        setup_test_with_verifier(|env| env.register(TrappingVerifier, ()));
 
-    let session_id = 14u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = 16u32;
+    GameBuilder::new(&client, session_id, &player1, &player2).build(GamePhase::Started);
+
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    let result = client.try_commit_moves(&session_id, &player1, &inputs, &Bytes::new(&env));
+    assert_clash_error(&result, Error::VerifierTrapped);
+}
 
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+#[test]
+fn test_malformed_public_inputs_rejected() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    // First reveal succeeds
-    let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
+    let session_id = 17u32;
+    GameBuilder::new(&client, session_id, &player1, &player2).build(GamePhase::Started);
 
-    // Second reveal should return same winner (idempotent)
-    let winner2 = client.reveal_winner(&session_id);
-    assert_eq!(winner, winner2);
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = Bytes::from(commitment);
+    let result = client.try_commit_moves(&session_id, &player1, &inputs, &Bytes::new(&env));
+    assert_clash_error(&result, Error::InvalidPublicInputs);
 }
 
-// ============================================================================
-// Multiple Games Tests
-// ============================================================================
+#[test]
+fn test_preverify_proof_reports_what_a_commit_would() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = GameBuilder::new(&client, 19, &player1, &player2).build(GamePhase::Started);
+    let proof = Bytes::new(&env);
+
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    assert_eq!(client.preverify_proof(&inputs, &proof), commitment);
+    let result = client.try_preverify_proof(&Bytes::from(commitment.clone()), &proof);
+    assert_clash_error(&result, Error::InvalidPublicInputs);
+
+    // Simulating stores nothing, so the commit still goes through; after it, the
+    // inputs are spent
+    assert!(!client.get_game(&session_id).has_player1_commitment);
+    client.commit_moves(&session_id, &player1, &inputs, &proof);
+    assert_clash_error(&client.try_preverify_proof(&inputs, &proof), Error::NullifierUsed);
+
+    let (env, client, _hub, player1, _player2) =
+        setup_test_with_verifier(|env| env.register(RejectingVerifier, ()));
+    let inputs = public_inputs(&env, &player1, 19, &commitment_for(&env, &player1, 19));
+    let result = client.try_preverify_proof(&inputs, &Bytes::new(&env));
+    assert_clash_error(&result, Error::InvalidProof);
+}
+
+#[test]
+fn test_hash_mode_commits_without_the_verifier() {
+    let (env, client, _hub, player1, player2) =
+        setup_test_with_verifier(|env| env.register(TrappingVerifier, ()));
+    let mut config = client.get_game_config();
+    config.commit_mode = CommitMode::Hash;
+    client.set_game_config(&config);
+
+    let session_id = GameBuilder::new(&client, 22, &player1, &player2).build(GamePhase::Started);
+    assert_eq!(client.get_game(&session_id).commit_mode, CommitMode::Hash);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment_for(&env, &player1, session_id));
+    let result = client.try_commit_moves(&session_id, &player1, &inputs, &Bytes::new(&env));
+    assert_clash_error(&result, Error::WrongCommitMode);
+
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    let (salt1, salt2) = (BytesN::from_array(&env, &[1u8; 32]), BytesN::from_array(&env, &[2u8; 32]));
+    let commitment = hashed_commitment(&env, &fireball, &salt1);
+    client.commit_moves_hashed(&session_id, &player1, &commitment);
+    // The opponent can't mirror a digest they have seen
+    let result = client.try_commit_moves_hashed(&session_id, &player2, &commitment);
+    assert_clash_error(&result, Error::NullifierUsed);
+    client.commit_moves_hashed(&session_id, &player2, &hashed_commitment(&env, &slash, &salt2));
+
+    let result = client.try_reveal_moves_hashed(&session_id, &player1, &fireball, &salt2);
+    assert_clash_error(&result, Error::CommitmentMismatch);
+    client.reveal_moves_hashed(&session_id, &player1, &fireball, &salt1);
+    client.reveal_moves_hashed(&session_id, &player2, &slash, &salt2);
+
+    // Resolution is the same as for a ZK game
+    let result = client.resolve_battle(&session_id);
+    assert_eq!(result.winner, Some(player1));
+}
 
 #[test]
-fn test_multiple_games_independent() {
+fn test_games_stay_pinned_to_their_circuit_version() {
     let (env, client, _hub, player1, player2) = setup_test();
-    let player3 = Address::generate(&env);
-    let player4 = Address::generate(&env);
+    assert_eq!(client.get_circuit_version(), 1);
+    let old_game = GameBuilder::new(&client, 23, &player1, &player2).build(GamePhase::Started);
+
+    // Version 2 becomes current; only games started after it use its verifier
+    let rejecting = env.register(RejectingVerifier, ());
+    assert_clash_error(&client.try_register_verifier(&0, &rejecting), Error::UnknownCircuitVersion);
+    client.register_verifier(&2, &rejecting);
+    assert_eq!(client.get_circuit_version(), 2);
+    let new_game = GameBuilder::new(&client, 24, &player1, &player2).build(GamePhase::Started);
+    assert_eq!(client.get_game(&old_game).circuit_version, 1);
+    assert_eq!(client.get_game(&new_game).circuit_version, 2);
+
+    let inputs = public_inputs(&env, &player1, new_game, &commitment_for(&env, &player1, new_game));
+    let result = client.try_commit_moves(&new_game, &player1, &inputs, &Bytes::new(&env));
+    assert_clash_error(&result, Error::InvalidProof);
+    let inputs = public_inputs(&env, &player1, old_game, &commitment_for(&env, &player1, old_game));
+    client.commit_moves(&old_game, &player1, &inputs, &Bytes::new(&env));
+
+    // Deprecating version 1 stops the old game's remaining commits
+    assert_clash_error(&client.try_deprecate_verifier(&2), Error::CircuitInUse);
+    assert_clash_error(&client.try_deprecate_verifier(&3), Error::UnknownCircuitVersion);
+    client.deprecate_verifier(&1);
+    assert!(client.get_verifier(&1).unwrap().deprecated);
+    let inputs = public_inputs(&env, &player2, old_game, &commitment_for(&env, &player2, old_game));
+    let result = client.try_commit_moves(&old_game, &player2, &inputs, &Bytes::new(&env));
+    assert_clash_error(&result, Error::CircuitDeprecated);
+}
 
-    let session1 = 20u32;
-    let session2 = 21u32;
+#[test]
+fn test_commit_proof_cannot_be_replayed() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    assert_eq!(client.get_nullifier_retention(), 518_400);
+    assert_clash_error(&client.try_set_nullifier_retention(&100), Error::InvalidGameConfig);
+    client.set_nullifier_retention(&200_000);
+    assert_eq!(client.get_nullifier_retention(), 200_000);
+
+    let session_id = GameBuilder::new(&client, 18, &player1, &player2).build(GamePhase::Committed);
 
-    // Start two games
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    // The game expires and the session id comes round again
+    env.as_contract(&client.address, || {
+        env.storage().temporary().remove(&DataKey::Game(session_id));
+    });
+    GameBuilder::new(&client, session_id, &player1, &player2).build(GamePhase::Started);
 
-    // Play both games independently
-    client.make_guess(&session1, &player1, &3);
-    client.make_guess(&session2, &player3, &8);
-    client.make_guess(&session1, &player2, &7);
-    client.make_guess(&session2, &player4, &2);
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    let result = client.try_commit_moves(&session_id, &player1, &inputs, &Bytes::new(&env));
+    assert_clash_error(&result, Error::NullifierUsed);
+    assert!(!client.get_game(&session_id).has_player1_commitment);
+}
 
-    // Reveal both winners
-    let winner1 = client.reveal_winner(&session1);
-    let winner2 = client.reveal_winner(&session2);
+#[test]
+fn test_commit_receipts_show_who_committed_first() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    assert!(winner1 == player1 || winner1 == player2);
-    assert!(winner2 == player3 || winner2 == player4);
+    let session_id = GameBuilder::new(&client, 19, &player1, &player2).build(GamePhase::Started);
+    let proof = Bytes::new(&env);
+    assert!(client.get_commit_receipt(&session_id, &player1).is_none());
 
-    // Verify both games are independent
-    let final_game1 = client.get_game(&session1);
-    let final_game2 = client.get_game(&session2);
+    let commitment = commitment_for(&env, &player2, session_id);
+    let inputs = public_inputs(&env, &player2, session_id, &commitment);
+    let first = client.commit_moves(&session_id, &player2, &inputs, &proof);
+    assert_eq!((first.player.clone(), first.commitment.clone()), (player2.clone(), commitment));
+    assert_eq!((first.ledger, first.timestamp), (env.ledger().sequence(), env.ledger().timestamp()));
 
-    assert!(final_game1.winner.is_some()); // Game 1 has ended
-    assert!(final_game2.winner.is_some()); // Game 2 has ended
+    env.ledger().with_mut(|ledger| {
+        ledger.sequence_number += 10;
+        ledger.timestamp += 50;
+    });
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    let second = client.commit_moves(&session_id, &player1, &inputs, &proof);
+    assert_eq!((second.ledger, second.timestamp), (first.ledger + 10, first.timestamp + 50));
 
-    // Note: winning numbers could be the same by chance, so we just verify they're both set
-    assert!(final_game1.winning_number.is_some());
-    assert!(final_game2.winning_number.is_some());
+    assert_eq!(client.get_commit_receipt(&session_id, &player2), Some(first));
+    assert_eq!(client.get_commit_receipt(&session_id, &player1), Some(second));
 }
 
 #[test]
-fn test_asymmetric_points() {
+fn test_public_inputs_bound_to_session_and_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let other = GameBuilder::new(&client, 20, &player1, &player2).build(GamePhase::Started);
+    let session_id = GameBuilder::new(&client, 21, &player1, &player2).build(GamePhase::Started);
+    let proof = Bytes::new(&env);
+
+    // A proof made for another session
+    let commitment = commitment_for(&env, &player1, other);
+    let inputs = public_inputs(&env, &player1, other, &commitment);
+    let result = client.try_commit_moves(&session_id, &player1, &inputs, &proof);
+    assert_clash_error(&result, Error::InvalidPublicInputs);
+
+    // The opponent's proof for this session
+    let commitment = commitment_for(&env, &player2, session_id);
+    let p2_inputs = public_inputs(&env, &player2, session_id, &commitment);
+    let result = client.try_commit_moves(&session_id, &player1, &p2_inputs, &proof);
+    assert_clash_error(&result, Error::InvalidPublicInputs);
+
+    // Reveals are checked the same way
+    let commitment = commitment_for(&env, &player1, session_id);
+    let p1_inputs = public_inputs(&env, &player1, session_id, &commitment);
+    client.commit_moves(&session_id, &player1, &p1_inputs, &proof);
+    client.commit_moves(&session_id, &player2, &p2_inputs, &proof);
+    let moves = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    let result = client.try_reveal_moves(&session_id, &player1, &p2_inputs, &moves);
+    assert_clash_error(&result, Error::InvalidPublicInputs);
+    let stale = public_inputs(&env, &player1, other, &commitment);
+    let result = client.try_reveal_moves(&session_id, &player1, &stale, &moves);
+    assert_clash_error(&result, Error::InvalidPublicInputs);
+    client.reveal_moves(&session_id, &player1, &p1_inputs, &moves);
+}
+
+#[test]
+fn test_cannot_start_game_against_self() {
+    let (_env, client, _hub, player1, _player2) = setup_test();
+
+    let result = client.try_start_game(&18, &player1, &player1, &100, &100);
+    assert_clash_error(&result, Error::CannotChallengeSelf);
+}
+
+#[test]
+fn test_starting_a_game_needs_both_players_to_sign_their_stake() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.start_game(&18, &player1, &player2, &100, &200);
+    for (player, points) in [(&player1, 100i128), (&player2, 200i128)] {
+        let signed = env.auths().iter().any(|(signer, invocation)| {
+            signer == player
+                && matches!(&invocation.function, AuthorizedFunction::Contract((_, _, args))
+                    if *args == vec![&env, 18u32.into_val(&env), points.into_val(&env)])
+        });
+        assert!(signed);
+    }
+}
+
+#[test]
+fn test_proposed_game_starts_only_once_player2_confirms() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.propose_game(&18, &player1, &player2, &100, &200);
+    assert!(client.try_get_game(&18).is_err());
+    let result = client.try_propose_game(&18, &player2, &player1, &100, &100);
+    assert_clash_error(&result, Error::SessionTaken);
+    assert_clash_error(&client.try_confirm_game(&18, &player1), Error::NotPlayer);
+
+    client.confirm_game(&18, &player2);
+    assert_eq!(env.auths()[0].0, player2);
+    let game = client.get_game(&18);
+    assert_eq!((game.player1_points, game.player2_points), (100, 200));
+    assert_eq!(client.get_game_proposal(&18), None);
+
+    // Unconfirmed proposals lapse like challenges
+    client.propose_game(&19, &player1, &player2, &100, &100);
+    env.ledger().with_mut(|ledger| ledger.timestamp += 8 * 24 * 60 * 60);
+    assert_clash_error(&client.try_confirm_game(&19, &player2), Error::ChallengeExpired);
+}
+
+#[test]
+fn test_settlement_pays_out_once() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.set_token_contract(&env.register(MockToken, ()));
+
+    let session_id = 19u32;
+    GameBuilder::new(&client, session_id, &player1, &player2)
+        .moves(
+            MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge),
+            MoveSeq::repeat(&env, Attack::Slash, Defense::Block),
+        )
+        .build(GamePhase::Revealed);
+
+    client.resolve_battle(&session_id);
+    assert!(client.get_game(&session_id).is_settled);
+    client.resolve_battle(&session_id);
+
+    assert_eq!(client.get_csh_balance(&player1), 10_0000000 + crate::DAILY_WIN_BONUS);
+    assert_eq!(client.get_csh_balance(&player2), 0);
+}
+
+#[test]
+fn test_first_rated_win_of_the_day_pays_a_bonus() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.set_token_contract(&env.register(MockToken, ()));
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    env.ledger().set_timestamp(10 * 24 * 60 * 60 + 100);
+
+    let session_id = GameBuilder::new(&client, 20, &player1, &player2)
+        .moves(fireball.clone(), slash.clone())
+        .build(GamePhase::Revealed);
+    client.resolve_battle(&session_id);
+    let bonus = DailyBonusAwarded {
+        player: player1.clone(),
+        day: 10,
+        amount: crate::DAILY_WIN_BONUS,
+    };
+    let events = env.events().all().filter_by_contract(&client.address);
+    assert!(events.events().contains(&bonus.to_xdr(&env, &client.address)));
+    assert_eq!(client.get_csh_balance(&player1), crate::CSH_REWARD_PER_WIN + crate::DAILY_WIN_BONUS);
+
+    // Later the same day, and sieges, only pay the usual reward
+    GameBuilder::new(&client, 21, &player1, &player2)
+        .moves(fireball.clone(), slash.clone())
+        .resolve();
+    env.ledger().set_timestamp(11 * 24 * 60 * 60);
+    GameBuilder::new(&client, 22, &player1, &player2)
+        .siege()
+        .moves(fireball.clone(), slash.clone())
+        .resolve();
+    assert_eq!(client.get_csh_balance(&player1), 3 * crate::CSH_REWARD_PER_WIN + crate::DAILY_WIN_BONUS);
+
+    GameBuilder::new(&client, 23, &player1, &player2)
+        .moves(fireball, slash)
+        .resolve();
+    assert_eq!(client.get_csh_balance(&player1), 4 * crate::CSH_REWARD_PER_WIN + 2 * crate::DAILY_WIN_BONUS);
+}
+
+#[test]
+fn test_daily_bonus_points_settle_to_the_hub() {
+    let (env, client, hub, player1, player2) = setup_test();
+
+    GameBuilder::new(&client, 20, &player1, &player2)
+        .moves(
+            MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge),
+            MoveSeq::repeat(&env, Attack::Slash, Defense::Block),
+        )
+        .resolve();
+    let earned = crate::points::DAILY_WIN_POINTS;
+    assert_eq!(client.get_bonus_points(&player1), earned);
+    assert_eq!(client.get_bonus_points(&player2), 0);
+
+    let result = client.try_settle_points_to_hub(&player1, &(earned + 1));
+    assert_clash_error(&result, Error::InsufficientPoints);
+    assert_eq!(client.settle_points_to_hub(&player1, &(earned / 2)), earned / 2);
+    assert_eq!(client.get_bonus_points(&player1), earned / 2);
+    assert_eq!(hub.points(&player1), earned / 2);
+}
+
+#[test]
+fn test_battle_seed_is_recomputable_from_commit_window() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 21u32;
+    let result = GameBuilder::new(&client, session_id, &player1, &player2).resolve();
+    let entropy = result.entropy;
+
+    let mut expected_inputs = Bytes::from_array(&env, &session_id.to_be_bytes());
+    expected_inputs.append(&Bytes::from(commitment_for(&env, &player1, session_id)));
+    expected_inputs.append(&Bytes::from(commitment_for(&env, &player2, session_id)));
+    assert_eq!(entropy.inputs, expected_inputs);
+    assert!(client.verify_randomness(&entropy));
+
+    let mut tampered = entropy.clone();
+    tampered.seed = BytesN::from_array(&env, &[9u8; 32]);
+    assert!(!client.verify_randomness(&tampered));
+}
+
+#[test]
+fn test_rules_table_matches_the_engine() {
+    let (_env, client, _hub, _player1, _player2) = setup_test();
+
+    let rules = client.get_rules();
+    assert_eq!((rules.starting_hp, rules.turns_per_battle), (100, 3));
+    assert_eq!((rules.combo_2_bonus, rules.combo_3_bonus, rules.momentum_bonus), (10, 25, 5));
+    assert_eq!(rules.initiative_bonus, client.get_game_config().initiative_bonus);
+    let fireball = rules.attacks.get(Attack::Fireball as u32).unwrap();
+    assert_eq!((fireball.damage, fireball.blocked_by), (40, Defense::Counter));
+    let hook = rules.attacks.get(Attack::Hook as u32).unwrap();
+    assert_eq!((hook.damage, hook.blocked_by), (20, Defense::Riposte));
+    assert_eq!((rules.attacks.len(), rules.riposte_reflect_pct), (5, 50));
+    for rule in rules.attacks.iter() {
+        assert_eq!(rule.damage, combat::base_damage(&RuleSet::DEFAULT, rule.attack));
+        assert!(combat::is_blocked(rule.attack, rule.blocked_by));
+    }
+}
+
+#[test]
+fn test_games_resolve_under_the_rules_they_started_with() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 15u32;
-    let points1 = 200_0000000;
-    let points2 = 50_0000000;
+    let before = GameBuilder::new(&client, 15, &player1, &player2).build(GamePhase::Revealed);
 
-    client.start_game(&session_id, &player1, &player2, &points1, &points2);
+    let rules = RuleSet {
+        version: 7,
+        starting_hp: 120,
+        turns_per_battle: 2,
+        slash_damage: 45,
+        ..RuleSet::DEFAULT
+    };
+    let invalid = RuleSet {
+        turns_per_battle: 4,
+        ..rules.clone()
+    };
+    assert_clash_error(&client.try_set_rules(&invalid), Error::InvalidRules);
+    let invalid = RuleSet {
+        slash_damage: 0,
+        ..rules.clone()
+    };
+    assert_clash_error(&client.try_set_rules(&invalid), Error::InvalidRules);
+    let invalid = RuleSet {
+        riposte_reflect_pct: 101,
+        ..rules.clone()
+    };
+    assert_clash_error(&client.try_set_rules(&invalid), Error::InvalidRules);
+    assert_eq!(client.set_rules(&rules), 1);
 
-    let game = client.get_game(&session_id);
-    assert_eq!(game.player1_points, points1);
-    assert_eq!(game.player2_points, points2);
+    let current = client.get_rules();
+    assert_eq!((current.version, current.starting_hp, current.turns_per_battle), (1, 120, 2));
+    assert_eq!(current.attacks.get(Attack::Slash as u32).unwrap().damage, 45);
 
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &5);
-    client.reveal_winner(&session_id);
+    // Both Slash/Block: the old game plays three turns of 30, 40, 55
+    let old = client.resolve_battle(&before);
+    assert_eq!(old.turn_results.len(), 3);
+    assert_eq!(old.turn_results.get(0).unwrap().player1_damage_dealt, 30);
 
-    // Game completes successfully with asymmetric points
-    let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some()); // Game has ended
+    let result = GameBuilder::new(&client, 16, &player1, &player2).resolve();
+    assert_eq!(client.get_game(&16).rules.version, 1);
+    assert_eq!(result.turn_results.len(), 2);
+    assert_eq!(result.turn_results.get(0).unwrap().player1_damage_dealt, 45);
+    let last = result.turn_results.get(1).unwrap();
+    assert_eq!(last.player1_hp_remaining, 120 - 45 - 55);
+    assert_eq!(last.player2_hp_remaining, 120 - 45 - 55);
+    // Level after two turns, so the mirror match is settled in overtime
+    assert!(result.went_to_overtime);
+    assert!(result.is_draw);
 }
 
-// ============================================================================
-// Admin Function Tests
-// ============================================================================
+#[test]
+fn test_crits_are_rolled_from_the_battle_seed() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let mut config = client.get_game_config();
+    assert_eq!((config.crit_chance_bps, config.crit_multiplier_pct), (0, 150));
+    config.crit_multiplier_pct = 99;
+    assert_clash_error(&client.try_set_game_config(&config), Error::InvalidGameConfig);
+    config.crit_multiplier_pct = 200;
+    config.crit_chance_bps = 5_001;
+    assert_clash_error(&client.try_set_game_config(&config), Error::InvalidGameConfig);
+    config.crit_chance_bps = 5_000;
+    client.set_game_config(&config);
+    assert_eq!(client.get_rules().crit_multiplier_pct, 200);
+
+    let mut crits_seen = 0;
+    for session_id in 1..=8u32 {
+        let result = GameBuilder::new(&client, session_id, &player1, &player2).resolve();
+        let crits = game::crit_rolls(&result.entropy.seed, 5_000, 200);
+        let playback = client.get_game_playback(&session_id);
+
+        for (turn, detailed) in result.turn_results.iter().zip(playback.turn_results.iter()) {
+            let bit = 1 << turn.turn;
+            assert_eq!(turn.player1_was_critical, crits.player1 & bit != 0);
+            assert_eq!(turn.player2_was_critical, crits.player2 & bit != 0);
+            assert_eq!(detailed.player1_was_critical, turn.player1_was_critical);
+            assert_eq!(detailed.player2_was_critical, turn.player2_was_critical);
+        }
+
+        // Turn one has no momentum or guard: a crit doubles the plain Slash
+        let first = result.turn_results.get(0).unwrap();
+        let expected = |critical: bool| if critical { 60 } else { 30 };
+        assert_eq!(first.player1_damage_dealt, expected(first.player1_was_critical));
+        assert_eq!(first.player2_damage_dealt, expected(first.player2_was_critical));
+        crits_seen += result
+            .turn_results
+            .iter()
+            .filter(|t| t.player1_was_critical || t.player2_was_critical)
+            .count();
+    }
+    assert!(crits_seen > 0);
+}
 
 #[test]
-fn test_upgrade_function_exists() {
-    let env = Env::default();
-    env.mock_all_auths();
+fn test_resolve_twice_is_idempotent() {
+    let (_env, client, _hub, player1, player2) = setup_test();
 
-    let admin = Address::generate(&env);
-    let hub_addr = env.register(MockGameHub, ());
+    let session_id = 14u32;
+    GameBuilder::new(&client, session_id, &player1, &player2).build(GamePhase::Revealed);
 
-    // Deploy clash with admin
-    let contract_id = env.register(ClashContract, (&admin, &hub_addr));
-    let client = ClashContractClient::new(&env, &contract_id);
+    let first = client.resolve_battle(&session_id);
+    let second = client.resolve_battle(&session_id);
+    assert_eq!(first, second);
+}
 
-    // Verify the upgrade function exists and can be called
-    // Note: We can't test actual upgrade without real WASM files
-    // The function will fail with MissingValue because the WASM hash doesn't exist
-    // But that's expected - we're just verifying the function signature is correct
-    let new_wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
-    let result = client.try_upgrade(&new_wasm_hash);
+#[test]
+fn test_player_active_games_load_in_one_call() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
 
-    // Should fail with MissingValue (WASM doesn't exist) not NotAdmin
+    GameBuilder::new(&client, 30, &player1, &player2).build(GamePhase::Committed);
+    GameBuilder::new(&client, 31, &player1, &player3).build(GamePhase::Started);
+    GameBuilder::new(&client, 32, &player1, &player2).resolve();
+    // Duels share the index but aren't games
+    client.start_duel(&33, &player1, &player2, &100_0000000, &100_0000000);
+    assert_eq!(client.get_player_active_games(&player1, &0, &30), vec![&env, 30u32, 31]);
+    assert_eq!(client.get_player_active_games(&player3, &0, &30), vec![&env, 31u32]);
+
+    let games = client.get_games(&vec![&env, 31u32, 999, 30]);
+    assert_eq!(games.len(), 2);
+    let first = games.get(0).unwrap();
+    assert_eq!((first.session_id, first.game.player2), (31, player3));
+    let second = games.get(1).unwrap();
+    assert_eq!(second.session_id, 30);
+    assert!(second.game.has_player1_commitment && !second.game.player1_commitment.has_revealed);
+}
+
+#[test]
+fn test_awaiting_action_follows_whose_move_it_is() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let empty = vec![&env];
+
+    let session_id = GameBuilder::new(&client, 18, &player1, &player2).build(GamePhase::Started);
+    client.start_duel(&19, &player1, &player2, &100_0000000, &100_0000000);
+    assert_eq!(client.get_awaiting_action(&player1, &0, &30), vec![&env, 18u32, 19]);
+
+    let proof = Bytes::new(&env);
+    let [p1_inputs, p2_inputs] = [&player1, &player2].map(|player| {
+        let commitment = commitment_for(&env, player, session_id);
+        public_inputs(&env, player, session_id, &commitment)
+    });
+    client.commit_moves(&session_id, &player1, &p1_inputs, &proof);
+    let action = Move { attack: Attack::Slash, defense: Defense::Block };
+    let salt = BytesN::from_array(&env, &[1u8; 32]);
+    client.commit_duel_move(&19, &player1, &duel_commitment(&env, &action, &salt));
+    assert_eq!(client.get_awaiting_action(&player1, &0, &30), empty);
+    assert_eq!(client.get_awaiting_action(&player2, &0, &30), vec![&env, 18u32, 19]);
+
+    client.commit_moves(&session_id, &player2, &p2_inputs, &proof);
+    let moves = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    client.reveal_moves(&session_id, &player2, &p2_inputs, &moves);
+    assert_eq!(client.get_awaiting_action(&player1, &0, &30), vec![&env, 18u32]);
+    assert_eq!(client.get_awaiting_action(&player2, &0, &30), vec![&env, 19u32]);
+
+    // Settled games drop off, and a missed deadline isn't the player's move any more
+    client.reveal_moves(&session_id, &player1, &p1_inputs, &moves);
+    client.resolve_battle(&session_id);
+    assert_eq!(client.get_awaiting_action(&player1, &0, &30), empty);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 6 * 60);
+    assert_eq!(client.get_awaiting_action(&player2, &0, &30), empty);
+}
+
+#[test]
+fn test_players_are_capped_on_live_sessions() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+
+    for session_id in 100..130u32 {
+        client.start_game(&session_id, &player1, &player2, &100, &100);
+    }
+    let result = client.try_start_game(&130, &player3, &player1, &100, &100);
+    assert_clash_error(&result, Error::TooManyLiveSessions);
+    assert_eq!(client.get_player_active_games(&player1, &25, &10).len(), 5);
+
+    // Settling one frees a slot
+    client.forfeit(&100, &player2);
+    client.start_game(&130, &player3, &player1, &100, &100);
+}
+
+#[test]
+fn test_badges_count_challenges_and_games_awaiting_the_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let badges = |pending_challenges, awaiting_action| Badges {
+        pending_challenges,
+        awaiting_action,
+        unclaimed_rewards: 0,
+    };
+
+    let declined = ChallengeBuilder::new(&client, &player1, &player2).send();
+    let (_challenge_id, session_id) = ChallengeBuilder::new(&client, &player1, &player2).accept();
+    assert_eq!(client.get_badges(&player2), badges(1, 1));
+    client.decline_challenge(&declined, &player2);
+    assert_eq!(client.get_badges(&player2), badges(0, 1));
+    assert_eq!(client.get_badges(&player1), badges(0, 1));
+
+    // Committing clears the player's badge until the reveal window opens
+    let proof = Bytes::new(&env);
+    let [p1_inputs, p2_inputs] = [&player1, &player2].map(|player| {
+        let commitment = commitment_for(&env, player, session_id);
+        public_inputs(&env, player, session_id, &commitment)
+    });
+    client.commit_moves(&session_id, &player1, &p1_inputs, &proof);
+    assert_eq!(client.get_badges(&player1), badges(0, 0));
+    client.commit_moves(&session_id, &player2, &p2_inputs, &proof);
+    assert_eq!(client.get_badges(&player1), badges(0, 1));
+
+    // Settling drops it for the player who never revealed too
+    let moves = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    client.reveal_moves(&session_id, &player1, &p1_inputs, &moves);
+    assert_eq!(client.get_badges(&player1), badges(0, 0));
+    client.forfeit(&session_id, &player2);
+    assert_eq!(client.get_badges(&player2), badges(0, 0));
+}
+
+#[test]
+fn test_revealed_games_are_listed_until_resolved() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    GameBuilder::new(&client, 15, &player1, &player2).build(GamePhase::Committed);
+    GameBuilder::new(&client, 16, &player1, &player2).build(GamePhase::Revealed);
+    GameBuilder::new(&client, 17, &player1, &player2).build(GamePhase::Revealed);
+    assert_eq!(client.get_pending_resolutions(&0, &10), vec![&env, 16u32, 17]);
+    assert_eq!(client.get_pending_resolutions(&1, &10), vec![&env, 17u32]);
+
+    client.resolve_battle(&16);
+    assert_eq!(client.get_pending_resolutions(&0, &10), vec![&env, 17u32]);
+    client.forfeit(&17, &player2);
+    assert!(client.get_pending_resolutions(&0, &10).is_empty());
+}
+
+// ============================================================================
+// Challenge Tests
+// ============================================================================
+
+#[test]
+fn test_accept_challenge_starts_game() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let (challenge_id, session_id) = ChallengeBuilder::new(&client, &player1, &player2)
+        .wager(25_0000000)
+        .accept();
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1, player1);
+    assert_eq!(game.player2, player2);
+    assert_eq!(game.player1_points, 25_0000000);
+
+    let (active, completed, expired) = client.get_player_challenges(&player2);
+    assert_eq!(active.len(), 1);
+    assert!(completed.is_empty() && expired.is_empty());
+    let challenge = active.get(0).unwrap();
+    assert_eq!(challenge.challenge_id, challenge_id);
+    assert!(challenge.is_accepted);
+    assert_eq!(challenge.session_id, Some(session_id));
+}
+
+#[test]
+fn test_challenge_lifecycle_is_published() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let challenge_id = ChallengeBuilder::new(&client, &player1, &player2).send();
+    let sent = ChallengeSent {
+        challenge_id,
+        challenger: player1.clone(),
+        challenged: player2.clone(),
+        points_wagered: 100_0000000,
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [sent.to_xdr(&env, &client.address)]
+    );
+
+    let session_id = client.accept_challenge(&challenge_id, &player2, &0);
+    let accepted = ChallengeAccepted {
+        challenge_id,
+        session_id,
+        challenged: player2.clone(),
+        challenger: player1.clone(),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [accepted.to_xdr(&env, &client.address)]
+    );
+
+    let proof = Bytes::new(&env);
+    let inputs = [&player1, &player2].map(|player| {
+        let commitment = commitment_for(&env, player, session_id);
+        let inputs = public_inputs(&env, player, session_id, &commitment);
+        client.commit_moves(&session_id, player, &inputs, &proof);
+        inputs
+    });
+    let moves = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    client.reveal_moves(&session_id, &player1, &inputs[0], &moves);
+    let revealed = MovesRevealed {
+        session_id,
+        player: player1.clone(),
+        moves,
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [revealed.to_xdr(&env, &client.address)]
+    );
+    client.reveal_moves(&session_id, &player2, &inputs[1], &MoveSeq::repeat(&env, Attack::Slash, Defense::Block));
+
+    client.resolve_battle(&session_id);
+    let resolved = BattleResolved {
+        session_id,
+        winner: Some(player1.clone()),
+        is_draw: false,
+    };
+    let unlocked = [Achievement::FirstWin, Achievement::Flawless, Achievement::TripleComboFinisher]
+        .map(|achievement| {
+            AchievementUnlocked {
+                player: player1.clone(),
+                achievement,
+                session_id,
+            }
+            .to_xdr(&env, &client.address)
+        });
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [resolved.to_xdr(&env, &client.address), unlocked[0].clone(), unlocked[1].clone(), unlocked[2].clone()]
+    );
+
+    // Resolving again doesn't announce the battle twice
+    client.resolve_battle(&session_id);
+    assert!(env.events().all().filter_by_contract(&client.address).events().is_empty());
+
+    let username = String::from_str(&env, "blackbeard");
+    client.set_username(&player1, &username);
+    let named = UsernameSet {
+        player: player1,
+        username,
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [named.to_xdr(&env, &client.address)]
+    );
+}
+
+#[test]
+fn test_challenges_accepted_before_the_session_index_can_be_backfilled() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let (challenge_id, session_id) = ChallengeBuilder::new(&client, &player1, &player2).accept();
+    let (_, other_session) = ChallengeBuilder::new(&client, &player2, &player1).accept();
+    ChallengeBuilder::new(&client, &player1, &player2).send();
+
+    // As if accepted before the upgrade that added the index
+    env.as_contract(&client.address, || {
+        for session in [session_id, other_session] {
+            env.storage().temporary().remove(&DataKey::ChallengeBySession(session));
+        }
+    });
+    client.forfeit(&other_session, &player1);
+    let (active, completed, _) = client.get_player_challenges(&player1);
+    assert_eq!((active.len(), completed.len()), (3, 0));
+
+    assert_eq!(client.index_challenge_sessions(&0, &1), 1);
+    assert_eq!(client.index_challenge_sessions(&1, &100), 1);
+    client.forfeit(&session_id, &player2);
+    let (_, completed, _) = client.get_player_challenges(&player1);
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed.get(0).unwrap().challenge_id, challenge_id);
+}
+
+#[test]
+fn test_migrate_indexes_legacy_challenges_in_batches() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let latest = client.get_migration_status().latest_version;
+    assert_eq!(client.get_migration_status(), MigrationStatus { version: latest, latest_version: latest, cursor: 0 });
+
+    let accepted = [0, 1, 2].map(|_| ChallengeBuilder::new(&client, &player1, &player2).accept());
+    let sessions = accepted.map(|(_, session_id)| session_id);
+    // As if deployed before storage versions, with challenges from before the index
+    // and one challenge and game still in the original layout
+    let (legacy_id, legacy_session) = accepted[1];
+    let challenge = client.get_challenge(&legacy_id);
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&DataKey::StorageVersion);
+        for session in sessions {
+            env.storage().temporary().remove(&DataKey::ChallengeBySession(session));
+        }
+        let legacy = ChallengeV0 {
+            challenge_id: legacy_id,
+            challenger: challenge.challenger.clone(),
+            challenged: challenge.challenged.clone(),
+            points_wagered: challenge.points_wagered,
+            created_at: challenge.created_at,
+            expires_at: challenge.expires_at,
+            is_accepted: true,
+            is_completed: false,
+            session_id: Some(legacy_session),
+        };
+        env.storage().temporary().set(&DataKey::Challenge(legacy_id), &legacy);
+        let no_commitment = PlayerCommitment {
+            proof_id: BytesN::from_array(&env, &[0u8; 32]),
+            has_revealed: false,
+            moves: MoveSequence { moves: vec![&env] },
+        };
+        let legacy = GameV0 {
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points: DEFAULT_POINTS,
+            player2_points: DEFAULT_POINTS,
+            has_player1_commitment: false,
+            player1_commitment: no_commitment.clone(),
+            has_player2_commitment: false,
+            player2_commitment: no_commitment,
+            has_battle_result: false,
+            battle_result: BattleResultV0 {
+                player1_hp: 0,
+                player2_hp: 0,
+                winner: None,
+                is_draw: false,
+                turn_results: vec![&env],
+            },
+        };
+        env.storage().temporary().set(&DataKey::Game(legacy_session), &legacy);
+    });
+
+    // Old entries read in the current layout
+    let challenge = client.get_challenge(&legacy_id);
+    assert_eq!((challenge.status, challenge.nonce), (ChallengeStatus::Accepted, 0));
+    let game = client.get_game(&legacy_session);
+    assert_eq!((game.mode, game.started_at), (GameMode::Classic, env.ledger().timestamp()));
+
+    // Reads fall back to the player's list until the migration has run
+    client.forfeit(&sessions[0], &player2);
+    let (_, completed, _) = client.get_player_challenges(&player1);
+    assert_eq!(completed.len(), 1);
+
+    assert_eq!(client.migrate(&2), MigrationStatus { version: 0, latest_version: latest, cursor: 2 });
+    assert_eq!(client.migrate(&2).version, 1);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [StorageMigrated { version: 1 }.to_xdr(&env, &client.address)]
+    );
+    assert_eq!(client.migrate(&2), client.get_migration_status());
+    env.as_contract(&client.address, || {
+        let stored: Option<Challenge> = env.storage().temporary().get(&DataKey::Challenge(legacy_id));
+        assert_eq!(stored.map(|challenge| challenge.status), Some(ChallengeStatus::Accepted));
+    });
+
+    client.forfeit(&sessions[2], &player2);
+    client.forfeit(&legacy_session, &player2);
+    let (_, completed, _) = client.get_player_challenges(&player1);
+    assert_eq!(completed.len(), 3);
+    assert_eq!(client.get_game(&legacy_session).battle_result.winner, Some(player1.clone()));
+}
+
+#[test]
+fn test_challenged_player_gets_first_turn_initiative() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let mut config = client.get_game_config();
+    config.initiative_bonus = 50;
+    assert_clash_error(&client.try_set_game_config(&config), Error::InvalidGameConfig);
+    config.initiative_bonus = 8;
+    client.set_game_config(&config);
+
+    let (_challenge_id, session_id) = ChallengeBuilder::new(&client, &player1, &player2).accept();
+    assert_eq!(client.get_game(&session_id).initiative_bonus, 8);
+
+    let proof = Bytes::new(&env);
+    let inputs = [&player1, &player2].map(|player| {
+        let commitment = commitment_for(&env, player, session_id);
+        let inputs = public_inputs(&env, player, session_id, &commitment);
+        client.commit_moves(&session_id, player, &inputs, &proof);
+        inputs
+    });
+    client.reveal_moves(&session_id, &player1, &inputs[0], &MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge));
+    client.reveal_moves(&session_id, &player2, &inputs[1], &MoveSeq::repeat(&env, Attack::Slash, Defense::Block));
+    client.resolve_battle(&session_id);
+
+    // Only the first Fireball is softened
+    let playback = client.get_game_playback(&session_id);
+    assert_eq!(playback.player2_initiative_bonus, 8);
+    assert_eq!(playback.turn_results.get(0).unwrap().player1_damage_dealt, 40 - 8);
+    assert_eq!(playback.turn_results.get(1).unwrap().player1_damage_dealt, 40 + 10 + 5);
+
+    // Games not started from a challenge have no initiative
+    let session_id = GameBuilder::new(&client, 91, &player1, &player2).build(GamePhase::Resolved);
+    assert_eq!(client.get_game_playback(&session_id).player2_initiative_bonus, 0);
+}
+
+#[test]
+fn test_challenge_forwarded_once_to_clanmate() {
+    let (env, client, _hub, challenger, challenged) = setup_test();
+    let clanmate = Address::generate(&env);
+
+    let challenge_id = ChallengeBuilder::new(&client, &challenger, &challenged)
+        .wager(40)
+        .send();
+    let result = client.try_forward_challenge(&challenge_id, &challenger);
+    assert_clash_error(&result, Error::CannotChallengeSelf);
+
+    client.forward_challenge(&challenge_id, &clanmate);
+    let challenge = client.get_challenge(&challenge_id);
+    assert_eq!(challenge.challenged, clanmate);
+    assert_eq!(challenge.forwarded_from, Some(challenged.clone()));
+    assert_eq!(challenge.points_wagered, 40);
+
+    let result = client.try_forward_challenge(&challenge_id, &Address::generate(&env));
+    assert_clash_error(&result, Error::ChallengeAlreadyForwarded);
+    let result = client.try_accept_challenge(&challenge_id, &challenged, &0);
+    assert_clash_error(&result, Error::NotPlayer);
+
+    // The clanmate's acceptance has to be built against the forwarded challenge
+    assert_eq!(challenge.nonce, 1);
+    let result = client.try_accept_challenge(&challenge_id, &clanmate, &0);
+    assert_clash_error(&result, Error::StaleChallenge);
+    let session_id = client.accept_challenge(&challenge_id, &clanmate, &challenge.nonce);
+    assert_eq!(client.get_game(&session_id).player2, clanmate);
+    assert_eq!(client.get_player_challenges(&clanmate).0.len(), 1);
+}
+
+#[test]
+fn test_challenge_tie_break_decides_a_mirror_match() {
+    let (env, client, _hub, challenger, challenged) = setup_test();
+    let mut config = client.get_game_config();
+    config.initiative_bonus = 0;
+    client.set_game_config(&config);
+
+    let challenge_id = ChallengeBuilder::new(&client, &challenger, &challenged).send();
+    let challenge = client.get_challenge(&challenge_id);
+    assert_eq!(challenge.tie_break, TieBreak::SuddenDeath);
+
+    client.set_challenge_tie_break(&challenge_id, &TieBreak::Player1Wins);
+    let result = client.try_accept_challenge(&challenge_id, &challenged, &challenge.nonce);
+    assert_clash_error(&result, Error::StaleChallenge);
+
+    let nonce = client.get_challenge(&challenge_id).nonce;
+    let session_id = client.accept_challenge(&challenge_id, &challenged, &nonce);
+    assert_eq!(client.get_game(&session_id).rules.tie_break, TieBreak::Player1Wins);
+
+    // Every attack is dodged, so it ends level with no overtime and the challenger takes it
+    let moves = MoveSeq::repeat(&env, Attack::Slash, Defense::Dodge);
+    let result = play_started_game(&client, session_id, &challenger, &challenged, &moves, &moves);
+    assert!(!result.went_to_overtime);
+    assert_eq!(result.winner, Some(challenger));
+}
+
+#[test]
+fn test_invite_code_is_one_time() {
+    let (env, client, _hub, creator, acceptor) = setup_test();
+
+    let result = client.try_create_invite_code(&creator, &100, &0);
+    assert_clash_error(&result, Error::InvalidTtl);
+    let code = client.create_invite_code(&creator, &100, &3600);
+
+    let result = client.try_accept_invite_code(&code, &creator);
+    assert_clash_error(&result, Error::CannotChallengeSelf);
+
+    let session_id = client.accept_invite_code(&code, &acceptor);
+    let game = client.get_game(&session_id);
+    assert_eq!((game.player1, game.player2), (creator.clone(), acceptor.clone()));
+    assert_eq!(game.player2_points, 100);
+
+    let result = client.try_accept_invite_code(&code, &Address::generate(&env));
+    assert_clash_error(&result, Error::ChallengeNotFound);
+
+    // Expired codes can't be redeemed
+    let code = client.create_invite_code(&creator, &100, &60);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 61);
+    let result = client.try_accept_invite_code(&code, &acceptor);
+    assert_clash_error(&result, Error::ChallengeExpired);
+}
+
+#[test]
+fn test_challenge_cannot_be_accepted_twice() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let (challenge_id, _session_id) = ChallengeBuilder::new(&client, &player1, &player2).accept();
+
+    let result = client.try_accept_challenge(&challenge_id, &player2, &0);
+    assert_clash_error(&result, Error::ChallengeAlreadyAccepted);
+}
+
+#[test]
+fn test_declined_and_cancelled_challenges_leave_player_lists() {
+    let (_env, client, _hub, challenger, challenged) = setup_test();
+
+    let declined = ChallengeBuilder::new(&client, &challenger, &challenged).send();
+    let cancelled = ChallengeBuilder::new(&client, &challenger, &challenged).send();
+    assert_eq!(client.get_challenge(&declined).status, ChallengeStatus::Pending);
+
+    // Each side can only close it from their end
+    assert_clash_error(&client.try_decline_challenge(&declined, &challenger), Error::NotPlayer);
+    assert_clash_error(&client.try_cancel_challenge(&cancelled, &challenged), Error::NotPlayer);
+
+    client.decline_challenge(&declined, &challenged);
+    client.cancel_challenge(&cancelled, &challenger);
+    assert_eq!(client.get_challenge(&declined).status, ChallengeStatus::Declined);
+    assert_eq!(client.get_challenge(&cancelled).status, ChallengeStatus::Cancelled);
+    assert!(client.get_player_challenges(&challenger).0.is_empty());
+    assert!(client.get_player_challenges(&challenged).0.is_empty());
+
+    let result = client.try_accept_challenge(&declined, &challenged, &0);
+    assert_clash_error(&result, Error::ChallengeClosed);
+    let result = client.try_decline_challenge(&cancelled, &challenged);
+    assert_clash_error(&result, Error::ChallengeClosed);
+
+    // Accepted challenges can no longer be withdrawn
+    let (accepted, _session_id) = ChallengeBuilder::new(&client, &challenger, &challenged).accept();
+    assert_eq!(client.get_challenge(&accepted).status, ChallengeStatus::Accepted);
+    let result = client.try_cancel_challenge(&accepted, &challenger);
+    assert_clash_error(&result, Error::ChallengeAlreadyAccepted);
+}
+
+#[test]
+fn test_player_challenge_lists_are_paged_and_capped() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let stale = ChallengeBuilder::new(&client, &player1, &player2).send();
+    env.ledger().with_mut(|ledger| ledger.timestamp += 8 * 24 * 60 * 60);
+    let mut sent = vec![&env];
+    for _ in 0..50 {
+        sent.push_back(ChallengeBuilder::new(&client, &player1, &player2).send());
+    }
+
+    // The 51st entry pushed out the expired one
+    let all = client.get_player_challenges_page(&player2, &0, &100);
+    assert_eq!(all.len(), 50);
+    assert!(all.iter().all(|challenge| challenge.challenge_id != stale));
+    assert_eq!(all.get(0).unwrap().challenge_id, sent.get(0).unwrap());
+
+    // With every entry still pending, the oldest goes
+    let newest = ChallengeBuilder::new(&client, &player1, &player2).send();
+    let page = client.get_player_challenges_page(&player2, &45, &10);
+    assert_eq!(page.len(), 5);
+    assert_eq!(page.get(4).unwrap().challenge_id, newest);
+    let first = client.get_player_challenges_page(&player2, &0, &1);
+    assert_eq!(first.get(0).unwrap().challenge_id, sent.get(1).unwrap());
+
+    // Evicted from the list, but still readable
+    assert_eq!(client.get_challenge(&stale).challenge_id, stale);
+}
+
+#[test]
+fn test_session_registry_lists_sessions_by_ledger() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let advance_to = |sequence: u32| env.ledger().with_mut(|ledger| ledger.sequence_number = sequence);
+
+    client.start_game(&64, &player1, &player2, &100, &100);
+    advance_to(900);
+    client.start_duel(&65, &player1, &player2, &100, &100);
+    advance_to(20_000);
+    client.start_game(&66, &player1, &player2, &100, &100);
+
+    let record = |session_id, kind, ledger| SessionRecord { session_id, kind, ledger };
+    let listed = |from: u32, to: u32| client.get_sessions_in_range(&from, &to, &0).sessions;
+    assert_eq!(
+        listed(0, 1000),
+        vec![&env, record(64, ActivityKind::Game, 100), record(65, ActivityKind::Duel, 900)]
+    );
+    assert_eq!(listed(101, 1000), vec![&env, record(65, ActivityKind::Duel, 900)]);
+    assert_eq!(listed(19_500, 20_000), vec![&env, record(66, ActivityKind::Game, 20_000)]);
+    assert!(listed(1000, 15_000).is_empty());
+
+    assert_clash_error(&client.try_get_sessions_in_range(&1000, &0, &0), Error::InvalidRange);
+    assert_clash_error(&client.try_get_sessions_in_range(&0, &20_000, &0), Error::InvalidRange);
+
+    // A busy hour is written in pages and listed over several calls
+    let bucket = 20_000 / registry::BUCKET_LEDGERS;
+    let last_page = registry::MAX_PAGES_PER_QUERY;
+    env.as_contract(&client.address, || {
+        for session_id in 0..registry::PAGE_RECORDS {
+            registry::record(&env, ActivityKind::Game, 1000 + session_id);
+        }
+        assert_eq!(storage::session_bucket_pages(&env, bucket), 2);
+        assert_eq!(storage::session_page(&env, bucket, 1).len(), 1);
+        // As if the pages between had filled up too
+        storage::save_session_page(&env, bucket, last_page, &vec![&env, record(2000, ActivityKind::Duel, 20_000)]);
+        storage::save_session_bucket_pages(&env, bucket, last_page + 1);
+    });
+    let range = client.get_sessions_in_range(&19_500, &20_000, &0);
+    assert_eq!(range.sessions.len(), registry::PAGE_RECORDS + 1);
+    assert_eq!(range.next_page, Some(last_page));
+    let rest = client.get_sessions_in_range(&19_500, &20_000, &last_page);
+    assert_eq!(rest.sessions, vec![&env, record(2000, ActivityKind::Duel, 20_000)]);
+    assert_eq!(rest.next_page, None);
+}
+
+#[test]
+fn test_updates_since_lists_what_changed_for_the_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (challenge_id, session_id) = ChallengeBuilder::new(&client, &player1, &player2).accept();
+
+    let updates = client.get_updates_since(&player2, &0);
+    assert!(updates.is_complete);
+    let changed = |kind, id| updates.changes.iter().any(|change| change.kind == kind && change.id == id);
+    assert!(changed(ActivityKind::Challenge, challenge_id));
+    assert!(changed(ActivityKind::Game, session_id));
+    assert_eq!(updates.changes.len(), 2);
+    let seen = updates.last_activity_seq;
+
+    // Someone else's challenge doesn't show; a commit in the player's game does
+    ChallengeBuilder::new(&client, &player1, &Address::generate(&env)).send();
+    assert!(client.get_updates_since(&player2, &seen).changes.is_empty());
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    client.commit_moves(&session_id, &player1, &inputs, &Bytes::new(&env));
+    let updates = client.get_updates_since(&player2, &seen);
+    assert_eq!(updates.changes.len(), 1);
+    assert_eq!(updates.changes.get(0).unwrap().kind, ActivityKind::Game);
+    assert_eq!(client.get_last_activity_seq(&player2), updates.last_activity_seq);
+
+    // A client that fell behind the log has to fetch everything again
+    for _ in 0..30 {
+        ChallengeBuilder::new(&client, &player1, &player2).send();
+    }
+    assert!(!client.get_updates_since(&player2, &seen).is_complete);
+}
+
+#[test]
+fn test_open_challenge_board_filters_and_accepts() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+
+    let small = client.post_open_challenge(&player1, &10);
+    let large = client.post_open_challenge(&player3, &500);
+    client.post_open_challenge(&player1, &50);
+
+    let all = client.get_open_challenges(&0, &10, &0, &i128::MAX);
+    assert_eq!(all.len(), 3);
+    let cheap = client.get_open_challenges(&0, &10, &0, &100);
+    assert_eq!(cheap.len(), 2);
+    let second = client.get_open_challenges(&1, &1, &0, &100);
+    assert_eq!(second.get(0).unwrap().points_wagered, 50);
+
+    let result = client.try_accept_open_challenge(&small, &player1);
+    assert_clash_error(&result, Error::CannotChallengeSelf);
+
+    // Accepting turns it into a regular challenge for both players
+    let session_id = client.accept_open_challenge(&small, &player2);
+    let game = client.get_game(&session_id);
+    assert_eq!((game.player1, game.player2), (player1.clone(), player2.clone()));
+    assert_eq!(game.player1_points, 10);
+    let challenge = client.get_challenge(&small);
+    assert_eq!(challenge.status, ChallengeStatus::Accepted);
+    assert_eq!(challenge.session_id, Some(session_id));
+    assert_eq!(client.get_player_challenges(&player2).0.len(), 1);
+    assert_eq!(client.get_open_challenges(&0, &10, &0, &i128::MAX).len(), 2);
+    let result = client.try_accept_open_challenge(&small, &player3);
+    assert_clash_error(&result, Error::ChallengeNotFound);
+
+    // Expired posts drop off the board
+    env.ledger().set_timestamp(env.ledger().timestamp() + 7 * 24 * 60 * 60 + 1);
+    assert!(client.get_open_challenges(&0, &10, &0, &i128::MAX).is_empty());
+    let result = client.try_accept_open_challenge(&large, &player2);
+    assert_clash_error(&result, Error::ChallengeExpired);
+}
+
+#[test]
+fn test_one_player_cannot_fill_the_open_board() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let first = client.post_open_challenge(&player1, &10);
+    client.post_open_challenge(&player1, &20);
+    client.post_open_challenge(&player1, &30);
+    let result = client.try_post_open_challenge(&player1, &40);
+    assert_clash_error(&result, Error::TooManyOpenChallenges);
+    client.post_open_challenge(&player2, &40);
+
+    // Only the poster can withdraw a post, which frees their slot
+    let result = client.try_cancel_open_challenge(&first, &player2);
+    assert_clash_error(&result, Error::NotPlayer);
+    client.cancel_open_challenge(&first, &player1);
+    assert_clash_error(&client.try_accept_open_challenge(&first, &player2), Error::ChallengeNotFound);
+    assert_eq!(client.get_open_challenges(&0, &10, &0, &i128::MAX).len(), 3);
+    client.post_open_challenge(&player1, &40);
+
+    // Expired posts don't count against the poster
+    env.ledger().set_timestamp(env.ledger().timestamp() + 7 * 24 * 60 * 60 + 1);
+    client.post_open_challenge(&player1, &10);
+}
+
+#[test]
+fn test_friends_status_shows_who_is_looking_for_a_game() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+
+    assert_clash_error(&client.try_add_friend(&player1, &player1), Error::CannotFriendSelf);
+    client.add_friend(&player1, &player2);
+    client.add_friend(&player1, &player3);
+    client.add_friend(&player1, &player2);
+    client.set_looking_for_game(&player3, &true);
+
+    let status = |friend: &Address, looking_for_game| FriendStatus { friend: friend.clone(), looking_for_game };
+    assert_eq!(client.get_friends_status(&player1), vec![&env, status(&player2, false), status(&player3, true)]);
+    // One-sided, like following
+    assert!(client.get_friends_status(&player2).is_empty());
+
+    client.set_looking_for_game(&player3, &false);
+    client.remove_friend(&player1, &player2);
+    assert_eq!(client.get_friends_status(&player1), vec![&env, status(&player3, false)]);
+}
+
+#[test]
+fn test_failed_hub_start_leaves_challenge_open() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let challenge_id = ChallengeBuilder::new(&client, &player1, &player2).send();
+    client.set_hub(&env.register(FailingGameHub, ()));
+
+    let result = client.try_accept_challenge(&challenge_id, &player2, &0);
+    assert_clash_error(&result, Error::GameHubCallFailed);
+
+    let challenge = client.get_challenge(&challenge_id);
+    assert!(!challenge.is_accepted);
+    assert_eq!(challenge.session_id, None);
+}
+
+#[test]
+fn test_rematch_replays_the_pairing_and_links_sessions() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let live = GameBuilder::new(&client, 40, &player1, &player2).build(GamePhase::Committed);
+    assert_clash_error(&client.try_request_rematch(&live, &player2), Error::GameNotEnded);
+    assert_clash_error(&client.try_accept_rematch(&live, &player1), Error::RematchNotFound);
+
+    let first = GameBuilder::new(&client, 41, &player1, &player2)
+        .points(30, 70)
+        .build(GamePhase::Resolved);
+    client.request_rematch(&first, &player2);
+    assert_clash_error(&client.try_request_rematch(&first, &player1), Error::RematchAlreadyRequested);
+    assert_clash_error(&client.try_accept_rematch(&first, &player2), Error::NotPlayer);
+
+    // Same seats and stakes, on a new session linked both ways
+    let second = client.accept_rematch(&first, &player1);
+    let game = client.get_game(&second);
+    assert_eq!((game.player1, game.player2), (player1.clone(), player2.clone()));
+    assert_eq!((game.player1_points, game.player2_points), (30, 70));
+    assert_eq!(client.get_rematch(&first).unwrap().rematch_session_id, Some(second));
+    assert_clash_error(&client.try_accept_rematch(&first, &player1), Error::ChallengeAlreadyAccepted);
+
+    let moves = MoveSeq::repeat(&env, Attack::Fireball, Defense::Block);
+    play_started_game(&client, second, &player1, &player2, &moves, &moves);
+    let playback = client.get_game_playback(&second);
+    assert_eq!((playback.rematch_of, playback.rematched_as), (Some(first), None));
+    assert_eq!(client.get_game_playback(&first).rematched_as, Some(second));
+
+    // Offers lapse like challenges
+    client.request_rematch(&second, &player1);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 7 * 24 * 60 * 60 + 1);
+    assert_clash_error(&client.try_accept_rematch(&second, &player2), Error::ChallengeExpired);
+}
+
+#[test]
+fn test_cannot_challenge_self() {
+    let (_env, client, _hub, player1, _player2) = setup_test();
+
+    let result = client.try_send_challenge(&player1, &player1, &100);
+    assert_clash_error(&result, Error::CannotChallengeSelf);
+}
+
+// ============================================================================
+// Wager Validation Tests
+// ============================================================================
+
+#[test]
+fn test_negative_wager_rejected() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let result = client.try_send_challenge(&player1, &player2, &-1);
+    assert_clash_error(&result, Error::InvalidWager);
+
+    let result = client.try_start_game(&50, &player1, &player2, &100, &-100);
+    assert_clash_error(&result, Error::InvalidWager);
+}
+
+#[test]
+fn test_wager_limits_enforced() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let result = client.try_set_wager_limits(&10, &5);
+    assert_clash_error(&result, Error::InvalidWager);
+
+    client.set_wager_limits(&10, &1_000);
+    assert_eq!(client.get_wager_limits().max, 1_000);
+
+    let result = client.try_start_game(&51, &player1, &player2, &1_001, &100);
+    assert_clash_error(&result, Error::InvalidWager);
+    let result = client.try_send_challenge(&player1, &player2, &9);
+    assert_clash_error(&result, Error::InvalidWager);
+
+    // Challenges are re-checked on accept in case the limits were tightened
+    let challenge_id = client.send_challenge(&player1, &player2, &1_000);
+    client.set_wager_limits(&10, &500);
+    let result = client.try_accept_challenge(&challenge_id, &player2, &0);
+    assert_clash_error(&result, Error::InvalidWager);
+}
+
+// ============================================================================
+// Commit Deposit Tests
+// ============================================================================
+
+/// Configure a 5 unit commit deposit and fund both players with 10 units each
+fn setup_deposit(
+    env: &Env,
+    client: &ClashContractClient,
+    player1: &Address,
+    player2: &Address,
+) -> TokenClient<'static> {
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    let minter = StellarAssetClient::new(env, &sac.address());
+    minter.mint(player1, &10);
+    minter.mint(player2, &10);
+    client.set_commit_deposit(&sac.address(), &5);
+    TokenClient::new(env, &sac.address())
+}
+
+#[test]
+fn test_commit_deposit_refunded_on_reveal() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let token = setup_deposit(&env, &client, &player1, &player2);
+
+    let session_id = GameBuilder::new(&client, 60, &player1, &player2).build(GamePhase::Committed);
+    assert_eq!(token.balance(&player1), 5);
+    assert_eq!(token.balance(&client.address), 10);
+
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    client.reveal_moves(&session_id, &player1, &inputs, &MoveSeq::repeat(&env, Attack::Slash, Defense::Block));
+    assert_eq!(token.balance(&player1), 10);
+
+    let commitment = commitment_for(&env, &player2, session_id);
+    let inputs = public_inputs(&env, &player2, session_id, &commitment);
+    client.reveal_moves(&session_id, &player2, &inputs, &MoveSeq::repeat(&env, Attack::Slash, Defense::Block));
+    assert_eq!(token.balance(&player2), 10);
+    assert_eq!(token.balance(&client.address), 0);
+
+    let deadline = client.get_game(&session_id).reveal_deadline;
+    env.ledger().set_timestamp(deadline + 1);
+    let result = client.try_claim_reveal_timeout(&session_id, &player1);
+    assert_clash_error(&result, Error::NothingToClaim);
+}
+
+#[test]
+fn test_commit_deposit_forfeited_to_opponent() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let token = setup_deposit(&env, &client, &player1, &player2);
+
+    let session_id = GameBuilder::new(&client, 61, &player1, &player2).build(GamePhase::Committed);
+
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    client.reveal_moves(&session_id, &player1, &inputs, &MoveSeq::repeat(&env, Attack::Slash, Defense::Block));
+
+    let result = client.try_claim_reveal_timeout(&session_id, &player1);
+    assert_clash_error(&result, Error::DeadlineNotReached);
+
+    let deadline = client.get_game(&session_id).reveal_deadline;
+    env.ledger().set_timestamp(deadline + 1);
+
+    let result = client.claim_reveal_timeout(&session_id, &player1);
+    assert_eq!(result.winner, Some(player1.clone()));
+    assert_eq!(token.balance(&player1), 10);
+    assert_eq!(client.get_winnings(&session_id, &player1).get(token.address.clone()), Some(5));
+
+    // The forfeit is pulled, not pushed, and only once
+    let claimed = client.claim_winnings(&session_id, &player1);
+    assert_eq!(claimed.get(token.address.clone()), Some(5));
+    assert_eq!(token.balance(&player1), 15);
+    assert_eq!(token.balance(&player2), 5);
+    assert_eq!(token.balance(&client.address), 0);
+    let result = client.try_claim_winnings(&session_id, &player1);
+    assert_clash_error(&result, Error::NothingToClaim);
+    let result = client.try_claim_winnings(&session_id, &player2);
+    assert_clash_error(&result, Error::NothingToClaim);
+}
+
+#[test]
+fn test_spectator_predictions_split_the_losing_pool() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let token = TokenClient::new(&env, &sac.address());
+    let [alice, bob, carol] = [(); 3].map(|_| Address::generate(&env));
+    for spectator in [&alice, &bob, &carol] {
+        StellarAssetClient::new(&env, &sac.address()).mint(spectator, &100);
+    }
+
+    let session_id = GameBuilder::new(&client, 64, &player1, &player2).build(GamePhase::Started);
+    let result = client.try_place_prediction(&session_id, &alice, &player1, &60);
+    assert_clash_error(&result, Error::InvalidPrediction);
+    client.set_prediction_token(&Some(sac.address()));
+
+    client.place_prediction(&session_id, &alice, &player1, &60);
+    client.place_prediction(&session_id, &bob, &player1, &40);
+    client.place_prediction(&session_id, &carol, &player2, &50);
+    let result = client.try_place_prediction(&session_id, &player2, &player2, &10);
+    assert_clash_error(&result, Error::InvalidPrediction);
+    let result = client.try_place_prediction(&session_id, &carol, &player1, &10);
+    assert_clash_error(&result, Error::InvalidPrediction);
+
+    // Bob changes his mind before anyone commits, then Carol tops up
+    assert_eq!(client.withdraw_prediction(&session_id, &bob), 40);
+    assert_eq!(token.balance(&bob), 100);
+    client.place_prediction(&session_id, &carol, &player2, &10);
+    let pool = client.get_prediction_pool(&session_id).unwrap();
+    assert_eq!((pool.player1_total, pool.player2_total), (60, 60));
+
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    let proof = Bytes::new(&env);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment_for(&env, &player1, session_id));
+    client.commit_moves(&session_id, &player1, &inputs, &proof);
+    let result = client.try_place_prediction(&session_id, &bob, &player1, &10);
+    assert_clash_error(&result, Error::PredictionsClosed);
+    assert_clash_error(&client.try_withdraw_prediction(&session_id, &carol), Error::PredictionsClosed);
+    assert_clash_error(&client.try_claim_prediction(&session_id, &alice), Error::GameNotEnded);
+
+    let inputs = public_inputs(&env, &player2, session_id, &commitment_for(&env, &player2, session_id));
+    client.commit_moves(&session_id, &player2, &inputs, &proof);
+    for (player, moves) in [(&player1, &fireball), (&player2, &slash)] {
+        let inputs = public_inputs(&env, player, session_id, &commitment_for(&env, player, session_id));
+        client.reveal_moves(&session_id, player, &inputs, moves);
+    }
+    assert_eq!(client.resolve_battle(&session_id).winner, Some(player1.clone()));
+
+    // Alice takes the whole losing side; Carol gets nothing
+    assert_eq!(client.claim_prediction(&session_id, &alice), 120);
+    assert_eq!(token.balance(&alice), 160);
+    assert_clash_error(&client.try_claim_prediction(&session_id, &alice), Error::NothingToClaim);
+    assert_clash_error(&client.try_claim_prediction(&session_id, &carol), Error::NothingToClaim);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_spectator_picks_build_a_streak() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let [alice, bob] = [(); 2].map(|_| Address::generate(&env));
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+
+    // Alice backs player1 three times running; Bob backs player2 and then player1
+    for (session_id, bob_backs) in [(64, &player2), (65, &player1), (66, &player1)] {
+        GameBuilder::new(&client, session_id, &player1, &player2).build(GamePhase::Started);
+        client.make_pick(&session_id, &alice, &player1);
+        client.make_pick(&session_id, &bob, bob_backs);
+        assert_clash_error(&client.try_make_pick(&session_id, &alice, &player2), Error::InvalidPrediction);
+        assert_clash_error(&client.try_make_pick(&session_id, &player2, &player2), Error::InvalidPrediction);
+        assert_eq!(client.get_picks(&session_id).len(), 2);
+        play_started_game(&client, session_id, &player1, &player2, &fireball, &slash);
+        assert!(client.get_picks(&session_id).is_empty());
+    }
+
+    let stats = client.get_pick_stats(&alice);
+    assert_eq!(stats, PickStats { picks: 3, correct: 3, streak: 3, best_streak: 3 });
+    let stats = client.get_pick_stats(&bob);
+    assert_eq!(stats, PickStats { picks: 3, correct: 2, streak: 2, best_streak: 2 });
+    let badges = client.get_achievements(&alice);
+    assert_eq!(badges.len(), 1);
+    assert_eq!(badges.get(0).unwrap().achievement, Achievement::SharpEye);
+    assert!(client.get_achievements(&bob).is_empty());
+
+    // Picks close once both players have revealed
+    let session_id = GameBuilder::new(&client, 67, &player1, &player2).build(GamePhase::Revealed);
+    assert_clash_error(&client.try_make_pick(&session_id, &alice, &player1), Error::PredictionsClosed);
+}
+
+#[test]
+fn test_session_signer_moves_for_one_session_only() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let hot_key = Address::generate(&env);
+    let session_id = GameBuilder::new(&client, 64, &player1, &player2).build(GamePhase::Started);
+    let other_session = GameBuilder::new(&client, 65, &player1, &player2).build(GamePhase::Started);
+
+    let result = client.try_authorize_session_signer(&player1, &player1, &session_id);
+    assert_clash_error(&result, Error::InvalidSessionSigner);
+    client.authorize_session_signer(&player1, &hot_key, &session_id);
+    assert_eq!(client.get_session_signer(&session_id, &player1), Some(hot_key.clone()));
+
+    // The hot key signs the commit, still recorded for the player
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    client.commit_moves(&session_id, &player1, &inputs, &Bytes::new(&env));
+    assert_eq!(env.auths()[0].0, hot_key);
+    assert!(client.get_game(&session_id).has_player1_commitment);
+
+    // Other sessions still need the player's own account
+    let commitment = commitment_for(&env, &player1, other_session);
+    let inputs = public_inputs(&env, &player1, other_session, &commitment);
+    client.commit_moves(&other_session, &player1, &inputs, &Bytes::new(&env));
+    assert_eq!(env.auths()[0].0, player1);
+
+    client.revoke_session_signer(&player1, &session_id);
+    assert_eq!(client.get_session_signer(&session_id, &player1), None);
+    let result = client.try_revoke_session_signer(&player1, &session_id);
+    assert_clash_error(&result, Error::InvalidSessionSigner);
+
+    // Deposits come out of the player's own account, so games that take one are off
+    // limits, as are sessions yet to start while deposits are on
+    setup_deposit(&env, &client, &player1, &player2);
+    let result = client.try_authorize_session_signer(&player1, &hot_key, &66);
+    assert_clash_error(&result, Error::InvalidSessionSigner);
+    let deposit_game = GameBuilder::new(&client, 66, &player1, &player2).build(GamePhase::Started);
+    let result = client.try_authorize_session_signer(&player1, &hot_key, &deposit_game);
+    assert_clash_error(&result, Error::InvalidSessionSigner);
+    client.start_duel(&67, &player1, &player2, &100, &100);
+    client.authorize_session_signer(&player1, &hot_key, &67);
+}
+
+#[test]
+fn test_coach_reads_moves_once_the_battle_is_resolved() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let coach = Address::generate(&env);
+    let moves = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let session_id = GameBuilder::new(&client, 66, &player1, &player2)
+        .moves(moves.clone(), MoveSeq::repeat(&env, Attack::Slash, Defense::Block))
+        .build(GamePhase::Revealed);
+
+    let result = client.try_set_coach(&session_id, &player1, &Some(player1.clone()));
+    assert_clash_error(&result, Error::InvalidCoach);
+    client.set_coach(&session_id, &player1, &Some(coach.clone()));
+
+    // Not while the match is live
+    let result = client.try_get_coached_moves(&session_id, &coach, &player1);
+    assert_clash_error(&result, Error::CoachingLocked);
+    client.resolve_battle(&session_id);
+    assert_eq!(client.get_coached_moves(&session_id, &coach, &player1), moves);
+    let result = client.try_get_coached_moves(&session_id, &coach, &player2);
+    assert_clash_error(&result, Error::InvalidCoach);
+}
+
+#[test]
+fn test_captain_classes_are_snapshotted_at_start_and_modify_the_battle() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    assert_eq!(client.get_captain_class(&player1), CaptainClass::None);
+    client.set_captain_class(&player1, &CaptainClass::Brawler);
+    client.set_captain_class(&player2, &CaptainClass::Trickster);
+    assert_eq!(client.get_captain_class(&player1), CaptainClass::Brawler);
+
+    let session_id = GameBuilder::new(&client, 67, &player1, &player2)
+        .moves(
+            MoveSeq::repeat(&env, Attack::Slash, Defense::Block),
+            MoveSeq::repeat(&env, Attack::Fireball, Defense::Block),
+        )
+        .build(GamePhase::Revealed);
+    // Picking again only counts for later games
+    client.set_captain_class(&player2, &CaptainClass::Gunner);
+    client.resolve_battle(&session_id);
+
+    let playback = client.get_game_playback(&session_id);
+    assert_eq!(
+        (playback.player1_class, playback.player2_class),
+        (CaptainClass::Brawler, CaptainClass::Trickster)
+    );
+    // The Brawler's Slash deals 33 instead of 30; Block is no Dodge, so no graze
+    let first = playback.turn_results.get(0).unwrap();
+    assert_eq!((first.player1_damage_dealt, first.player2_damage_dealt), (33, 40));
+}
+
+#[test]
+fn test_last_reveal_can_resolve_in_the_same_call() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let reveal_and_resolve = |session_id: u32, player: &Address, moves: &Vec<Move>| {
+        let commitment = commitment_for(&env, player, session_id);
+        let inputs = public_inputs(&env, player, session_id, &commitment);
+        client.reveal_and_resolve(&session_id, player, &inputs, moves)
+    };
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+
+    let session_id = GameBuilder::new(&client, 68, &player1, &player2).build(GamePhase::Committed);
+    // The first reveal has nothing to resolve yet
+    assert_eq!(reveal_and_resolve(session_id, &player1, &fireball).reason, OutcomeReason::Pending);
+    let result = reveal_and_resolve(session_id, &player2, &slash);
+    assert_eq!(result.winner, Some(player1.clone()));
+    assert_eq!(result.reason, OutcomeReason::BattleDecision);
+    let game = client.get_game(&session_id);
+    assert!(game.has_battle_result && game.is_settled);
+
+    // A resolution pause lets the reveal through and leaves the battle for later
+    let session_id = GameBuilder::new(&client, 69, &player1, &player2).build(GamePhase::Committed);
+    reveal_and_resolve(session_id, &player1, &fireball);
+    client.set_pause(&PauseFlags {
+        resolution: true,
+        ..Default::default()
+    });
+    assert_eq!(reveal_and_resolve(session_id, &player2, &slash).reason, OutcomeReason::Pending);
+    assert!(client.get_game(&session_id).player2_commitment.has_revealed);
+    assert!(client.get_pending_resolutions(&0, &10).contains(session_id));
+}
+
+#[test]
+fn test_anyone_can_enforce_missed_deadlines() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = GameBuilder::new(&client, 62, &player1, &player2).build(GamePhase::Committed);
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    client.reveal_moves(&session_id, &player1, &inputs, &MoveSeq::repeat(&env, Attack::Slash, Defense::Block));
+    assert_clash_error(&client.try_enforce_deadlines(&session_id), Error::DeadlineNotReached);
+
+    // Nobody signs: the player who revealed wins without coming back
+    env.ledger().set_timestamp(client.get_game(&session_id).reveal_deadline + 1);
+    let result = client.enforce_deadlines(&session_id);
+    assert!(env.auths().is_empty());
+    assert_eq!(result.winner, Some(player1.clone()));
+    assert_eq!(result.reason, OutcomeReason::RevealTimeout);
+    assert!(client.get_game(&session_id).has_battle_result);
+    assert_clash_error(&client.try_enforce_deadlines(&session_id), Error::GameAlreadyEnded);
+
+    // A duel nobody acted in is voided as a draw
+    client.start_duel(&63, &player1, &player2, &100, &100);
+    env.ledger().set_timestamp(client.get_duel(&63).deadline + 1);
+    let result = client.enforce_deadlines(&63);
+    assert!(result.is_draw);
+    assert_eq!(client.get_duel(&63).phase, DuelPhase::Finished);
+    assert_clash_error(&client.try_enforce_deadlines(&64), Error::GameNotFound);
+}
+
+#[test]
+fn test_reveal_window_is_configurable() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let mut config = client.get_game_config();
+    assert_eq!(config.reveal_window_secs, 24 * 60 * 60);
+    config.reveal_window_secs = 60;
+    assert_clash_error(&client.try_set_game_config(&config), Error::InvalidGameConfig);
+    config.reveal_window_secs = 10 * 60;
+    client.set_game_config(&config);
+
+    env.ledger().set_timestamp(1_000);
+    let session_id = GameBuilder::new(&client, 62, &player1, &player2).build(GamePhase::Committed);
+    assert_eq!(client.get_game(&session_id).reveal_deadline, 1_600);
+}
+
+#[test]
+fn test_forfeit_concedes_and_returns_deposits() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let token = setup_deposit(&env, &client, &player1, &player2);
+
+    let session_id = GameBuilder::new(&client, 63, &player1, &player2).build(GamePhase::Committed);
+    let stranger = Address::generate(&env);
+    assert_clash_error(&client.try_forfeit(&session_id, &stranger), Error::NotPlayer);
+
+    env.ledger().set_timestamp(500);
+    let result = client.forfeit(&session_id, &player2);
+    assert_eq!(result.winner, Some(player1.clone()));
+    assert_eq!(result.reason, OutcomeReason::Forfeit);
+    assert!(client.get_game(&session_id).is_settled);
+    assert_eq!(client.get_escrow(&session_id).unwrap().released_at, 500);
+    assert_clash_error(&client.try_forfeit(&session_id, &player1), Error::GameAlreadyEnded);
+
+    // Surrender isn't a no-show: each side gets its own deposit back
+    client.claim_winnings(&session_id, &player1);
+    client.claim_winnings(&session_id, &player2);
+    assert_eq!(token.balance(&player1), 10);
+    assert_eq!(token.balance(&player2), 10);
+}
+
+#[test]
+fn test_stale_game_can_be_cancelled_with_stakes_returned() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    env.ledger().set_timestamp(1_000);
+    let session_id = GameBuilder::new(&client, 64, &player1, &player2).build(GamePhase::Started);
+
+    assert_clash_error(&client.try_cancel_game(&session_id, &player1), Error::DeadlineNotReached);
+    env.ledger().set_timestamp(1_000 + 24 * 60 * 60 + 1);
+    let stranger = Address::generate(&env);
+    assert_clash_error(&client.try_cancel_game(&session_id, &stranger), Error::NotPlayer);
+
+    client.cancel_game(&session_id, &client.get_admin());
+    let game = client.get_game(&session_id);
+    assert!(game.is_cancelled && game.is_settled);
+    assert_eq!(game.battle_result.winner, None);
+    assert_eq!(game.battle_result.reason, OutcomeReason::Voided);
+    assert!(client.get_escrow(&session_id).unwrap().released_at > 0);
+    assert_eq!(client.get_player_stats(&player1), client.get_player_stats(&stranger));
+    assert_clash_error(&client.try_cancel_game(&session_id, &player1), Error::GameAlreadyEnded);
+
+    // Once anyone has committed, the game has to be played out or timed out
+    let session_id = GameBuilder::new(&client, 65, &player1, &player2).build(GamePhase::Started);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment_for(&env, &player1, session_id));
+    client.commit_moves(&session_id, &player1, &inputs, &Bytes::new(&env));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 24 * 60 * 60 + 1);
+    assert_clash_error(&client.try_cancel_game(&session_id, &player2), Error::GameInProgress);
+}
+
+#[test]
+fn test_game_past_its_lifetime_expires_with_stakes_refunded() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let token = setup_raise(&env, &client, &player1, &player2);
+    let session_id = GameBuilder::new(&client, 66, &player1, &player2).build(GamePhase::Committed);
+    client.raise_wager(&session_id, &player2, &15);
+    client.call_raise(&session_id, &player1);
+
+    // Stalled in the reveal phase with a called raise in escrow
+    let expiry = client.get_game_expiry(&session_id);
+    assert_eq!(expiry, client.get_game(&session_id).started_at + game::MAX_GAME_LIFETIME_SECS);
+    env.ledger().set_timestamp(expiry);
+    assert_clash_error(&client.try_expire_game(&session_id), Error::DeadlineNotReached);
+    env.ledger().set_timestamp(expiry + 1);
+    client.expire_game(&session_id);
+
+    let game = client.get_game(&session_id);
+    assert!(game.is_cancelled && game.is_settled);
+    assert_eq!(game.battle_result.reason, OutcomeReason::Voided);
+    assert!(client.get_escrow(&session_id).unwrap().released_at > 0);
+    client.claim_winnings(&session_id, &player1);
+    client.claim_winnings(&session_id, &player2);
+    assert_eq!((token.balance(&player1), token.balance(&player2)), (50, 50));
+    assert_clash_error(&client.try_expire_game(&session_id), Error::GameAlreadyEnded);
+}
+
+// ============================================================================
+// Blind Raise Tests
+// ============================================================================
+
+/// Allow raises of up to 20 units and fund both players with 50 units each
+fn setup_raise(
+    env: &Env,
+    client: &ClashContractClient,
+    player1: &Address,
+    player2: &Address,
+) -> TokenClient<'static> {
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    let minter = StellarAssetClient::new(env, &sac.address());
+    minter.mint(player1, &50);
+    minter.mint(player2, &50);
+    client.set_raise_config(&sac.address(), &20);
+    TokenClient::new(env, &sac.address())
+}
+
+#[test]
+fn test_called_raise_goes_to_winner() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let token = setup_raise(&env, &client, &player1, &player2);
+
+    let session_id = GameBuilder::new(&client, 110, &player1, &player2).build(GamePhase::Started);
+    let result = client.try_raise_wager(&session_id, &player2, &10);
+    assert_clash_error(&result, Error::RaiseNotAllowed);
+
+    let proof = Bytes::new(&env);
+    let inputs = [&player1, &player2].map(|player| {
+        let commitment = commitment_for(&env, player, session_id);
+        let inputs = public_inputs(&env, player, session_id, &commitment);
+        client.commit_moves(&session_id, player, &inputs, &proof);
+        inputs
+    });
+
+    let result = client.try_raise_wager(&session_id, &player2, &21);
+    assert_clash_error(&result, Error::InvalidRaise);
+    client.raise_wager(&session_id, &player2, &15);
+    let result = client.try_raise_wager(&session_id, &player1, &5);
+    assert_clash_error(&result, Error::RaiseNotAllowed);
+    let result = client.try_call_raise(&session_id, &player2);
+    assert_clash_error(&result, Error::NotPlayer);
+
+    // Nobody reveals until the raise is answered
+    let p1_moves = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let result = client.try_reveal_moves(&session_id, &player1, &inputs[0], &p1_moves);
+    assert_clash_error(&result, Error::RaisePending);
+
+    client.call_raise(&session_id, &player1);
+    assert_eq!(token.balance(&client.address), 30);
+
+    client.reveal_moves(&session_id, &player1, &inputs[0], &p1_moves);
+    client.reveal_moves(&session_id, &player2, &inputs[1], &MoveSeq::repeat(&env, Attack::Slash, Defense::Block));
+    let result = client.resolve_battle(&session_id);
+
+    assert_eq!(result.winner, Some(player1.clone()));
+    assert_eq!(token.balance(&client.address), 30);
+    client.claim_winnings(&session_id, &player1);
+    assert_eq!(token.balance(&player1), 65);
+    assert_eq!(token.balance(&player2), 35);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_effective_wager_includes_the_called_raise() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    setup_raise(&env, &client, &player1, &player2);
+
+    let session_id = GameBuilder::new(&client, 111, &player1, &player2).build(GamePhase::Started);
+    let proof = Bytes::new(&env);
+    let inputs = [&player1, &player2].map(|player| {
+        let commitment = commitment_for(&env, player, session_id);
+        let inputs = public_inputs(&env, player, session_id, &commitment);
+        client.commit_moves(&session_id, player, &inputs, &proof);
+        inputs
+    });
+
+    assert_eq!(client.get_effective_wager(&session_id, &player1), DEFAULT_POINTS);
+    client.raise_wager(&session_id, &player2, &15);
+    client.call_raise(&session_id, &player1);
+    assert_eq!(client.get_effective_wager(&session_id, &player1), DEFAULT_POINTS + 15);
+    assert_eq!(client.get_effective_wager(&session_id, &player2), DEFAULT_POINTS + 15);
+
+    // A reveal takes the commit's inputs as they were, with nothing appended
+    let moves = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    let mut padded = inputs[0].clone();
+    padded.extend_from_array(&[0u8; 32]);
+    let result = client.try_reveal_moves(&session_id, &player1, &padded, &moves);
+    assert_clash_error(&result, Error::InvalidPublicInputs);
+    client.reveal_moves(&session_id, &player1, &inputs[0], &moves);
+}
+
+#[test]
+fn test_fold_concedes_at_original_stake() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let token = setup_raise(&env, &client, &player1, &player2);
+
+    let session_id = GameBuilder::new(&client, 111, &player1, &player2).build(GamePhase::Committed);
+    client.raise_wager(&session_id, &player1, &20);
+
+    let result = client.fold_raise(&session_id, &player2);
+    assert_eq!(result.winner, Some(player1.clone()));
+    assert!(client.get_game(&session_id).is_settled);
+
+    // The raise comes back; only the hub stake changed hands
+    client.claim_winnings(&session_id, &player1);
+    assert_eq!(token.balance(&player1), 50);
+    assert_eq!(token.balance(&player2), 50);
+    let result = client.try_call_raise(&session_id, &player2);
+    assert_clash_error(&result, Error::NoRaisePending);
+}
+
+#[test]
+fn test_called_raise_pays_tiered_rake() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let token = setup_raise(&env, &client, &player1, &player2);
+
+    let unordered = vec![
+        &env,
+        FeeTier { min_wager: 10, fee_bps: 500 },
+        FeeTier { min_wager: 0, fee_bps: 1_000 },
+    ];
+    assert_clash_error(&client.try_set_fee_schedule(&unordered), Error::InvalidFeeSchedule);
+    let too_high = vec![&env, FeeTier { min_wager: 0, fee_bps: 1_001 }];
+    assert_clash_error(&client.try_set_fee_schedule(&too_high), Error::InvalidFeeSchedule);
+
+    // 10% of the pot below 10 units, 5% from 10 up
+    client.set_fee_schedule(&vec![
+        &env,
+        FeeTier { min_wager: 0, fee_bps: 1_000 },
+        FeeTier { min_wager: 10, fee_bps: 500 },
+    ]);
+    assert_eq!(client.quote_fee(&5), 1);
+    assert_eq!(client.quote_fee(&20), 2);
+
+    let session_id = GameBuilder::new(&client, 112, &player1, &player2).build(GamePhase::Committed);
+    client.raise_wager(&session_id, &player2, &20);
+    client.call_raise(&session_id, &player1);
+
+    // Changing the schedule mid-game doesn't change this game's cut
+    client.set_fee_schedule(&vec![&env]);
+    assert_eq!(client.quote_fee(&20), 0);
+
+    let [p1_inputs, p2_inputs] = [&player1, &player2].map(|player| {
+        let commitment = commitment_for(&env, player, session_id);
+        public_inputs(&env, player, session_id, &commitment)
+    });
+    client.reveal_moves(&session_id, &player1, &p1_inputs, &MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge));
+    client.reveal_moves(&session_id, &player2, &p2_inputs, &MoveSeq::repeat(&env, Attack::Slash, Defense::Block));
+    client.resolve_battle(&session_id);
+
+    client.claim_winnings(&session_id, &player1);
+    assert_eq!(token.balance(&player1), 68);
+    assert_eq!(client.get_collected_fees(&token.address), 2);
+
+    let treasury = Address::generate(&env);
+    assert_eq!(client.withdraw_fees(&token.address, &treasury), 2);
+    assert_eq!(token.balance(&treasury), 2);
+    assert_eq!(client.get_collected_fees(&token.address), 0);
+}
+
+#[test]
+fn test_collected_fees_sweep_to_the_treasury() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let token = TokenClient::new(&env, &sac.address());
+    StellarAssetClient::new(&env, &sac.address()).mint(&client.address, &30);
+    env.as_contract(&client.address, || fees::collect(&env, &sac.address(), 30));
+
+    assert_clash_error(&client.try_sweep_fees(&sac.address()), Error::NoTreasury);
+
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+    assert_eq!(client.get_treasury(), Some(treasury.clone()));
+    assert_eq!(client.sweep_fees(&sac.address()), 30);
+    assert_eq!(token.balance(&treasury), 30);
+    assert_eq!(client.sweep_fees(&sac.address()), 0);
+}
+
+// ============================================================================
+// Promo Code Tests
+// ============================================================================
+
+#[test]
+fn test_challenge_quote_matches_configuration() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let quote = client.quote_challenge(&25_0000000, &GameMode::Classic);
+    assert_eq!(quote.payout, 50_0000000);
+    assert_eq!(quote.win_reward, 0);
+    assert_eq!((quote.deposit_token, quote.raise_token), (None, None));
+    assert_eq!((quote.deposit_amount, quote.max_raise, quote.max_raise_fee), (0, 0, 0));
+    assert_eq!(quote.commit_window_secs, 0);
+    assert_eq!(quote.reveal_window_secs, client.get_game_config().reveal_window_secs);
+
+    let deposit = setup_deposit(&env, &client, &player1, &player2);
+    let raise = setup_raise(&env, &client, &player1, &player2);
+    client.set_fee_schedule(&vec![&env, FeeTier { min_wager: 10, fee_bps: 500 }]);
+    let quote = client.quote_challenge(&25_0000000, &GameMode::Correspondence);
+    assert_eq!(quote.deposit_token, Some(deposit.address));
+    assert_eq!(quote.deposit_amount, 5);
+    assert_eq!(quote.raise_token, Some(raise.address));
+    assert_eq!((quote.max_raise, quote.max_raise_fee), (20, 2));
+    assert_eq!(quote.commit_window_secs, 3 * 24 * 60 * 60);
+    assert_eq!(quote.reveal_window_secs, 3 * 24 * 60 * 60);
+    assert_eq!(quote.expires_at, env.ledger().timestamp() + 7 * 24 * 60 * 60);
+
+    let result = client.try_quote_challenge(&-1, &GameMode::Classic);
+    assert_clash_error(&result, Error::InvalidWager);
+}
+
+#[test]
+fn test_promo_codes_waive_fees_and_pay_deposit_bonus() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let token = setup_raise(&env, &client, &player1, &player2);
+    let csh = MockTokenClient::new(&env, &env.register(MockToken, ()));
+    client.set_token_contract(&csh.address);
+    client.set_fee_schedule(&vec![&env, FeeTier { min_wager: 0, fee_bps: 1_000 }]);
+
+    let waiver = Symbol::new(&env, "NOFEE");
+    let bonus = Symbol::new(&env, "WELCOME");
+    let result = client.try_create_promo_code(&waiver, &PromoReward::FeeWaiver, &1, &0);
+    assert_clash_error(&result, Error::InvalidPromoCode);
+    client.create_promo_code(&waiver, &PromoReward::FeeWaiver, &1, &1);
+    client.create_promo_code(&bonus, &PromoReward::DepositBonus, &3_0000000, &10);
+
+    client.redeem_code(&player1, &waiver);
+    assert_clash_error(&client.try_redeem_code(&player1, &waiver), Error::PromoAlreadyRedeemed);
+    assert_clash_error(&client.try_redeem_code(&player2, &waiver), Error::InvalidPromoCode);
+    let unknown = Symbol::new(&env, "NOPE");
+    assert_clash_error(&client.try_redeem_code(&player2, &unknown), Error::InvalidPromoCode);
+    assert_eq!(client.get_fee_waivers(&player1), 1);
+
+    // The bonus waits for the next commit deposit
+    client.redeem_code(&player2, &bonus);
+    assert_eq!(csh.balance(&player2), 0);
+    setup_deposit(&env, &client, &player1, &player2);
+    let session_id = GameBuilder::new(&client, 113, &player1, &player2).build(GamePhase::Committed);
+    assert_eq!(csh.balance(&player2), 3_0000000);
+    assert_eq!(client.get_promo_code(&bonus).unwrap().uses, 1);
+
+    // Player1 wins the called pot without paying the 10% rake
+    client.raise_wager(&session_id, &player2, &20);
+    client.call_raise(&session_id, &player1);
+    let [p1_inputs, p2_inputs] = [&player1, &player2].map(|player| {
+        let commitment = commitment_for(&env, player, session_id);
+        public_inputs(&env, player, session_id, &commitment)
+    });
+    client.reveal_moves(&session_id, &player1, &p1_inputs, &MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge));
+    client.reveal_moves(&session_id, &player2, &p2_inputs, &MoveSeq::repeat(&env, Attack::Slash, Defense::Block));
+    client.resolve_battle(&session_id);
+
+    client.claim_winnings(&session_id, &player1);
+    assert_eq!(token.balance(&player1), 70);
+    assert_eq!(client.get_collected_fees(&token.address), 0);
+    assert_eq!(client.get_fee_waivers(&player1), 0);
+}
+
+#[test]
+fn test_referrer_is_credited_after_the_first_battle() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let csh = MockTokenClient::new(&env, &env.register(MockToken, ()));
+    client.set_token_contract(&csh.address);
+    client.set_referral_bonus(&7_0000000);
+    let newcomer = Address::generate(&env);
+    GameBuilder::new(&client, 114, &player1, &player2).resolve();
+
+    // Referrers must have played; the new player must not have
+    let result = client.try_register_referral(&newcomer, &newcomer);
+    assert_clash_error(&result, Error::InvalidReferral);
+    let result = client.try_register_referral(&newcomer, &Address::generate(&env));
+    assert_clash_error(&result, Error::InvalidReferral);
+    let result = client.try_register_referral(&player2, &player1);
+    assert_clash_error(&result, Error::InvalidReferral);
+
+    client.register_referral(&newcomer, &player1);
+    let result = client.try_register_referral(&newcomer, &player2);
+    assert_clash_error(&result, Error::AlreadyReferred);
+    assert_eq!(client.get_referrer(&newcomer), Some(player1.clone()));
+    assert_eq!(client.get_referral_stats(&player1).referred, 1);
+    let result = client.try_claim_referral_rewards(&player1);
+    assert_clash_error(&result, Error::NothingToClaim);
+
+    // Credited once, on the first battle only
+    GameBuilder::new(&client, 115, &newcomer, &player2).resolve();
+    GameBuilder::new(&client, 116, &newcomer, &player2).resolve();
+    let stats = client.get_referral_stats(&player1);
+    assert_eq!(stats, ReferralStats { referred: 1, completed: 1, earned: 7_0000000, unclaimed: 7_0000000 });
+    assert_eq!(client.get_referrer(&newcomer), None);
+
+    let before = csh.balance(&player1);
+    assert_eq!(client.claim_referral_rewards(&player1), 7_0000000);
+    assert_eq!(csh.balance(&player1), before + 7_0000000);
+    assert_eq!(client.get_referral_stats(&player1).unclaimed, 0);
+}
+
+// ============================================================================
+// Lobby Tests
+// ============================================================================
+
+#[test]
+fn test_lobby_counts_and_pairs_waiting_players() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+
+    assert_clash_error(&client.try_join_queue(&player1, &3), Error::InvalidTier);
+    assert_eq!(client.join_queue(&player1, &1), None);
+    assert_clash_error(&client.try_join_queue(&player1, &0), Error::AlreadyQueued);
+    assert_eq!(client.join_queue(&player3, &0), None);
+
+    let stats = client.get_queue_stats();
+    assert_eq!(stats.len(), 3);
+    assert_eq!(stats.get(1).unwrap().wager, 100_0000000);
+    let waiting = [0, 1, 2].map(|tier| stats.get(tier).unwrap().waiting);
+    assert_eq!(waiting, [1, 1, 0]);
+
+    let session_id = client.join_queue(&player2, &1).unwrap();
+    let game = client.get_game(&session_id);
+    assert_eq!((game.player1, game.player2), (player1.clone(), player2));
+    assert_eq!(game.player1_points, 100_0000000);
+    assert_eq!(client.get_queue_stats().get(1).unwrap().waiting, 0);
+
+    // Matched players can queue again; leaving clears the count
+    client.join_queue(&player1, &2);
+    client.leave_queue(&player1);
+    assert_eq!(client.get_queue_stats().get(2).unwrap().waiting, 0);
+    assert_clash_error(&client.try_leave_queue(&player1), Error::NotQueued);
+}
+
+#[test]
+fn test_lobby_drops_a_waiting_player_whose_game_cannot_start() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+
+    // player1 queues, then registration becomes required before anyone is paired
+    assert_eq!(client.join_queue(&player1, &0), None);
+    client.set_require_registered(&true);
+    hub.register_player(&player2);
+    hub.register_player(&player3);
+
+    // player2 can't be paired with the unregistered player1, so player1 is dropped
+    // and player2 waits instead
+    assert_eq!(client.join_queue(&player2, &0), None);
+    assert_eq!(client.get_queue_stats().get(0).unwrap().waiting, 1);
+    assert_clash_error(&client.try_leave_queue(&player1), Error::NotQueued);
+
+    let session_id = client.join_queue(&player3, &0).unwrap();
+    let game = client.get_game(&session_id);
+    assert_eq!((game.player1, game.player2), (player2, player3));
+}
+
+#[test]
+fn test_shadowed_player_is_quietly_kept_off_the_ladder_and_lobby() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    GameBuilder::new(&client, 64, &player1, &player2).moves(fireball.clone(), slash.clone()).resolve();
+    assert_eq!(client.get_top_players(&10).len(), 2);
+
+    client.set_shadowed(&player1, &true);
+    assert!(client.is_shadowed(&player1));
+    assert_eq!(env.auths()[0].0, client.get_admin());
+    let top = client.get_top_players(&10);
+    assert_eq!(top.len(), 1);
+    assert_eq!(top.get(0).unwrap().player, player2);
+
+    // Games still play out, unrated, and don't earn the daily bonus either
+    env.ledger().set_timestamp(env.ledger().timestamp() + 24 * 60 * 60);
+    GameBuilder::new(&client, 65, &player1, &player2).moves(fireball, slash).resolve();
+    assert_eq!(client.get_player_stats(&player1).wins, 1);
+    assert_eq!(client.get_player_stats(&player2).losses, 1);
+    assert_eq!(client.get_bonus_points(&player1), crate::points::DAILY_WIN_POINTS);
+
+    // Open challenges post without error but aren't listed
+    client.post_open_challenge(&player1, &10);
+    assert!(client.get_open_challenges(&0, &10, &0, &i128::MAX).is_empty());
+
+    // The lobby only pairs shadowed players with each other
+    assert_eq!(client.join_queue(&player1, &0), None);
+    assert_eq!(client.join_queue(&player3, &0), None);
+    client.set_shadowed(&player2, &true);
+    let session_id = client.join_queue(&player2, &0).unwrap();
+    assert_eq!(client.get_game(&session_id).player1, player1);
+    assert_eq!(client.get_queue_stats().get(0).unwrap().waiting, 1);
+
+    client.set_shadowed(&player1, &false);
+    assert!(!client.is_shadowed(&player1));
+}
+
+// ============================================================================
+// Registration Gate Tests
+// ============================================================================
+
+#[test]
+fn test_registration_gate_blocks_unregistered_players() {
+    let (_env, client, hub, player1, player2) = setup_test();
+
+    // Off by default
+    client.start_game(&70, &player1, &player2, &100, &100);
+
+    client.set_require_registered(&true);
+    hub.register_player(&player1);
+
+    let result = client.try_start_game(&71, &player1, &player2, &100, &100);
+    assert_clash_error(&result, Error::PlayerNotRegistered);
+    let result = client.try_start_duel(&72, &player1, &player2, &100, &100);
+    assert_clash_error(&result, Error::PlayerNotRegistered);
+
+    hub.register_player(&player2);
+    client.start_game(&73, &player1, &player2, &100, &100);
+}
+
+// ============================================================================
+// Signed Settlement Tests
+// ============================================================================
+
+/// A player with a registered settlement key derived from `seed`
+fn signing_player(client: &ClashContractClient, seed: u8) -> (SigningKey, Address) {
+    let key = SigningKey::from_bytes(&[seed; 32]);
+    let player = player_address(&client.env, seed);
+    let public_key = BytesN::from_array(&client.env, &key.verifying_key().to_bytes());
+    client.set_settlement_key(&player, &public_key);
+    (key, player)
+}
+
+fn sign_result(
+    client: &ClashContractClient,
+    key: &SigningKey,
+    session_id: u32,
+    result: &SignedResult,
+) -> BytesN<64> {
+    let digest = client.signed_result_digest(&session_id, result);
+    BytesN::from_array(&client.env, &key.sign(&digest.to_array()).to_bytes())
+}
+
+#[test]
+fn test_signed_result_settles_after_challenge_window() {
+    let (env, client, _hub, _, _) = setup_test();
+    let token = MockTokenClient::new(&env, &env.register(MockToken, ()));
+    client.set_token_contract(&token.address);
+    let (key1, player1) = signing_player(&client, 1);
+    let (key2, player2) = signing_player(&client, 2);
+    let session_id = GameBuilder::new(&client, 80, &player1, &player2).build(GamePhase::Started);
+
+    let result = SignedResult {
+        winner: Some(player2.clone()),
+        is_draw: false,
+        player1_hp: 0,
+        player2_hp: 40,
+    };
+    let sig1 = sign_result(&client, &key1, session_id, &result);
+    let sig2 = sign_result(&client, &key2, session_id, &result);
+    let pending = client.settle_signed_result(&session_id, &result, &sig1, &sig2);
+
+    let finalized = client.try_finalize_signed_result(&session_id);
+    assert_clash_error(&finalized, Error::DeadlineNotReached);
+    let resubmitted = client.try_settle_signed_result(&session_id, &result, &sig1, &sig2);
+    assert_clash_error(&resubmitted, Error::SettlementPending);
+
+    env.ledger().set_timestamp(pending.challenge_deadline + 1);
+    let battle = client.finalize_signed_result(&session_id);
+    assert_eq!(battle.winner, Some(player2.clone()));
+    assert_eq!(battle.player2_hp, 40);
+    assert!(client.get_game(&session_id).is_settled);
+    assert_eq!(client.get_pending_settlement(&session_id), None);
+    assert_eq!(token.balance(&player2), crate::CSH_REWARD_PER_WIN);
+}
+
+#[test]
+fn test_disputed_signed_result_falls_back_to_zk() {
+    let (_env, client, _hub, _, _) = setup_test();
+    let (key1, player1) = signing_player(&client, 3);
+    let (key2, player2) = signing_player(&client, 4);
+    let session_id = GameBuilder::new(&client, 81, &player1, &player2).build(GamePhase::Revealed);
+
+    let result = SignedResult {
+        winner: None,
+        is_draw: true,
+        player1_hp: 10,
+        player2_hp: 10,
+    };
+    let sig1 = sign_result(&client, &key1, session_id, &result);
+    let sig2 = sign_result(&client, &key2, session_id, &result);
+    client.settle_signed_result(&session_id, &result, &sig1, &sig2);
+
+    let resolved = client.try_resolve_battle(&session_id);
+    assert_clash_error(&resolved, Error::SettlementPending);
+
+    client.dispute_signed_result(&session_id, &player1);
+    let finalized = client.try_finalize_signed_result(&session_id);
+    assert_clash_error(&finalized, Error::NoPendingSettlement);
+    let resubmitted = client.try_settle_signed_result(&session_id, &result, &sig1, &sig2);
+    assert_clash_error(&resubmitted, Error::SettlementDisputed);
+
+    // Both Slash/Block: the ZK path decides it instead of the signed draw
+    let battle = client.resolve_battle(&session_id);
+    assert!(battle.is_draw);
+}
+
+#[test]
+fn test_signed_result_must_name_a_player() {
+    let (_env, client, _hub, outsider, _) = setup_test();
+    let (key1, player1) = signing_player(&client, 5);
+    let (key2, player2) = signing_player(&client, 6);
+    let session_id = GameBuilder::new(&client, 82, &player1, &player2).build(GamePhase::Started);
+
+    let result = SignedResult {
+        winner: Some(outsider),
+        is_draw: false,
+        player1_hp: 0,
+        player2_hp: 0,
+    };
+    let sig1 = sign_result(&client, &key1, session_id, &result);
+    let sig2 = sign_result(&client, &key2, session_id, &result);
+    let submitted = client.try_settle_signed_result(&session_id, &result, &sig1, &sig2);
+    assert_clash_error(&submitted, Error::InvalidSignedResult);
+}
+
+#[test]
+fn test_signed_result_requires_settlement_keys() {
+    let (_env, client, _hub, _, player2) = setup_test();
+    let (key1, player1) = signing_player(&client, 9);
+    let session_id = GameBuilder::new(&client, 84, &player1, &player2).build(GamePhase::Started);
+
+    let result = SignedResult {
+        winner: Some(player1.clone()),
+        is_draw: false,
+        player1_hp: 50,
+        player2_hp: 0,
+    };
+    let sig1 = sign_result(&client, &key1, session_id, &result);
+    let submitted = client.try_settle_signed_result(&session_id, &result, &sig1, &sig1);
+    assert_clash_error(&submitted, Error::InvalidSignedResult);
+}
+
+#[test]
+#[should_panic]
+fn test_signed_result_rejects_forged_signature() {
+    let (_env, client, _hub, _, _) = setup_test();
+    let (key1, player1) = signing_player(&client, 7);
+    let (_, player2) = signing_player(&client, 8);
+    let session_id = GameBuilder::new(&client, 83, &player1, &player2).build(GamePhase::Started);
+
+    let result = SignedResult {
+        winner: Some(player1.clone()),
+        is_draw: false,
+        player1_hp: 50,
+        player2_hp: 0,
+    };
+    // player1 signs for both
+    let sig1 = sign_result(&client, &key1, session_id, &result);
+    client.settle_signed_result(&session_id, &result, &sig1, &sig1);
+}
+
+// ============================================================================
+// Export / Import Tests
+// ============================================================================
+
+#[test]
+fn test_export_import_restores_expired_game() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = GameBuilder::new(&client, 90, &player1, &player2).build(GamePhase::Committed);
+
+    let export = client.export_game(&session_id);
+    assert_eq!(export.game, client.get_game(&session_id));
+    assert_eq!(export.pending_settlement, ExportedSettlement::None);
+
+    // Simulate the temporary entry being archived
+    env.as_contract(&client.address, || {
+        env.storage().temporary().remove(&DataKey::Game(session_id));
+    });
+    assert_clash_error(&client.try_get_game(&session_id), Error::GameNotFound);
+
+    client.import_game(&export);
+    assert_eq!(client.get_game(&session_id), export.game);
+
+    // Play continues from where the export left off
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    client.reveal_moves(&session_id, &player1, &inputs, &MoveSeq::repeat(&env, Attack::Slash, Defense::Block));
+    let commitment = commitment_for(&env, &player2, session_id);
+    let inputs = public_inputs(&env, &player2, session_id, &commitment);
+    client.reveal_moves(&session_id, &player2, &inputs, &MoveSeq::repeat(&env, Attack::Slash, Defense::Block));
+    assert!(client.resolve_battle(&session_id).is_draw);
+}
+
+// ============================================================================
+// Practice Tests
+// ============================================================================
+
+#[test]
+fn test_practice_game_against_the_bot_is_unranked() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let practice_id = client.start_practice_game(&player1, &PracticeDifficulty::Hard);
+    let practice = client.get_practice_game(&practice_id).unwrap();
+    assert!(!practice.has_battle_result);
+    assert!(practice.bot_moves.is_empty());
+
+    let moves = MoveSeq::repeat(&env, Attack::Lightning, Defense::Counter);
+    assert_clash_error(&client.try_resolve_practice_battle(&practice_id, &player2, &moves), Error::NotPlayer);
+    let short = MoveSeq::of(&env, &[(Attack::Slash, Defense::Block)]);
+    assert_clash_error(&client.try_resolve_practice_battle(&practice_id, &player1, &short), Error::InvalidMoveSequence);
+
+    let result = client.resolve_practice_battle(&practice_id, &player1, &moves);
+    let practice = client.get_practice_game(&practice_id).unwrap();
+    assert_eq!(practice.battle_result, result);
+    assert_eq!(practice.player_moves, moves);
+    // The hard bot repeats its strongest combo
+    let bot_attack = practice.bot_moves.get(0).unwrap().attack;
+    assert!(practice.bot_moves.iter().all(|m| m.attack == bot_attack));
+
+    let result = client.try_resolve_practice_battle(&practice_id, &player1, &moves);
+    assert_clash_error(&result, Error::GameAlreadyEnded);
+
+    // The easy bot repeats one move
+    let easy_id = client.start_practice_game(&player1, &PracticeDifficulty::Easy);
+    client.resolve_practice_battle(&easy_id, &player1, &moves);
+    let bot_moves = client.get_practice_game(&easy_id).unwrap().bot_moves;
+    let first = bot_moves.get(0).unwrap();
+    assert!(bot_moves.iter().all(|m| m == first));
+
+    // Each tier keeps its own record, apart from the rating
+    for difficulty in [PracticeDifficulty::Easy, PracticeDifficulty::Hard] {
+        let stats = client.get_practice_stats(&player1, &difficulty);
+        assert_eq!(stats.wins + stats.losses + stats.draws, 1);
+    }
+    let normal = client.get_practice_stats(&player1, &PracticeDifficulty::Normal);
+    assert_eq!(normal.wins + normal.losses + normal.draws, 0);
+    assert_eq!(client.get_player_stats(&player1).rating, 1500);
+}
+
+
+
+// ============================================================================
+// Siege Tests
+// ============================================================================
+
+#[test]
+fn test_siege_defender_wins_by_surviving() {
+    let (env, client, _hub, attacker, defender) = setup_test();
+
+    // Main Slash and its Block-slot volley (also Slash) are both dodged
+    let result = GameBuilder::new(&client, 40, &attacker, &defender)
+        .siege()
+        .moves(
+            MoveSeq::repeat(&env, Attack::Slash, Defense::Block),
+            MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge),
+        )
+        .resolve();
+
+    assert_eq!(result.winner, Some(defender.clone()));
+    assert_eq!(result.player2_hp, 100);
+    assert_eq!(result.turn_results.len(), 3);
+
+    let attacker_stats = client.get_siege_stats(&attacker);
+    assert_eq!((attacker_stats.attacker_wins, attacker_stats.attacker_losses), (0, 1));
+    let defender_stats = client.get_siege_stats(&defender);
+    assert_eq!((defender_stats.defender_wins, defender_stats.defender_losses), (1, 0));
+}
+
+#[test]
+fn test_siege_attacker_breaks_through() {
+    let (env, client, _hub, attacker, defender) = setup_test();
+
+    // Fireball combo (40, 50) plus a 20 damage Fireball volley each turn: 60 + 70
+    let session_id = GameBuilder::new(&client, 41, &attacker, &defender)
+        .siege()
+        .moves(
+            MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge),
+            MoveSeq::repeat(&env, Attack::Slash, Defense::Block),
+        )
+        .build(GamePhase::Resolved);
+
+    let playback = client.get_game_playback(&session_id);
+    assert_eq!(playback.winner, Some(attacker.clone()));
+    assert_eq!(playback.turn_results.len(), 2);
+    assert_eq!(playback.final_player1_hp, 100);
+    assert_eq!(playback.final_player2_hp, -30);
+
+    assert_eq!(client.get_siege_stats(&attacker).attacker_wins, 1);
+    assert_eq!(client.get_siege_stats(&defender).defender_losses, 1);
+    // Classic games never touch siege stats
+    assert_eq!(client.get_siege_stats(&attacker).defender_wins, 0);
+}
+
+#[test]
+fn test_playback_keeps_usernames_from_the_start() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    client.set_username(&player1, &String::from_str(&env, "blackbeard"));
+    let session_id = GameBuilder::new(&client, 42, &player1, &player2).build(GamePhase::Revealed);
+    client.set_username(&player1, &String::from_str(&env, "redbeard"));
+    client.set_username(&player2, &String::from_str(&env, "anne_bonny"));
+    client.resolve_battle(&session_id);
+
+    let playback = client.get_game_playback(&session_id);
+    assert_eq!(playback.player1_username, Some(String::from_str(&env, "blackbeard")));
+    assert_eq!(playback.player2_username, None);
+}
+
+#[test]
+fn test_usernames_and_addresses_resolve_in_bulk() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let blackbeard = String::from_str(&env, "blackbeard");
+    let nobody = String::from_str(&env, "nobody");
+    client.set_username(&player1, &blackbeard);
+
+    let names = client.get_usernames(&vec![&env, player2.clone(), player1.clone()]);
+    assert_eq!(names, vec![&env, None, Some(blackbeard.clone())]);
+    let holders = client.get_addresses(&vec![&env, blackbeard, nobody]);
+    assert_eq!(holders, vec![&env, Some(player1.clone()), None]);
+
+    let mut many = vec![&env];
+    for _ in 0..identity::MAX_BULK_LOOKUPS + 1 {
+        many.push_back(player1.clone());
+    }
+    assert_eq!(client.get_usernames(&many).len(), identity::MAX_BULK_LOOKUPS);
+}
+
+#[test]
+fn test_usernames_can_be_released_transferred_and_expire() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    let name = String::from_str(&env, "blackbeard");
+    client.set_username(&player1, &name);
+
+    client.transfer_username(&player1, &player2);
+    assert_eq!(client.get_username(&player1), None);
+    assert_eq!(client.get_address_by_username(&name), Some(player2.clone()));
+    assert_clash_error(&client.try_release_username(&player1), Error::NoUsername);
+    client.release_username(&player2);
+    assert_eq!(client.get_address_by_username(&name), None);
+
+    // A battle keeps the name; a month without one frees it
+    client.set_username_expiry(&(30 * 24 * 60 * 60));
+    client.set_username(&player1, &name);
+    env.ledger().with_mut(|ledger| ledger.timestamp += 20 * 24 * 60 * 60);
+    GameBuilder::new(&client, 44, &player1, &player2).resolve();
+    assert_eq!(client.get_last_active_at(&player1), env.ledger().timestamp());
+    env.ledger().with_mut(|ledger| ledger.timestamp += 20 * 24 * 60 * 60);
+    assert_clash_error(&client.try_set_username(&player3, &name), Error::UsernameAlreadyTaken);
+    env.ledger().with_mut(|ledger| ledger.timestamp += 11 * 24 * 60 * 60);
+    client.set_username(&player3, &name);
+    assert_eq!(client.get_username(&player1), None);
+    assert_eq!(client.get_address_by_username(&name), Some(player3.clone()));
+
+    client.force_release(&name);
+    assert_eq!(client.get_username(&player3), None);
+    assert_eq!(client.get_address_by_username(&name), None);
+}
+
+#[test]
+fn test_linked_address_plays_as_its_primary() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let new_wallet = Address::generate(&env);
+    let name = String::from_str(&env, "blackbeard");
+    client.set_username(&player1, &name);
+    GameBuilder::new(&client, 45, &player1, &player2).resolve();
+
+    // Only fresh addresses can be linked, and only once they confirm
+    assert_clash_error(&client.try_link_address(&player1, &player2), Error::InvalidLink);
+    assert_clash_error(&client.try_confirm_link(&new_wallet, &player1), Error::InvalidLink);
+    client.link_address(&player1, &new_wallet);
+    assert_clash_error(&client.try_confirm_link(&new_wallet, &player2), Error::InvalidLink);
+    assert_eq!(client.get_identity(&new_wallet), new_wallet);
+    client.confirm_link(&new_wallet, &player1);
+    assert_eq!(client.get_identity(&new_wallet), player1);
+    assert_eq!(client.get_linked_addresses(&player1), vec![&env, new_wallet.clone()]);
+
+    // The new wallet's games, name and class all belong to the primary
+    let stats = client.get_player_stats(&player1);
+    client.set_captain_class(&new_wallet, &CaptainClass::Gunner);
+    assert_eq!(client.get_captain_class(&player1), CaptainClass::Gunner);
+    GameBuilder::new(&client, 46, &new_wallet, &player2)
+        .moves(
+            MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge),
+            MoveSeq::repeat(&env, Attack::Slash, Defense::Block),
+        )
+        .resolve();
+    let game = client.get_game(&46);
+    assert_eq!(game.player1_username, Some(name.clone()));
+    assert_eq!(game.player1_class, CaptainClass::Gunner);
+    assert_eq!(client.get_player_stats(&player1).wins, stats.wins + 1);
+    assert_eq!(client.get_player_stats(&new_wallet), client.get_player_stats(&player1));
+    assert_eq!(client.get_username(&new_wallet), Some(name));
+
+    // A linked address can't play its own primary
+    let result = client.try_start_game(&47, &player1, &new_wallet, &100, &100);
+    assert_clash_error(&result, Error::CannotChallengeSelf);
+    let result = client.try_start_duel(&47, &new_wallet, &player1, &100, &100);
+    assert_clash_error(&result, Error::CannotChallengeSelf);
+
+    assert_clash_error(&client.try_unlink_address(&player2, &new_wallet), Error::InvalidLink);
+    client.unlink_address(&new_wallet, &new_wallet);
+    assert_eq!(client.get_identity(&new_wallet), new_wallet);
+    assert_eq!(client.get_linked_addresses(&player1).len(), 0);
+    assert_eq!(client.get_player_stats(&player1).wins, stats.wins + 1);
+}
+
+#[test]
+fn test_busy_addresses_cannot_be_linked() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let in_game = player_address(&env, 3);
+    let challenger = player_address(&env, 4);
+    let poster = player_address(&env, 5);
+
+    client.start_game(&50, &in_game, &player2, &100, &100);
+    assert_clash_error(&client.try_link_address(&player1, &in_game), Error::InvalidLink);
+
+    let challenge_id = ChallengeBuilder::new(&client, &challenger, &player2).send();
+    assert_clash_error(&client.try_link_address(&player1, &challenger), Error::InvalidLink);
+    client.cancel_challenge(&challenge_id, &challenger);
+    client.link_address(&player1, &challenger);
+
+    // Checked again on confirmation, in case it got busy after the request
+    client.link_address(&player1, &poster);
+    let open_id = client.post_open_challenge(&poster, &10);
+    assert_clash_error(&client.try_link_address(&player1, &poster), Error::InvalidLink);
+    let result = client.try_confirm_link(&poster, &player1);
+    assert_clash_error(&result, Error::InvalidLink);
+    client.cancel_open_challenge(&open_id, &poster);
+    client.confirm_link(&poster, &player1);
+}
+
+#[test]
+fn test_playback_reads_the_turns_stored_at_resolve() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = 43;
+    GameBuilder::new(&client, session_id, &player1, &player2)
+        .moves(
+            MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge),
+            MoveSeq::repeat(&env, Attack::Slash, Defense::Block),
+        )
+        .resolve();
+
+    let playback = client.get_game_playback(&session_id);
+    assert_eq!(playback.turn_results.len(), 3);
+    assert_eq!(playback.reason, OutcomeReason::BattleDecision);
+    let stored = env.as_contract(&client.address, || {
+        let stored = env.storage().temporary().get::<_, soroban_sdk::Vec<DetailedTurnResult>>(&DataKey::PlaybackTurns(session_id));
+        // Games resolved before turns were stored are built from the battle instead
+        env.storage().temporary().remove(&DataKey::PlaybackTurns(session_id));
+        stored
+    });
+    assert_eq!(stored, Some(playback.turn_results.clone()));
+    assert_eq!(client.get_game_playback(&session_id), playback);
+}
+
+// ============================================================================
+// Correspondence Tests
+// ============================================================================
+
+#[test]
+fn test_correspondence_windows_are_announced() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let start = env.ledger().timestamp();
+    let three_days = 3 * 24 * 60 * 60;
+
+    let session_id = GameBuilder::new(&client, 100, &player1, &player2)
+        .correspondence()
+        .build(GamePhase::Started);
+    let commit_opened = WindowOpened {
+        session_id,
+        window: GameWindow::Commit,
+        deadline: start + three_days,
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [commit_opened.to_xdr(&env, &client.address)]
+    );
+    assert_eq!(client.get_game(&session_id).commit_deadline, start + three_days);
+
+    // Two days in, both commit: the reveal window gets three more days
+    env.ledger().set_timestamp(start + 2 * 24 * 60 * 60);
+    let proof = Bytes::new(&env);
+    let inputs = [&player1, &player2].map(|player| {
+        let commitment = commitment_for(&env, player, session_id);
+        let inputs = public_inputs(&env, player, session_id, &commitment);
+        client.commit_moves(&session_id, player, &inputs, &proof);
+        inputs
+    });
+    let reveal_deadline = env.ledger().timestamp() + three_days;
+    let committed = MovesCommitted {
+        session_id,
+        player: player2.clone(),
+        commitment: commitment_for(&env, &player2, session_id),
+    };
+    let reveal_opened = WindowOpened {
+        session_id,
+        window: GameWindow::Reveal,
+        deadline: reveal_deadline,
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [
+            committed.to_xdr(&env, &client.address),
+            reveal_opened.to_xdr(&env, &client.address),
+        ]
+    );
+
+    // Still within the window two days later, where a classic game would have expired
+    env.ledger().set_timestamp(reveal_deadline - 1);
+    let moves = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    client.reveal_moves(&session_id, &player1, &inputs[0], &moves);
+    client.reveal_moves(&session_id, &player2, &inputs[1], &moves);
+    assert!(client.resolve_battle(&session_id).is_draw);
+
+    // Kept in persistent storage
+    env.as_contract(&client.address, || {
+        assert!(env.storage().persistent().has(&DataKey::Game(session_id)));
+        assert!(!env.storage().temporary().has(&DataKey::Game(session_id)));
+    });
+}
+
+#[test]
+fn test_correspondence_missed_commit_can_be_claimed() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = GameBuilder::new(&client, 101, &player1, &player2)
+        .correspondence()
+        .build(GamePhase::Started);
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    client.commit_moves(&session_id, &player1, &inputs, &Bytes::new(&env));
+
+    let result = client.try_claim_reveal_timeout(&session_id, &player1);
+    assert_clash_error(&result, Error::DeadlineNotReached);
+
+    let deadline = client.get_game(&session_id).commit_deadline;
+    env.ledger().set_timestamp(deadline + 1);
+
+    let commitment = commitment_for(&env, &player2, session_id);
+    let inputs = public_inputs(&env, &player2, session_id, &commitment);
+    let late = client.try_commit_moves(&session_id, &player2, &inputs, &Bytes::new(&env));
+    assert_clash_error(&late, Error::DeadlinePassed);
+
+    let result = client.claim_reveal_timeout(&session_id, &player1);
+    assert_eq!(result.winner, Some(player1));
+}
+
+#[test]
+fn test_agreed_pause_freezes_correspondence_deadlines() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let day = 24 * 60 * 60;
+
+    let classic = GameBuilder::new(&client, 102, &player1, &player2).build(GamePhase::Started);
+    let result = client.try_propose_pause(&classic, &player1, &day);
+    assert_clash_error(&result, Error::InvalidPause);
+
+    let session_id = GameBuilder::new(&client, 103, &player1, &player2)
+        .correspondence()
+        .build(GamePhase::Started);
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    client.commit_moves(&session_id, &player1, &inputs, &Bytes::new(&env));
+    let deadline = client.get_game(&session_id).commit_deadline;
+
+    // Only the opponent can accept, and only within the allowance
+    let result = client.try_propose_pause(&session_id, &player1, &(15 * day));
+    assert_clash_error(&result, Error::InvalidPause);
+    client.propose_pause(&session_id, &player1, &(10 * day));
+    let result = client.try_accept_pause(&session_id, &player1);
+    assert_clash_error(&result, Error::InvalidPause);
+    let paused_until = client.accept_pause(&session_id, &player2);
+    assert_eq!(paused_until, env.ledger().timestamp() + 10 * day);
+    assert_eq!(client.get_game(&session_id).commit_deadline, deadline + 10 * day);
+
+    // The old deadline no longer counts
+    env.ledger().set_timestamp(deadline + 1);
+    let result = client.try_claim_reveal_timeout(&session_id, &player1);
+    assert_clash_error(&result, Error::DeadlineNotReached);
+
+    // Resuming early hands the unused days back
+    client.resume_game(&session_id, &player2);
+    let pause = client.get_game_pause(&session_id);
+    let unused = paused_until - env.ledger().timestamp();
+    assert_eq!(pause.used_secs, 10 * day - unused);
+    assert_eq!(client.get_game(&session_id).commit_deadline, deadline + 10 * day - unused);
+    let result = client.try_resume_game(&session_id, &player1);
+    assert_clash_error(&result, Error::InvalidPause);
+
+    // A reveal window that opens during a pause starts when the pause ends
+    client.propose_pause(&session_id, &player1, &day);
+    let paused_until = client.accept_pause(&session_id, &player2);
+    let commitment = commitment_for(&env, &player2, session_id);
+    let inputs = public_inputs(&env, &player2, session_id, &commitment);
+    client.commit_moves(&session_id, &player2, &inputs, &Bytes::new(&env));
+    let window = client.get_game(&session_id).reveal_deadline - paused_until;
+    env.ledger().with_mut(|l| l.timestamp += 60);
+    client.resume_game(&session_id, &player1);
+    assert_eq!(client.get_game(&session_id).reveal_deadline, env.ledger().timestamp() + window);
+
+    let pause = client.get_game_pause(&session_id);
+    client.set_max_game_pause(&pause.used_secs);
+    let result = client.try_propose_pause(&session_id, &player2, &day);
+    assert_clash_error(&result, Error::InvalidPause);
+}
+
+// ============================================================================
+// Series Tests
+// ============================================================================
+
+#[test]
+fn test_series_plays_rounds_until_a_majority() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+
+    let result = client.try_start_series(&200, &player1, &player2, &100_0000000, &100_0000000, &4);
+    assert_clash_error(&result, Error::InvalidSeriesLength);
+
+    let first = client.start_series(&200, &player1, &player2, &100_0000000, &100_0000000, &3);
+    assert!(client.get_escrow(&first).is_none());
+    play_started_game(&client, first, &player1, &player2, &fireball, &slash);
+
+    // A drawn round doesn't count towards the majority
+    let series = client.get_series(&200);
+    assert_eq!((series.player1_wins, series.player2_wins, series.sessions.len()), (1, 0, 2));
+    let second = series.sessions.get(1).unwrap();
+    play_started_game(&client, second, &player1, &player2, &slash, &slash);
+    assert!(!client.get_series(&200).is_finished);
+    assert_eq!(client.get_escrow(&200).unwrap().released_at, 0);
+
+    let third = client.get_series(&200).sessions.get(2).unwrap();
+    play_started_game(&client, third, &player1, &player2, &fireball, &slash);
+
+    // Two round wins take it, rated once as a single result
+    let series = client.get_series(&200);
+    assert!(series.is_finished);
+    assert_eq!((series.player1_wins, series.player2_wins, series.draws), (2, 0, 1));
+    assert_eq!(series.winner, Some(player1.clone()));
+    assert_eq!(series.sessions.len(), 3);
+    assert_ne!(client.get_escrow(&200).unwrap().released_at, 0);
+    assert_eq!(client.get_player_stats(&player1).wins, 1);
+    assert_eq!(client.get_player_stats(&player2).losses, 1);
+}
+
+#[test]
+fn test_series_is_called_off_when_its_round_is_voided() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+
+    let first = client.start_series(&201, &player1, &player2, &100_0000000, &100_0000000, &3);
+    play_started_game(&client, first, &player1, &player2, &fireball, &slash);
+
+    // Nobody commits to the second round, which goes stale and is cancelled
+    let second = client.get_series(&201).sessions.get(1).unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + 2 * 24 * 60 * 60);
+    client.cancel_game(&second, &player2);
+
+    let series = client.get_series(&201);
+    assert!(series.is_finished && series.is_cancelled);
+    assert_eq!((series.winner, series.sessions.len()), (None, 2));
+    assert_ne!(client.get_escrow(&201).unwrap().released_at, 0);
+    assert_eq!(client.get_player_stats(&player1).wins, 0);
+
+    // A round that stalls mid-game and expires calls off its series the same way
+    let round = client.start_series(&202, &player1, &player2, &100_0000000, &100_0000000, &3);
+    let p1_inputs = public_inputs(&env, &player1, round, &commitment_for(&env, &player1, round));
+    client.commit_moves(&round, &player1, &p1_inputs, &Bytes::new(&env));
+    env.ledger().set_timestamp(client.get_game_expiry(&round) + 1);
+    client.expire_game(&round);
+    let series = client.get_series(&202);
+    assert!(series.is_finished && series.is_cancelled);
+    assert_ne!(client.get_escrow(&202).unwrap().released_at, 0);
+}
+
+// ============================================================================
+// Tournament Tests
+// ============================================================================
+
+/// Check every player in, then start the tournament once its deadline passes
+fn start_tournament(env: &Env, client: &ClashContractClient, id: u32, deadline: u64, players: &[Address]) {
+    env.ledger().set_timestamp(deadline - tournament::CHECK_IN_SECS);
+    for player in players {
+        client.check_in(&id, player);
+    }
+    env.ledger().set_timestamp(deadline + 1);
+    client.advance_bracket(&id);
+}
+
+#[test]
+fn test_tournament_bracket_runs_to_a_champion() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 3600;
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+
+    let single = BracketFormat::SingleElimination;
+    let result = client.try_create_tournament(&player1, &3, &10_0000000, &deadline, &single);
+    assert_clash_error(&result, Error::InvalidTournament);
+    let id = client.create_tournament(&player1, &4, &10_0000000, &deadline, &single);
+    let no_show = Address::generate(&env);
+    for player in [&player1, &player2, &player3, &no_show] {
+        client.register_for_tournament(&id, player);
+    }
+    let result = client.try_register_for_tournament(&id, &player2);
+    assert_clash_error(&result, Error::AlreadyRegistered);
+    assert_clash_error(&client.try_advance_bracket(&id), Error::DeadlineNotReached);
+
+    // Check-in opens half an hour before the start, for registered players only
+    assert_clash_error(&client.try_check_in(&id, &player1), Error::CheckInClosed);
+    env.ledger().set_timestamp(deadline - tournament::CHECK_IN_SECS);
+    for player in [&player1, &player2, &player3] {
+        client.check_in(&id, player);
+    }
+    assert_clash_error(&client.try_check_in(&id, &Address::generate(&env)), Error::NotPlayer);
+
+    // The no-show is dropped, leaving three players in a four-slot bracket: the top
+    // seed gets a bye
+    env.ledger().set_timestamp(deadline + 1);
+    let tournament = client.advance_bracket(&id);
+    assert_eq!(tournament.players, vec![&env, player1.clone(), player2.clone(), player3.clone()]);
+    assert_clash_error(&client.try_check_in(&id, &no_show), Error::CheckInClosed);
+    let result = client.try_register_for_tournament(&id, &Address::generate(&env));
+    assert_clash_error(&result, Error::RegistrationClosed);
+    let bracket = client.get_bracket(&id);
+    assert_eq!(bracket.len(), 3);
+    let bye = bracket.get(0).unwrap();
+    assert_eq!((bye.player2, bye.session_id, bye.winner), (None, None, Some(player1.clone())));
+    let semi = bracket.get(1).unwrap().session_id.unwrap();
+    play_started_game(&client, semi, &player2, &player3, &fireball, &slash);
+
+    // The final is drawn once and replayed
+    let tournament = client.advance_bracket(&id);
+    assert_eq!(tournament.round, 1);
+    let final_match = client.get_bracket(&id).get(2).unwrap();
+    assert_eq!((final_match.player1, final_match.player2), (Some(player1.clone()), Some(player2.clone())));
+    let drawn = final_match.session_id.unwrap();
+    play_started_game(&client, drawn, &player1, &player2, &slash, &slash);
+    client.advance_bracket(&id);
+    let replay = client.get_bracket(&id).get(2).unwrap().session_id.unwrap();
+    assert_ne!(replay, drawn);
+    play_started_game(&client, replay, &player1, &player2, &slash, &fireball);
+
+    let tournament = client.advance_bracket(&id);
+    assert_eq!(tournament.status, TournamentStatus::Completed);
+    assert_eq!(tournament.champion, Some(player2));
+    assert_eq!(client.get_tournament(&id), tournament);
+}
+
+#[test]
+fn test_tournament_bracket_order_and_matches_without_a_winner() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let deadline = env.ledger().timestamp() + 3600;
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+
+    // Equal ratings: seeds follow registration, not check-in
+    let format = BracketFormat::SingleElimination;
+    let id = client.create_tournament(&Address::generate(&env), &8, &10_0000000, &deadline, &format);
+    let seeds: [Address; 8] = core::array::from_fn(|seed| player_address(&env, 10 + seed as u8));
+    for player in &seeds {
+        client.register_for_tournament(&id, player);
+    }
+    let mut check_ins = seeds.clone();
+    check_ins.reverse();
+    start_tournament(&env, &client, id, deadline, &check_ins);
+    let bracket = client.get_bracket(&id);
+    let pairs = [(0, 7), (3, 4), (1, 6), (2, 5)];
+    for (k, (top, bottom)) in pairs.into_iter().enumerate() {
+        let slot = bracket.get(k as u32).unwrap();
+        assert_eq!((slot.player1, slot.player2), (Some(seeds[top].clone()), Some(seeds[bottom].clone())));
+    }
+    let session = |k: u32| client.get_bracket(&id).get(k).unwrap().session_id.unwrap();
+
+    // Two games start per call; the next call starts the rest
+    assert_eq!(client.get_bracket(&id).get(2).unwrap().session_id, None);
+    client.advance_bracket(&id);
+
+    // Seed 4 commits and seed 5 never shows; seeds 2 and 3 win their games
+    let no_show = session(1);
+    let inputs = public_inputs(&env, &seeds[3], no_show, &commitment_for(&env, &seeds[3], no_show));
+    client.commit_moves(&no_show, &seeds[3], &inputs, &Bytes::new(&env));
+    play_started_game(&client, session(2), &seeds[1], &seeds[6], &fireball, &slash);
+    play_started_game(&client, session(3), &seeds[2], &seeds[5], &fireball, &slash);
+
+    // Seeds 1 and 8 draw, and draw the replay too: the higher seed goes through
+    play_started_game(&client, session(0), &seeds[0], &seeds[7], &slash, &slash);
+    client.advance_bracket(&id);
+    assert!(client.get_bracket(&id).get(0).unwrap().replayed);
+    play_started_game(&client, session(0), &seeds[0], &seeds[7], &slash, &slash);
+
+    // The no-show's game holds the round until its commit deadline passes, and then
+    // the seed who committed goes through on a walkover
+    assert_eq!(client.advance_bracket(&id).round, 0);
+    env.ledger().set_timestamp(env.ledger().timestamp() + tournament::MATCH_COMMIT_SECS + 1);
+    assert_eq!(client.advance_bracket(&id).round, 1);
+    assert_eq!(client.get_game(&no_show).battle_result.reason, OutcomeReason::CommitTimeout);
+    let bracket = client.get_bracket(&id);
+    assert!(bracket.get(1).unwrap().is_walkover);
+    assert!(!bracket.get(0).unwrap().is_walkover && !bracket.get(2).unwrap().is_walkover);
+    let semis = [bracket.get(4).unwrap(), bracket.get(5).unwrap()];
+    assert_eq!((semis[0].player1.clone(), semis[0].player2.clone()), (Some(seeds[0].clone()), Some(seeds[3].clone())));
+    assert_eq!((semis[1].player1.clone(), semis[1].player2.clone()), (Some(seeds[1].clone()), Some(seeds[2].clone())));
+}
+
+#[test]
+fn test_tournament_seeds_by_rating() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+    let deadline = env.ledger().timestamp() + 3600;
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+
+    // The last to register beats the third, so they are seeded first and last
+    let players: [Address; 4] = core::array::from_fn(|k| player_address(&env, 10 + k as u8));
+    GameBuilder::new(&client, 20, &players[3], &players[2])
+        .moves(fireball, slash)
+        .resolve();
+    let format = BracketFormat::SingleElimination;
+    let id = client.create_tournament(&Address::generate(&env), &4, &10_0000000, &deadline, &format);
+    for player in &players {
+        client.register_for_tournament(&id, player);
+    }
+    start_tournament(&env, &client, id, deadline, &players);
+
+    let seeds = vec![&env, players[3].clone(), players[0].clone(), players[1].clone(), players[2].clone()];
+    assert_eq!(client.get_tournament(&id).players, seeds);
+    let bracket = client.get_bracket(&id);
+    let (first, second) = (bracket.get(0).unwrap(), bracket.get(1).unwrap());
+    assert_eq!((first.player1, first.player2), (Some(players[3].clone()), Some(players[2].clone())));
+    assert_eq!((second.player1, second.player2), (Some(players[0].clone()), Some(players[1].clone())));
+}
+
+#[test]
+fn test_tournament_prizes_split_by_final_standing() {
+    let (env, client, _hub, organizer, _player2) = setup_test();
+    let deadline = env.ledger().timestamp() + 3600;
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    StellarAssetClient::new(&env, &sac.address()).mint(&organizer, &2000);
+    let token = TokenClient::new(&env, &sac.address());
+
+    let format = BracketFormat::SingleElimination;
+    let id = client.create_tournament(&organizer, &4, &10_0000000, &deadline, &format);
+    for payouts in [vec![&env, 5000, 3000, 1999], vec![&env, 4000, 3000, 2000, 500, 500], vec![&env]] {
+        let result = client.try_set_tournament_prizes(&id, &token.address, &1000, &payouts);
+        assert_clash_error(&result, Error::InvalidPrizeSchedule);
+    }
+    client.set_tournament_prizes(&id, &token.address, &1000, &vec![&env, 5000, 3000, 2000]);
+    assert_eq!(token.balance(&organizer), 1000);
+    let result = client.try_set_tournament_prizes(&id, &token.address, &1000, &vec![&env, 10_000]);
+    assert_clash_error(&result, Error::InvalidPrizeSchedule);
+
+    let seeds: [Address; 4] = core::array::from_fn(|seed| player_address(&env, 10 + seed as u8));
+    for player in &seeds {
+        client.register_for_tournament(&id, player);
+    }
+    start_tournament(&env, &client, id, deadline, &seeds);
+    let session = |position: u32| client.get_bracket(&id).get(position).unwrap().session_id.unwrap();
+    play_started_game(&client, session(0), &seeds[0], &seeds[3], &fireball, &slash);
+    play_started_game(&client, session(1), &seeds[1], &seeds[2], &fireball, &slash);
+    client.advance_bracket(&id);
+    assert_clash_error(&client.try_claim_prize(&id, &seeds[1]), Error::NothingToClaim);
+    play_started_game(&client, session(2), &seeds[0], &seeds[1], &slash, &fireball);
+    assert_eq!(client.advance_bracket(&id).status, TournamentStatus::Completed);
+
+    // Both semi-final losers tie for third and split third and fourth place's shares
+    let owed = [300, 500, 100, 100];
+    for (player, amount) in seeds.iter().zip(owed) {
+        assert_eq!(client.claim_prize(&id, player), amount);
+        assert_eq!(token.balance(player), amount);
+    }
+    assert_clash_error(&client.try_claim_prize(&id, &seeds[1]), Error::NothingToClaim);
+
+    // A cancelled tournament owes the organizer their pool back
+    let deadline = env.ledger().timestamp() + 3600;
+    let id = client.create_tournament(&organizer, &4, &10_0000000, &deadline, &format);
+    client.set_tournament_prizes(&id, &token.address, &1000, &vec![&env, 10_000]);
+    client.register_for_tournament(&id, &seeds[0]);
+    env.ledger().set_timestamp(deadline + 1);
+    assert_eq!(client.advance_bracket(&id).status, TournamentStatus::Cancelled);
+    assert_eq!(client.claim_prize(&id, &organizer), 1000);
+    assert_eq!(token.balance(&organizer), 1000);
+}
+
+#[test]
+fn test_large_tournament_prizes_vest() {
+    let (env, client, _hub, organizer, _player2) = setup_test();
+    let deadline = env.ledger().timestamp() + 3600;
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    StellarAssetClient::new(&env, &sac.address()).mint(&organizer, &1000);
+    let token = TokenClient::new(&env, &sac.address());
+
+    let format = BracketFormat::SingleElimination;
+    let id = client.create_tournament(&organizer, &2, &10_0000000, &deadline, &format);
+    client.set_tournament_prizes(&id, &token.address, &1000, &vec![&env, 8000, 2000]);
+    let month = 30 * 24 * 60 * 60;
+    for (immediate_bps, lock_secs) in [(10_000, month), (5000, 0), (5000, month + 1)] {
+        let result = client.try_set_prize_vesting(&id, &500, &immediate_bps, &lock_secs);
+        assert_clash_error(&result, Error::InvalidPrizeSchedule);
+    }
+    client.set_prize_vesting(&id, &500, &5000, &month);
+    let result = client.try_set_prize_vesting(&id, &500, &5000, &month);
+    assert_clash_error(&result, Error::InvalidPrizeSchedule);
+
+    let seeds: [Address; 2] = core::array::from_fn(|seed| player_address(&env, 10 + seed as u8));
+    for player in &seeds {
+        client.register_for_tournament(&id, player);
+    }
+    start_tournament(&env, &client, id, deadline, &seeds);
+    let session = client.get_bracket(&id).get(0).unwrap().session_id.unwrap();
+    play_started_game(&client, session, &seeds[0], &seeds[1], &fireball, &slash);
+    assert_eq!(client.advance_bracket(&id).status, TournamentStatus::Completed);
+
+    // The champion's prize is over the threshold: half now, half in a month. The
+    // runner-up's is paid in full.
+    let completed_at = env.ledger().timestamp();
+    assert_eq!(client.claim_prize(&id, &seeds[0]), 400);
+    assert_eq!(client.claim_prize(&id, &seeds[1]), 200);
+    assert_eq!(client.get_vested_prize(&id, &seeds[1]), None);
+    let vested = client.get_vested_prize(&id, &seeds[0]).unwrap();
+    assert_eq!((vested.amount, vested.unlocks_at), (400, completed_at + month));
+    assert_clash_error(&client.try_claim_vested(&id, &seeds[0]), Error::PrizeStillVesting);
+    assert_clash_error(&client.try_claim_vested(&id, &seeds[1]), Error::NothingToClaim);
+
+    env.ledger().set_timestamp(completed_at + month);
+    assert_eq!(client.claim_vested(&id, &seeds[0]), 400);
+    assert_eq!(token.balance(&seeds[0]), 800);
+    assert_clash_error(&client.try_claim_vested(&id, &seeds[0]), Error::NothingToClaim);
+}
+
+#[test]
+fn test_tournament_match_that_cannot_start_is_settled() {
+    let (env, client, _hub, organizer, busy) = setup_test();
+    let format = BracketFormat::SingleElimination;
+
+    // A player at their live session limit hands their opponent a walkover
+    let deadline = env.ledger().timestamp() + 3600;
+    let id = client.create_tournament(&organizer, &2, &10_0000000, &deadline, &format);
+    let rival = player_address(&env, 10);
+    for player in [&busy, &rival] {
+        client.register_for_tournament(&id, player);
+    }
+    for session_id in 100..130u32 {
+        client.start_game(&session_id, &busy, &organizer, &100, &100);
+    }
+    start_tournament(&env, &client, id, deadline, &[busy.clone(), rival.clone()]);
+    let tournament = client.get_tournament(&id);
+    assert_eq!((tournament.status, tournament.champion), (TournamentStatus::Completed, Some(rival)));
+    let final_match = client.get_bracket(&id).get(0).unwrap();
+    assert_eq!((final_match.session_id, final_match.is_walkover), (None, true));
+
+    // Two entrants linked into one identity after registering can't play each
+    // other, so the higher seed goes through
+    let deadline = env.ledger().timestamp() + 3600;
+    let id = client.create_tournament(&organizer, &2, &10_0000000, &deadline, &format);
+    let (primary, secondary) = (player_address(&env, 11), player_address(&env, 12));
+    for player in [&primary, &secondary] {
+        client.register_for_tournament(&id, player);
+    }
+    client.link_address(&primary, &secondary);
+    client.confirm_link(&secondary, &primary);
+    start_tournament(&env, &client, id, deadline, &[primary.clone(), secondary.clone()]);
+    let tournament = client.get_tournament(&id);
+    assert_eq!((tournament.status, tournament.champion), (TournamentStatus::Completed, Some(primary.clone())));
+    assert!(!client.get_bracket(&id).get(0).unwrap().is_walkover);
+
+    // Once linked, they can't both register
+    let deadline = env.ledger().timestamp() + 3600;
+    let id = client.create_tournament(&organizer, &2, &10_0000000, &deadline, &format);
+    client.register_for_tournament(&id, &primary);
+    let result = client.try_register_for_tournament(&id, &secondary);
+    assert_clash_error(&result, Error::AlreadyRegistered);
+}
+
+#[test]
+fn test_tournament_match_retried_after_hub_failure() {
+    let (env, client, hub, organizer, _player2) = setup_test();
+    let deadline = env.ledger().timestamp() + 3600;
+    let format = BracketFormat::SingleElimination;
+    let id = client.create_tournament(&organizer, &2, &10_0000000, &deadline, &format);
+    let seeds: [Address; 2] = core::array::from_fn(|seed| player_address(&env, 10 + seed as u8));
+    for player in &seeds {
+        client.register_for_tournament(&id, player);
+    }
+
+    // A failing hub isn't either player's doing, so the match waits unstarted
+    client.set_hub(&env.register(FailingGameHub, ()));
+    start_tournament(&env, &client, id, deadline, &seeds);
+    assert_eq!(client.get_tournament(&id).status, TournamentStatus::InProgress);
+    let final_match = client.get_bracket(&id).get(0).unwrap();
+    assert_eq!((final_match.session_id, final_match.winner), (None, None));
+
+    // and starts once the hub is back
+    client.set_hub(&hub.address);
+    client.advance_bracket(&id);
+    let final_match = client.get_bracket(&id).get(0).unwrap();
+    assert!(final_match.session_id.is_some());
+    assert_eq!(final_match.winner, None);
+}
+
+#[test]
+fn test_cancelled_tournament_refunds_the_pool() {
+    let (env, client, _hub, organizer, _player2) = setup_test();
+    let deadline = env.ledger().timestamp() + 3600;
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    StellarAssetClient::new(&env, &sac.address()).mint(&organizer, &1000);
+    let token = TokenClient::new(&env, &sac.address());
+    let format = BracketFormat::SingleElimination;
+
+    let id = client.create_tournament(&organizer, &4, &10_0000000, &deadline, &format);
+    client.set_tournament_prizes(&id, &token.address, &1000, &vec![&env, 10_000]);
+    let seeds: [Address; 2] = core::array::from_fn(|seed| player_address(&env, 10 + seed as u8));
+    for player in &seeds {
+        client.register_for_tournament(&id, player);
+    }
+    start_tournament(&env, &client, id, deadline, &seeds);
+
+    // The organizer or admin can call it off mid-bracket, and only once
+    assert_clash_error(&client.try_cancel_tournament(&id, &seeds[0]), Error::NotPlayer);
+    let tournament = client.cancel_tournament(&id, &client.get_admin());
+    assert_eq!(tournament.status, TournamentStatus::Cancelled);
+    assert_clash_error(&client.try_cancel_tournament(&id, &organizer), Error::TournamentFinished);
+    assert_eq!(client.advance_bracket(&id).status, TournamentStatus::Cancelled);
+    assert_eq!(client.claim_prize(&id, &organizer), 1000);
+    assert_eq!(token.balance(&organizer), 1000);
+
+    let deadline = env.ledger().timestamp() + 3600;
+    let id = client.create_tournament(&organizer, &2, &10_0000000, &deadline, &format);
+    assert_eq!(client.cancel_tournament(&id, &organizer).status, TournamentStatus::Cancelled);
+}
+
+#[test]
+fn test_double_elimination_losers_bracket_and_grand_final_reset() {
+    let (env, client, _hub, organizer, _player2) = setup_test();
+    let deadline = env.ledger().timestamp() + 3600;
+    let format = BracketFormat::DoubleElimination;
+    let result = client.try_create_tournament(&organizer, &2, &10_0000000, &deadline, &format);
+    assert_clash_error(&result, Error::InvalidTournament);
+
+    let id = client.create_tournament(&organizer, &4, &10_0000000, &deadline, &format);
+    let seeds: [Address; 4] = core::array::from_fn(|seed| player_address(&env, 10 + seed as u8));
+    for player in &seeds {
+        client.register_for_tournament(&id, player);
+    }
+    start_tournament(&env, &client, id, deadline, &seeds);
+    // Winners' bracket (3), losers' bracket (2), grand final and reset
+    let bracket = client.get_bracket(&id);
+    assert_eq!(bracket.len(), 7);
+    let sides = bracket.iter().map(|bracket_match| bracket_match.side);
+    let expected = [0, 0, 0, 1, 1, 2, 2].map(|side| [BracketSide::Winners, BracketSide::Losers, BracketSide::GrandFinal][side]);
+    assert!(sides.eq(expected));
+
+    // Play the match at `position`, won by `winner`, and move the bracket on
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    let play = |position: u32, winner: &Address| {
+        let bracket_match = client.get_bracket(&id).get(position).unwrap();
+        let (player1, player2) = (bracket_match.player1.unwrap(), bracket_match.player2.unwrap());
+        let (moves1, moves2) = if player1 == *winner { (&fireball, &slash) } else { (&slash, &fireball) };
+        play_started_game(&client, bracket_match.session_id.unwrap(), &player1, &player2, moves1, moves2);
+    };
+    let players = |position: u32| {
+        let bracket_match = client.get_bracket(&id).get(position).unwrap();
+        (bracket_match.player1, bracket_match.player2)
+    };
+
+    // Both first-round losers drop into the losers' bracket and meet there
+    play(0, &seeds[0]);
+    play(1, &seeds[1]);
+    assert_eq!(client.advance_bracket(&id).round, 1);
+    assert_eq!(players(3), (Some(seeds[3].clone()), Some(seeds[2].clone())));
+    assert_eq!(players(2), (Some(seeds[0].clone()), Some(seeds[1].clone())));
+
+    // The winners' final loser takes on the losers' bracket winner
+    play(2, &seeds[1]);
+    play(3, &seeds[3]);
+    assert_eq!(client.advance_bracket(&id).round, 1);
+    assert_eq!(players(4), (Some(seeds[3].clone()), Some(seeds[0].clone())));
+    play(4, &seeds[0]);
+    client.advance_bracket(&id);
+    assert_eq!(players(5), (Some(seeds[1].clone()), Some(seeds[0].clone())));
+
+    // Losing the grand final is the winners' champion's first loss, so it is reset
+    play(5, &seeds[0]);
+    let tournament = client.advance_bracket(&id);
+    assert_eq!(tournament.status, TournamentStatus::InProgress);
+    assert_eq!(players(6), (Some(seeds[0].clone()), Some(seeds[1].clone())));
+    play(6, &seeds[1]);
+    let tournament = client.advance_bracket(&id);
+    assert_eq!(tournament.status, TournamentStatus::Completed);
+    assert_eq!(tournament.champion, Some(seeds[1].clone()));
+}
+
+#[test]
+fn test_double_elimination_winners_champion_skips_the_reset() {
+    let (env, client, _hub, organizer, _player2) = setup_test();
+    let deadline = env.ledger().timestamp() + 3600;
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+
+    // Three players: the top seed's bye leaves the losers' bracket a bye of its own
+    let format = BracketFormat::DoubleElimination;
+    let id = client.create_tournament(&organizer, &4, &10_0000000, &deadline, &format);
+    let seeds: [Address; 3] = core::array::from_fn(|seed| player_address(&env, 10 + seed as u8));
+    for player in &seeds {
+        client.register_for_tournament(&id, player);
+    }
+    start_tournament(&env, &client, id, deadline, &seeds);
+    let session = |position: u32| client.get_bracket(&id).get(position).unwrap().session_id.unwrap();
+    play_started_game(&client, session(1), &seeds[1], &seeds[2], &fireball, &slash);
+    client.advance_bracket(&id);
+    let bracket = client.get_bracket(&id);
+    assert_eq!(bracket.get(3).unwrap().winner, Some(seeds[2].clone()));
+    assert_eq!(bracket.get(3).unwrap().session_id, None);
+
+    play_started_game(&client, session(2), &seeds[0], &seeds[1], &fireball, &slash);
+    client.advance_bracket(&id);
+    play_started_game(&client, session(4), &seeds[2], &seeds[1], &slash, &fireball);
+    client.advance_bracket(&id);
+    play_started_game(&client, session(5), &seeds[0], &seeds[1], &fireball, &slash);
+
+    let tournament = client.advance_bracket(&id);
+    assert_eq!(tournament.status, TournamentStatus::Completed);
+    assert_eq!(tournament.champion, Some(seeds[0].clone()));
+    let reset = client.get_bracket(&id).get(6).unwrap();
+    assert_eq!((reset.player1, reset.player2, reset.winner), (None, None, None));
+}
+
+// ============================================================================
+// Turn-by-turn Duel Tests
+// ============================================================================
+
+#[test]
+fn test_duel_resolves_round_by_round() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 30u32;
+    client.start_duel(&session_id, &player1, &player2, &100, &100);
+
+    let fireball = Move { attack: Attack::Fireball, defense: Defense::Dodge };
+    let slash = Move { attack: Attack::Slash, defense: Defense::Block };
+
+    play_duel_round(&client, session_id, &player1, &player2, &fireball, &slash);
+
+    let duel = client.get_duel(&session_id);
+    assert_eq!(duel.phase, DuelPhase::Commit);
+    assert_eq!(duel.round, 1);
+    assert_eq!(duel.battle_result.turn_results.len(), 1);
+    assert_eq!(duel.battle_result.player1_hp, 100);
+    assert_eq!(duel.battle_result.player2_hp, 60);
+
+    // Combos and momentum carry over between rounds: 40, 55, 70
+    play_duel_round(&client, session_id, &player1, &player2, &fireball, &slash);
+    play_duel_round(&client, session_id, &player1, &player2, &fireball, &slash);
+
+    let duel = client.get_duel(&session_id);
+    assert_eq!(duel.phase, DuelPhase::Finished);
+    assert!(duel.battle_result.turn_results.get(1).unwrap().player1_momentum);
+    assert_eq!(duel.battle_result.player2_hp, -65);
+    assert_eq!(duel.battle_result.winner, Some(player1));
+    assert!(!duel.battle_result.is_draw);
+
+    let salt = BytesN::from_array(&env, &[3u8; 32]);
+    let result = client.try_commit_duel_move(&session_id, &player2, &duel_commitment(&env, &slash, &salt));
+    assert_clash_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_duel_reveal_must_match_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 31u32;
+    client.start_duel(&session_id, &player1, &player2, &100, &100);
+
+    let slash = Move { attack: Attack::Slash, defense: Defense::Block };
+    let lightning = Move { attack: Attack::Lightning, defense: Defense::Block };
+    let salt = BytesN::from_array(&env, &[1u8; 32]);
+
+    client.commit_duel_move(&session_id, &player1, &duel_commitment(&env, &slash, &salt));
+    let result = client.try_reveal_duel_move(&session_id, &player1, &slash, &salt);
+    assert_clash_error(&result, Error::BothPlayersNotCommitted);
+
+    client.commit_duel_move(&session_id, &player2, &duel_commitment(&env, &slash, &salt));
+    let result = client.try_reveal_duel_move(&session_id, &player1, &lightning, &salt);
+    assert_clash_error(&result, Error::CommitmentMismatch);
+}
+
+#[test]
+fn test_duel_timeout_awards_player_who_acted() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 32u32;
+    client.start_duel(&session_id, &player1, &player2, &100, &100);
+
+    let slash = Move { attack: Attack::Slash, defense: Defense::Block };
+    let salt = BytesN::from_array(&env, &[1u8; 32]);
+    client.commit_duel_move(&session_id, &player1, &duel_commitment(&env, &slash, &salt));
+
+    let result = client.try_claim_duel_timeout(&session_id, &player1);
+    assert_clash_error(&result, Error::DeadlineNotReached);
+
+    let deadline = client.get_duel(&session_id).deadline;
+    env.ledger().set_timestamp(deadline + 1);
+
+    let result = client.try_commit_duel_move(&session_id, &player2, &duel_commitment(&env, &slash, &salt));
+    assert_clash_error(&result, Error::DeadlinePassed);
+
+    client.claim_duel_timeout(&session_id, &player1);
+    let duel = client.get_duel(&session_id);
+    assert_eq!(duel.phase, DuelPhase::Finished);
+    assert_eq!(duel.battle_result.winner, Some(player1));
+}
+
+// ============================================================================
+// Team Game Tests
+// ============================================================================
+
+#[test]
+fn test_team_game_pools_hp_and_pays_the_winning_team() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (player3, player4) = (player_address(&env, 3), player_address(&env, 4));
+    let team1 = vec![&env, player1.clone(), player3.clone()];
+    let team2 = vec![&env, player2.clone(), player4.clone()];
+    let points = 10_0000000;
+
+    let short = vec![&env, player2.clone()];
+    let result = client.try_start_team_game(&300, &team1, &short, &points, &points);
+    assert_clash_error(&result, Error::InvalidTeam);
+    let overlapping = vec![&env, player2.clone(), player3.clone()];
+    let result = client.try_start_team_game(&300, &team1, &overlapping, &points, &points);
+    assert_clash_error(&result, Error::InvalidTeam);
+    client.start_team_game(&300, &team1, &team2, &points, &points);
+    assert_eq!(client.get_escrow(&300).unwrap().player2, player2);
+
+    let proof = Bytes::new(&env);
+    let players = [&player1, &player3, &player2, &player4];
+    let inputs = players.map(|player| public_inputs(&env, player, 300, &commitment_for(&env, player, 300)));
+    let outsider = player_address(&env, 5);
+    let result = client.try_commit_team_moves(&300, &outsider, &public_inputs(&env, &outsider, 300, &commitment_for(&env, &outsider, 300)), &proof);
+    assert_clash_error(&result, Error::NotPlayer);
+    for (player, inputs) in players.iter().zip(&inputs) {
+        client.commit_team_moves(&300, player, inputs, &proof);
+    }
+
+    // Team one throws Fireballs the other side can't block; team two's Slashes are dodged
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    for (i, player) in players.iter().enumerate() {
+        let moves = if i < 2 { &fireball } else { &slash };
+        client.reveal_team_moves(&300, player, &inputs[i], moves);
+    }
+    let result = client.resolve_team_battle(&300);
+
+    // 80, then 110 and 140 with momentum on both lanes, against a pool of 200
+    assert_eq!(result.winner, Some(player1.clone()));
+    assert_eq!((result.player1_hp, result.player2_hp), (200, -130));
+    assert_eq!(result.turn_results.get(0).unwrap().player1_damage_dealt, 80);
+    assert_ne!(client.get_escrow(&300).unwrap().released_at, 0);
+    assert_eq!(client.resolve_team_battle(&300), result);
+}
+
+// ============================================================================
+// Multiple Games Tests
+// ============================================================================
+
+#[test]
+fn test_asymmetric_points() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 15u32;
+    let points1 = 200_0000000;
+    let points2 = 50_0000000;
+
+    GameBuilder::new(&client, session_id, &player1, &player2)
+        .points(points1, points2)
+        .build(GamePhase::Resolved);
+
+    // Game completes successfully with asymmetric points
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_points, points1);
+    assert_eq!(game.player2_points, points2);
+    assert!(game.has_battle_result);
+}
+
+// ============================================================================
+// Rating Tests
+// ============================================================================
+
+#[test]
+fn test_ratings_and_leaderboard_follow_results() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+
+    assert_eq!(client.get_player_stats(&player1).rating, 1500);
+    assert!(client.get_top_players(&10).is_empty());
+
+    // Even match: the winner takes half the (provisional) K-factor
+    GameBuilder::new(&client, 20, &player1, &player2)
+        .moves(fireball.clone(), slash.clone())
+        .resolve();
+    let stats1 = client.get_player_stats(&player1);
+    let stats2 = client.get_player_stats(&player2);
+    assert_eq!((stats1.wins, stats1.rating, stats1.streak), (1, 1532, 1));
+    assert_eq!((stats2.losses, stats2.rating, stats2.streak), (1, 1468, -1));
+
+    // A draw against a weaker player costs the favourite a little
+    GameBuilder::new(&client, 21, &player1, &player3).resolve();
+    let stats1 = client.get_player_stats(&player1);
+    assert_eq!((stats1.draws, stats1.rating, stats1.streak), (1, 1529, 0));
+    assert_eq!(client.get_player_stats(&player3).rating, 1503);
+
+    let top = client.get_top_players(&2);
+    assert_eq!(top.len(), 2);
+    assert_eq!((top.get(0).unwrap().player, top.get(0).unwrap().rating), (player1.clone(), 1529));
+    assert_eq!(top.get(1).unwrap().player, player3);
+    assert_eq!(client.get_top_players(&10).len(), 3);
+
+    // Sieges aren't rated
+    GameBuilder::new(&client, 22, &player2, &player3)
+        .siege()
+        .moves(fireball, slash)
+        .resolve();
+    assert_eq!(client.get_player_stats(&player2).rating, 1468);
+}
+
+#[test]
+fn test_first_rated_games_are_provisional() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+
+    assert!(client.is_provisional(&player1));
+    assert!(client.get_rating_percentile(&player1).is_provisional);
+    for session_id in 20..29 {
+        GameBuilder::new(&client, session_id, &player1, &player2).resolve();
+    }
+    assert!(client.is_provisional(&player1));
+    GameBuilder::new(&client, 29, &player1, &player2).resolve();
+    assert!(!client.is_provisional(&player1));
+    assert!(!client.get_rating_percentile(&player1).is_provisional);
+
+    // Each side moves by its own K-factor: the established winner half as far as
+    // the provisional loser
+    GameBuilder::new(&client, 30, &player1, &player3)
+        .moves(fireball, slash)
+        .resolve();
+    assert_eq!(client.get_player_stats(&player1).rating, 1516);
+    assert_eq!(client.get_player_stats(&player3).rating, 1468);
+}
+
+#[test]
+fn test_inactive_ratings_decay_to_the_starting_rating() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    let week = 7 * 24 * 60 * 60;
+    client.set_rating_decay(&week, &5);
+
+    GameBuilder::new(&client, 20, &player1, &player2)
+        .moves(fireball.clone(), slash.clone())
+        .resolve();
+    let rated_at = env.ledger().timestamp();
+
+    // Two idle weeks cost the winner 10 points on read; the loser is below 1500
+    // and keeps their rating
+    env.ledger().set_timestamp(rated_at + 2 * week + 1);
+    assert_eq!(client.get_player_stats(&player1).rating, 1522);
+    assert_eq!(client.get_rating_percentile(&player1).rating, 1522);
+    assert_eq!(client.get_player_stats(&player2).rating, 1468);
+    assert_eq!(client.get_top_players(&1).get(0).unwrap().rating, 1522);
+    assert_eq!(client.decay_rating(&player1).rating, 1522);
+    assert_eq!(client.get_top_players(&1).get(0).unwrap().rating, 1522);
+    assert_eq!(client.get_player_stats(&player1).rating, 1522);
+
+    // Decay stops at the starting rating, and a rated game restarts the clock
+    env.ledger().set_timestamp(rated_at + 10 * week);
+    assert_eq!(client.get_player_stats(&player1).rating, 1500);
+    GameBuilder::new(&client, 21, &player1, &player3)
+        .moves(fireball, slash)
+        .resolve();
+    assert_eq!(client.get_player_stats(&player1).rating, 1532);
+    env.ledger().set_timestamp(env.ledger().timestamp() + week - 1);
+    assert_eq!(client.get_player_stats(&player1).rating, 1532);
+}
+
+#[test]
+fn test_decayed_legends_drop_down_the_views() {
+    let (env, client, _hub, legend, player2) = setup_test();
+    let active = player_address(&env, 3);
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    let week = 7 * 24 * 60 * 60;
+    client.set_rating_decay(&week, &50);
+
+    let win = |session_id: u32, winner: &Address, loser: &Address| {
+        GameBuilder::new(&client, session_id, winner, loser)
+            .moves(fireball.clone(), slash.clone())
+            .resolve();
+    };
+    win(20, &legend, &player2);
+    win(21, &legend, &player_address(&env, 4));
+    env.ledger().set_timestamp(env.ledger().timestamp() + week + 1);
+    win(22, &active, &player_address(&env, 5));
+    win(23, &active, &player_address(&env, 6));
+
+    // Nothing has been written back, yet the idle legend already ranks below
+    let top = client.get_top_players(&2);
+    assert_eq!((top.get(0).unwrap().player, top.get(0).unwrap().rating), (active.clone(), 1561));
+    assert_eq!((top.get(1).unwrap().player, top.get(1).unwrap().rating), (legend.clone(), 1511));
+    // Alone in the top bucket out of 6 rated players
+    assert_eq!(client.get_rating_percentile(&active).top_pct, 17);
+    assert_eq!(client.get_rating_percentile(&legend).top_pct, 34);
+}
+
+#[test]
+fn test_rating_percentile_reads_the_histogram() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+
+    GameBuilder::new(&client, 20, &player1, &player2)
+        .moves(
+            MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge),
+            MoveSeq::repeat(&env, Attack::Slash, Defense::Block),
+        )
+        .resolve();
+    assert_eq!(client.get_rating_percentile(&player1).top_pct, 50);
+    assert_eq!(client.get_rating_percentile(&player2).top_pct, 100);
+    let unrated = client.get_rating_percentile(&player3);
+    assert_eq!((unrated.rated_players, unrated.top_pct), (2, 0));
+
+    // player1 moves within their bucket and is still counted once; player3 joins it
+    GameBuilder::new(&client, 21, &player1, &player3).resolve();
+    let percentile = client.get_rating_percentile(&player3);
+    assert_eq!((percentile.rating, percentile.rated_players), (1503, 3));
+    assert_eq!(percentile.top_pct, 67);
+    assert_eq!(client.get_rating_percentile(&player1).top_pct, 67);
+    assert_eq!(client.get_rating_percentile(&player2).top_pct, 100);
+}
+
+#[test]
+fn test_achievements_are_awarded_once_on_resolve() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    let badges = |player: &Address| {
+        let mut achievements = vec![&env];
+        for badge in client.get_achievements(player).iter() {
+            achievements.push_back(badge.achievement);
+        }
+        achievements
+    };
+
+    // Untouched, and the third Fireball in a row lands the knockout
+    let result = GameBuilder::new(&client, 20, &player1, &player2)
+        .moves(fireball.clone(), slash.clone())
+        .resolve();
+    assert_eq!((result.player1_hp, result.turn_results.len()), (100, 3));
+    assert_eq!(
+        badges(&player1),
+        vec![&env, Achievement::FirstWin, Achievement::Flawless, Achievement::TripleComboFinisher]
+    );
+    assert!(badges(&player2).is_empty());
+
+    for session_id in 21..30 {
+        GameBuilder::new(&client, session_id, &player1, &player2)
+            .moves(fireball.clone(), slash.clone())
+            .resolve();
+    }
+    let earned = client.get_achievements(&player1);
+    assert_eq!(earned.len(), 5);
+    assert_eq!(earned.get(3).unwrap().achievement, Achievement::WinStreak3);
+    assert_eq!(earned.get(3).unwrap().session_id, 22);
+    assert_eq!(earned.get(4).unwrap().session_id, 29);
+    assert_eq!(badges(&player2), vec![&env, Achievement::TenBattles]);
+}
+
+#[test]
+fn test_seasons_rate_separately_and_freeze_standings() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+
+    // Played before any season: lifetime only
+    GameBuilder::new(&client, 20, &player1, &player2)
+        .moves(fireball.clone(), slash.clone())
+        .resolve();
+    assert_clash_error(&client.try_end_season(&10), Error::InvalidSeason);
+
+    client.start_season(&1, &(env.ledger().timestamp() + 1000));
+    assert_clash_error(&client.try_start_season(&2, &5000), Error::SeasonInProgress);
+    GameBuilder::new(&client, 21, &player1, &player2)
+        .moves(slash.clone(), fireball.clone())
+        .resolve();
+    let season = client.get_season_stats(&1, &player2);
+    assert_eq!((season.wins, season.losses, season.rating), (1, 0, 1516));
+    let lifetime = client.get_player_stats(&player2);
+    assert_eq!((lifetime.wins, lifetime.losses), (1, 1));
+
+    // Results after the end time don't count, even before the season is closed
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1001);
+    GameBuilder::new(&client, 22, &player1, &player2)
+        .moves(slash, fireball)
+        .resolve();
+    assert_eq!(client.get_season_stats(&1, &player2).wins, 1);
+
+    let results = client.end_season(&1);
+    assert_eq!(results.standings.len(), 1);
+    assert_eq!(results.standings.get(0).unwrap().player, player2);
+    assert_eq!(client.get_season_results(&1), Some(results));
+    assert!(client.get_season().unwrap().has_ended);
+
+    let next_end = env.ledger().timestamp() + 1000;
+    assert_clash_error(&client.try_start_season(&1, &next_end), Error::InvalidSeason);
+    client.start_season(&2, &next_end);
+    assert_eq!(client.get_season_stats(&2, &player2).rating, 1500);
+    assert_eq!(client.get_player_stats(&player2).wins, 2);
+}
+
+#[test]
+fn test_sponsored_games_stake_new_players_from_the_pool() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let token = TokenClient::new(&env, &sac.address());
+    StellarAssetClient::new(&env, &sac.address()).mint(&client.get_admin(), &100);
+    let play = |session_id: u32, moves: [(&Address, Vec<Move>); 2]| {
+        for (player, _) in &moves {
+            let commitment = commitment_for(&env, player, session_id);
+            let inputs = public_inputs(&env, player, session_id, &commitment);
+            client.commit_moves(&session_id, player, &inputs, &Bytes::new(&env));
+        }
+        for (player, moves) in &moves {
+            let commitment = commitment_for(&env, player, session_id);
+            let inputs = public_inputs(&env, player, session_id, &commitment);
+            client.reveal_moves(&session_id, player, &inputs, moves);
+        }
+        client.resolve_battle(&session_id)
+    };
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+
+    assert_clash_error(&client.try_start_sponsored_game(&70, &player1, &player2), Error::NotSponsored);
+    let tier = SponsorTier {
+        token: sac.address(),
+        stake: 10,
+        max_rated_games: 1,
+        daily_games: 1,
+    };
+    client.set_sponsor_tier(&Some(tier));
+    assert_eq!(client.fund_sponsor_pool(&100), 100);
+    assert_eq!(client.get_sponsored_games_left(&player1), 1);
+
+    // The pool sets both stakes aside; nobody locks any points
+    client.start_sponsored_game(&70, &player1, &player2);
+    assert_eq!(client.get_sponsor_pool(), 80);
+    assert_eq!(client.get_game(&70).player1_points, 0);
+    assert_eq!(client.get_sponsored_games_left(&player1), 0);
+    assert_clash_error(&client.try_start_sponsored_game(&71, &player1, &player3), Error::SponsorLimitReached);
+
+    assert_eq!(play(70, [(&player1, fireball.clone()), (&player2, slash.clone())]).winner, Some(player1.clone()));
+    assert_eq!(token.balance(&player1), 0);
+    assert_eq!(client.claim_winnings(&70, &player1).get(sac.address()), Some(20));
+    assert_eq!(token.balance(&player1), 20);
+    assert_eq!(client.get_sponsorship(&70), None);
+
+    // A draw puts the stakes back in the pool
+    env.ledger().with_mut(|l| l.timestamp += 24 * 60 * 60);
+    client.start_sponsored_game(&71, &player1, &player3);
+    assert!(play(71, [(&player1, slash.clone()), (&player3, slash)]).is_draw);
+    assert_eq!(client.get_sponsor_pool(), 80);
+
+    // Two rated games is past this tier's new-player limit
+    assert_eq!(client.get_sponsored_games_left(&player1), 0);
+    env.ledger().with_mut(|l| l.timestamp += 24 * 60 * 60);
+    assert_clash_error(&client.try_start_sponsored_game(&72, &player1, &player3), Error::NotSponsored);
+    assert_eq!(client.withdraw_sponsor_pool(&75), 5);
+    let newcomer = Address::generate(&env);
+    assert_clash_error(&client.try_start_sponsored_game(&72, &player3, &newcomer), Error::SponsorPoolEmpty);
+}
+
+#[test]
+fn test_season_rewards_are_claimed_or_swept_to_fees() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let token = TokenClient::new(&env, &sac.address());
+    StellarAssetClient::new(&env, &sac.address()).mint(&client.get_admin(), &1_000);
+    client.set_season_rewards(&sac.address(), &vec![&env, 300, 100]);
+
+    client.start_season(&1, &(env.ledger().timestamp() + 1000));
+    GameBuilder::new(&client, 20, &player1, &player2)
+        .moves(
+            MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge),
+            MoveSeq::repeat(&env, Attack::Slash, Defense::Block),
+        )
+        .resolve();
+
+    // Ending rolls straight into the next season and takes the pot from the admin
+    let results = client.rollover_season(&10, &2, &(env.ledger().timestamp() + 5000));
+    assert_eq!(results.standings.len(), 2);
+    assert_eq!(client.get_season().unwrap().season_id, 2);
+    assert_eq!(token.balance(&client.address), 400);
+    let placement = client.get_season_placement(&1, &player2).unwrap();
+    assert_eq!((placement.rank, placement.amount), (2, 100));
+
+    assert_eq!(client.claim_season_placement(&player1, &1), 300);
+    assert_eq!(token.balance(&player1), 300);
+    assert_clash_error(&client.try_claim_season_placement(&player1, &1), Error::NothingToClaim);
+    assert_clash_error(&client.try_sweep_season_rewards(&1), Error::DeadlineNotReached);
+
+    // Whatever is left after the claim window goes back to the fees
+    env.ledger().set_timestamp(placement.expires_at + 1);
+    assert_clash_error(&client.try_claim_season_placement(&player2, &1), Error::ClaimExpired);
+    assert_eq!(client.sweep_season_rewards(&1), 100);
+    assert_eq!(client.sweep_season_rewards(&1), 0);
+    assert_eq!(client.get_season_placement(&1, &player2).unwrap().rank, 2);
+    assert_eq!(client.withdraw_fees(&sac.address(), &player2), 100);
+}
+
+#[test]
+fn test_mutual_rivals_get_bonus_and_head_to_head() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let csh = MockTokenClient::new(&env, &env.register(MockToken, ()));
+    client.set_token_contract(&csh.address);
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+
+    assert_clash_error(&client.try_declare_rival(&player1, &player1), Error::CannotChallengeSelf);
+
+    // One-sided: no rivalry yet
+    client.declare_rival(&player1, &player2);
+    let challenge_id = ChallengeBuilder::new(&client, &player1, &player2).send();
+    assert!(!client.get_challenge(&challenge_id).is_rival_match);
+
+    client.declare_rival(&player2, &player1);
+    let (challenge_id, session_id) = ChallengeBuilder::new(&client, &player1, &player2).accept();
+    assert!(client.get_challenge(&challenge_id).is_rival_match);
+    let proof = Bytes::new(&env);
+    let inputs = [&player1, &player2].map(|player| {
+        let commitment = commitment_for(&env, player, session_id);
+        let inputs = public_inputs(&env, player, session_id, &commitment);
+        client.commit_moves(&session_id, player, &inputs, &proof);
+        inputs
+    });
+    client.reveal_moves(&session_id, &player1, &inputs[0], &fireball);
+    client.reveal_moves(&session_id, &player2, &inputs[1], &slash);
+    client.resolve_battle(&session_id);
+    assert_eq!(csh.balance(&player1), crate::CSH_REWARD_PER_WIN + 5_0000000 + crate::DAILY_WIN_BONUS);
+
+    let record = client.get_head_to_head(&player1, &player2);
+    assert_eq!((record.wins, record.losses, record.draws), (1, 0, 0));
+    let record = client.get_head_to_head(&player2, &player1);
+    assert_eq!((record.wins, record.losses, record.draws), (0, 1, 0));
+
+    // Ending the rivalry stops the bonus and the record
+    client.clear_rival(&player2);
+    assert_eq!(client.get_rival(&player2), None);
+    GameBuilder::new(&client, 30, &player1, &player2)
+        .moves(fireball, slash)
+        .resolve();
+    assert_eq!(csh.balance(&player1), 2 * crate::CSH_REWARD_PER_WIN + 5_0000000 + crate::DAILY_WIN_BONUS);
+    assert_eq!(client.get_head_to_head(&player1, &player2).wins, 1);
+}
+
+#[test]
+fn test_consecutive_games_form_a_grudge_series() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = player_address(&env, 3);
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    let slash = MoveSeq::repeat(&env, Attack::Slash, Defense::Block);
+    assert_eq!(client.get_rivalry_series(&player1, &player2), None);
+
+    GameBuilder::new(&client, 31, &player1, &player2).moves(fireball.clone(), slash.clone()).resolve();
+    GameBuilder::new(&client, 32, &player2, &player1).moves(fireball.clone(), slash.clone()).resolve();
+    GameBuilder::new(&client, 33, &player1, &player2).moves(fireball.clone(), slash.clone()).resolve();
+
+    // Scored from whichever side asks
+    let series = client.get_rivalry_series(&player1, &player2).unwrap();
+    assert_eq!((series.player_a, series.a_wins, series.b_wins), (player1.clone(), 2, 1));
+    assert_eq!(series.sessions, vec![&env, 31, 32, 33]);
+    let series = client.get_rivalry_series(&player2, &player1).unwrap();
+    assert_eq!((series.player_a, series.a_wins, series.b_wins), (player2.clone(), 1, 2));
+
+    // A game against someone else breaks the run; the next meeting starts afresh
+    GameBuilder::new(&client, 34, &player2, &player3).resolve();
+    GameBuilder::new(&client, 35, &player1, &player2).moves(slash, fireball).resolve();
+    let series = client.get_rivalry_series(&player1, &player2).unwrap();
+    assert_eq!(series.sessions, vec![&env, 35]);
+    assert_eq!((series.a_wins, series.b_wins, series.draws), (0, 1, 0));
+}
+
+// ============================================================================
+// Match History Tests
+// ============================================================================
+
+#[test]
+fn test_match_history_keeps_latest_games() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+
+    env.ledger().set_timestamp(5_000);
+    let fireball = MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge);
+    GameBuilder::new(&client, 100, &player1, &player3)
+        .moves(fireball.clone(), MoveSeq::repeat(&env, Attack::Slash, Defense::Block))
+        .resolve();
+
+    let history = client.get_match_history(&player3, &0, &10);
+    assert_eq!(history.len(), 1);
+    let archived = history.get(0).unwrap();
+    assert_eq!(archived.session_id, 100);
+    assert_eq!(archived.player1_moves, fireball);
+    assert_eq!(archived.winner, Some(player1.clone()));
+    assert_eq!(archived.turn_results.len(), 3);
+    assert_eq!(archived.resolved_at, 5_000);
+
+    // Only the 20 most recent are kept, newest first
+    for session_id in 101..121 {
+        GameBuilder::new(&client, session_id, &player1, &player2).resolve();
+    }
+    let history = client.get_match_history(&player1, &0, &50);
+    assert_eq!(history.len(), 20);
+    assert_eq!(history.get(0).unwrap().session_id, 120);
+    assert_eq!(history.get(19).unwrap().session_id, 101);
+    let page = client.get_match_history(&player1, &18, &5);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().session_id, 102);
+    assert_eq!(client.get_match_history(&player3, &0, &10).len(), 1);
+}
+
+#[test]
+fn test_match_notes_only_on_own_finished_sessions() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let note = BytesN::from_array(&env, &[7u8; 32]);
+
+    let session_id = GameBuilder::new(&client, 130, &player1, &player2).build(GamePhase::Committed);
+    let result = client.try_set_match_note(&player1, &session_id, &note);
+    assert_clash_error(&result, Error::GameNotEnded);
+    let result = client.try_set_match_note(&player1, &999, &note);
+    assert_clash_error(&result, Error::GameNotFound);
+
+    GameBuilder::new(&client, 131, &player1, &player2).resolve();
+    let stranger = Address::generate(&env);
+    let result = client.try_set_match_note(&stranger, &131, &note);
+    assert_clash_error(&result, Error::NotPlayer);
+
+    client.set_match_note(&player1, &131, &note);
+    assert_eq!(client.get_match_note(&player1, &131), Some(note));
+    assert_eq!(client.get_match_note(&player2, &131), None);
+}
+
+// ============================================================================
+// Global Stats Tests
+// ============================================================================
+
+#[test]
+fn test_global_stats_count_resolved_battles() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    assert_eq!(client.get_global_stats().total_games, 0);
+
+    let mirror = GameBuilder::new(&client, 18, &player1, &player2).resolve();
+    assert!(mirror.is_draw);
+    GameBuilder::new(&client, 19, &player1, &player2)
+        .moves(
+            MoveSeq::repeat(&env, Attack::Fireball, Defense::Dodge),
+            MoveSeq::repeat(&env, Attack::Slash, Defense::Block),
+        )
+        .resolve();
+
+    let stats = client.get_global_stats();
+    assert_eq!((stats.total_games, stats.total_draws), (2, 1));
+    let usage = (stats.slash_count, stats.fireball_count, stats.lightning_count);
+    assert_eq!(usage, (9, 3, 0));
+    assert_eq!((stats.total_turns, stats.average_turns_x100), (6, 300));
+
+    // Replaying resolve on a settled game doesn't count it twice
+    client.resolve_battle(&18);
+    assert_eq!(client.get_global_stats().total_games, 2);
+}
+
+// ============================================================================
+// Escrow Receipt Tests
+// ============================================================================
+
+#[test]
+fn test_escrow_receipt_tracks_locked_stakes() {
+    let (env, client, hub, player1, player2) = setup_test();
+
+    env.ledger().set_timestamp(1_000);
+    let session_id = GameBuilder::new(&client, 16, &player1, &player2)
+        .points(200_0000000, 50_0000000)
+        .build(GamePhase::Started);
+
+    let receipt = client.get_escrow(&session_id).unwrap();
+    assert_eq!(receipt.asset, hub.address);
+    assert_eq!((receipt.player1, receipt.player1_amount), (player1.clone(), 200_0000000));
+    assert_eq!((receipt.player2, receipt.player2_amount), (player2.clone(), 50_0000000));
+    assert_eq!((receipt.locked_at, receipt.released_at), (1_000, 0));
+    assert_eq!(client.get_escrow(&(session_id + 1)), None);
+
+    // Stamped when the game settles
+    env.ledger().set_timestamp(2_000);
+    let session_id = GameBuilder::new(&client, 17, &player1, &player2)
+        .build(GamePhase::Resolved);
+    let receipt = client.get_escrow(&session_id).unwrap();
+    assert_eq!((receipt.locked_at, receipt.released_at), (2_000, 2_000));
+}
+
+// ============================================================================
+// Admin Function Tests
+// ============================================================================
+
+#[test]
+fn test_version_reports_contract_semver_and_deploy_ledger() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+
+    let version = client.version();
+    assert_eq!(version.version, soroban_sdk::String::from_str(&env, "1.0.0"));
+    assert_eq!(version.build.wasm_hash, None);
+    assert_eq!(version.build.installed_ledger, 100);
+    assert_eq!(version.build.installed_at, 1441065600);
+}
+
+#[test]
+fn test_status_reports_hub_verifier_and_season() {
+    let (env, client, hub, player1, player2) = setup_test();
+
+    let status = client.status();
+    assert_eq!(status.hub, hub.address);
+    assert_eq!(status.hub_last_ok_at, 0);
+    assert_eq!(status.circuit_version, 1);
+    assert!(status.verifier_ready);
+    assert_eq!(status.rules_version, 0);
+    assert_eq!(status.season_id, 0);
+    assert!(!status.season_running);
+
+    // Starting a game reaches the hub
+    GameBuilder::new(&client, 1, &player1, &player2).build(GamePhase::Started);
+    client.start_season(&7, &(env.ledger().timestamp() + 1000));
+
+    let status = client.status();
+    assert_eq!(status.hub_last_ok_at, env.ledger().timestamp());
+    assert_eq!(status.season_id, 7);
+    assert!(status.season_running);
+}
+
+#[test]
+fn test_pause_flags_stop_their_entrypoints_only() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    GameBuilder::new(&client, 1, &player1, &player2).build(GamePhase::Revealed);
+    GameBuilder::new(&client, 2, &player1, &player2).build(GamePhase::Started);
+
+    client.set_pause(&PauseFlags { resolution: true, ..Default::default() });
+    assert_clash_error(&client.try_resolve_battle(&1), Error::ContractPaused);
+    assert!(!client.get_game(&1).has_battle_result);
+    let commitment = commitment_for(&env, &player1, 2);
+    let inputs = public_inputs(&env, &player1, 2, &commitment);
+    client.commit_moves(&2, &player1, &inputs, &Bytes::new(&env));
+
+    // A player who can't commit mustn't lose on time
+    client.set_pause(&PauseFlags { commits: true, ..Default::default() });
+    let commitment = commitment_for(&env, &player2, 2);
+    let inputs = public_inputs(&env, &player2, 2, &commitment);
+    let result = client.try_commit_moves(&2, &player2, &inputs, &Bytes::new(&env));
+    assert_clash_error(&result, Error::ContractPaused);
+    assert_clash_error(&client.try_claim_reveal_timeout(&2, &player1), Error::ContractPaused);
+    client.send_challenge(&player1, &player2, &100);
+
+    client.set_pause(&PauseFlags { all: true, ..Default::default() });
+    assert_clash_error(&client.try_send_challenge(&player1, &player2, &100), Error::ContractPaused);
+    assert!(client.status().pause.all);
+
+    client.set_pause(&PauseFlags::default());
+    client.resolve_battle(&1);
+    assert!(client.get_game(&1).has_battle_result);
+}
+
+#[test]
+fn test_deadline_in_a_move_pause_gets_a_grace_window() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = GameBuilder::new(&client, 1, &player1, &player2).build(GamePhase::Committed);
+    let commitment = commitment_for(&env, &player1, session_id);
+    let inputs = public_inputs(&env, &player1, session_id, &commitment);
+    client.reveal_moves(&session_id, &player1, &inputs, &MoveSeq::repeat(&env, Attack::Slash, Defense::Block));
+    let deadline = client.get_game(&session_id).reveal_deadline;
+
+    // The deadline passes while reveals are paused
+    client.set_pause(&PauseFlags { commits: true, ..Default::default() });
+    env.ledger().set_timestamp(deadline + 100);
+    client.set_pause(&PauseFlags::default());
+    let lifted = env.ledger().timestamp();
+    assert_clash_error(&client.try_claim_reveal_timeout(&session_id, &player1), Error::DeadlineNotReached);
+    assert_eq!(client.get_awaiting_action(&player2, &0, &30), vec![&env, session_id]);
+
+    env.ledger().set_timestamp(lifted + 3600 + 1);
+    let result = client.claim_reveal_timeout(&session_id, &player1);
+    assert_eq!(result.winner, Some(player1.clone()));
+}
+
+#[test]
+fn test_upgrade_function_exists() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let hub_addr = env.register(MockGameHub, ());
+    let verifier_addr = register_mock_verifier(&env);
+
+    // Deploy clash with admin
+    let contract_id = env.register(ClashContract, (&admin, &hub_addr, &verifier_addr));
+    let client = ClashContractClient::new(&env, &contract_id);
+
+    // Verify the upgrade function exists and can be called
+    // Note: We can't test actual upgrade without real WASM files
+    // The function will fail with MissingValue because the WASM hash doesn't exist
+    // But that's expected - we're just verifying the function signature is correct
+    let new_wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let result = client.try_upgrade(&new_wasm_hash);
+
+    // Should fail with MissingValue (WASM doesn't exist) not NotAdmin
     // This confirms the authorization check passed
     assert!(result.is_err());
 }
+
+// ============================================================================
+// Budget Tests (`cargo test --features debug`)
+// ============================================================================
+
+/// CPU instruction ceilings for the benchmark workloads, about 20% above what they
+/// measured when added. Raise them deliberately when a change is expected to cost
+/// more, so regressions show up in review.
+#[cfg(feature = "debug")]
+const RESOLVE_BATTLE_CPU_CEILING: u64 = 1_050_000; // Raised for the snapshotted rule set, the coaching slots, activity logs, captain classes, then the session registry and its pages
+#[cfg(feature = "debug")]
+const CHALLENGE_PAGE_CPU_CEILING: u64 = 2_450_000; // Raised for the returned page, the challenge nonce, then reads that check the storage version
+
+#[cfg(feature = "debug")]
+#[test]
+fn test_budget_resolve_max_length_game() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    env.cost_estimate().budget().reset_default();
+    let result = client.bench_resolve_battle(&player1, &player2);
+    let cpu = env.cost_estimate().budget().cpu_instruction_cost();
+
+    assert_eq!(result.turn_results.len(), 3);
+    assert!(cpu <= RESOLVE_BATTLE_CPU_CEILING, "cpu {cpu} over ceiling");
+}
+
+#[cfg(feature = "debug")]
+#[test]
+fn test_budget_page_through_100_challenges() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    for _ in 0..4 {
+        client.bench_seed_challenges(&player1, &player2, &25);
+    }
+
+    let mut listed = 0;
+    for page in 0..4u32 {
+        env.cost_estimate().budget().reset_default();
+        listed += client.bench_player_challenges(&player1, &(page * 25), &25);
+        let cpu = env.cost_estimate().budget().cpu_instruction_cost();
+        assert!(cpu <= CHALLENGE_PAGE_CPU_CEILING, "page {page}: cpu {cpu} over ceiling");
+    }
+
+    assert_eq!(listed, 100);
+}
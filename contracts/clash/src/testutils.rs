@@ -0,0 +1,349 @@
+//! Test helpers for setting up Clash battles in a few lines.
+//!
+//! Enabled with the `testutils` feature (and always in this crate's own tests).
+//! The builders drive a real `ClashContractClient`, so they expect the test
+//! environment to have `env.mock_all_auths()` enabled and a verifier registered
+//! via [`register_mock_verifier`].
+
+use soroban_sdk::xdr::{ContractId, Hash, ScAddress};
+use soroban_sdk::{contract, contractimpl, vec, Address, Bytes, BytesN, Env, TryFromVal, Vec};
+
+use crate::{Attack, BattleResult, ClashContractClient, Defense, GameMode, Move};
+
+/// Default points wagered by both players when a builder is not told otherwise.
+pub const DEFAULT_POINTS: i128 = 100_0000000;
+
+// ============================================================================
+// Mock Verifier
+// ============================================================================
+
+/// Verifier stand-in that accepts every proof.
+///
+/// Exposes the same `verify_proof(public_inputs, proof_bytes)` entrypoint as the
+/// UltraHonk verifier contract, so `commit_moves` only depends on the layout of
+/// `public_inputs`.
+#[contract]
+pub struct MockVerifier;
+
+#[contractimpl]
+impl MockVerifier {
+    pub fn verify_proof(_env: Env, _public_inputs: Bytes, _proof_bytes: Bytes) {
+        // Mock implementation - every proof is valid
+    }
+}
+
+/// Register a [`MockVerifier`] and return its address, ready to pass to the
+/// Clash constructor.
+pub fn register_mock_verifier(env: &Env) -> Address {
+    env.register(MockVerifier, ())
+}
+
+// ============================================================================
+// Move Sequences
+// ============================================================================
+
+/// Shorthand for building the `Vec<Move>` a player reveals.
+pub struct MoveSeq;
+
+impl MoveSeq {
+    /// Build a move sequence from `(attack, defense)` pairs, one per turn.
+    ///
+    /// ```ignore
+    /// let moves = MoveSeq::of(&env, &[
+    ///     (Attack::Slash, Defense::Block),
+    ///     (Attack::Slash, Defense::Dodge),
+    ///     (Attack::Fireball, Defense::Counter),
+    /// ]);
+    /// ```
+    pub fn of(env: &Env, turns: &[(Attack, Defense)]) -> Vec<Move> {
+        let mut moves = vec![env];
+        for (attack, defense) in turns.iter() {
+            moves.push_back(Move {
+                attack: *attack,
+                defense: *defense,
+            });
+        }
+        moves
+    }
+
+    /// The same attack and defense on every turn.
+    pub fn repeat(env: &Env, attack: Attack, defense: Defense) -> Vec<Move> {
+        Self::of(env, &[(attack, defense), (attack, defense), (attack, defense)])
+    }
+}
+
+// ============================================================================
+// Public Inputs
+// ============================================================================
+
+/// Build `public_inputs` in the circuit layout:
+/// `[player_address (32 bytes), session_id (32 bytes), commitment_hash (32 bytes)]`.
+///
+/// The player field is the first 31 bytes of the strkey, left-padded to 32 bytes,
+/// and the session id is a big-endian field element — the same encoding the
+/// frontend's `NoirService` uses.
+pub fn public_inputs(
+    env: &Env,
+    player: &Address,
+    session_id: u32,
+    commitment: &BytesN<32>,
+) -> Bytes {
+    let mut strkey = [0u8; 56];
+    player.to_string().copy_into_slice(&mut strkey);
+
+    let mut player_field = [0u8; 32];
+    player_field[1..].copy_from_slice(&strkey[..31]);
+
+    let mut session_field = [0u8; 32];
+    session_field[28..].copy_from_slice(&session_id.to_be_bytes());
+
+    let mut inputs = Bytes::from_array(env, &player_field);
+    inputs.extend_from_array(&session_field);
+    inputs.append(&Bytes::from(commitment.clone()));
+    inputs
+}
+
+/// Commitment `commit_moves_hashed` expects for `moves`, salted with `salt`.
+pub fn hashed_commitment(env: &Env, moves: &Vec<Move>, salt: &BytesN<32>) -> BytesN<32> {
+    crate::zk::hashed_commitment(env, moves, salt)
+}
+
+/// A test address whose strkey differs from other seeds' within its first 31
+/// characters. `Address::generate` hands out addresses that only differ near the
+/// end, so their `public_inputs` player fields would all be the same.
+pub fn player_address(env: &Env, seed: u8) -> Address {
+    let contract_id = ContractId(Hash([seed; 32]));
+    Address::try_from_val(env, &ScAddress::Contract(contract_id)).unwrap()
+}
+
+/// Deterministic stand-in commitment hash for a player's moves in a session.
+pub fn commitment_for(env: &Env, player: &Address, session_id: u32) -> BytesN<32> {
+    let seed = public_inputs(env, player, session_id, &BytesN::from_array(env, &[0u8; 32]));
+    env.crypto().sha256(&seed).into()
+}
+
+// ============================================================================
+// Game Builder
+// ============================================================================
+
+/// How far a [`GameBuilder`] should drive a game.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum GamePhase {
+    /// `start_game` has been called.
+    Started,
+    /// Both players have committed.
+    Committed,
+    /// Both players have revealed.
+    Revealed,
+    /// `resolve_battle` has been called.
+    Resolved,
+}
+
+/// Drives a game through `start_game`, `commit_moves`, `reveal_moves` and
+/// `resolve_battle` up to the requested [`GamePhase`].
+pub struct GameBuilder<'a> {
+    client: &'a ClashContractClient<'a>,
+    session_id: u32,
+    player1: Address,
+    player2: Address,
+    player1_points: i128,
+    player2_points: i128,
+    player1_moves: Vec<Move>,
+    player2_moves: Vec<Move>,
+    mode: GameMode,
+}
+
+impl<'a> GameBuilder<'a> {
+    pub fn new(
+        client: &'a ClashContractClient<'a>,
+        session_id: u32,
+        player1: &Address,
+        player2: &Address,
+    ) -> Self {
+        let env = &client.env;
+        Self {
+            client,
+            session_id,
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points: DEFAULT_POINTS,
+            player2_points: DEFAULT_POINTS,
+            player1_moves: MoveSeq::repeat(env, Attack::Slash, Defense::Block),
+            player2_moves: MoveSeq::repeat(env, Attack::Slash, Defense::Block),
+            mode: GameMode::Classic,
+        }
+    }
+
+    /// Play a siege instead, with player1 attacking and player2 defending.
+    pub fn siege(mut self) -> Self {
+        self.mode = GameMode::Siege;
+        self
+    }
+
+    /// Play a correspondence game instead, with multi-day windows.
+    pub fn correspondence(mut self) -> Self {
+        self.mode = GameMode::Correspondence;
+        self
+    }
+
+    pub fn points(mut self, player1_points: i128, player2_points: i128) -> Self {
+        self.player1_points = player1_points;
+        self.player2_points = player2_points;
+        self
+    }
+
+    pub fn moves(mut self, player1_moves: Vec<Move>, player2_moves: Vec<Move>) -> Self {
+        self.player1_moves = player1_moves;
+        self.player2_moves = player2_moves;
+        self
+    }
+
+    /// Drive the game to `phase` and return its session id.
+    pub fn build(self, phase: GamePhase) -> u32 {
+        let env = &self.client.env;
+        let session_id = self.session_id;
+
+        let (p1, p2) = (&self.player1, &self.player2);
+        let (p1_points, p2_points) = (&self.player1_points, &self.player2_points);
+        match self.mode {
+            GameMode::Classic => self.client.start_game(&session_id, p1, p2, p1_points, p2_points),
+            GameMode::Siege => self.client.start_siege(&session_id, p1, p2, p1_points, p2_points),
+            GameMode::Correspondence => {
+                self.client.start_correspondence(&session_id, p1, p2, p1_points, p2_points)
+            }
+        }
+        if phase == GamePhase::Started {
+            return session_id;
+        }
+
+        let p1_commitment = commitment_for(env, &self.player1, session_id);
+        let p2_commitment = commitment_for(env, &self.player2, session_id);
+        let p1_inputs = public_inputs(env, &self.player1, session_id, &p1_commitment);
+        let p2_inputs = public_inputs(env, &self.player2, session_id, &p2_commitment);
+        let proof = Bytes::new(env);
+
+        self.client
+            .commit_moves(&session_id, &self.player1, &p1_inputs, &proof);
+        self.client
+            .commit_moves(&session_id, &self.player2, &p2_inputs, &proof);
+        if phase == GamePhase::Committed {
+            return session_id;
+        }
+
+        self.client
+            .reveal_moves(&session_id, &self.player1, &p1_inputs, &self.player1_moves);
+        self.client
+            .reveal_moves(&session_id, &self.player2, &p2_inputs, &self.player2_moves);
+        if phase == GamePhase::Revealed {
+            return session_id;
+        }
+
+        self.client.resolve_battle(&session_id);
+        session_id
+    }
+
+    /// Play the game to completion and return the battle result.
+    pub fn resolve(self) -> BattleResult {
+        let client = self.client;
+        let session_id = self.build(GamePhase::Resolved);
+        client.get_game(&session_id).battle_result
+    }
+}
+
+/// Commit, reveal and resolve a game that was started some other way (an accepted
+/// challenge, a series round), with the default commitments.
+pub fn play_started_game(
+    client: &ClashContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    player1_moves: &Vec<Move>,
+    player2_moves: &Vec<Move>,
+) -> BattleResult {
+    let env = &client.env;
+    let proof = Bytes::new(env);
+    let p1_inputs = public_inputs(env, player1, session_id, &commitment_for(env, player1, session_id));
+    let p2_inputs = public_inputs(env, player2, session_id, &commitment_for(env, player2, session_id));
+
+    client.commit_moves(&session_id, player1, &p1_inputs, &proof);
+    client.commit_moves(&session_id, player2, &p2_inputs, &proof);
+    client.reveal_moves(&session_id, player1, &p1_inputs, player1_moves);
+    client.reveal_moves(&session_id, player2, &p2_inputs, player2_moves);
+    client.resolve_battle(&session_id)
+}
+
+// ============================================================================
+// Duels
+// ============================================================================
+
+/// Commitment `commit_duel_move` expects for `action`, salted with `salt`.
+pub fn duel_commitment(env: &Env, action: &Move, salt: &BytesN<32>) -> BytesN<32> {
+    crate::duel::move_commitment(env, action, salt)
+}
+
+/// Commit and reveal one duel round for both players.
+pub fn play_duel_round(
+    client: &ClashContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    player1_move: &Move,
+    player2_move: &Move,
+) {
+    let env = &client.env;
+    let salt1 = BytesN::from_array(env, &[1u8; 32]);
+    let salt2 = BytesN::from_array(env, &[2u8; 32]);
+
+    client.commit_duel_move(&session_id, player1, &duel_commitment(env, player1_move, &salt1));
+    client.commit_duel_move(&session_id, player2, &duel_commitment(env, player2_move, &salt2));
+    client.reveal_duel_move(&session_id, player1, player1_move, &salt1);
+    client.reveal_duel_move(&session_id, player2, player2_move, &salt2);
+}
+
+// ============================================================================
+// Challenge Builder
+// ============================================================================
+
+/// Sends (and optionally accepts) a challenge between two players.
+pub struct ChallengeBuilder<'a> {
+    client: &'a ClashContractClient<'a>,
+    challenger: Address,
+    challenged: Address,
+    points_wagered: i128,
+}
+
+impl<'a> ChallengeBuilder<'a> {
+    pub fn new(
+        client: &'a ClashContractClient<'a>,
+        challenger: &Address,
+        challenged: &Address,
+    ) -> Self {
+        Self {
+            client,
+            challenger: challenger.clone(),
+            challenged: challenged.clone(),
+            points_wagered: DEFAULT_POINTS,
+        }
+    }
+
+    pub fn wager(mut self, points_wagered: i128) -> Self {
+        self.points_wagered = points_wagered;
+        self
+    }
+
+    /// Send the challenge and return its id.
+    pub fn send(self) -> u32 {
+        self.client
+            .send_challenge(&self.challenger, &self.challenged, &self.points_wagered)
+    }
+
+    /// Send and accept the challenge; returns `(challenge_id, session_id)`.
+    pub fn accept(self) -> (u32, u32) {
+        let client = self.client;
+        let challenged = self.challenged.clone();
+        let challenge_id = self.send();
+        let nonce = client.get_challenge(&challenge_id).nonce;
+        let session_id = client.accept_challenge(&challenge_id, &challenged, &nonce);
+        (challenge_id, session_id)
+    }
+}
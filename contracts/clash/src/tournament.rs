@@ -0,0 +1,760 @@
+//! Single- and double-elimination tournaments.
+//!
+//! An organizer opens registration for up to `max_players`, until the tournament
+//! starts at its registration deadline. Registered players check in during the
+//! `CHECK_IN_SECS` before the start; anyone who doesn't is dropped. Once the start
+//! passes with at least two players checked in, the bracket is seeded from them by
+//! rating in the standard order: the top seeds meet the bottom seeds and take any
+//! byes, and the top two can only meet in the final. Every bracket match is an
+//! ordinary classic game with `entry_points` staked on each side, played through the
+//! usual commit/reveal/resolve flow. `advance_bracket` (callable by anyone) reads the
+//! results and moves players along the bracket until one champion is left, starting
+//! a few games per call.
+//!
+//! The whole bracket is laid out when it is seeded. Each match names the slots its
+//! winner and loser move to, and counts the slots still waiting on an earlier match;
+//! a match whose feeders sent only one player is a bye, and one they sent nobody is
+//! skipped. In double elimination a winners' bracket loser drops into the losers'
+//! bracket, whose champion meets the winners' champion in the grand final. If the
+//! losers' champion wins it, both have lost once and the final is reset: one more
+//! match decides it.
+//!
+//! The organizer can put up a prize pool in any token, with the share each place
+//! takes. When the bracket completes the pool is credited by final standing, to
+//! collect with `claim_prize`: the champion first, then the players knocked out
+//! latest. Players knocked out in the same round tie, and split the shares of the
+//! places they take evenly. A tournament that doesn't get two players checked in is
+//! cancelled, and the organizer or admin can call one off with `cancel_tournament`
+//! until it completes; either way the pool is credited back to the organizer.
+//!
+//! To deter win-trading in high-stakes events the organizer can also have large
+//! prizes vest: a prize above the threshold is credited only in part, and the rest
+//! is locked for a while after the tournament completes, to collect with
+//! `claim_vested`.
+//!
+//! Match games get a commit deadline of `MATCH_COMMIT_SECS`, so the schedule keeps
+//! moving without the organizer. `advance_bracket` enforces missed deadlines itself:
+//! a player whose opponent never committed goes through on a walkover, recorded
+//! apart from a won battle. A match that ends without a winner is settled by these
+//! rules:
+//!
+//! - a game past its lifetime is expired by `advance_bracket` itself, so a match
+//!   nobody plays can't hold the bracket up,
+//! - if only one player ever committed, the other was a no-show and goes out,
+//! - otherwise (a draw, or neither played) the match is replayed once, and if the
+//!   replay has no winner either the higher seed goes through.
+//!
+//! A match whose game can't be started because of one player (at their live session
+//! limit, or not registered with the Game Hub) is a walkover against them, and one
+//! between two addresses that have become one identity goes to the higher seed.
+//! Any other failure, such as the hub call failing, leaves the match unstarted for
+//! the next `advance_bracket` to retry.
+
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{vec, Address, Env, Vec};
+
+use crate::pause;
+use crate::storage;
+use crate::{awaiting, game, identity, ratings};
+use crate::{BracketFormat, BracketMatch, BracketSide, Error, GameHubClient, GameMode, OutcomeReason};
+use crate::{PrizeVesting, Tournament, TournamentPrizes, TournamentStatus, VestedPrize};
+
+/// Largest field a tournament can take
+const MAX_TOURNAMENT_PLAYERS: u32 = 32;
+
+/// Prize shares are in basis points of the pool
+const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Longest a prize can stay locked
+const MAX_VESTING_SECS: u64 = 30 * 24 * 60 * 60;
+
+/// How long before the start check-in opens
+pub(crate) const CHECK_IN_SECS: u64 = 30 * 60;
+
+/// Most bracket games one call starts, replays or expires, which keeps it within a
+/// transaction's ledger entry limit; the rest wait for the next `advance_bracket`
+const MAX_GAMES_PER_CALL: u32 = 2;
+
+/// Time both players get to commit once a match game starts
+pub(crate) const MATCH_COMMIT_SECS: u64 = 24 * 60 * 60;
+
+/// How a bracket game stands
+enum Outcome {
+    InPlay,
+    NoWinner,
+    Won(Address),
+    Walkover(Address), // The opponent never committed
+}
+
+pub(crate) fn create_tournament(
+    env: &Env,
+    organizer: Address,
+    max_players: u32,
+    entry_points: i128,
+    registration_deadline: u64,
+    format: BracketFormat,
+) -> Result<u32, Error> {
+    organizer.require_auth();
+
+    if !(2..=MAX_TOURNAMENT_PLAYERS).contains(&max_players) || !max_players.is_power_of_two() {
+        return Err(Error::InvalidTournament);
+    }
+    // A losers' bracket needs at least two first-round losers
+    if format == BracketFormat::DoubleElimination && max_players < 4 {
+        return Err(Error::InvalidTournament);
+    }
+    if registration_deadline <= env.ledger().timestamp() {
+        return Err(Error::InvalidTournament);
+    }
+    game::validate_wager(env, entry_points)?;
+
+    let tournament_id = storage::next_tournament_id(env);
+    let tournament = Tournament {
+        tournament_id,
+        organizer,
+        max_players,
+        entry_points,
+        registration_deadline,
+        format,
+        players: vec![env],
+        checked_in: vec![env],
+        status: TournamentStatus::Registration,
+        round: 0,
+        champion: None,
+    };
+    storage::save_tournament(env, &tournament);
+    Ok(tournament_id)
+}
+
+/// Take a place in the field, to confirm at check-in
+pub(crate) fn register_for_tournament(
+    env: &Env,
+    tournament_id: u32,
+    player: Address,
+) -> Result<(), Error> {
+    player.require_auth();
+
+    let mut tournament = load(env, tournament_id)?;
+    if tournament.status != TournamentStatus::Registration
+        || env.ledger().timestamp() > tournament.registration_deadline
+    {
+        return Err(Error::RegistrationClosed);
+    }
+    // Two addresses of one identity would have to play each other
+    let identity = identity::primary(env, &player);
+    if tournament.players.iter().any(|registered| identity::primary(env, &registered) == identity) {
+        return Err(Error::AlreadyRegistered);
+    }
+    if tournament.players.len() >= tournament.max_players {
+        return Err(Error::TournamentFull);
+    }
+
+    tournament.players.push_back(player);
+    storage::save_tournament(env, &tournament);
+    Ok(())
+}
+
+/// Confirm a place in the check-in window; checking in again does nothing
+pub(crate) fn check_in(env: &Env, tournament_id: u32, player: Address) -> Result<(), Error> {
+    player.require_auth();
+
+    let mut tournament = load(env, tournament_id)?;
+    let now = env.ledger().timestamp();
+    let opens = tournament.registration_deadline.saturating_sub(CHECK_IN_SECS);
+    if tournament.status != TournamentStatus::Registration
+        || !(opens..=tournament.registration_deadline).contains(&now)
+    {
+        return Err(Error::CheckInClosed);
+    }
+    if !tournament.players.contains(&player) {
+        return Err(Error::NotPlayer);
+    }
+    if tournament.checked_in.contains(&player) {
+        return Ok(());
+    }
+    tournament.checked_in.push_back(player);
+    storage::save_tournament(env, &tournament);
+    Ok(())
+}
+
+/// Move the tournament on as far as the recorded results allow: seed the bracket from
+/// the checked-in players once it starts, record finished matches, replay draws, and
+/// start the matches their players were sent on to (or crown the champion).
+pub(crate) fn advance_bracket(env: &Env, tournament_id: u32) -> Result<Tournament, Error> {
+    let mut tournament = load(env, tournament_id)?;
+
+    match tournament.status {
+        TournamentStatus::Registration => {
+            if env.ledger().timestamp() <= tournament.registration_deadline {
+                return Err(Error::DeadlineNotReached);
+            }
+            // No-shows are dropped; the rest keep their registration order
+            let mut checked_in = vec![env];
+            for player in tournament.players.iter() {
+                if tournament.checked_in.contains(&player) {
+                    checked_in.push_back(player);
+                }
+            }
+            tournament.players = checked_in;
+            if tournament.players.len() < 2 {
+                cancel(env, &mut tournament);
+            } else {
+                seed(env, &mut tournament)?;
+            }
+        }
+        TournamentStatus::InProgress => advance_round(env, &mut tournament)?,
+        TournamentStatus::Completed | TournamentStatus::Cancelled => return Ok(tournament),
+    }
+
+    storage::save_tournament(env, &tournament);
+    Ok(tournament)
+}
+
+/// Call off a tournament that hasn't completed. Games already started play out as
+/// ordinary games; nobody is placed or paid, and the prize pool is credited back to
+/// the organizer.
+pub(crate) fn cancel_tournament(env: &Env, tournament_id: u32, caller: Address) -> Result<Tournament, Error> {
+    caller.require_auth();
+
+    let mut tournament = load(env, tournament_id)?;
+    if caller != tournament.organizer && caller != storage::admin(env) {
+        return Err(Error::NotPlayer);
+    }
+    if matches!(tournament.status, TournamentStatus::Completed | TournamentStatus::Cancelled) {
+        return Err(Error::TournamentFinished);
+    }
+    cancel(env, &mut tournament);
+    storage::save_tournament(env, &tournament);
+    Ok(tournament)
+}
+
+fn cancel(env: &Env, tournament: &mut Tournament) {
+    tournament.status = TournamentStatus::Cancelled;
+    if let Some(prizes) = storage::tournament_prizes(env, tournament.tournament_id) {
+        credit(env, tournament.tournament_id, &tournament.organizer, prizes.pool);
+    }
+}
+
+/// Put up the prize pool, moving it from the organizer now. Only the organizer can,
+/// once, before the bracket is seeded.
+pub(crate) fn set_prizes(
+    env: &Env,
+    tournament_id: u32,
+    token: Address,
+    pool: i128,
+    payouts_bps: Vec<u32>,
+) -> Result<(), Error> {
+    let tournament = load(env, tournament_id)?;
+    tournament.organizer.require_auth();
+
+    if tournament.status != TournamentStatus::Registration {
+        return Err(Error::RegistrationClosed);
+    }
+    if storage::tournament_prizes(env, tournament_id).is_some() || pool <= 0 {
+        return Err(Error::InvalidPrizeSchedule);
+    }
+    let total: u64 = payouts_bps.iter().map(u64::from).sum();
+    if payouts_bps.is_empty() || payouts_bps.len() > tournament.max_players || total != BPS_DENOMINATOR as u64 {
+        return Err(Error::InvalidPrizeSchedule);
+    }
+
+    TokenClient::new(env, &token).transfer(&tournament.organizer, env.current_contract_address(), &pool);
+    let prizes = TournamentPrizes {
+        token,
+        pool,
+        payouts_bps,
+    };
+    storage::save_tournament_prizes(env, tournament_id, &prizes);
+    Ok(())
+}
+
+/// Have prizes above `threshold` vest. Only the organizer can, once, before the
+/// bracket is seeded.
+pub(crate) fn set_prize_vesting(
+    env: &Env,
+    tournament_id: u32,
+    threshold: i128,
+    immediate_bps: u32,
+    lock_secs: u64,
+) -> Result<(), Error> {
+    let tournament = load(env, tournament_id)?;
+    tournament.organizer.require_auth();
+
+    if tournament.status != TournamentStatus::Registration {
+        return Err(Error::RegistrationClosed);
+    }
+    if storage::prize_vesting(env, tournament_id).is_some() || threshold < 0 {
+        return Err(Error::InvalidPrizeSchedule);
+    }
+    if immediate_bps >= BPS_DENOMINATOR || !(1..=MAX_VESTING_SECS).contains(&lock_secs) {
+        return Err(Error::InvalidPrizeSchedule);
+    }
+
+    let vesting = PrizeVesting {
+        threshold,
+        immediate_bps,
+        lock_secs,
+    };
+    storage::save_prize_vesting(env, tournament_id, &vesting);
+    Ok(())
+}
+
+/// Pay out what `player` was credited from the prize pool
+pub(crate) fn claim_prize(env: &Env, tournament_id: u32, player: Address) -> Result<i128, Error> {
+    player.require_auth();
+
+    let owed = storage::tournament_prize(env, tournament_id, &player);
+    let Some(prizes) = storage::tournament_prizes(env, tournament_id).filter(|_| owed > 0) else {
+        return Err(Error::NothingToClaim);
+    };
+    // Cleared before transferring so a re-entrant claim finds nothing
+    storage::remove_tournament_prize(env, tournament_id, &player);
+    TokenClient::new(env, &prizes.token).transfer(&env.current_contract_address(), &player, &owed);
+    Ok(owed)
+}
+
+/// Pay out the locked part of `player`'s prize once it has vested
+pub(crate) fn claim_vested(env: &Env, tournament_id: u32, player: Address) -> Result<i128, Error> {
+    player.require_auth();
+
+    let vested = storage::vested_prize(env, tournament_id, &player);
+    let (Some(vested), Some(prizes)) = (vested, storage::tournament_prizes(env, tournament_id)) else {
+        return Err(Error::NothingToClaim);
+    };
+    if env.ledger().timestamp() < vested.unlocks_at {
+        return Err(Error::PrizeStillVesting);
+    }
+    // Cleared before transferring so a re-entrant claim finds nothing
+    storage::remove_vested_prize(env, tournament_id, &player);
+    TokenClient::new(env, &prizes.token).transfer(&env.current_contract_address(), &player, &vested.amount);
+    Ok(vested.amount)
+}
+
+pub(crate) fn load(env: &Env, tournament_id: u32) -> Result<Tournament, Error> {
+    storage::tournament(env, tournament_id).ok_or(Error::TournamentNotFound)
+}
+
+/// Lay out the bracket: players sorted by rating, with ties kept in registration
+/// order, then paired in the standard bracket order. Seeds past the field are byes,
+/// so the top seeds go straight through.
+fn seed(env: &Env, tournament: &mut Tournament) -> Result<(), Error> {
+    let mut seeds: Vec<Address> = vec![env];
+    let mut seed_ratings: Vec<i32> = vec![env];
+    for player in tournament.players.iter() {
+        let rating = ratings::player_stats(env, &player).rating;
+        let index = seed_ratings
+            .iter()
+            .position(|seeded| seeded < rating)
+            .map(|index| index as u32)
+            .unwrap_or(seeds.len());
+        seeds.insert(index, player);
+        seed_ratings.insert(index, rating);
+    }
+
+    // Each doubling of the bracket pairs every seed `s` with `2n - 1 - s`, which
+    // keeps the top seeds on opposite halves: 1v8, 4v5, 2v7, 3v6 for eight
+    let size = seeds.len().next_power_of_two();
+    let mut order: Vec<u32> = vec![env, 0];
+    while order.len() < size {
+        let doubled = order.len() * 2;
+        let mut next = vec![env];
+        for seed in order.iter() {
+            next.push_back(seed);
+            next.push_back(doubled - 1 - seed);
+        }
+        order = next;
+    }
+    let mut bracket = layout(env, tournament.format, size);
+    for k in 0..size / 2 {
+        let mut first = bracket.get_unchecked(k);
+        first.player1 = seeds.get(order.get_unchecked(2 * k));
+        first.player2 = seeds.get(order.get_unchecked(2 * k + 1));
+        first.pending = 0;
+        bracket.set(k, first);
+    }
+
+    tournament.players = seeds;
+    tournament.status = TournamentStatus::InProgress;
+    tournament.round = 0;
+    storage::save_bracket(env, tournament.tournament_id, &bracket);
+    advance_round(env, tournament)
+}
+
+/// Every match of a bracket for `size` seeds, winners' bracket first, each waiting
+/// on its feeders. A slot is a match's position times two, plus one for player2.
+///
+/// With `size = 2^k`, winners' round `r` has `size / 2^(r + 1)` matches. The losers'
+/// bracket has `2(k - 1)` rounds: its even rounds pair up the players already in it,
+/// and its odd round `2r - 1` takes on the losers of winners' round `r`.
+fn layout(env: &Env, format: BracketFormat, size: u32) -> Vec<BracketMatch> {
+    let rounds = size.trailing_zeros();
+    let winners_at = |round: u32, index: u32| size - (size >> round) + index;
+    let losers_rounds = match format {
+        BracketFormat::SingleElimination => 0,
+        BracketFormat::DoubleElimination => 2 * (rounds - 1),
+    };
+    let losers_count = |round: u32| size >> (round / 2 + 2);
+    let mut losers_start: Vec<u32> = vec![env];
+    let mut next_start = size - 1;
+    for round in 0..losers_rounds {
+        losers_start.push_back(next_start);
+        next_start += losers_count(round);
+    }
+    let losers_at = |round: u32, index: u32| losers_start.get_unchecked(round) + index;
+    let grand_final = next_start;
+
+    let mut bracket = vec![env];
+    for round in 0..rounds {
+        for index in 0..size >> (round + 1) {
+            let winner_to = if round + 1 < rounds {
+                Some(2 * winners_at(round + 1, index / 2) + index % 2)
+            } else if losers_rounds > 0 {
+                Some(2 * grand_final)
+            } else {
+                None
+            };
+            let loser_to = match (losers_rounds, round) {
+                (0, _) => None,
+                (_, 0) => Some(2 * losers_at(0, index / 2) + index % 2),
+                _ => Some(2 * losers_at(2 * round - 1, index) + 1),
+            };
+            bracket.push_back(slot_match(BracketSide::Winners, round, index, winner_to, loser_to));
+        }
+    }
+    for round in 0..losers_rounds {
+        for index in 0..losers_count(round) {
+            let winner_to = if round + 1 == losers_rounds {
+                Some(2 * grand_final + 1)
+            } else if round % 2 == 0 {
+                Some(2 * losers_at(round + 1, index))
+            } else {
+                Some(2 * losers_at(round + 1, index / 2) + index % 2)
+            };
+            bracket.push_back(slot_match(BracketSide::Losers, round, index, winner_to, None));
+        }
+    }
+    if losers_rounds > 0 {
+        let reset = grand_final + 1;
+        bracket.push_back(slot_match(BracketSide::GrandFinal, 0, 0, Some(2 * reset), Some(2 * reset + 1)));
+        bracket.push_back(slot_match(BracketSide::GrandFinal, 1, 0, None, None));
+    }
+    bracket
+}
+
+/// Move the bracket on: record finished matches, start the games still to be
+/// played as far as the call has room, and send each result's players on to their
+/// next slots. Once every match is decided, crown the champion.
+fn advance_round(env: &Env, tournament: &mut Tournament) -> Result<(), Error> {
+    let mut bracket = storage::bracket(env, tournament.tournament_id);
+    let mut budget = MAX_GAMES_PER_CALL;
+
+    // Matches only feed later ones, so a single pass carries results through
+    for position in 0..bracket.len() {
+        let mut bracket_match = bracket.get_unchecked(position);
+        if bracket_match.pending > 0 || bracket_match.winner.is_some() {
+            continue;
+        }
+        let loser = match (&bracket_match.player1, &bracket_match.player2) {
+            (Some(_), Some(_)) => {
+                update_match(env, tournament, &mut bracket_match, &mut budget);
+                bracket_match.winner.as_ref().map(|winner| other_player(&bracket_match, winner))
+            }
+            (Some(player), None) | (None, Some(player)) => {
+                bracket_match.winner = Some(player.clone());
+                None
+            }
+            // Skipped; its slots were passed on when it was left empty
+            (None, None) => continue,
+        };
+        bracket.set(position, bracket_match.clone());
+        let Some(winner) = bracket_match.winner.clone() else {
+            continue;
+        };
+        // The winners' champion taking the grand final leaves the reset unplayed
+        if bracket_match.side == BracketSide::GrandFinal && bracket_match.player1 == Some(winner.clone()) {
+            fill(&mut bracket, bracket_match.winner_to, None);
+            fill(&mut bracket, bracket_match.loser_to, None);
+        } else {
+            fill(&mut bracket, bracket_match.winner_to, Some(winner));
+            fill(&mut bracket, bracket_match.loser_to, loser);
+        }
+    }
+
+    let undecided = bracket
+        .iter()
+        .find(|bracket_match| bracket_match.winner.is_none() && !is_skipped(bracket_match));
+    match undecided {
+        Some(bracket_match) => tournament.round = bracket_match.round,
+        None => {
+            tournament.champion = bracket.iter().filter_map(|bracket_match| bracket_match.winner).last();
+            tournament.status = TournamentStatus::Completed;
+            award_prizes(env, tournament, &bracket);
+        }
+    }
+    storage::save_bracket(env, tournament.tournament_id, &bracket);
+    Ok(())
+}
+
+/// Put `player` in `slot`, or record that its feeder had nobody to send. A match
+/// left with no players at all passes the same on to its own next slots.
+fn fill(bracket: &mut Vec<BracketMatch>, slot: Option<u32>, player: Option<Address>) {
+    let Some(slot) = slot else {
+        return;
+    };
+    let position = slot / 2;
+    let mut bracket_match = bracket.get_unchecked(position);
+    bracket_match.pending -= 1;
+    if slot % 2 == 0 {
+        bracket_match.player1 = player;
+    } else {
+        bracket_match.player2 = player;
+    }
+    bracket.set(position, bracket_match.clone());
+    if is_skipped(&bracket_match) {
+        fill(bracket, bracket_match.winner_to, None);
+        fill(bracket, bracket_match.loser_to, None);
+    }
+}
+
+/// Credit the prize pool by final standing. Whatever the shares don't place (places
+/// past the field, rounding) goes to the champion.
+fn award_prizes(env: &Env, tournament: &Tournament, bracket: &Vec<BracketMatch>) {
+    let prizes = storage::tournament_prizes(env, tournament.tournament_id);
+    let (Some(prizes), Some(champion)) = (prizes, &tournament.champion) else {
+        return;
+    };
+    let vesting = storage::prize_vesting(env, tournament.tournament_id);
+    let share = |place: u32| {
+        let bps = prizes.payouts_bps.get(place).unwrap_or(0);
+        prizes.pool * i128::from(bps) / i128::from(BPS_DENOMINATOR)
+    };
+
+    // Later matches knock players out later, so walk the bracket from the back, a
+    // round of one side at a time
+    let mut paid = 0;
+    let mut place = 1;
+    let mut position = bracket.len();
+    while position > 0 {
+        let last = bracket.get_unchecked(position - 1);
+        let mut tied: Vec<Address> = vec![env];
+        while position > 0 {
+            let bracket_match = bracket.get_unchecked(position - 1);
+            if (bracket_match.side, bracket_match.round) != (last.side, last.round) {
+                break;
+            }
+            if let Some(player) = knocked_out(bracket, &bracket_match) {
+                tied.push_back(player);
+            }
+            position -= 1;
+        }
+        if tied.is_empty() {
+            continue;
+        }
+        let each = (place..place + tied.len()).map(share).sum::<i128>() / i128::from(tied.len());
+        for player in tied.iter() {
+            award(env, tournament.tournament_id, &vesting, &player, each);
+            paid += each;
+        }
+        place += tied.len();
+    }
+    award(env, tournament.tournament_id, &vesting, champion, prizes.pool - paid);
+}
+
+/// Credit a prize, locking the part of it that vests
+fn award(env: &Env, tournament_id: u32, vesting: &Option<PrizeVesting>, player: &Address, amount: i128) {
+    let Some(vesting) = vesting.as_ref().filter(|vesting| amount > vesting.threshold) else {
+        credit(env, tournament_id, player, amount);
+        return;
+    };
+    let immediate = amount * i128::from(vesting.immediate_bps) / i128::from(BPS_DENOMINATOR);
+    credit(env, tournament_id, player, immediate);
+    let vested = VestedPrize {
+        amount: amount - immediate,
+        unlocks_at: env.ledger().timestamp() + vesting.lock_secs,
+    };
+    storage::save_vested_prize(env, tournament_id, player, &vested);
+}
+
+/// The player a decided match put out of the tournament: its loser, unless they
+/// moved on to another match
+fn knocked_out(bracket: &Vec<BracketMatch>, bracket_match: &BracketMatch) -> Option<Address> {
+    let winner = bracket_match.winner.as_ref()?;
+    bracket_match.player1.as_ref().zip(bracket_match.player2.as_ref())?;
+    let loser = other_player(bracket_match, winner);
+    let moved_on = bracket_match.loser_to.is_some_and(|slot| {
+        let next = bracket.get_unchecked(slot / 2);
+        let placed = if slot % 2 == 0 { next.player1 } else { next.player2 };
+        placed == Some(loser.clone())
+    });
+    (!moved_on).then_some(loser)
+}
+
+fn credit(env: &Env, tournament_id: u32, player: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let owed = storage::tournament_prize(env, tournament_id, player);
+    storage::save_tournament_prize(env, tournament_id, player, owed + amount);
+}
+
+fn is_skipped(bracket_match: &BracketMatch) -> bool {
+    bracket_match.pending == 0 && bracket_match.player1.is_none() && bracket_match.player2.is_none()
+}
+
+fn other_player(bracket_match: &BracketMatch, player: &Address) -> Address {
+    match &bracket_match.player1 {
+        Some(player1) if player1 != player => player1.clone(),
+        _ => bracket_match.player2.clone().unwrap_or(player.clone()),
+    }
+}
+
+/// Take an unfinished two-player match as far as `budget` allows: record its result,
+/// or start its game (or the replay) if none is in play
+fn update_match(env: &Env, tournament: &Tournament, bracket_match: &mut BracketMatch, budget: &mut u32) {
+    let (Some(player1), Some(player2)) = (bracket_match.player1.clone(), bracket_match.player2.clone()) else {
+        return;
+    };
+    if let Some(session_id) = bracket_match.session_id {
+        match game_result(env, session_id, budget) {
+            Outcome::InPlay => return,
+            Outcome::Won(winner) => {
+                bracket_match.winner = Some(winner);
+                return;
+            }
+            Outcome::Walkover(winner) => {
+                bracket_match.winner = Some(winner);
+                bracket_match.is_walkover = true;
+                return;
+            }
+            // Elimination needs a winner: replay once on a fresh session
+            Outcome::NoWinner if !bracket_match.replayed => {
+                bracket_match.session_id = None;
+                bracket_match.replayed = true;
+            }
+            Outcome::NoWinner => {
+                bracket_match.winner = Some(higher_seed(tournament, player1, player2));
+                return;
+            }
+        }
+    }
+
+    if *budget == 0 {
+        return;
+    }
+    *budget -= 1;
+    match start_match(env, tournament.entry_points, &player1, &player2) {
+        Ok(session_id) => bracket_match.session_id = Some(session_id),
+        // One identity on both sides can never play itself
+        Err(Error::CannotChallengeSelf) => {
+            bracket_match.winner = Some(higher_seed(tournament, player1, player2));
+        }
+        // Anything not down to one player may clear up, so leave it to be retried
+        Err(error) => {
+            if let Some(blocked) = blocked_player(env, error, &player1, &player2) {
+                bracket_match.winner = Some(other_player(bracket_match, &blocked));
+                bracket_match.is_walkover = true;
+            }
+        }
+    }
+}
+
+/// The player whose side a match game failed to start on with `error`, if it was
+/// down to just one of them
+fn blocked_player(env: &Env, error: Error, player1: &Address, player2: &Address) -> Option<Address> {
+    let is_blocked = |player: &Address| match error {
+        Error::TooManyLiveSessions => storage::player_sessions(env, player).len() >= awaiting::MAX_LIVE_SESSIONS,
+        Error::PlayerNotRegistered => !GameHubClient::new(env, &storage::game_hub(env)).is_registered(player),
+        _ => false,
+    };
+    match (is_blocked(player1), is_blocked(player2)) {
+        (true, false) => Some(player1.clone()),
+        (false, true) => Some(player2.clone()),
+        _ => None,
+    }
+}
+
+/// How a bracket game ended. A game with a missed deadline, or past its lifetime, is
+/// ended here if `budget` has room, and one gone from storage counts as ended without
+/// a winner.
+fn game_result(env: &Env, session_id: u32, budget: &mut u32) -> Outcome {
+    let Ok(mut game) = storage::load_game(env, session_id) else {
+        return Outcome::NoWinner;
+    };
+    if !game.has_battle_result {
+        if *budget == 0 || !end_overdue(env, session_id) {
+            return Outcome::InPlay;
+        }
+        *budget -= 1;
+        let Ok(ended) = storage::load_game(env, session_id) else {
+            return Outcome::NoWinner;
+        };
+        game = ended;
+    }
+    match game.battle_result.winner {
+        Some(winner) if game.battle_result.reason == OutcomeReason::CommitTimeout => Outcome::Walkover(winner),
+        Some(winner) => Outcome::Won(winner),
+        // If only one side ever committed, the other didn't show up
+        None => match (game.has_player1_commitment, game.has_player2_commitment) {
+            (true, false) => Outcome::Walkover(game.player1),
+            (false, true) => Outcome::Walkover(game.player2),
+            _ => Outcome::NoWinner,
+        },
+    }
+}
+
+/// Apply a missed deadline as anyone's `enforce_deadlines` would, or expire the game
+/// once it is past its lifetime; false if neither is due yet
+fn end_overdue(env: &Env, session_id: u32) -> bool {
+    let enforced = pause::check_deadline_claim(env).is_ok() && game::enforce_deadlines(env, session_id).is_ok();
+    enforced || game::expire_game(env, session_id).is_ok()
+}
+
+/// Players are kept in seed order once the bracket is seeded
+fn higher_seed(tournament: &Tournament, player1: Address, player2: Address) -> Address {
+    let seed = |player: &Address| tournament.players.first_index_of(player);
+    if seed(&player2) < seed(&player1) {
+        player2
+    } else {
+        player1
+    }
+}
+
+/// A match still waiting on both of its slots
+fn slot_match(side: BracketSide, round: u32, index: u32, winner_to: Option<u32>, loser_to: Option<u32>) -> BracketMatch {
+    BracketMatch {
+        side,
+        round,
+        index,
+        player1: None,
+        player2: None,
+        session_id: None,
+        winner: None,
+        replayed: false,
+        is_walkover: false,
+        pending: 2,
+        winner_to,
+        loser_to,
+    }
+}
+
+fn start_match(
+    env: &Env,
+    entry_points: i128,
+    player1: &Address,
+    player2: &Address,
+) -> Result<u32, Error> {
+    let session_id = game::allocate_session_id(env);
+    game::start_game_after_auth(
+        env,
+        session_id,
+        player1.clone(),
+        player2.clone(),
+        entry_points,
+        entry_points,
+        GameMode::Classic,
+    )?;
+    let mut game = storage::load_game(env, session_id)?;
+    game.commit_deadline = env.ledger().timestamp() + MATCH_COMMIT_SECS;
+    storage::save_game(env, session_id, &game);
+    Ok(session_id)
+}
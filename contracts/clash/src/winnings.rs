@@ -0,0 +1,50 @@
+//! Pull-model token payouts.
+//!
+//! Settling a game doesn't transfer tokens: forfeited deposits, refunds and raise
+//! payouts are recorded as claimable balances per session and player, which the
+//! player collects with `claim_winnings`. A recipient that can't receive an asset
+//! (no trustline, deauthorized) then can't make the game fail to settle, and the
+//! resolve path never calls a token contract.
+
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, Env, Map};
+
+use crate::badges;
+use crate::storage;
+use crate::Error;
+
+/// Record `amount` of `token` as claimable by `to` from this session
+pub(crate) fn credit(env: &Env, session_id: u32, to: &Address, token: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let mut owed = storage::winnings(env, session_id, to);
+    if owed.is_empty() {
+        badges::reward_credited(env, to);
+    }
+    owed.set(token.clone(), owed.get(token.clone()).unwrap_or(0) + amount);
+    storage::save_winnings(env, session_id, to, &owed);
+}
+
+/// Pay out everything `player` is owed from this session, per token
+pub(crate) fn claim_winnings(
+    env: &Env,
+    session_id: u32,
+    player: Address,
+) -> Result<Map<Address, i128>, Error> {
+    player.require_auth();
+
+    let owed = storage::winnings(env, session_id, &player);
+    if owed.is_empty() {
+        return Err(Error::NothingToClaim);
+    }
+    // Cleared before transferring so a re-entrant claim finds nothing
+    storage::remove_winnings(env, session_id, &player);
+    badges::reward_claimed(env, &player);
+
+    let contract = env.current_contract_address();
+    for (token, amount) in owed.iter() {
+        TokenClient::new(env, &token).transfer(&contract, &player, &amount);
+    }
+    Ok(owed)
+}
@@ -0,0 +1,390 @@
+//! ZK commit/reveal: UltraHonk proof verification and commitment handling, plus the
+//! plain hash commit/reveal fallback for games played without the verifier.
+
+use soroban_sdk::{
+    vec, Address, Bytes, BytesN, Env, IntoVal, InvokeError, Symbol, Val, Vec,
+};
+
+use crate::storage;
+use crate::{agreed_pause, badges, deposit, game, pause, signers};
+use crate::{
+    CommitMode, CommitReceipt, Error, Game, GameWindow, Move, MoveSequence, MovesCommitted,
+    MovesRevealed, PlayerCommitment, RaiseStatus, TURNS_PER_BATTLE,
+};
+
+// use ultrahonk_soroban_verifier::PROOF_BYTES;
+
+// Import UltraHonk verifier contract
+// mod ultrahonk_contract {
+//     soroban_sdk::contractimport!(file = "ultrahonk_soroban_contract.wasm");
+// }
+
+// pub const ULTRAHONK_CONTRACT_ADDRESS: &str = "CCSORRUPEPDR4KPXLWIF4WCHERHJDOAHRAK6NTFSI2WLPPFTDVTATM74";
+
+/// Public inputs are three 32-byte fields: `[player_address | session_id | commitment_hash]`
+const PUBLIC_INPUTS_LEN: u32 = 96;
+
+pub(crate) fn verify_proof(
+    env: &Env,
+    verifier: &Address,
+    player: &Address,
+    session_id: u32,
+    public_inputs: Bytes,
+    proof_bytes: Bytes,
+) -> Result<BytesN<32>, Error> {
+    // Reject malformed or misdirected inputs before paying for a verifier call
+    let commitment_hash = parse_public_inputs(env, &public_inputs, player, session_id)?;
+    invoke_verifier(env, verifier, public_inputs, proof_bytes)?;
+    Ok(commitment_hash)
+}
+
+/// Check a proof without committing it, so a frontend can simulate this before
+/// paying for `commit_moves`. Returns the commitment hash the proof would commit.
+/// Nothing is stored, and the player and session fields are not checked against
+/// any game.
+pub(crate) fn preverify_proof(
+    env: &Env,
+    public_inputs: Bytes,
+    proof_bytes: Bytes,
+) -> Result<BytesN<32>, Error> {
+    if public_inputs.len() != PUBLIC_INPUTS_LEN {
+        return Err(Error::InvalidPublicInputs);
+    }
+    if storage::is_nullifier_spent(env, &nullifier(env, &public_inputs)) {
+        return Err(Error::NullifierUsed);
+    }
+    let commitment_hash = public_inputs.slice(PUBLIC_INPUTS_LEN - 32..);
+    let verifier = storage::verifier(env, storage::circuit_version(env))?;
+    invoke_verifier(env, &verifier, public_inputs, proof_bytes)?;
+    Ok(commitment_hash.try_into().unwrap())
+}
+
+fn invoke_verifier(
+    env: &Env,
+    verifier: &Address,
+    public_inputs: Bytes,
+    proof_bytes: Bytes,
+) -> Result<(), Error> {
+    let mut args: Vec<Val> = Vec::new(env);
+    args.push_back(public_inputs.into_val(env));
+    args.push_back(proof_bytes.into_val(env));
+
+    match env.try_invoke_contract::<(), InvokeError>(
+        verifier,
+        &Symbol::new(env, "verify_proof"),
+        args,
+    ) {
+        Ok(Ok(())) => Ok(()),
+        // The verifier returned a contract error: it checked the proof and said no
+        Err(Ok(InvokeError::Contract(_))) => Err(Error::InvalidProof),
+        // Trapped, or returned a value we can't decode
+        Ok(Err(_)) | Err(Ok(InvokeError::Abort)) | Err(Err(_)) => Err(Error::VerifierTrapped),
+    }
+}
+
+/// Check the public inputs were made for this player and session, and return their
+/// commitment hash. A proof for another session, or the opponent's proof, fails
+/// here rather than being accepted on the strength of its commitment alone.
+pub(crate) fn parse_public_inputs(
+    env: &Env,
+    public_inputs: &Bytes,
+    player: &Address,
+    session_id: u32,
+) -> Result<BytesN<32>, Error> {
+    if public_inputs.len() != PUBLIC_INPUTS_LEN {
+        return Err(Error::InvalidPublicInputs);
+    }
+
+    let mut fields = [0u8; PUBLIC_INPUTS_LEN as usize];
+    public_inputs.copy_into_slice(&mut fields);
+    let (player_field, rest) = fields.split_at(32);
+    let (session_field, commitment_hash) = rest.split_at(32);
+
+    if *player_field != player_field_for(player) || *session_field != session_field_for(session_id) {
+        return Err(Error::InvalidPublicInputs);
+    }
+
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(commitment_hash);
+    Ok(BytesN::from_array(env, &hash))
+}
+
+/// The circuit's `player_address` field: the first 31 bytes of the strkey,
+/// left-padded to 32 bytes so it fits a field element
+fn player_field_for(player: &Address) -> [u8; 32] {
+    let mut strkey = [0u8; 56];
+    player.to_string().copy_into_slice(&mut strkey);
+
+    let mut field = [0u8; 32];
+    field[1..].copy_from_slice(&strkey[..31]);
+    field
+}
+
+/// The circuit's `session_id` field: the session id as a big-endian field element
+fn session_field_for(session_id: u32) -> [u8; 32] {
+    let mut field = [0u8; 32];
+    field[28..].copy_from_slice(&session_id.to_be_bytes());
+    field
+}
+
+/// Nullifier for a commit: the hash of its public inputs, so a proof (and the move
+/// set behind it) can only be committed once
+pub(crate) fn nullifier(env: &Env, public_inputs: &Bytes) -> BytesN<32> {
+    env.crypto().sha256(public_inputs).into()
+}
+
+/// Commit move sequence with ZK proof
+/// Player proves they know valid moves WITHOUT revealing them.
+/// The proof's public output (commitment hash) is stored.
+pub(crate) fn commit_moves(
+    env: &Env,
+    session_id: u32,
+    player: Address,
+    public_inputs: Bytes,
+    proof_bytes: Bytes,
+) -> Result<CommitReceipt, Error> {
+    signers::require_move_auth(env, session_id, &player);
+
+    let game = load_for_commit(env, session_id, CommitMode::Zk)?;
+
+    // Each game is checked by the circuit version it was started under
+    let ultrahonk_addr = storage::verifier(env, game.circuit_version)?;
+
+    let nullifier = nullifier(env, &public_inputs);
+    let commitment_hash =
+        verify_proof(env, &ultrahonk_addr, &player, session_id, public_inputs, proof_bytes)?;
+
+    accept_commit(env, session_id, game, player, commitment_hash, nullifier)
+}
+
+/// Hash-mode commit: store `sha256(moves || salt)` as is. The digest is spent like
+/// a proof's nullifier, so it can't be reused or copied from the opponent.
+pub(crate) fn commit_moves_hashed(
+    env: &Env,
+    session_id: u32,
+    player: Address,
+    commitment: BytesN<32>,
+) -> Result<CommitReceipt, Error> {
+    signers::require_move_auth(env, session_id, &player);
+
+    let game = load_for_commit(env, session_id, CommitMode::Hash)?;
+    let nullifier = nullifier(env, &Bytes::from(commitment.clone()));
+    accept_commit(env, session_id, game, player, commitment, nullifier)
+}
+
+/// Hash-mode commitment: `sha256(moves || salt)`, each move as its attack and
+/// defense byte
+pub(crate) fn hashed_commitment(env: &Env, moves: &Vec<Move>, salt: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = Bytes::new(env);
+    for m in moves.iter() {
+        preimage.push_back(m.attack as u8);
+        preimage.push_back(m.defense as u8);
+    }
+    preimage.append(&Bytes::from(salt.clone()));
+    env.crypto().sha256(&preimage).into()
+}
+
+fn load_for_commit(env: &Env, session_id: u32, mode: CommitMode) -> Result<Game, Error> {
+    let game = storage::load_game(env, session_id)?;
+
+    if game.commit_mode != mode {
+        return Err(Error::WrongCommitMode);
+    }
+    if game.has_battle_result {
+        return Err(Error::GameAlreadyEnded);
+    }
+    if game.commit_deadline != 0 && env.ledger().timestamp() > pause::deadline(env, game.commit_deadline) {
+        return Err(Error::DeadlinePassed);
+    }
+    Ok(game)
+}
+
+/// Record a verified commitment, whichever way it was made
+fn accept_commit(
+    env: &Env,
+    session_id: u32,
+    mut game: Game,
+    player: Address,
+    commitment_hash: BytesN<32>,
+    nullifier: BytesN<32>,
+) -> Result<CommitReceipt, Error> {
+    let commitment = PlayerCommitment {
+        proof_id: commitment_hash.clone(),
+        has_revealed: false,
+        moves: MoveSequence { moves: vec![env] },
+    };
+
+    if player == game.player1 {
+        if game.has_player1_commitment {
+            return Err(Error::AlreadyCommitted);
+        }
+        game.player1_commitment = commitment;
+        game.has_player1_commitment = true;
+    } else if player == game.player2 {
+        if game.has_player2_commitment {
+            return Err(Error::AlreadyCommitted);
+        }
+        game.player2_commitment = commitment;
+        game.has_player2_commitment = true;
+    } else {
+        return Err(Error::NotPlayer);
+    }
+
+    // A proof is good for one commit: replaying it in another game is rejected
+    if storage::is_nullifier_spent(env, &nullifier) {
+        return Err(Error::NullifierUsed);
+    }
+    storage::spend_nullifier(env, &nullifier);
+
+    // Both in: the reveal window opens
+    let both_committed = game.has_player1_commitment && game.has_player2_commitment;
+    if both_committed {
+        game.reveal_deadline = agreed_pause::clock_start(env, session_id) + game::reveal_window(env, game.mode);
+    }
+
+    storage::save_game(env, session_id, &game);
+    badges::sync_game(env, session_id, &game);
+    let receipt = record_commit(env, session_id, &player, commitment_hash);
+    if both_committed {
+        game::announce_window(env, session_id, &game, GameWindow::Reveal, game.reveal_deadline);
+    }
+    deposit::collect(env, &game, &player);
+    Ok(receipt)
+}
+
+/// Store the receipt for an accepted commit and announce it
+pub(crate) fn record_commit(
+    env: &Env,
+    session_id: u32,
+    player: &Address,
+    commitment: BytesN<32>,
+) -> CommitReceipt {
+    let receipt = CommitReceipt {
+        session_id,
+        player: player.clone(),
+        commitment: commitment.clone(),
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    };
+    storage::save_commit_receipt(env, &receipt);
+    MovesCommitted {
+        session_id,
+        player: player.clone(),
+        commitment,
+    }
+    .publish(env);
+    receipt
+}
+
+/// Reveal moves — player re-proves with moves now PUBLIC.
+/// The contract verifies the new proof's commitment output
+/// matches what was stored at commit time.
+///
+/// Reveals wait for a pending raise to be answered, so the stake each side plays
+/// for (see `get_effective_wager`) is settled on-chain before any moves are public.
+pub(crate) fn reveal_moves(
+    env: &Env,
+    session_id: u32,
+    player: Address,
+    public_inputs: Bytes,
+    moves: Vec<Move>,
+) -> Result<(), Error> {
+    signers::require_move_auth(env, session_id, &player);
+
+    let game = load_for_reveal(env, session_id, &player, &moves, CommitMode::Zk)?;
+    let revealed_hash = parse_public_inputs(env, &public_inputs, &player, session_id)?;
+
+    accept_reveal(env, session_id, game, player, revealed_hash, moves)
+}
+
+/// Hash-mode reveal: the commitment is recomputed from the moves and salt. Reveals
+/// wait for a pending raise to be answered here too.
+pub(crate) fn reveal_moves_hashed(
+    env: &Env,
+    session_id: u32,
+    player: Address,
+    moves: Vec<Move>,
+    salt: BytesN<32>,
+) -> Result<(), Error> {
+    signers::require_move_auth(env, session_id, &player);
+
+    let game = load_for_reveal(env, session_id, &player, &moves, CommitMode::Hash)?;
+    let revealed_hash = hashed_commitment(env, &moves, &salt);
+
+    accept_reveal(env, session_id, game, player, revealed_hash, moves)
+}
+
+fn load_for_reveal(
+    env: &Env,
+    session_id: u32,
+    player: &Address,
+    moves: &Vec<Move>,
+    mode: CommitMode,
+) -> Result<Game, Error> {
+    if moves.len() != TURNS_PER_BATTLE {
+        return Err(Error::InvalidMoveSequence);
+    }
+
+    let game = storage::load_game(env, session_id)?;
+
+    if game.commit_mode != mode {
+        return Err(Error::WrongCommitMode);
+    }
+    if !game.has_player1_commitment || !game.has_player2_commitment {
+        return Err(Error::BothPlayersNotCommitted);
+    }
+    if game.raise.status == RaiseStatus::Pending {
+        return Err(Error::RaisePending);
+    }
+
+    if *player != game.player1 && *player != game.player2 {
+        return Err(Error::NotPlayer);
+    }
+    Ok(game)
+}
+
+/// Record revealed moves once they are shown to match the player's commitment
+fn accept_reveal(
+    env: &Env,
+    session_id: u32,
+    mut game: Game,
+    player: Address,
+    revealed_hash: BytesN<32>,
+    moves: Vec<Move>,
+) -> Result<(), Error> {
+    if player == game.player1 {
+        if game.player1_commitment.has_revealed {
+            return Err(Error::AlreadyRevealed);
+        }
+        if revealed_hash != game.player1_commitment.proof_id {
+            return Err(Error::CommitmentMismatch);
+        }
+        game.player1_commitment.moves = MoveSequence { moves: moves.clone() };
+        game.player1_commitment.has_revealed = true;
+    } else if player == game.player2 {
+        if game.player2_commitment.has_revealed {
+            return Err(Error::AlreadyRevealed);
+        }
+        if revealed_hash != game.player2_commitment.proof_id {
+            return Err(Error::CommitmentMismatch);
+        }
+        game.player2_commitment.moves = MoveSequence { moves: moves.clone() };
+        game.player2_commitment.has_revealed = true;
+    } else {
+        return Err(Error::NotPlayer);
+    }
+
+    storage::save_game(env, session_id, &game);
+    badges::sync_game(env, session_id, &game);
+    MovesRevealed {
+        session_id,
+        player: player.clone(),
+        moves,
+    }
+    .publish(env);
+    if game.player1_commitment.has_revealed && game.player2_commitment.has_revealed {
+        game::queue_resolution(env, session_id);
+    }
+    deposit::refund(env, &game, &player);
+    Ok(())
+}
@@ -5,7 +5,7 @@ use soroban_sdk::{contract, contractevent, contractimpl, Address, Env};
 /// Mock Game Hub contract for game studio development
 ///
 /// This contract provides the same external interface that games expect
-/// (start_game, end_game) but does nothing internally. It exists purely
+/// (start_game, end_game, cancel_game, is_registered, add_points) but does nothing internally. It exists purely
 /// for game contracts to compile and integrate during development.
 #[contract]
 pub struct MockGameHub;
@@ -26,6 +26,18 @@ pub struct GameEnded {
     pub player1_won: bool,
 }
 
+#[contractevent]
+pub struct GameCancelled {
+    pub session_id: u32,
+}
+
+#[contractevent]
+pub struct PointsAdded {
+    pub game_id: Address,
+    pub player: Address,
+    pub points: i128,
+}
+
 #[contractimpl]
 impl MockGameHub {
     /// Start a game session
@@ -77,6 +89,38 @@ impl MockGameHub {
         }
         .publish(&env);
     }
+
+    /// End a game session without a winner, refunding both players' points
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being cancelled
+    pub fn cancel_game(env: Env, session_id: u32) {
+        // No auth required for mock
+        GameCancelled { session_id }.publish(&env);
+    }
+
+    /// Whether `player` is registered with the hub
+    ///
+    /// Every player counts as registered in the mock.
+    pub fn is_registered(_env: Env, _player: Address) -> bool {
+        true
+    }
+
+    /// Add points a game awarded outside a session to a player's balance
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract awarding the points
+    /// * `player` - Address of the player receiving them
+    /// * `points` - Points amount (ignored in mock)
+    pub fn add_points(env: Env, game_id: Address, player: Address, points: i128) {
+        // No auth required for mock
+        PointsAdded {
+            game_id,
+            player,
+            points,
+        }
+        .publish(&env);
+    }
 }
 
 #[cfg(test)]
@@ -86,7 +130,7 @@ mod test {
     #[test]
     fn test_start_and_end_game() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, MockGameHub);
+        let contract_id = env.register(MockGameHub, ());
         let client = MockGameHubClient::new(&env, &contract_id);
         let game_id = Address::generate(&env);
         let player1 = Address::generate(&env);
@@ -94,4 +138,16 @@ mod test {
         client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
         client.end_game(&1, &true);
     }
+
+    #[test]
+    fn test_cancel_game() {
+        let env = Env::default();
+        let contract_id = env.register(MockGameHub, ());
+        let client = MockGameHubClient::new(&env, &contract_id);
+        let game_id = Address::generate(&env);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+        client.start_game(&game_id, &2, &player1, &player2, &1000, &1000);
+        client.cancel_game(&2);
+    }
 }
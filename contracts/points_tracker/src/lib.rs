@@ -75,7 +75,7 @@ impl PointsTracker {
             .instance()
             .get(&DataKey::Points(loser.clone()))
             .unwrap_or(0u64);
-        let new_loser_pts = if loser_pts >= 15 { loser_pts - 15 } else { 0 };
+        let new_loser_pts = loser_pts.saturating_sub(15);
         env.storage().instance().set(
             &DataKey::Points(loser.clone()),
             &new_loser_pts,
@@ -44,6 +44,34 @@ echo ""
 echo -e "${BLUE}==> 1) cd $CIRCUIT_DIR${NC}"
 cd "$CIRCUIT_DIR"
 
+echo ""
+echo -e "${BLUE}==> Setting up player accounts${NC}"
+
+# Generate keys if they don't exist
+if ! stellar keys address playerone 2>/dev/null; then
+    echo "    Generating playerone key..."
+    stellar keys generate --global playerone
+fi
+
+if ! stellar keys address playertwo 2>/dev/null; then
+    echo "    Generating playertwo key..."
+    stellar keys generate --global playertwo
+fi
+
+# Store addresses in variables
+PLAYER1_ADDR=$(stellar keys address playerone)
+PLAYER2_ADDR=$(stellar keys address playertwo)
+
+SESSION_ID=42
+
+# The proofs' public inputs must name the real player and session: the circuit's
+# player_address is the first 31 bytes of the strkey, left-padded to a field
+# (as NoirService.addressToField builds it), and session_id is the plain number
+address_field() {
+  python3 -c 'import sys; print("0x" + sys.argv[1].encode()[:31].hex().rjust(64, "0"))' "$1"
+}
+SESSION_FIELD="$(printf '0x%064x' "$SESSION_ID")"
+
 # ── Step 2: Install dependencies ─────────────────────────────────────────────
 echo ""
 echo -e "${BLUE}==> 2) Install bb.js${NC}"
@@ -59,11 +87,11 @@ echo -e "${YELLOW}╔═══════════════════
 echo -e "${YELLOW}║  PLAYER 1: Generating Commitment Proof                    ║${NC}"
 echo -e "${YELLOW}╚═══════════════════════════════════════════════════════════╝${NC}"
 
-cat > Prover.toml <<'PLAYER1'
+cat > Prover.toml <<PLAYER1
 attacks = ["0", "1", "2"]
 defenses = ["0", "1", "2"]
-player_address = "0x0000000000000000000000000000000000000000000000000000000000000001"
-session_id = "0x0000000000000000000000000000000000000000000000000000000000000042"
+player_address = "$(address_field "$PLAYER1_ADDR")"
+session_id = "$SESSION_FIELD"
 PLAYER1
 
 echo "    Player 1 Moves:"
@@ -127,11 +155,11 @@ echo -e "${YELLOW}╔═══════════════════
 echo -e "${YELLOW}║  PLAYER 2: Generating Commitment Proof                    ║${NC}"
 echo -e "${YELLOW}╚═══════════════════════════════════════════════════════════╝${NC}"
 
-cat > Prover.toml <<'PLAYER2'
+cat > Prover.toml <<PLAYER2
 attacks = ["1", "2", "0"]
 defenses = ["2", "0", "1"]
-player_address = "0x0000000000000000000000000000000000000000000000000000000000000002"
-session_id = "0x0000000000000000000000000000000000000000000000000000000000000042"
+player_address = "$(address_field "$PLAYER2_ADDR")"
+session_id = "$SESSION_FIELD"
 PLAYER2
 
 echo "    Player 2 Moves:"
@@ -271,23 +299,6 @@ stellar contract build
 ADMIN_ADDR="$(stellar keys address kaysT)"
 # PLAYER1_ADDR="$(stellar keys address player1 2>/dev/null || stellar keys generate player1 && stellar keys address player1)"
 # PLAYER2_ADDR="$(stellar keys address player2 2>/dev/null || stellar keys generate player2 && stellar keys address player2)"
-echo ""
-echo -e "${BLUE}==> Setting up player accounts${NC}"
-
-# Generate keys if they don't exist
-if ! stellar keys address playerone 2>/dev/null; then
-    echo "    Generating playerone key..."
-    stellar keys generate --global playerone
-fi
-
-if ! stellar keys address playertwo 2>/dev/null; then
-    echo "    Generating playertwo key..."
-    stellar keys generate --global playertwo
-fi
-
-# Store addresses in variables
-PLAYER1_ADDR=$(stellar keys address playerone)
-PLAYER2_ADDR=$(stellar keys address playertwo)
 
 # Fund the accounts on testnet network
 echo "    Funding player1..."
@@ -371,7 +382,6 @@ echo -e "${YELLOW}╔═══════════════════
 echo -e "${YELLOW}║  🎮 SIMULATING COMPLETE GAME FLOW                         ║${NC}"
 echo -e "${YELLOW}╚═══════════════════════════════════════════════════════════╝${NC}"
 
-SESSION_ID=42
 POINTS_WAGERED=1000
 
 # Step 1: Start Game
@@ -381,19 +391,31 @@ echo "    Player 1: $PLAYER1_ADDR"
 echo "    Player 2: $PLAYER2_ADDR"
 echo "    Wager: $POINTS_WAGERED points each"
 
+# start_game needs both signatures in one transaction; the CLI signs as one
+# account, so player 1 proposes and player 2 confirms
 stellar contract invoke \
   --id "$CLASH_CID" \
   --network testnet \
   --source-account playerone \
   --send yes \
   -- \
-  start_game \
+  propose_game \
   --session_id "$SESSION_ID" \
   --player1 "$PLAYER1_ADDR" \
   --player2 "$PLAYER2_ADDR" \
   --player1_points "$POINTS_WAGERED" \
   --player2_points "$POINTS_WAGERED"
 
+stellar contract invoke \
+  --id "$CLASH_CID" \
+  --network testnet \
+  --source-account playertwo \
+  --send yes \
+  -- \
+  confirm_game \
+  --session_id "$SESSION_ID" \
+  --player2 "$PLAYER2_ADDR"
+
 echo -e "${GREEN}✓ Game started!${NC}"
 
 # Step 2: Player 1 commits moves
@@ -433,6 +455,7 @@ stellar contract invoke \
 echo -e "${GREEN}✓ Player 2 committed!${NC}"
 
 # Step 4: Player 1 reveals moves
+# A reveal reuses the commit's public inputs as they are, with no proof
 echo ""
 echo -e "${BLUE}==> 18) Player 1 reveals moves${NC}"
 